@@ -1,6 +1,8 @@
 "use client";
 
 import { ConnectionProvider } from "@storiny/shared";
+import { get_connection_provider } from "@storiny/shared/src/utils/get-connection-provider";
+import { get_timestamp } from "@storiny/shared/src/utils/timestamp-fields";
 import { clsx } from "clsx";
 import React from "react";
 
@@ -28,7 +30,7 @@ const ConnectionItem = ({
   provider
 }: {
   connection?: ConnectionsProps["connections"][number];
-  provider: string;
+  provider: ConnectionProvider;
 }): React.ReactElement => {
   const [connection, set_connection] =
     React.useState<typeof connection_prop>(connection_prop);
@@ -42,9 +44,7 @@ const ConnectionItem = ({
     >
       <div className={clsx(css["flex-center"], styles.details)}>
         <span className={styles.icon}>
-          {React.createElement(
-            PROVIDER_ICON_MAP[connection?.provider || provider]
-          )}
+          {React.createElement(PROVIDER_ICON_MAP[provider])}
         </span>
         <div className={css["flex-col"]}>
           {connection ? (
@@ -69,11 +69,17 @@ const ConnectionItem = ({
                 color={"minor"}
                 ellipsis
                 level={"body2"}
-                title={format_date(connection.created_at, DateFormat.STANDARD)}
+                title={format_date(
+                  get_timestamp(connection, "created_at")!,
+                  DateFormat.STANDARD
+                )}
               >
-                {PROVIDER_DISPLAY_NAME_MAP[connection.provider]}{" "}
+                {PROVIDER_DISPLAY_NAME_MAP[provider]}{" "}
                 <span className={css["t-muted"]}>&bull;</span> Connected{" "}
-                {format_date(connection.created_at, DateFormat.RELATIVE)}
+                {format_date(
+                  get_timestamp(connection, "created_at")!,
+                  DateFormat.RELATIVE
+                )}
               </Typography>
             </NoSsr>
           ) : (
@@ -134,7 +140,8 @@ const ConnectionSettingsClient = ({
             .map((provider) => (
               <ConnectionItem
                 connection={connections.find(
-                  (connection) => connection.provider === provider
+                  (connection) =>
+                    get_connection_provider(connection) === provider
                 )}
                 key={provider}
                 provider={provider}
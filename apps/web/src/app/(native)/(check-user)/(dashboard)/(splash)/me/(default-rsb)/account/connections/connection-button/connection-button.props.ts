@@ -1,7 +1,9 @@
+import { ConnectionProvider } from "@storiny/shared";
+
 import { ConnectionsProps } from "../connections.props";
 
 export interface ConnectionButtonProps {
   connection?: ConnectionsProps["connections"][number];
   on_remove: () => void;
-  provider: string;
+  provider: ConnectionProvider;
 }
@@ -24,7 +24,7 @@ const ConnectionButton = ({
   );
   const [remove_connection, { isLoading: is_loading }] =
     use_remove_connection_mutation();
-  const display_name = PROVIDER_DISPLAY_NAME_MAP[connection?.provider || 0];
+  const display_name = PROVIDER_DISPLAY_NAME_MAP[provider];
 
   /**
    * Handles confirmation
@@ -67,9 +67,7 @@ const ConnectionButton = ({
     connection
       ? {
           on_confirm: handle_confirm,
-          decorator: React.createElement(
-            PROVIDER_ICON_MAP[connection.provider]
-          ),
+          decorator: React.createElement(PROVIDER_ICON_MAP[provider]),
           title: `Disconnect ${display_name}?`,
           description: `Your ${display_name} details will be deleted, and your ${display_name} account will not be displayed on your profile until you link it again.`
         }
@@ -1,6 +1,6 @@
 // noinspection JSUnusedGlobalSymbols
 
-import { ConnectionProvider } from "@storiny/shared";
+import { ConnectionProvider } from "@storiny/proto/dist/connection_def/v1/def";
 import type { Meta, StoryObj } from "@storybook/react";
 import React from "react";
 
@@ -19,9 +19,11 @@ const meta: Meta<typeof AccountConnectionsPage> = {
     connections: [
       {
         id: "0",
-        provider: ConnectionProvider.GITHUB,
+        provider: "github",
+        provider_type: ConnectionProvider.GITHUB,
         url: "/",
         created_at: new Date().toJSON(),
+        created_at_ts: new Date(),
         display_name: "zignis",
         hidden: false
       }
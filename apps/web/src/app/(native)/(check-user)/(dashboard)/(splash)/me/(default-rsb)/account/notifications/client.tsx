@@ -18,6 +18,7 @@ const PrivacyClient = ({
   mail_digest,
   features_and_updates,
   replies,
+  mentions,
   friend_requests,
   collaboration_requests,
   blog_requests,
@@ -34,6 +35,7 @@ const PrivacyClient = ({
         {...{
           features_and_updates,
           replies,
+          mentions,
           friend_requests,
           collaboration_requests,
           blog_requests,
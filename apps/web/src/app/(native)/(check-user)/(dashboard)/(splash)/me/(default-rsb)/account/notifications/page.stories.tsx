@@ -25,6 +25,7 @@ const meta: Meta<typeof AccountNotificationsPage> = {
     mail_features_and_updates: true,
     mail_login_activity: true,
     mail_newsletters: true,
+    mentions: true,
     new_followers: true,
     replies: true,
     stories: true
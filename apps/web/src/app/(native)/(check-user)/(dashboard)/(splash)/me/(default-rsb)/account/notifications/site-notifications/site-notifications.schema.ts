@@ -9,6 +9,7 @@ export const SITE_NOTIFICATIONS_SCHEMA = z.object({
   tags: z.boolean(),
   comments: z.boolean(),
   replies: z.boolean(),
+  mentions: z.boolean(),
   new_followers: z.boolean(),
   friend_requests: z.boolean(),
   collaboration_requests: z.boolean(),
@@ -17,6 +17,7 @@ describe("<SiteNotifications />", () => {
         comments
         features_and_updates
         friend_requests
+        mentions
         new_followers
         on_submit={mock_submit}
         replies
@@ -37,6 +38,7 @@ describe("<SiteNotifications />", () => {
       comments: true,
       features_and_updates: false,
       friend_requests: true,
+      mentions: true,
       new_followers: true,
       replies: true,
       stories: true,
@@ -26,6 +26,7 @@ const SiteNotifications = ({
   blog_requests,
   tags,
   replies,
+  mentions,
   new_followers,
   stories,
   comments,
@@ -42,6 +43,7 @@ const SiteNotifications = ({
       features_and_updates,
       new_followers,
       replies,
+      mentions,
       comments,
       tags,
       stories,
@@ -156,6 +158,15 @@ const SiteNotifications = ({
           onCheckedChange={submit_form}
           size={"lg"}
         />
+        <FormCheckbox
+          helper_text={
+            <>Receive a notification when someone mentions you in a story.</>
+          }
+          label={"Mentions"}
+          name={"mentions"}
+          onCheckedChange={submit_form}
+          size={"lg"}
+        />
         <FormCheckbox
           helper_text={
             <>Receive a notification when someone starts following you.</>
@@ -1,6 +1,7 @@
 "use client";
 
 import { DeviceType } from "@storiny/shared";
+import { get_timestamp } from "@storiny/shared/src/utils/timestamp-fields";
 import { clsx } from "clsx";
 import dynamic from "next/dynamic";
 import NextLink from "next/link";
@@ -137,7 +138,7 @@ const LoginAccordion = (props: LoginAccordionProps): React.ReactElement => {
                     )}
                     {login.location?.display_name || "Unknown location"}{" "}
                     <span className={css["t-muted"]}>&bull;</span>{" "}
-                    <DateTime date={login.created_at} />
+                    <DateTime date={get_timestamp(login, "created_at")!} />
                   </React.Fragment>
                 )}
               </Typography>
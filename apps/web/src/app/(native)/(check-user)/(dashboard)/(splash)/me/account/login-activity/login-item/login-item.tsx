@@ -1,6 +1,7 @@
 "use client";
 
 import { DeviceType } from "@storiny/shared";
+import { get_timestamp } from "@storiny/shared/src/utils/timestamp-fields";
 import { clsx } from "clsx";
 import dynamic from "next/dynamic";
 import React from "react";
@@ -142,7 +143,7 @@ const LoginItem = (props: LoginItemProps): React.ReactElement => {
               <React.Fragment>
                 {login.location?.display_name || "Unknown location"}{" "}
                 <span className={css["t-muted"]}>&bull;</span>{" "}
-                <DateTime date={login.created_at} />
+                <DateTime date={get_timestamp(login, "created_at")!} />
               </React.Fragment>
             )}
           </Typography>
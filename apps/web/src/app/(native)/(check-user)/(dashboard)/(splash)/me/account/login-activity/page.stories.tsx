@@ -27,7 +27,8 @@ const meta: Meta<typeof AccountLoginActivityPage> = {
         lat: 43.6532
       },
       is_active: false,
-      created_at: new Date().toJSON()
+      created_at: new Date().toJSON(),
+      created_at_ts: new Date()
     },
     logins: [
       {
@@ -43,7 +44,8 @@ const meta: Meta<typeof AccountLoginActivityPage> = {
         },
         domain: "test.storiny.com",
         is_active: true,
-        created_at: "2023-05-18T01:07:02.000Z"
+        created_at: "2023-05-18T01:07:02.000Z",
+        created_at_ts: new Date("2023-05-18T01:07:02.000Z")
       },
       {
         id: "1",
@@ -58,7 +60,8 @@ const meta: Meta<typeof AccountLoginActivityPage> = {
         },
         domain: "example.storiny.com",
         is_active: false,
-        created_at: "2022-11-18T01:07:02.000Z"
+        created_at: "2022-11-18T01:07:02.000Z",
+        created_at_ts: new Date("2022-11-18T01:07:02.000Z")
       },
       {
         id: "2",
@@ -70,7 +73,8 @@ const meta: Meta<typeof AccountLoginActivityPage> = {
           display_name: "Unknown location"
         },
         is_active: false,
-        created_at: "2022-10-03T01:07:02.000Z"
+        created_at: "2022-10-03T01:07:02.000Z",
+        created_at_ts: new Date("2022-10-03T01:07:02.000Z")
       },
       {
         id: "3",
@@ -84,7 +88,8 @@ const meta: Meta<typeof AccountLoginActivityPage> = {
           lat: 33.7537
         },
         is_active: false,
-        created_at: "2021-07-03T01:07:02.000Z"
+        created_at: "2021-07-03T01:07:02.000Z",
+        created_at_ts: new Date("2021-07-03T01:07:02.000Z")
       }
     ]
   },
@@ -20,6 +20,10 @@ const meta: Meta<typeof ContentDraftsPage> = {
     latest_draft: {
       ...MOCK_STORIES[0],
       edited_at: MOCK_STORIES[0].edited_at || undefined,
+      created_at_ts: new Date(MOCK_STORIES[0].created_at),
+      edited_at_ts: MOCK_STORIES[0].edited_at
+        ? new Date(MOCK_STORIES[0].edited_at)
+        : undefined,
       splash_hex: MOCK_STORIES[0].splash_hex || undefined,
       splash_id: MOCK_STORIES[0].splash_id || undefined
     }
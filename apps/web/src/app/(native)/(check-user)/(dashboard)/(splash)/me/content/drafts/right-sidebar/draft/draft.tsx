@@ -1,4 +1,5 @@
 import { ImageSize } from "@storiny/shared";
+import { get_timestamp } from "@storiny/shared/src/utils/timestamp-fields";
 import { clsx } from "clsx";
 import NextLink from "next/link";
 import React from "react";
@@ -66,7 +67,10 @@ const Draft = (props: DraftProps): React.ReactElement => {
             {abbreviate_number(latest_draft.word_count)} words{" "}
             <span className={css["t-muted"]}>&bull;</span> Edited{" "}
             <DateTime
-              date={latest_draft.edited_at || latest_draft.created_at}
+              date={
+                (get_timestamp(latest_draft, "edited_at") ||
+                  get_timestamp(latest_draft, "created_at"))!
+              }
               format={DateFormat.RELATIVE}
             />
           </Typography>
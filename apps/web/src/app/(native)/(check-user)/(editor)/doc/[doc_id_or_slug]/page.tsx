@@ -1,6 +1,8 @@
 import "server-only";
 
 import Editor from "@storiny/editor";
+import { serialize_timestamps } from "@storiny/shared/src/utils/serialize-timestamps";
+import { get_timestamp } from "@storiny/shared/src/utils/timestamp-fields";
 import { DocUserRole, Story } from "@storiny/types";
 import { decompressSync as decompress_sync } from "fflate";
 import { redirect } from "next/navigation";
@@ -34,7 +36,7 @@ const Page = async ({
 
     if (
       is_writer &&
-      typeof story_metadata_response.deleted_at !== "undefined"
+      get_timestamp(story_metadata_response, "deleted_at") !== undefined
     ) {
       const doc = await get_doc_by_key(story_metadata_response.doc_key);
       return (
@@ -44,7 +46,9 @@ const Page = async ({
           is_writer
           role={"editor"}
           status={"deleted"}
-          story={story_metadata_response as unknown as Story}
+          story={
+            serialize_timestamps(story_metadata_response) as unknown as Story
+          }
         />
       );
     }
@@ -55,11 +59,13 @@ const Page = async ({
         is_writer={is_writer}
         role={story_metadata_response.role as DocUserRole}
         status={
-          typeof story_metadata_response.published_at !== "undefined"
+          get_timestamp(story_metadata_response, "published_at") !== undefined
             ? "published"
             : "draft"
         }
-        story={story_metadata_response as unknown as Story}
+        story={
+          serialize_timestamps(story_metadata_response) as unknown as Story
+        }
       />
     );
   } catch (e) {
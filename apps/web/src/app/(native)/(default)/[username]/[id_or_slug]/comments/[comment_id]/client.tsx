@@ -1,5 +1,7 @@
 "use client";
 
+import { serialize_timestamps } from "@storiny/shared/src/utils/serialize-timestamps";
+import { get_timestamp } from "@storiny/shared/src/utils/timestamp-fields";
 import { User } from "@storiny/types";
 import { clsx } from "clsx";
 import React from "react";
@@ -33,9 +35,9 @@ const Page = (props: Props): React.ReactElement => {
       <Comment
         className={clsx(styles.x, styles.comment)}
         comment={{
-          ...props,
-          edited_at: props.edited_at || null,
-          user: props.user as User | undefined
+          ...serialize_timestamps(props),
+          edited_at: get_timestamp(props, "edited_at")?.toISOString() || null,
+          user: serialize_timestamps(props.user) as User | undefined
         }}
         hide_hidden_overlay
       />
@@ -1,4 +1,5 @@
 import { ImageSize } from "@storiny/shared";
+import { get_timestamp } from "@storiny/shared/src/utils/timestamp-fields";
 import React from "react";
 import { Graph } from "schema-dts";
 
@@ -36,8 +37,8 @@ const generate_json_ld = (comment: Props): Graph => ({
         128
       )}"`,
       identifier: comment.id,
-      datePublished: comment.created_at,
-      dateModified: comment.edited_at || undefined,
+      datePublished: get_timestamp(comment, "created_at")?.toISOString(),
+      dateModified: get_timestamp(comment, "edited_at")?.toISOString(),
       url: `${process.env.NEXT_PUBLIC_WEB_URL}/${comment.story_writer_username}/${comment.story_slug}/comments/${comment.id}`,
       upvoteCount: comment.like_count,
       text: comment.content,
@@ -21,6 +21,7 @@ const COMMENT_USER: NonNullable<GetCommentResponse["user"]> = {
   public_flags: MOCK_USERS[5].public_flags,
   is_private: MOCK_USERS[5].is_private,
   created_at: MOCK_USERS[5].created_at,
+  created_at_ts: new Date(MOCK_USERS[5].created_at),
   follower_count: MOCK_USERS[5].follower_count,
   location: MOCK_USERS[5].location
 };
@@ -33,6 +34,10 @@ const meta: Meta<typeof StoryComponent> = {
     story_writer_username: MOCK_COMMENTS[5].story?.user?.username,
     story_slug: MOCK_COMMENTS[5].story?.slug,
     edited_at: MOCK_COMMENTS[5].edited_at || undefined,
+    created_at_ts: new Date(MOCK_COMMENTS[5].created_at),
+    edited_at_ts: MOCK_COMMENTS[5].edited_at
+      ? new Date(MOCK_COMMENTS[5].edited_at)
+      : undefined,
     user: COMMENT_USER
   },
   parameters: {
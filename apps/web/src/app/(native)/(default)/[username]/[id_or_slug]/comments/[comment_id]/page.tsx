@@ -1,5 +1,7 @@
 import "server-only";
 
+import { get_timestamp } from "@storiny/shared/src/utils/timestamp-fields";
+import { is_suspended_user } from "@storiny/shared/src/utils/user-flags";
 import { notFound as not_found, redirect } from "next/navigation";
 import React from "react";
 
@@ -34,6 +36,14 @@ const Page = async ({
       current_user_id: user_id || undefined
     });
 
+    // Comment taken down or the commenter suspended
+    if (
+      get_timestamp(comment_response, "deleted_at") !== undefined ||
+      (comment_response.user && is_suspended_user(comment_response.user))
+    ) {
+      not_found();
+    }
+
     // Redirect if the username or story slug is incorrect
     if (
       comment_response.story_writer_username !== username ||
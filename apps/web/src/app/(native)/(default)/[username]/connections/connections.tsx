@@ -1,3 +1,4 @@
+import { get_connection_provider } from "@storiny/shared/src/utils/get-connection-provider";
 import { clsx } from "clsx";
 import React from "react";
 
@@ -21,29 +22,32 @@ const Connections = ({
   is_inside_sidebar
 }: Props): React.ReactElement => (
   <div className={clsx(css.flex, styles.connections)}>
-    {connections.map((connection) =>
-      !PROVIDER_DISPLAY_NAME_MAP[connection.provider] ||
-      !PROVIDER_ICON_MAP[connection.provider] ? null : (
+    {connections.map((connection) => {
+      const provider = get_connection_provider(connection);
+
+      if (!provider) {
+        return null;
+      }
+
+      const label = `${connection.display_name} on ${PROVIDER_DISPLAY_NAME_MAP[provider]}`;
+
+      return (
         <IconButton
-          aria-label={`${connection.display_name} on ${
-            PROVIDER_DISPLAY_NAME_MAP[connection.provider]
-          }`}
+          aria-label={label}
           as={"a"}
           className={clsx(styles.x, styles.connection)}
           href={connection.url}
-          key={connection.provider}
+          key={provider}
           rel={"noreferrer"}
           size={is_inside_sidebar ? "md" : "lg"}
           target={"_blank"}
-          title={`${connection.display_name} on ${
-            PROVIDER_DISPLAY_NAME_MAP[connection.provider]
-          }`}
+          title={label}
           variant={"ghost"}
         >
-          {React.createElement(PROVIDER_ICON_MAP[connection.provider])}
+          {React.createElement(PROVIDER_ICON_MAP[provider])}
         </IconButton>
-      )
-    )}
+      );
+    })}
   </div>
 );
 
@@ -1,5 +1,6 @@
 "use client";
 
+import { get_timestamp } from "@storiny/shared/src/utils/timestamp-fields";
 import { clsx } from "clsx";
 import dynamic from "next/dynamic";
 import NextLink from "next/link";
@@ -377,7 +378,10 @@ const ProfileContent = ({
                     !is_inside_sidebar && styles.status
                   )}
                   emoji={profile.status.emoji}
-                  expires_at={profile.status.expires_at}
+                  expires_at={get_timestamp(
+                    profile.status,
+                    "expires_at"
+                  )?.toISOString()}
                   text={profile.status.text}
                   user_id={profile.id}
                 />
@@ -425,12 +429,18 @@ const ProfileContent = ({
                   <Typography
                     as={"time"}
                     color={"minor"}
-                    dateTime={profile.created_at}
+                    dateTime={get_timestamp(
+                      profile,
+                      "created_at"
+                    )?.toISOString()}
                     level={"body2"}
-                    title={format_date(profile.created_at)}
+                    title={format_date(get_timestamp(profile, "created_at")!)}
                   >
                     Joined{" "}
-                    {format_date(profile.created_at, DateFormat.RELATIVE)}
+                    {format_date(
+                      get_timestamp(profile, "created_at")!,
+                      DateFormat.RELATIVE
+                    )}
                   </Typography>
                 </NoSsr>
               </li>
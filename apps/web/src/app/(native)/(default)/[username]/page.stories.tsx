@@ -1,7 +1,8 @@
 // noinspection JSUnusedGlobalSymbols
 
+import { ConnectionProvider } from "@storiny/proto/dist/connection_def/v1/def";
 import { GetProfileResponse } from "@storiny/proto/dist/profile_def/v1/def";
-import { ConnectionProvider, UserFlag } from "@storiny/shared";
+import { UserFlag } from "@storiny/shared";
 import { MOCK_USERS } from "@storiny/ui/src/mocks";
 import type { Meta, StoryObj } from "@storybook/react";
 import React from "react";
@@ -25,7 +26,8 @@ const MOCK_RESPONSE = {
   connections: [
     {
       url: "https://github.com/storiny",
-      provider: ConnectionProvider.GITHUB,
+      provider: "github",
+      provider_type: ConnectionProvider.GITHUB,
       display_name: "Some user"
     }
   ]
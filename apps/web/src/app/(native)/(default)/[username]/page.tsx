@@ -1,5 +1,6 @@
 import "server-only";
 
+import { is_suspended_user } from "@storiny/shared/src/utils/user-flags";
 import { notFound as not_found } from "next/navigation";
 import React from "react";
 
@@ -28,6 +29,10 @@ const Page = async ({
       current_user_id: user_id || undefined
     });
 
+    if (is_suspended_user(profile)) {
+      not_found();
+    }
+
     return <Component profile={profile} />;
   } catch (e) {
     handle_exception(e);
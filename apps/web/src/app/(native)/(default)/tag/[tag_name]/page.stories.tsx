@@ -13,6 +13,7 @@ import Tag from "./component";
 const MOCK_RESPONSE: GetTagResponse = {
   name: "test-tag",
   created_at: new Date().toJSON(),
+  created_at_ts: new Date(),
   follower_count: 1920,
   story_count: 14,
   is_following: true,
@@ -1,6 +1,9 @@
 import "server-only";
 
 import { get_blog_url } from "@storiny/shared/src/utils/get-blog-url";
+import { serialize_timestamps } from "@storiny/shared/src/utils/serialize-timestamps";
+import { get_timestamp } from "@storiny/shared/src/utils/timestamp-fields";
+import { is_suspended_user } from "@storiny/shared/src/utils/user-flags";
 import { Story } from "@storiny/types";
 import { notFound as not_found, redirect } from "next/navigation";
 import React from "react";
@@ -33,20 +36,23 @@ const Page = async ({
       current_user_id: user_id || undefined
     });
 
+    const published_at = get_timestamp(story_response, "published_at");
+    const first_published_at = get_timestamp(
+      story_response,
+      "first_published_at"
+    );
+
     if (
       !story_response.user || // Sanity
-      (typeof story_response.published_at === "undefined" &&
-        typeof story_response.first_published_at === "undefined") || // Story was never published
-      typeof story_response.deleted_at !== "undefined" // Delete story
+      (published_at === undefined && first_published_at === undefined) || // Story was never published
+      get_timestamp(story_response, "deleted_at") !== undefined || // Delete story
+      is_suspended_user(story_response.user) // Writer suspended
     ) {
       not_found();
     }
 
     // Story unpubished
-    if (
-      typeof story_response.first_published_at !== "undefined" &&
-      typeof story_response.published_at === "undefined"
-    ) {
+    if (first_published_at !== undefined && published_at === undefined) {
       return (
         <RestrictedStory type={"unpublished"} user={story_response.user} />
       );
@@ -74,12 +80,24 @@ const Page = async ({
       }
     }
 
+    // Rated story that the viewer has not opted into
+    if (story_response.is_gated) {
+      return (
+        <RestrictedStory type={"age-restricted"} user={story_response.user} />
+      );
+    }
+
     // Uint8Array needs to be converted into an untyped array so that it can be
     // safely serialized to JSON for client-side hydration. It is converted back
     // into a Uint8Array on the client side.
     const doc = Array.from(await get_doc_by_key(story_response.doc_key));
 
-    return <Component doc={doc} story={story_response as Story} />;
+    return (
+      <Component
+        doc={doc}
+        story={serialize_timestamps(story_response) as Story}
+      />
+    );
   } catch (e) {
     handle_exception(e);
   }
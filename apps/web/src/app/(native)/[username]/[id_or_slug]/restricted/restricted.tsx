@@ -46,6 +46,12 @@ const RestrictedStory = (props: RestrictedStoryProps): React.ReactElement => {
                 You cannot access this story as you have been blocked by{" "}
                 <span className={css["t-medium"]}>@{user.username}</span>.
               </>
+            ) : type === "age-restricted" ? (
+              <>
+                <span className={css["t-medium"]}>@{user.username}</span> has
+                marked this story as mature. Log in and allow sensitive media
+                from your site safety settings to read it.
+              </>
             ) : (
               <>
                 <span className={css["t-medium"]}>@{user.username}</span> has
@@ -57,7 +63,9 @@ const RestrictedStory = (props: RestrictedStoryProps): React.ReactElement => {
           title={
             type === "user-blocked"
               ? "You are being restricted"
-              : "This story has been unpublished"
+              : type === "age-restricted"
+                ? "This story is age-restricted"
+                : "This story has been unpublished"
           }
         />
       </Main>
@@ -99,6 +99,7 @@ const STORY_USER: NonNullable<GetStoryResponse["user"]> = {
   public_flags: MOCK_USERS[5].public_flags,
   is_private: MOCK_USERS[5].is_private,
   created_at: MOCK_USERS[5].created_at,
+  created_at_ts: new Date(MOCK_USERS[5].created_at),
   follower_count: MOCK_USERS[5].follower_count,
   location: MOCK_USERS[5].location,
   is_blocked_by_user: false,
@@ -179,6 +180,20 @@ export const Blocked: Story = {
   ]
 };
 
+export const AgeRestricted: Story = {
+  decorators: [
+    (): React.ReactElement =>
+      render_with_state(
+        <StorybookBlogLayout blog={{ is_story_minimal_layout: false }}>
+          <RestrictedStory type={"age-restricted"} user={STORY_USER} />
+        </StorybookBlogLayout>,
+        {
+          ignore_primitive_providers: true
+        }
+      )
+  ]
+};
+
 export const MinimalLayout: Story = {
   decorators: [
     (Story): React.ReactElement =>
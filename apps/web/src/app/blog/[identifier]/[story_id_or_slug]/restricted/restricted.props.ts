@@ -1,6 +1,6 @@
 import { GetStoryResponse } from "~/common/grpc";
 
 export interface RestrictedStoryProps {
-  type: "user-blocked" | "unpublished";
+  type: "user-blocked" | "unpublished" | "age-restricted";
   user: NonNullable<GetStoryResponse["user"]>;
 }
@@ -3,9 +3,11 @@
 import "server-only";
 
 import {
+  ChannelCredentials,
   credentials,
   InterceptingCall,
   Interceptor,
+  Metadata,
   RequesterBuilder,
   ServiceError
 } from "@grpc/grpc-js";
@@ -111,24 +113,69 @@ import {
   GetUserRelationsInfoRequest,
   GetUserRelationsInfoResponse
 } from "@storiny/proto/dist/user_def/v1/def";
+import { headers } from "next/headers";
 import { cache } from "react";
 
+import { get_session_token } from "~/common/utils/get-session-token";
+
+import { create_service_credential } from "./utils/create-service-credential";
+
 declare global {
   /* eslint-disable no-var */
   var grpc_client: InstanceType<typeof ApiServiceClient>;
   /* eslint-enable no-var */
 }
 
+/**
+ * Returns the call metadata carrying the session token of the user, which
+ * the gRPC service uses to authenticate the user, and the IP address of the
+ * visitor, which it uses for rate limiting.
+ */
+const get_call_metadata = async (): Promise<Metadata> => {
+  const metadata = new Metadata();
+
+  try {
+    const [session_token, headers_value] = await Promise.all([
+      get_session_token(),
+      headers()
+    ]);
+    // `X-Real-IP` is set by nginx from the address of the peer. The leftmost
+    // `X-Forwarded-For` entries are sent by the client and can be spoofed, so
+    // only the rightmost entry, appended by the proxy, is trusted.
+    const client_ip = (
+      headers_value.get("x-real-ip") ||
+      headers_value.get("x-forwarded-for")?.split(",").pop() ||
+      ""
+    ).trim();
+
+    if (session_token) {
+      metadata.set("x-session-token", session_token);
+    }
+
+    if (client_ip) {
+      metadata.set("x-client-ip", client_ip);
+    }
+  } catch {
+    // Cookies are unavailable outside a request scope, where the calls are
+    // made anonymously.
+  }
+
+  return metadata;
+};
+
 /**
  * Converts callback style function to a promise
  * @param callback Callback style function to promisify
  */
 const promisify =
   <Req, Res>(callback: (...args: any) => any) =>
-  (payload: Req): Promise<Res> =>
-    new Promise((resolve, reject) => {
+  async (payload: Req): Promise<Res> => {
+    const metadata = await get_call_metadata();
+
+    return new Promise((resolve, reject) => {
       callback.bind(global.grpc_client)(
         payload,
+        metadata,
         (err: ServiceError | null, response: Res) => {
           if (err) {
             reject(err);
@@ -137,6 +184,7 @@ const promisify =
         }
       );
     });
+  };
 
 /**
  * Authentication interceptor for the channel.
@@ -144,7 +192,12 @@ const promisify =
 const auth_interceptor: Interceptor = (options, next_call) => {
   const requester = new RequesterBuilder()
     .withStart((metadata, listener, next) => {
-      metadata.set("authorization", `Bearer ${process.env.GRPC_SECRET_TOKEN}`);
+      const credential = create_service_credential();
+
+      if (credential) {
+        metadata.set("authorization", `Bearer ${credential}`);
+      }
+
       next(metadata, listener);
     })
     .build();
@@ -152,9 +205,28 @@ const auth_interceptor: Interceptor = (options, next_call) => {
   return new InterceptingCall(next_call(options), requester);
 };
 
+/**
+ * Returns the channel credentials. The connection uses mutual TLS when the
+ * certificates are present in the environment, and is insecure otherwise
+ * (during local development).
+ */
+const get_channel_credentials = (): ChannelCredentials => {
+  const { GRPC_SSL_CA, GRPC_SSL_CERT, GRPC_SSL_KEY } = process.env;
+
+  if (!GRPC_SSL_CA || !GRPC_SSL_CERT || !GRPC_SSL_KEY) {
+    return credentials.createInsecure();
+  }
+
+  return credentials.createSsl(
+    Buffer.from(GRPC_SSL_CA),
+    Buffer.from(GRPC_SSL_KEY),
+    Buffer.from(GRPC_SSL_CERT)
+  );
+};
+
 global.grpc_client = new ApiServiceClient(
   process.env.GRPC_ENDPOINT as string,
-  credentials.createInsecure(),
+  get_channel_credentials(),
   {
     interceptors: [auth_interceptor],
     "grpc.default_compression_algorithm": CompressionAlgorithms.gzip
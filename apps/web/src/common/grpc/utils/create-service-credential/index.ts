@@ -0,0 +1,29 @@
+import "server-only";
+
+import { createHmac as create_hmac } from "node:crypto";
+
+const CREDENTIAL_VERSION = "v1";
+const SERVICE_NAME = "web";
+const CREDENTIAL_LIFETIME = 60; // In seconds
+
+/**
+ * Returns a short-lived credential identifying this service to the gRPC
+ * service, in the form of `v1.<service>.<expires_at>.<signature>`, signed
+ * using the `GRPC_SECRET_TOKEN` environment variable. Returns `null` if the
+ * secret is missing from the environment.
+ */
+export const create_service_credential = (): string | null => {
+  const secret = process.env.GRPC_SECRET_TOKEN;
+
+  if (!secret) {
+    return null;
+  }
+
+  const expires_at = Math.floor(Date.now() / 1000) + CREDENTIAL_LIFETIME;
+  const payload = `${CREDENTIAL_VERSION}.${SERVICE_NAME}.${expires_at}`;
+  const signature = create_hmac("sha256", secret)
+    .update(payload)
+    .digest("base64url");
+
+  return `${payload}.${signature}`;
+};
@@ -0,0 +1,41 @@
+import "server-only";
+
+import { ServiceError } from "@grpc/grpc-js";
+import {
+  ErrorDetail,
+  ErrorReason
+} from "@storiny/proto/dist/error_def/v1/def";
+import { Status } from "@storiny/proto/dist/google/rpc/status";
+
+const ERROR_DETAIL_TYPE_URL = "type.googleapis.com/error_def.v1.ErrorDetail";
+
+/**
+ * Decodes the `ErrorDetail` attached to the `google.rpc.Status` details of an
+ * error from the gRPC service. Returns `null` for errors without a detail.
+ * @param err `ServiceError` object
+ */
+export const get_error_detail = (err: ServiceError): ErrorDetail | null => {
+  const [details] = err?.metadata?.get("grpc-status-details-bin") || [];
+
+  if (!(details instanceof Uint8Array)) {
+    return null;
+  }
+
+  try {
+    const detail = Status.decode(details).details.find(
+      (item) => item.type_url === ERROR_DETAIL_TYPE_URL
+    );
+
+    return detail ? ErrorDetail.decode(detail.value) : null;
+  } catch {
+    return null;
+  }
+};
+
+/**
+ * Returns the reason of an error from the gRPC service, or
+ * `ErrorReason.UNSPECIFIED` for errors without a detail.
+ * @param err `ServiceError` object
+ */
+export const get_error_reason = (err: ServiceError): ErrorReason =>
+  get_error_detail(err)?.reason ?? ErrorReason.UNSPECIFIED;
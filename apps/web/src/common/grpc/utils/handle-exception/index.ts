@@ -3,10 +3,36 @@ import "server-only";
 import { ServiceError } from "@grpc/grpc-js";
 import { Status } from "@grpc/grpc-js/build/src/constants";
 import { captureException as capture_exception } from "@sentry/nextjs";
+import { ErrorReason } from "@storiny/proto/dist/error_def/v1/def";
 import { isHTTPAccessFallbackError as is_http_access_fallback_error } from "next/dist/client/components/http-access-fallback/http-access-fallback";
 import { isRedirectError as is_redirect_error } from "next/dist/client/components/redirect-error";
 import { notFound as not_found, redirect } from "next/navigation";
 
+import { get_error_reason } from "../get-error-detail";
+
+/**
+ * Reasons for the errors that are expected during normal use, such as a
+ * visitor following a link to a deleted story. These are not reported.
+ */
+const EXPECTED_ERROR_REASONS = new Set<ErrorReason>([
+  ErrorReason.USER_NOT_FOUND,
+  ErrorReason.USER_SUSPENDED,
+  ErrorReason.USER_BLOCKED,
+  ErrorReason.PROFILE_PRIVATE,
+  ErrorReason.STORY_NOT_FOUND,
+  ErrorReason.STORY_DELETED,
+  ErrorReason.STORY_UNPUBLISHED,
+  ErrorReason.DRAFT_NOT_FOUND,
+  ErrorReason.COMMENT_NOT_FOUND,
+  ErrorReason.COMMENT_DELETED,
+  ErrorReason.BLOG_NOT_FOUND,
+  ErrorReason.TAG_NOT_FOUND,
+  ErrorReason.TOKEN_NOT_FOUND,
+  ErrorReason.TOKEN_EXPIRED,
+  ErrorReason.SESSION_INVALID,
+  ErrorReason.RATE_LIMITED
+]);
+
 /**
  * Returns the 404, login, and gateway error pages for errors from the gRPC service.
  * @param err `ServiceError` object
@@ -17,22 +43,35 @@ export const handle_exception = (err: ServiceError): void => {
     throw err;
   }
 
-  capture_exception(err);
-
   const err_code = err?.code;
+  const reason = get_error_reason(err);
 
-  // 404 page
-  if (err_code === Status.NOT_FOUND) {
+  if (!EXPECTED_ERROR_REASONS.has(reason)) {
+    capture_exception(err);
+  }
+
+  // 404 page, also used for the content hidden from the viewer
+  if (
+    err_code === Status.NOT_FOUND ||
+    reason === ErrorReason.USER_BLOCKED ||
+    reason === ErrorReason.PROFILE_PRIVATE
+  ) {
     not_found();
   }
 
   // Login page
   if (err_code === Status.UNAUTHENTICATED) {
-    // This case indicates that the auth token is missing from the environment.
-    if (err.message === "missing_client_auth_token") {
+    // This case indicates that the auth token is missing from the environment
+    // or does not match the one known to the service.
+    if (reason === ErrorReason.CREDENTIAL_INVALID) {
       throw new Error("gateway_error");
     }
 
+    // The session cookie is stale or invalid.
+    if (reason === ErrorReason.SESSION_INVALID) {
+      redirect("/logout");
+    }
+
     redirect("/login");
   }
 
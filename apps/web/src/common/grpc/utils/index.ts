@@ -1 +1,3 @@
+export * from "./create-service-credential";
+export * from "./get-error-detail";
 export * from "./handle-exception";
@@ -3,12 +3,13 @@
 import "server-only";
 
 import { Status } from "@grpc/grpc-js/build/src/constants";
+import { ErrorReason } from "@storiny/proto/dist/error_def/v1/def";
 import { cookies } from "next/headers";
 import { redirect } from "next/navigation";
 
 import { SESSION_COOKIE_ID } from "~/common/constants";
 import { get_user_id } from "~/common/grpc";
-import { handle_exception } from "~/common/grpc/utils";
+import { get_error_reason, handle_exception } from "~/common/grpc/utils";
 
 /**
  * Checks whether the user maintains a valid session by sending a
@@ -29,11 +30,15 @@ export const get_user = async (): Promise<string | null> => {
 
       return user.id;
     } catch (e) {
-      // Not found error is thrown if the token is stale or invalid
+      // Not found error is thrown if the token is stale or invalid, and the
+      // session error if the service rejects the token sent in the metadata
       const err_code = e?.code;
 
       // Session not found
-      if (err_code === Status.NOT_FOUND) {
+      if (
+        err_code === Status.NOT_FOUND ||
+        get_error_reason(e) === ErrorReason.SESSION_INVALID
+      ) {
         redirect("/logout");
       }
 
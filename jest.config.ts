@@ -60,6 +60,16 @@ const config: Config = {
         "~/(.*)": `<rootDir>/packages/ui/src/$1`,
       },
     },
+    {
+      displayName: "shared",
+      setupFilesAfterEnv: [`<rootDir>/packages/shared/jest.setup.ts`],
+      testEnvironment: "node",
+      transform: {
+        "^.+\\.(t|j)sx?$": "@swc/jest",
+      },
+      testMatch: ["<rootDir>/packages/shared/**/*.test.ts"],
+      modulePathIgnorePatterns: ["<rootDir>/.vercel/"],
+    },
   ],
   transformIgnorePatterns: ["<rootDir>/node_modules/", "<rootDir>/e2e/"],
   verbose: true,
@@ -0,0 +1,72 @@
+import { $convertFromMarkdownString as $convert_from_markdown_string } from "@lexical/markdown";
+import { compressSync as compress_sync } from "fflate";
+import { $getRoot as $get_root, createEditor as create_editor } from "lexical";
+import { Doc, encodeStateAsUpdateV2 as encode_state_as_update_v2 } from "yjs";
+
+import { create_binding } from "../../collaboration/bindings";
+import { EDITOR_NODES } from "../../nodes";
+import { MD_TRANSFORMERS } from "../../plugins/markdown/transformers";
+import { sync_with_transaction } from "../sync-with-transaction";
+
+/**
+ * Creates the binary document stored behind `doc_key` from Markdown content.
+ * Used when importing stories, the result is compressed in the same way as
+ * the documents read by the story pages.
+ * @param markdown Markdown content
+ */
+export const create_doc_from_markdown = (markdown: string): Uint8Array => {
+  const editor = create_editor({
+    namespace: "import",
+    nodes: EDITOR_NODES,
+    onError: (error) => {
+      throw error;
+    }
+  });
+  const doc = new Doc();
+  const binding = create_binding(editor, doc, new Map([["main", doc]]));
+
+  // There is no provider for a headless editor, so only the document tree is
+  // synced (without the selection).
+  const remove_listener = editor.registerUpdateListener(
+    ({
+      prevEditorState: prev_editor_state,
+      editorState: curr_editor_state,
+      dirtyElements: dirty_elements,
+      dirtyLeaves: dirty_leaves
+    }) => {
+      sync_with_transaction(binding, () => {
+        curr_editor_state.read(() => {
+          const root = $get_root();
+          const prev_node_map = prev_editor_state._nodeMap;
+
+          binding.root.sync_properties_from_lexical(
+            binding,
+            root,
+            prev_node_map
+          );
+          binding.root.sync_children_from_lexical(
+            binding,
+            root,
+            prev_node_map,
+            dirty_elements,
+            dirty_leaves
+          );
+        });
+      });
+    }
+  );
+
+  editor.update(
+    () => {
+      $convert_from_markdown_string(markdown, MD_TRANSFORMERS);
+    },
+    { discrete: true }
+  );
+
+  remove_listener();
+
+  const data = compress_sync(encode_state_as_update_v2(doc));
+  doc.destroy();
+
+  return data;
+};
@@ -0,0 +1,52 @@
+import { $convertToMarkdownString as $convert_to_markdown_string } from "@lexical/markdown";
+import { decompressSync as decompress_sync } from "fflate";
+import { createEditor as create_editor } from "lexical";
+import { applyUpdateV2 as apply_update_v2, Doc, YEvent } from "yjs";
+
+import { create_binding } from "../../collaboration/bindings";
+import { EDITOR_NODES } from "../../nodes";
+import { MD_TRANSFORMERS } from "../../plugins/markdown/transformers";
+import { sync_yjs_changes_to_lexical } from "../sync-yjs-changes-to-lexical";
+
+/**
+ * Returns the Markdown content of the binary document stored behind
+ * `doc_key`. Used when exporting stories, this is the inverse of
+ * `create_doc_from_markdown`.
+ * @param data Compressed document data
+ */
+export const get_markdown_from_doc = (data: Uint8Array): string => {
+  const editor = create_editor({
+    namespace: "export",
+    nodes: EDITOR_NODES,
+    onError: (error) => {
+      throw error;
+    }
+  });
+  const doc = new Doc();
+  const binding = create_binding(editor, doc, new Map([["main", doc]]));
+  const shared_type = binding.root.get_shared_type();
+  const listener = (events: YEvent<any>[]): void => {
+    sync_yjs_changes_to_lexical({
+      binding,
+      events,
+      is_from_undo_manager: false,
+      read_only: true
+    });
+  };
+
+  shared_type.observeDeep(listener);
+
+  try {
+    apply_update_v2(doc, decompress_sync(data));
+  } finally {
+    shared_type.unobserveDeep(listener);
+    doc.destroy();
+  }
+
+  // Commits the pending update synchronously
+  editor.update(() => undefined, { discrete: true });
+
+  return editor
+    .getEditorState()
+    .read(() => $convert_to_markdown_string(MD_TRANSFORMERS));
+};
@@ -0,0 +1,877 @@
+/* eslint-disable */
+import Long from "long";
+import _m0 from "protobufjs/minimal";
+import { Timestamp } from "../../google/protobuf/timestamp";
+
+export const protobufPackage = "account_def.v1";
+
+export const AccountExportStatus = {
+  UNSPECIFIED: 0,
+  QUEUED: 1,
+  PROCESSING: 2,
+  READY: 3,
+  FAILED: 4,
+  EXPIRED: 5,
+  UNRECOGNIZED: -1,
+} as const;
+
+export type AccountExportStatus = typeof AccountExportStatus[keyof typeof AccountExportStatus];
+
+export function accountExportStatusFromJSON(object: any): AccountExportStatus {
+  switch (object) {
+    case 0:
+    case "ACCOUNT_EXPORT_STATUS_UNSPECIFIED":
+      return AccountExportStatus.UNSPECIFIED;
+    case 1:
+    case "ACCOUNT_EXPORT_STATUS_QUEUED":
+      return AccountExportStatus.QUEUED;
+    case 2:
+    case "ACCOUNT_EXPORT_STATUS_PROCESSING":
+      return AccountExportStatus.PROCESSING;
+    case 3:
+    case "ACCOUNT_EXPORT_STATUS_READY":
+      return AccountExportStatus.READY;
+    case 4:
+    case "ACCOUNT_EXPORT_STATUS_FAILED":
+      return AccountExportStatus.FAILED;
+    case 5:
+    case "ACCOUNT_EXPORT_STATUS_EXPIRED":
+      return AccountExportStatus.EXPIRED;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return AccountExportStatus.UNRECOGNIZED;
+  }
+}
+
+export function accountExportStatusToJSON(object: AccountExportStatus): string {
+  switch (object) {
+    case AccountExportStatus.UNSPECIFIED:
+      return "ACCOUNT_EXPORT_STATUS_UNSPECIFIED";
+    case AccountExportStatus.QUEUED:
+      return "ACCOUNT_EXPORT_STATUS_QUEUED";
+    case AccountExportStatus.PROCESSING:
+      return "ACCOUNT_EXPORT_STATUS_PROCESSING";
+    case AccountExportStatus.READY:
+      return "ACCOUNT_EXPORT_STATUS_READY";
+    case AccountExportStatus.FAILED:
+      return "ACCOUNT_EXPORT_STATUS_FAILED";
+    case AccountExportStatus.EXPIRED:
+      return "ACCOUNT_EXPORT_STATUS_EXPIRED";
+    case AccountExportStatus.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
+export const StoryDisposition = {
+  UNSPECIFIED: 0,
+  DELETE: 1,
+  ANONYMIZE: 2,
+  TRANSFER_TO_BLOG: 3,
+  UNRECOGNIZED: -1,
+} as const;
+
+export type StoryDisposition = typeof StoryDisposition[keyof typeof StoryDisposition];
+
+export function storyDispositionFromJSON(object: any): StoryDisposition {
+  switch (object) {
+    case 0:
+    case "STORY_DISPOSITION_UNSPECIFIED":
+      return StoryDisposition.UNSPECIFIED;
+    case 1:
+    case "STORY_DISPOSITION_DELETE":
+      return StoryDisposition.DELETE;
+    case 2:
+    case "STORY_DISPOSITION_ANONYMIZE":
+      return StoryDisposition.ANONYMIZE;
+    case 3:
+    case "STORY_DISPOSITION_TRANSFER_TO_BLOG":
+      return StoryDisposition.TRANSFER_TO_BLOG;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return StoryDisposition.UNRECOGNIZED;
+  }
+}
+
+export function storyDispositionToJSON(object: StoryDisposition): string {
+  switch (object) {
+    case StoryDisposition.UNSPECIFIED:
+      return "STORY_DISPOSITION_UNSPECIFIED";
+    case StoryDisposition.DELETE:
+      return "STORY_DISPOSITION_DELETE";
+    case StoryDisposition.ANONYMIZE:
+      return "STORY_DISPOSITION_ANONYMIZE";
+    case StoryDisposition.TRANSFER_TO_BLOG:
+      return "STORY_DISPOSITION_TRANSFER_TO_BLOG";
+    case StoryDisposition.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
+export interface AccountExport {
+  id: string;
+  status: AccountExportStatus;
+  created_at: Date | undefined;
+  completed_at?:
+    | Date
+    | undefined;
+  /** Expiry of the archive and its download token */
+  expires_at?:
+    | Date
+    | undefined;
+  /** Only present when the archive is ready */
+  download_token?:
+    | string
+    | undefined;
+  /** Size of the archive (in bytes) */
+  size?: number | undefined;
+}
+
+export interface RequestAccountExportRequest {
+  user_id: string;
+}
+
+export interface RequestAccountExportResponse {
+  account_export: AccountExport | undefined;
+}
+
+export interface GetAccountExportStatusRequest {
+  user_id: string;
+  export_id: string;
+}
+
+export interface GetAccountExportStatusResponse {
+  account_export: AccountExport | undefined;
+}
+
+export interface ScheduleAccountDeletionRequest {
+  user_id: string;
+  story_disposition: StoryDisposition;
+  /** Required for `STORY_DISPOSITION_TRANSFER_TO_BLOG` */
+  blog_id?: string | undefined;
+}
+
+export interface ScheduleAccountDeletionResponse {
+  /** End of the grace period */
+  scheduled_for: Date | undefined;
+}
+
+export interface CancelAccountDeletionRequest {
+  user_id: string;
+}
+
+export interface CancelAccountDeletionResponse {
+}
+
+function createBaseAccountExport(): AccountExport {
+  return {
+    id: "",
+    status: 0,
+    created_at: undefined,
+    completed_at: undefined,
+    expires_at: undefined,
+    download_token: undefined,
+    size: undefined,
+  };
+}
+
+export const AccountExport = {
+  encode(message: AccountExport, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.id !== "") {
+      writer.uint32(10).string(message.id);
+    }
+    if (message.status !== 0) {
+      writer.uint32(16).int32(message.status);
+    }
+    if (message.created_at !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at), writer.uint32(26).fork()).ldelim();
+    }
+    if (message.completed_at !== undefined) {
+      Timestamp.encode(toTimestamp(message.completed_at), writer.uint32(34).fork()).ldelim();
+    }
+    if (message.expires_at !== undefined) {
+      Timestamp.encode(toTimestamp(message.expires_at), writer.uint32(42).fork()).ldelim();
+    }
+    if (message.download_token !== undefined) {
+      writer.uint32(50).string(message.download_token);
+    }
+    if (message.size !== undefined) {
+      writer.uint32(56).uint64(message.size);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): AccountExport {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseAccountExport();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.status = reader.int32() as any;
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.created_at = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.completed_at = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.expires_at = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 6:
+          if (tag !== 50) {
+            break;
+          }
+
+          message.download_token = reader.string();
+          continue;
+        case 7:
+          if (tag !== 56) {
+            break;
+          }
+
+          message.size = longToNumber(reader.uint64() as Long);
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): AccountExport {
+    return {
+      id: isSet(object.id) ? globalThis.String(object.id) : "",
+      status: isSet(object.status) ? accountExportStatusFromJSON(object.status) : 0,
+      created_at: isSet(object.created_at) ? fromJsonTimestamp(object.created_at) : undefined,
+      completed_at: isSet(object.completed_at) ? fromJsonTimestamp(object.completed_at) : undefined,
+      expires_at: isSet(object.expires_at) ? fromJsonTimestamp(object.expires_at) : undefined,
+      download_token: isSet(object.download_token) ? globalThis.String(object.download_token) : undefined,
+      size: isSet(object.size) ? globalThis.Number(object.size) : undefined,
+    };
+  },
+
+  toJSON(message: AccountExport): unknown {
+    const obj: any = {};
+    if (message.id !== "") {
+      obj.id = message.id;
+    }
+    if (message.status !== 0) {
+      obj.status = accountExportStatusToJSON(message.status);
+    }
+    if (message.created_at !== undefined) {
+      obj.created_at = message.created_at.toISOString();
+    }
+    if (message.completed_at !== undefined) {
+      obj.completed_at = message.completed_at.toISOString();
+    }
+    if (message.expires_at !== undefined) {
+      obj.expires_at = message.expires_at.toISOString();
+    }
+    if (message.download_token !== undefined) {
+      obj.download_token = message.download_token;
+    }
+    if (message.size !== undefined) {
+      obj.size = Math.round(message.size);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<AccountExport>, I>>(base?: I): AccountExport {
+    return AccountExport.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<AccountExport>, I>>(object: I): AccountExport {
+    const message = createBaseAccountExport();
+    message.id = object.id ?? "";
+    message.status = object.status ?? 0;
+    message.created_at = object.created_at ?? undefined;
+    message.completed_at = object.completed_at ?? undefined;
+    message.expires_at = object.expires_at ?? undefined;
+    message.download_token = object.download_token ?? undefined;
+    message.size = object.size ?? undefined;
+    return message;
+  },
+};
+
+function createBaseRequestAccountExportRequest(): RequestAccountExportRequest {
+  return { user_id: "" };
+}
+
+export const RequestAccountExportRequest = {
+  encode(message: RequestAccountExportRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): RequestAccountExportRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRequestAccountExportRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): RequestAccountExportRequest {
+    return { user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "" };
+  },
+
+  toJSON(message: RequestAccountExportRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RequestAccountExportRequest>, I>>(base?: I): RequestAccountExportRequest {
+    return RequestAccountExportRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RequestAccountExportRequest>, I>>(object: I): RequestAccountExportRequest {
+    const message = createBaseRequestAccountExportRequest();
+    message.user_id = object.user_id ?? "";
+    return message;
+  },
+};
+
+function createBaseRequestAccountExportResponse(): RequestAccountExportResponse {
+  return { account_export: undefined };
+}
+
+export const RequestAccountExportResponse = {
+  encode(message: RequestAccountExportResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.account_export !== undefined) {
+      AccountExport.encode(message.account_export, writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): RequestAccountExportResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRequestAccountExportResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.account_export = AccountExport.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): RequestAccountExportResponse {
+    return { account_export: isSet(object.account_export) ? AccountExport.fromJSON(object.account_export) : undefined };
+  },
+
+  toJSON(message: RequestAccountExportResponse): unknown {
+    const obj: any = {};
+    if (message.account_export !== undefined) {
+      obj.account_export = AccountExport.toJSON(message.account_export);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RequestAccountExportResponse>, I>>(base?: I): RequestAccountExportResponse {
+    return RequestAccountExportResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RequestAccountExportResponse>, I>>(object: I): RequestAccountExportResponse {
+    const message = createBaseRequestAccountExportResponse();
+    message.account_export = (object.account_export !== undefined && object.account_export !== null)
+      ? AccountExport.fromPartial(object.account_export)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseGetAccountExportStatusRequest(): GetAccountExportStatusRequest {
+  return { user_id: "", export_id: "" };
+}
+
+export const GetAccountExportStatusRequest = {
+  encode(message: GetAccountExportStatusRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.export_id !== "") {
+      writer.uint32(18).string(message.export_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): GetAccountExportStatusRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseGetAccountExportStatusRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.export_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): GetAccountExportStatusRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      export_id: isSet(object.export_id) ? globalThis.String(object.export_id) : "",
+    };
+  },
+
+  toJSON(message: GetAccountExportStatusRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.export_id !== "") {
+      obj.export_id = message.export_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<GetAccountExportStatusRequest>, I>>(base?: I): GetAccountExportStatusRequest {
+    return GetAccountExportStatusRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<GetAccountExportStatusRequest>, I>>(
+    object: I,
+  ): GetAccountExportStatusRequest {
+    const message = createBaseGetAccountExportStatusRequest();
+    message.user_id = object.user_id ?? "";
+    message.export_id = object.export_id ?? "";
+    return message;
+  },
+};
+
+function createBaseGetAccountExportStatusResponse(): GetAccountExportStatusResponse {
+  return { account_export: undefined };
+}
+
+export const GetAccountExportStatusResponse = {
+  encode(message: GetAccountExportStatusResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.account_export !== undefined) {
+      AccountExport.encode(message.account_export, writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): GetAccountExportStatusResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseGetAccountExportStatusResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.account_export = AccountExport.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): GetAccountExportStatusResponse {
+    return { account_export: isSet(object.account_export) ? AccountExport.fromJSON(object.account_export) : undefined };
+  },
+
+  toJSON(message: GetAccountExportStatusResponse): unknown {
+    const obj: any = {};
+    if (message.account_export !== undefined) {
+      obj.account_export = AccountExport.toJSON(message.account_export);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<GetAccountExportStatusResponse>, I>>(base?: I): GetAccountExportStatusResponse {
+    return GetAccountExportStatusResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<GetAccountExportStatusResponse>, I>>(
+    object: I,
+  ): GetAccountExportStatusResponse {
+    const message = createBaseGetAccountExportStatusResponse();
+    message.account_export = (object.account_export !== undefined && object.account_export !== null)
+      ? AccountExport.fromPartial(object.account_export)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseScheduleAccountDeletionRequest(): ScheduleAccountDeletionRequest {
+  return { user_id: "", story_disposition: 0, blog_id: undefined };
+}
+
+export const ScheduleAccountDeletionRequest = {
+  encode(message: ScheduleAccountDeletionRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.story_disposition !== 0) {
+      writer.uint32(16).int32(message.story_disposition);
+    }
+    if (message.blog_id !== undefined) {
+      writer.uint32(26).string(message.blog_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ScheduleAccountDeletionRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseScheduleAccountDeletionRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.story_disposition = reader.int32() as any;
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.blog_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ScheduleAccountDeletionRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      story_disposition: isSet(object.story_disposition) ? storyDispositionFromJSON(object.story_disposition) : 0,
+      blog_id: isSet(object.blog_id) ? globalThis.String(object.blog_id) : undefined,
+    };
+  },
+
+  toJSON(message: ScheduleAccountDeletionRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.story_disposition !== 0) {
+      obj.story_disposition = storyDispositionToJSON(message.story_disposition);
+    }
+    if (message.blog_id !== undefined) {
+      obj.blog_id = message.blog_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ScheduleAccountDeletionRequest>, I>>(base?: I): ScheduleAccountDeletionRequest {
+    return ScheduleAccountDeletionRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ScheduleAccountDeletionRequest>, I>>(
+    object: I,
+  ): ScheduleAccountDeletionRequest {
+    const message = createBaseScheduleAccountDeletionRequest();
+    message.user_id = object.user_id ?? "";
+    message.story_disposition = object.story_disposition ?? 0;
+    message.blog_id = object.blog_id ?? undefined;
+    return message;
+  },
+};
+
+function createBaseScheduleAccountDeletionResponse(): ScheduleAccountDeletionResponse {
+  return { scheduled_for: undefined };
+}
+
+export const ScheduleAccountDeletionResponse = {
+  encode(message: ScheduleAccountDeletionResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.scheduled_for !== undefined) {
+      Timestamp.encode(toTimestamp(message.scheduled_for), writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ScheduleAccountDeletionResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseScheduleAccountDeletionResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.scheduled_for = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ScheduleAccountDeletionResponse {
+    return { scheduled_for: isSet(object.scheduled_for) ? fromJsonTimestamp(object.scheduled_for) : undefined };
+  },
+
+  toJSON(message: ScheduleAccountDeletionResponse): unknown {
+    const obj: any = {};
+    if (message.scheduled_for !== undefined) {
+      obj.scheduled_for = message.scheduled_for.toISOString();
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ScheduleAccountDeletionResponse>, I>>(base?: I): ScheduleAccountDeletionResponse {
+    return ScheduleAccountDeletionResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ScheduleAccountDeletionResponse>, I>>(
+    object: I,
+  ): ScheduleAccountDeletionResponse {
+    const message = createBaseScheduleAccountDeletionResponse();
+    message.scheduled_for = object.scheduled_for ?? undefined;
+    return message;
+  },
+};
+
+function createBaseCancelAccountDeletionRequest(): CancelAccountDeletionRequest {
+  return { user_id: "" };
+}
+
+export const CancelAccountDeletionRequest = {
+  encode(message: CancelAccountDeletionRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): CancelAccountDeletionRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCancelAccountDeletionRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): CancelAccountDeletionRequest {
+    return { user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "" };
+  },
+
+  toJSON(message: CancelAccountDeletionRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<CancelAccountDeletionRequest>, I>>(base?: I): CancelAccountDeletionRequest {
+    return CancelAccountDeletionRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<CancelAccountDeletionRequest>, I>>(object: I): CancelAccountDeletionRequest {
+    const message = createBaseCancelAccountDeletionRequest();
+    message.user_id = object.user_id ?? "";
+    return message;
+  },
+};
+
+function createBaseCancelAccountDeletionResponse(): CancelAccountDeletionResponse {
+  return {};
+}
+
+export const CancelAccountDeletionResponse = {
+  encode(_: CancelAccountDeletionResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): CancelAccountDeletionResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCancelAccountDeletionResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(_: any): CancelAccountDeletionResponse {
+    return {};
+  },
+
+  toJSON(_: CancelAccountDeletionResponse): unknown {
+    const obj: any = {};
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<CancelAccountDeletionResponse>, I>>(base?: I): CancelAccountDeletionResponse {
+    return CancelAccountDeletionResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<CancelAccountDeletionResponse>, I>>(_: I): CancelAccountDeletionResponse {
+    const message = createBaseCancelAccountDeletionResponse();
+    return message;
+  },
+};
+
+type Builtin = Date | Function | Uint8Array | string | number | boolean | undefined;
+
+export type DeepPartial<T> = T extends Builtin ? T
+  : T extends globalThis.Array<infer U> ? globalThis.Array<DeepPartial<U>>
+  : T extends ReadonlyArray<infer U> ? ReadonlyArray<DeepPartial<U>>
+  : T extends {} ? { [K in keyof T]?: DeepPartial<T[K]> }
+  : Partial<T>;
+
+type KeysOfUnion<T> = T extends T ? keyof T : never;
+export type Exact<P, I extends P> = P extends Builtin ? P
+  : P & { [K in keyof P]: Exact<P[K], I[K]> } & { [K in Exclude<keyof I, KeysOfUnion<P>>]: never };
+
+function toTimestamp(date: Date): Timestamp {
+  const seconds = Math.trunc(date.getTime() / 1_000);
+  const nanos = (date.getTime() % 1_000) * 1_000_000;
+  return { seconds, nanos };
+}
+
+function fromTimestamp(t: Timestamp): Date {
+  let millis = (t.seconds || 0) * 1_000;
+  millis += (t.nanos || 0) / 1_000_000;
+  return new globalThis.Date(millis);
+}
+
+function fromJsonTimestamp(o: any): Date {
+  if (o instanceof globalThis.Date) {
+    return o;
+  } else if (typeof o === "string") {
+    return new globalThis.Date(o);
+  } else {
+    return fromTimestamp(Timestamp.fromJSON(o));
+  }
+}
+
+function longToNumber(long: Long): number {
+  if (long.gt(globalThis.Number.MAX_SAFE_INTEGER)) {
+    throw new globalThis.Error("Value is larger than Number.MAX_SAFE_INTEGER");
+  }
+  return long.toNumber();
+}
+
+if (_m0.util.Long !== Long) {
+  _m0.util.Long = Long as any;
+  _m0.configure();
+}
+
+function isSet(value: any): boolean {
+  return value !== null && value !== undefined;
+}
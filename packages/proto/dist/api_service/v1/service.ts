@@ -3,13 +3,35 @@ import { ChannelCredentials, Client, makeGenericClientConstructor, Metadata } fr
 import type {
   CallOptions,
   ClientOptions,
+  ClientReadableStream,
   ClientUnaryCall,
+  ClientWritableStream,
+  handleClientStreamingCall,
+  handleServerStreamingCall,
   handleUnaryCall,
   ServiceError,
   UntypedServiceImplementation,
 } from "@grpc/grpc-js";
 import _m0 from "protobufjs/minimal";
 import {
+  CancelAccountDeletionRequest,
+  CancelAccountDeletionResponse,
+  GetAccountExportStatusRequest,
+  GetAccountExportStatusResponse,
+  RequestAccountExportRequest,
+  RequestAccountExportResponse,
+  ScheduleAccountDeletionRequest,
+  ScheduleAccountDeletionResponse,
+} from "../../account_def/v1/def";
+import {
+  SetAssetRatingRequest,
+  SetAssetRatingResponse,
+  UploadImageRequest,
+  UploadImageResponse,
+} from "../../asset_def/v1/def";
+import {
+  ExportBlogRequest,
+  ExportBlogResponse,
   GetBlogArchiveRequest,
   GetBlogArchiveResponse,
   GetBlogEditorsInfoRequest,
@@ -34,12 +56,64 @@ import {
   VerifyBlogLoginResponse,
 } from "../../blog_def/v1/def";
 import { GetCommentRequest, GetCommentResponse } from "../../comment_def/v1/def";
-import { GetConnectionSettingsRequest, GetConnectionSettingsResponse } from "../../connection_settings_def/v1/def";
-import { GetCredentialSettingsRequest, GetCredentialSettingsResponse } from "../../credential_settings_def/v1/def";
+import {
+  AddConnectionRequest,
+  AddConnectionResponse,
+  GetConnectionSettingsRequest,
+  GetConnectionSettingsResponse,
+  RefreshConnectionRequest,
+  RefreshConnectionResponse,
+  RemoveConnectionRequest,
+  RemoveConnectionResponse,
+  SetConnectionHiddenRequest,
+  SetConnectionHiddenResponse,
+} from "../../connection_settings_def/v1/def";
+import {
+  BeginMfaChallengeRequest,
+  BeginMfaChallengeResponse,
+  BeginMfaEnrollmentRequest,
+  BeginMfaEnrollmentResponse,
+  BeginPasskeyAuthenticationRequest,
+  BeginPasskeyAuthenticationResponse,
+  BeginPasskeyRegistrationRequest,
+  BeginPasskeyRegistrationResponse,
+  ConfirmMfaEnrollmentRequest,
+  ConfirmMfaEnrollmentResponse,
+  DisableMfaRequest,
+  DisableMfaResponse,
+  FinishPasskeyAuthenticationRequest,
+  FinishPasskeyAuthenticationResponse,
+  FinishPasskeyRegistrationRequest,
+  FinishPasskeyRegistrationResponse,
+  GetCredentialSettingsRequest,
+  GetCredentialSettingsResponse,
+  LinkLoginProviderRequest,
+  LinkLoginProviderResponse,
+  RegenerateMfaRecoveryCodesRequest,
+  RegenerateMfaRecoveryCodesResponse,
+  RemovePasskeyRequest,
+  RemovePasskeyResponse,
+  UnlinkLoginProviderRequest,
+  UnlinkLoginProviderResponse,
+  VerifyMfaCodeRequest,
+  VerifyMfaCodeResponse,
+} from "../../credential_settings_def/v1/def";
 import { GetLoginActivityRequest, GetLoginActivityResponse } from "../../login_activity_def/v1/def";
+import {
+  GetUnreadNotificationCountRequest,
+  GetUnreadNotificationCountResponse,
+  ListNotificationsRequest,
+  ListNotificationsResponse,
+  MarkNotificationsReadRequest,
+  MarkNotificationsReadResponse,
+  WatchNotificationsRequest,
+  WatchNotificationsResponse,
+} from "../../notification_def/v1/def";
 import {
   GetNotificationSettingsRequest,
   GetNotificationSettingsResponse,
+  UpdateNotificationSettingsRequest,
+  UpdateNotificationSettingsResponse,
 } from "../../notification_settings_def/v1/def";
 import {
   GetStoryOpenGraphDataRequest,
@@ -47,8 +121,26 @@ import {
   GetTagOpenGraphDataRequest,
   GetTagOpenGraphDataResponse,
 } from "../../open_graph_def/v1/def";
-import { GetPrivacySettingsRequest, GetPrivacySettingsResponse } from "../../privacy_settings_def/v1/def";
-import { GetProfileRequest, GetProfileResponse } from "../../profile_def/v1/def";
+import {
+  GetPrivacySettingsRequest,
+  GetPrivacySettingsResponse,
+  UpdatePrivacySettingsRequest,
+  UpdatePrivacySettingsResponse,
+} from "../../privacy_settings_def/v1/def";
+import {
+  GetProfileRequest,
+  GetProfileResponse,
+  UpdateProfileRequest,
+  UpdateProfileResponse,
+} from "../../profile_def/v1/def";
+import {
+  ListReportsRequest,
+  ListReportsResponse,
+  ReportContentRequest,
+  ReportContentResponse,
+  ResolveReportRequest,
+  ResolveReportResponse,
+} from "../../report_def/v1/def";
 import {
   GetResponsesInfoRequest,
   GetResponsesInfoResponse,
@@ -58,6 +150,8 @@ import {
 import {
   CreateDraftRequest,
   CreateDraftResponse,
+  ExportStoryRequest,
+  ExportStoryResponse,
   GetContributionsInfoRequest,
   GetContributionsInfoResponse,
   GetDraftsInfoRequest,
@@ -68,6 +162,10 @@ import {
   GetStoryMetadataResponse,
   GetStoryRequest,
   GetStoryResponse,
+  ImportStoriesRequest,
+  ImportStoriesResponse,
+  ImportStoryRequest,
+  ImportStoryResponse,
   ValidateStoryRequest,
   ValidateStoryResponse,
 } from "../../story_def/v1/def";
@@ -86,6 +184,8 @@ import {
   VerifyNewsletterSubscriptionResponse,
 } from "../../token_def/v1/def";
 import {
+  ClearStatusRequest,
+  ClearStatusResponse,
   GetUserBlockCountRequest,
   GetUserBlockCountResponse,
   GetUserIdRequest,
@@ -96,6 +196,16 @@ import {
   GetUsernameResponse,
   GetUserRelationsInfoRequest,
   GetUserRelationsInfoResponse,
+  GrantUserFlagRequest,
+  GrantUserFlagResponse,
+  RevokeUserFlagRequest,
+  RevokeUserFlagResponse,
+  SetStatusRequest,
+  SetStatusResponse,
+  SuspendUserRequest,
+  SuspendUserResponse,
+  UnsuspendUserRequest,
+  UnsuspendUserResponse,
 } from "../../user_def/v1/def";
 
 export const protobufPackage = "api_service.v1";
@@ -147,7 +257,25 @@ export const Placeholder = {
   },
 };
 
-/** Service definition */
+/**
+ * Service definition
+ *
+ * Every call must carry a signed service credential in the `authorization`
+ * metadata, and the session token of the user (if any) in the
+ * `x-session-token` metadata. The caller identity is derived from these, and the
+ * `user_id`, `current_user_id`, `moderator_id`, and `admin_id` fields of the
+ * requests can be omitted. When set, they must match the authenticated user.
+ *
+ * The connections use mutual TLS, and the server only allows each method to be
+ * invoked by the client services listed in its policy.
+ *
+ * Some methods are rate limited per IP address (from the `x-client-ip`
+ * metadata), user, or token. The rejected calls fail with `RESOURCE_EXHAUSTED`
+ * and carry a `google.rpc.RetryInfo` in the details.
+ *
+ * Failed RPCs carry an `error_def.v1.ErrorDetail` (and a `google.rpc.BadRequest`
+ * for invalid requests) in the `google.rpc.Status` details
+ */
 export type ApiServiceService = typeof ApiServiceService;
 export const ApiServiceService = {
   /** Checks whether the user is authenticated using the token from the session cookie */
@@ -579,6 +707,477 @@ export const ApiServiceService = {
       Buffer.from(GetTagOpenGraphDataResponse.encode(value).finish()),
     responseDeserialize: (value: Buffer) => GetTagOpenGraphDataResponse.decode(value),
   },
+  /** Generates a pending TOTP secret for the user */
+  beginMfaEnrollment: {
+    path: "/api_service.v1.ApiService/BeginMfaEnrollment",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: BeginMfaEnrollmentRequest) =>
+      Buffer.from(BeginMfaEnrollmentRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => BeginMfaEnrollmentRequest.decode(value),
+    responseSerialize: (value: BeginMfaEnrollmentResponse) =>
+      Buffer.from(BeginMfaEnrollmentResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => BeginMfaEnrollmentResponse.decode(value),
+  },
+  /** Enables MFA for the user after verifying a code generated using the pending secret */
+  confirmMfaEnrollment: {
+    path: "/api_service.v1.ApiService/ConfirmMfaEnrollment",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: ConfirmMfaEnrollmentRequest) =>
+      Buffer.from(ConfirmMfaEnrollmentRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => ConfirmMfaEnrollmentRequest.decode(value),
+    responseSerialize: (value: ConfirmMfaEnrollmentResponse) =>
+      Buffer.from(ConfirmMfaEnrollmentResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => ConfirmMfaEnrollmentResponse.decode(value),
+  },
+  /** Issues an MFA challenge for a login whose first factor (password or OAuth) has been verified */
+  beginMfaChallenge: {
+    path: "/api_service.v1.ApiService/BeginMfaChallenge",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: BeginMfaChallengeRequest) => Buffer.from(BeginMfaChallengeRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => BeginMfaChallengeRequest.decode(value),
+    responseSerialize: (value: BeginMfaChallengeResponse) =>
+      Buffer.from(BeginMfaChallengeResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => BeginMfaChallengeResponse.decode(value),
+  },
+  /** Verifies a TOTP or recovery code against an MFA challenge during login */
+  verifyMfaCode: {
+    path: "/api_service.v1.ApiService/VerifyMfaCode",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: VerifyMfaCodeRequest) => Buffer.from(VerifyMfaCodeRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => VerifyMfaCodeRequest.decode(value),
+    responseSerialize: (value: VerifyMfaCodeResponse) => Buffer.from(VerifyMfaCodeResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => VerifyMfaCodeResponse.decode(value),
+  },
+  /** Disables MFA for the user */
+  disableMfa: {
+    path: "/api_service.v1.ApiService/DisableMfa",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: DisableMfaRequest) => Buffer.from(DisableMfaRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => DisableMfaRequest.decode(value),
+    responseSerialize: (value: DisableMfaResponse) => Buffer.from(DisableMfaResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => DisableMfaResponse.decode(value),
+  },
+  /** Replaces the user's recovery codes */
+  regenerateMfaRecoveryCodes: {
+    path: "/api_service.v1.ApiService/RegenerateMfaRecoveryCodes",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: RegenerateMfaRecoveryCodesRequest) =>
+      Buffer.from(RegenerateMfaRecoveryCodesRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => RegenerateMfaRecoveryCodesRequest.decode(value),
+    responseSerialize: (value: RegenerateMfaRecoveryCodesResponse) =>
+      Buffer.from(RegenerateMfaRecoveryCodesResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => RegenerateMfaRecoveryCodesResponse.decode(value),
+  },
+  /** Returns the options for registering a new passkey */
+  beginPasskeyRegistration: {
+    path: "/api_service.v1.ApiService/BeginPasskeyRegistration",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: BeginPasskeyRegistrationRequest) =>
+      Buffer.from(BeginPasskeyRegistrationRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => BeginPasskeyRegistrationRequest.decode(value),
+    responseSerialize: (value: BeginPasskeyRegistrationResponse) =>
+      Buffer.from(BeginPasskeyRegistrationResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => BeginPasskeyRegistrationResponse.decode(value),
+  },
+  /** Verifies the attestation from the authenticator and registers the passkey */
+  finishPasskeyRegistration: {
+    path: "/api_service.v1.ApiService/FinishPasskeyRegistration",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: FinishPasskeyRegistrationRequest) =>
+      Buffer.from(FinishPasskeyRegistrationRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => FinishPasskeyRegistrationRequest.decode(value),
+    responseSerialize: (value: FinishPasskeyRegistrationResponse) =>
+      Buffer.from(FinishPasskeyRegistrationResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => FinishPasskeyRegistrationResponse.decode(value),
+  },
+  /** Returns the options for authenticating using a passkey */
+  beginPasskeyAuthentication: {
+    path: "/api_service.v1.ApiService/BeginPasskeyAuthentication",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: BeginPasskeyAuthenticationRequest) =>
+      Buffer.from(BeginPasskeyAuthenticationRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => BeginPasskeyAuthenticationRequest.decode(value),
+    responseSerialize: (value: BeginPasskeyAuthenticationResponse) =>
+      Buffer.from(BeginPasskeyAuthenticationResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => BeginPasskeyAuthenticationResponse.decode(value),
+  },
+  /** Verifies the assertion from the authenticator and returns the user ID */
+  finishPasskeyAuthentication: {
+    path: "/api_service.v1.ApiService/FinishPasskeyAuthentication",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: FinishPasskeyAuthenticationRequest) =>
+      Buffer.from(FinishPasskeyAuthenticationRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => FinishPasskeyAuthenticationRequest.decode(value),
+    responseSerialize: (value: FinishPasskeyAuthenticationResponse) =>
+      Buffer.from(FinishPasskeyAuthenticationResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => FinishPasskeyAuthenticationResponse.decode(value),
+  },
+  /** Removes a passkey registered by the user */
+  removePasskey: {
+    path: "/api_service.v1.ApiService/RemovePasskey",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: RemovePasskeyRequest) => Buffer.from(RemovePasskeyRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => RemovePasskeyRequest.decode(value),
+    responseSerialize: (value: RemovePasskeyResponse) => Buffer.from(RemovePasskeyResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => RemovePasskeyResponse.decode(value),
+  },
+  /** Links an external login provider to the user's account */
+  linkLoginProvider: {
+    path: "/api_service.v1.ApiService/LinkLoginProvider",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: LinkLoginProviderRequest) => Buffer.from(LinkLoginProviderRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => LinkLoginProviderRequest.decode(value),
+    responseSerialize: (value: LinkLoginProviderResponse) =>
+      Buffer.from(LinkLoginProviderResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => LinkLoginProviderResponse.decode(value),
+  },
+  /** Unlinks an external login provider, unless it is the last remaining login method */
+  unlinkLoginProvider: {
+    path: "/api_service.v1.ApiService/UnlinkLoginProvider",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: UnlinkLoginProviderRequest) =>
+      Buffer.from(UnlinkLoginProviderRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => UnlinkLoginProviderRequest.decode(value),
+    responseSerialize: (value: UnlinkLoginProviderResponse) =>
+      Buffer.from(UnlinkLoginProviderResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => UnlinkLoginProviderResponse.decode(value),
+  },
+  /** Adds a connection using the authorization code from the provider's OAuth callback */
+  addConnection: {
+    path: "/api_service.v1.ApiService/AddConnection",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: AddConnectionRequest) => Buffer.from(AddConnectionRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => AddConnectionRequest.decode(value),
+    responseSerialize: (value: AddConnectionResponse) => Buffer.from(AddConnectionResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => AddConnectionResponse.decode(value),
+  },
+  /** Hides or shows a connection on the user's public profile */
+  setConnectionHidden: {
+    path: "/api_service.v1.ApiService/SetConnectionHidden",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: SetConnectionHiddenRequest) =>
+      Buffer.from(SetConnectionHiddenRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => SetConnectionHiddenRequest.decode(value),
+    responseSerialize: (value: SetConnectionHiddenResponse) =>
+      Buffer.from(SetConnectionHiddenResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => SetConnectionHiddenResponse.decode(value),
+  },
+  /** Removes a connection along with the data received from the provider */
+  removeConnection: {
+    path: "/api_service.v1.ApiService/RemoveConnection",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: RemoveConnectionRequest) => Buffer.from(RemoveConnectionRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => RemoveConnectionRequest.decode(value),
+    responseSerialize: (value: RemoveConnectionResponse) =>
+      Buffer.from(RemoveConnectionResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => RemoveConnectionResponse.decode(value),
+  },
+  /** Refreshes the display name and URL of a connection from the provider */
+  refreshConnection: {
+    path: "/api_service.v1.ApiService/RefreshConnection",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: RefreshConnectionRequest) => Buffer.from(RefreshConnectionRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => RefreshConnectionRequest.decode(value),
+    responseSerialize: (value: RefreshConnectionResponse) =>
+      Buffer.from(RefreshConnectionResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => RefreshConnectionResponse.decode(value),
+  },
+  /** Updates the user's privacy settings and applies the resulting cascades */
+  updatePrivacySettings: {
+    path: "/api_service.v1.ApiService/UpdatePrivacySettings",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: UpdatePrivacySettingsRequest) =>
+      Buffer.from(UpdatePrivacySettingsRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => UpdatePrivacySettingsRequest.decode(value),
+    responseSerialize: (value: UpdatePrivacySettingsResponse) =>
+      Buffer.from(UpdatePrivacySettingsResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => UpdatePrivacySettingsResponse.decode(value),
+  },
+  /** Updates the user's notification settings */
+  updateNotificationSettings: {
+    path: "/api_service.v1.ApiService/UpdateNotificationSettings",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: UpdateNotificationSettingsRequest) =>
+      Buffer.from(UpdateNotificationSettingsRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => UpdateNotificationSettingsRequest.decode(value),
+    responseSerialize: (value: UpdateNotificationSettingsResponse) =>
+      Buffer.from(UpdateNotificationSettingsResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => UpdateNotificationSettingsResponse.decode(value),
+  },
+  /** Returns the notifications for a user, newest first */
+  listNotifications: {
+    path: "/api_service.v1.ApiService/ListNotifications",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: ListNotificationsRequest) => Buffer.from(ListNotificationsRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => ListNotificationsRequest.decode(value),
+    responseSerialize: (value: ListNotificationsResponse) =>
+      Buffer.from(ListNotificationsResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => ListNotificationsResponse.decode(value),
+  },
+  /** Marks notifications as read for a user */
+  markNotificationsRead: {
+    path: "/api_service.v1.ApiService/MarkNotificationsRead",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: MarkNotificationsReadRequest) =>
+      Buffer.from(MarkNotificationsReadRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => MarkNotificationsReadRequest.decode(value),
+    responseSerialize: (value: MarkNotificationsReadResponse) =>
+      Buffer.from(MarkNotificationsReadResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => MarkNotificationsReadResponse.decode(value),
+  },
+  /** Returns the number of unread notifications for a user */
+  getUnreadNotificationCount: {
+    path: "/api_service.v1.ApiService/GetUnreadNotificationCount",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: GetUnreadNotificationCountRequest) =>
+      Buffer.from(GetUnreadNotificationCountRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => GetUnreadNotificationCountRequest.decode(value),
+    responseSerialize: (value: GetUnreadNotificationCountResponse) =>
+      Buffer.from(GetUnreadNotificationCountResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => GetUnreadNotificationCountResponse.decode(value),
+  },
+  /** Streams new notifications for a user as they are created */
+  watchNotifications: {
+    path: "/api_service.v1.ApiService/WatchNotifications",
+    requestStream: false,
+    responseStream: true,
+    requestSerialize: (value: WatchNotificationsRequest) =>
+      Buffer.from(WatchNotificationsRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => WatchNotificationsRequest.decode(value),
+    responseSerialize: (value: WatchNotificationsResponse) =>
+      Buffer.from(WatchNotificationsResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => WatchNotificationsResponse.decode(value),
+  },
+  /** Sets the status of a user, replacing the previous status */
+  setStatus: {
+    path: "/api_service.v1.ApiService/SetStatus",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: SetStatusRequest) => Buffer.from(SetStatusRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => SetStatusRequest.decode(value),
+    responseSerialize: (value: SetStatusResponse) => Buffer.from(SetStatusResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => SetStatusResponse.decode(value),
+  },
+  /** Clears the status of a user */
+  clearStatus: {
+    path: "/api_service.v1.ApiService/ClearStatus",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: ClearStatusRequest) => Buffer.from(ClearStatusRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => ClearStatusRequest.decode(value),
+    responseSerialize: (value: ClearStatusResponse) => Buffer.from(ClearStatusResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => ClearStatusResponse.decode(value),
+  },
+  /** Updates the profile of a user */
+  updateProfile: {
+    path: "/api_service.v1.ApiService/UpdateProfile",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: UpdateProfileRequest) => Buffer.from(UpdateProfileRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => UpdateProfileRequest.decode(value),
+    responseSerialize: (value: UpdateProfileResponse) => Buffer.from(UpdateProfileResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => UpdateProfileResponse.decode(value),
+  },
+  /** Uploads an image in chunks and returns its ID along with the derived colors */
+  uploadImage: {
+    path: "/api_service.v1.ApiService/UploadImage",
+    requestStream: true,
+    responseStream: false,
+    requestSerialize: (value: UploadImageRequest) => Buffer.from(UploadImageRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => UploadImageRequest.decode(value),
+    responseSerialize: (value: UploadImageResponse) => Buffer.from(UploadImageResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => UploadImageResponse.decode(value),
+  },
+  /** Sets the rating of an image, used by the moderators to flag sensitive media */
+  setAssetRating: {
+    path: "/api_service.v1.ApiService/SetAssetRating",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: SetAssetRatingRequest) => Buffer.from(SetAssetRatingRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => SetAssetRatingRequest.decode(value),
+    responseSerialize: (value: SetAssetRatingResponse) => Buffer.from(SetAssetRatingResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => SetAssetRatingResponse.decode(value),
+  },
+  /** Reports a story, comment, user, blog, or tag */
+  reportContent: {
+    path: "/api_service.v1.ApiService/ReportContent",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: ReportContentRequest) => Buffer.from(ReportContentRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => ReportContentRequest.decode(value),
+    responseSerialize: (value: ReportContentResponse) => Buffer.from(ReportContentResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => ReportContentResponse.decode(value),
+  },
+  /** Returns the moderation queue, used by the moderators */
+  listReports: {
+    path: "/api_service.v1.ApiService/ListReports",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: ListReportsRequest) => Buffer.from(ListReportsRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => ListReportsRequest.decode(value),
+    responseSerialize: (value: ListReportsResponse) => Buffer.from(ListReportsResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => ListReportsResponse.decode(value),
+  },
+  /** Resolves a report and records the action in the moderation audit log */
+  resolveReport: {
+    path: "/api_service.v1.ApiService/ResolveReport",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: ResolveReportRequest) => Buffer.from(ResolveReportRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => ResolveReportRequest.decode(value),
+    responseSerialize: (value: ResolveReportResponse) => Buffer.from(ResolveReportResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => ResolveReportResponse.decode(value),
+  },
+  /** Grants a flag to a user, used by the administrators */
+  grantUserFlag: {
+    path: "/api_service.v1.ApiService/GrantUserFlag",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: GrantUserFlagRequest) => Buffer.from(GrantUserFlagRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => GrantUserFlagRequest.decode(value),
+    responseSerialize: (value: GrantUserFlagResponse) => Buffer.from(GrantUserFlagResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => GrantUserFlagResponse.decode(value),
+  },
+  /** Revokes a flag from a user, used by the administrators */
+  revokeUserFlag: {
+    path: "/api_service.v1.ApiService/RevokeUserFlag",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: RevokeUserFlagRequest) => Buffer.from(RevokeUserFlagRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => RevokeUserFlagRequest.decode(value),
+    responseSerialize: (value: RevokeUserFlagResponse) => Buffer.from(RevokeUserFlagResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => RevokeUserFlagResponse.decode(value),
+  },
+  /** Suspends a user, hiding their stories, comments, and profile */
+  suspendUser: {
+    path: "/api_service.v1.ApiService/SuspendUser",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: SuspendUserRequest) => Buffer.from(SuspendUserRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => SuspendUserRequest.decode(value),
+    responseSerialize: (value: SuspendUserResponse) => Buffer.from(SuspendUserResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => SuspendUserResponse.decode(value),
+  },
+  /** Lifts the suspension of a user */
+  unsuspendUser: {
+    path: "/api_service.v1.ApiService/UnsuspendUser",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: UnsuspendUserRequest) => Buffer.from(UnsuspendUserRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => UnsuspendUserRequest.decode(value),
+    responseSerialize: (value: UnsuspendUserResponse) => Buffer.from(UnsuspendUserResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => UnsuspendUserResponse.decode(value),
+  },
+  /** Queues an archive of the user's data for export */
+  requestAccountExport: {
+    path: "/api_service.v1.ApiService/RequestAccountExport",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: RequestAccountExportRequest) =>
+      Buffer.from(RequestAccountExportRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => RequestAccountExportRequest.decode(value),
+    responseSerialize: (value: RequestAccountExportResponse) =>
+      Buffer.from(RequestAccountExportResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => RequestAccountExportResponse.decode(value),
+  },
+  /** Returns the status of an account export along with its download token */
+  getAccountExportStatus: {
+    path: "/api_service.v1.ApiService/GetAccountExportStatus",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: GetAccountExportStatusRequest) =>
+      Buffer.from(GetAccountExportStatusRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => GetAccountExportStatusRequest.decode(value),
+    responseSerialize: (value: GetAccountExportStatusResponse) =>
+      Buffer.from(GetAccountExportStatusResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => GetAccountExportStatusResponse.decode(value),
+  },
+  /** Schedules the deletion of an account at the end of the grace period */
+  scheduleAccountDeletion: {
+    path: "/api_service.v1.ApiService/ScheduleAccountDeletion",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: ScheduleAccountDeletionRequest) =>
+      Buffer.from(ScheduleAccountDeletionRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => ScheduleAccountDeletionRequest.decode(value),
+    responseSerialize: (value: ScheduleAccountDeletionResponse) =>
+      Buffer.from(ScheduleAccountDeletionResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => ScheduleAccountDeletionResponse.decode(value),
+  },
+  /** Cancels a scheduled account deletion during the grace period */
+  cancelAccountDeletion: {
+    path: "/api_service.v1.ApiService/CancelAccountDeletion",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: CancelAccountDeletionRequest) =>
+      Buffer.from(CancelAccountDeletionRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => CancelAccountDeletionRequest.decode(value),
+    responseSerialize: (value: CancelAccountDeletionResponse) =>
+      Buffer.from(CancelAccountDeletionResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => CancelAccountDeletionResponse.decode(value),
+  },
+  /** Imports a Markdown or HTML document as a new draft */
+  importStory: {
+    path: "/api_service.v1.ApiService/ImportStory",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: ImportStoryRequest) => Buffer.from(ImportStoryRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => ImportStoryRequest.decode(value),
+    responseSerialize: (value: ImportStoryResponse) => Buffer.from(ImportStoryResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => ImportStoryResponse.decode(value),
+  },
+  /** Imports every story from an export archive as drafts */
+  importStories: {
+    path: "/api_service.v1.ApiService/ImportStories",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: ImportStoriesRequest) => Buffer.from(ImportStoriesRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => ImportStoriesRequest.decode(value),
+    responseSerialize: (value: ImportStoriesResponse) => Buffer.from(ImportStoriesResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => ImportStoriesResponse.decode(value),
+  },
+  /** Exports a story as Markdown, HTML, EPUB, or PDF */
+  exportStory: {
+    path: "/api_service.v1.ApiService/ExportStory",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: ExportStoryRequest) => Buffer.from(ExportStoryRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => ExportStoryRequest.decode(value),
+    responseSerialize: (value: ExportStoryResponse) => Buffer.from(ExportStoryResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => ExportStoryResponse.decode(value),
+  },
+  /** Exports the published stories of a blog as an EPUB anthology */
+  exportBlog: {
+    path: "/api_service.v1.ApiService/ExportBlog",
+    requestStream: false,
+    responseStream: false,
+    requestSerialize: (value: ExportBlogRequest) => Buffer.from(ExportBlogRequest.encode(value).finish()),
+    requestDeserialize: (value: Buffer) => ExportBlogRequest.decode(value),
+    responseSerialize: (value: ExportBlogResponse) => Buffer.from(ExportBlogResponse.encode(value).finish()),
+    responseDeserialize: (value: Buffer) => ExportBlogResponse.decode(value),
+  },
 } as const;
 
 export interface ApiServiceServer extends UntypedServiceImplementation {
@@ -663,6 +1262,92 @@ export interface ApiServiceServer extends UntypedServiceImplementation {
   getStoryOpenGraphData: handleUnaryCall<GetStoryOpenGraphDataRequest, GetStoryOpenGraphDataResponse>;
   /** Returns the tag's open graph data */
   getTagOpenGraphData: handleUnaryCall<GetTagOpenGraphDataRequest, GetTagOpenGraphDataResponse>;
+  /** Generates a pending TOTP secret for the user */
+  beginMfaEnrollment: handleUnaryCall<BeginMfaEnrollmentRequest, BeginMfaEnrollmentResponse>;
+  /** Enables MFA for the user after verifying a code generated using the pending secret */
+  confirmMfaEnrollment: handleUnaryCall<ConfirmMfaEnrollmentRequest, ConfirmMfaEnrollmentResponse>;
+  /** Issues an MFA challenge for a login whose first factor (password or OAuth) has been verified */
+  beginMfaChallenge: handleUnaryCall<BeginMfaChallengeRequest, BeginMfaChallengeResponse>;
+  /** Verifies a TOTP or recovery code against an MFA challenge during login */
+  verifyMfaCode: handleUnaryCall<VerifyMfaCodeRequest, VerifyMfaCodeResponse>;
+  /** Disables MFA for the user */
+  disableMfa: handleUnaryCall<DisableMfaRequest, DisableMfaResponse>;
+  /** Replaces the user's recovery codes */
+  regenerateMfaRecoveryCodes: handleUnaryCall<RegenerateMfaRecoveryCodesRequest, RegenerateMfaRecoveryCodesResponse>;
+  /** Returns the options for registering a new passkey */
+  beginPasskeyRegistration: handleUnaryCall<BeginPasskeyRegistrationRequest, BeginPasskeyRegistrationResponse>;
+  /** Verifies the attestation from the authenticator and registers the passkey */
+  finishPasskeyRegistration: handleUnaryCall<FinishPasskeyRegistrationRequest, FinishPasskeyRegistrationResponse>;
+  /** Returns the options for authenticating using a passkey */
+  beginPasskeyAuthentication: handleUnaryCall<BeginPasskeyAuthenticationRequest, BeginPasskeyAuthenticationResponse>;
+  /** Verifies the assertion from the authenticator and returns the user ID */
+  finishPasskeyAuthentication: handleUnaryCall<FinishPasskeyAuthenticationRequest, FinishPasskeyAuthenticationResponse>;
+  /** Removes a passkey registered by the user */
+  removePasskey: handleUnaryCall<RemovePasskeyRequest, RemovePasskeyResponse>;
+  /** Links an external login provider to the user's account */
+  linkLoginProvider: handleUnaryCall<LinkLoginProviderRequest, LinkLoginProviderResponse>;
+  /** Unlinks an external login provider, unless it is the last remaining login method */
+  unlinkLoginProvider: handleUnaryCall<UnlinkLoginProviderRequest, UnlinkLoginProviderResponse>;
+  /** Adds a connection using the authorization code from the provider's OAuth callback */
+  addConnection: handleUnaryCall<AddConnectionRequest, AddConnectionResponse>;
+  /** Hides or shows a connection on the user's public profile */
+  setConnectionHidden: handleUnaryCall<SetConnectionHiddenRequest, SetConnectionHiddenResponse>;
+  /** Removes a connection along with the data received from the provider */
+  removeConnection: handleUnaryCall<RemoveConnectionRequest, RemoveConnectionResponse>;
+  /** Refreshes the display name and URL of a connection from the provider */
+  refreshConnection: handleUnaryCall<RefreshConnectionRequest, RefreshConnectionResponse>;
+  /** Updates the user's privacy settings and applies the resulting cascades */
+  updatePrivacySettings: handleUnaryCall<UpdatePrivacySettingsRequest, UpdatePrivacySettingsResponse>;
+  /** Updates the user's notification settings */
+  updateNotificationSettings: handleUnaryCall<UpdateNotificationSettingsRequest, UpdateNotificationSettingsResponse>;
+  /** Returns the notifications for a user, newest first */
+  listNotifications: handleUnaryCall<ListNotificationsRequest, ListNotificationsResponse>;
+  /** Marks notifications as read for a user */
+  markNotificationsRead: handleUnaryCall<MarkNotificationsReadRequest, MarkNotificationsReadResponse>;
+  /** Returns the number of unread notifications for a user */
+  getUnreadNotificationCount: handleUnaryCall<GetUnreadNotificationCountRequest, GetUnreadNotificationCountResponse>;
+  /** Streams new notifications for a user as they are created */
+  watchNotifications: handleServerStreamingCall<WatchNotificationsRequest, WatchNotificationsResponse>;
+  /** Sets the status of a user, replacing the previous status */
+  setStatus: handleUnaryCall<SetStatusRequest, SetStatusResponse>;
+  /** Clears the status of a user */
+  clearStatus: handleUnaryCall<ClearStatusRequest, ClearStatusResponse>;
+  /** Updates the profile of a user */
+  updateProfile: handleUnaryCall<UpdateProfileRequest, UpdateProfileResponse>;
+  /** Uploads an image in chunks and returns its ID along with the derived colors */
+  uploadImage: handleClientStreamingCall<UploadImageRequest, UploadImageResponse>;
+  /** Sets the rating of an image, used by the moderators to flag sensitive media */
+  setAssetRating: handleUnaryCall<SetAssetRatingRequest, SetAssetRatingResponse>;
+  /** Reports a story, comment, user, blog, or tag */
+  reportContent: handleUnaryCall<ReportContentRequest, ReportContentResponse>;
+  /** Returns the moderation queue, used by the moderators */
+  listReports: handleUnaryCall<ListReportsRequest, ListReportsResponse>;
+  /** Resolves a report and records the action in the moderation audit log */
+  resolveReport: handleUnaryCall<ResolveReportRequest, ResolveReportResponse>;
+  /** Grants a flag to a user, used by the administrators */
+  grantUserFlag: handleUnaryCall<GrantUserFlagRequest, GrantUserFlagResponse>;
+  /** Revokes a flag from a user, used by the administrators */
+  revokeUserFlag: handleUnaryCall<RevokeUserFlagRequest, RevokeUserFlagResponse>;
+  /** Suspends a user, hiding their stories, comments, and profile */
+  suspendUser: handleUnaryCall<SuspendUserRequest, SuspendUserResponse>;
+  /** Lifts the suspension of a user */
+  unsuspendUser: handleUnaryCall<UnsuspendUserRequest, UnsuspendUserResponse>;
+  /** Queues an archive of the user's data for export */
+  requestAccountExport: handleUnaryCall<RequestAccountExportRequest, RequestAccountExportResponse>;
+  /** Returns the status of an account export along with its download token */
+  getAccountExportStatus: handleUnaryCall<GetAccountExportStatusRequest, GetAccountExportStatusResponse>;
+  /** Schedules the deletion of an account at the end of the grace period */
+  scheduleAccountDeletion: handleUnaryCall<ScheduleAccountDeletionRequest, ScheduleAccountDeletionResponse>;
+  /** Cancels a scheduled account deletion during the grace period */
+  cancelAccountDeletion: handleUnaryCall<CancelAccountDeletionRequest, CancelAccountDeletionResponse>;
+  /** Imports a Markdown or HTML document as a new draft */
+  importStory: handleUnaryCall<ImportStoryRequest, ImportStoryResponse>;
+  /** Imports every story from an export archive as drafts */
+  importStories: handleUnaryCall<ImportStoriesRequest, ImportStoriesResponse>;
+  /** Exports a story as Markdown, HTML, EPUB, or PDF */
+  exportStory: handleUnaryCall<ExportStoryRequest, ExportStoryResponse>;
+  /** Exports the published stories of a blog as an EPUB anthology */
+  exportBlog: handleUnaryCall<ExportBlogRequest, ExportBlogResponse>;
 }
 
 export interface ApiServiceClient extends Client {
@@ -1290,6 +1975,689 @@ export interface ApiServiceClient extends Client {
     options: Partial<CallOptions>,
     callback: (error: ServiceError | null, response: GetTagOpenGraphDataResponse) => void,
   ): ClientUnaryCall;
+  /** Generates a pending TOTP secret for the user */
+  beginMfaEnrollment(
+    request: BeginMfaEnrollmentRequest,
+    callback: (error: ServiceError | null, response: BeginMfaEnrollmentResponse) => void,
+  ): ClientUnaryCall;
+  beginMfaEnrollment(
+    request: BeginMfaEnrollmentRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: BeginMfaEnrollmentResponse) => void,
+  ): ClientUnaryCall;
+  beginMfaEnrollment(
+    request: BeginMfaEnrollmentRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: BeginMfaEnrollmentResponse) => void,
+  ): ClientUnaryCall;
+  /** Enables MFA for the user after verifying a code generated using the pending secret */
+  confirmMfaEnrollment(
+    request: ConfirmMfaEnrollmentRequest,
+    callback: (error: ServiceError | null, response: ConfirmMfaEnrollmentResponse) => void,
+  ): ClientUnaryCall;
+  confirmMfaEnrollment(
+    request: ConfirmMfaEnrollmentRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: ConfirmMfaEnrollmentResponse) => void,
+  ): ClientUnaryCall;
+  confirmMfaEnrollment(
+    request: ConfirmMfaEnrollmentRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: ConfirmMfaEnrollmentResponse) => void,
+  ): ClientUnaryCall;
+  /** Issues an MFA challenge for a login whose first factor (password or OAuth) has been verified */
+  beginMfaChallenge(
+    request: BeginMfaChallengeRequest,
+    callback: (error: ServiceError | null, response: BeginMfaChallengeResponse) => void,
+  ): ClientUnaryCall;
+  beginMfaChallenge(
+    request: BeginMfaChallengeRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: BeginMfaChallengeResponse) => void,
+  ): ClientUnaryCall;
+  beginMfaChallenge(
+    request: BeginMfaChallengeRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: BeginMfaChallengeResponse) => void,
+  ): ClientUnaryCall;
+  /** Verifies a TOTP or recovery code against an MFA challenge during login */
+  verifyMfaCode(
+    request: VerifyMfaCodeRequest,
+    callback: (error: ServiceError | null, response: VerifyMfaCodeResponse) => void,
+  ): ClientUnaryCall;
+  verifyMfaCode(
+    request: VerifyMfaCodeRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: VerifyMfaCodeResponse) => void,
+  ): ClientUnaryCall;
+  verifyMfaCode(
+    request: VerifyMfaCodeRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: VerifyMfaCodeResponse) => void,
+  ): ClientUnaryCall;
+  /** Disables MFA for the user */
+  disableMfa(
+    request: DisableMfaRequest,
+    callback: (error: ServiceError | null, response: DisableMfaResponse) => void,
+  ): ClientUnaryCall;
+  disableMfa(
+    request: DisableMfaRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: DisableMfaResponse) => void,
+  ): ClientUnaryCall;
+  disableMfa(
+    request: DisableMfaRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: DisableMfaResponse) => void,
+  ): ClientUnaryCall;
+  /** Replaces the user's recovery codes */
+  regenerateMfaRecoveryCodes(
+    request: RegenerateMfaRecoveryCodesRequest,
+    callback: (error: ServiceError | null, response: RegenerateMfaRecoveryCodesResponse) => void,
+  ): ClientUnaryCall;
+  regenerateMfaRecoveryCodes(
+    request: RegenerateMfaRecoveryCodesRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: RegenerateMfaRecoveryCodesResponse) => void,
+  ): ClientUnaryCall;
+  regenerateMfaRecoveryCodes(
+    request: RegenerateMfaRecoveryCodesRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: RegenerateMfaRecoveryCodesResponse) => void,
+  ): ClientUnaryCall;
+  /** Returns the options for registering a new passkey */
+  beginPasskeyRegistration(
+    request: BeginPasskeyRegistrationRequest,
+    callback: (error: ServiceError | null, response: BeginPasskeyRegistrationResponse) => void,
+  ): ClientUnaryCall;
+  beginPasskeyRegistration(
+    request: BeginPasskeyRegistrationRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: BeginPasskeyRegistrationResponse) => void,
+  ): ClientUnaryCall;
+  beginPasskeyRegistration(
+    request: BeginPasskeyRegistrationRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: BeginPasskeyRegistrationResponse) => void,
+  ): ClientUnaryCall;
+  /** Verifies the attestation from the authenticator and registers the passkey */
+  finishPasskeyRegistration(
+    request: FinishPasskeyRegistrationRequest,
+    callback: (error: ServiceError | null, response: FinishPasskeyRegistrationResponse) => void,
+  ): ClientUnaryCall;
+  finishPasskeyRegistration(
+    request: FinishPasskeyRegistrationRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: FinishPasskeyRegistrationResponse) => void,
+  ): ClientUnaryCall;
+  finishPasskeyRegistration(
+    request: FinishPasskeyRegistrationRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: FinishPasskeyRegistrationResponse) => void,
+  ): ClientUnaryCall;
+  /** Returns the options for authenticating using a passkey */
+  beginPasskeyAuthentication(
+    request: BeginPasskeyAuthenticationRequest,
+    callback: (error: ServiceError | null, response: BeginPasskeyAuthenticationResponse) => void,
+  ): ClientUnaryCall;
+  beginPasskeyAuthentication(
+    request: BeginPasskeyAuthenticationRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: BeginPasskeyAuthenticationResponse) => void,
+  ): ClientUnaryCall;
+  beginPasskeyAuthentication(
+    request: BeginPasskeyAuthenticationRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: BeginPasskeyAuthenticationResponse) => void,
+  ): ClientUnaryCall;
+  /** Verifies the assertion from the authenticator and returns the user ID */
+  finishPasskeyAuthentication(
+    request: FinishPasskeyAuthenticationRequest,
+    callback: (error: ServiceError | null, response: FinishPasskeyAuthenticationResponse) => void,
+  ): ClientUnaryCall;
+  finishPasskeyAuthentication(
+    request: FinishPasskeyAuthenticationRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: FinishPasskeyAuthenticationResponse) => void,
+  ): ClientUnaryCall;
+  finishPasskeyAuthentication(
+    request: FinishPasskeyAuthenticationRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: FinishPasskeyAuthenticationResponse) => void,
+  ): ClientUnaryCall;
+  /** Removes a passkey registered by the user */
+  removePasskey(
+    request: RemovePasskeyRequest,
+    callback: (error: ServiceError | null, response: RemovePasskeyResponse) => void,
+  ): ClientUnaryCall;
+  removePasskey(
+    request: RemovePasskeyRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: RemovePasskeyResponse) => void,
+  ): ClientUnaryCall;
+  removePasskey(
+    request: RemovePasskeyRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: RemovePasskeyResponse) => void,
+  ): ClientUnaryCall;
+  /** Links an external login provider to the user's account */
+  linkLoginProvider(
+    request: LinkLoginProviderRequest,
+    callback: (error: ServiceError | null, response: LinkLoginProviderResponse) => void,
+  ): ClientUnaryCall;
+  linkLoginProvider(
+    request: LinkLoginProviderRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: LinkLoginProviderResponse) => void,
+  ): ClientUnaryCall;
+  linkLoginProvider(
+    request: LinkLoginProviderRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: LinkLoginProviderResponse) => void,
+  ): ClientUnaryCall;
+  /** Unlinks an external login provider, unless it is the last remaining login method */
+  unlinkLoginProvider(
+    request: UnlinkLoginProviderRequest,
+    callback: (error: ServiceError | null, response: UnlinkLoginProviderResponse) => void,
+  ): ClientUnaryCall;
+  unlinkLoginProvider(
+    request: UnlinkLoginProviderRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: UnlinkLoginProviderResponse) => void,
+  ): ClientUnaryCall;
+  unlinkLoginProvider(
+    request: UnlinkLoginProviderRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: UnlinkLoginProviderResponse) => void,
+  ): ClientUnaryCall;
+  /** Adds a connection using the authorization code from the provider's OAuth callback */
+  addConnection(
+    request: AddConnectionRequest,
+    callback: (error: ServiceError | null, response: AddConnectionResponse) => void,
+  ): ClientUnaryCall;
+  addConnection(
+    request: AddConnectionRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: AddConnectionResponse) => void,
+  ): ClientUnaryCall;
+  addConnection(
+    request: AddConnectionRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: AddConnectionResponse) => void,
+  ): ClientUnaryCall;
+  /** Hides or shows a connection on the user's public profile */
+  setConnectionHidden(
+    request: SetConnectionHiddenRequest,
+    callback: (error: ServiceError | null, response: SetConnectionHiddenResponse) => void,
+  ): ClientUnaryCall;
+  setConnectionHidden(
+    request: SetConnectionHiddenRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: SetConnectionHiddenResponse) => void,
+  ): ClientUnaryCall;
+  setConnectionHidden(
+    request: SetConnectionHiddenRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: SetConnectionHiddenResponse) => void,
+  ): ClientUnaryCall;
+  /** Removes a connection along with the data received from the provider */
+  removeConnection(
+    request: RemoveConnectionRequest,
+    callback: (error: ServiceError | null, response: RemoveConnectionResponse) => void,
+  ): ClientUnaryCall;
+  removeConnection(
+    request: RemoveConnectionRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: RemoveConnectionResponse) => void,
+  ): ClientUnaryCall;
+  removeConnection(
+    request: RemoveConnectionRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: RemoveConnectionResponse) => void,
+  ): ClientUnaryCall;
+  /** Refreshes the display name and URL of a connection from the provider */
+  refreshConnection(
+    request: RefreshConnectionRequest,
+    callback: (error: ServiceError | null, response: RefreshConnectionResponse) => void,
+  ): ClientUnaryCall;
+  refreshConnection(
+    request: RefreshConnectionRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: RefreshConnectionResponse) => void,
+  ): ClientUnaryCall;
+  refreshConnection(
+    request: RefreshConnectionRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: RefreshConnectionResponse) => void,
+  ): ClientUnaryCall;
+  /** Updates the user's privacy settings and applies the resulting cascades */
+  updatePrivacySettings(
+    request: UpdatePrivacySettingsRequest,
+    callback: (error: ServiceError | null, response: UpdatePrivacySettingsResponse) => void,
+  ): ClientUnaryCall;
+  updatePrivacySettings(
+    request: UpdatePrivacySettingsRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: UpdatePrivacySettingsResponse) => void,
+  ): ClientUnaryCall;
+  updatePrivacySettings(
+    request: UpdatePrivacySettingsRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: UpdatePrivacySettingsResponse) => void,
+  ): ClientUnaryCall;
+  /** Updates the user's notification settings */
+  updateNotificationSettings(
+    request: UpdateNotificationSettingsRequest,
+    callback: (error: ServiceError | null, response: UpdateNotificationSettingsResponse) => void,
+  ): ClientUnaryCall;
+  updateNotificationSettings(
+    request: UpdateNotificationSettingsRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: UpdateNotificationSettingsResponse) => void,
+  ): ClientUnaryCall;
+  updateNotificationSettings(
+    request: UpdateNotificationSettingsRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: UpdateNotificationSettingsResponse) => void,
+  ): ClientUnaryCall;
+  /** Returns the notifications for a user, newest first */
+  listNotifications(
+    request: ListNotificationsRequest,
+    callback: (error: ServiceError | null, response: ListNotificationsResponse) => void,
+  ): ClientUnaryCall;
+  listNotifications(
+    request: ListNotificationsRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: ListNotificationsResponse) => void,
+  ): ClientUnaryCall;
+  listNotifications(
+    request: ListNotificationsRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: ListNotificationsResponse) => void,
+  ): ClientUnaryCall;
+  /** Marks notifications as read for a user */
+  markNotificationsRead(
+    request: MarkNotificationsReadRequest,
+    callback: (error: ServiceError | null, response: MarkNotificationsReadResponse) => void,
+  ): ClientUnaryCall;
+  markNotificationsRead(
+    request: MarkNotificationsReadRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: MarkNotificationsReadResponse) => void,
+  ): ClientUnaryCall;
+  markNotificationsRead(
+    request: MarkNotificationsReadRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: MarkNotificationsReadResponse) => void,
+  ): ClientUnaryCall;
+  /** Returns the number of unread notifications for a user */
+  getUnreadNotificationCount(
+    request: GetUnreadNotificationCountRequest,
+    callback: (error: ServiceError | null, response: GetUnreadNotificationCountResponse) => void,
+  ): ClientUnaryCall;
+  getUnreadNotificationCount(
+    request: GetUnreadNotificationCountRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: GetUnreadNotificationCountResponse) => void,
+  ): ClientUnaryCall;
+  getUnreadNotificationCount(
+    request: GetUnreadNotificationCountRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: GetUnreadNotificationCountResponse) => void,
+  ): ClientUnaryCall;
+  /** Streams new notifications for a user as they are created */
+  watchNotifications(
+    request: WatchNotificationsRequest,
+    options?: Partial<CallOptions>,
+  ): ClientReadableStream<WatchNotificationsResponse>;
+  watchNotifications(
+    request: WatchNotificationsRequest,
+    metadata?: Metadata,
+    options?: Partial<CallOptions>,
+  ): ClientReadableStream<WatchNotificationsResponse>;
+  /** Sets the status of a user, replacing the previous status */
+  setStatus(
+    request: SetStatusRequest,
+    callback: (error: ServiceError | null, response: SetStatusResponse) => void,
+  ): ClientUnaryCall;
+  setStatus(
+    request: SetStatusRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: SetStatusResponse) => void,
+  ): ClientUnaryCall;
+  setStatus(
+    request: SetStatusRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: SetStatusResponse) => void,
+  ): ClientUnaryCall;
+  /** Clears the status of a user */
+  clearStatus(
+    request: ClearStatusRequest,
+    callback: (error: ServiceError | null, response: ClearStatusResponse) => void,
+  ): ClientUnaryCall;
+  clearStatus(
+    request: ClearStatusRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: ClearStatusResponse) => void,
+  ): ClientUnaryCall;
+  clearStatus(
+    request: ClearStatusRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: ClearStatusResponse) => void,
+  ): ClientUnaryCall;
+  /** Updates the profile of a user */
+  updateProfile(
+    request: UpdateProfileRequest,
+    callback: (error: ServiceError | null, response: UpdateProfileResponse) => void,
+  ): ClientUnaryCall;
+  updateProfile(
+    request: UpdateProfileRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: UpdateProfileResponse) => void,
+  ): ClientUnaryCall;
+  updateProfile(
+    request: UpdateProfileRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: UpdateProfileResponse) => void,
+  ): ClientUnaryCall;
+  /** Uploads an image in chunks and returns its ID along with the derived colors */
+  uploadImage(
+    callback: (error: ServiceError | null, response: UploadImageResponse) => void,
+  ): ClientWritableStream<UploadImageRequest>;
+  uploadImage(
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: UploadImageResponse) => void,
+  ): ClientWritableStream<UploadImageRequest>;
+  uploadImage(
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: UploadImageResponse) => void,
+  ): ClientWritableStream<UploadImageRequest>;
+  uploadImage(
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: UploadImageResponse) => void,
+  ): ClientWritableStream<UploadImageRequest>;
+  /** Sets the rating of an image, used by the moderators to flag sensitive media */
+  setAssetRating(
+    request: SetAssetRatingRequest,
+    callback: (error: ServiceError | null, response: SetAssetRatingResponse) => void,
+  ): ClientUnaryCall;
+  setAssetRating(
+    request: SetAssetRatingRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: SetAssetRatingResponse) => void,
+  ): ClientUnaryCall;
+  setAssetRating(
+    request: SetAssetRatingRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: SetAssetRatingResponse) => void,
+  ): ClientUnaryCall;
+  /** Reports a story, comment, user, blog, or tag */
+  reportContent(
+    request: ReportContentRequest,
+    callback: (error: ServiceError | null, response: ReportContentResponse) => void,
+  ): ClientUnaryCall;
+  reportContent(
+    request: ReportContentRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: ReportContentResponse) => void,
+  ): ClientUnaryCall;
+  reportContent(
+    request: ReportContentRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: ReportContentResponse) => void,
+  ): ClientUnaryCall;
+  /** Returns the moderation queue, used by the moderators */
+  listReports(
+    request: ListReportsRequest,
+    callback: (error: ServiceError | null, response: ListReportsResponse) => void,
+  ): ClientUnaryCall;
+  listReports(
+    request: ListReportsRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: ListReportsResponse) => void,
+  ): ClientUnaryCall;
+  listReports(
+    request: ListReportsRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: ListReportsResponse) => void,
+  ): ClientUnaryCall;
+  /** Resolves a report and records the action in the moderation audit log */
+  resolveReport(
+    request: ResolveReportRequest,
+    callback: (error: ServiceError | null, response: ResolveReportResponse) => void,
+  ): ClientUnaryCall;
+  resolveReport(
+    request: ResolveReportRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: ResolveReportResponse) => void,
+  ): ClientUnaryCall;
+  resolveReport(
+    request: ResolveReportRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: ResolveReportResponse) => void,
+  ): ClientUnaryCall;
+  /** Grants a flag to a user, used by the administrators */
+  grantUserFlag(
+    request: GrantUserFlagRequest,
+    callback: (error: ServiceError | null, response: GrantUserFlagResponse) => void,
+  ): ClientUnaryCall;
+  grantUserFlag(
+    request: GrantUserFlagRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: GrantUserFlagResponse) => void,
+  ): ClientUnaryCall;
+  grantUserFlag(
+    request: GrantUserFlagRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: GrantUserFlagResponse) => void,
+  ): ClientUnaryCall;
+  /** Revokes a flag from a user, used by the administrators */
+  revokeUserFlag(
+    request: RevokeUserFlagRequest,
+    callback: (error: ServiceError | null, response: RevokeUserFlagResponse) => void,
+  ): ClientUnaryCall;
+  revokeUserFlag(
+    request: RevokeUserFlagRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: RevokeUserFlagResponse) => void,
+  ): ClientUnaryCall;
+  revokeUserFlag(
+    request: RevokeUserFlagRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: RevokeUserFlagResponse) => void,
+  ): ClientUnaryCall;
+  /** Suspends a user, hiding their stories, comments, and profile */
+  suspendUser(
+    request: SuspendUserRequest,
+    callback: (error: ServiceError | null, response: SuspendUserResponse) => void,
+  ): ClientUnaryCall;
+  suspendUser(
+    request: SuspendUserRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: SuspendUserResponse) => void,
+  ): ClientUnaryCall;
+  suspendUser(
+    request: SuspendUserRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: SuspendUserResponse) => void,
+  ): ClientUnaryCall;
+  /** Lifts the suspension of a user */
+  unsuspendUser(
+    request: UnsuspendUserRequest,
+    callback: (error: ServiceError | null, response: UnsuspendUserResponse) => void,
+  ): ClientUnaryCall;
+  unsuspendUser(
+    request: UnsuspendUserRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: UnsuspendUserResponse) => void,
+  ): ClientUnaryCall;
+  unsuspendUser(
+    request: UnsuspendUserRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: UnsuspendUserResponse) => void,
+  ): ClientUnaryCall;
+  /** Queues an archive of the user's data for export */
+  requestAccountExport(
+    request: RequestAccountExportRequest,
+    callback: (error: ServiceError | null, response: RequestAccountExportResponse) => void,
+  ): ClientUnaryCall;
+  requestAccountExport(
+    request: RequestAccountExportRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: RequestAccountExportResponse) => void,
+  ): ClientUnaryCall;
+  requestAccountExport(
+    request: RequestAccountExportRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: RequestAccountExportResponse) => void,
+  ): ClientUnaryCall;
+  /** Returns the status of an account export along with its download token */
+  getAccountExportStatus(
+    request: GetAccountExportStatusRequest,
+    callback: (error: ServiceError | null, response: GetAccountExportStatusResponse) => void,
+  ): ClientUnaryCall;
+  getAccountExportStatus(
+    request: GetAccountExportStatusRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: GetAccountExportStatusResponse) => void,
+  ): ClientUnaryCall;
+  getAccountExportStatus(
+    request: GetAccountExportStatusRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: GetAccountExportStatusResponse) => void,
+  ): ClientUnaryCall;
+  /** Schedules the deletion of an account at the end of the grace period */
+  scheduleAccountDeletion(
+    request: ScheduleAccountDeletionRequest,
+    callback: (error: ServiceError | null, response: ScheduleAccountDeletionResponse) => void,
+  ): ClientUnaryCall;
+  scheduleAccountDeletion(
+    request: ScheduleAccountDeletionRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: ScheduleAccountDeletionResponse) => void,
+  ): ClientUnaryCall;
+  scheduleAccountDeletion(
+    request: ScheduleAccountDeletionRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: ScheduleAccountDeletionResponse) => void,
+  ): ClientUnaryCall;
+  /** Cancels a scheduled account deletion during the grace period */
+  cancelAccountDeletion(
+    request: CancelAccountDeletionRequest,
+    callback: (error: ServiceError | null, response: CancelAccountDeletionResponse) => void,
+  ): ClientUnaryCall;
+  cancelAccountDeletion(
+    request: CancelAccountDeletionRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: CancelAccountDeletionResponse) => void,
+  ): ClientUnaryCall;
+  cancelAccountDeletion(
+    request: CancelAccountDeletionRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: CancelAccountDeletionResponse) => void,
+  ): ClientUnaryCall;
+  /** Imports a Markdown or HTML document as a new draft */
+  importStory(
+    request: ImportStoryRequest,
+    callback: (error: ServiceError | null, response: ImportStoryResponse) => void,
+  ): ClientUnaryCall;
+  importStory(
+    request: ImportStoryRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: ImportStoryResponse) => void,
+  ): ClientUnaryCall;
+  importStory(
+    request: ImportStoryRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: ImportStoryResponse) => void,
+  ): ClientUnaryCall;
+  /** Imports every story from an export archive as drafts */
+  importStories(
+    request: ImportStoriesRequest,
+    callback: (error: ServiceError | null, response: ImportStoriesResponse) => void,
+  ): ClientUnaryCall;
+  importStories(
+    request: ImportStoriesRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: ImportStoriesResponse) => void,
+  ): ClientUnaryCall;
+  importStories(
+    request: ImportStoriesRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: ImportStoriesResponse) => void,
+  ): ClientUnaryCall;
+  /** Exports a story as Markdown, HTML, EPUB, or PDF */
+  exportStory(
+    request: ExportStoryRequest,
+    callback: (error: ServiceError | null, response: ExportStoryResponse) => void,
+  ): ClientUnaryCall;
+  exportStory(
+    request: ExportStoryRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: ExportStoryResponse) => void,
+  ): ClientUnaryCall;
+  exportStory(
+    request: ExportStoryRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: ExportStoryResponse) => void,
+  ): ClientUnaryCall;
+  /** Exports the published stories of a blog as an EPUB anthology */
+  exportBlog(
+    request: ExportBlogRequest,
+    callback: (error: ServiceError | null, response: ExportBlogResponse) => void,
+  ): ClientUnaryCall;
+  exportBlog(
+    request: ExportBlogRequest,
+    metadata: Metadata,
+    callback: (error: ServiceError | null, response: ExportBlogResponse) => void,
+  ): ClientUnaryCall;
+  exportBlog(
+    request: ExportBlogRequest,
+    metadata: Metadata,
+    options: Partial<CallOptions>,
+    callback: (error: ServiceError | null, response: ExportBlogResponse) => void,
+  ): ClientUnaryCall;
 }
 
 export const ApiServiceClient = makeGenericClientConstructor(
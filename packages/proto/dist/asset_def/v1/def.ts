@@ -0,0 +1,662 @@
+/* eslint-disable */
+import _m0 from "protobufjs/minimal";
+
+export const protobufPackage = "asset_def.v1";
+
+export const AssetPurpose = {
+  UNSPECIFIED: 0,
+  AVATAR: 1,
+  BANNER: 2,
+  SPLASH: 3,
+  LOGO: 4,
+  NEWSLETTER_SPLASH: 5,
+  STORY: 6,
+  UNRECOGNIZED: -1,
+} as const;
+
+export type AssetPurpose = typeof AssetPurpose[keyof typeof AssetPurpose];
+
+export function assetPurposeFromJSON(object: any): AssetPurpose {
+  switch (object) {
+    case 0:
+    case "ASSET_PURPOSE_UNSPECIFIED":
+      return AssetPurpose.UNSPECIFIED;
+    case 1:
+    case "ASSET_PURPOSE_AVATAR":
+      return AssetPurpose.AVATAR;
+    case 2:
+    case "ASSET_PURPOSE_BANNER":
+      return AssetPurpose.BANNER;
+    case 3:
+    case "ASSET_PURPOSE_SPLASH":
+      return AssetPurpose.SPLASH;
+    case 4:
+    case "ASSET_PURPOSE_LOGO":
+      return AssetPurpose.LOGO;
+    case 5:
+    case "ASSET_PURPOSE_NEWSLETTER_SPLASH":
+      return AssetPurpose.NEWSLETTER_SPLASH;
+    case 6:
+    case "ASSET_PURPOSE_STORY":
+      return AssetPurpose.STORY;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return AssetPurpose.UNRECOGNIZED;
+  }
+}
+
+export function assetPurposeToJSON(object: AssetPurpose): string {
+  switch (object) {
+    case AssetPurpose.UNSPECIFIED:
+      return "ASSET_PURPOSE_UNSPECIFIED";
+    case AssetPurpose.AVATAR:
+      return "ASSET_PURPOSE_AVATAR";
+    case AssetPurpose.BANNER:
+      return "ASSET_PURPOSE_BANNER";
+    case AssetPurpose.SPLASH:
+      return "ASSET_PURPOSE_SPLASH";
+    case AssetPurpose.LOGO:
+      return "ASSET_PURPOSE_LOGO";
+    case AssetPurpose.NEWSLETTER_SPLASH:
+      return "ASSET_PURPOSE_NEWSLETTER_SPLASH";
+    case AssetPurpose.STORY:
+      return "ASSET_PURPOSE_STORY";
+    case AssetPurpose.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
+/** Mirrors the `AssetRating` enum in `@storiny/shared` */
+export const AssetRating = {
+  UNSPECIFIED: 0,
+  NOT_RATED: 1,
+  SUGGESTIVE_NUDITY: 2,
+  VIOLENCE: 3,
+  SENSITIVE: 4,
+  UNRECOGNIZED: -1,
+} as const;
+
+export type AssetRating = typeof AssetRating[keyof typeof AssetRating];
+
+export function assetRatingFromJSON(object: any): AssetRating {
+  switch (object) {
+    case 0:
+    case "ASSET_RATING_UNSPECIFIED":
+      return AssetRating.UNSPECIFIED;
+    case 1:
+    case "ASSET_RATING_NOT_RATED":
+      return AssetRating.NOT_RATED;
+    case 2:
+    case "ASSET_RATING_SUGGESTIVE_NUDITY":
+      return AssetRating.SUGGESTIVE_NUDITY;
+    case 3:
+    case "ASSET_RATING_VIOLENCE":
+      return AssetRating.VIOLENCE;
+    case 4:
+    case "ASSET_RATING_SENSITIVE":
+      return AssetRating.SENSITIVE;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return AssetRating.UNRECOGNIZED;
+  }
+}
+
+export function assetRatingToJSON(object: AssetRating): string {
+  switch (object) {
+    case AssetRating.UNSPECIFIED:
+      return "ASSET_RATING_UNSPECIFIED";
+    case AssetRating.NOT_RATED:
+      return "ASSET_RATING_NOT_RATED";
+    case AssetRating.SUGGESTIVE_NUDITY:
+      return "ASSET_RATING_SUGGESTIVE_NUDITY";
+    case AssetRating.VIOLENCE:
+      return "ASSET_RATING_VIOLENCE";
+    case AssetRating.SENSITIVE:
+      return "ASSET_RATING_SENSITIVE";
+    case AssetRating.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
+export interface UploadImageMetadata {
+  user_id: string;
+  /** Determines the resized variants */
+  purpose: AssetPurpose;
+  content_type: string;
+  /** Total size of the chunks (in bytes) */
+  size: number;
+  alt?:
+    | string
+    | undefined;
+  /** Set by the uploader, can be changed by the moderators */
+  rating: AssetRating;
+}
+
+export interface UploadImageRequest {
+  metadata?: UploadImageMetadata | undefined;
+  chunk?: Uint8Array | undefined;
+}
+
+export interface UploadImageResponse {
+  /** Used as `avatar_id`, `splash_id`, etc. */
+  id: string;
+  /** Dominant color, used as `avatar_hex`, `splash_hex`, etc. */
+  hex: string;
+  blurhash: string;
+  width: number;
+  height: number;
+  rating: AssetRating;
+}
+
+export interface SetAssetRatingRequest {
+  asset_id: string;
+  rating: AssetRating;
+  moderator_id: string;
+}
+
+export interface SetAssetRatingResponse {
+}
+
+function createBaseUploadImageMetadata(): UploadImageMetadata {
+  return { user_id: "", purpose: 0, content_type: "", size: 0, alt: undefined, rating: 0 };
+}
+
+export const UploadImageMetadata = {
+  encode(message: UploadImageMetadata, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.purpose !== 0) {
+      writer.uint32(16).int32(message.purpose);
+    }
+    if (message.content_type !== "") {
+      writer.uint32(26).string(message.content_type);
+    }
+    if (message.size !== 0) {
+      writer.uint32(32).uint32(message.size);
+    }
+    if (message.alt !== undefined) {
+      writer.uint32(42).string(message.alt);
+    }
+    if (message.rating !== 0) {
+      writer.uint32(48).int32(message.rating);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): UploadImageMetadata {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUploadImageMetadata();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.purpose = reader.int32() as any;
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.content_type = reader.string();
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.size = reader.uint32();
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.alt = reader.string();
+          continue;
+        case 6:
+          if (tag !== 48) {
+            break;
+          }
+
+          message.rating = reader.int32() as any;
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UploadImageMetadata {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      purpose: isSet(object.purpose) ? assetPurposeFromJSON(object.purpose) : 0,
+      content_type: isSet(object.content_type) ? globalThis.String(object.content_type) : "",
+      size: isSet(object.size) ? globalThis.Number(object.size) : 0,
+      alt: isSet(object.alt) ? globalThis.String(object.alt) : undefined,
+      rating: isSet(object.rating) ? assetRatingFromJSON(object.rating) : 0,
+    };
+  },
+
+  toJSON(message: UploadImageMetadata): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.purpose !== 0) {
+      obj.purpose = assetPurposeToJSON(message.purpose);
+    }
+    if (message.content_type !== "") {
+      obj.content_type = message.content_type;
+    }
+    if (message.size !== 0) {
+      obj.size = Math.round(message.size);
+    }
+    if (message.alt !== undefined) {
+      obj.alt = message.alt;
+    }
+    if (message.rating !== 0) {
+      obj.rating = assetRatingToJSON(message.rating);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UploadImageMetadata>, I>>(base?: I): UploadImageMetadata {
+    return UploadImageMetadata.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UploadImageMetadata>, I>>(object: I): UploadImageMetadata {
+    const message = createBaseUploadImageMetadata();
+    message.user_id = object.user_id ?? "";
+    message.purpose = object.purpose ?? 0;
+    message.content_type = object.content_type ?? "";
+    message.size = object.size ?? 0;
+    message.alt = object.alt ?? undefined;
+    message.rating = object.rating ?? 0;
+    return message;
+  },
+};
+
+function createBaseUploadImageRequest(): UploadImageRequest {
+  return { metadata: undefined, chunk: undefined };
+}
+
+export const UploadImageRequest = {
+  encode(message: UploadImageRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.metadata !== undefined) {
+      UploadImageMetadata.encode(message.metadata, writer.uint32(10).fork()).ldelim();
+    }
+    if (message.chunk !== undefined) {
+      writer.uint32(18).bytes(message.chunk);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): UploadImageRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUploadImageRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.metadata = UploadImageMetadata.decode(reader, reader.uint32());
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.chunk = reader.bytes();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UploadImageRequest {
+    return {
+      metadata: isSet(object.metadata) ? UploadImageMetadata.fromJSON(object.metadata) : undefined,
+      chunk: isSet(object.chunk) ? bytesFromBase64(object.chunk) : undefined,
+    };
+  },
+
+  toJSON(message: UploadImageRequest): unknown {
+    const obj: any = {};
+    if (message.metadata !== undefined) {
+      obj.metadata = UploadImageMetadata.toJSON(message.metadata);
+    }
+    if (message.chunk !== undefined) {
+      obj.chunk = base64FromBytes(message.chunk);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UploadImageRequest>, I>>(base?: I): UploadImageRequest {
+    return UploadImageRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UploadImageRequest>, I>>(object: I): UploadImageRequest {
+    const message = createBaseUploadImageRequest();
+    message.metadata = (object.metadata !== undefined && object.metadata !== null)
+      ? UploadImageMetadata.fromPartial(object.metadata)
+      : undefined;
+    message.chunk = object.chunk ?? undefined;
+    return message;
+  },
+};
+
+function createBaseUploadImageResponse(): UploadImageResponse {
+  return { id: "", hex: "", blurhash: "", width: 0, height: 0, rating: 0 };
+}
+
+export const UploadImageResponse = {
+  encode(message: UploadImageResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.id !== "") {
+      writer.uint32(10).string(message.id);
+    }
+    if (message.hex !== "") {
+      writer.uint32(18).string(message.hex);
+    }
+    if (message.blurhash !== "") {
+      writer.uint32(26).string(message.blurhash);
+    }
+    if (message.width !== 0) {
+      writer.uint32(32).uint32(message.width);
+    }
+    if (message.height !== 0) {
+      writer.uint32(40).uint32(message.height);
+    }
+    if (message.rating !== 0) {
+      writer.uint32(48).int32(message.rating);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): UploadImageResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUploadImageResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.hex = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.blurhash = reader.string();
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.width = reader.uint32();
+          continue;
+        case 5:
+          if (tag !== 40) {
+            break;
+          }
+
+          message.height = reader.uint32();
+          continue;
+        case 6:
+          if (tag !== 48) {
+            break;
+          }
+
+          message.rating = reader.int32() as any;
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UploadImageResponse {
+    return {
+      id: isSet(object.id) ? globalThis.String(object.id) : "",
+      hex: isSet(object.hex) ? globalThis.String(object.hex) : "",
+      blurhash: isSet(object.blurhash) ? globalThis.String(object.blurhash) : "",
+      width: isSet(object.width) ? globalThis.Number(object.width) : 0,
+      height: isSet(object.height) ? globalThis.Number(object.height) : 0,
+      rating: isSet(object.rating) ? assetRatingFromJSON(object.rating) : 0,
+    };
+  },
+
+  toJSON(message: UploadImageResponse): unknown {
+    const obj: any = {};
+    if (message.id !== "") {
+      obj.id = message.id;
+    }
+    if (message.hex !== "") {
+      obj.hex = message.hex;
+    }
+    if (message.blurhash !== "") {
+      obj.blurhash = message.blurhash;
+    }
+    if (message.width !== 0) {
+      obj.width = Math.round(message.width);
+    }
+    if (message.height !== 0) {
+      obj.height = Math.round(message.height);
+    }
+    if (message.rating !== 0) {
+      obj.rating = assetRatingToJSON(message.rating);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UploadImageResponse>, I>>(base?: I): UploadImageResponse {
+    return UploadImageResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UploadImageResponse>, I>>(object: I): UploadImageResponse {
+    const message = createBaseUploadImageResponse();
+    message.id = object.id ?? "";
+    message.hex = object.hex ?? "";
+    message.blurhash = object.blurhash ?? "";
+    message.width = object.width ?? 0;
+    message.height = object.height ?? 0;
+    message.rating = object.rating ?? 0;
+    return message;
+  },
+};
+
+function createBaseSetAssetRatingRequest(): SetAssetRatingRequest {
+  return { asset_id: "", rating: 0, moderator_id: "" };
+}
+
+export const SetAssetRatingRequest = {
+  encode(message: SetAssetRatingRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.asset_id !== "") {
+      writer.uint32(10).string(message.asset_id);
+    }
+    if (message.rating !== 0) {
+      writer.uint32(16).int32(message.rating);
+    }
+    if (message.moderator_id !== "") {
+      writer.uint32(26).string(message.moderator_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): SetAssetRatingRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSetAssetRatingRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.asset_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.rating = reader.int32() as any;
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.moderator_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SetAssetRatingRequest {
+    return {
+      asset_id: isSet(object.asset_id) ? globalThis.String(object.asset_id) : "",
+      rating: isSet(object.rating) ? assetRatingFromJSON(object.rating) : 0,
+      moderator_id: isSet(object.moderator_id) ? globalThis.String(object.moderator_id) : "",
+    };
+  },
+
+  toJSON(message: SetAssetRatingRequest): unknown {
+    const obj: any = {};
+    if (message.asset_id !== "") {
+      obj.asset_id = message.asset_id;
+    }
+    if (message.rating !== 0) {
+      obj.rating = assetRatingToJSON(message.rating);
+    }
+    if (message.moderator_id !== "") {
+      obj.moderator_id = message.moderator_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SetAssetRatingRequest>, I>>(base?: I): SetAssetRatingRequest {
+    return SetAssetRatingRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SetAssetRatingRequest>, I>>(object: I): SetAssetRatingRequest {
+    const message = createBaseSetAssetRatingRequest();
+    message.asset_id = object.asset_id ?? "";
+    message.rating = object.rating ?? 0;
+    message.moderator_id = object.moderator_id ?? "";
+    return message;
+  },
+};
+
+function createBaseSetAssetRatingResponse(): SetAssetRatingResponse {
+  return {};
+}
+
+export const SetAssetRatingResponse = {
+  encode(_: SetAssetRatingResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): SetAssetRatingResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSetAssetRatingResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(_: any): SetAssetRatingResponse {
+    return {};
+  },
+
+  toJSON(_: SetAssetRatingResponse): unknown {
+    const obj: any = {};
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SetAssetRatingResponse>, I>>(base?: I): SetAssetRatingResponse {
+    return SetAssetRatingResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SetAssetRatingResponse>, I>>(_: I): SetAssetRatingResponse {
+    const message = createBaseSetAssetRatingResponse();
+    return message;
+  },
+};
+
+function bytesFromBase64(b64: string): Uint8Array {
+  return Uint8Array.from(globalThis.Buffer.from(b64, "base64"));
+}
+
+function base64FromBytes(arr: Uint8Array): string {
+  return globalThis.Buffer.from(arr).toString("base64");
+}
+
+type Builtin = Date | Function | Uint8Array | string | number | boolean | undefined;
+
+export type DeepPartial<T> = T extends Builtin ? T
+  : T extends globalThis.Array<infer U> ? globalThis.Array<DeepPartial<U>>
+  : T extends ReadonlyArray<infer U> ? ReadonlyArray<DeepPartial<U>>
+  : T extends {} ? { [K in keyof T]?: DeepPartial<T[K]> }
+  : Partial<T>;
+
+type KeysOfUnion<T> = T extends T ? keyof T : never;
+export type Exact<P, I extends P> = P extends Builtin ? P
+  : P & { [K in keyof P]: Exact<P[K], I[K]> } & { [K in Exclude<keyof I, KeysOfUnion<P>>]: never };
+
+function isSet(value: any): boolean {
+  return value !== null && value !== undefined;
+}
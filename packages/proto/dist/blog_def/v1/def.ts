@@ -1,5 +1,6 @@
 /* eslint-disable */
 import _m0 from "protobufjs/minimal";
+import { Timestamp } from "../../google/protobuf/timestamp";
 import { BareUser } from "../../user_def/v1/def";
 
 export const protobufPackage = "blog_def.v1";
@@ -98,13 +99,18 @@ export interface GetBlogResponse {
     | string
     | undefined;
   /** Other props */
-  domain?: string | undefined;
+  domain?:
+    | string
+    | undefined;
+  /** @deprecated */
   created_at: string;
   category: string;
   user_id: string;
   rsb_items_label: string;
   lsb_items: LeftSidebarItem[];
   rsb_items: RightSidebarItem[];
+  /** Timestamps */
+  created_at_ts: Date | undefined;
 }
 
 export interface ArchiveTimeline {
@@ -208,6 +214,18 @@ export interface VerifyBlogLoginResponse {
   is_persistent_cookie?: boolean | undefined;
 }
 
+export interface ExportBlogRequest {
+  identifier: string;
+  current_user_id?: string | undefined;
+}
+
+/** EPUB anthology of the published stories, ordered by `published_at` */
+export interface ExportBlogResponse {
+  filename: string;
+  content_type: string;
+  content: Uint8Array;
+}
+
 function createBaseBareBlog(): BareBlog {
   return { id: "", slug: "", domain: undefined, name: "", logo_id: undefined, logo_hex: undefined };
 }
@@ -686,6 +704,7 @@ function createBaseGetBlogResponse(): GetBlogResponse {
     rsb_items_label: "",
     lsb_items: [],
     rsb_items: [],
+    created_at_ts: undefined,
   };
 }
 
@@ -826,6 +845,9 @@ export const GetBlogResponse = {
     for (const v of message.rsb_items) {
       RightSidebarItem.encode(v!, writer.uint32(362).fork()).ldelim();
     }
+    if (message.created_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at_ts), writer.uint32(370).fork()).ldelim();
+    }
     return writer;
   },
 
@@ -1151,6 +1173,13 @@ export const GetBlogResponse = {
 
           message.rsb_items.push(RightSidebarItem.decode(reader, reader.uint32()));
           continue;
+        case 46:
+          if (tag !== 370) {
+            break;
+          }
+
+          message.created_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -1221,6 +1250,7 @@ export const GetBlogResponse = {
       rsb_items: globalThis.Array.isArray(object?.rsb_items)
         ? object.rsb_items.map((e: any) => RightSidebarItem.fromJSON(e))
         : [],
+      created_at_ts: isSet(object.created_at_ts) ? fromJsonTimestamp(object.created_at_ts) : undefined,
     };
   },
 
@@ -1361,6 +1391,9 @@ export const GetBlogResponse = {
     if (message.rsb_items?.length) {
       obj.rsb_items = message.rsb_items.map((e) => RightSidebarItem.toJSON(e));
     }
+    if (message.created_at_ts !== undefined) {
+      obj.created_at_ts = message.created_at_ts.toISOString();
+    }
     return obj;
   },
 
@@ -1414,6 +1447,7 @@ export const GetBlogResponse = {
     message.rsb_items_label = object.rsb_items_label ?? "";
     message.lsb_items = object.lsb_items?.map((e) => LeftSidebarItem.fromPartial(e)) || [];
     message.rsb_items = object.rsb_items?.map((e) => RightSidebarItem.fromPartial(e)) || [];
+    message.created_at_ts = object.created_at_ts ?? undefined;
     return message;
   },
 };
@@ -2944,6 +2978,177 @@ export const VerifyBlogLoginResponse = {
   },
 };
 
+function createBaseExportBlogRequest(): ExportBlogRequest {
+  return { identifier: "", current_user_id: undefined };
+}
+
+export const ExportBlogRequest = {
+  encode(message: ExportBlogRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.identifier !== "") {
+      writer.uint32(10).string(message.identifier);
+    }
+    if (message.current_user_id !== undefined) {
+      writer.uint32(18).string(message.current_user_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ExportBlogRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseExportBlogRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.identifier = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.current_user_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ExportBlogRequest {
+    return {
+      identifier: isSet(object.identifier) ? globalThis.String(object.identifier) : "",
+      current_user_id: isSet(object.current_user_id) ? globalThis.String(object.current_user_id) : undefined,
+    };
+  },
+
+  toJSON(message: ExportBlogRequest): unknown {
+    const obj: any = {};
+    if (message.identifier !== "") {
+      obj.identifier = message.identifier;
+    }
+    if (message.current_user_id !== undefined) {
+      obj.current_user_id = message.current_user_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ExportBlogRequest>, I>>(base?: I): ExportBlogRequest {
+    return ExportBlogRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ExportBlogRequest>, I>>(object: I): ExportBlogRequest {
+    const message = createBaseExportBlogRequest();
+    message.identifier = object.identifier ?? "";
+    message.current_user_id = object.current_user_id ?? undefined;
+    return message;
+  },
+};
+
+function createBaseExportBlogResponse(): ExportBlogResponse {
+  return { filename: "", content_type: "", content: new Uint8Array(0) };
+}
+
+export const ExportBlogResponse = {
+  encode(message: ExportBlogResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.filename !== "") {
+      writer.uint32(10).string(message.filename);
+    }
+    if (message.content_type !== "") {
+      writer.uint32(18).string(message.content_type);
+    }
+    if (message.content.length !== 0) {
+      writer.uint32(26).bytes(message.content);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ExportBlogResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseExportBlogResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.filename = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.content_type = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.content = reader.bytes();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ExportBlogResponse {
+    return {
+      filename: isSet(object.filename) ? globalThis.String(object.filename) : "",
+      content_type: isSet(object.content_type) ? globalThis.String(object.content_type) : "",
+      content: isSet(object.content) ? bytesFromBase64(object.content) : new Uint8Array(0),
+    };
+  },
+
+  toJSON(message: ExportBlogResponse): unknown {
+    const obj: any = {};
+    if (message.filename !== "") {
+      obj.filename = message.filename;
+    }
+    if (message.content_type !== "") {
+      obj.content_type = message.content_type;
+    }
+    if (message.content.length !== 0) {
+      obj.content = base64FromBytes(message.content);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ExportBlogResponse>, I>>(base?: I): ExportBlogResponse {
+    return ExportBlogResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ExportBlogResponse>, I>>(object: I): ExportBlogResponse {
+    const message = createBaseExportBlogResponse();
+    message.filename = object.filename ?? "";
+    message.content_type = object.content_type ?? "";
+    message.content = object.content ?? new Uint8Array(0);
+    return message;
+  },
+};
+
+function bytesFromBase64(b64: string): Uint8Array {
+  return Uint8Array.from(globalThis.Buffer.from(b64, "base64"));
+}
+
+function base64FromBytes(arr: Uint8Array): string {
+  return globalThis.Buffer.from(arr).toString("base64");
+}
+
 type Builtin = Date | Function | Uint8Array | string | number | boolean | undefined;
 
 export type DeepPartial<T> = T extends Builtin ? T
@@ -2956,6 +3161,28 @@ type KeysOfUnion<T> = T extends T ? keyof T : never;
 export type Exact<P, I extends P> = P extends Builtin ? P
   : P & { [K in keyof P]: Exact<P[K], I[K]> } & { [K in Exclude<keyof I, KeysOfUnion<P>>]: never };
 
+function toTimestamp(date: Date): Timestamp {
+  const seconds = Math.trunc(date.getTime() / 1_000);
+  const nanos = (date.getTime() % 1_000) * 1_000_000;
+  return { seconds, nanos };
+}
+
+function fromTimestamp(t: Timestamp): Date {
+  let millis = (t.seconds || 0) * 1_000;
+  millis += (t.nanos || 0) / 1_000_000;
+  return new globalThis.Date(millis);
+}
+
+function fromJsonTimestamp(o: any): Date {
+  if (o instanceof globalThis.Date) {
+    return o;
+  } else if (typeof o === "string") {
+    return new globalThis.Date(o);
+  } else {
+    return fromTimestamp(Timestamp.fromJSON(o));
+  }
+}
+
 function isSet(value: any): boolean {
   return value !== null && value !== undefined;
 }
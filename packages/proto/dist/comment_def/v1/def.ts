@@ -1,5 +1,6 @@
 /* eslint-disable */
 import _m0 from "protobufjs/minimal";
+import { Timestamp } from "../../google/protobuf/timestamp";
 import { BareUser } from "../../user_def/v1/def";
 
 export const protobufPackage = "comment_def.v1";
@@ -18,7 +19,11 @@ export interface GetCommentResponse {
   story_slug: string;
   story_writer_username: string;
   hidden: boolean;
-  edited_at?: string | undefined;
+  /** @deprecated */
+  edited_at?:
+    | string
+    | undefined;
+  /** @deprecated */
   created_at: string;
   like_count: number;
   reply_count: number;
@@ -27,6 +32,20 @@ export interface GetCommentResponse {
     | undefined;
   /** User specific props */
   is_liked: boolean;
+  /**
+   * Moderation
+   * @deprecated
+   */
+  deleted_at?:
+    | string
+    | undefined;
+  /** Timestamps */
+  edited_at_ts?: Date | undefined;
+  created_at_ts:
+    | Date
+    | undefined;
+  /** Set when the comment has been taken down */
+  deleted_at_ts?: Date | undefined;
 }
 
 function createBaseGetCommentRequest(): GetCommentRequest {
@@ -119,6 +138,10 @@ function createBaseGetCommentResponse(): GetCommentResponse {
     reply_count: 0,
     user: undefined,
     is_liked: false,
+    deleted_at: undefined,
+    edited_at_ts: undefined,
+    created_at_ts: undefined,
+    deleted_at_ts: undefined,
   };
 }
 
@@ -166,6 +189,18 @@ export const GetCommentResponse = {
     if (message.is_liked === true) {
       writer.uint32(112).bool(message.is_liked);
     }
+    if (message.deleted_at !== undefined) {
+      writer.uint32(122).string(message.deleted_at);
+    }
+    if (message.edited_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.edited_at_ts), writer.uint32(130).fork()).ldelim();
+    }
+    if (message.created_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at_ts), writer.uint32(138).fork()).ldelim();
+    }
+    if (message.deleted_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.deleted_at_ts), writer.uint32(146).fork()).ldelim();
+    }
     return writer;
   },
 
@@ -274,6 +309,34 @@ export const GetCommentResponse = {
 
           message.is_liked = reader.bool();
           continue;
+        case 15:
+          if (tag !== 122) {
+            break;
+          }
+
+          message.deleted_at = reader.string();
+          continue;
+        case 16:
+          if (tag !== 130) {
+            break;
+          }
+
+          message.edited_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 17:
+          if (tag !== 138) {
+            break;
+          }
+
+          message.created_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 18:
+          if (tag !== 146) {
+            break;
+          }
+
+          message.deleted_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -299,6 +362,10 @@ export const GetCommentResponse = {
       reply_count: isSet(object.reply_count) ? globalThis.Number(object.reply_count) : 0,
       user: isSet(object.user) ? BareUser.fromJSON(object.user) : undefined,
       is_liked: isSet(object.is_liked) ? globalThis.Boolean(object.is_liked) : false,
+      deleted_at: isSet(object.deleted_at) ? globalThis.String(object.deleted_at) : undefined,
+      edited_at_ts: isSet(object.edited_at_ts) ? fromJsonTimestamp(object.edited_at_ts) : undefined,
+      created_at_ts: isSet(object.created_at_ts) ? fromJsonTimestamp(object.created_at_ts) : undefined,
+      deleted_at_ts: isSet(object.deleted_at_ts) ? fromJsonTimestamp(object.deleted_at_ts) : undefined,
     };
   },
 
@@ -346,6 +413,18 @@ export const GetCommentResponse = {
     if (message.is_liked === true) {
       obj.is_liked = message.is_liked;
     }
+    if (message.deleted_at !== undefined) {
+      obj.deleted_at = message.deleted_at;
+    }
+    if (message.edited_at_ts !== undefined) {
+      obj.edited_at_ts = message.edited_at_ts.toISOString();
+    }
+    if (message.created_at_ts !== undefined) {
+      obj.created_at_ts = message.created_at_ts.toISOString();
+    }
+    if (message.deleted_at_ts !== undefined) {
+      obj.deleted_at_ts = message.deleted_at_ts.toISOString();
+    }
     return obj;
   },
 
@@ -368,6 +447,10 @@ export const GetCommentResponse = {
     message.reply_count = object.reply_count ?? 0;
     message.user = (object.user !== undefined && object.user !== null) ? BareUser.fromPartial(object.user) : undefined;
     message.is_liked = object.is_liked ?? false;
+    message.deleted_at = object.deleted_at ?? undefined;
+    message.edited_at_ts = object.edited_at_ts ?? undefined;
+    message.created_at_ts = object.created_at_ts ?? undefined;
+    message.deleted_at_ts = object.deleted_at_ts ?? undefined;
     return message;
   },
 };
@@ -384,6 +467,28 @@ type KeysOfUnion<T> = T extends T ? keyof T : never;
 export type Exact<P, I extends P> = P extends Builtin ? P
   : P & { [K in keyof P]: Exact<P[K], I[K]> } & { [K in Exclude<keyof I, KeysOfUnion<P>>]: never };
 
+function toTimestamp(date: Date): Timestamp {
+  const seconds = Math.trunc(date.getTime() / 1_000);
+  const nanos = (date.getTime() % 1_000) * 1_000_000;
+  return { seconds, nanos };
+}
+
+function fromTimestamp(t: Timestamp): Date {
+  let millis = (t.seconds || 0) * 1_000;
+  millis += (t.nanos || 0) / 1_000_000;
+  return new globalThis.Date(millis);
+}
+
+function fromJsonTimestamp(o: any): Date {
+  if (o instanceof globalThis.Date) {
+    return o;
+  } else if (typeof o === "string") {
+    return new globalThis.Date(o);
+  } else {
+    return fromTimestamp(Timestamp.fromJSON(o));
+  }
+}
+
 function isSet(value: any): boolean {
   return value !== null && value !== undefined;
 }
@@ -1,25 +1,130 @@
 /* eslint-disable */
 import _m0 from "protobufjs/minimal";
+import { Timestamp } from "../../google/protobuf/timestamp";
 
 export const protobufPackage = "connection_def.v1";
 
+export const ConnectionProvider = {
+  UNSPECIFIED: 0,
+  DISCORD: 1,
+  DRIBBBLE: 2,
+  FIGMA: 3,
+  GITHUB: 4,
+  LINKEDIN: 5,
+  REDDIT: 6,
+  SNAPCHAT: 7,
+  SPOTIFY: 8,
+  TWITCH: 9,
+  YOUTUBE: 10,
+  UNRECOGNIZED: -1,
+} as const;
+
+export type ConnectionProvider = typeof ConnectionProvider[keyof typeof ConnectionProvider];
+
+export function connectionProviderFromJSON(object: any): ConnectionProvider {
+  switch (object) {
+    case 0:
+    case "CONNECTION_PROVIDER_UNSPECIFIED":
+      return ConnectionProvider.UNSPECIFIED;
+    case 1:
+    case "CONNECTION_PROVIDER_DISCORD":
+      return ConnectionProvider.DISCORD;
+    case 2:
+    case "CONNECTION_PROVIDER_DRIBBBLE":
+      return ConnectionProvider.DRIBBBLE;
+    case 3:
+    case "CONNECTION_PROVIDER_FIGMA":
+      return ConnectionProvider.FIGMA;
+    case 4:
+    case "CONNECTION_PROVIDER_GITHUB":
+      return ConnectionProvider.GITHUB;
+    case 5:
+    case "CONNECTION_PROVIDER_LINKEDIN":
+      return ConnectionProvider.LINKEDIN;
+    case 6:
+    case "CONNECTION_PROVIDER_REDDIT":
+      return ConnectionProvider.REDDIT;
+    case 7:
+    case "CONNECTION_PROVIDER_SNAPCHAT":
+      return ConnectionProvider.SNAPCHAT;
+    case 8:
+    case "CONNECTION_PROVIDER_SPOTIFY":
+      return ConnectionProvider.SPOTIFY;
+    case 9:
+    case "CONNECTION_PROVIDER_TWITCH":
+      return ConnectionProvider.TWITCH;
+    case 10:
+    case "CONNECTION_PROVIDER_YOUTUBE":
+      return ConnectionProvider.YOUTUBE;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return ConnectionProvider.UNRECOGNIZED;
+  }
+}
+
+export function connectionProviderToJSON(object: ConnectionProvider): string {
+  switch (object) {
+    case ConnectionProvider.UNSPECIFIED:
+      return "CONNECTION_PROVIDER_UNSPECIFIED";
+    case ConnectionProvider.DISCORD:
+      return "CONNECTION_PROVIDER_DISCORD";
+    case ConnectionProvider.DRIBBBLE:
+      return "CONNECTION_PROVIDER_DRIBBBLE";
+    case ConnectionProvider.FIGMA:
+      return "CONNECTION_PROVIDER_FIGMA";
+    case ConnectionProvider.GITHUB:
+      return "CONNECTION_PROVIDER_GITHUB";
+    case ConnectionProvider.LINKEDIN:
+      return "CONNECTION_PROVIDER_LINKEDIN";
+    case ConnectionProvider.REDDIT:
+      return "CONNECTION_PROVIDER_REDDIT";
+    case ConnectionProvider.SNAPCHAT:
+      return "CONNECTION_PROVIDER_SNAPCHAT";
+    case ConnectionProvider.SPOTIFY:
+      return "CONNECTION_PROVIDER_SPOTIFY";
+    case ConnectionProvider.TWITCH:
+      return "CONNECTION_PROVIDER_TWITCH";
+    case ConnectionProvider.YOUTUBE:
+      return "CONNECTION_PROVIDER_YOUTUBE";
+    case ConnectionProvider.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
 export interface Connection {
+  /**
+   * Slug of the provider, e.g. `github`
+   * @deprecated
+   */
   provider: string;
   url: string;
   display_name: string;
+  /** Replaces `provider` */
+  provider_type: ConnectionProvider;
 }
 
 export interface ConnectionSetting {
   id: string;
+  /**
+   * Slug of the provider, e.g. `github`
+   * @deprecated
+   */
   provider: string;
   hidden: boolean;
   display_name: string;
   url: string;
+  /** @deprecated */
   created_at: string;
+  /** Replaces `provider` */
+  provider_type: ConnectionProvider;
+  /** Timestamps */
+  created_at_ts: Date | undefined;
 }
 
 function createBaseConnection(): Connection {
-  return { provider: "", url: "", display_name: "" };
+  return { provider: "", url: "", display_name: "", provider_type: 0 };
 }
 
 export const Connection = {
@@ -33,6 +138,9 @@ export const Connection = {
     if (message.display_name !== "") {
       writer.uint32(26).string(message.display_name);
     }
+    if (message.provider_type !== 0) {
+      writer.uint32(32).int32(message.provider_type);
+    }
     return writer;
   },
 
@@ -64,6 +172,13 @@ export const Connection = {
 
           message.display_name = reader.string();
           continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.provider_type = reader.int32() as any;
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -78,6 +193,7 @@ export const Connection = {
       provider: isSet(object.provider) ? globalThis.String(object.provider) : "",
       url: isSet(object.url) ? globalThis.String(object.url) : "",
       display_name: isSet(object.display_name) ? globalThis.String(object.display_name) : "",
+      provider_type: isSet(object.provider_type) ? connectionProviderFromJSON(object.provider_type) : 0,
     };
   },
 
@@ -92,6 +208,9 @@ export const Connection = {
     if (message.display_name !== "") {
       obj.display_name = message.display_name;
     }
+    if (message.provider_type !== 0) {
+      obj.provider_type = connectionProviderToJSON(message.provider_type);
+    }
     return obj;
   },
 
@@ -103,12 +222,22 @@ export const Connection = {
     message.provider = object.provider ?? "";
     message.url = object.url ?? "";
     message.display_name = object.display_name ?? "";
+    message.provider_type = object.provider_type ?? 0;
     return message;
   },
 };
 
 function createBaseConnectionSetting(): ConnectionSetting {
-  return { id: "", provider: "", hidden: false, display_name: "", url: "", created_at: "" };
+  return {
+    id: "",
+    provider: "",
+    hidden: false,
+    display_name: "",
+    url: "",
+    created_at: "",
+    provider_type: 0,
+    created_at_ts: undefined,
+  };
 }
 
 export const ConnectionSetting = {
@@ -131,6 +260,12 @@ export const ConnectionSetting = {
     if (message.created_at !== "") {
       writer.uint32(50).string(message.created_at);
     }
+    if (message.provider_type !== 0) {
+      writer.uint32(64).int32(message.provider_type);
+    }
+    if (message.created_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at_ts), writer.uint32(58).fork()).ldelim();
+    }
     return writer;
   },
 
@@ -183,6 +318,20 @@ export const ConnectionSetting = {
 
           message.created_at = reader.string();
           continue;
+        case 8:
+          if (tag !== 64) {
+            break;
+          }
+
+          message.provider_type = reader.int32() as any;
+          continue;
+        case 7:
+          if (tag !== 58) {
+            break;
+          }
+
+          message.created_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -200,6 +349,8 @@ export const ConnectionSetting = {
       display_name: isSet(object.display_name) ? globalThis.String(object.display_name) : "",
       url: isSet(object.url) ? globalThis.String(object.url) : "",
       created_at: isSet(object.created_at) ? globalThis.String(object.created_at) : "",
+      provider_type: isSet(object.provider_type) ? connectionProviderFromJSON(object.provider_type) : 0,
+      created_at_ts: isSet(object.created_at_ts) ? fromJsonTimestamp(object.created_at_ts) : undefined,
     };
   },
 
@@ -223,6 +374,12 @@ export const ConnectionSetting = {
     if (message.created_at !== "") {
       obj.created_at = message.created_at;
     }
+    if (message.provider_type !== 0) {
+      obj.provider_type = connectionProviderToJSON(message.provider_type);
+    }
+    if (message.created_at_ts !== undefined) {
+      obj.created_at_ts = message.created_at_ts.toISOString();
+    }
     return obj;
   },
 
@@ -237,6 +394,8 @@ export const ConnectionSetting = {
     message.display_name = object.display_name ?? "";
     message.url = object.url ?? "";
     message.created_at = object.created_at ?? "";
+    message.provider_type = object.provider_type ?? 0;
+    message.created_at_ts = object.created_at_ts ?? undefined;
     return message;
   },
 };
@@ -253,6 +412,28 @@ type KeysOfUnion<T> = T extends T ? keyof T : never;
 export type Exact<P, I extends P> = P extends Builtin ? P
   : P & { [K in keyof P]: Exact<P[K], I[K]> } & { [K in Exclude<keyof I, KeysOfUnion<P>>]: never };
 
+function toTimestamp(date: Date): Timestamp {
+  const seconds = Math.trunc(date.getTime() / 1_000);
+  const nanos = (date.getTime() % 1_000) * 1_000_000;
+  return { seconds, nanos };
+}
+
+function fromTimestamp(t: Timestamp): Date {
+  let millis = (t.seconds || 0) * 1_000;
+  millis += (t.nanos || 0) / 1_000_000;
+  return new globalThis.Date(millis);
+}
+
+function fromJsonTimestamp(o: any): Date {
+  if (o instanceof globalThis.Date) {
+    return o;
+  } else if (typeof o === "string") {
+    return new globalThis.Date(o);
+  } else {
+    return fromTimestamp(Timestamp.fromJSON(o));
+  }
+}
+
 function isSet(value: any): boolean {
   return value !== null && value !== undefined;
 }
@@ -1,6 +1,6 @@
 /* eslint-disable */
 import _m0 from "protobufjs/minimal";
-import { ConnectionSetting } from "../../connection_def/v1/def";
+import { ConnectionProvider, ConnectionSetting } from "../../connection_def/v1/def";
 
 export const protobufPackage = "connection_settings_def.v1";
 
@@ -12,6 +12,44 @@ export interface GetConnectionSettingsResponse {
   connections: ConnectionSetting[];
 }
 
+export interface AddConnectionRequest {
+  user_id: string;
+  provider: ConnectionProvider;
+  /** Authorization code from the OAuth callback */
+  code: string;
+  redirect_uri: string;
+}
+
+export interface AddConnectionResponse {
+  connection: ConnectionSetting | undefined;
+}
+
+export interface SetConnectionHiddenRequest {
+  user_id: string;
+  connection_id: string;
+  hidden: boolean;
+}
+
+export interface SetConnectionHiddenResponse {
+}
+
+export interface RemoveConnectionRequest {
+  user_id: string;
+  connection_id: string;
+}
+
+export interface RemoveConnectionResponse {
+}
+
+export interface RefreshConnectionRequest {
+  user_id: string;
+  connection_id: string;
+}
+
+export interface RefreshConnectionResponse {
+  connection: ConnectionSetting | undefined;
+}
+
 function createBaseGetConnectionSettingsRequest(): GetConnectionSettingsRequest {
   return { user_id: "" };
 }
@@ -132,6 +170,551 @@ export const GetConnectionSettingsResponse = {
   },
 };
 
+function createBaseAddConnectionRequest(): AddConnectionRequest {
+  return { user_id: "", provider: 0, code: "", redirect_uri: "" };
+}
+
+export const AddConnectionRequest = {
+  encode(message: AddConnectionRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.provider !== 0) {
+      writer.uint32(16).int32(message.provider);
+    }
+    if (message.code !== "") {
+      writer.uint32(26).string(message.code);
+    }
+    if (message.redirect_uri !== "") {
+      writer.uint32(34).string(message.redirect_uri);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): AddConnectionRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseAddConnectionRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.provider = reader.int32() as any;
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.code = reader.string();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.redirect_uri = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): AddConnectionRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      provider: isSet(object.provider) ? connectionProviderFromJSON(object.provider) : 0,
+      code: isSet(object.code) ? globalThis.String(object.code) : "",
+      redirect_uri: isSet(object.redirect_uri) ? globalThis.String(object.redirect_uri) : "",
+    };
+  },
+
+  toJSON(message: AddConnectionRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.provider !== 0) {
+      obj.provider = connectionProviderToJSON(message.provider);
+    }
+    if (message.code !== "") {
+      obj.code = message.code;
+    }
+    if (message.redirect_uri !== "") {
+      obj.redirect_uri = message.redirect_uri;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<AddConnectionRequest>, I>>(base?: I): AddConnectionRequest {
+    return AddConnectionRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<AddConnectionRequest>, I>>(object: I): AddConnectionRequest {
+    const message = createBaseAddConnectionRequest();
+    message.user_id = object.user_id ?? "";
+    message.provider = object.provider ?? 0;
+    message.code = object.code ?? "";
+    message.redirect_uri = object.redirect_uri ?? "";
+    return message;
+  },
+};
+
+function createBaseAddConnectionResponse(): AddConnectionResponse {
+  return { connection: undefined };
+}
+
+export const AddConnectionResponse = {
+  encode(message: AddConnectionResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.connection !== undefined) {
+      ConnectionSetting.encode(message.connection, writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): AddConnectionResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseAddConnectionResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.connection = ConnectionSetting.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): AddConnectionResponse {
+    return { connection: isSet(object.connection) ? ConnectionSetting.fromJSON(object.connection) : undefined };
+  },
+
+  toJSON(message: AddConnectionResponse): unknown {
+    const obj: any = {};
+    if (message.connection !== undefined) {
+      obj.connection = ConnectionSetting.toJSON(message.connection);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<AddConnectionResponse>, I>>(base?: I): AddConnectionResponse {
+    return AddConnectionResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<AddConnectionResponse>, I>>(object: I): AddConnectionResponse {
+    const message = createBaseAddConnectionResponse();
+    message.connection = (object.connection !== undefined && object.connection !== null)
+      ? ConnectionSetting.fromPartial(object.connection)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseSetConnectionHiddenRequest(): SetConnectionHiddenRequest {
+  return { user_id: "", connection_id: "", hidden: false };
+}
+
+export const SetConnectionHiddenRequest = {
+  encode(message: SetConnectionHiddenRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.connection_id !== "") {
+      writer.uint32(18).string(message.connection_id);
+    }
+    if (message.hidden === true) {
+      writer.uint32(24).bool(message.hidden);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): SetConnectionHiddenRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSetConnectionHiddenRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.connection_id = reader.string();
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.hidden = reader.bool();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SetConnectionHiddenRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      connection_id: isSet(object.connection_id) ? globalThis.String(object.connection_id) : "",
+      hidden: isSet(object.hidden) ? globalThis.Boolean(object.hidden) : false,
+    };
+  },
+
+  toJSON(message: SetConnectionHiddenRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.connection_id !== "") {
+      obj.connection_id = message.connection_id;
+    }
+    if (message.hidden === true) {
+      obj.hidden = message.hidden;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SetConnectionHiddenRequest>, I>>(base?: I): SetConnectionHiddenRequest {
+    return SetConnectionHiddenRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SetConnectionHiddenRequest>, I>>(object: I): SetConnectionHiddenRequest {
+    const message = createBaseSetConnectionHiddenRequest();
+    message.user_id = object.user_id ?? "";
+    message.connection_id = object.connection_id ?? "";
+    message.hidden = object.hidden ?? false;
+    return message;
+  },
+};
+
+function createBaseSetConnectionHiddenResponse(): SetConnectionHiddenResponse {
+  return {};
+}
+
+export const SetConnectionHiddenResponse = {
+  encode(_: SetConnectionHiddenResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): SetConnectionHiddenResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSetConnectionHiddenResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(_: any): SetConnectionHiddenResponse {
+    return {};
+  },
+
+  toJSON(_: SetConnectionHiddenResponse): unknown {
+    const obj: any = {};
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SetConnectionHiddenResponse>, I>>(base?: I): SetConnectionHiddenResponse {
+    return SetConnectionHiddenResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SetConnectionHiddenResponse>, I>>(_: I): SetConnectionHiddenResponse {
+    const message = createBaseSetConnectionHiddenResponse();
+    return message;
+  },
+};
+
+function createBaseRemoveConnectionRequest(): RemoveConnectionRequest {
+  return { user_id: "", connection_id: "" };
+}
+
+export const RemoveConnectionRequest = {
+  encode(message: RemoveConnectionRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.connection_id !== "") {
+      writer.uint32(18).string(message.connection_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): RemoveConnectionRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRemoveConnectionRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.connection_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): RemoveConnectionRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      connection_id: isSet(object.connection_id) ? globalThis.String(object.connection_id) : "",
+    };
+  },
+
+  toJSON(message: RemoveConnectionRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.connection_id !== "") {
+      obj.connection_id = message.connection_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RemoveConnectionRequest>, I>>(base?: I): RemoveConnectionRequest {
+    return RemoveConnectionRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RemoveConnectionRequest>, I>>(object: I): RemoveConnectionRequest {
+    const message = createBaseRemoveConnectionRequest();
+    message.user_id = object.user_id ?? "";
+    message.connection_id = object.connection_id ?? "";
+    return message;
+  },
+};
+
+function createBaseRemoveConnectionResponse(): RemoveConnectionResponse {
+  return {};
+}
+
+export const RemoveConnectionResponse = {
+  encode(_: RemoveConnectionResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): RemoveConnectionResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRemoveConnectionResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(_: any): RemoveConnectionResponse {
+    return {};
+  },
+
+  toJSON(_: RemoveConnectionResponse): unknown {
+    const obj: any = {};
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RemoveConnectionResponse>, I>>(base?: I): RemoveConnectionResponse {
+    return RemoveConnectionResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RemoveConnectionResponse>, I>>(_: I): RemoveConnectionResponse {
+    const message = createBaseRemoveConnectionResponse();
+    return message;
+  },
+};
+
+function createBaseRefreshConnectionRequest(): RefreshConnectionRequest {
+  return { user_id: "", connection_id: "" };
+}
+
+export const RefreshConnectionRequest = {
+  encode(message: RefreshConnectionRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.connection_id !== "") {
+      writer.uint32(18).string(message.connection_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): RefreshConnectionRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRefreshConnectionRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.connection_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): RefreshConnectionRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      connection_id: isSet(object.connection_id) ? globalThis.String(object.connection_id) : "",
+    };
+  },
+
+  toJSON(message: RefreshConnectionRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.connection_id !== "") {
+      obj.connection_id = message.connection_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RefreshConnectionRequest>, I>>(base?: I): RefreshConnectionRequest {
+    return RefreshConnectionRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RefreshConnectionRequest>, I>>(object: I): RefreshConnectionRequest {
+    const message = createBaseRefreshConnectionRequest();
+    message.user_id = object.user_id ?? "";
+    message.connection_id = object.connection_id ?? "";
+    return message;
+  },
+};
+
+function createBaseRefreshConnectionResponse(): RefreshConnectionResponse {
+  return { connection: undefined };
+}
+
+export const RefreshConnectionResponse = {
+  encode(message: RefreshConnectionResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.connection !== undefined) {
+      ConnectionSetting.encode(message.connection, writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): RefreshConnectionResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRefreshConnectionResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.connection = ConnectionSetting.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): RefreshConnectionResponse {
+    return { connection: isSet(object.connection) ? ConnectionSetting.fromJSON(object.connection) : undefined };
+  },
+
+  toJSON(message: RefreshConnectionResponse): unknown {
+    const obj: any = {};
+    if (message.connection !== undefined) {
+      obj.connection = ConnectionSetting.toJSON(message.connection);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RefreshConnectionResponse>, I>>(base?: I): RefreshConnectionResponse {
+    return RefreshConnectionResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RefreshConnectionResponse>, I>>(object: I): RefreshConnectionResponse {
+    const message = createBaseRefreshConnectionResponse();
+    message.connection = (object.connection !== undefined && object.connection !== null)
+      ? ConnectionSetting.fromPartial(object.connection)
+      : undefined;
+    return message;
+  },
+};
+
 type Builtin = Date | Function | Uint8Array | string | number | boolean | undefined;
 
 export type DeepPartial<T> = T extends Builtin ? T
@@ -1,8 +1,60 @@
 /* eslint-disable */
 import _m0 from "protobufjs/minimal";
+import { Timestamp } from "../../google/protobuf/timestamp";
 
 export const protobufPackage = "credential_settings_def.v1";
 
+export const LoginProvider = { UNSPECIFIED: 0, APPLE: 1, GOOGLE: 2, UNRECOGNIZED: -1 } as const;
+
+export type LoginProvider = typeof LoginProvider[keyof typeof LoginProvider];
+
+export function loginProviderFromJSON(object: any): LoginProvider {
+  switch (object) {
+    case 0:
+    case "LOGIN_PROVIDER_UNSPECIFIED":
+      return LoginProvider.UNSPECIFIED;
+    case 1:
+    case "LOGIN_PROVIDER_APPLE":
+      return LoginProvider.APPLE;
+    case 2:
+    case "LOGIN_PROVIDER_GOOGLE":
+      return LoginProvider.GOOGLE;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return LoginProvider.UNRECOGNIZED;
+  }
+}
+
+export function loginProviderToJSON(object: LoginProvider): string {
+  switch (object) {
+    case LoginProvider.UNSPECIFIED:
+      return "LOGIN_PROVIDER_UNSPECIFIED";
+    case LoginProvider.APPLE:
+      return "LOGIN_PROVIDER_APPLE";
+    case LoginProvider.GOOGLE:
+      return "LOGIN_PROVIDER_GOOGLE";
+    case LoginProvider.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
+export interface LinkedLoginProvider {
+  provider: LoginProvider;
+  /** Subject identifier issued by the provider */
+  provider_user_id: string;
+  email?: string | undefined;
+  created_at: Date | undefined;
+}
+
+export interface Passkey {
+  id: string;
+  name: string;
+  last_used_at?: Date | undefined;
+  created_at: Date | undefined;
+}
+
 export interface GetCredentialSettingsRequest {
   user_id: string;
 }
@@ -10,10 +62,385 @@ export interface GetCredentialSettingsRequest {
 export interface GetCredentialSettingsResponse {
   has_password: boolean;
   mfa_enabled: boolean;
-  login_apple_id?: string | undefined;
+  /**
+   * Use `login_providers`
+   * @deprecated
+   */
+  login_apple_id?:
+    | string
+    | undefined;
+  /**
+   * Use `login_providers`
+   * @deprecated
+   */
   login_google_id?: string | undefined;
+  passkeys: Passkey[];
+  login_providers: LinkedLoginProvider[];
+}
+
+export interface BeginMfaEnrollmentRequest {
+  user_id: string;
+}
+
+export interface BeginMfaEnrollmentResponse {
+  /** Base32 encoded TOTP secret */
+  secret: string;
+  /** `otpauth://` URI for the QR code */
+  otpauth_uri: string;
+}
+
+export interface ConfirmMfaEnrollmentRequest {
+  user_id: string;
+  /** Code generated using the secret from `BeginMfaEnrollment` */
+  code: string;
+}
+
+export interface ConfirmMfaEnrollmentResponse {
+  recovery_codes: string[];
+}
+
+/**
+ * Issued only after the first factor of the login has been verified. The
+ * challenge expires, allows a limited number of attempts, and is consumed by
+ * the first valid code.
+ */
+export interface BeginMfaChallengeRequest {
+  user_id: string;
+}
+
+export interface BeginMfaChallengeResponse {
+  /** Opaque token binding the second factor to the login */
+  challenge_token: string;
+  expires_at: Date | undefined;
+  max_attempts: number;
+}
+
+/**
+ * The user is identified by the challenge. A TOTP code is rejected if its time
+ * step is not after the last one used by the user (RFC 6238, Section 5.2).
+ */
+export interface VerifyMfaCodeRequest {
+  /** TOTP code or an unused recovery code */
+  code: string;
+  /** From `BeginMfaChallenge` */
+  challenge_token: string;
+}
+
+export interface VerifyMfaCodeResponse {
+  is_valid: boolean;
+  /** Recovery codes can only be used once */
+  used_recovery_code: boolean;
+  /** Set when the code is valid */
+  user_id?:
+    | string
+    | undefined;
+  /** The challenge is invalidated once exhausted */
+  remaining_attempts: number;
+}
+
+export interface DisableMfaRequest {
+  user_id: string;
+  /** TOTP code or an unused recovery code */
+  code: string;
+}
+
+export interface DisableMfaResponse {
+}
+
+export interface RegenerateMfaRecoveryCodesRequest {
+  user_id: string;
+  /** TOTP code */
+  code: string;
+}
+
+export interface RegenerateMfaRecoveryCodesResponse {
+  /** Replaces all the previous recovery codes */
+  recovery_codes: string[];
+}
+
+export interface BeginPasskeyRegistrationRequest {
+  user_id: string;
+}
+
+export interface BeginPasskeyRegistrationResponse {
+  challenge_id: string;
+  /** JSON encoded `PublicKeyCredentialCreationOptions` */
+  options: string;
+}
+
+export interface FinishPasskeyRegistrationRequest {
+  user_id: string;
+  challenge_id: string;
+  /** Display name of the authenticator */
+  name: string;
+  /** JSON encoded `RegistrationResponseJSON` from the client */
+  credential: string;
+}
+
+export interface FinishPasskeyRegistrationResponse {
+  passkey: Passkey | undefined;
+}
+
+export interface BeginPasskeyAuthenticationRequest {
+  /** Omitted for discoverable credentials */
+  user_id?: string | undefined;
+}
+
+export interface BeginPasskeyAuthenticationResponse {
+  challenge_id: string;
+  /** JSON encoded `PublicKeyCredentialRequestOptions` */
+  options: string;
+}
+
+export interface FinishPasskeyAuthenticationRequest {
+  challenge_id: string;
+  /** JSON encoded `AuthenticationResponseJSON` from the client */
+  credential: string;
+}
+
+export interface FinishPasskeyAuthenticationResponse {
+  user_id: string;
+  passkey_id: string;
+}
+
+export interface RemovePasskeyRequest {
+  user_id: string;
+  passkey_id: string;
+}
+
+export interface RemovePasskeyResponse {
+}
+
+export interface LinkLoginProviderRequest {
+  user_id: string;
+  provider: LoginProvider;
+  /** Authorization code from the OAuth callback */
+  code: string;
+  redirect_uri: string;
+  /** Nonce sent with the authorization request, required */
+  nonce: string;
+}
+
+export interface LinkLoginProviderResponse {
+  login_provider: LinkedLoginProvider | undefined;
+}
+
+export interface UnlinkLoginProviderRequest {
+  user_id: string;
+  provider: LoginProvider;
+}
+
+export interface UnlinkLoginProviderResponse {
+}
+
+function createBaseLinkedLoginProvider(): LinkedLoginProvider {
+  return { provider: 0, provider_user_id: "", email: undefined, created_at: undefined };
+}
+
+export const LinkedLoginProvider = {
+  encode(message: LinkedLoginProvider, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.provider !== 0) {
+      writer.uint32(8).int32(message.provider);
+    }
+    if (message.provider_user_id !== "") {
+      writer.uint32(18).string(message.provider_user_id);
+    }
+    if (message.email !== undefined) {
+      writer.uint32(26).string(message.email);
+    }
+    if (message.created_at !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at), writer.uint32(34).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): LinkedLoginProvider {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseLinkedLoginProvider();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.provider = reader.int32() as any;
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.provider_user_id = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.email = reader.string();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.created_at = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): LinkedLoginProvider {
+    return {
+      provider: isSet(object.provider) ? loginProviderFromJSON(object.provider) : 0,
+      provider_user_id: isSet(object.provider_user_id) ? globalThis.String(object.provider_user_id) : "",
+      email: isSet(object.email) ? globalThis.String(object.email) : undefined,
+      created_at: isSet(object.created_at) ? fromJsonTimestamp(object.created_at) : undefined,
+    };
+  },
+
+  toJSON(message: LinkedLoginProvider): unknown {
+    const obj: any = {};
+    if (message.provider !== 0) {
+      obj.provider = loginProviderToJSON(message.provider);
+    }
+    if (message.provider_user_id !== "") {
+      obj.provider_user_id = message.provider_user_id;
+    }
+    if (message.email !== undefined) {
+      obj.email = message.email;
+    }
+    if (message.created_at !== undefined) {
+      obj.created_at = message.created_at.toISOString();
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<LinkedLoginProvider>, I>>(base?: I): LinkedLoginProvider {
+    return LinkedLoginProvider.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<LinkedLoginProvider>, I>>(object: I): LinkedLoginProvider {
+    const message = createBaseLinkedLoginProvider();
+    message.provider = object.provider ?? 0;
+    message.provider_user_id = object.provider_user_id ?? "";
+    message.email = object.email ?? undefined;
+    message.created_at = object.created_at ?? undefined;
+    return message;
+  },
+};
+
+function createBasePasskey(): Passkey {
+  return { id: "", name: "", last_used_at: undefined, created_at: undefined };
 }
 
+export const Passkey = {
+  encode(message: Passkey, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.id !== "") {
+      writer.uint32(10).string(message.id);
+    }
+    if (message.name !== "") {
+      writer.uint32(18).string(message.name);
+    }
+    if (message.last_used_at !== undefined) {
+      Timestamp.encode(toTimestamp(message.last_used_at), writer.uint32(26).fork()).ldelim();
+    }
+    if (message.created_at !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at), writer.uint32(34).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): Passkey {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBasePasskey();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.name = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.last_used_at = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.created_at = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): Passkey {
+    return {
+      id: isSet(object.id) ? globalThis.String(object.id) : "",
+      name: isSet(object.name) ? globalThis.String(object.name) : "",
+      last_used_at: isSet(object.last_used_at) ? fromJsonTimestamp(object.last_used_at) : undefined,
+      created_at: isSet(object.created_at) ? fromJsonTimestamp(object.created_at) : undefined,
+    };
+  },
+
+  toJSON(message: Passkey): unknown {
+    const obj: any = {};
+    if (message.id !== "") {
+      obj.id = message.id;
+    }
+    if (message.name !== "") {
+      obj.name = message.name;
+    }
+    if (message.last_used_at !== undefined) {
+      obj.last_used_at = message.last_used_at.toISOString();
+    }
+    if (message.created_at !== undefined) {
+      obj.created_at = message.created_at.toISOString();
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<Passkey>, I>>(base?: I): Passkey {
+    return Passkey.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<Passkey>, I>>(object: I): Passkey {
+    const message = createBasePasskey();
+    message.id = object.id ?? "";
+    message.name = object.name ?? "";
+    message.last_used_at = object.last_used_at ?? undefined;
+    message.created_at = object.created_at ?? undefined;
+    return message;
+  },
+};
+
 function createBaseGetCredentialSettingsRequest(): GetCredentialSettingsRequest {
   return { user_id: "" };
 }
@@ -72,7 +499,14 @@ export const GetCredentialSettingsRequest = {
 };
 
 function createBaseGetCredentialSettingsResponse(): GetCredentialSettingsResponse {
-  return { has_password: false, mfa_enabled: false, login_apple_id: undefined, login_google_id: undefined };
+  return {
+    has_password: false,
+    mfa_enabled: false,
+    login_apple_id: undefined,
+    login_google_id: undefined,
+    passkeys: [],
+    login_providers: [],
+  };
 }
 
 export const GetCredentialSettingsResponse = {
@@ -89,6 +523,12 @@ export const GetCredentialSettingsResponse = {
     if (message.login_google_id !== undefined) {
       writer.uint32(34).string(message.login_google_id);
     }
+    for (const v of message.passkeys) {
+      Passkey.encode(v!, writer.uint32(42).fork()).ldelim();
+    }
+    for (const v of message.login_providers) {
+      LinkedLoginProvider.encode(v!, writer.uint32(50).fork()).ldelim();
+    }
     return writer;
   },
 
@@ -127,6 +567,20 @@ export const GetCredentialSettingsResponse = {
 
           message.login_google_id = reader.string();
           continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.passkeys.push(Passkey.decode(reader, reader.uint32()));
+          continue;
+        case 6:
+          if (tag !== 50) {
+            break;
+          }
+
+          message.login_providers.push(LinkedLoginProvider.decode(reader, reader.uint32()));
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -142,6 +596,10 @@ export const GetCredentialSettingsResponse = {
       mfa_enabled: isSet(object.mfa_enabled) ? globalThis.Boolean(object.mfa_enabled) : false,
       login_apple_id: isSet(object.login_apple_id) ? globalThis.String(object.login_apple_id) : undefined,
       login_google_id: isSet(object.login_google_id) ? globalThis.String(object.login_google_id) : undefined,
+      passkeys: globalThis.Array.isArray(object?.passkeys) ? object.passkeys.map((e: any) => Passkey.fromJSON(e)) : [],
+      login_providers: globalThis.Array.isArray(object?.login_providers)
+        ? object.login_providers.map((e: any) => LinkedLoginProvider.fromJSON(e))
+        : [],
     };
   },
 
@@ -159,6 +617,12 @@ export const GetCredentialSettingsResponse = {
     if (message.login_google_id !== undefined) {
       obj.login_google_id = message.login_google_id;
     }
+    if (message.passkeys?.length) {
+      obj.passkeys = message.passkeys.map((e) => Passkey.toJSON(e));
+    }
+    if (message.login_providers?.length) {
+      obj.login_providers = message.login_providers.map((e) => LinkedLoginProvider.toJSON(e));
+    }
     return obj;
   },
 
@@ -173,21 +637,1912 @@ export const GetCredentialSettingsResponse = {
     message.mfa_enabled = object.mfa_enabled ?? false;
     message.login_apple_id = object.login_apple_id ?? undefined;
     message.login_google_id = object.login_google_id ?? undefined;
+    message.passkeys = object.passkeys?.map((e) => Passkey.fromPartial(e)) || [];
+    message.login_providers = object.login_providers?.map((e) => LinkedLoginProvider.fromPartial(e)) || [];
     return message;
   },
 };
 
-type Builtin = Date | Function | Uint8Array | string | number | boolean | undefined;
+function createBaseBeginMfaEnrollmentRequest(): BeginMfaEnrollmentRequest {
+  return { user_id: "" };
+}
 
-export type DeepPartial<T> = T extends Builtin ? T
-  : T extends globalThis.Array<infer U> ? globalThis.Array<DeepPartial<U>>
-  : T extends ReadonlyArray<infer U> ? ReadonlyArray<DeepPartial<U>>
-  : T extends {} ? { [K in keyof T]?: DeepPartial<T[K]> }
-  : Partial<T>;
+export const BeginMfaEnrollmentRequest = {
+  encode(message: BeginMfaEnrollmentRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    return writer;
+  },
 
-type KeysOfUnion<T> = T extends T ? keyof T : never;
-export type Exact<P, I extends P> = P extends Builtin ? P
-  : P & { [K in keyof P]: Exact<P[K], I[K]> } & { [K in Exclude<keyof I, KeysOfUnion<P>>]: never };
+  decode(input: _m0.Reader | Uint8Array, length?: number): BeginMfaEnrollmentRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseBeginMfaEnrollmentRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): BeginMfaEnrollmentRequest {
+    return { user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "" };
+  },
+
+  toJSON(message: BeginMfaEnrollmentRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<BeginMfaEnrollmentRequest>, I>>(base?: I): BeginMfaEnrollmentRequest {
+    return BeginMfaEnrollmentRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<BeginMfaEnrollmentRequest>, I>>(object: I): BeginMfaEnrollmentRequest {
+    const message = createBaseBeginMfaEnrollmentRequest();
+    message.user_id = object.user_id ?? "";
+    return message;
+  },
+};
+
+function createBaseBeginMfaEnrollmentResponse(): BeginMfaEnrollmentResponse {
+  return { secret: "", otpauth_uri: "" };
+}
+
+export const BeginMfaEnrollmentResponse = {
+  encode(message: BeginMfaEnrollmentResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.secret !== "") {
+      writer.uint32(10).string(message.secret);
+    }
+    if (message.otpauth_uri !== "") {
+      writer.uint32(18).string(message.otpauth_uri);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): BeginMfaEnrollmentResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseBeginMfaEnrollmentResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.secret = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.otpauth_uri = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): BeginMfaEnrollmentResponse {
+    return {
+      secret: isSet(object.secret) ? globalThis.String(object.secret) : "",
+      otpauth_uri: isSet(object.otpauth_uri) ? globalThis.String(object.otpauth_uri) : "",
+    };
+  },
+
+  toJSON(message: BeginMfaEnrollmentResponse): unknown {
+    const obj: any = {};
+    if (message.secret !== "") {
+      obj.secret = message.secret;
+    }
+    if (message.otpauth_uri !== "") {
+      obj.otpauth_uri = message.otpauth_uri;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<BeginMfaEnrollmentResponse>, I>>(base?: I): BeginMfaEnrollmentResponse {
+    return BeginMfaEnrollmentResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<BeginMfaEnrollmentResponse>, I>>(object: I): BeginMfaEnrollmentResponse {
+    const message = createBaseBeginMfaEnrollmentResponse();
+    message.secret = object.secret ?? "";
+    message.otpauth_uri = object.otpauth_uri ?? "";
+    return message;
+  },
+};
+
+function createBaseConfirmMfaEnrollmentRequest(): ConfirmMfaEnrollmentRequest {
+  return { user_id: "", code: "" };
+}
+
+export const ConfirmMfaEnrollmentRequest = {
+  encode(message: ConfirmMfaEnrollmentRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.code !== "") {
+      writer.uint32(18).string(message.code);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ConfirmMfaEnrollmentRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseConfirmMfaEnrollmentRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.code = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ConfirmMfaEnrollmentRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      code: isSet(object.code) ? globalThis.String(object.code) : "",
+    };
+  },
+
+  toJSON(message: ConfirmMfaEnrollmentRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.code !== "") {
+      obj.code = message.code;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ConfirmMfaEnrollmentRequest>, I>>(base?: I): ConfirmMfaEnrollmentRequest {
+    return ConfirmMfaEnrollmentRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ConfirmMfaEnrollmentRequest>, I>>(object: I): ConfirmMfaEnrollmentRequest {
+    const message = createBaseConfirmMfaEnrollmentRequest();
+    message.user_id = object.user_id ?? "";
+    message.code = object.code ?? "";
+    return message;
+  },
+};
+
+function createBaseConfirmMfaEnrollmentResponse(): ConfirmMfaEnrollmentResponse {
+  return { recovery_codes: [] };
+}
+
+export const ConfirmMfaEnrollmentResponse = {
+  encode(message: ConfirmMfaEnrollmentResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    for (const v of message.recovery_codes) {
+      writer.uint32(10).string(v!);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ConfirmMfaEnrollmentResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseConfirmMfaEnrollmentResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.recovery_codes.push(reader.string());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ConfirmMfaEnrollmentResponse {
+    return {
+      recovery_codes: globalThis.Array.isArray(object?.recovery_codes)
+        ? object.recovery_codes.map((e: any) => globalThis.String(e))
+        : [],
+    };
+  },
+
+  toJSON(message: ConfirmMfaEnrollmentResponse): unknown {
+    const obj: any = {};
+    if (message.recovery_codes?.length) {
+      obj.recovery_codes = message.recovery_codes;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ConfirmMfaEnrollmentResponse>, I>>(base?: I): ConfirmMfaEnrollmentResponse {
+    return ConfirmMfaEnrollmentResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ConfirmMfaEnrollmentResponse>, I>>(object: I): ConfirmMfaEnrollmentResponse {
+    const message = createBaseConfirmMfaEnrollmentResponse();
+    message.recovery_codes = object.recovery_codes?.map((e) => e) || [];
+    return message;
+  },
+};
+
+function createBaseBeginMfaChallengeRequest(): BeginMfaChallengeRequest {
+  return { user_id: "" };
+}
+
+export const BeginMfaChallengeRequest = {
+  encode(message: BeginMfaChallengeRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): BeginMfaChallengeRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseBeginMfaChallengeRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): BeginMfaChallengeRequest {
+    return { user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "" };
+  },
+
+  toJSON(message: BeginMfaChallengeRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<BeginMfaChallengeRequest>, I>>(base?: I): BeginMfaChallengeRequest {
+    return BeginMfaChallengeRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<BeginMfaChallengeRequest>, I>>(object: I): BeginMfaChallengeRequest {
+    const message = createBaseBeginMfaChallengeRequest();
+    message.user_id = object.user_id ?? "";
+    return message;
+  },
+};
+
+function createBaseBeginMfaChallengeResponse(): BeginMfaChallengeResponse {
+  return { challenge_token: "", expires_at: undefined, max_attempts: 0 };
+}
+
+export const BeginMfaChallengeResponse = {
+  encode(message: BeginMfaChallengeResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.challenge_token !== "") {
+      writer.uint32(10).string(message.challenge_token);
+    }
+    if (message.expires_at !== undefined) {
+      Timestamp.encode(toTimestamp(message.expires_at), writer.uint32(18).fork()).ldelim();
+    }
+    if (message.max_attempts !== 0) {
+      writer.uint32(24).uint32(message.max_attempts);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): BeginMfaChallengeResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseBeginMfaChallengeResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.challenge_token = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.expires_at = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.max_attempts = reader.uint32();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): BeginMfaChallengeResponse {
+    return {
+      challenge_token: isSet(object.challenge_token) ? globalThis.String(object.challenge_token) : "",
+      expires_at: isSet(object.expires_at) ? fromJsonTimestamp(object.expires_at) : undefined,
+      max_attempts: isSet(object.max_attempts) ? globalThis.Number(object.max_attempts) : 0,
+    };
+  },
+
+  toJSON(message: BeginMfaChallengeResponse): unknown {
+    const obj: any = {};
+    if (message.challenge_token !== "") {
+      obj.challenge_token = message.challenge_token;
+    }
+    if (message.expires_at !== undefined) {
+      obj.expires_at = message.expires_at.toISOString();
+    }
+    if (message.max_attempts !== 0) {
+      obj.max_attempts = Math.round(message.max_attempts);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<BeginMfaChallengeResponse>, I>>(base?: I): BeginMfaChallengeResponse {
+    return BeginMfaChallengeResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<BeginMfaChallengeResponse>, I>>(object: I): BeginMfaChallengeResponse {
+    const message = createBaseBeginMfaChallengeResponse();
+    message.challenge_token = object.challenge_token ?? "";
+    message.expires_at = object.expires_at ?? undefined;
+    message.max_attempts = object.max_attempts ?? 0;
+    return message;
+  },
+};
+
+function createBaseVerifyMfaCodeRequest(): VerifyMfaCodeRequest {
+  return { code: "", challenge_token: "" };
+}
+
+export const VerifyMfaCodeRequest = {
+  encode(message: VerifyMfaCodeRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.code !== "") {
+      writer.uint32(18).string(message.code);
+    }
+    if (message.challenge_token !== "") {
+      writer.uint32(26).string(message.challenge_token);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): VerifyMfaCodeRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseVerifyMfaCodeRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.code = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.challenge_token = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): VerifyMfaCodeRequest {
+    return {
+      code: isSet(object.code) ? globalThis.String(object.code) : "",
+      challenge_token: isSet(object.challenge_token) ? globalThis.String(object.challenge_token) : "",
+    };
+  },
+
+  toJSON(message: VerifyMfaCodeRequest): unknown {
+    const obj: any = {};
+    if (message.code !== "") {
+      obj.code = message.code;
+    }
+    if (message.challenge_token !== "") {
+      obj.challenge_token = message.challenge_token;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<VerifyMfaCodeRequest>, I>>(base?: I): VerifyMfaCodeRequest {
+    return VerifyMfaCodeRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<VerifyMfaCodeRequest>, I>>(object: I): VerifyMfaCodeRequest {
+    const message = createBaseVerifyMfaCodeRequest();
+    message.code = object.code ?? "";
+    message.challenge_token = object.challenge_token ?? "";
+    return message;
+  },
+};
+
+function createBaseVerifyMfaCodeResponse(): VerifyMfaCodeResponse {
+  return { is_valid: false, used_recovery_code: false, user_id: undefined, remaining_attempts: 0 };
+}
+
+export const VerifyMfaCodeResponse = {
+  encode(message: VerifyMfaCodeResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.is_valid === true) {
+      writer.uint32(8).bool(message.is_valid);
+    }
+    if (message.used_recovery_code === true) {
+      writer.uint32(16).bool(message.used_recovery_code);
+    }
+    if (message.user_id !== undefined) {
+      writer.uint32(26).string(message.user_id);
+    }
+    if (message.remaining_attempts !== 0) {
+      writer.uint32(32).uint32(message.remaining_attempts);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): VerifyMfaCodeResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseVerifyMfaCodeResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.is_valid = reader.bool();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.used_recovery_code = reader.bool();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.remaining_attempts = reader.uint32();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): VerifyMfaCodeResponse {
+    return {
+      is_valid: isSet(object.is_valid) ? globalThis.Boolean(object.is_valid) : false,
+      used_recovery_code: isSet(object.used_recovery_code) ? globalThis.Boolean(object.used_recovery_code) : false,
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : undefined,
+      remaining_attempts: isSet(object.remaining_attempts) ? globalThis.Number(object.remaining_attempts) : 0,
+    };
+  },
+
+  toJSON(message: VerifyMfaCodeResponse): unknown {
+    const obj: any = {};
+    if (message.is_valid === true) {
+      obj.is_valid = message.is_valid;
+    }
+    if (message.used_recovery_code === true) {
+      obj.used_recovery_code = message.used_recovery_code;
+    }
+    if (message.user_id !== undefined) {
+      obj.user_id = message.user_id;
+    }
+    if (message.remaining_attempts !== 0) {
+      obj.remaining_attempts = Math.round(message.remaining_attempts);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<VerifyMfaCodeResponse>, I>>(base?: I): VerifyMfaCodeResponse {
+    return VerifyMfaCodeResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<VerifyMfaCodeResponse>, I>>(object: I): VerifyMfaCodeResponse {
+    const message = createBaseVerifyMfaCodeResponse();
+    message.is_valid = object.is_valid ?? false;
+    message.used_recovery_code = object.used_recovery_code ?? false;
+    message.user_id = object.user_id ?? undefined;
+    message.remaining_attempts = object.remaining_attempts ?? 0;
+    return message;
+  },
+};
+
+function createBaseDisableMfaRequest(): DisableMfaRequest {
+  return { user_id: "", code: "" };
+}
+
+export const DisableMfaRequest = {
+  encode(message: DisableMfaRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.code !== "") {
+      writer.uint32(18).string(message.code);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): DisableMfaRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseDisableMfaRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.code = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): DisableMfaRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      code: isSet(object.code) ? globalThis.String(object.code) : "",
+    };
+  },
+
+  toJSON(message: DisableMfaRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.code !== "") {
+      obj.code = message.code;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<DisableMfaRequest>, I>>(base?: I): DisableMfaRequest {
+    return DisableMfaRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<DisableMfaRequest>, I>>(object: I): DisableMfaRequest {
+    const message = createBaseDisableMfaRequest();
+    message.user_id = object.user_id ?? "";
+    message.code = object.code ?? "";
+    return message;
+  },
+};
+
+function createBaseDisableMfaResponse(): DisableMfaResponse {
+  return {};
+}
+
+export const DisableMfaResponse = {
+  encode(_: DisableMfaResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): DisableMfaResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseDisableMfaResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(_: any): DisableMfaResponse {
+    return {};
+  },
+
+  toJSON(_: DisableMfaResponse): unknown {
+    const obj: any = {};
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<DisableMfaResponse>, I>>(base?: I): DisableMfaResponse {
+    return DisableMfaResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<DisableMfaResponse>, I>>(_: I): DisableMfaResponse {
+    const message = createBaseDisableMfaResponse();
+    return message;
+  },
+};
+
+function createBaseRegenerateMfaRecoveryCodesRequest(): RegenerateMfaRecoveryCodesRequest {
+  return { user_id: "", code: "" };
+}
+
+export const RegenerateMfaRecoveryCodesRequest = {
+  encode(message: RegenerateMfaRecoveryCodesRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.code !== "") {
+      writer.uint32(18).string(message.code);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): RegenerateMfaRecoveryCodesRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRegenerateMfaRecoveryCodesRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.code = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): RegenerateMfaRecoveryCodesRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      code: isSet(object.code) ? globalThis.String(object.code) : "",
+    };
+  },
+
+  toJSON(message: RegenerateMfaRecoveryCodesRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.code !== "") {
+      obj.code = message.code;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RegenerateMfaRecoveryCodesRequest>, I>>(
+    base?: I,
+  ): RegenerateMfaRecoveryCodesRequest {
+    return RegenerateMfaRecoveryCodesRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RegenerateMfaRecoveryCodesRequest>, I>>(
+    object: I,
+  ): RegenerateMfaRecoveryCodesRequest {
+    const message = createBaseRegenerateMfaRecoveryCodesRequest();
+    message.user_id = object.user_id ?? "";
+    message.code = object.code ?? "";
+    return message;
+  },
+};
+
+function createBaseRegenerateMfaRecoveryCodesResponse(): RegenerateMfaRecoveryCodesResponse {
+  return { recovery_codes: [] };
+}
+
+export const RegenerateMfaRecoveryCodesResponse = {
+  encode(message: RegenerateMfaRecoveryCodesResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    for (const v of message.recovery_codes) {
+      writer.uint32(10).string(v!);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): RegenerateMfaRecoveryCodesResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRegenerateMfaRecoveryCodesResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.recovery_codes.push(reader.string());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): RegenerateMfaRecoveryCodesResponse {
+    return {
+      recovery_codes: globalThis.Array.isArray(object?.recovery_codes)
+        ? object.recovery_codes.map((e: any) => globalThis.String(e))
+        : [],
+    };
+  },
+
+  toJSON(message: RegenerateMfaRecoveryCodesResponse): unknown {
+    const obj: any = {};
+    if (message.recovery_codes?.length) {
+      obj.recovery_codes = message.recovery_codes;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RegenerateMfaRecoveryCodesResponse>, I>>(
+    base?: I,
+  ): RegenerateMfaRecoveryCodesResponse {
+    return RegenerateMfaRecoveryCodesResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RegenerateMfaRecoveryCodesResponse>, I>>(
+    object: I,
+  ): RegenerateMfaRecoveryCodesResponse {
+    const message = createBaseRegenerateMfaRecoveryCodesResponse();
+    message.recovery_codes = object.recovery_codes?.map((e) => e) || [];
+    return message;
+  },
+};
+
+function createBaseBeginPasskeyRegistrationRequest(): BeginPasskeyRegistrationRequest {
+  return { user_id: "" };
+}
+
+export const BeginPasskeyRegistrationRequest = {
+  encode(message: BeginPasskeyRegistrationRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): BeginPasskeyRegistrationRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseBeginPasskeyRegistrationRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): BeginPasskeyRegistrationRequest {
+    return { user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "" };
+  },
+
+  toJSON(message: BeginPasskeyRegistrationRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<BeginPasskeyRegistrationRequest>, I>>(base?: I): BeginPasskeyRegistrationRequest {
+    return BeginPasskeyRegistrationRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<BeginPasskeyRegistrationRequest>, I>>(
+    object: I,
+  ): BeginPasskeyRegistrationRequest {
+    const message = createBaseBeginPasskeyRegistrationRequest();
+    message.user_id = object.user_id ?? "";
+    return message;
+  },
+};
+
+function createBaseBeginPasskeyRegistrationResponse(): BeginPasskeyRegistrationResponse {
+  return { challenge_id: "", options: "" };
+}
+
+export const BeginPasskeyRegistrationResponse = {
+  encode(message: BeginPasskeyRegistrationResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.challenge_id !== "") {
+      writer.uint32(10).string(message.challenge_id);
+    }
+    if (message.options !== "") {
+      writer.uint32(18).string(message.options);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): BeginPasskeyRegistrationResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseBeginPasskeyRegistrationResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.challenge_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.options = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): BeginPasskeyRegistrationResponse {
+    return {
+      challenge_id: isSet(object.challenge_id) ? globalThis.String(object.challenge_id) : "",
+      options: isSet(object.options) ? globalThis.String(object.options) : "",
+    };
+  },
+
+  toJSON(message: BeginPasskeyRegistrationResponse): unknown {
+    const obj: any = {};
+    if (message.challenge_id !== "") {
+      obj.challenge_id = message.challenge_id;
+    }
+    if (message.options !== "") {
+      obj.options = message.options;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<BeginPasskeyRegistrationResponse>, I>>(
+    base?: I,
+  ): BeginPasskeyRegistrationResponse {
+    return BeginPasskeyRegistrationResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<BeginPasskeyRegistrationResponse>, I>>(
+    object: I,
+  ): BeginPasskeyRegistrationResponse {
+    const message = createBaseBeginPasskeyRegistrationResponse();
+    message.challenge_id = object.challenge_id ?? "";
+    message.options = object.options ?? "";
+    return message;
+  },
+};
+
+function createBaseFinishPasskeyRegistrationRequest(): FinishPasskeyRegistrationRequest {
+  return { user_id: "", challenge_id: "", name: "", credential: "" };
+}
+
+export const FinishPasskeyRegistrationRequest = {
+  encode(message: FinishPasskeyRegistrationRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.challenge_id !== "") {
+      writer.uint32(18).string(message.challenge_id);
+    }
+    if (message.name !== "") {
+      writer.uint32(26).string(message.name);
+    }
+    if (message.credential !== "") {
+      writer.uint32(34).string(message.credential);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): FinishPasskeyRegistrationRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseFinishPasskeyRegistrationRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.challenge_id = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.name = reader.string();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.credential = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): FinishPasskeyRegistrationRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      challenge_id: isSet(object.challenge_id) ? globalThis.String(object.challenge_id) : "",
+      name: isSet(object.name) ? globalThis.String(object.name) : "",
+      credential: isSet(object.credential) ? globalThis.String(object.credential) : "",
+    };
+  },
+
+  toJSON(message: FinishPasskeyRegistrationRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.challenge_id !== "") {
+      obj.challenge_id = message.challenge_id;
+    }
+    if (message.name !== "") {
+      obj.name = message.name;
+    }
+    if (message.credential !== "") {
+      obj.credential = message.credential;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<FinishPasskeyRegistrationRequest>, I>>(
+    base?: I,
+  ): FinishPasskeyRegistrationRequest {
+    return FinishPasskeyRegistrationRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<FinishPasskeyRegistrationRequest>, I>>(
+    object: I,
+  ): FinishPasskeyRegistrationRequest {
+    const message = createBaseFinishPasskeyRegistrationRequest();
+    message.user_id = object.user_id ?? "";
+    message.challenge_id = object.challenge_id ?? "";
+    message.name = object.name ?? "";
+    message.credential = object.credential ?? "";
+    return message;
+  },
+};
+
+function createBaseFinishPasskeyRegistrationResponse(): FinishPasskeyRegistrationResponse {
+  return { passkey: undefined };
+}
+
+export const FinishPasskeyRegistrationResponse = {
+  encode(message: FinishPasskeyRegistrationResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.passkey !== undefined) {
+      Passkey.encode(message.passkey, writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): FinishPasskeyRegistrationResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseFinishPasskeyRegistrationResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.passkey = Passkey.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): FinishPasskeyRegistrationResponse {
+    return { passkey: isSet(object.passkey) ? Passkey.fromJSON(object.passkey) : undefined };
+  },
+
+  toJSON(message: FinishPasskeyRegistrationResponse): unknown {
+    const obj: any = {};
+    if (message.passkey !== undefined) {
+      obj.passkey = Passkey.toJSON(message.passkey);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<FinishPasskeyRegistrationResponse>, I>>(
+    base?: I,
+  ): FinishPasskeyRegistrationResponse {
+    return FinishPasskeyRegistrationResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<FinishPasskeyRegistrationResponse>, I>>(
+    object: I,
+  ): FinishPasskeyRegistrationResponse {
+    const message = createBaseFinishPasskeyRegistrationResponse();
+    message.passkey = (object.passkey !== undefined && object.passkey !== null)
+      ? Passkey.fromPartial(object.passkey)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseBeginPasskeyAuthenticationRequest(): BeginPasskeyAuthenticationRequest {
+  return { user_id: undefined };
+}
+
+export const BeginPasskeyAuthenticationRequest = {
+  encode(message: BeginPasskeyAuthenticationRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== undefined) {
+      writer.uint32(10).string(message.user_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): BeginPasskeyAuthenticationRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseBeginPasskeyAuthenticationRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): BeginPasskeyAuthenticationRequest {
+    return { user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : undefined };
+  },
+
+  toJSON(message: BeginPasskeyAuthenticationRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== undefined) {
+      obj.user_id = message.user_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<BeginPasskeyAuthenticationRequest>, I>>(
+    base?: I,
+  ): BeginPasskeyAuthenticationRequest {
+    return BeginPasskeyAuthenticationRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<BeginPasskeyAuthenticationRequest>, I>>(
+    object: I,
+  ): BeginPasskeyAuthenticationRequest {
+    const message = createBaseBeginPasskeyAuthenticationRequest();
+    message.user_id = object.user_id ?? undefined;
+    return message;
+  },
+};
+
+function createBaseBeginPasskeyAuthenticationResponse(): BeginPasskeyAuthenticationResponse {
+  return { challenge_id: "", options: "" };
+}
+
+export const BeginPasskeyAuthenticationResponse = {
+  encode(message: BeginPasskeyAuthenticationResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.challenge_id !== "") {
+      writer.uint32(10).string(message.challenge_id);
+    }
+    if (message.options !== "") {
+      writer.uint32(18).string(message.options);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): BeginPasskeyAuthenticationResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseBeginPasskeyAuthenticationResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.challenge_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.options = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): BeginPasskeyAuthenticationResponse {
+    return {
+      challenge_id: isSet(object.challenge_id) ? globalThis.String(object.challenge_id) : "",
+      options: isSet(object.options) ? globalThis.String(object.options) : "",
+    };
+  },
+
+  toJSON(message: BeginPasskeyAuthenticationResponse): unknown {
+    const obj: any = {};
+    if (message.challenge_id !== "") {
+      obj.challenge_id = message.challenge_id;
+    }
+    if (message.options !== "") {
+      obj.options = message.options;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<BeginPasskeyAuthenticationResponse>, I>>(
+    base?: I,
+  ): BeginPasskeyAuthenticationResponse {
+    return BeginPasskeyAuthenticationResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<BeginPasskeyAuthenticationResponse>, I>>(
+    object: I,
+  ): BeginPasskeyAuthenticationResponse {
+    const message = createBaseBeginPasskeyAuthenticationResponse();
+    message.challenge_id = object.challenge_id ?? "";
+    message.options = object.options ?? "";
+    return message;
+  },
+};
+
+function createBaseFinishPasskeyAuthenticationRequest(): FinishPasskeyAuthenticationRequest {
+  return { challenge_id: "", credential: "" };
+}
+
+export const FinishPasskeyAuthenticationRequest = {
+  encode(message: FinishPasskeyAuthenticationRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.challenge_id !== "") {
+      writer.uint32(10).string(message.challenge_id);
+    }
+    if (message.credential !== "") {
+      writer.uint32(18).string(message.credential);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): FinishPasskeyAuthenticationRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseFinishPasskeyAuthenticationRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.challenge_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.credential = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): FinishPasskeyAuthenticationRequest {
+    return {
+      challenge_id: isSet(object.challenge_id) ? globalThis.String(object.challenge_id) : "",
+      credential: isSet(object.credential) ? globalThis.String(object.credential) : "",
+    };
+  },
+
+  toJSON(message: FinishPasskeyAuthenticationRequest): unknown {
+    const obj: any = {};
+    if (message.challenge_id !== "") {
+      obj.challenge_id = message.challenge_id;
+    }
+    if (message.credential !== "") {
+      obj.credential = message.credential;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<FinishPasskeyAuthenticationRequest>, I>>(
+    base?: I,
+  ): FinishPasskeyAuthenticationRequest {
+    return FinishPasskeyAuthenticationRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<FinishPasskeyAuthenticationRequest>, I>>(
+    object: I,
+  ): FinishPasskeyAuthenticationRequest {
+    const message = createBaseFinishPasskeyAuthenticationRequest();
+    message.challenge_id = object.challenge_id ?? "";
+    message.credential = object.credential ?? "";
+    return message;
+  },
+};
+
+function createBaseFinishPasskeyAuthenticationResponse(): FinishPasskeyAuthenticationResponse {
+  return { user_id: "", passkey_id: "" };
+}
+
+export const FinishPasskeyAuthenticationResponse = {
+  encode(message: FinishPasskeyAuthenticationResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.passkey_id !== "") {
+      writer.uint32(18).string(message.passkey_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): FinishPasskeyAuthenticationResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseFinishPasskeyAuthenticationResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.passkey_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): FinishPasskeyAuthenticationResponse {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      passkey_id: isSet(object.passkey_id) ? globalThis.String(object.passkey_id) : "",
+    };
+  },
+
+  toJSON(message: FinishPasskeyAuthenticationResponse): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.passkey_id !== "") {
+      obj.passkey_id = message.passkey_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<FinishPasskeyAuthenticationResponse>, I>>(
+    base?: I,
+  ): FinishPasskeyAuthenticationResponse {
+    return FinishPasskeyAuthenticationResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<FinishPasskeyAuthenticationResponse>, I>>(
+    object: I,
+  ): FinishPasskeyAuthenticationResponse {
+    const message = createBaseFinishPasskeyAuthenticationResponse();
+    message.user_id = object.user_id ?? "";
+    message.passkey_id = object.passkey_id ?? "";
+    return message;
+  },
+};
+
+function createBaseRemovePasskeyRequest(): RemovePasskeyRequest {
+  return { user_id: "", passkey_id: "" };
+}
+
+export const RemovePasskeyRequest = {
+  encode(message: RemovePasskeyRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.passkey_id !== "") {
+      writer.uint32(18).string(message.passkey_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): RemovePasskeyRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRemovePasskeyRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.passkey_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): RemovePasskeyRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      passkey_id: isSet(object.passkey_id) ? globalThis.String(object.passkey_id) : "",
+    };
+  },
+
+  toJSON(message: RemovePasskeyRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.passkey_id !== "") {
+      obj.passkey_id = message.passkey_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RemovePasskeyRequest>, I>>(base?: I): RemovePasskeyRequest {
+    return RemovePasskeyRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RemovePasskeyRequest>, I>>(object: I): RemovePasskeyRequest {
+    const message = createBaseRemovePasskeyRequest();
+    message.user_id = object.user_id ?? "";
+    message.passkey_id = object.passkey_id ?? "";
+    return message;
+  },
+};
+
+function createBaseRemovePasskeyResponse(): RemovePasskeyResponse {
+  return {};
+}
+
+export const RemovePasskeyResponse = {
+  encode(_: RemovePasskeyResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): RemovePasskeyResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRemovePasskeyResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(_: any): RemovePasskeyResponse {
+    return {};
+  },
+
+  toJSON(_: RemovePasskeyResponse): unknown {
+    const obj: any = {};
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RemovePasskeyResponse>, I>>(base?: I): RemovePasskeyResponse {
+    return RemovePasskeyResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RemovePasskeyResponse>, I>>(_: I): RemovePasskeyResponse {
+    const message = createBaseRemovePasskeyResponse();
+    return message;
+  },
+};
+
+function createBaseLinkLoginProviderRequest(): LinkLoginProviderRequest {
+  return { user_id: "", provider: 0, code: "", redirect_uri: "", nonce: "" };
+}
+
+export const LinkLoginProviderRequest = {
+  encode(message: LinkLoginProviderRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.provider !== 0) {
+      writer.uint32(16).int32(message.provider);
+    }
+    if (message.code !== "") {
+      writer.uint32(26).string(message.code);
+    }
+    if (message.redirect_uri !== "") {
+      writer.uint32(34).string(message.redirect_uri);
+    }
+    if (message.nonce !== "") {
+      writer.uint32(42).string(message.nonce);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): LinkLoginProviderRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseLinkLoginProviderRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.provider = reader.int32() as any;
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.code = reader.string();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.redirect_uri = reader.string();
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.nonce = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): LinkLoginProviderRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      provider: isSet(object.provider) ? loginProviderFromJSON(object.provider) : 0,
+      code: isSet(object.code) ? globalThis.String(object.code) : "",
+      redirect_uri: isSet(object.redirect_uri) ? globalThis.String(object.redirect_uri) : "",
+      nonce: isSet(object.nonce) ? globalThis.String(object.nonce) : "",
+    };
+  },
+
+  toJSON(message: LinkLoginProviderRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.provider !== 0) {
+      obj.provider = loginProviderToJSON(message.provider);
+    }
+    if (message.code !== "") {
+      obj.code = message.code;
+    }
+    if (message.redirect_uri !== "") {
+      obj.redirect_uri = message.redirect_uri;
+    }
+    if (message.nonce !== "") {
+      obj.nonce = message.nonce;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<LinkLoginProviderRequest>, I>>(base?: I): LinkLoginProviderRequest {
+    return LinkLoginProviderRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<LinkLoginProviderRequest>, I>>(object: I): LinkLoginProviderRequest {
+    const message = createBaseLinkLoginProviderRequest();
+    message.user_id = object.user_id ?? "";
+    message.provider = object.provider ?? 0;
+    message.code = object.code ?? "";
+    message.redirect_uri = object.redirect_uri ?? "";
+    message.nonce = object.nonce ?? "";
+    return message;
+  },
+};
+
+function createBaseLinkLoginProviderResponse(): LinkLoginProviderResponse {
+  return { login_provider: undefined };
+}
+
+export const LinkLoginProviderResponse = {
+  encode(message: LinkLoginProviderResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.login_provider !== undefined) {
+      LinkedLoginProvider.encode(message.login_provider, writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): LinkLoginProviderResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseLinkLoginProviderResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.login_provider = LinkedLoginProvider.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): LinkLoginProviderResponse {
+    return {
+      login_provider: isSet(object.login_provider) ? LinkedLoginProvider.fromJSON(object.login_provider) : undefined,
+    };
+  },
+
+  toJSON(message: LinkLoginProviderResponse): unknown {
+    const obj: any = {};
+    if (message.login_provider !== undefined) {
+      obj.login_provider = LinkedLoginProvider.toJSON(message.login_provider);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<LinkLoginProviderResponse>, I>>(base?: I): LinkLoginProviderResponse {
+    return LinkLoginProviderResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<LinkLoginProviderResponse>, I>>(object: I): LinkLoginProviderResponse {
+    const message = createBaseLinkLoginProviderResponse();
+    message.login_provider = (object.login_provider !== undefined && object.login_provider !== null)
+      ? LinkedLoginProvider.fromPartial(object.login_provider)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseUnlinkLoginProviderRequest(): UnlinkLoginProviderRequest {
+  return { user_id: "", provider: 0 };
+}
+
+export const UnlinkLoginProviderRequest = {
+  encode(message: UnlinkLoginProviderRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.provider !== 0) {
+      writer.uint32(16).int32(message.provider);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): UnlinkLoginProviderRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUnlinkLoginProviderRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.provider = reader.int32() as any;
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UnlinkLoginProviderRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      provider: isSet(object.provider) ? loginProviderFromJSON(object.provider) : 0,
+    };
+  },
+
+  toJSON(message: UnlinkLoginProviderRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.provider !== 0) {
+      obj.provider = loginProviderToJSON(message.provider);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UnlinkLoginProviderRequest>, I>>(base?: I): UnlinkLoginProviderRequest {
+    return UnlinkLoginProviderRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UnlinkLoginProviderRequest>, I>>(object: I): UnlinkLoginProviderRequest {
+    const message = createBaseUnlinkLoginProviderRequest();
+    message.user_id = object.user_id ?? "";
+    message.provider = object.provider ?? 0;
+    return message;
+  },
+};
+
+function createBaseUnlinkLoginProviderResponse(): UnlinkLoginProviderResponse {
+  return {};
+}
+
+export const UnlinkLoginProviderResponse = {
+  encode(_: UnlinkLoginProviderResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): UnlinkLoginProviderResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUnlinkLoginProviderResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(_: any): UnlinkLoginProviderResponse {
+    return {};
+  },
+
+  toJSON(_: UnlinkLoginProviderResponse): unknown {
+    const obj: any = {};
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UnlinkLoginProviderResponse>, I>>(base?: I): UnlinkLoginProviderResponse {
+    return UnlinkLoginProviderResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UnlinkLoginProviderResponse>, I>>(_: I): UnlinkLoginProviderResponse {
+    const message = createBaseUnlinkLoginProviderResponse();
+    return message;
+  },
+};
+
+type Builtin = Date | Function | Uint8Array | string | number | boolean | undefined;
+
+export type DeepPartial<T> = T extends Builtin ? T
+  : T extends globalThis.Array<infer U> ? globalThis.Array<DeepPartial<U>>
+  : T extends ReadonlyArray<infer U> ? ReadonlyArray<DeepPartial<U>>
+  : T extends {} ? { [K in keyof T]?: DeepPartial<T[K]> }
+  : Partial<T>;
+
+type KeysOfUnion<T> = T extends T ? keyof T : never;
+export type Exact<P, I extends P> = P extends Builtin ? P
+  : P & { [K in keyof P]: Exact<P[K], I[K]> } & { [K in Exclude<keyof I, KeysOfUnion<P>>]: never };
+
+function toTimestamp(date: Date): Timestamp {
+  const seconds = Math.trunc(date.getTime() / 1_000);
+  const nanos = (date.getTime() % 1_000) * 1_000_000;
+  return { seconds, nanos };
+}
+
+function fromTimestamp(t: Timestamp): Date {
+  let millis = (t.seconds || 0) * 1_000;
+  millis += (t.nanos || 0) / 1_000_000;
+  return new globalThis.Date(millis);
+}
+
+function fromJsonTimestamp(o: any): Date {
+  if (o instanceof globalThis.Date) {
+    return o;
+  } else if (typeof o === "string") {
+    return new globalThis.Date(o);
+  } else {
+    return fromTimestamp(Timestamp.fromJSON(o));
+  }
+}
 
 function isSet(value: any): boolean {
   return value !== null && value !== undefined;
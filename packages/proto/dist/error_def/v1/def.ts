@@ -0,0 +1,379 @@
+/* eslint-disable */
+import _m0 from "protobufjs/minimal";
+
+export const protobufPackage = "error_def.v1";
+
+/** Attached to the `google.rpc.Status` details of a failed RPC as an `ErrorDetail` */
+export const ErrorReason = {
+  UNSPECIFIED: 0,
+  USER_NOT_FOUND: 1,
+  USER_SUSPENDED: 2,
+  USER_BLOCKED: 3,
+  PROFILE_PRIVATE: 4,
+  STORY_NOT_FOUND: 5,
+  STORY_DELETED: 6,
+  STORY_UNPUBLISHED: 7,
+  DRAFT_NOT_FOUND: 8,
+  COMMENT_NOT_FOUND: 9,
+  COMMENT_DELETED: 10,
+  BLOG_NOT_FOUND: 11,
+  BLOG_PLUS_REQUIRED: 12,
+  BLOG_LOGIN_REQUIRED: 13,
+  TAG_NOT_FOUND: 14,
+  TOKEN_NOT_FOUND: 15,
+  TOKEN_EXPIRED: 16,
+  ACCOUNT_EXPORT_IN_PROGRESS: 17,
+  ACCOUNT_EXPORT_COOLDOWN: 18,
+  ACCOUNT_DELETION_SCHEDULED: 19,
+  MFA_CHALLENGE_INVALID: 20,
+  CREDENTIAL_INVALID: 21,
+  SESSION_INVALID: 22,
+  IDENTITY_MISMATCH: 23,
+  RATE_LIMITED: 24,
+  UNRECOGNIZED: -1,
+} as const;
+
+export type ErrorReason = typeof ErrorReason[keyof typeof ErrorReason];
+
+export function errorReasonFromJSON(object: any): ErrorReason {
+  switch (object) {
+    case 0:
+    case "ERROR_REASON_UNSPECIFIED":
+      return ErrorReason.UNSPECIFIED;
+    case 1:
+    case "ERROR_REASON_USER_NOT_FOUND":
+      return ErrorReason.USER_NOT_FOUND;
+    case 2:
+    case "ERROR_REASON_USER_SUSPENDED":
+      return ErrorReason.USER_SUSPENDED;
+    case 3:
+    case "ERROR_REASON_USER_BLOCKED":
+      return ErrorReason.USER_BLOCKED;
+    case 4:
+    case "ERROR_REASON_PROFILE_PRIVATE":
+      return ErrorReason.PROFILE_PRIVATE;
+    case 5:
+    case "ERROR_REASON_STORY_NOT_FOUND":
+      return ErrorReason.STORY_NOT_FOUND;
+    case 6:
+    case "ERROR_REASON_STORY_DELETED":
+      return ErrorReason.STORY_DELETED;
+    case 7:
+    case "ERROR_REASON_STORY_UNPUBLISHED":
+      return ErrorReason.STORY_UNPUBLISHED;
+    case 8:
+    case "ERROR_REASON_DRAFT_NOT_FOUND":
+      return ErrorReason.DRAFT_NOT_FOUND;
+    case 9:
+    case "ERROR_REASON_COMMENT_NOT_FOUND":
+      return ErrorReason.COMMENT_NOT_FOUND;
+    case 10:
+    case "ERROR_REASON_COMMENT_DELETED":
+      return ErrorReason.COMMENT_DELETED;
+    case 11:
+    case "ERROR_REASON_BLOG_NOT_FOUND":
+      return ErrorReason.BLOG_NOT_FOUND;
+    case 12:
+    case "ERROR_REASON_BLOG_PLUS_REQUIRED":
+      return ErrorReason.BLOG_PLUS_REQUIRED;
+    case 13:
+    case "ERROR_REASON_BLOG_LOGIN_REQUIRED":
+      return ErrorReason.BLOG_LOGIN_REQUIRED;
+    case 14:
+    case "ERROR_REASON_TAG_NOT_FOUND":
+      return ErrorReason.TAG_NOT_FOUND;
+    case 15:
+    case "ERROR_REASON_TOKEN_NOT_FOUND":
+      return ErrorReason.TOKEN_NOT_FOUND;
+    case 16:
+    case "ERROR_REASON_TOKEN_EXPIRED":
+      return ErrorReason.TOKEN_EXPIRED;
+    case 17:
+    case "ERROR_REASON_ACCOUNT_EXPORT_IN_PROGRESS":
+      return ErrorReason.ACCOUNT_EXPORT_IN_PROGRESS;
+    case 18:
+    case "ERROR_REASON_ACCOUNT_EXPORT_COOLDOWN":
+      return ErrorReason.ACCOUNT_EXPORT_COOLDOWN;
+    case 19:
+    case "ERROR_REASON_ACCOUNT_DELETION_SCHEDULED":
+      return ErrorReason.ACCOUNT_DELETION_SCHEDULED;
+    case 20:
+    case "ERROR_REASON_MFA_CHALLENGE_INVALID":
+      return ErrorReason.MFA_CHALLENGE_INVALID;
+    case 21:
+    case "ERROR_REASON_CREDENTIAL_INVALID":
+      return ErrorReason.CREDENTIAL_INVALID;
+    case 22:
+    case "ERROR_REASON_SESSION_INVALID":
+      return ErrorReason.SESSION_INVALID;
+    case 23:
+    case "ERROR_REASON_IDENTITY_MISMATCH":
+      return ErrorReason.IDENTITY_MISMATCH;
+    case 24:
+    case "ERROR_REASON_RATE_LIMITED":
+      return ErrorReason.RATE_LIMITED;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return ErrorReason.UNRECOGNIZED;
+  }
+}
+
+export function errorReasonToJSON(object: ErrorReason): string {
+  switch (object) {
+    case ErrorReason.UNSPECIFIED:
+      return "ERROR_REASON_UNSPECIFIED";
+    case ErrorReason.USER_NOT_FOUND:
+      return "ERROR_REASON_USER_NOT_FOUND";
+    case ErrorReason.USER_SUSPENDED:
+      return "ERROR_REASON_USER_SUSPENDED";
+    case ErrorReason.USER_BLOCKED:
+      return "ERROR_REASON_USER_BLOCKED";
+    case ErrorReason.PROFILE_PRIVATE:
+      return "ERROR_REASON_PROFILE_PRIVATE";
+    case ErrorReason.STORY_NOT_FOUND:
+      return "ERROR_REASON_STORY_NOT_FOUND";
+    case ErrorReason.STORY_DELETED:
+      return "ERROR_REASON_STORY_DELETED";
+    case ErrorReason.STORY_UNPUBLISHED:
+      return "ERROR_REASON_STORY_UNPUBLISHED";
+    case ErrorReason.DRAFT_NOT_FOUND:
+      return "ERROR_REASON_DRAFT_NOT_FOUND";
+    case ErrorReason.COMMENT_NOT_FOUND:
+      return "ERROR_REASON_COMMENT_NOT_FOUND";
+    case ErrorReason.COMMENT_DELETED:
+      return "ERROR_REASON_COMMENT_DELETED";
+    case ErrorReason.BLOG_NOT_FOUND:
+      return "ERROR_REASON_BLOG_NOT_FOUND";
+    case ErrorReason.BLOG_PLUS_REQUIRED:
+      return "ERROR_REASON_BLOG_PLUS_REQUIRED";
+    case ErrorReason.BLOG_LOGIN_REQUIRED:
+      return "ERROR_REASON_BLOG_LOGIN_REQUIRED";
+    case ErrorReason.TAG_NOT_FOUND:
+      return "ERROR_REASON_TAG_NOT_FOUND";
+    case ErrorReason.TOKEN_NOT_FOUND:
+      return "ERROR_REASON_TOKEN_NOT_FOUND";
+    case ErrorReason.TOKEN_EXPIRED:
+      return "ERROR_REASON_TOKEN_EXPIRED";
+    case ErrorReason.ACCOUNT_EXPORT_IN_PROGRESS:
+      return "ERROR_REASON_ACCOUNT_EXPORT_IN_PROGRESS";
+    case ErrorReason.ACCOUNT_EXPORT_COOLDOWN:
+      return "ERROR_REASON_ACCOUNT_EXPORT_COOLDOWN";
+    case ErrorReason.ACCOUNT_DELETION_SCHEDULED:
+      return "ERROR_REASON_ACCOUNT_DELETION_SCHEDULED";
+    case ErrorReason.MFA_CHALLENGE_INVALID:
+      return "ERROR_REASON_MFA_CHALLENGE_INVALID";
+    case ErrorReason.CREDENTIAL_INVALID:
+      return "ERROR_REASON_CREDENTIAL_INVALID";
+    case ErrorReason.SESSION_INVALID:
+      return "ERROR_REASON_SESSION_INVALID";
+    case ErrorReason.IDENTITY_MISMATCH:
+      return "ERROR_REASON_IDENTITY_MISMATCH";
+    case ErrorReason.RATE_LIMITED:
+      return "ERROR_REASON_RATE_LIMITED";
+    case ErrorReason.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
+export interface ErrorDetail {
+  reason: ErrorReason;
+  /** Identifiers of the entities involved, e.g. `story_id` */
+  metadata: { [key: string]: string };
+}
+
+export interface ErrorDetail_MetadataEntry {
+  key: string;
+  value: string;
+}
+
+function createBaseErrorDetail(): ErrorDetail {
+  return { reason: 0, metadata: {} };
+}
+
+export const ErrorDetail = {
+  encode(message: ErrorDetail, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.reason !== 0) {
+      writer.uint32(8).int32(message.reason);
+    }
+    Object.entries(message.metadata).forEach(([key, value]) => {
+      ErrorDetail_MetadataEntry.encode({ key: key as any, value }, writer.uint32(18).fork()).ldelim();
+    });
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ErrorDetail {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseErrorDetail();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.reason = reader.int32() as any;
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          const entry2 = ErrorDetail_MetadataEntry.decode(reader, reader.uint32());
+          if (entry2.value !== undefined) {
+            message.metadata[entry2.key] = entry2.value;
+          }
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ErrorDetail {
+    return {
+      reason: isSet(object.reason) ? errorReasonFromJSON(object.reason) : 0,
+      metadata: isObject(object.metadata)
+        ? Object.entries(object.metadata).reduce<{ [key: string]: string }>((acc, [key, value]) => {
+          acc[key] = String(value);
+          return acc;
+        }, {})
+        : {},
+    };
+  },
+
+  toJSON(message: ErrorDetail): unknown {
+    const obj: any = {};
+    if (message.reason !== 0) {
+      obj.reason = errorReasonToJSON(message.reason);
+    }
+    if (message.metadata) {
+      const entries = Object.entries(message.metadata);
+      if (entries.length > 0) {
+        obj.metadata = {};
+        entries.forEach(([k, v]) => {
+          obj.metadata[k] = v;
+        });
+      }
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ErrorDetail>, I>>(base?: I): ErrorDetail {
+    return ErrorDetail.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ErrorDetail>, I>>(object: I): ErrorDetail {
+    const message = createBaseErrorDetail();
+    message.reason = object.reason ?? 0;
+    message.metadata = Object.entries(object.metadata ?? {}).reduce<{ [key: string]: string }>(
+      (acc, [key, value]) => {
+        if (value !== undefined) {
+          acc[key] = globalThis.String(value);
+        }
+        return acc;
+      },
+      {},
+    );
+    return message;
+  },
+};
+
+function createBaseErrorDetail_MetadataEntry(): ErrorDetail_MetadataEntry {
+  return { key: "", value: "" };
+}
+
+export const ErrorDetail_MetadataEntry = {
+  encode(message: ErrorDetail_MetadataEntry, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.key !== "") {
+      writer.uint32(10).string(message.key);
+    }
+    if (message.value !== "") {
+      writer.uint32(18).string(message.value);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ErrorDetail_MetadataEntry {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseErrorDetail_MetadataEntry();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.key = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.value = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ErrorDetail_MetadataEntry {
+    return {
+      key: isSet(object.key) ? globalThis.String(object.key) : "",
+      value: isSet(object.value) ? globalThis.String(object.value) : "",
+    };
+  },
+
+  toJSON(message: ErrorDetail_MetadataEntry): unknown {
+    const obj: any = {};
+    if (message.key !== "") {
+      obj.key = message.key;
+    }
+    if (message.value !== "") {
+      obj.value = message.value;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ErrorDetail_MetadataEntry>, I>>(base?: I): ErrorDetail_MetadataEntry {
+    return ErrorDetail_MetadataEntry.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ErrorDetail_MetadataEntry>, I>>(object: I): ErrorDetail_MetadataEntry {
+    const message = createBaseErrorDetail_MetadataEntry();
+    message.key = object.key ?? "";
+    message.value = object.value ?? "";
+    return message;
+  },
+};
+
+type Builtin = Date | Function | Uint8Array | string | number | boolean | undefined;
+
+export type DeepPartial<T> = T extends Builtin ? T
+  : T extends globalThis.Array<infer U> ? globalThis.Array<DeepPartial<U>>
+  : T extends ReadonlyArray<infer U> ? ReadonlyArray<DeepPartial<U>>
+  : T extends {} ? { [K in keyof T]?: DeepPartial<T[K]> }
+  : Partial<T>;
+
+type KeysOfUnion<T> = T extends T ? keyof T : never;
+export type Exact<P, I extends P> = P extends Builtin ? P
+  : P & { [K in keyof P]: Exact<P[K], I[K]> } & { [K in Exclude<keyof I, KeysOfUnion<P>>]: never };
+
+function isObject(value: any): boolean {
+  return typeof value === "object" && value !== null;
+}
+
+function isSet(value: any): boolean {
+  return value !== null && value !== undefined;
+}
@@ -1,5 +1,6 @@
 /* eslint-disable */
 import _m0 from "protobufjs/minimal";
+import { Timestamp } from "../../google/protobuf/timestamp";
 
 export const protobufPackage = "login_activity_def.v1";
 
@@ -49,6 +50,53 @@ export function deviceTypeToJSON(object: DeviceType): string {
   }
 }
 
+export const LoginAlertReason = {
+  UNSPECIFIED: 0,
+  NEW_DEVICE_TYPE: 1,
+  IMPOSSIBLE_TRAVEL: 2,
+  UNUSUAL_TIME: 3,
+  UNRECOGNIZED: -1,
+} as const;
+
+export type LoginAlertReason = typeof LoginAlertReason[keyof typeof LoginAlertReason];
+
+export function loginAlertReasonFromJSON(object: any): LoginAlertReason {
+  switch (object) {
+    case 0:
+    case "LOGIN_ALERT_REASON_UNSPECIFIED":
+      return LoginAlertReason.UNSPECIFIED;
+    case 1:
+    case "LOGIN_ALERT_REASON_NEW_DEVICE_TYPE":
+      return LoginAlertReason.NEW_DEVICE_TYPE;
+    case 2:
+    case "LOGIN_ALERT_REASON_IMPOSSIBLE_TRAVEL":
+      return LoginAlertReason.IMPOSSIBLE_TRAVEL;
+    case 3:
+    case "LOGIN_ALERT_REASON_UNUSUAL_TIME":
+      return LoginAlertReason.UNUSUAL_TIME;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return LoginAlertReason.UNRECOGNIZED;
+  }
+}
+
+export function loginAlertReasonToJSON(object: LoginAlertReason): string {
+  switch (object) {
+    case LoginAlertReason.UNSPECIFIED:
+      return "LOGIN_ALERT_REASON_UNSPECIFIED";
+    case LoginAlertReason.NEW_DEVICE_TYPE:
+      return "LOGIN_ALERT_REASON_NEW_DEVICE_TYPE";
+    case LoginAlertReason.IMPOSSIBLE_TRAVEL:
+      return "LOGIN_ALERT_REASON_IMPOSSIBLE_TRAVEL";
+    case LoginAlertReason.UNUSUAL_TIME:
+      return "LOGIN_ALERT_REASON_UNUSUAL_TIME";
+    case LoginAlertReason.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
 export interface Device {
   display_name: string;
   type: DeviceType;
@@ -66,7 +114,19 @@ export interface Login {
   location: Location | undefined;
   domain?: string | undefined;
   is_active: boolean;
+  /** @deprecated */
   created_at: string;
+  /** Timestamps */
+  created_at_ts: Date | undefined;
+}
+
+export interface LoginAlert {
+  id: string;
+  login_id: string;
+  reasons: LoginAlertReason[];
+  /** Only sent when `mail_login_activity` is enabled */
+  mail_sent: boolean;
+  created_at: Date | undefined;
 }
 
 export interface GetLoginActivityRequest {
@@ -79,6 +139,7 @@ export interface GetLoginActivityRequest {
 export interface GetLoginActivityResponse {
   recent?: Login | undefined;
   logins: Login[];
+  alerts: LoginAlert[];
 }
 
 function createBaseDevice(): Device {
@@ -245,7 +306,15 @@ export const Location = {
 };
 
 function createBaseLogin(): Login {
-  return { id: "", device: undefined, location: undefined, domain: undefined, is_active: false, created_at: "" };
+  return {
+    id: "",
+    device: undefined,
+    location: undefined,
+    domain: undefined,
+    is_active: false,
+    created_at: "",
+    created_at_ts: undefined,
+  };
 }
 
 export const Login = {
@@ -268,6 +337,9 @@ export const Login = {
     if (message.created_at !== "") {
       writer.uint32(50).string(message.created_at);
     }
+    if (message.created_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at_ts), writer.uint32(58).fork()).ldelim();
+    }
     return writer;
   },
 
@@ -320,6 +392,13 @@ export const Login = {
 
           message.created_at = reader.string();
           continue;
+        case 7:
+          if (tag !== 58) {
+            break;
+          }
+
+          message.created_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -337,6 +416,7 @@ export const Login = {
       domain: isSet(object.domain) ? globalThis.String(object.domain) : undefined,
       is_active: isSet(object.is_active) ? globalThis.Boolean(object.is_active) : false,
       created_at: isSet(object.created_at) ? globalThis.String(object.created_at) : "",
+      created_at_ts: isSet(object.created_at_ts) ? fromJsonTimestamp(object.created_at_ts) : undefined,
     };
   },
 
@@ -360,6 +440,9 @@ export const Login = {
     if (message.created_at !== "") {
       obj.created_at = message.created_at;
     }
+    if (message.created_at_ts !== undefined) {
+      obj.created_at_ts = message.created_at_ts.toISOString();
+    }
     return obj;
   },
 
@@ -378,6 +461,140 @@ export const Login = {
     message.domain = object.domain ?? undefined;
     message.is_active = object.is_active ?? false;
     message.created_at = object.created_at ?? "";
+    message.created_at_ts = object.created_at_ts ?? undefined;
+    return message;
+  },
+};
+
+function createBaseLoginAlert(): LoginAlert {
+  return { id: "", login_id: "", reasons: [], mail_sent: false, created_at: undefined };
+}
+
+export const LoginAlert = {
+  encode(message: LoginAlert, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.id !== "") {
+      writer.uint32(10).string(message.id);
+    }
+    if (message.login_id !== "") {
+      writer.uint32(18).string(message.login_id);
+    }
+    writer.uint32(26).fork();
+    for (const v of message.reasons) {
+      writer.int32(v);
+    }
+    writer.ldelim();
+    if (message.mail_sent === true) {
+      writer.uint32(32).bool(message.mail_sent);
+    }
+    if (message.created_at !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at), writer.uint32(42).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): LoginAlert {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseLoginAlert();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.login_id = reader.string();
+          continue;
+        case 3:
+          if (tag === 24) {
+            message.reasons.push(reader.int32() as any);
+
+            continue;
+          }
+
+          if (tag === 26) {
+            const end2 = reader.uint32() + reader.pos;
+            while (reader.pos < end2) {
+              message.reasons.push(reader.int32() as any);
+            }
+
+            continue;
+          }
+
+          break;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.mail_sent = reader.bool();
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.created_at = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): LoginAlert {
+    return {
+      id: isSet(object.id) ? globalThis.String(object.id) : "",
+      login_id: isSet(object.login_id) ? globalThis.String(object.login_id) : "",
+      reasons: globalThis.Array.isArray(object?.reasons)
+        ? object.reasons.map((e: any) => loginAlertReasonFromJSON(e))
+        : [],
+      mail_sent: isSet(object.mail_sent) ? globalThis.Boolean(object.mail_sent) : false,
+      created_at: isSet(object.created_at) ? fromJsonTimestamp(object.created_at) : undefined,
+    };
+  },
+
+  toJSON(message: LoginAlert): unknown {
+    const obj: any = {};
+    if (message.id !== "") {
+      obj.id = message.id;
+    }
+    if (message.login_id !== "") {
+      obj.login_id = message.login_id;
+    }
+    if (message.reasons?.length) {
+      obj.reasons = message.reasons.map((e) => loginAlertReasonToJSON(e));
+    }
+    if (message.mail_sent === true) {
+      obj.mail_sent = message.mail_sent;
+    }
+    if (message.created_at !== undefined) {
+      obj.created_at = message.created_at.toISOString();
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<LoginAlert>, I>>(base?: I): LoginAlert {
+    return LoginAlert.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<LoginAlert>, I>>(object: I): LoginAlert {
+    const message = createBaseLoginAlert();
+    message.id = object.id ?? "";
+    message.login_id = object.login_id ?? "";
+    message.reasons = object.reasons?.map((e) => e) || [];
+    message.mail_sent = object.mail_sent ?? false;
+    message.created_at = object.created_at ?? undefined;
     return message;
   },
 };
@@ -457,7 +674,7 @@ export const GetLoginActivityRequest = {
 };
 
 function createBaseGetLoginActivityResponse(): GetLoginActivityResponse {
-  return { recent: undefined, logins: [] };
+  return { recent: undefined, logins: [], alerts: [] };
 }
 
 export const GetLoginActivityResponse = {
@@ -468,6 +685,9 @@ export const GetLoginActivityResponse = {
     for (const v of message.logins) {
       Login.encode(v!, writer.uint32(18).fork()).ldelim();
     }
+    for (const v of message.alerts) {
+      LoginAlert.encode(v!, writer.uint32(26).fork()).ldelim();
+    }
     return writer;
   },
 
@@ -492,6 +712,13 @@ export const GetLoginActivityResponse = {
 
           message.logins.push(Login.decode(reader, reader.uint32()));
           continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.alerts.push(LoginAlert.decode(reader, reader.uint32()));
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -505,6 +732,7 @@ export const GetLoginActivityResponse = {
     return {
       recent: isSet(object.recent) ? Login.fromJSON(object.recent) : undefined,
       logins: globalThis.Array.isArray(object?.logins) ? object.logins.map((e: any) => Login.fromJSON(e)) : [],
+      alerts: globalThis.Array.isArray(object?.alerts) ? object.alerts.map((e: any) => LoginAlert.fromJSON(e)) : [],
     };
   },
 
@@ -516,6 +744,9 @@ export const GetLoginActivityResponse = {
     if (message.logins?.length) {
       obj.logins = message.logins.map((e) => Login.toJSON(e));
     }
+    if (message.alerts?.length) {
+      obj.alerts = message.alerts.map((e) => LoginAlert.toJSON(e));
+    }
     return obj;
   },
 
@@ -528,6 +759,7 @@ export const GetLoginActivityResponse = {
       ? Login.fromPartial(object.recent)
       : undefined;
     message.logins = object.logins?.map((e) => Login.fromPartial(e)) || [];
+    message.alerts = object.alerts?.map((e) => LoginAlert.fromPartial(e)) || [];
     return message;
   },
 };
@@ -544,6 +776,28 @@ type KeysOfUnion<T> = T extends T ? keyof T : never;
 export type Exact<P, I extends P> = P extends Builtin ? P
   : P & { [K in keyof P]: Exact<P[K], I[K]> } & { [K in Exclude<keyof I, KeysOfUnion<P>>]: never };
 
+function toTimestamp(date: Date): Timestamp {
+  const seconds = Math.trunc(date.getTime() / 1_000);
+  const nanos = (date.getTime() % 1_000) * 1_000_000;
+  return { seconds, nanos };
+}
+
+function fromTimestamp(t: Timestamp): Date {
+  let millis = (t.seconds || 0) * 1_000;
+  millis += (t.nanos || 0) / 1_000_000;
+  return new globalThis.Date(millis);
+}
+
+function fromJsonTimestamp(o: any): Date {
+  if (o instanceof globalThis.Date) {
+    return o;
+  } else if (typeof o === "string") {
+    return new globalThis.Date(o);
+  } else {
+    return fromTimestamp(Timestamp.fromJSON(o));
+  }
+}
+
 function isSet(value: any): boolean {
   return value !== null && value !== undefined;
 }
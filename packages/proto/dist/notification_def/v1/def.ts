@@ -0,0 +1,2189 @@
+/* eslint-disable */
+import _m0 from "protobufjs/minimal";
+import { Timestamp } from "../../google/protobuf/timestamp";
+import { BareUser } from "../../user_def/v1/def";
+
+export const protobufPackage = "notification_def.v1";
+
+export const NotificationType = {
+  UNSPECIFIED: 0,
+  SYSTEM: 1,
+  LOGIN_ATTEMPT: 2,
+  FRIEND_REQ_ACCEPT: 3,
+  FRIEND_REQ_RECEIVED: 4,
+  FOLLOWER_ADD: 5,
+  COMMENT_ADD: 6,
+  REPLY_ADD: 7,
+  STORY_MENTION: 8,
+  STORY_LIKE: 9,
+  STORY_ADD_BY_USER: 10,
+  STORY_ADD_BY_TAG: 11,
+  COLLAB_REQ_ACCEPT: 12,
+  COLLAB_REQ_RECEIVED: 13,
+  BLOG_EDITOR_INVITE: 14,
+  BLOG_WRITER_INVITE: 15,
+  UNRECOGNIZED: -1,
+} as const;
+
+export type NotificationType = typeof NotificationType[keyof typeof NotificationType];
+
+export function notificationTypeFromJSON(object: any): NotificationType {
+  switch (object) {
+    case 0:
+    case "NOTIFICATION_TYPE_UNSPECIFIED":
+      return NotificationType.UNSPECIFIED;
+    case 1:
+    case "NOTIFICATION_TYPE_SYSTEM":
+      return NotificationType.SYSTEM;
+    case 2:
+    case "NOTIFICATION_TYPE_LOGIN_ATTEMPT":
+      return NotificationType.LOGIN_ATTEMPT;
+    case 3:
+    case "NOTIFICATION_TYPE_FRIEND_REQ_ACCEPT":
+      return NotificationType.FRIEND_REQ_ACCEPT;
+    case 4:
+    case "NOTIFICATION_TYPE_FRIEND_REQ_RECEIVED":
+      return NotificationType.FRIEND_REQ_RECEIVED;
+    case 5:
+    case "NOTIFICATION_TYPE_FOLLOWER_ADD":
+      return NotificationType.FOLLOWER_ADD;
+    case 6:
+    case "NOTIFICATION_TYPE_COMMENT_ADD":
+      return NotificationType.COMMENT_ADD;
+    case 7:
+    case "NOTIFICATION_TYPE_REPLY_ADD":
+      return NotificationType.REPLY_ADD;
+    case 8:
+    case "NOTIFICATION_TYPE_STORY_MENTION":
+      return NotificationType.STORY_MENTION;
+    case 9:
+    case "NOTIFICATION_TYPE_STORY_LIKE":
+      return NotificationType.STORY_LIKE;
+    case 10:
+    case "NOTIFICATION_TYPE_STORY_ADD_BY_USER":
+      return NotificationType.STORY_ADD_BY_USER;
+    case 11:
+    case "NOTIFICATION_TYPE_STORY_ADD_BY_TAG":
+      return NotificationType.STORY_ADD_BY_TAG;
+    case 12:
+    case "NOTIFICATION_TYPE_COLLAB_REQ_ACCEPT":
+      return NotificationType.COLLAB_REQ_ACCEPT;
+    case 13:
+    case "NOTIFICATION_TYPE_COLLAB_REQ_RECEIVED":
+      return NotificationType.COLLAB_REQ_RECEIVED;
+    case 14:
+    case "NOTIFICATION_TYPE_BLOG_EDITOR_INVITE":
+      return NotificationType.BLOG_EDITOR_INVITE;
+    case 15:
+    case "NOTIFICATION_TYPE_BLOG_WRITER_INVITE":
+      return NotificationType.BLOG_WRITER_INVITE;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return NotificationType.UNRECOGNIZED;
+  }
+}
+
+export function notificationTypeToJSON(object: NotificationType): string {
+  switch (object) {
+    case NotificationType.UNSPECIFIED:
+      return "NOTIFICATION_TYPE_UNSPECIFIED";
+    case NotificationType.SYSTEM:
+      return "NOTIFICATION_TYPE_SYSTEM";
+    case NotificationType.LOGIN_ATTEMPT:
+      return "NOTIFICATION_TYPE_LOGIN_ATTEMPT";
+    case NotificationType.FRIEND_REQ_ACCEPT:
+      return "NOTIFICATION_TYPE_FRIEND_REQ_ACCEPT";
+    case NotificationType.FRIEND_REQ_RECEIVED:
+      return "NOTIFICATION_TYPE_FRIEND_REQ_RECEIVED";
+    case NotificationType.FOLLOWER_ADD:
+      return "NOTIFICATION_TYPE_FOLLOWER_ADD";
+    case NotificationType.COMMENT_ADD:
+      return "NOTIFICATION_TYPE_COMMENT_ADD";
+    case NotificationType.REPLY_ADD:
+      return "NOTIFICATION_TYPE_REPLY_ADD";
+    case NotificationType.STORY_MENTION:
+      return "NOTIFICATION_TYPE_STORY_MENTION";
+    case NotificationType.STORY_LIKE:
+      return "NOTIFICATION_TYPE_STORY_LIKE";
+    case NotificationType.STORY_ADD_BY_USER:
+      return "NOTIFICATION_TYPE_STORY_ADD_BY_USER";
+    case NotificationType.STORY_ADD_BY_TAG:
+      return "NOTIFICATION_TYPE_STORY_ADD_BY_TAG";
+    case NotificationType.COLLAB_REQ_ACCEPT:
+      return "NOTIFICATION_TYPE_COLLAB_REQ_ACCEPT";
+    case NotificationType.COLLAB_REQ_RECEIVED:
+      return "NOTIFICATION_TYPE_COLLAB_REQ_RECEIVED";
+    case NotificationType.BLOG_EDITOR_INVITE:
+      return "NOTIFICATION_TYPE_BLOG_EDITOR_INVITE";
+    case NotificationType.BLOG_WRITER_INVITE:
+      return "NOTIFICATION_TYPE_BLOG_WRITER_INVITE";
+    case NotificationType.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
+export interface FeaturesAndUpdatesPayload {
+  title: string;
+  description: string;
+  url?: string | undefined;
+}
+
+export interface LoginActivityPayload {
+  login_id: string;
+}
+
+export interface StoryPayload {
+  story_id: string;
+  story_slug: string;
+  story_title: string;
+}
+
+export interface StoryLikePayload {
+  story_id: string;
+  story_slug: string;
+  story_title: string;
+}
+
+export interface TagPayload {
+  tag_id: string;
+  tag_name: string;
+  story_id: string;
+  story_slug: string;
+  story_title: string;
+}
+
+export interface CommentPayload {
+  comment_id: string;
+  story_id: string;
+  story_slug: string;
+  story_title: string;
+  /** Truncated plain text content of the comment */
+  excerpt: string;
+}
+
+export interface ReplyPayload {
+  reply_id: string;
+  comment_id: string;
+  story_id: string;
+  story_slug: string;
+  /** Truncated plain text content of the reply */
+  excerpt: string;
+}
+
+export interface NewFollowerPayload {
+}
+
+export interface FriendRequestPayload {
+  /** Omitted once the request is accepted */
+  friend_request_id?: string | undefined;
+}
+
+export interface CollaborationRequestPayload {
+  /** Omitted once the request is accepted */
+  collaboration_request_id?: string | undefined;
+  story_id: string;
+  story_title: string;
+}
+
+export interface BlogRequestPayload {
+  blog_request_id: string;
+  blog_id: string;
+  blog_name: string;
+  blog_slug: string;
+}
+
+export interface Notification {
+  id: string;
+  type: NotificationType;
+  /** Omitted for system notifications */
+  actor?: BareUser | undefined;
+  read_at?: Date | undefined;
+  created_at: Date | undefined;
+  features_and_updates?: FeaturesAndUpdatesPayload | undefined;
+  login_activity?: LoginActivityPayload | undefined;
+  story?: StoryPayload | undefined;
+  story_like?: StoryLikePayload | undefined;
+  tag?: TagPayload | undefined;
+  comment?: CommentPayload | undefined;
+  reply?: ReplyPayload | undefined;
+  new_follower?: NewFollowerPayload | undefined;
+  friend_request?: FriendRequestPayload | undefined;
+  collaboration_request?: CollaborationRequestPayload | undefined;
+  blog_request?: BlogRequestPayload | undefined;
+}
+
+export interface ListNotificationsRequest {
+  user_id: string;
+  /** `next_cursor` from the previous page, `INVALID_ARGUMENT` if malformed */
+  cursor?: string | undefined;
+  limit: number;
+  unread_only: boolean;
+}
+
+export interface ListNotificationsResponse {
+  notifications: Notification[];
+  /** Omitted on the last page */
+  next_cursor?: string | undefined;
+}
+
+/**
+ * Either `notification_ids` or `all` must be set. An empty list without `all`
+ * is rejected with `INVALID_ARGUMENT`.
+ */
+export interface MarkNotificationsReadRequest {
+  user_id: string;
+  notification_ids: string[];
+  /** Marks every notification as read */
+  all: boolean;
+}
+
+export interface MarkNotificationsReadResponse {
+  unread_count: number;
+}
+
+export interface GetUnreadNotificationCountRequest {
+  user_id: string;
+}
+
+export interface GetUnreadNotificationCountResponse {
+  unread_count: number;
+}
+
+export interface WatchNotificationsRequest {
+  user_id: string;
+}
+
+export interface WatchNotificationsResponse {
+  notification: Notification | undefined;
+  unread_count: number;
+}
+
+function createBaseFeaturesAndUpdatesPayload(): FeaturesAndUpdatesPayload {
+  return { title: "", description: "", url: undefined };
+}
+
+export const FeaturesAndUpdatesPayload = {
+  encode(message: FeaturesAndUpdatesPayload, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.title !== "") {
+      writer.uint32(10).string(message.title);
+    }
+    if (message.description !== "") {
+      writer.uint32(18).string(message.description);
+    }
+    if (message.url !== undefined) {
+      writer.uint32(26).string(message.url);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): FeaturesAndUpdatesPayload {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseFeaturesAndUpdatesPayload();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.title = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.description = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.url = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): FeaturesAndUpdatesPayload {
+    return {
+      title: isSet(object.title) ? globalThis.String(object.title) : "",
+      description: isSet(object.description) ? globalThis.String(object.description) : "",
+      url: isSet(object.url) ? globalThis.String(object.url) : undefined,
+    };
+  },
+
+  toJSON(message: FeaturesAndUpdatesPayload): unknown {
+    const obj: any = {};
+    if (message.title !== "") {
+      obj.title = message.title;
+    }
+    if (message.description !== "") {
+      obj.description = message.description;
+    }
+    if (message.url !== undefined) {
+      obj.url = message.url;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<FeaturesAndUpdatesPayload>, I>>(base?: I): FeaturesAndUpdatesPayload {
+    return FeaturesAndUpdatesPayload.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<FeaturesAndUpdatesPayload>, I>>(object: I): FeaturesAndUpdatesPayload {
+    const message = createBaseFeaturesAndUpdatesPayload();
+    message.title = object.title ?? "";
+    message.description = object.description ?? "";
+    message.url = object.url ?? undefined;
+    return message;
+  },
+};
+
+function createBaseLoginActivityPayload(): LoginActivityPayload {
+  return { login_id: "" };
+}
+
+export const LoginActivityPayload = {
+  encode(message: LoginActivityPayload, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.login_id !== "") {
+      writer.uint32(10).string(message.login_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): LoginActivityPayload {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseLoginActivityPayload();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.login_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): LoginActivityPayload {
+    return { login_id: isSet(object.login_id) ? globalThis.String(object.login_id) : "" };
+  },
+
+  toJSON(message: LoginActivityPayload): unknown {
+    const obj: any = {};
+    if (message.login_id !== "") {
+      obj.login_id = message.login_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<LoginActivityPayload>, I>>(base?: I): LoginActivityPayload {
+    return LoginActivityPayload.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<LoginActivityPayload>, I>>(object: I): LoginActivityPayload {
+    const message = createBaseLoginActivityPayload();
+    message.login_id = object.login_id ?? "";
+    return message;
+  },
+};
+
+function createBaseStoryPayload(): StoryPayload {
+  return { story_id: "", story_slug: "", story_title: "" };
+}
+
+export const StoryPayload = {
+  encode(message: StoryPayload, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.story_id !== "") {
+      writer.uint32(10).string(message.story_id);
+    }
+    if (message.story_slug !== "") {
+      writer.uint32(18).string(message.story_slug);
+    }
+    if (message.story_title !== "") {
+      writer.uint32(26).string(message.story_title);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): StoryPayload {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseStoryPayload();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.story_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.story_slug = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.story_title = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): StoryPayload {
+    return {
+      story_id: isSet(object.story_id) ? globalThis.String(object.story_id) : "",
+      story_slug: isSet(object.story_slug) ? globalThis.String(object.story_slug) : "",
+      story_title: isSet(object.story_title) ? globalThis.String(object.story_title) : "",
+    };
+  },
+
+  toJSON(message: StoryPayload): unknown {
+    const obj: any = {};
+    if (message.story_id !== "") {
+      obj.story_id = message.story_id;
+    }
+    if (message.story_slug !== "") {
+      obj.story_slug = message.story_slug;
+    }
+    if (message.story_title !== "") {
+      obj.story_title = message.story_title;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<StoryPayload>, I>>(base?: I): StoryPayload {
+    return StoryPayload.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<StoryPayload>, I>>(object: I): StoryPayload {
+    const message = createBaseStoryPayload();
+    message.story_id = object.story_id ?? "";
+    message.story_slug = object.story_slug ?? "";
+    message.story_title = object.story_title ?? "";
+    return message;
+  },
+};
+
+function createBaseStoryLikePayload(): StoryLikePayload {
+  return { story_id: "", story_slug: "", story_title: "" };
+}
+
+export const StoryLikePayload = {
+  encode(message: StoryLikePayload, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.story_id !== "") {
+      writer.uint32(10).string(message.story_id);
+    }
+    if (message.story_slug !== "") {
+      writer.uint32(18).string(message.story_slug);
+    }
+    if (message.story_title !== "") {
+      writer.uint32(26).string(message.story_title);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): StoryLikePayload {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseStoryLikePayload();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.story_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.story_slug = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.story_title = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): StoryLikePayload {
+    return {
+      story_id: isSet(object.story_id) ? globalThis.String(object.story_id) : "",
+      story_slug: isSet(object.story_slug) ? globalThis.String(object.story_slug) : "",
+      story_title: isSet(object.story_title) ? globalThis.String(object.story_title) : "",
+    };
+  },
+
+  toJSON(message: StoryLikePayload): unknown {
+    const obj: any = {};
+    if (message.story_id !== "") {
+      obj.story_id = message.story_id;
+    }
+    if (message.story_slug !== "") {
+      obj.story_slug = message.story_slug;
+    }
+    if (message.story_title !== "") {
+      obj.story_title = message.story_title;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<StoryLikePayload>, I>>(base?: I): StoryLikePayload {
+    return StoryLikePayload.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<StoryLikePayload>, I>>(object: I): StoryLikePayload {
+    const message = createBaseStoryLikePayload();
+    message.story_id = object.story_id ?? "";
+    message.story_slug = object.story_slug ?? "";
+    message.story_title = object.story_title ?? "";
+    return message;
+  },
+};
+
+function createBaseTagPayload(): TagPayload {
+  return { tag_id: "", tag_name: "", story_id: "", story_slug: "", story_title: "" };
+}
+
+export const TagPayload = {
+  encode(message: TagPayload, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.tag_id !== "") {
+      writer.uint32(10).string(message.tag_id);
+    }
+    if (message.tag_name !== "") {
+      writer.uint32(18).string(message.tag_name);
+    }
+    if (message.story_id !== "") {
+      writer.uint32(26).string(message.story_id);
+    }
+    if (message.story_slug !== "") {
+      writer.uint32(34).string(message.story_slug);
+    }
+    if (message.story_title !== "") {
+      writer.uint32(42).string(message.story_title);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): TagPayload {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseTagPayload();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.tag_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.tag_name = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.story_id = reader.string();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.story_slug = reader.string();
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.story_title = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): TagPayload {
+    return {
+      tag_id: isSet(object.tag_id) ? globalThis.String(object.tag_id) : "",
+      tag_name: isSet(object.tag_name) ? globalThis.String(object.tag_name) : "",
+      story_id: isSet(object.story_id) ? globalThis.String(object.story_id) : "",
+      story_slug: isSet(object.story_slug) ? globalThis.String(object.story_slug) : "",
+      story_title: isSet(object.story_title) ? globalThis.String(object.story_title) : "",
+    };
+  },
+
+  toJSON(message: TagPayload): unknown {
+    const obj: any = {};
+    if (message.tag_id !== "") {
+      obj.tag_id = message.tag_id;
+    }
+    if (message.tag_name !== "") {
+      obj.tag_name = message.tag_name;
+    }
+    if (message.story_id !== "") {
+      obj.story_id = message.story_id;
+    }
+    if (message.story_slug !== "") {
+      obj.story_slug = message.story_slug;
+    }
+    if (message.story_title !== "") {
+      obj.story_title = message.story_title;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<TagPayload>, I>>(base?: I): TagPayload {
+    return TagPayload.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<TagPayload>, I>>(object: I): TagPayload {
+    const message = createBaseTagPayload();
+    message.tag_id = object.tag_id ?? "";
+    message.tag_name = object.tag_name ?? "";
+    message.story_id = object.story_id ?? "";
+    message.story_slug = object.story_slug ?? "";
+    message.story_title = object.story_title ?? "";
+    return message;
+  },
+};
+
+function createBaseCommentPayload(): CommentPayload {
+  return { comment_id: "", story_id: "", story_slug: "", story_title: "", excerpt: "" };
+}
+
+export const CommentPayload = {
+  encode(message: CommentPayload, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.comment_id !== "") {
+      writer.uint32(10).string(message.comment_id);
+    }
+    if (message.story_id !== "") {
+      writer.uint32(18).string(message.story_id);
+    }
+    if (message.story_slug !== "") {
+      writer.uint32(26).string(message.story_slug);
+    }
+    if (message.story_title !== "") {
+      writer.uint32(34).string(message.story_title);
+    }
+    if (message.excerpt !== "") {
+      writer.uint32(42).string(message.excerpt);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): CommentPayload {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCommentPayload();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.comment_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.story_id = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.story_slug = reader.string();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.story_title = reader.string();
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.excerpt = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): CommentPayload {
+    return {
+      comment_id: isSet(object.comment_id) ? globalThis.String(object.comment_id) : "",
+      story_id: isSet(object.story_id) ? globalThis.String(object.story_id) : "",
+      story_slug: isSet(object.story_slug) ? globalThis.String(object.story_slug) : "",
+      story_title: isSet(object.story_title) ? globalThis.String(object.story_title) : "",
+      excerpt: isSet(object.excerpt) ? globalThis.String(object.excerpt) : "",
+    };
+  },
+
+  toJSON(message: CommentPayload): unknown {
+    const obj: any = {};
+    if (message.comment_id !== "") {
+      obj.comment_id = message.comment_id;
+    }
+    if (message.story_id !== "") {
+      obj.story_id = message.story_id;
+    }
+    if (message.story_slug !== "") {
+      obj.story_slug = message.story_slug;
+    }
+    if (message.story_title !== "") {
+      obj.story_title = message.story_title;
+    }
+    if (message.excerpt !== "") {
+      obj.excerpt = message.excerpt;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<CommentPayload>, I>>(base?: I): CommentPayload {
+    return CommentPayload.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<CommentPayload>, I>>(object: I): CommentPayload {
+    const message = createBaseCommentPayload();
+    message.comment_id = object.comment_id ?? "";
+    message.story_id = object.story_id ?? "";
+    message.story_slug = object.story_slug ?? "";
+    message.story_title = object.story_title ?? "";
+    message.excerpt = object.excerpt ?? "";
+    return message;
+  },
+};
+
+function createBaseReplyPayload(): ReplyPayload {
+  return { reply_id: "", comment_id: "", story_id: "", story_slug: "", excerpt: "" };
+}
+
+export const ReplyPayload = {
+  encode(message: ReplyPayload, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.reply_id !== "") {
+      writer.uint32(10).string(message.reply_id);
+    }
+    if (message.comment_id !== "") {
+      writer.uint32(18).string(message.comment_id);
+    }
+    if (message.story_id !== "") {
+      writer.uint32(26).string(message.story_id);
+    }
+    if (message.story_slug !== "") {
+      writer.uint32(34).string(message.story_slug);
+    }
+    if (message.excerpt !== "") {
+      writer.uint32(42).string(message.excerpt);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ReplyPayload {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseReplyPayload();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.reply_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.comment_id = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.story_id = reader.string();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.story_slug = reader.string();
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.excerpt = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ReplyPayload {
+    return {
+      reply_id: isSet(object.reply_id) ? globalThis.String(object.reply_id) : "",
+      comment_id: isSet(object.comment_id) ? globalThis.String(object.comment_id) : "",
+      story_id: isSet(object.story_id) ? globalThis.String(object.story_id) : "",
+      story_slug: isSet(object.story_slug) ? globalThis.String(object.story_slug) : "",
+      excerpt: isSet(object.excerpt) ? globalThis.String(object.excerpt) : "",
+    };
+  },
+
+  toJSON(message: ReplyPayload): unknown {
+    const obj: any = {};
+    if (message.reply_id !== "") {
+      obj.reply_id = message.reply_id;
+    }
+    if (message.comment_id !== "") {
+      obj.comment_id = message.comment_id;
+    }
+    if (message.story_id !== "") {
+      obj.story_id = message.story_id;
+    }
+    if (message.story_slug !== "") {
+      obj.story_slug = message.story_slug;
+    }
+    if (message.excerpt !== "") {
+      obj.excerpt = message.excerpt;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ReplyPayload>, I>>(base?: I): ReplyPayload {
+    return ReplyPayload.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ReplyPayload>, I>>(object: I): ReplyPayload {
+    const message = createBaseReplyPayload();
+    message.reply_id = object.reply_id ?? "";
+    message.comment_id = object.comment_id ?? "";
+    message.story_id = object.story_id ?? "";
+    message.story_slug = object.story_slug ?? "";
+    message.excerpt = object.excerpt ?? "";
+    return message;
+  },
+};
+
+function createBaseNewFollowerPayload(): NewFollowerPayload {
+  return {};
+}
+
+export const NewFollowerPayload = {
+  encode(_: NewFollowerPayload, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): NewFollowerPayload {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseNewFollowerPayload();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(_: any): NewFollowerPayload {
+    return {};
+  },
+
+  toJSON(_: NewFollowerPayload): unknown {
+    const obj: any = {};
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<NewFollowerPayload>, I>>(base?: I): NewFollowerPayload {
+    return NewFollowerPayload.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<NewFollowerPayload>, I>>(_: I): NewFollowerPayload {
+    const message = createBaseNewFollowerPayload();
+    return message;
+  },
+};
+
+function createBaseFriendRequestPayload(): FriendRequestPayload {
+  return { friend_request_id: undefined };
+}
+
+export const FriendRequestPayload = {
+  encode(message: FriendRequestPayload, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.friend_request_id !== undefined) {
+      writer.uint32(10).string(message.friend_request_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): FriendRequestPayload {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseFriendRequestPayload();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.friend_request_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): FriendRequestPayload {
+    return {
+      friend_request_id: isSet(object.friend_request_id) ? globalThis.String(object.friend_request_id) : undefined,
+    };
+  },
+
+  toJSON(message: FriendRequestPayload): unknown {
+    const obj: any = {};
+    if (message.friend_request_id !== undefined) {
+      obj.friend_request_id = message.friend_request_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<FriendRequestPayload>, I>>(base?: I): FriendRequestPayload {
+    return FriendRequestPayload.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<FriendRequestPayload>, I>>(object: I): FriendRequestPayload {
+    const message = createBaseFriendRequestPayload();
+    message.friend_request_id = object.friend_request_id ?? undefined;
+    return message;
+  },
+};
+
+function createBaseCollaborationRequestPayload(): CollaborationRequestPayload {
+  return { collaboration_request_id: undefined, story_id: "", story_title: "" };
+}
+
+export const CollaborationRequestPayload = {
+  encode(message: CollaborationRequestPayload, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.collaboration_request_id !== undefined) {
+      writer.uint32(10).string(message.collaboration_request_id);
+    }
+    if (message.story_id !== "") {
+      writer.uint32(18).string(message.story_id);
+    }
+    if (message.story_title !== "") {
+      writer.uint32(26).string(message.story_title);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): CollaborationRequestPayload {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCollaborationRequestPayload();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.collaboration_request_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.story_id = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.story_title = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): CollaborationRequestPayload {
+    return {
+      collaboration_request_id: isSet(object.collaboration_request_id)
+        ? globalThis.String(object.collaboration_request_id)
+        : undefined,
+      story_id: isSet(object.story_id) ? globalThis.String(object.story_id) : "",
+      story_title: isSet(object.story_title) ? globalThis.String(object.story_title) : "",
+    };
+  },
+
+  toJSON(message: CollaborationRequestPayload): unknown {
+    const obj: any = {};
+    if (message.collaboration_request_id !== undefined) {
+      obj.collaboration_request_id = message.collaboration_request_id;
+    }
+    if (message.story_id !== "") {
+      obj.story_id = message.story_id;
+    }
+    if (message.story_title !== "") {
+      obj.story_title = message.story_title;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<CollaborationRequestPayload>, I>>(base?: I): CollaborationRequestPayload {
+    return CollaborationRequestPayload.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<CollaborationRequestPayload>, I>>(object: I): CollaborationRequestPayload {
+    const message = createBaseCollaborationRequestPayload();
+    message.collaboration_request_id = object.collaboration_request_id ?? undefined;
+    message.story_id = object.story_id ?? "";
+    message.story_title = object.story_title ?? "";
+    return message;
+  },
+};
+
+function createBaseBlogRequestPayload(): BlogRequestPayload {
+  return { blog_request_id: "", blog_id: "", blog_name: "", blog_slug: "" };
+}
+
+export const BlogRequestPayload = {
+  encode(message: BlogRequestPayload, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.blog_request_id !== "") {
+      writer.uint32(10).string(message.blog_request_id);
+    }
+    if (message.blog_id !== "") {
+      writer.uint32(18).string(message.blog_id);
+    }
+    if (message.blog_name !== "") {
+      writer.uint32(26).string(message.blog_name);
+    }
+    if (message.blog_slug !== "") {
+      writer.uint32(34).string(message.blog_slug);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): BlogRequestPayload {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseBlogRequestPayload();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.blog_request_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.blog_id = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.blog_name = reader.string();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.blog_slug = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): BlogRequestPayload {
+    return {
+      blog_request_id: isSet(object.blog_request_id) ? globalThis.String(object.blog_request_id) : "",
+      blog_id: isSet(object.blog_id) ? globalThis.String(object.blog_id) : "",
+      blog_name: isSet(object.blog_name) ? globalThis.String(object.blog_name) : "",
+      blog_slug: isSet(object.blog_slug) ? globalThis.String(object.blog_slug) : "",
+    };
+  },
+
+  toJSON(message: BlogRequestPayload): unknown {
+    const obj: any = {};
+    if (message.blog_request_id !== "") {
+      obj.blog_request_id = message.blog_request_id;
+    }
+    if (message.blog_id !== "") {
+      obj.blog_id = message.blog_id;
+    }
+    if (message.blog_name !== "") {
+      obj.blog_name = message.blog_name;
+    }
+    if (message.blog_slug !== "") {
+      obj.blog_slug = message.blog_slug;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<BlogRequestPayload>, I>>(base?: I): BlogRequestPayload {
+    return BlogRequestPayload.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<BlogRequestPayload>, I>>(object: I): BlogRequestPayload {
+    const message = createBaseBlogRequestPayload();
+    message.blog_request_id = object.blog_request_id ?? "";
+    message.blog_id = object.blog_id ?? "";
+    message.blog_name = object.blog_name ?? "";
+    message.blog_slug = object.blog_slug ?? "";
+    return message;
+  },
+};
+
+function createBaseNotification(): Notification {
+  return {
+    id: "",
+    type: 0,
+    actor: undefined,
+    read_at: undefined,
+    created_at: undefined,
+    features_and_updates: undefined,
+    login_activity: undefined,
+    story: undefined,
+    story_like: undefined,
+    tag: undefined,
+    comment: undefined,
+    reply: undefined,
+    new_follower: undefined,
+    friend_request: undefined,
+    collaboration_request: undefined,
+    blog_request: undefined,
+  };
+}
+
+export const Notification = {
+  encode(message: Notification, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.id !== "") {
+      writer.uint32(10).string(message.id);
+    }
+    if (message.type !== 0) {
+      writer.uint32(16).int32(message.type);
+    }
+    if (message.actor !== undefined) {
+      BareUser.encode(message.actor, writer.uint32(26).fork()).ldelim();
+    }
+    if (message.read_at !== undefined) {
+      Timestamp.encode(toTimestamp(message.read_at), writer.uint32(34).fork()).ldelim();
+    }
+    if (message.created_at !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at), writer.uint32(42).fork()).ldelim();
+    }
+    if (message.features_and_updates !== undefined) {
+      FeaturesAndUpdatesPayload.encode(message.features_and_updates, writer.uint32(50).fork()).ldelim();
+    }
+    if (message.login_activity !== undefined) {
+      LoginActivityPayload.encode(message.login_activity, writer.uint32(58).fork()).ldelim();
+    }
+    if (message.story !== undefined) {
+      StoryPayload.encode(message.story, writer.uint32(66).fork()).ldelim();
+    }
+    if (message.story_like !== undefined) {
+      StoryLikePayload.encode(message.story_like, writer.uint32(74).fork()).ldelim();
+    }
+    if (message.tag !== undefined) {
+      TagPayload.encode(message.tag, writer.uint32(82).fork()).ldelim();
+    }
+    if (message.comment !== undefined) {
+      CommentPayload.encode(message.comment, writer.uint32(90).fork()).ldelim();
+    }
+    if (message.reply !== undefined) {
+      ReplyPayload.encode(message.reply, writer.uint32(98).fork()).ldelim();
+    }
+    if (message.new_follower !== undefined) {
+      NewFollowerPayload.encode(message.new_follower, writer.uint32(106).fork()).ldelim();
+    }
+    if (message.friend_request !== undefined) {
+      FriendRequestPayload.encode(message.friend_request, writer.uint32(114).fork()).ldelim();
+    }
+    if (message.collaboration_request !== undefined) {
+      CollaborationRequestPayload.encode(message.collaboration_request, writer.uint32(122).fork()).ldelim();
+    }
+    if (message.blog_request !== undefined) {
+      BlogRequestPayload.encode(message.blog_request, writer.uint32(130).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): Notification {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseNotification();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.type = reader.int32() as any;
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.actor = BareUser.decode(reader, reader.uint32());
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.read_at = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.created_at = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 6:
+          if (tag !== 50) {
+            break;
+          }
+
+          message.features_and_updates = FeaturesAndUpdatesPayload.decode(reader, reader.uint32());
+          continue;
+        case 7:
+          if (tag !== 58) {
+            break;
+          }
+
+          message.login_activity = LoginActivityPayload.decode(reader, reader.uint32());
+          continue;
+        case 8:
+          if (tag !== 66) {
+            break;
+          }
+
+          message.story = StoryPayload.decode(reader, reader.uint32());
+          continue;
+        case 9:
+          if (tag !== 74) {
+            break;
+          }
+
+          message.story_like = StoryLikePayload.decode(reader, reader.uint32());
+          continue;
+        case 10:
+          if (tag !== 82) {
+            break;
+          }
+
+          message.tag = TagPayload.decode(reader, reader.uint32());
+          continue;
+        case 11:
+          if (tag !== 90) {
+            break;
+          }
+
+          message.comment = CommentPayload.decode(reader, reader.uint32());
+          continue;
+        case 12:
+          if (tag !== 98) {
+            break;
+          }
+
+          message.reply = ReplyPayload.decode(reader, reader.uint32());
+          continue;
+        case 13:
+          if (tag !== 106) {
+            break;
+          }
+
+          message.new_follower = NewFollowerPayload.decode(reader, reader.uint32());
+          continue;
+        case 14:
+          if (tag !== 114) {
+            break;
+          }
+
+          message.friend_request = FriendRequestPayload.decode(reader, reader.uint32());
+          continue;
+        case 15:
+          if (tag !== 122) {
+            break;
+          }
+
+          message.collaboration_request = CollaborationRequestPayload.decode(reader, reader.uint32());
+          continue;
+        case 16:
+          if (tag !== 130) {
+            break;
+          }
+
+          message.blog_request = BlogRequestPayload.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): Notification {
+    return {
+      id: isSet(object.id) ? globalThis.String(object.id) : "",
+      type: isSet(object.type) ? notificationTypeFromJSON(object.type) : 0,
+      actor: isSet(object.actor) ? BareUser.fromJSON(object.actor) : undefined,
+      read_at: isSet(object.read_at) ? fromJsonTimestamp(object.read_at) : undefined,
+      created_at: isSet(object.created_at) ? fromJsonTimestamp(object.created_at) : undefined,
+      features_and_updates: isSet(object.features_and_updates)
+        ? FeaturesAndUpdatesPayload.fromJSON(object.features_and_updates)
+        : undefined,
+      login_activity: isSet(object.login_activity) ? LoginActivityPayload.fromJSON(object.login_activity) : undefined,
+      story: isSet(object.story) ? StoryPayload.fromJSON(object.story) : undefined,
+      story_like: isSet(object.story_like) ? StoryLikePayload.fromJSON(object.story_like) : undefined,
+      tag: isSet(object.tag) ? TagPayload.fromJSON(object.tag) : undefined,
+      comment: isSet(object.comment) ? CommentPayload.fromJSON(object.comment) : undefined,
+      reply: isSet(object.reply) ? ReplyPayload.fromJSON(object.reply) : undefined,
+      new_follower: isSet(object.new_follower) ? NewFollowerPayload.fromJSON(object.new_follower) : undefined,
+      friend_request: isSet(object.friend_request) ? FriendRequestPayload.fromJSON(object.friend_request) : undefined,
+      collaboration_request: isSet(object.collaboration_request)
+        ? CollaborationRequestPayload.fromJSON(object.collaboration_request)
+        : undefined,
+      blog_request: isSet(object.blog_request) ? BlogRequestPayload.fromJSON(object.blog_request) : undefined,
+    };
+  },
+
+  toJSON(message: Notification): unknown {
+    const obj: any = {};
+    if (message.id !== "") {
+      obj.id = message.id;
+    }
+    if (message.type !== 0) {
+      obj.type = notificationTypeToJSON(message.type);
+    }
+    if (message.actor !== undefined) {
+      obj.actor = BareUser.toJSON(message.actor);
+    }
+    if (message.read_at !== undefined) {
+      obj.read_at = message.read_at.toISOString();
+    }
+    if (message.created_at !== undefined) {
+      obj.created_at = message.created_at.toISOString();
+    }
+    if (message.features_and_updates !== undefined) {
+      obj.features_and_updates = FeaturesAndUpdatesPayload.toJSON(message.features_and_updates);
+    }
+    if (message.login_activity !== undefined) {
+      obj.login_activity = LoginActivityPayload.toJSON(message.login_activity);
+    }
+    if (message.story !== undefined) {
+      obj.story = StoryPayload.toJSON(message.story);
+    }
+    if (message.story_like !== undefined) {
+      obj.story_like = StoryLikePayload.toJSON(message.story_like);
+    }
+    if (message.tag !== undefined) {
+      obj.tag = TagPayload.toJSON(message.tag);
+    }
+    if (message.comment !== undefined) {
+      obj.comment = CommentPayload.toJSON(message.comment);
+    }
+    if (message.reply !== undefined) {
+      obj.reply = ReplyPayload.toJSON(message.reply);
+    }
+    if (message.new_follower !== undefined) {
+      obj.new_follower = NewFollowerPayload.toJSON(message.new_follower);
+    }
+    if (message.friend_request !== undefined) {
+      obj.friend_request = FriendRequestPayload.toJSON(message.friend_request);
+    }
+    if (message.collaboration_request !== undefined) {
+      obj.collaboration_request = CollaborationRequestPayload.toJSON(message.collaboration_request);
+    }
+    if (message.blog_request !== undefined) {
+      obj.blog_request = BlogRequestPayload.toJSON(message.blog_request);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<Notification>, I>>(base?: I): Notification {
+    return Notification.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<Notification>, I>>(object: I): Notification {
+    const message = createBaseNotification();
+    message.id = object.id ?? "";
+    message.type = object.type ?? 0;
+    message.actor = (object.actor !== undefined && object.actor !== null)
+      ? BareUser.fromPartial(object.actor)
+      : undefined;
+    message.read_at = object.read_at ?? undefined;
+    message.created_at = object.created_at ?? undefined;
+    message.features_and_updates = (object.features_and_updates !== undefined && object.features_and_updates !== null)
+      ? FeaturesAndUpdatesPayload.fromPartial(object.features_and_updates)
+      : undefined;
+    message.login_activity = (object.login_activity !== undefined && object.login_activity !== null)
+      ? LoginActivityPayload.fromPartial(object.login_activity)
+      : undefined;
+    message.story = (object.story !== undefined && object.story !== null)
+      ? StoryPayload.fromPartial(object.story)
+      : undefined;
+    message.story_like = (object.story_like !== undefined && object.story_like !== null)
+      ? StoryLikePayload.fromPartial(object.story_like)
+      : undefined;
+    message.tag = (object.tag !== undefined && object.tag !== null) ? TagPayload.fromPartial(object.tag) : undefined;
+    message.comment = (object.comment !== undefined && object.comment !== null)
+      ? CommentPayload.fromPartial(object.comment)
+      : undefined;
+    message.reply = (object.reply !== undefined && object.reply !== null)
+      ? ReplyPayload.fromPartial(object.reply)
+      : undefined;
+    message.new_follower = (object.new_follower !== undefined && object.new_follower !== null)
+      ? NewFollowerPayload.fromPartial(object.new_follower)
+      : undefined;
+    message.friend_request = (object.friend_request !== undefined && object.friend_request !== null)
+      ? FriendRequestPayload.fromPartial(object.friend_request)
+      : undefined;
+    message.collaboration_request = (object.collaboration_request !== undefined && object.collaboration_request !== null)
+      ? CollaborationRequestPayload.fromPartial(object.collaboration_request)
+      : undefined;
+    message.blog_request = (object.blog_request !== undefined && object.blog_request !== null)
+      ? BlogRequestPayload.fromPartial(object.blog_request)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseListNotificationsRequest(): ListNotificationsRequest {
+  return { user_id: "", cursor: undefined, limit: 0, unread_only: false };
+}
+
+export const ListNotificationsRequest = {
+  encode(message: ListNotificationsRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.cursor !== undefined) {
+      writer.uint32(18).string(message.cursor);
+    }
+    if (message.limit !== 0) {
+      writer.uint32(24).uint32(message.limit);
+    }
+    if (message.unread_only === true) {
+      writer.uint32(32).bool(message.unread_only);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ListNotificationsRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseListNotificationsRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.cursor = reader.string();
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.limit = reader.uint32();
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.unread_only = reader.bool();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ListNotificationsRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      cursor: isSet(object.cursor) ? globalThis.String(object.cursor) : undefined,
+      limit: isSet(object.limit) ? globalThis.Number(object.limit) : 0,
+      unread_only: isSet(object.unread_only) ? globalThis.Boolean(object.unread_only) : false,
+    };
+  },
+
+  toJSON(message: ListNotificationsRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.cursor !== undefined) {
+      obj.cursor = message.cursor;
+    }
+    if (message.limit !== 0) {
+      obj.limit = Math.round(message.limit);
+    }
+    if (message.unread_only === true) {
+      obj.unread_only = message.unread_only;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ListNotificationsRequest>, I>>(base?: I): ListNotificationsRequest {
+    return ListNotificationsRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ListNotificationsRequest>, I>>(object: I): ListNotificationsRequest {
+    const message = createBaseListNotificationsRequest();
+    message.user_id = object.user_id ?? "";
+    message.cursor = object.cursor ?? undefined;
+    message.limit = object.limit ?? 0;
+    message.unread_only = object.unread_only ?? false;
+    return message;
+  },
+};
+
+function createBaseListNotificationsResponse(): ListNotificationsResponse {
+  return { notifications: [], next_cursor: undefined };
+}
+
+export const ListNotificationsResponse = {
+  encode(message: ListNotificationsResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    for (const v of message.notifications) {
+      Notification.encode(v!, writer.uint32(10).fork()).ldelim();
+    }
+    if (message.next_cursor !== undefined) {
+      writer.uint32(18).string(message.next_cursor);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ListNotificationsResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseListNotificationsResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.notifications.push(Notification.decode(reader, reader.uint32()));
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.next_cursor = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ListNotificationsResponse {
+    return {
+      notifications: globalThis.Array.isArray(object?.notifications)
+        ? object.notifications.map((e: any) => Notification.fromJSON(e))
+        : [],
+      next_cursor: isSet(object.next_cursor) ? globalThis.String(object.next_cursor) : undefined,
+    };
+  },
+
+  toJSON(message: ListNotificationsResponse): unknown {
+    const obj: any = {};
+    if (message.notifications?.length) {
+      obj.notifications = message.notifications.map((e) => Notification.toJSON(e));
+    }
+    if (message.next_cursor !== undefined) {
+      obj.next_cursor = message.next_cursor;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ListNotificationsResponse>, I>>(base?: I): ListNotificationsResponse {
+    return ListNotificationsResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ListNotificationsResponse>, I>>(object: I): ListNotificationsResponse {
+    const message = createBaseListNotificationsResponse();
+    message.notifications = object.notifications?.map((e) => Notification.fromPartial(e)) || [];
+    message.next_cursor = object.next_cursor ?? undefined;
+    return message;
+  },
+};
+
+function createBaseMarkNotificationsReadRequest(): MarkNotificationsReadRequest {
+  return { user_id: "", notification_ids: [], all: false };
+}
+
+export const MarkNotificationsReadRequest = {
+  encode(message: MarkNotificationsReadRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    for (const v of message.notification_ids) {
+      writer.uint32(18).string(v!);
+    }
+    if (message.all === true) {
+      writer.uint32(24).bool(message.all);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): MarkNotificationsReadRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseMarkNotificationsReadRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.notification_ids.push(reader.string());
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.all = reader.bool();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): MarkNotificationsReadRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      notification_ids: globalThis.Array.isArray(object?.notification_ids)
+        ? object.notification_ids.map((e: any) => globalThis.String(e))
+        : [],
+      all: isSet(object.all) ? globalThis.Boolean(object.all) : false,
+    };
+  },
+
+  toJSON(message: MarkNotificationsReadRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.notification_ids?.length) {
+      obj.notification_ids = message.notification_ids;
+    }
+    if (message.all === true) {
+      obj.all = message.all;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<MarkNotificationsReadRequest>, I>>(base?: I): MarkNotificationsReadRequest {
+    return MarkNotificationsReadRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<MarkNotificationsReadRequest>, I>>(object: I): MarkNotificationsReadRequest {
+    const message = createBaseMarkNotificationsReadRequest();
+    message.user_id = object.user_id ?? "";
+    message.notification_ids = object.notification_ids?.map((e) => e) || [];
+    message.all = object.all ?? false;
+    return message;
+  },
+};
+
+function createBaseMarkNotificationsReadResponse(): MarkNotificationsReadResponse {
+  return { unread_count: 0 };
+}
+
+export const MarkNotificationsReadResponse = {
+  encode(message: MarkNotificationsReadResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.unread_count !== 0) {
+      writer.uint32(8).uint32(message.unread_count);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): MarkNotificationsReadResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseMarkNotificationsReadResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.unread_count = reader.uint32();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): MarkNotificationsReadResponse {
+    return { unread_count: isSet(object.unread_count) ? globalThis.Number(object.unread_count) : 0 };
+  },
+
+  toJSON(message: MarkNotificationsReadResponse): unknown {
+    const obj: any = {};
+    if (message.unread_count !== 0) {
+      obj.unread_count = Math.round(message.unread_count);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<MarkNotificationsReadResponse>, I>>(base?: I): MarkNotificationsReadResponse {
+    return MarkNotificationsReadResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<MarkNotificationsReadResponse>, I>>(
+    object: I,
+  ): MarkNotificationsReadResponse {
+    const message = createBaseMarkNotificationsReadResponse();
+    message.unread_count = object.unread_count ?? 0;
+    return message;
+  },
+};
+
+function createBaseGetUnreadNotificationCountRequest(): GetUnreadNotificationCountRequest {
+  return { user_id: "" };
+}
+
+export const GetUnreadNotificationCountRequest = {
+  encode(message: GetUnreadNotificationCountRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): GetUnreadNotificationCountRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseGetUnreadNotificationCountRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): GetUnreadNotificationCountRequest {
+    return { user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "" };
+  },
+
+  toJSON(message: GetUnreadNotificationCountRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<GetUnreadNotificationCountRequest>, I>>(
+    base?: I,
+  ): GetUnreadNotificationCountRequest {
+    return GetUnreadNotificationCountRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<GetUnreadNotificationCountRequest>, I>>(
+    object: I,
+  ): GetUnreadNotificationCountRequest {
+    const message = createBaseGetUnreadNotificationCountRequest();
+    message.user_id = object.user_id ?? "";
+    return message;
+  },
+};
+
+function createBaseGetUnreadNotificationCountResponse(): GetUnreadNotificationCountResponse {
+  return { unread_count: 0 };
+}
+
+export const GetUnreadNotificationCountResponse = {
+  encode(message: GetUnreadNotificationCountResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.unread_count !== 0) {
+      writer.uint32(8).uint32(message.unread_count);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): GetUnreadNotificationCountResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseGetUnreadNotificationCountResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.unread_count = reader.uint32();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): GetUnreadNotificationCountResponse {
+    return { unread_count: isSet(object.unread_count) ? globalThis.Number(object.unread_count) : 0 };
+  },
+
+  toJSON(message: GetUnreadNotificationCountResponse): unknown {
+    const obj: any = {};
+    if (message.unread_count !== 0) {
+      obj.unread_count = Math.round(message.unread_count);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<GetUnreadNotificationCountResponse>, I>>(
+    base?: I,
+  ): GetUnreadNotificationCountResponse {
+    return GetUnreadNotificationCountResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<GetUnreadNotificationCountResponse>, I>>(
+    object: I,
+  ): GetUnreadNotificationCountResponse {
+    const message = createBaseGetUnreadNotificationCountResponse();
+    message.unread_count = object.unread_count ?? 0;
+    return message;
+  },
+};
+
+function createBaseWatchNotificationsRequest(): WatchNotificationsRequest {
+  return { user_id: "" };
+}
+
+export const WatchNotificationsRequest = {
+  encode(message: WatchNotificationsRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): WatchNotificationsRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseWatchNotificationsRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): WatchNotificationsRequest {
+    return { user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "" };
+  },
+
+  toJSON(message: WatchNotificationsRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<WatchNotificationsRequest>, I>>(base?: I): WatchNotificationsRequest {
+    return WatchNotificationsRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<WatchNotificationsRequest>, I>>(object: I): WatchNotificationsRequest {
+    const message = createBaseWatchNotificationsRequest();
+    message.user_id = object.user_id ?? "";
+    return message;
+  },
+};
+
+function createBaseWatchNotificationsResponse(): WatchNotificationsResponse {
+  return { notification: undefined, unread_count: 0 };
+}
+
+export const WatchNotificationsResponse = {
+  encode(message: WatchNotificationsResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.notification !== undefined) {
+      Notification.encode(message.notification, writer.uint32(10).fork()).ldelim();
+    }
+    if (message.unread_count !== 0) {
+      writer.uint32(16).uint32(message.unread_count);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): WatchNotificationsResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseWatchNotificationsResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.notification = Notification.decode(reader, reader.uint32());
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.unread_count = reader.uint32();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): WatchNotificationsResponse {
+    return {
+      notification: isSet(object.notification) ? Notification.fromJSON(object.notification) : undefined,
+      unread_count: isSet(object.unread_count) ? globalThis.Number(object.unread_count) : 0,
+    };
+  },
+
+  toJSON(message: WatchNotificationsResponse): unknown {
+    const obj: any = {};
+    if (message.notification !== undefined) {
+      obj.notification = Notification.toJSON(message.notification);
+    }
+    if (message.unread_count !== 0) {
+      obj.unread_count = Math.round(message.unread_count);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<WatchNotificationsResponse>, I>>(base?: I): WatchNotificationsResponse {
+    return WatchNotificationsResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<WatchNotificationsResponse>, I>>(object: I): WatchNotificationsResponse {
+    const message = createBaseWatchNotificationsResponse();
+    message.notification = (object.notification !== undefined && object.notification !== null)
+      ? Notification.fromPartial(object.notification)
+      : undefined;
+    message.unread_count = object.unread_count ?? 0;
+    return message;
+  },
+};
+
+type Builtin = Date | Function | Uint8Array | string | number | boolean | undefined;
+
+export type DeepPartial<T> = T extends Builtin ? T
+  : T extends globalThis.Array<infer U> ? globalThis.Array<DeepPartial<U>>
+  : T extends ReadonlyArray<infer U> ? ReadonlyArray<DeepPartial<U>>
+  : T extends {} ? { [K in keyof T]?: DeepPartial<T[K]> }
+  : Partial<T>;
+
+type KeysOfUnion<T> = T extends T ? keyof T : never;
+export type Exact<P, I extends P> = P extends Builtin ? P
+  : P & { [K in keyof P]: Exact<P[K], I[K]> } & { [K in Exclude<keyof I, KeysOfUnion<P>>]: never };
+
+function toTimestamp(date: Date): Timestamp {
+  const seconds = Math.trunc(date.getTime() / 1_000);
+  const nanos = (date.getTime() % 1_000) * 1_000_000;
+  return { seconds, nanos };
+}
+
+function fromTimestamp(t: Timestamp): Date {
+  let millis = (t.seconds || 0) * 1_000;
+  millis += (t.nanos || 0) / 1_000_000;
+  return new globalThis.Date(millis);
+}
+
+function fromJsonTimestamp(o: any): Date {
+  if (o instanceof globalThis.Date) {
+    return o;
+  } else if (typeof o === "string") {
+    return new globalThis.Date(o);
+  } else {
+    return fromTimestamp(Timestamp.fromJSON(o));
+  }
+}
+
+function isSet(value: any): boolean {
+  return value !== null && value !== undefined;
+}
@@ -1,5 +1,6 @@
 /* eslint-disable */
 import _m0 from "protobufjs/minimal";
+import { FieldMask } from "../../google/protobuf/field_mask";
 
 export const protobufPackage = "notification_settings_def.v1";
 
@@ -19,6 +20,7 @@ export interface GetNotificationSettingsResponse {
   friend_requests: boolean;
   collaboration_requests: boolean;
   blog_requests: boolean;
+  mentions: boolean;
   /** Mail notifications */
   mail_login_activity: boolean;
   mail_features_and_updates: boolean;
@@ -26,6 +28,17 @@ export interface GetNotificationSettingsResponse {
   mail_digest: boolean;
 }
 
+export interface UpdateNotificationSettingsRequest {
+  user_id: string;
+  /** Paths of the fields in `settings` to update */
+  update_mask: string[] | undefined;
+  settings: GetNotificationSettingsResponse | undefined;
+}
+
+export interface UpdateNotificationSettingsResponse {
+  settings: GetNotificationSettingsResponse | undefined;
+}
+
 function createBaseGetNotificationSettingsRequest(): GetNotificationSettingsRequest {
   return { user_id: "" };
 }
@@ -97,6 +110,7 @@ function createBaseGetNotificationSettingsResponse(): GetNotificationSettingsRes
     friend_requests: false,
     collaboration_requests: false,
     blog_requests: false,
+    mentions: false,
     mail_login_activity: false,
     mail_features_and_updates: false,
     mail_newsletters: false,
@@ -136,6 +150,9 @@ export const GetNotificationSettingsResponse = {
     if (message.blog_requests === true) {
       writer.uint32(80).bool(message.blog_requests);
     }
+    if (message.mentions === true) {
+      writer.uint32(120).bool(message.mentions);
+    }
     if (message.mail_login_activity === true) {
       writer.uint32(88).bool(message.mail_login_activity);
     }
@@ -228,6 +245,13 @@ export const GetNotificationSettingsResponse = {
 
           message.blog_requests = reader.bool();
           continue;
+        case 15:
+          if (tag !== 120) {
+            break;
+          }
+
+          message.mentions = reader.bool();
+          continue;
         case 11:
           if (tag !== 88) {
             break;
@@ -281,6 +305,7 @@ export const GetNotificationSettingsResponse = {
         ? globalThis.Boolean(object.collaboration_requests)
         : false,
       blog_requests: isSet(object.blog_requests) ? globalThis.Boolean(object.blog_requests) : false,
+      mentions: isSet(object.mentions) ? globalThis.Boolean(object.mentions) : false,
       mail_login_activity: isSet(object.mail_login_activity) ? globalThis.Boolean(object.mail_login_activity) : false,
       mail_features_and_updates: isSet(object.mail_features_and_updates)
         ? globalThis.Boolean(object.mail_features_and_updates)
@@ -322,6 +347,9 @@ export const GetNotificationSettingsResponse = {
     if (message.blog_requests === true) {
       obj.blog_requests = message.blog_requests;
     }
+    if (message.mentions === true) {
+      obj.mentions = message.mentions;
+    }
     if (message.mail_login_activity === true) {
       obj.mail_login_activity = message.mail_login_activity;
     }
@@ -354,6 +382,7 @@ export const GetNotificationSettingsResponse = {
     message.friend_requests = object.friend_requests ?? false;
     message.collaboration_requests = object.collaboration_requests ?? false;
     message.blog_requests = object.blog_requests ?? false;
+    message.mentions = object.mentions ?? false;
     message.mail_login_activity = object.mail_login_activity ?? false;
     message.mail_features_and_updates = object.mail_features_and_updates ?? false;
     message.mail_newsletters = object.mail_newsletters ?? false;
@@ -362,6 +391,164 @@ export const GetNotificationSettingsResponse = {
   },
 };
 
+function createBaseUpdateNotificationSettingsRequest(): UpdateNotificationSettingsRequest {
+  return { user_id: "", update_mask: undefined, settings: undefined };
+}
+
+export const UpdateNotificationSettingsRequest = {
+  encode(message: UpdateNotificationSettingsRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.update_mask !== undefined) {
+      FieldMask.encode(FieldMask.wrap(message.update_mask), writer.uint32(18).fork()).ldelim();
+    }
+    if (message.settings !== undefined) {
+      GetNotificationSettingsResponse.encode(message.settings, writer.uint32(26).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): UpdateNotificationSettingsRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUpdateNotificationSettingsRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.update_mask = FieldMask.unwrap(FieldMask.decode(reader, reader.uint32()));
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.settings = GetNotificationSettingsResponse.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UpdateNotificationSettingsRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      update_mask: isSet(object.update_mask) ? FieldMask.unwrap(FieldMask.fromJSON(object.update_mask)) : undefined,
+      settings: isSet(object.settings) ? GetNotificationSettingsResponse.fromJSON(object.settings) : undefined,
+    };
+  },
+
+  toJSON(message: UpdateNotificationSettingsRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.update_mask !== undefined) {
+      obj.update_mask = FieldMask.toJSON(FieldMask.wrap(message.update_mask));
+    }
+    if (message.settings !== undefined) {
+      obj.settings = GetNotificationSettingsResponse.toJSON(message.settings);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UpdateNotificationSettingsRequest>, I>>(
+    base?: I,
+  ): UpdateNotificationSettingsRequest {
+    return UpdateNotificationSettingsRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UpdateNotificationSettingsRequest>, I>>(
+    object: I,
+  ): UpdateNotificationSettingsRequest {
+    const message = createBaseUpdateNotificationSettingsRequest();
+    message.user_id = object.user_id ?? "";
+    message.update_mask = object.update_mask ?? undefined;
+    message.settings = (object.settings !== undefined && object.settings !== null)
+      ? GetNotificationSettingsResponse.fromPartial(object.settings)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseUpdateNotificationSettingsResponse(): UpdateNotificationSettingsResponse {
+  return { settings: undefined };
+}
+
+export const UpdateNotificationSettingsResponse = {
+  encode(message: UpdateNotificationSettingsResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.settings !== undefined) {
+      GetNotificationSettingsResponse.encode(message.settings, writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): UpdateNotificationSettingsResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUpdateNotificationSettingsResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.settings = GetNotificationSettingsResponse.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UpdateNotificationSettingsResponse {
+    return { settings: isSet(object.settings) ? GetNotificationSettingsResponse.fromJSON(object.settings) : undefined };
+  },
+
+  toJSON(message: UpdateNotificationSettingsResponse): unknown {
+    const obj: any = {};
+    if (message.settings !== undefined) {
+      obj.settings = GetNotificationSettingsResponse.toJSON(message.settings);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UpdateNotificationSettingsResponse>, I>>(
+    base?: I,
+  ): UpdateNotificationSettingsResponse {
+    return UpdateNotificationSettingsResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UpdateNotificationSettingsResponse>, I>>(
+    object: I,
+  ): UpdateNotificationSettingsResponse {
+    const message = createBaseUpdateNotificationSettingsResponse();
+    message.settings = (object.settings !== undefined && object.settings !== null)
+      ? GetNotificationSettingsResponse.fromPartial(object.settings)
+      : undefined;
+    return message;
+  },
+};
+
 type Builtin = Date | Function | Uint8Array | string | number | boolean | undefined;
 
 export type DeepPartial<T> = T extends Builtin ? T
@@ -1,5 +1,6 @@
 /* eslint-disable */
 import _m0 from "protobufjs/minimal";
+import { FieldMask } from "../../google/protobuf/field_mask";
 
 export const protobufPackage = "privacy_settings_def.v1";
 
@@ -219,6 +220,29 @@ export interface GetPrivacySettingsResponse {
   friend_list_visibility: RelationVisibility;
 }
 
+export interface UpdatePrivacySettingsRequest {
+  user_id: string;
+  /** Paths of the fields in `settings` to update */
+  update_mask: string[] | undefined;
+  settings:
+    | GetPrivacySettingsResponse
+    | undefined;
+  /** Deletes the existing read history when `record_read_history` is turned off */
+  purge_read_history: boolean;
+}
+
+export interface UpdatePrivacySettingsResponse {
+  settings:
+    | GetPrivacySettingsResponse
+    | undefined;
+  /** Pending follows converted into follow requests */
+  converted_follow_count: number;
+  declined_friend_request_count: number;
+  declined_collaboration_request_count: number;
+  declined_blog_request_count: number;
+  read_history_purged: boolean;
+}
+
 function createBaseGetPrivacySettingsRequest(): GetPrivacySettingsRequest {
   return { user_id: "" };
 }
@@ -461,6 +485,265 @@ export const GetPrivacySettingsResponse = {
   },
 };
 
+function createBaseUpdatePrivacySettingsRequest(): UpdatePrivacySettingsRequest {
+  return { user_id: "", update_mask: undefined, settings: undefined, purge_read_history: false };
+}
+
+export const UpdatePrivacySettingsRequest = {
+  encode(message: UpdatePrivacySettingsRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.update_mask !== undefined) {
+      FieldMask.encode(FieldMask.wrap(message.update_mask), writer.uint32(18).fork()).ldelim();
+    }
+    if (message.settings !== undefined) {
+      GetPrivacySettingsResponse.encode(message.settings, writer.uint32(26).fork()).ldelim();
+    }
+    if (message.purge_read_history === true) {
+      writer.uint32(32).bool(message.purge_read_history);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): UpdatePrivacySettingsRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUpdatePrivacySettingsRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.update_mask = FieldMask.unwrap(FieldMask.decode(reader, reader.uint32()));
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.settings = GetPrivacySettingsResponse.decode(reader, reader.uint32());
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.purge_read_history = reader.bool();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UpdatePrivacySettingsRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      update_mask: isSet(object.update_mask) ? FieldMask.unwrap(FieldMask.fromJSON(object.update_mask)) : undefined,
+      settings: isSet(object.settings) ? GetPrivacySettingsResponse.fromJSON(object.settings) : undefined,
+      purge_read_history: isSet(object.purge_read_history) ? globalThis.Boolean(object.purge_read_history) : false,
+    };
+  },
+
+  toJSON(message: UpdatePrivacySettingsRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.update_mask !== undefined) {
+      obj.update_mask = FieldMask.toJSON(FieldMask.wrap(message.update_mask));
+    }
+    if (message.settings !== undefined) {
+      obj.settings = GetPrivacySettingsResponse.toJSON(message.settings);
+    }
+    if (message.purge_read_history === true) {
+      obj.purge_read_history = message.purge_read_history;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UpdatePrivacySettingsRequest>, I>>(base?: I): UpdatePrivacySettingsRequest {
+    return UpdatePrivacySettingsRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UpdatePrivacySettingsRequest>, I>>(object: I): UpdatePrivacySettingsRequest {
+    const message = createBaseUpdatePrivacySettingsRequest();
+    message.user_id = object.user_id ?? "";
+    message.update_mask = object.update_mask ?? undefined;
+    message.settings = (object.settings !== undefined && object.settings !== null)
+      ? GetPrivacySettingsResponse.fromPartial(object.settings)
+      : undefined;
+    message.purge_read_history = object.purge_read_history ?? false;
+    return message;
+  },
+};
+
+function createBaseUpdatePrivacySettingsResponse(): UpdatePrivacySettingsResponse {
+  return {
+    settings: undefined,
+    converted_follow_count: 0,
+    declined_friend_request_count: 0,
+    declined_collaboration_request_count: 0,
+    declined_blog_request_count: 0,
+    read_history_purged: false,
+  };
+}
+
+export const UpdatePrivacySettingsResponse = {
+  encode(message: UpdatePrivacySettingsResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.settings !== undefined) {
+      GetPrivacySettingsResponse.encode(message.settings, writer.uint32(10).fork()).ldelim();
+    }
+    if (message.converted_follow_count !== 0) {
+      writer.uint32(16).uint32(message.converted_follow_count);
+    }
+    if (message.declined_friend_request_count !== 0) {
+      writer.uint32(24).uint32(message.declined_friend_request_count);
+    }
+    if (message.declined_collaboration_request_count !== 0) {
+      writer.uint32(32).uint32(message.declined_collaboration_request_count);
+    }
+    if (message.declined_blog_request_count !== 0) {
+      writer.uint32(40).uint32(message.declined_blog_request_count);
+    }
+    if (message.read_history_purged === true) {
+      writer.uint32(48).bool(message.read_history_purged);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): UpdatePrivacySettingsResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUpdatePrivacySettingsResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.settings = GetPrivacySettingsResponse.decode(reader, reader.uint32());
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.converted_follow_count = reader.uint32();
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.declined_friend_request_count = reader.uint32();
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.declined_collaboration_request_count = reader.uint32();
+          continue;
+        case 5:
+          if (tag !== 40) {
+            break;
+          }
+
+          message.declined_blog_request_count = reader.uint32();
+          continue;
+        case 6:
+          if (tag !== 48) {
+            break;
+          }
+
+          message.read_history_purged = reader.bool();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UpdatePrivacySettingsResponse {
+    return {
+      settings: isSet(object.settings) ? GetPrivacySettingsResponse.fromJSON(object.settings) : undefined,
+      converted_follow_count: isSet(object.converted_follow_count)
+        ? globalThis.Number(object.converted_follow_count)
+        : 0,
+      declined_friend_request_count: isSet(object.declined_friend_request_count)
+        ? globalThis.Number(object.declined_friend_request_count)
+        : 0,
+      declined_collaboration_request_count: isSet(object.declined_collaboration_request_count)
+        ? globalThis.Number(object.declined_collaboration_request_count)
+        : 0,
+      declined_blog_request_count: isSet(object.declined_blog_request_count)
+        ? globalThis.Number(object.declined_blog_request_count)
+        : 0,
+      read_history_purged: isSet(object.read_history_purged) ? globalThis.Boolean(object.read_history_purged) : false,
+    };
+  },
+
+  toJSON(message: UpdatePrivacySettingsResponse): unknown {
+    const obj: any = {};
+    if (message.settings !== undefined) {
+      obj.settings = GetPrivacySettingsResponse.toJSON(message.settings);
+    }
+    if (message.converted_follow_count !== 0) {
+      obj.converted_follow_count = Math.round(message.converted_follow_count);
+    }
+    if (message.declined_friend_request_count !== 0) {
+      obj.declined_friend_request_count = Math.round(message.declined_friend_request_count);
+    }
+    if (message.declined_collaboration_request_count !== 0) {
+      obj.declined_collaboration_request_count = Math.round(message.declined_collaboration_request_count);
+    }
+    if (message.declined_blog_request_count !== 0) {
+      obj.declined_blog_request_count = Math.round(message.declined_blog_request_count);
+    }
+    if (message.read_history_purged === true) {
+      obj.read_history_purged = message.read_history_purged;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UpdatePrivacySettingsResponse>, I>>(base?: I): UpdatePrivacySettingsResponse {
+    return UpdatePrivacySettingsResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UpdatePrivacySettingsResponse>, I>>(
+    object: I,
+  ): UpdatePrivacySettingsResponse {
+    const message = createBaseUpdatePrivacySettingsResponse();
+    message.settings = (object.settings !== undefined && object.settings !== null)
+      ? GetPrivacySettingsResponse.fromPartial(object.settings)
+      : undefined;
+    message.converted_follow_count = object.converted_follow_count ?? 0;
+    message.declined_friend_request_count = object.declined_friend_request_count ?? 0;
+    message.declined_collaboration_request_count = object.declined_collaboration_request_count ?? 0;
+    message.declined_blog_request_count = object.declined_blog_request_count ?? 0;
+    message.read_history_purged = object.read_history_purged ?? false;
+    return message;
+  },
+};
+
 type Builtin = Date | Function | Uint8Array | string | number | boolean | undefined;
 
 export type DeepPartial<T> = T extends Builtin ? T
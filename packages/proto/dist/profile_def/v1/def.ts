@@ -1,6 +1,8 @@
 /* eslint-disable */
 import _m0 from "protobufjs/minimal";
 import { Connection } from "../../connection_def/v1/def";
+import { FieldMask } from "../../google/protobuf/field_mask";
+import { Timestamp } from "../../google/protobuf/timestamp";
 import { ExtendedStatus } from "../../user_def/v1/def";
 
 export const protobufPackage = "profile_def.v1";
@@ -23,7 +25,9 @@ export interface GetProfileResponse {
   banner_id?: string | undefined;
   banner_hex?: string | undefined;
   location: string;
+  /** @deprecated */
   created_at: string;
+  /** Bitfield of `user_def.v1.UserFlag` */
   public_flags: number;
   story_count: number;
   follower_count: number;
@@ -43,6 +47,42 @@ export interface GetProfileResponse {
   is_muted: boolean;
   is_self: boolean;
   is_plus_member: boolean;
+  /** Timestamps */
+  created_at_ts: Date | undefined;
+}
+
+export interface UpdateProfileRequest {
+  user_id: string;
+  /** Only the fields in the mask are updated */
+  update_mask: string[] | undefined;
+  name: string;
+  /** Subject to a cooldown and the reserved names */
+  username: string;
+  /** Markdown source for `rendered_bio` */
+  bio?: string | undefined;
+  location: string;
+  /** ID of an uploaded image, or omitted to remove the avatar */
+  avatar_id?:
+    | string
+    | undefined;
+  /** ID of an uploaded image, or omitted to remove the banner */
+  banner_id?: string | undefined;
+}
+
+export interface UpdateProfileResponse {
+  name: string;
+  username: string;
+  bio?: string | undefined;
+  rendered_bio?: string | undefined;
+  location: string;
+  avatar_id?: string | undefined;
+  avatar_hex?: string | undefined;
+  banner_id?: string | undefined;
+  banner_hex?:
+    | string
+    | undefined;
+  /** Omitted when the username can be changed right away */
+  next_username_change_at?: Date | undefined;
 }
 
 function createBaseGetProfileRequest(): GetProfileRequest {
@@ -150,6 +190,7 @@ function createBaseGetProfileResponse(): GetProfileResponse {
     is_muted: false,
     is_self: false,
     is_plus_member: false,
+    created_at_ts: undefined,
   };
 }
 
@@ -242,6 +283,9 @@ export const GetProfileResponse = {
     if (message.is_plus_member === true) {
       writer.uint32(232).bool(message.is_plus_member);
     }
+    if (message.created_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at_ts), writer.uint32(242).fork()).ldelim();
+    }
     return writer;
   },
 
@@ -455,6 +499,13 @@ export const GetProfileResponse = {
 
           message.is_plus_member = reader.bool();
           continue;
+        case 30:
+          if (tag !== 242) {
+            break;
+          }
+
+          message.created_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -499,6 +550,7 @@ export const GetProfileResponse = {
       is_muted: isSet(object.is_muted) ? globalThis.Boolean(object.is_muted) : false,
       is_self: isSet(object.is_self) ? globalThis.Boolean(object.is_self) : false,
       is_plus_member: isSet(object.is_plus_member) ? globalThis.Boolean(object.is_plus_member) : false,
+      created_at_ts: isSet(object.created_at_ts) ? fromJsonTimestamp(object.created_at_ts) : undefined,
     };
   },
 
@@ -591,6 +643,9 @@ export const GetProfileResponse = {
     if (message.is_plus_member === true) {
       obj.is_plus_member = message.is_plus_member;
     }
+    if (message.created_at_ts !== undefined) {
+      obj.created_at_ts = message.created_at_ts.toISOString();
+    }
     return obj;
   },
 
@@ -630,6 +685,387 @@ export const GetProfileResponse = {
     message.is_muted = object.is_muted ?? false;
     message.is_self = object.is_self ?? false;
     message.is_plus_member = object.is_plus_member ?? false;
+    message.created_at_ts = object.created_at_ts ?? undefined;
+    return message;
+  },
+};
+
+function createBaseUpdateProfileRequest(): UpdateProfileRequest {
+  return {
+    user_id: "",
+    update_mask: undefined,
+    name: "",
+    username: "",
+    bio: undefined,
+    location: "",
+    avatar_id: undefined,
+    banner_id: undefined,
+  };
+}
+
+export const UpdateProfileRequest = {
+  encode(message: UpdateProfileRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.update_mask !== undefined) {
+      FieldMask.encode(FieldMask.wrap(message.update_mask), writer.uint32(18).fork()).ldelim();
+    }
+    if (message.name !== "") {
+      writer.uint32(26).string(message.name);
+    }
+    if (message.username !== "") {
+      writer.uint32(34).string(message.username);
+    }
+    if (message.bio !== undefined) {
+      writer.uint32(42).string(message.bio);
+    }
+    if (message.location !== "") {
+      writer.uint32(50).string(message.location);
+    }
+    if (message.avatar_id !== undefined) {
+      writer.uint32(58).string(message.avatar_id);
+    }
+    if (message.banner_id !== undefined) {
+      writer.uint32(66).string(message.banner_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): UpdateProfileRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUpdateProfileRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.update_mask = FieldMask.unwrap(FieldMask.decode(reader, reader.uint32()));
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.name = reader.string();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.username = reader.string();
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.bio = reader.string();
+          continue;
+        case 6:
+          if (tag !== 50) {
+            break;
+          }
+
+          message.location = reader.string();
+          continue;
+        case 7:
+          if (tag !== 58) {
+            break;
+          }
+
+          message.avatar_id = reader.string();
+          continue;
+        case 8:
+          if (tag !== 66) {
+            break;
+          }
+
+          message.banner_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UpdateProfileRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      update_mask: isSet(object.update_mask) ? FieldMask.unwrap(FieldMask.fromJSON(object.update_mask)) : undefined,
+      name: isSet(object.name) ? globalThis.String(object.name) : "",
+      username: isSet(object.username) ? globalThis.String(object.username) : "",
+      bio: isSet(object.bio) ? globalThis.String(object.bio) : undefined,
+      location: isSet(object.location) ? globalThis.String(object.location) : "",
+      avatar_id: isSet(object.avatar_id) ? globalThis.String(object.avatar_id) : undefined,
+      banner_id: isSet(object.banner_id) ? globalThis.String(object.banner_id) : undefined,
+    };
+  },
+
+  toJSON(message: UpdateProfileRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.update_mask !== undefined) {
+      obj.update_mask = FieldMask.toJSON(FieldMask.wrap(message.update_mask));
+    }
+    if (message.name !== "") {
+      obj.name = message.name;
+    }
+    if (message.username !== "") {
+      obj.username = message.username;
+    }
+    if (message.bio !== undefined) {
+      obj.bio = message.bio;
+    }
+    if (message.location !== "") {
+      obj.location = message.location;
+    }
+    if (message.avatar_id !== undefined) {
+      obj.avatar_id = message.avatar_id;
+    }
+    if (message.banner_id !== undefined) {
+      obj.banner_id = message.banner_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UpdateProfileRequest>, I>>(base?: I): UpdateProfileRequest {
+    return UpdateProfileRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UpdateProfileRequest>, I>>(object: I): UpdateProfileRequest {
+    const message = createBaseUpdateProfileRequest();
+    message.user_id = object.user_id ?? "";
+    message.update_mask = object.update_mask ?? undefined;
+    message.name = object.name ?? "";
+    message.username = object.username ?? "";
+    message.bio = object.bio ?? undefined;
+    message.location = object.location ?? "";
+    message.avatar_id = object.avatar_id ?? undefined;
+    message.banner_id = object.banner_id ?? undefined;
+    return message;
+  },
+};
+
+function createBaseUpdateProfileResponse(): UpdateProfileResponse {
+  return {
+    name: "",
+    username: "",
+    bio: undefined,
+    rendered_bio: undefined,
+    location: "",
+    avatar_id: undefined,
+    avatar_hex: undefined,
+    banner_id: undefined,
+    banner_hex: undefined,
+    next_username_change_at: undefined,
+  };
+}
+
+export const UpdateProfileResponse = {
+  encode(message: UpdateProfileResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.name !== "") {
+      writer.uint32(10).string(message.name);
+    }
+    if (message.username !== "") {
+      writer.uint32(18).string(message.username);
+    }
+    if (message.bio !== undefined) {
+      writer.uint32(26).string(message.bio);
+    }
+    if (message.rendered_bio !== undefined) {
+      writer.uint32(34).string(message.rendered_bio);
+    }
+    if (message.location !== "") {
+      writer.uint32(42).string(message.location);
+    }
+    if (message.avatar_id !== undefined) {
+      writer.uint32(50).string(message.avatar_id);
+    }
+    if (message.avatar_hex !== undefined) {
+      writer.uint32(58).string(message.avatar_hex);
+    }
+    if (message.banner_id !== undefined) {
+      writer.uint32(66).string(message.banner_id);
+    }
+    if (message.banner_hex !== undefined) {
+      writer.uint32(74).string(message.banner_hex);
+    }
+    if (message.next_username_change_at !== undefined) {
+      Timestamp.encode(toTimestamp(message.next_username_change_at), writer.uint32(82).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): UpdateProfileResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUpdateProfileResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.name = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.username = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.bio = reader.string();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.rendered_bio = reader.string();
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.location = reader.string();
+          continue;
+        case 6:
+          if (tag !== 50) {
+            break;
+          }
+
+          message.avatar_id = reader.string();
+          continue;
+        case 7:
+          if (tag !== 58) {
+            break;
+          }
+
+          message.avatar_hex = reader.string();
+          continue;
+        case 8:
+          if (tag !== 66) {
+            break;
+          }
+
+          message.banner_id = reader.string();
+          continue;
+        case 9:
+          if (tag !== 74) {
+            break;
+          }
+
+          message.banner_hex = reader.string();
+          continue;
+        case 10:
+          if (tag !== 82) {
+            break;
+          }
+
+          message.next_username_change_at = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UpdateProfileResponse {
+    return {
+      name: isSet(object.name) ? globalThis.String(object.name) : "",
+      username: isSet(object.username) ? globalThis.String(object.username) : "",
+      bio: isSet(object.bio) ? globalThis.String(object.bio) : undefined,
+      rendered_bio: isSet(object.rendered_bio) ? globalThis.String(object.rendered_bio) : undefined,
+      location: isSet(object.location) ? globalThis.String(object.location) : "",
+      avatar_id: isSet(object.avatar_id) ? globalThis.String(object.avatar_id) : undefined,
+      avatar_hex: isSet(object.avatar_hex) ? globalThis.String(object.avatar_hex) : undefined,
+      banner_id: isSet(object.banner_id) ? globalThis.String(object.banner_id) : undefined,
+      banner_hex: isSet(object.banner_hex) ? globalThis.String(object.banner_hex) : undefined,
+      next_username_change_at: isSet(object.next_username_change_at)
+        ? fromJsonTimestamp(object.next_username_change_at)
+        : undefined,
+    };
+  },
+
+  toJSON(message: UpdateProfileResponse): unknown {
+    const obj: any = {};
+    if (message.name !== "") {
+      obj.name = message.name;
+    }
+    if (message.username !== "") {
+      obj.username = message.username;
+    }
+    if (message.bio !== undefined) {
+      obj.bio = message.bio;
+    }
+    if (message.rendered_bio !== undefined) {
+      obj.rendered_bio = message.rendered_bio;
+    }
+    if (message.location !== "") {
+      obj.location = message.location;
+    }
+    if (message.avatar_id !== undefined) {
+      obj.avatar_id = message.avatar_id;
+    }
+    if (message.avatar_hex !== undefined) {
+      obj.avatar_hex = message.avatar_hex;
+    }
+    if (message.banner_id !== undefined) {
+      obj.banner_id = message.banner_id;
+    }
+    if (message.banner_hex !== undefined) {
+      obj.banner_hex = message.banner_hex;
+    }
+    if (message.next_username_change_at !== undefined) {
+      obj.next_username_change_at = message.next_username_change_at.toISOString();
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UpdateProfileResponse>, I>>(base?: I): UpdateProfileResponse {
+    return UpdateProfileResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UpdateProfileResponse>, I>>(object: I): UpdateProfileResponse {
+    const message = createBaseUpdateProfileResponse();
+    message.name = object.name ?? "";
+    message.username = object.username ?? "";
+    message.bio = object.bio ?? undefined;
+    message.rendered_bio = object.rendered_bio ?? undefined;
+    message.location = object.location ?? "";
+    message.avatar_id = object.avatar_id ?? undefined;
+    message.avatar_hex = object.avatar_hex ?? undefined;
+    message.banner_id = object.banner_id ?? undefined;
+    message.banner_hex = object.banner_hex ?? undefined;
+    message.next_username_change_at = object.next_username_change_at ?? undefined;
     return message;
   },
 };
@@ -646,6 +1082,28 @@ type KeysOfUnion<T> = T extends T ? keyof T : never;
 export type Exact<P, I extends P> = P extends Builtin ? P
   : P & { [K in keyof P]: Exact<P[K], I[K]> } & { [K in Exclude<keyof I, KeysOfUnion<P>>]: never };
 
+function toTimestamp(date: Date): Timestamp {
+  const seconds = Math.trunc(date.getTime() / 1_000);
+  const nanos = (date.getTime() % 1_000) * 1_000_000;
+  return { seconds, nanos };
+}
+
+function fromTimestamp(t: Timestamp): Date {
+  let millis = (t.seconds || 0) * 1_000;
+  millis += (t.nanos || 0) / 1_000_000;
+  return new globalThis.Date(millis);
+}
+
+function fromJsonTimestamp(o: any): Date {
+  if (o instanceof globalThis.Date) {
+    return o;
+  } else if (typeof o === "string") {
+    return new globalThis.Date(o);
+  } else {
+    return fromTimestamp(Timestamp.fromJSON(o));
+  }
+}
+
 function isSet(value: any): boolean {
   return value !== null && value !== undefined;
 }
@@ -0,0 +1,1283 @@
+/* eslint-disable */
+import _m0 from "protobufjs/minimal";
+import { Timestamp } from "../../google/protobuf/timestamp";
+import { BareUser } from "../../user_def/v1/def";
+
+export const protobufPackage = "report_def.v1";
+
+export const ReportEntityType = {
+  UNSPECIFIED: 0,
+  STORY: 1,
+  COMMENT: 2,
+  USER: 3,
+  BLOG: 4,
+  TAG: 5,
+  UNRECOGNIZED: -1,
+} as const;
+
+export type ReportEntityType = typeof ReportEntityType[keyof typeof ReportEntityType];
+
+export function reportEntityTypeFromJSON(object: any): ReportEntityType {
+  switch (object) {
+    case 0:
+    case "REPORT_ENTITY_TYPE_UNSPECIFIED":
+      return ReportEntityType.UNSPECIFIED;
+    case 1:
+    case "REPORT_ENTITY_TYPE_STORY":
+      return ReportEntityType.STORY;
+    case 2:
+    case "REPORT_ENTITY_TYPE_COMMENT":
+      return ReportEntityType.COMMENT;
+    case 3:
+    case "REPORT_ENTITY_TYPE_USER":
+      return ReportEntityType.USER;
+    case 4:
+    case "REPORT_ENTITY_TYPE_BLOG":
+      return ReportEntityType.BLOG;
+    case 5:
+    case "REPORT_ENTITY_TYPE_TAG":
+      return ReportEntityType.TAG;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return ReportEntityType.UNRECOGNIZED;
+  }
+}
+
+export function reportEntityTypeToJSON(object: ReportEntityType): string {
+  switch (object) {
+    case ReportEntityType.UNSPECIFIED:
+      return "REPORT_ENTITY_TYPE_UNSPECIFIED";
+    case ReportEntityType.STORY:
+      return "REPORT_ENTITY_TYPE_STORY";
+    case ReportEntityType.COMMENT:
+      return "REPORT_ENTITY_TYPE_COMMENT";
+    case ReportEntityType.USER:
+      return "REPORT_ENTITY_TYPE_USER";
+    case ReportEntityType.BLOG:
+      return "REPORT_ENTITY_TYPE_BLOG";
+    case ReportEntityType.TAG:
+      return "REPORT_ENTITY_TYPE_TAG";
+    case ReportEntityType.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
+export const ReportReason = {
+  UNSPECIFIED: 0,
+  SPAM: 1,
+  HARASSMENT: 2,
+  HATE_SPEECH: 3,
+  VIOLENCE: 4,
+  SEXUAL_CONTENT: 5,
+  SELF_HARM: 6,
+  MISINFORMATION: 7,
+  COPYRIGHT: 8,
+  IMPERSONATION: 9,
+  OTHER: 10,
+  UNRECOGNIZED: -1,
+} as const;
+
+export type ReportReason = typeof ReportReason[keyof typeof ReportReason];
+
+export function reportReasonFromJSON(object: any): ReportReason {
+  switch (object) {
+    case 0:
+    case "REPORT_REASON_UNSPECIFIED":
+      return ReportReason.UNSPECIFIED;
+    case 1:
+    case "REPORT_REASON_SPAM":
+      return ReportReason.SPAM;
+    case 2:
+    case "REPORT_REASON_HARASSMENT":
+      return ReportReason.HARASSMENT;
+    case 3:
+    case "REPORT_REASON_HATE_SPEECH":
+      return ReportReason.HATE_SPEECH;
+    case 4:
+    case "REPORT_REASON_VIOLENCE":
+      return ReportReason.VIOLENCE;
+    case 5:
+    case "REPORT_REASON_SEXUAL_CONTENT":
+      return ReportReason.SEXUAL_CONTENT;
+    case 6:
+    case "REPORT_REASON_SELF_HARM":
+      return ReportReason.SELF_HARM;
+    case 7:
+    case "REPORT_REASON_MISINFORMATION":
+      return ReportReason.MISINFORMATION;
+    case 8:
+    case "REPORT_REASON_COPYRIGHT":
+      return ReportReason.COPYRIGHT;
+    case 9:
+    case "REPORT_REASON_IMPERSONATION":
+      return ReportReason.IMPERSONATION;
+    case 10:
+    case "REPORT_REASON_OTHER":
+      return ReportReason.OTHER;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return ReportReason.UNRECOGNIZED;
+  }
+}
+
+export function reportReasonToJSON(object: ReportReason): string {
+  switch (object) {
+    case ReportReason.UNSPECIFIED:
+      return "REPORT_REASON_UNSPECIFIED";
+    case ReportReason.SPAM:
+      return "REPORT_REASON_SPAM";
+    case ReportReason.HARASSMENT:
+      return "REPORT_REASON_HARASSMENT";
+    case ReportReason.HATE_SPEECH:
+      return "REPORT_REASON_HATE_SPEECH";
+    case ReportReason.VIOLENCE:
+      return "REPORT_REASON_VIOLENCE";
+    case ReportReason.SEXUAL_CONTENT:
+      return "REPORT_REASON_SEXUAL_CONTENT";
+    case ReportReason.SELF_HARM:
+      return "REPORT_REASON_SELF_HARM";
+    case ReportReason.MISINFORMATION:
+      return "REPORT_REASON_MISINFORMATION";
+    case ReportReason.COPYRIGHT:
+      return "REPORT_REASON_COPYRIGHT";
+    case ReportReason.IMPERSONATION:
+      return "REPORT_REASON_IMPERSONATION";
+    case ReportReason.OTHER:
+      return "REPORT_REASON_OTHER";
+    case ReportReason.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
+export const ReportResolution = {
+  UNSPECIFIED: 0,
+  TAKE_DOWN: 1,
+  WARN_USER: 2,
+  SUSPEND_USER: 3,
+  DISMISS: 4,
+  UNRECOGNIZED: -1,
+} as const;
+
+export type ReportResolution = typeof ReportResolution[keyof typeof ReportResolution];
+
+export function reportResolutionFromJSON(object: any): ReportResolution {
+  switch (object) {
+    case 0:
+    case "REPORT_RESOLUTION_UNSPECIFIED":
+      return ReportResolution.UNSPECIFIED;
+    case 1:
+    case "REPORT_RESOLUTION_TAKE_DOWN":
+      return ReportResolution.TAKE_DOWN;
+    case 2:
+    case "REPORT_RESOLUTION_WARN_USER":
+      return ReportResolution.WARN_USER;
+    case 3:
+    case "REPORT_RESOLUTION_SUSPEND_USER":
+      return ReportResolution.SUSPEND_USER;
+    case 4:
+    case "REPORT_RESOLUTION_DISMISS":
+      return ReportResolution.DISMISS;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return ReportResolution.UNRECOGNIZED;
+  }
+}
+
+export function reportResolutionToJSON(object: ReportResolution): string {
+  switch (object) {
+    case ReportResolution.UNSPECIFIED:
+      return "REPORT_RESOLUTION_UNSPECIFIED";
+    case ReportResolution.TAKE_DOWN:
+      return "REPORT_RESOLUTION_TAKE_DOWN";
+    case ReportResolution.WARN_USER:
+      return "REPORT_RESOLUTION_WARN_USER";
+    case ReportResolution.SUSPEND_USER:
+      return "REPORT_RESOLUTION_SUSPEND_USER";
+    case ReportResolution.DISMISS:
+      return "REPORT_RESOLUTION_DISMISS";
+    case ReportResolution.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
+export interface Report {
+  id: string;
+  entity_type: ReportEntityType;
+  entity_id: string;
+  reason: ReportReason;
+  description?: string | undefined;
+  reporter: BareUser | undefined;
+  created_at:
+    | Date
+    | undefined;
+  /** Resolution props */
+  resolution?: ReportResolution | undefined;
+  resolved_at?: Date | undefined;
+  moderator_id?: string | undefined;
+}
+
+export interface ModerationAuditLogEntry {
+  id: string;
+  moderator_id: string;
+  action: ReportResolution;
+  entity_type: ReportEntityType;
+  entity_id: string;
+  report_id?: string | undefined;
+  note?: string | undefined;
+  created_at: Date | undefined;
+}
+
+export interface ReportContentRequest {
+  user_id: string;
+  entity_type: ReportEntityType;
+  entity_id: string;
+  reason: ReportReason;
+  description?: string | undefined;
+}
+
+export interface ReportContentResponse {
+  report_id: string;
+}
+
+export interface ListReportsRequest {
+  moderator_id: string;
+  /** `next_cursor` from the previous page */
+  cursor?: string | undefined;
+  limit: number;
+  pending_only: boolean;
+  entity_type?: ReportEntityType | undefined;
+}
+
+export interface ListReportsResponse {
+  reports: Report[];
+  /** Omitted on the last page */
+  next_cursor?: string | undefined;
+}
+
+export interface ResolveReportRequest {
+  moderator_id: string;
+  report_id: string;
+  resolution: ReportResolution;
+  /** Recorded in the audit log and included in the warning */
+  note?:
+    | string
+    | undefined;
+  /** Suspension period, omitted for a permanent suspension */
+  suspension_days?: number | undefined;
+}
+
+export interface ResolveReportResponse {
+  report: Report | undefined;
+  audit_log_entry:
+    | ModerationAuditLogEntry
+    | undefined;
+  /** Set when the content has been taken down */
+  deleted_at?: Date | undefined;
+}
+
+function createBaseReport(): Report {
+  return {
+    id: "",
+    entity_type: 0,
+    entity_id: "",
+    reason: 0,
+    description: undefined,
+    reporter: undefined,
+    created_at: undefined,
+    resolution: undefined,
+    resolved_at: undefined,
+    moderator_id: undefined,
+  };
+}
+
+export const Report = {
+  encode(message: Report, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.id !== "") {
+      writer.uint32(10).string(message.id);
+    }
+    if (message.entity_type !== 0) {
+      writer.uint32(16).int32(message.entity_type);
+    }
+    if (message.entity_id !== "") {
+      writer.uint32(26).string(message.entity_id);
+    }
+    if (message.reason !== 0) {
+      writer.uint32(32).int32(message.reason);
+    }
+    if (message.description !== undefined) {
+      writer.uint32(42).string(message.description);
+    }
+    if (message.reporter !== undefined) {
+      BareUser.encode(message.reporter, writer.uint32(50).fork()).ldelim();
+    }
+    if (message.created_at !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at), writer.uint32(58).fork()).ldelim();
+    }
+    if (message.resolution !== undefined) {
+      writer.uint32(64).int32(message.resolution);
+    }
+    if (message.resolved_at !== undefined) {
+      Timestamp.encode(toTimestamp(message.resolved_at), writer.uint32(74).fork()).ldelim();
+    }
+    if (message.moderator_id !== undefined) {
+      writer.uint32(82).string(message.moderator_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): Report {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseReport();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.entity_type = reader.int32() as any;
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.entity_id = reader.string();
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.reason = reader.int32() as any;
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.description = reader.string();
+          continue;
+        case 6:
+          if (tag !== 50) {
+            break;
+          }
+
+          message.reporter = BareUser.decode(reader, reader.uint32());
+          continue;
+        case 7:
+          if (tag !== 58) {
+            break;
+          }
+
+          message.created_at = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 8:
+          if (tag !== 64) {
+            break;
+          }
+
+          message.resolution = reader.int32() as any;
+          continue;
+        case 9:
+          if (tag !== 74) {
+            break;
+          }
+
+          message.resolved_at = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 10:
+          if (tag !== 82) {
+            break;
+          }
+
+          message.moderator_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): Report {
+    return {
+      id: isSet(object.id) ? globalThis.String(object.id) : "",
+      entity_type: isSet(object.entity_type) ? reportEntityTypeFromJSON(object.entity_type) : 0,
+      entity_id: isSet(object.entity_id) ? globalThis.String(object.entity_id) : "",
+      reason: isSet(object.reason) ? reportReasonFromJSON(object.reason) : 0,
+      description: isSet(object.description) ? globalThis.String(object.description) : undefined,
+      reporter: isSet(object.reporter) ? BareUser.fromJSON(object.reporter) : undefined,
+      created_at: isSet(object.created_at) ? fromJsonTimestamp(object.created_at) : undefined,
+      resolution: isSet(object.resolution) ? reportResolutionFromJSON(object.resolution) : undefined,
+      resolved_at: isSet(object.resolved_at) ? fromJsonTimestamp(object.resolved_at) : undefined,
+      moderator_id: isSet(object.moderator_id) ? globalThis.String(object.moderator_id) : undefined,
+    };
+  },
+
+  toJSON(message: Report): unknown {
+    const obj: any = {};
+    if (message.id !== "") {
+      obj.id = message.id;
+    }
+    if (message.entity_type !== 0) {
+      obj.entity_type = reportEntityTypeToJSON(message.entity_type);
+    }
+    if (message.entity_id !== "") {
+      obj.entity_id = message.entity_id;
+    }
+    if (message.reason !== 0) {
+      obj.reason = reportReasonToJSON(message.reason);
+    }
+    if (message.description !== undefined) {
+      obj.description = message.description;
+    }
+    if (message.reporter !== undefined) {
+      obj.reporter = BareUser.toJSON(message.reporter);
+    }
+    if (message.created_at !== undefined) {
+      obj.created_at = message.created_at.toISOString();
+    }
+    if (message.resolution !== undefined) {
+      obj.resolution = reportResolutionToJSON(message.resolution);
+    }
+    if (message.resolved_at !== undefined) {
+      obj.resolved_at = message.resolved_at.toISOString();
+    }
+    if (message.moderator_id !== undefined) {
+      obj.moderator_id = message.moderator_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<Report>, I>>(base?: I): Report {
+    return Report.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<Report>, I>>(object: I): Report {
+    const message = createBaseReport();
+    message.id = object.id ?? "";
+    message.entity_type = object.entity_type ?? 0;
+    message.entity_id = object.entity_id ?? "";
+    message.reason = object.reason ?? 0;
+    message.description = object.description ?? undefined;
+    message.reporter = (object.reporter !== undefined && object.reporter !== null)
+      ? BareUser.fromPartial(object.reporter)
+      : undefined;
+    message.created_at = object.created_at ?? undefined;
+    message.resolution = object.resolution ?? undefined;
+    message.resolved_at = object.resolved_at ?? undefined;
+    message.moderator_id = object.moderator_id ?? undefined;
+    return message;
+  },
+};
+
+function createBaseModerationAuditLogEntry(): ModerationAuditLogEntry {
+  return {
+    id: "",
+    moderator_id: "",
+    action: 0,
+    entity_type: 0,
+    entity_id: "",
+    report_id: undefined,
+    note: undefined,
+    created_at: undefined,
+  };
+}
+
+export const ModerationAuditLogEntry = {
+  encode(message: ModerationAuditLogEntry, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.id !== "") {
+      writer.uint32(10).string(message.id);
+    }
+    if (message.moderator_id !== "") {
+      writer.uint32(18).string(message.moderator_id);
+    }
+    if (message.action !== 0) {
+      writer.uint32(24).int32(message.action);
+    }
+    if (message.entity_type !== 0) {
+      writer.uint32(32).int32(message.entity_type);
+    }
+    if (message.entity_id !== "") {
+      writer.uint32(42).string(message.entity_id);
+    }
+    if (message.report_id !== undefined) {
+      writer.uint32(50).string(message.report_id);
+    }
+    if (message.note !== undefined) {
+      writer.uint32(58).string(message.note);
+    }
+    if (message.created_at !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at), writer.uint32(66).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ModerationAuditLogEntry {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseModerationAuditLogEntry();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.moderator_id = reader.string();
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.action = reader.int32() as any;
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.entity_type = reader.int32() as any;
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.entity_id = reader.string();
+          continue;
+        case 6:
+          if (tag !== 50) {
+            break;
+          }
+
+          message.report_id = reader.string();
+          continue;
+        case 7:
+          if (tag !== 58) {
+            break;
+          }
+
+          message.note = reader.string();
+          continue;
+        case 8:
+          if (tag !== 66) {
+            break;
+          }
+
+          message.created_at = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ModerationAuditLogEntry {
+    return {
+      id: isSet(object.id) ? globalThis.String(object.id) : "",
+      moderator_id: isSet(object.moderator_id) ? globalThis.String(object.moderator_id) : "",
+      action: isSet(object.action) ? reportResolutionFromJSON(object.action) : 0,
+      entity_type: isSet(object.entity_type) ? reportEntityTypeFromJSON(object.entity_type) : 0,
+      entity_id: isSet(object.entity_id) ? globalThis.String(object.entity_id) : "",
+      report_id: isSet(object.report_id) ? globalThis.String(object.report_id) : undefined,
+      note: isSet(object.note) ? globalThis.String(object.note) : undefined,
+      created_at: isSet(object.created_at) ? fromJsonTimestamp(object.created_at) : undefined,
+    };
+  },
+
+  toJSON(message: ModerationAuditLogEntry): unknown {
+    const obj: any = {};
+    if (message.id !== "") {
+      obj.id = message.id;
+    }
+    if (message.moderator_id !== "") {
+      obj.moderator_id = message.moderator_id;
+    }
+    if (message.action !== 0) {
+      obj.action = reportResolutionToJSON(message.action);
+    }
+    if (message.entity_type !== 0) {
+      obj.entity_type = reportEntityTypeToJSON(message.entity_type);
+    }
+    if (message.entity_id !== "") {
+      obj.entity_id = message.entity_id;
+    }
+    if (message.report_id !== undefined) {
+      obj.report_id = message.report_id;
+    }
+    if (message.note !== undefined) {
+      obj.note = message.note;
+    }
+    if (message.created_at !== undefined) {
+      obj.created_at = message.created_at.toISOString();
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ModerationAuditLogEntry>, I>>(base?: I): ModerationAuditLogEntry {
+    return ModerationAuditLogEntry.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ModerationAuditLogEntry>, I>>(object: I): ModerationAuditLogEntry {
+    const message = createBaseModerationAuditLogEntry();
+    message.id = object.id ?? "";
+    message.moderator_id = object.moderator_id ?? "";
+    message.action = object.action ?? 0;
+    message.entity_type = object.entity_type ?? 0;
+    message.entity_id = object.entity_id ?? "";
+    message.report_id = object.report_id ?? undefined;
+    message.note = object.note ?? undefined;
+    message.created_at = object.created_at ?? undefined;
+    return message;
+  },
+};
+
+function createBaseReportContentRequest(): ReportContentRequest {
+  return { user_id: "", entity_type: 0, entity_id: "", reason: 0, description: undefined };
+}
+
+export const ReportContentRequest = {
+  encode(message: ReportContentRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.entity_type !== 0) {
+      writer.uint32(16).int32(message.entity_type);
+    }
+    if (message.entity_id !== "") {
+      writer.uint32(26).string(message.entity_id);
+    }
+    if (message.reason !== 0) {
+      writer.uint32(32).int32(message.reason);
+    }
+    if (message.description !== undefined) {
+      writer.uint32(42).string(message.description);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ReportContentRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseReportContentRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.entity_type = reader.int32() as any;
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.entity_id = reader.string();
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.reason = reader.int32() as any;
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.description = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ReportContentRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      entity_type: isSet(object.entity_type) ? reportEntityTypeFromJSON(object.entity_type) : 0,
+      entity_id: isSet(object.entity_id) ? globalThis.String(object.entity_id) : "",
+      reason: isSet(object.reason) ? reportReasonFromJSON(object.reason) : 0,
+      description: isSet(object.description) ? globalThis.String(object.description) : undefined,
+    };
+  },
+
+  toJSON(message: ReportContentRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.entity_type !== 0) {
+      obj.entity_type = reportEntityTypeToJSON(message.entity_type);
+    }
+    if (message.entity_id !== "") {
+      obj.entity_id = message.entity_id;
+    }
+    if (message.reason !== 0) {
+      obj.reason = reportReasonToJSON(message.reason);
+    }
+    if (message.description !== undefined) {
+      obj.description = message.description;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ReportContentRequest>, I>>(base?: I): ReportContentRequest {
+    return ReportContentRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ReportContentRequest>, I>>(object: I): ReportContentRequest {
+    const message = createBaseReportContentRequest();
+    message.user_id = object.user_id ?? "";
+    message.entity_type = object.entity_type ?? 0;
+    message.entity_id = object.entity_id ?? "";
+    message.reason = object.reason ?? 0;
+    message.description = object.description ?? undefined;
+    return message;
+  },
+};
+
+function createBaseReportContentResponse(): ReportContentResponse {
+  return { report_id: "" };
+}
+
+export const ReportContentResponse = {
+  encode(message: ReportContentResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.report_id !== "") {
+      writer.uint32(10).string(message.report_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ReportContentResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseReportContentResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.report_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ReportContentResponse {
+    return { report_id: isSet(object.report_id) ? globalThis.String(object.report_id) : "" };
+  },
+
+  toJSON(message: ReportContentResponse): unknown {
+    const obj: any = {};
+    if (message.report_id !== "") {
+      obj.report_id = message.report_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ReportContentResponse>, I>>(base?: I): ReportContentResponse {
+    return ReportContentResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ReportContentResponse>, I>>(object: I): ReportContentResponse {
+    const message = createBaseReportContentResponse();
+    message.report_id = object.report_id ?? "";
+    return message;
+  },
+};
+
+function createBaseListReportsRequest(): ListReportsRequest {
+  return { moderator_id: "", cursor: undefined, limit: 0, pending_only: false, entity_type: undefined };
+}
+
+export const ListReportsRequest = {
+  encode(message: ListReportsRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.moderator_id !== "") {
+      writer.uint32(10).string(message.moderator_id);
+    }
+    if (message.cursor !== undefined) {
+      writer.uint32(18).string(message.cursor);
+    }
+    if (message.limit !== 0) {
+      writer.uint32(24).uint32(message.limit);
+    }
+    if (message.pending_only === true) {
+      writer.uint32(32).bool(message.pending_only);
+    }
+    if (message.entity_type !== undefined) {
+      writer.uint32(40).int32(message.entity_type);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ListReportsRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseListReportsRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.moderator_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.cursor = reader.string();
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.limit = reader.uint32();
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.pending_only = reader.bool();
+          continue;
+        case 5:
+          if (tag !== 40) {
+            break;
+          }
+
+          message.entity_type = reader.int32() as any;
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ListReportsRequest {
+    return {
+      moderator_id: isSet(object.moderator_id) ? globalThis.String(object.moderator_id) : "",
+      cursor: isSet(object.cursor) ? globalThis.String(object.cursor) : undefined,
+      limit: isSet(object.limit) ? globalThis.Number(object.limit) : 0,
+      pending_only: isSet(object.pending_only) ? globalThis.Boolean(object.pending_only) : false,
+      entity_type: isSet(object.entity_type) ? reportEntityTypeFromJSON(object.entity_type) : undefined,
+    };
+  },
+
+  toJSON(message: ListReportsRequest): unknown {
+    const obj: any = {};
+    if (message.moderator_id !== "") {
+      obj.moderator_id = message.moderator_id;
+    }
+    if (message.cursor !== undefined) {
+      obj.cursor = message.cursor;
+    }
+    if (message.limit !== 0) {
+      obj.limit = Math.round(message.limit);
+    }
+    if (message.pending_only === true) {
+      obj.pending_only = message.pending_only;
+    }
+    if (message.entity_type !== undefined) {
+      obj.entity_type = reportEntityTypeToJSON(message.entity_type);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ListReportsRequest>, I>>(base?: I): ListReportsRequest {
+    return ListReportsRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ListReportsRequest>, I>>(object: I): ListReportsRequest {
+    const message = createBaseListReportsRequest();
+    message.moderator_id = object.moderator_id ?? "";
+    message.cursor = object.cursor ?? undefined;
+    message.limit = object.limit ?? 0;
+    message.pending_only = object.pending_only ?? false;
+    message.entity_type = object.entity_type ?? undefined;
+    return message;
+  },
+};
+
+function createBaseListReportsResponse(): ListReportsResponse {
+  return { reports: [], next_cursor: undefined };
+}
+
+export const ListReportsResponse = {
+  encode(message: ListReportsResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    for (const v of message.reports) {
+      Report.encode(v!, writer.uint32(10).fork()).ldelim();
+    }
+    if (message.next_cursor !== undefined) {
+      writer.uint32(18).string(message.next_cursor);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ListReportsResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseListReportsResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.reports.push(Report.decode(reader, reader.uint32()));
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.next_cursor = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ListReportsResponse {
+    return {
+      reports: globalThis.Array.isArray(object?.reports) ? object.reports.map((e: any) => Report.fromJSON(e)) : [],
+      next_cursor: isSet(object.next_cursor) ? globalThis.String(object.next_cursor) : undefined,
+    };
+  },
+
+  toJSON(message: ListReportsResponse): unknown {
+    const obj: any = {};
+    if (message.reports?.length) {
+      obj.reports = message.reports.map((e) => Report.toJSON(e));
+    }
+    if (message.next_cursor !== undefined) {
+      obj.next_cursor = message.next_cursor;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ListReportsResponse>, I>>(base?: I): ListReportsResponse {
+    return ListReportsResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ListReportsResponse>, I>>(object: I): ListReportsResponse {
+    const message = createBaseListReportsResponse();
+    message.reports = object.reports?.map((e) => Report.fromPartial(e)) || [];
+    message.next_cursor = object.next_cursor ?? undefined;
+    return message;
+  },
+};
+
+function createBaseResolveReportRequest(): ResolveReportRequest {
+  return { moderator_id: "", report_id: "", resolution: 0, note: undefined, suspension_days: undefined };
+}
+
+export const ResolveReportRequest = {
+  encode(message: ResolveReportRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.moderator_id !== "") {
+      writer.uint32(10).string(message.moderator_id);
+    }
+    if (message.report_id !== "") {
+      writer.uint32(18).string(message.report_id);
+    }
+    if (message.resolution !== 0) {
+      writer.uint32(24).int32(message.resolution);
+    }
+    if (message.note !== undefined) {
+      writer.uint32(34).string(message.note);
+    }
+    if (message.suspension_days !== undefined) {
+      writer.uint32(40).uint32(message.suspension_days);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ResolveReportRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseResolveReportRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.moderator_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.report_id = reader.string();
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.resolution = reader.int32() as any;
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.note = reader.string();
+          continue;
+        case 5:
+          if (tag !== 40) {
+            break;
+          }
+
+          message.suspension_days = reader.uint32();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ResolveReportRequest {
+    return {
+      moderator_id: isSet(object.moderator_id) ? globalThis.String(object.moderator_id) : "",
+      report_id: isSet(object.report_id) ? globalThis.String(object.report_id) : "",
+      resolution: isSet(object.resolution) ? reportResolutionFromJSON(object.resolution) : 0,
+      note: isSet(object.note) ? globalThis.String(object.note) : undefined,
+      suspension_days: isSet(object.suspension_days) ? globalThis.Number(object.suspension_days) : undefined,
+    };
+  },
+
+  toJSON(message: ResolveReportRequest): unknown {
+    const obj: any = {};
+    if (message.moderator_id !== "") {
+      obj.moderator_id = message.moderator_id;
+    }
+    if (message.report_id !== "") {
+      obj.report_id = message.report_id;
+    }
+    if (message.resolution !== 0) {
+      obj.resolution = reportResolutionToJSON(message.resolution);
+    }
+    if (message.note !== undefined) {
+      obj.note = message.note;
+    }
+    if (message.suspension_days !== undefined) {
+      obj.suspension_days = Math.round(message.suspension_days);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ResolveReportRequest>, I>>(base?: I): ResolveReportRequest {
+    return ResolveReportRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ResolveReportRequest>, I>>(object: I): ResolveReportRequest {
+    const message = createBaseResolveReportRequest();
+    message.moderator_id = object.moderator_id ?? "";
+    message.report_id = object.report_id ?? "";
+    message.resolution = object.resolution ?? 0;
+    message.note = object.note ?? undefined;
+    message.suspension_days = object.suspension_days ?? undefined;
+    return message;
+  },
+};
+
+function createBaseResolveReportResponse(): ResolveReportResponse {
+  return { report: undefined, audit_log_entry: undefined, deleted_at: undefined };
+}
+
+export const ResolveReportResponse = {
+  encode(message: ResolveReportResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.report !== undefined) {
+      Report.encode(message.report, writer.uint32(10).fork()).ldelim();
+    }
+    if (message.audit_log_entry !== undefined) {
+      ModerationAuditLogEntry.encode(message.audit_log_entry, writer.uint32(18).fork()).ldelim();
+    }
+    if (message.deleted_at !== undefined) {
+      Timestamp.encode(toTimestamp(message.deleted_at), writer.uint32(26).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ResolveReportResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseResolveReportResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.report = Report.decode(reader, reader.uint32());
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.audit_log_entry = ModerationAuditLogEntry.decode(reader, reader.uint32());
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.deleted_at = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ResolveReportResponse {
+    return {
+      report: isSet(object.report) ? Report.fromJSON(object.report) : undefined,
+      audit_log_entry: isSet(object.audit_log_entry)
+        ? ModerationAuditLogEntry.fromJSON(object.audit_log_entry)
+        : undefined,
+      deleted_at: isSet(object.deleted_at) ? fromJsonTimestamp(object.deleted_at) : undefined,
+    };
+  },
+
+  toJSON(message: ResolveReportResponse): unknown {
+    const obj: any = {};
+    if (message.report !== undefined) {
+      obj.report = Report.toJSON(message.report);
+    }
+    if (message.audit_log_entry !== undefined) {
+      obj.audit_log_entry = ModerationAuditLogEntry.toJSON(message.audit_log_entry);
+    }
+    if (message.deleted_at !== undefined) {
+      obj.deleted_at = message.deleted_at.toISOString();
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ResolveReportResponse>, I>>(base?: I): ResolveReportResponse {
+    return ResolveReportResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ResolveReportResponse>, I>>(object: I): ResolveReportResponse {
+    const message = createBaseResolveReportResponse();
+    message.report = (object.report !== undefined && object.report !== null)
+      ? Report.fromPartial(object.report)
+      : undefined;
+    message.audit_log_entry = (object.audit_log_entry !== undefined && object.audit_log_entry !== null)
+      ? ModerationAuditLogEntry.fromPartial(object.audit_log_entry)
+      : undefined;
+    message.deleted_at = object.deleted_at ?? undefined;
+    return message;
+  },
+};
+
+type Builtin = Date | Function | Uint8Array | string | number | boolean | undefined;
+
+export type DeepPartial<T> = T extends Builtin ? T
+  : T extends globalThis.Array<infer U> ? globalThis.Array<DeepPartial<U>>
+  : T extends ReadonlyArray<infer U> ? ReadonlyArray<DeepPartial<U>>
+  : T extends {} ? { [K in keyof T]?: DeepPartial<T[K]> }
+  : Partial<T>;
+
+type KeysOfUnion<T> = T extends T ? keyof T : never;
+export type Exact<P, I extends P> = P extends Builtin ? P
+  : P & { [K in keyof P]: Exact<P[K], I[K]> } & { [K in Exclude<keyof I, KeysOfUnion<P>>]: never };
+
+function toTimestamp(date: Date): Timestamp {
+  const seconds = Math.trunc(date.getTime() / 1_000);
+  const nanos = (date.getTime() % 1_000) * 1_000_000;
+  return { seconds, nanos };
+}
+
+function fromTimestamp(t: Timestamp): Date {
+  let millis = (t.seconds || 0) * 1_000;
+  millis += (t.nanos || 0) / 1_000_000;
+  return new globalThis.Date(millis);
+}
+
+function fromJsonTimestamp(o: any): Date {
+  if (o instanceof globalThis.Date) {
+    return o;
+  } else if (typeof o === "string") {
+    return new globalThis.Date(o);
+  } else {
+    return fromTimestamp(Timestamp.fromJSON(o));
+  }
+}
+
+function isSet(value: any): boolean {
+  return value !== null && value !== undefined;
+}
@@ -1,6 +1,8 @@
 /* eslint-disable */
 import _m0 from "protobufjs/minimal";
+import { AssetRating } from "../../asset_def/v1/def";
 import { BareBlog } from "../../blog_def/v1/def";
+import { Timestamp } from "../../google/protobuf/timestamp";
 import { Tag } from "../../tag_def/v1/def";
 import { BareUser, ExtendedUser } from "../../user_def/v1/def";
 
@@ -155,14 +157,120 @@ export function storyLicenseToJSON(object: StoryLicense): string {
   }
 }
 
+export const StoryImportFormat = {
+  UNSPECIFIED: 0,
+  MARKDOWN: 1,
+  HTML: 2,
+  MEDIUM_ARCHIVE: 3,
+  SUBSTACK_ARCHIVE: 4,
+  UNRECOGNIZED: -1,
+} as const;
+
+export type StoryImportFormat = typeof StoryImportFormat[keyof typeof StoryImportFormat];
+
+export function storyImportFormatFromJSON(object: any): StoryImportFormat {
+  switch (object) {
+    case 0:
+    case "STORY_IMPORT_FORMAT_UNSPECIFIED":
+      return StoryImportFormat.UNSPECIFIED;
+    case 1:
+    case "STORY_IMPORT_FORMAT_MARKDOWN":
+      return StoryImportFormat.MARKDOWN;
+    case 2:
+    case "STORY_IMPORT_FORMAT_HTML":
+      return StoryImportFormat.HTML;
+    case 3:
+    case "STORY_IMPORT_FORMAT_MEDIUM_ARCHIVE":
+      return StoryImportFormat.MEDIUM_ARCHIVE;
+    case 4:
+    case "STORY_IMPORT_FORMAT_SUBSTACK_ARCHIVE":
+      return StoryImportFormat.SUBSTACK_ARCHIVE;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return StoryImportFormat.UNRECOGNIZED;
+  }
+}
+
+export function storyImportFormatToJSON(object: StoryImportFormat): string {
+  switch (object) {
+    case StoryImportFormat.UNSPECIFIED:
+      return "STORY_IMPORT_FORMAT_UNSPECIFIED";
+    case StoryImportFormat.MARKDOWN:
+      return "STORY_IMPORT_FORMAT_MARKDOWN";
+    case StoryImportFormat.HTML:
+      return "STORY_IMPORT_FORMAT_HTML";
+    case StoryImportFormat.MEDIUM_ARCHIVE:
+      return "STORY_IMPORT_FORMAT_MEDIUM_ARCHIVE";
+    case StoryImportFormat.SUBSTACK_ARCHIVE:
+      return "STORY_IMPORT_FORMAT_SUBSTACK_ARCHIVE";
+    case StoryImportFormat.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
+export const StoryExportFormat = { UNSPECIFIED: 0, MARKDOWN: 1, HTML: 2, EPUB: 3, PDF: 4, UNRECOGNIZED: -1 } as const;
+
+export type StoryExportFormat = typeof StoryExportFormat[keyof typeof StoryExportFormat];
+
+export function storyExportFormatFromJSON(object: any): StoryExportFormat {
+  switch (object) {
+    case 0:
+    case "STORY_EXPORT_FORMAT_UNSPECIFIED":
+      return StoryExportFormat.UNSPECIFIED;
+    case 1:
+    case "STORY_EXPORT_FORMAT_MARKDOWN":
+      return StoryExportFormat.MARKDOWN;
+    case 2:
+    case "STORY_EXPORT_FORMAT_HTML":
+      return StoryExportFormat.HTML;
+    case 3:
+    case "STORY_EXPORT_FORMAT_EPUB":
+      return StoryExportFormat.EPUB;
+    case 4:
+    case "STORY_EXPORT_FORMAT_PDF":
+      return StoryExportFormat.PDF;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return StoryExportFormat.UNRECOGNIZED;
+  }
+}
+
+export function storyExportFormatToJSON(object: StoryExportFormat): string {
+  switch (object) {
+    case StoryExportFormat.UNSPECIFIED:
+      return "STORY_EXPORT_FORMAT_UNSPECIFIED";
+    case StoryExportFormat.MARKDOWN:
+      return "STORY_EXPORT_FORMAT_MARKDOWN";
+    case StoryExportFormat.HTML:
+      return "STORY_EXPORT_FORMAT_HTML";
+    case StoryExportFormat.EPUB:
+      return "STORY_EXPORT_FORMAT_EPUB";
+    case StoryExportFormat.PDF:
+      return "STORY_EXPORT_FORMAT_PDF";
+    case StoryExportFormat.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
 export interface Draft {
   id: string;
   title: string;
   splash_id?: string | undefined;
   splash_hex?: string | undefined;
   word_count: number;
+  /** @deprecated */
   created_at: string;
-  edited_at?: string | undefined;
+  /** @deprecated */
+  edited_at?:
+    | string
+    | undefined;
+  /** Timestamps */
+  created_at_ts: Date | undefined;
+  edited_at_ts?: Date | undefined;
 }
 
 export interface ValidateStoryRequest {
@@ -181,6 +289,36 @@ export interface CreateDraftResponse {
   draft_id: string;
 }
 
+export interface ImportStoryRequest {
+  user_id: string;
+  /** Only `MARKDOWN` and `HTML`, archives are imported using `ImportStories` */
+  format: StoryImportFormat;
+  content: Uint8Array;
+}
+
+export interface ImportStoryResponse {
+  draft_id: string;
+}
+
+export interface ImportStoriesRequest {
+  user_id: string;
+  format: StoryImportFormat;
+  content: Uint8Array;
+  /** Used to derive the canonical URLs of Substack posts */
+  publication_url?: string | undefined;
+}
+
+export interface ImportedStory {
+  title: string;
+  /** Omitted when the story could not be imported */
+  draft_id?: string | undefined;
+  error?: string | undefined;
+}
+
+export interface ImportStoriesResponse {
+  stories: ImportedStory[];
+}
+
 export interface GetDraftsInfoRequest {
   user_id: string;
 }
@@ -239,11 +377,24 @@ export interface GetStoryResponse {
   canonical_url?: string | undefined;
   seo_description?: string | undefined;
   seo_title?: string | undefined;
-  preview_image?: string | undefined;
+  preview_image?:
+    | string
+    | undefined;
+  /** @deprecated */
   created_at: string;
-  edited_at?: string | undefined;
-  published_at?: string | undefined;
-  first_published_at?: string | undefined;
+  /** @deprecated */
+  edited_at?:
+    | string
+    | undefined;
+  /** @deprecated */
+  published_at?:
+    | string
+    | undefined;
+  /** @deprecated */
+  first_published_at?:
+    | string
+    | undefined;
+  /** @deprecated */
   deleted_at?:
     | string
     | undefined;
@@ -259,6 +410,32 @@ export interface GetStoryResponse {
   is_liked: boolean;
   /** Reading session token */
   reading_session_token: string;
+  /** Sensitive media */
+  splash_rating: AssetRating;
+  /** Rated images embedded in the content */
+  sensitive_asset_ids: string[];
+  /** Set when the viewer has not opted into sensitive media */
+  blur_sensitive_media: boolean;
+  /** Rated story hidden from the viewer, the content fields are omitted */
+  is_gated: boolean;
+  /** Timestamps */
+  created_at_ts: Date | undefined;
+  edited_at_ts?: Date | undefined;
+  published_at_ts?: Date | undefined;
+  first_published_at_ts?: Date | undefined;
+  deleted_at_ts?: Date | undefined;
+}
+
+export interface ExportStoryRequest {
+  id_or_slug: string;
+  current_user_id?: string | undefined;
+  format: StoryExportFormat;
+}
+
+export interface ExportStoryResponse {
+  filename: string;
+  content_type: string;
+  content: Uint8Array;
 }
 
 export interface GetStoryMetadataRequest {
@@ -287,11 +464,24 @@ export interface GetStoryMetadataResponse {
   canonical_url?: string | undefined;
   seo_description?: string | undefined;
   seo_title?: string | undefined;
-  preview_image?: string | undefined;
+  preview_image?:
+    | string
+    | undefined;
+  /** @deprecated */
   created_at: string;
-  edited_at?: string | undefined;
-  published_at?: string | undefined;
-  first_published_at?: string | undefined;
+  /** @deprecated */
+  edited_at?:
+    | string
+    | undefined;
+  /** @deprecated */
+  published_at?:
+    | string
+    | undefined;
+  /** @deprecated */
+  first_published_at?:
+    | string
+    | undefined;
+  /** @deprecated */
   deleted_at?:
     | string
     | undefined;
@@ -299,6 +489,12 @@ export interface GetStoryMetadataResponse {
   user: BareUser | undefined;
   blog?: BareBlog | undefined;
   tags: Tag[];
+  /** Timestamps */
+  created_at_ts: Date | undefined;
+  edited_at_ts?: Date | undefined;
+  published_at_ts?: Date | undefined;
+  first_published_at_ts?: Date | undefined;
+  deleted_at_ts?: Date | undefined;
 }
 
 function createBaseDraft(): Draft {
@@ -310,6 +506,8 @@ function createBaseDraft(): Draft {
     word_count: 0,
     created_at: "",
     edited_at: undefined,
+    created_at_ts: undefined,
+    edited_at_ts: undefined,
   };
 }
 
@@ -336,6 +534,12 @@ export const Draft = {
     if (message.edited_at !== undefined) {
       writer.uint32(58).string(message.edited_at);
     }
+    if (message.created_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at_ts), writer.uint32(66).fork()).ldelim();
+    }
+    if (message.edited_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.edited_at_ts), writer.uint32(74).fork()).ldelim();
+    }
     return writer;
   },
 
@@ -395,6 +599,20 @@ export const Draft = {
 
           message.edited_at = reader.string();
           continue;
+        case 8:
+          if (tag !== 66) {
+            break;
+          }
+
+          message.created_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 9:
+          if (tag !== 74) {
+            break;
+          }
+
+          message.edited_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -413,6 +631,8 @@ export const Draft = {
       word_count: isSet(object.word_count) ? globalThis.Number(object.word_count) : 0,
       created_at: isSet(object.created_at) ? globalThis.String(object.created_at) : "",
       edited_at: isSet(object.edited_at) ? globalThis.String(object.edited_at) : undefined,
+      created_at_ts: isSet(object.created_at_ts) ? fromJsonTimestamp(object.created_at_ts) : undefined,
+      edited_at_ts: isSet(object.edited_at_ts) ? fromJsonTimestamp(object.edited_at_ts) : undefined,
     };
   },
 
@@ -439,6 +659,12 @@ export const Draft = {
     if (message.edited_at !== undefined) {
       obj.edited_at = message.edited_at;
     }
+    if (message.created_at_ts !== undefined) {
+      obj.created_at_ts = message.created_at_ts.toISOString();
+    }
+    if (message.edited_at_ts !== undefined) {
+      obj.edited_at_ts = message.edited_at_ts.toISOString();
+    }
     return obj;
   },
 
@@ -454,6 +680,8 @@ export const Draft = {
     message.word_count = object.word_count ?? 0;
     message.created_at = object.created_at ?? "";
     message.edited_at = object.edited_at ?? undefined;
+    message.created_at_ts = object.created_at_ts ?? undefined;
+    message.edited_at_ts = object.edited_at_ts ?? undefined;
     return message;
   },
 };
@@ -632,22 +860,418 @@ export const CreateDraftRequest = {
   },
 };
 
-function createBaseCreateDraftResponse(): CreateDraftResponse {
-  return { draft_id: "" };
+function createBaseCreateDraftResponse(): CreateDraftResponse {
+  return { draft_id: "" };
+}
+
+export const CreateDraftResponse = {
+  encode(message: CreateDraftResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.draft_id !== "") {
+      writer.uint32(10).string(message.draft_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): CreateDraftResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCreateDraftResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.draft_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): CreateDraftResponse {
+    return { draft_id: isSet(object.draft_id) ? globalThis.String(object.draft_id) : "" };
+  },
+
+  toJSON(message: CreateDraftResponse): unknown {
+    const obj: any = {};
+    if (message.draft_id !== "") {
+      obj.draft_id = message.draft_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<CreateDraftResponse>, I>>(base?: I): CreateDraftResponse {
+    return CreateDraftResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<CreateDraftResponse>, I>>(object: I): CreateDraftResponse {
+    const message = createBaseCreateDraftResponse();
+    message.draft_id = object.draft_id ?? "";
+    return message;
+  },
+};
+
+function createBaseImportStoryRequest(): ImportStoryRequest {
+  return { user_id: "", format: 0, content: new Uint8Array(0) };
+}
+
+export const ImportStoryRequest = {
+  encode(message: ImportStoryRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.format !== 0) {
+      writer.uint32(16).int32(message.format);
+    }
+    if (message.content.length !== 0) {
+      writer.uint32(26).bytes(message.content);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ImportStoryRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseImportStoryRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.format = reader.int32() as any;
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.content = reader.bytes();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ImportStoryRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      format: isSet(object.format) ? storyImportFormatFromJSON(object.format) : 0,
+      content: isSet(object.content) ? bytesFromBase64(object.content) : new Uint8Array(0),
+    };
+  },
+
+  toJSON(message: ImportStoryRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.format !== 0) {
+      obj.format = storyImportFormatToJSON(message.format);
+    }
+    if (message.content.length !== 0) {
+      obj.content = base64FromBytes(message.content);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ImportStoryRequest>, I>>(base?: I): ImportStoryRequest {
+    return ImportStoryRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ImportStoryRequest>, I>>(object: I): ImportStoryRequest {
+    const message = createBaseImportStoryRequest();
+    message.user_id = object.user_id ?? "";
+    message.format = object.format ?? 0;
+    message.content = object.content ?? new Uint8Array(0);
+    return message;
+  },
+};
+
+function createBaseImportStoryResponse(): ImportStoryResponse {
+  return { draft_id: "" };
+}
+
+export const ImportStoryResponse = {
+  encode(message: ImportStoryResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.draft_id !== "") {
+      writer.uint32(10).string(message.draft_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ImportStoryResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseImportStoryResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.draft_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ImportStoryResponse {
+    return { draft_id: isSet(object.draft_id) ? globalThis.String(object.draft_id) : "" };
+  },
+
+  toJSON(message: ImportStoryResponse): unknown {
+    const obj: any = {};
+    if (message.draft_id !== "") {
+      obj.draft_id = message.draft_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ImportStoryResponse>, I>>(base?: I): ImportStoryResponse {
+    return ImportStoryResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ImportStoryResponse>, I>>(object: I): ImportStoryResponse {
+    const message = createBaseImportStoryResponse();
+    message.draft_id = object.draft_id ?? "";
+    return message;
+  },
+};
+
+function createBaseImportStoriesRequest(): ImportStoriesRequest {
+  return { user_id: "", format: 0, content: new Uint8Array(0), publication_url: undefined };
+}
+
+export const ImportStoriesRequest = {
+  encode(message: ImportStoriesRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.format !== 0) {
+      writer.uint32(16).int32(message.format);
+    }
+    if (message.content.length !== 0) {
+      writer.uint32(26).bytes(message.content);
+    }
+    if (message.publication_url !== undefined) {
+      writer.uint32(34).string(message.publication_url);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ImportStoriesRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseImportStoriesRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.format = reader.int32() as any;
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.content = reader.bytes();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.publication_url = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ImportStoriesRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      format: isSet(object.format) ? storyImportFormatFromJSON(object.format) : 0,
+      content: isSet(object.content) ? bytesFromBase64(object.content) : new Uint8Array(0),
+      publication_url: isSet(object.publication_url) ? globalThis.String(object.publication_url) : undefined,
+    };
+  },
+
+  toJSON(message: ImportStoriesRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.format !== 0) {
+      obj.format = storyImportFormatToJSON(message.format);
+    }
+    if (message.content.length !== 0) {
+      obj.content = base64FromBytes(message.content);
+    }
+    if (message.publication_url !== undefined) {
+      obj.publication_url = message.publication_url;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ImportStoriesRequest>, I>>(base?: I): ImportStoriesRequest {
+    return ImportStoriesRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ImportStoriesRequest>, I>>(object: I): ImportStoriesRequest {
+    const message = createBaseImportStoriesRequest();
+    message.user_id = object.user_id ?? "";
+    message.format = object.format ?? 0;
+    message.content = object.content ?? new Uint8Array(0);
+    message.publication_url = object.publication_url ?? undefined;
+    return message;
+  },
+};
+
+function createBaseImportedStory(): ImportedStory {
+  return { title: "", draft_id: undefined, error: undefined };
+}
+
+export const ImportedStory = {
+  encode(message: ImportedStory, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.title !== "") {
+      writer.uint32(10).string(message.title);
+    }
+    if (message.draft_id !== undefined) {
+      writer.uint32(18).string(message.draft_id);
+    }
+    if (message.error !== undefined) {
+      writer.uint32(26).string(message.error);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ImportedStory {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseImportedStory();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.title = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.draft_id = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.error = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ImportedStory {
+    return {
+      title: isSet(object.title) ? globalThis.String(object.title) : "",
+      draft_id: isSet(object.draft_id) ? globalThis.String(object.draft_id) : undefined,
+      error: isSet(object.error) ? globalThis.String(object.error) : undefined,
+    };
+  },
+
+  toJSON(message: ImportedStory): unknown {
+    const obj: any = {};
+    if (message.title !== "") {
+      obj.title = message.title;
+    }
+    if (message.draft_id !== undefined) {
+      obj.draft_id = message.draft_id;
+    }
+    if (message.error !== undefined) {
+      obj.error = message.error;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ImportedStory>, I>>(base?: I): ImportedStory {
+    return ImportedStory.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ImportedStory>, I>>(object: I): ImportedStory {
+    const message = createBaseImportedStory();
+    message.title = object.title ?? "";
+    message.draft_id = object.draft_id ?? undefined;
+    message.error = object.error ?? undefined;
+    return message;
+  },
+};
+
+function createBaseImportStoriesResponse(): ImportStoriesResponse {
+  return { stories: [] };
 }
 
-export const CreateDraftResponse = {
-  encode(message: CreateDraftResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
-    if (message.draft_id !== "") {
-      writer.uint32(10).string(message.draft_id);
+export const ImportStoriesResponse = {
+  encode(message: ImportStoriesResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    for (const v of message.stories) {
+      ImportedStory.encode(v!, writer.uint32(10).fork()).ldelim();
     }
     return writer;
   },
 
-  decode(input: _m0.Reader | Uint8Array, length?: number): CreateDraftResponse {
+  decode(input: _m0.Reader | Uint8Array, length?: number): ImportStoriesResponse {
     const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseCreateDraftResponse();
+    const message = createBaseImportStoriesResponse();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -656,7 +1280,7 @@ export const CreateDraftResponse = {
             break;
           }
 
-          message.draft_id = reader.string();
+          message.stories.push(ImportedStory.decode(reader, reader.uint32()));
           continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
@@ -667,24 +1291,28 @@ export const CreateDraftResponse = {
     return message;
   },
 
-  fromJSON(object: any): CreateDraftResponse {
-    return { draft_id: isSet(object.draft_id) ? globalThis.String(object.draft_id) : "" };
+  fromJSON(object: any): ImportStoriesResponse {
+    return {
+      stories: globalThis.Array.isArray(object?.stories)
+        ? object.stories.map((e: any) => ImportedStory.fromJSON(e))
+        : [],
+    };
   },
 
-  toJSON(message: CreateDraftResponse): unknown {
+  toJSON(message: ImportStoriesResponse): unknown {
     const obj: any = {};
-    if (message.draft_id !== "") {
-      obj.draft_id = message.draft_id;
+    if (message.stories?.length) {
+      obj.stories = message.stories.map((e) => ImportedStory.toJSON(e));
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<CreateDraftResponse>, I>>(base?: I): CreateDraftResponse {
-    return CreateDraftResponse.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<ImportStoriesResponse>, I>>(base?: I): ImportStoriesResponse {
+    return ImportStoriesResponse.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<CreateDraftResponse>, I>>(object: I): CreateDraftResponse {
-    const message = createBaseCreateDraftResponse();
-    message.draft_id = object.draft_id ?? "";
+  fromPartial<I extends Exact<DeepPartial<ImportStoriesResponse>, I>>(object: I): ImportStoriesResponse {
+    const message = createBaseImportStoriesResponse();
+    message.stories = object.stories?.map((e) => ImportedStory.fromPartial(e)) || [];
     return message;
   },
 };
@@ -1214,6 +1842,15 @@ function createBaseGetStoryResponse(): GetStoryResponse {
     is_bookmarked: false,
     is_liked: false,
     reading_session_token: "",
+    splash_rating: 0,
+    sensitive_asset_ids: [],
+    blur_sensitive_media: false,
+    is_gated: false,
+    created_at_ts: undefined,
+    edited_at_ts: undefined,
+    published_at_ts: undefined,
+    first_published_at_ts: undefined,
+    deleted_at_ts: undefined,
   };
 }
 
@@ -1324,6 +1961,33 @@ export const GetStoryResponse = {
     if (message.reading_session_token !== "") {
       writer.uint32(282).string(message.reading_session_token);
     }
+    if (message.splash_rating !== 0) {
+      writer.uint32(288).int32(message.splash_rating);
+    }
+    for (const v of message.sensitive_asset_ids) {
+      writer.uint32(298).string(v!);
+    }
+    if (message.blur_sensitive_media === true) {
+      writer.uint32(304).bool(message.blur_sensitive_media);
+    }
+    if (message.is_gated === true) {
+      writer.uint32(312).bool(message.is_gated);
+    }
+    if (message.created_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at_ts), writer.uint32(322).fork()).ldelim();
+    }
+    if (message.edited_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.edited_at_ts), writer.uint32(330).fork()).ldelim();
+    }
+    if (message.published_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.published_at_ts), writer.uint32(338).fork()).ldelim();
+    }
+    if (message.first_published_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.first_published_at_ts), writer.uint32(346).fork()).ldelim();
+    }
+    if (message.deleted_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.deleted_at_ts), writer.uint32(354).fork()).ldelim();
+    }
     return writer;
   },
 
@@ -1579,6 +2243,69 @@ export const GetStoryResponse = {
 
           message.reading_session_token = reader.string();
           continue;
+        case 36:
+          if (tag !== 288) {
+            break;
+          }
+
+          message.splash_rating = reader.int32() as any;
+          continue;
+        case 37:
+          if (tag !== 298) {
+            break;
+          }
+
+          message.sensitive_asset_ids.push(reader.string());
+          continue;
+        case 38:
+          if (tag !== 304) {
+            break;
+          }
+
+          message.blur_sensitive_media = reader.bool();
+          continue;
+        case 39:
+          if (tag !== 312) {
+            break;
+          }
+
+          message.is_gated = reader.bool();
+          continue;
+        case 40:
+          if (tag !== 322) {
+            break;
+          }
+
+          message.created_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 41:
+          if (tag !== 330) {
+            break;
+          }
+
+          message.edited_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 42:
+          if (tag !== 338) {
+            break;
+          }
+
+          message.published_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 43:
+          if (tag !== 346) {
+            break;
+          }
+
+          message.first_published_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 44:
+          if (tag !== 354) {
+            break;
+          }
+
+          message.deleted_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -1629,6 +2356,21 @@ export const GetStoryResponse = {
       is_bookmarked: isSet(object.is_bookmarked) ? globalThis.Boolean(object.is_bookmarked) : false,
       is_liked: isSet(object.is_liked) ? globalThis.Boolean(object.is_liked) : false,
       reading_session_token: isSet(object.reading_session_token) ? globalThis.String(object.reading_session_token) : "",
+      splash_rating: isSet(object.splash_rating) ? assetRatingFromJSON(object.splash_rating) : 0,
+      sensitive_asset_ids: globalThis.Array.isArray(object?.sensitive_asset_ids)
+        ? object.sensitive_asset_ids.map((e: any) => globalThis.String(e))
+        : [],
+      blur_sensitive_media: isSet(object.blur_sensitive_media)
+        ? globalThis.Boolean(object.blur_sensitive_media)
+        : false,
+      is_gated: isSet(object.is_gated) ? globalThis.Boolean(object.is_gated) : false,
+      created_at_ts: isSet(object.created_at_ts) ? fromJsonTimestamp(object.created_at_ts) : undefined,
+      edited_at_ts: isSet(object.edited_at_ts) ? fromJsonTimestamp(object.edited_at_ts) : undefined,
+      published_at_ts: isSet(object.published_at_ts) ? fromJsonTimestamp(object.published_at_ts) : undefined,
+      first_published_at_ts: isSet(object.first_published_at_ts)
+        ? fromJsonTimestamp(object.first_published_at_ts)
+        : undefined,
+      deleted_at_ts: isSet(object.deleted_at_ts) ? fromJsonTimestamp(object.deleted_at_ts) : undefined,
     };
   },
 
@@ -1739,6 +2481,33 @@ export const GetStoryResponse = {
     if (message.reading_session_token !== "") {
       obj.reading_session_token = message.reading_session_token;
     }
+    if (message.splash_rating !== 0) {
+      obj.splash_rating = assetRatingToJSON(message.splash_rating);
+    }
+    if (message.sensitive_asset_ids?.length) {
+      obj.sensitive_asset_ids = message.sensitive_asset_ids;
+    }
+    if (message.blur_sensitive_media === true) {
+      obj.blur_sensitive_media = message.blur_sensitive_media;
+    }
+    if (message.is_gated === true) {
+      obj.is_gated = message.is_gated;
+    }
+    if (message.created_at_ts !== undefined) {
+      obj.created_at_ts = message.created_at_ts.toISOString();
+    }
+    if (message.edited_at_ts !== undefined) {
+      obj.edited_at_ts = message.edited_at_ts.toISOString();
+    }
+    if (message.published_at_ts !== undefined) {
+      obj.published_at_ts = message.published_at_ts.toISOString();
+    }
+    if (message.first_published_at_ts !== undefined) {
+      obj.first_published_at_ts = message.first_published_at_ts.toISOString();
+    }
+    if (message.deleted_at_ts !== undefined) {
+      obj.deleted_at_ts = message.deleted_at_ts.toISOString();
+    }
     return obj;
   },
 
@@ -1784,6 +2553,193 @@ export const GetStoryResponse = {
     message.is_bookmarked = object.is_bookmarked ?? false;
     message.is_liked = object.is_liked ?? false;
     message.reading_session_token = object.reading_session_token ?? "";
+    message.splash_rating = object.splash_rating ?? 0;
+    message.sensitive_asset_ids = object.sensitive_asset_ids?.map((e) => e) || [];
+    message.blur_sensitive_media = object.blur_sensitive_media ?? false;
+    message.is_gated = object.is_gated ?? false;
+    message.created_at_ts = object.created_at_ts ?? undefined;
+    message.edited_at_ts = object.edited_at_ts ?? undefined;
+    message.published_at_ts = object.published_at_ts ?? undefined;
+    message.first_published_at_ts = object.first_published_at_ts ?? undefined;
+    message.deleted_at_ts = object.deleted_at_ts ?? undefined;
+    return message;
+  },
+};
+
+function createBaseExportStoryRequest(): ExportStoryRequest {
+  return { id_or_slug: "", current_user_id: undefined, format: 0 };
+}
+
+export const ExportStoryRequest = {
+  encode(message: ExportStoryRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.id_or_slug !== "") {
+      writer.uint32(10).string(message.id_or_slug);
+    }
+    if (message.current_user_id !== undefined) {
+      writer.uint32(18).string(message.current_user_id);
+    }
+    if (message.format !== 0) {
+      writer.uint32(24).int32(message.format);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ExportStoryRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseExportStoryRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.id_or_slug = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.current_user_id = reader.string();
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.format = reader.int32() as any;
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ExportStoryRequest {
+    return {
+      id_or_slug: isSet(object.id_or_slug) ? globalThis.String(object.id_or_slug) : "",
+      current_user_id: isSet(object.current_user_id) ? globalThis.String(object.current_user_id) : undefined,
+      format: isSet(object.format) ? storyExportFormatFromJSON(object.format) : 0,
+    };
+  },
+
+  toJSON(message: ExportStoryRequest): unknown {
+    const obj: any = {};
+    if (message.id_or_slug !== "") {
+      obj.id_or_slug = message.id_or_slug;
+    }
+    if (message.current_user_id !== undefined) {
+      obj.current_user_id = message.current_user_id;
+    }
+    if (message.format !== 0) {
+      obj.format = storyExportFormatToJSON(message.format);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ExportStoryRequest>, I>>(base?: I): ExportStoryRequest {
+    return ExportStoryRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ExportStoryRequest>, I>>(object: I): ExportStoryRequest {
+    const message = createBaseExportStoryRequest();
+    message.id_or_slug = object.id_or_slug ?? "";
+    message.current_user_id = object.current_user_id ?? undefined;
+    message.format = object.format ?? 0;
+    return message;
+  },
+};
+
+function createBaseExportStoryResponse(): ExportStoryResponse {
+  return { filename: "", content_type: "", content: new Uint8Array(0) };
+}
+
+export const ExportStoryResponse = {
+  encode(message: ExportStoryResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.filename !== "") {
+      writer.uint32(10).string(message.filename);
+    }
+    if (message.content_type !== "") {
+      writer.uint32(18).string(message.content_type);
+    }
+    if (message.content.length !== 0) {
+      writer.uint32(26).bytes(message.content);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ExportStoryResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseExportStoryResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.filename = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.content_type = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.content = reader.bytes();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ExportStoryResponse {
+    return {
+      filename: isSet(object.filename) ? globalThis.String(object.filename) : "",
+      content_type: isSet(object.content_type) ? globalThis.String(object.content_type) : "",
+      content: isSet(object.content) ? bytesFromBase64(object.content) : new Uint8Array(0),
+    };
+  },
+
+  toJSON(message: ExportStoryResponse): unknown {
+    const obj: any = {};
+    if (message.filename !== "") {
+      obj.filename = message.filename;
+    }
+    if (message.content_type !== "") {
+      obj.content_type = message.content_type;
+    }
+    if (message.content.length !== 0) {
+      obj.content = base64FromBytes(message.content);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ExportStoryResponse>, I>>(base?: I): ExportStoryResponse {
+    return ExportStoryResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ExportStoryResponse>, I>>(object: I): ExportStoryResponse {
+    const message = createBaseExportStoryResponse();
+    message.filename = object.filename ?? "";
+    message.content_type = object.content_type ?? "";
+    message.content = object.content ?? new Uint8Array(0);
     return message;
   },
 };
@@ -1892,6 +2848,11 @@ function createBaseGetStoryMetadataResponse(): GetStoryMetadataResponse {
     user: undefined,
     blog: undefined,
     tags: [],
+    created_at_ts: undefined,
+    edited_at_ts: undefined,
+    published_at_ts: undefined,
+    first_published_at_ts: undefined,
+    deleted_at_ts: undefined,
   };
 }
 
@@ -1981,6 +2942,21 @@ export const GetStoryMetadataResponse = {
     for (const v of message.tags) {
       Tag.encode(v!, writer.uint32(226).fork()).ldelim();
     }
+    if (message.created_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at_ts), writer.uint32(234).fork()).ldelim();
+    }
+    if (message.edited_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.edited_at_ts), writer.uint32(242).fork()).ldelim();
+    }
+    if (message.published_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.published_at_ts), writer.uint32(250).fork()).ldelim();
+    }
+    if (message.first_published_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.first_published_at_ts), writer.uint32(258).fork()).ldelim();
+    }
+    if (message.deleted_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.deleted_at_ts), writer.uint32(266).fork()).ldelim();
+    }
     return writer;
   },
 
@@ -2187,6 +3163,41 @@ export const GetStoryMetadataResponse = {
 
           message.tags.push(Tag.decode(reader, reader.uint32()));
           continue;
+        case 29:
+          if (tag !== 234) {
+            break;
+          }
+
+          message.created_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 30:
+          if (tag !== 242) {
+            break;
+          }
+
+          message.edited_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 31:
+          if (tag !== 250) {
+            break;
+          }
+
+          message.published_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 32:
+          if (tag !== 258) {
+            break;
+          }
+
+          message.first_published_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 33:
+          if (tag !== 266) {
+            break;
+          }
+
+          message.deleted_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -2228,6 +3239,13 @@ export const GetStoryMetadataResponse = {
       user: isSet(object.user) ? BareUser.fromJSON(object.user) : undefined,
       blog: isSet(object.blog) ? BareBlog.fromJSON(object.blog) : undefined,
       tags: globalThis.Array.isArray(object?.tags) ? object.tags.map((e: any) => Tag.fromJSON(e)) : [],
+      created_at_ts: isSet(object.created_at_ts) ? fromJsonTimestamp(object.created_at_ts) : undefined,
+      edited_at_ts: isSet(object.edited_at_ts) ? fromJsonTimestamp(object.edited_at_ts) : undefined,
+      published_at_ts: isSet(object.published_at_ts) ? fromJsonTimestamp(object.published_at_ts) : undefined,
+      first_published_at_ts: isSet(object.first_published_at_ts)
+        ? fromJsonTimestamp(object.first_published_at_ts)
+        : undefined,
+      deleted_at_ts: isSet(object.deleted_at_ts) ? fromJsonTimestamp(object.deleted_at_ts) : undefined,
     };
   },
 
@@ -2317,6 +3335,21 @@ export const GetStoryMetadataResponse = {
     if (message.tags?.length) {
       obj.tags = message.tags.map((e) => Tag.toJSON(e));
     }
+    if (message.created_at_ts !== undefined) {
+      obj.created_at_ts = message.created_at_ts.toISOString();
+    }
+    if (message.edited_at_ts !== undefined) {
+      obj.edited_at_ts = message.edited_at_ts.toISOString();
+    }
+    if (message.published_at_ts !== undefined) {
+      obj.published_at_ts = message.published_at_ts.toISOString();
+    }
+    if (message.first_published_at_ts !== undefined) {
+      obj.first_published_at_ts = message.first_published_at_ts.toISOString();
+    }
+    if (message.deleted_at_ts !== undefined) {
+      obj.deleted_at_ts = message.deleted_at_ts.toISOString();
+    }
     return obj;
   },
 
@@ -2353,10 +3386,23 @@ export const GetStoryMetadataResponse = {
     message.user = (object.user !== undefined && object.user !== null) ? BareUser.fromPartial(object.user) : undefined;
     message.blog = (object.blog !== undefined && object.blog !== null) ? BareBlog.fromPartial(object.blog) : undefined;
     message.tags = object.tags?.map((e) => Tag.fromPartial(e)) || [];
+    message.created_at_ts = object.created_at_ts ?? undefined;
+    message.edited_at_ts = object.edited_at_ts ?? undefined;
+    message.published_at_ts = object.published_at_ts ?? undefined;
+    message.first_published_at_ts = object.first_published_at_ts ?? undefined;
+    message.deleted_at_ts = object.deleted_at_ts ?? undefined;
     return message;
   },
 };
 
+function bytesFromBase64(b64: string): Uint8Array {
+  return Uint8Array.from(globalThis.Buffer.from(b64, "base64"));
+}
+
+function base64FromBytes(arr: Uint8Array): string {
+  return globalThis.Buffer.from(arr).toString("base64");
+}
+
 type Builtin = Date | Function | Uint8Array | string | number | boolean | undefined;
 
 export type DeepPartial<T> = T extends Builtin ? T
@@ -2369,6 +3415,28 @@ type KeysOfUnion<T> = T extends T ? keyof T : never;
 export type Exact<P, I extends P> = P extends Builtin ? P
   : P & { [K in keyof P]: Exact<P[K], I[K]> } & { [K in Exclude<keyof I, KeysOfUnion<P>>]: never };
 
+function toTimestamp(date: Date): Timestamp {
+  const seconds = Math.trunc(date.getTime() / 1_000);
+  const nanos = (date.getTime() % 1_000) * 1_000_000;
+  return { seconds, nanos };
+}
+
+function fromTimestamp(t: Timestamp): Date {
+  let millis = (t.seconds || 0) * 1_000;
+  millis += (t.nanos || 0) / 1_000_000;
+  return new globalThis.Date(millis);
+}
+
+function fromJsonTimestamp(o: any): Date {
+  if (o instanceof globalThis.Date) {
+    return o;
+  } else if (typeof o === "string") {
+    return new globalThis.Date(o);
+  } else {
+    return fromTimestamp(Timestamp.fromJSON(o));
+  }
+}
+
 function isSet(value: any): boolean {
   return value !== null && value !== undefined;
 }
@@ -1,5 +1,6 @@
 /* eslint-disable */
 import _m0 from "protobufjs/minimal";
+import { Timestamp } from "../../google/protobuf/timestamp";
 
 export const protobufPackage = "tag_def.v1";
 
@@ -19,9 +20,12 @@ export interface GetTagResponse {
   name: string;
   story_count: number;
   follower_count: number;
+  /** @deprecated */
   created_at: string;
   /** User specific props */
   is_following: boolean;
+  /** Timestamps */
+  created_at_ts: Date | undefined;
 }
 
 export interface GetFollowedTagCountRequest {
@@ -181,7 +185,15 @@ export const GetTagRequest = {
 };
 
 function createBaseGetTagResponse(): GetTagResponse {
-  return { id: "", name: "", story_count: 0, follower_count: 0, created_at: "", is_following: false };
+  return {
+    id: "",
+    name: "",
+    story_count: 0,
+    follower_count: 0,
+    created_at: "",
+    is_following: false,
+    created_at_ts: undefined,
+  };
 }
 
 export const GetTagResponse = {
@@ -204,6 +216,9 @@ export const GetTagResponse = {
     if (message.is_following === true) {
       writer.uint32(48).bool(message.is_following);
     }
+    if (message.created_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at_ts), writer.uint32(58).fork()).ldelim();
+    }
     return writer;
   },
 
@@ -256,6 +271,13 @@ export const GetTagResponse = {
 
           message.is_following = reader.bool();
           continue;
+        case 7:
+          if (tag !== 58) {
+            break;
+          }
+
+          message.created_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -273,6 +295,7 @@ export const GetTagResponse = {
       follower_count: isSet(object.follower_count) ? globalThis.Number(object.follower_count) : 0,
       created_at: isSet(object.created_at) ? globalThis.String(object.created_at) : "",
       is_following: isSet(object.is_following) ? globalThis.Boolean(object.is_following) : false,
+      created_at_ts: isSet(object.created_at_ts) ? fromJsonTimestamp(object.created_at_ts) : undefined,
     };
   },
 
@@ -296,6 +319,9 @@ export const GetTagResponse = {
     if (message.is_following === true) {
       obj.is_following = message.is_following;
     }
+    if (message.created_at_ts !== undefined) {
+      obj.created_at_ts = message.created_at_ts.toISOString();
+    }
     return obj;
   },
 
@@ -310,6 +336,7 @@ export const GetTagResponse = {
     message.follower_count = object.follower_count ?? 0;
     message.created_at = object.created_at ?? "";
     message.is_following = object.is_following ?? false;
+    message.created_at_ts = object.created_at_ts ?? undefined;
     return message;
   },
 };
@@ -440,6 +467,28 @@ type KeysOfUnion<T> = T extends T ? keyof T : never;
 export type Exact<P, I extends P> = P extends Builtin ? P
   : P & { [K in keyof P]: Exact<P[K], I[K]> } & { [K in Exclude<keyof I, KeysOfUnion<P>>]: never };
 
+function toTimestamp(date: Date): Timestamp {
+  const seconds = Math.trunc(date.getTime() / 1_000);
+  const nanos = (date.getTime() % 1_000) * 1_000_000;
+  return { seconds, nanos };
+}
+
+function fromTimestamp(t: Timestamp): Date {
+  let millis = (t.seconds || 0) * 1_000;
+  millis += (t.nanos || 0) / 1_000_000;
+  return new globalThis.Date(millis);
+}
+
+function fromJsonTimestamp(o: any): Date {
+  if (o instanceof globalThis.Date) {
+    return o;
+  } else if (typeof o === "string") {
+    return new globalThis.Date(o);
+  } else {
+    return fromTimestamp(Timestamp.fromJSON(o));
+  }
+}
+
 function isSet(value: any): boolean {
   return value !== null && value !== undefined;
 }
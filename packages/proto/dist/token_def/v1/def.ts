@@ -8,6 +8,7 @@ export const TokenType = {
   EMAIL_VERIFICATION: 1,
   PASSWORD_RESET: 2,
   PASSWORD_ADD: 3,
+  ACCOUNT_EXPORT: 4,
   UNRECOGNIZED: -1,
 } as const;
 
@@ -27,6 +28,9 @@ export function tokenTypeFromJSON(object: any): TokenType {
     case 3:
     case "TOKEN_TYPE_PASSWORD_ADD":
       return TokenType.PASSWORD_ADD;
+    case 4:
+    case "TOKEN_TYPE_ACCOUNT_EXPORT":
+      return TokenType.ACCOUNT_EXPORT;
     case -1:
     case "UNRECOGNIZED":
     default:
@@ -44,6 +48,8 @@ export function tokenTypeToJSON(object: TokenType): string {
       return "TOKEN_TYPE_PASSWORD_RESET";
     case TokenType.PASSWORD_ADD:
       return "TOKEN_TYPE_PASSWORD_ADD";
+    case TokenType.ACCOUNT_EXPORT:
+      return "TOKEN_TYPE_ACCOUNT_EXPORT";
     case TokenType.UNRECOGNIZED:
     default:
       return "UNRECOGNIZED";
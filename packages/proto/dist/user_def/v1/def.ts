@@ -1,5 +1,6 @@
 /* eslint-disable */
 import _m0 from "protobufjs/minimal";
+import { Timestamp } from "../../google/protobuf/timestamp";
 
 export const protobufPackage = "user_def.v1";
 
@@ -103,18 +104,96 @@ export function statusDurationToJSON(object: StatusDuration): string {
   }
 }
 
+/** Flag bits for `public_flags`, always a power of 2 (2, 4, 8, ...) */
+export const UserFlag = {
+  UNSPECIFIED: 0,
+  STAFF: 1,
+  TEMPORARILY_SUSPENDED: 2,
+  PERMANENTLY_SUSPENDED: 4,
+  VERIFIED: 8,
+  EARLY_USER: 16,
+  PLUS_MEMBER: 32,
+  UNRECOGNIZED: -1,
+} as const;
+
+export type UserFlag = typeof UserFlag[keyof typeof UserFlag];
+
+export function userFlagFromJSON(object: any): UserFlag {
+  switch (object) {
+    case 0:
+    case "USER_FLAG_UNSPECIFIED":
+      return UserFlag.UNSPECIFIED;
+    case 1:
+    case "USER_FLAG_STAFF":
+      return UserFlag.STAFF;
+    case 2:
+    case "USER_FLAG_TEMPORARILY_SUSPENDED":
+      return UserFlag.TEMPORARILY_SUSPENDED;
+    case 4:
+    case "USER_FLAG_PERMANENTLY_SUSPENDED":
+      return UserFlag.PERMANENTLY_SUSPENDED;
+    case 8:
+    case "USER_FLAG_VERIFIED":
+      return UserFlag.VERIFIED;
+    case 16:
+    case "USER_FLAG_EARLY_USER":
+      return UserFlag.EARLY_USER;
+    case 32:
+    case "USER_FLAG_PLUS_MEMBER":
+      return UserFlag.PLUS_MEMBER;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return UserFlag.UNRECOGNIZED;
+  }
+}
+
+export function userFlagToJSON(object: UserFlag): string {
+  switch (object) {
+    case UserFlag.UNSPECIFIED:
+      return "USER_FLAG_UNSPECIFIED";
+    case UserFlag.STAFF:
+      return "USER_FLAG_STAFF";
+    case UserFlag.TEMPORARILY_SUSPENDED:
+      return "USER_FLAG_TEMPORARILY_SUSPENDED";
+    case UserFlag.PERMANENTLY_SUSPENDED:
+      return "USER_FLAG_PERMANENTLY_SUSPENDED";
+    case UserFlag.VERIFIED:
+      return "USER_FLAG_VERIFIED";
+    case UserFlag.EARLY_USER:
+      return "USER_FLAG_EARLY_USER";
+    case UserFlag.PLUS_MEMBER:
+      return "USER_FLAG_PLUS_MEMBER";
+    case UserFlag.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
 export interface BareStatus {
   emoji?: string | undefined;
-  text?: string | undefined;
-  expires_at?: string | undefined;
+  text?:
+    | string
+    | undefined;
+  /** @deprecated */
+  expires_at?:
+    | string
+    | undefined;
+  /** Timestamps */
+  expires_at_ts?: Date | undefined;
 }
 
 export interface ExtendedStatus {
   emoji?: string | undefined;
-  text?: string | undefined;
+  text?:
+    | string
+    | undefined;
+  /** @deprecated */
   expires_at?: string | undefined;
   duration: StatusDuration;
   visibility: StatusVisibility;
+  /** Timestamps */
+  expires_at_ts?: Date | undefined;
 }
 
 export interface BareUser {
@@ -122,7 +201,10 @@ export interface BareUser {
   name: string;
   username: string;
   avatar_id?: string | undefined;
-  avatar_hex?: string | undefined;
+  avatar_hex?:
+    | string
+    | undefined;
+  /** Bitfield of `UserFlag` */
   public_flags: number;
 }
 
@@ -132,10 +214,14 @@ export interface ExtendedUser {
   username: string;
   rendered_bio: string;
   avatar_id?: string | undefined;
-  avatar_hex?: string | undefined;
+  avatar_hex?:
+    | string
+    | undefined;
+  /** Bitfield of `UserFlag` */
   public_flags: number;
   is_private: boolean;
   location: string;
+  /** @deprecated */
   created_at: string;
   follower_count: number;
   status:
@@ -147,6 +233,8 @@ export interface ExtendedUser {
   is_follower: boolean;
   is_friend: boolean;
   is_blocked_by_user: boolean;
+  /** Timestamps */
+  created_at_ts: Date | undefined;
 }
 
 export interface GetUserIdRequest {
@@ -193,8 +281,75 @@ export interface GetUserMuteCountResponse {
   mute_count: number;
 }
 
+export interface SetStatusRequest {
+  user_id: string;
+  emoji?: string | undefined;
+  text?:
+    | string
+    | undefined;
+  /** Used to compute `expires_at` */
+  duration: StatusDuration;
+  visibility: StatusVisibility;
+}
+
+export interface SetStatusResponse {
+  status: ExtendedStatus | undefined;
+}
+
+export interface ClearStatusRequest {
+  user_id: string;
+}
+
+export interface ClearStatusResponse {
+}
+
+export interface GrantUserFlagRequest {
+  admin_id: string;
+  user_id: string;
+  /** Suspension flags are managed through `SuspendUser` */
+  flag: UserFlag;
+}
+
+export interface GrantUserFlagResponse {
+  public_flags: number;
+}
+
+export interface RevokeUserFlagRequest {
+  admin_id: string;
+  user_id: string;
+  /** Suspension flags are managed through `UnsuspendUser` */
+  flag: UserFlag;
+}
+
+export interface RevokeUserFlagResponse {
+  public_flags: number;
+}
+
+export interface SuspendUserRequest {
+  admin_id: string;
+  user_id: string;
+  /** Omitted for a permanent suspension */
+  suspension_days?: number | undefined;
+  reason?: string | undefined;
+}
+
+export interface SuspendUserResponse {
+  public_flags: number;
+  /** Omitted for a permanent suspension */
+  suspended_until?: Date | undefined;
+}
+
+export interface UnsuspendUserRequest {
+  admin_id: string;
+  user_id: string;
+}
+
+export interface UnsuspendUserResponse {
+  public_flags: number;
+}
+
 function createBaseBareStatus(): BareStatus {
-  return { emoji: undefined, text: undefined, expires_at: undefined };
+  return { emoji: undefined, text: undefined, expires_at: undefined, expires_at_ts: undefined };
 }
 
 export const BareStatus = {
@@ -208,6 +363,9 @@ export const BareStatus = {
     if (message.expires_at !== undefined) {
       writer.uint32(26).string(message.expires_at);
     }
+    if (message.expires_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.expires_at_ts), writer.uint32(34).fork()).ldelim();
+    }
     return writer;
   },
 
@@ -239,6 +397,13 @@ export const BareStatus = {
 
           message.expires_at = reader.string();
           continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.expires_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -253,6 +418,7 @@ export const BareStatus = {
       emoji: isSet(object.emoji) ? globalThis.String(object.emoji) : undefined,
       text: isSet(object.text) ? globalThis.String(object.text) : undefined,
       expires_at: isSet(object.expires_at) ? globalThis.String(object.expires_at) : undefined,
+      expires_at_ts: isSet(object.expires_at_ts) ? fromJsonTimestamp(object.expires_at_ts) : undefined,
     };
   },
 
@@ -267,6 +433,9 @@ export const BareStatus = {
     if (message.expires_at !== undefined) {
       obj.expires_at = message.expires_at;
     }
+    if (message.expires_at_ts !== undefined) {
+      obj.expires_at_ts = message.expires_at_ts.toISOString();
+    }
     return obj;
   },
 
@@ -278,12 +447,20 @@ export const BareStatus = {
     message.emoji = object.emoji ?? undefined;
     message.text = object.text ?? undefined;
     message.expires_at = object.expires_at ?? undefined;
+    message.expires_at_ts = object.expires_at_ts ?? undefined;
     return message;
   },
 };
 
 function createBaseExtendedStatus(): ExtendedStatus {
-  return { emoji: undefined, text: undefined, expires_at: undefined, duration: 0, visibility: 0 };
+  return {
+    emoji: undefined,
+    text: undefined,
+    expires_at: undefined,
+    duration: 0,
+    visibility: 0,
+    expires_at_ts: undefined,
+  };
 }
 
 export const ExtendedStatus = {
@@ -303,6 +480,9 @@ export const ExtendedStatus = {
     if (message.visibility !== 0) {
       writer.uint32(40).int32(message.visibility);
     }
+    if (message.expires_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.expires_at_ts), writer.uint32(50).fork()).ldelim();
+    }
     return writer;
   },
 
@@ -348,6 +528,13 @@ export const ExtendedStatus = {
 
           message.visibility = reader.int32() as any;
           continue;
+        case 6:
+          if (tag !== 50) {
+            break;
+          }
+
+          message.expires_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -364,6 +551,7 @@ export const ExtendedStatus = {
       expires_at: isSet(object.expires_at) ? globalThis.String(object.expires_at) : undefined,
       duration: isSet(object.duration) ? statusDurationFromJSON(object.duration) : 0,
       visibility: isSet(object.visibility) ? statusVisibilityFromJSON(object.visibility) : 0,
+      expires_at_ts: isSet(object.expires_at_ts) ? fromJsonTimestamp(object.expires_at_ts) : undefined,
     };
   },
 
@@ -384,6 +572,9 @@ export const ExtendedStatus = {
     if (message.visibility !== 0) {
       obj.visibility = statusVisibilityToJSON(message.visibility);
     }
+    if (message.expires_at_ts !== undefined) {
+      obj.expires_at_ts = message.expires_at_ts.toISOString();
+    }
     return obj;
   },
 
@@ -397,6 +588,7 @@ export const ExtendedStatus = {
     message.expires_at = object.expires_at ?? undefined;
     message.duration = object.duration ?? 0;
     message.visibility = object.visibility ?? 0;
+    message.expires_at_ts = object.expires_at_ts ?? undefined;
     return message;
   },
 };
@@ -554,6 +746,7 @@ function createBaseExtendedUser(): ExtendedUser {
     is_follower: false,
     is_friend: false,
     is_blocked_by_user: false,
+    created_at_ts: undefined,
   };
 }
 
@@ -610,6 +803,9 @@ export const ExtendedUser = {
     if (message.is_blocked_by_user === true) {
       writer.uint32(136).bool(message.is_blocked_by_user);
     }
+    if (message.created_at_ts !== undefined) {
+      Timestamp.encode(toTimestamp(message.created_at_ts), writer.uint32(146).fork()).ldelim();
+    }
     return writer;
   },
 
@@ -739,6 +935,13 @@ export const ExtendedUser = {
 
           message.is_blocked_by_user = reader.bool();
           continue;
+        case 18:
+          if (tag !== 146) {
+            break;
+          }
+
+          message.created_at_ts = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -767,6 +970,7 @@ export const ExtendedUser = {
       is_follower: isSet(object.is_follower) ? globalThis.Boolean(object.is_follower) : false,
       is_friend: isSet(object.is_friend) ? globalThis.Boolean(object.is_friend) : false,
       is_blocked_by_user: isSet(object.is_blocked_by_user) ? globalThis.Boolean(object.is_blocked_by_user) : false,
+      created_at_ts: isSet(object.created_at_ts) ? fromJsonTimestamp(object.created_at_ts) : undefined,
     };
   },
 
@@ -823,6 +1027,9 @@ export const ExtendedUser = {
     if (message.is_blocked_by_user === true) {
       obj.is_blocked_by_user = message.is_blocked_by_user;
     }
+    if (message.created_at_ts !== undefined) {
+      obj.created_at_ts = message.created_at_ts.toISOString();
+    }
     return obj;
   },
 
@@ -850,6 +1057,7 @@ export const ExtendedUser = {
     message.is_follower = object.is_follower ?? false;
     message.is_friend = object.is_friend ?? false;
     message.is_blocked_by_user = object.is_blocked_by_user ?? false;
+    message.created_at_ts = object.created_at_ts ?? undefined;
     return message;
   },
 };
@@ -1473,17 +1681,918 @@ export const GetUserMuteCountResponse = {
   },
 };
 
-type Builtin = Date | Function | Uint8Array | string | number | boolean | undefined;
+function createBaseSetStatusRequest(): SetStatusRequest {
+  return { user_id: "", emoji: undefined, text: undefined, duration: 0, visibility: 0 };
+}
 
-export type DeepPartial<T> = T extends Builtin ? T
-  : T extends globalThis.Array<infer U> ? globalThis.Array<DeepPartial<U>>
-  : T extends ReadonlyArray<infer U> ? ReadonlyArray<DeepPartial<U>>
-  : T extends {} ? { [K in keyof T]?: DeepPartial<T[K]> }
-  : Partial<T>;
+export const SetStatusRequest = {
+  encode(message: SetStatusRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    if (message.emoji !== undefined) {
+      writer.uint32(18).string(message.emoji);
+    }
+    if (message.text !== undefined) {
+      writer.uint32(26).string(message.text);
+    }
+    if (message.duration !== 0) {
+      writer.uint32(32).int32(message.duration);
+    }
+    if (message.visibility !== 0) {
+      writer.uint32(40).int32(message.visibility);
+    }
+    return writer;
+  },
 
-type KeysOfUnion<T> = T extends T ? keyof T : never;
-export type Exact<P, I extends P> = P extends Builtin ? P
-  : P & { [K in keyof P]: Exact<P[K], I[K]> } & { [K in Exclude<keyof I, KeysOfUnion<P>>]: never };
+  decode(input: _m0.Reader | Uint8Array, length?: number): SetStatusRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSetStatusRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.emoji = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.text = reader.string();
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.duration = reader.int32() as any;
+          continue;
+        case 5:
+          if (tag !== 40) {
+            break;
+          }
+
+          message.visibility = reader.int32() as any;
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SetStatusRequest {
+    return {
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      emoji: isSet(object.emoji) ? globalThis.String(object.emoji) : undefined,
+      text: isSet(object.text) ? globalThis.String(object.text) : undefined,
+      duration: isSet(object.duration) ? statusDurationFromJSON(object.duration) : 0,
+      visibility: isSet(object.visibility) ? statusVisibilityFromJSON(object.visibility) : 0,
+    };
+  },
+
+  toJSON(message: SetStatusRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.emoji !== undefined) {
+      obj.emoji = message.emoji;
+    }
+    if (message.text !== undefined) {
+      obj.text = message.text;
+    }
+    if (message.duration !== 0) {
+      obj.duration = statusDurationToJSON(message.duration);
+    }
+    if (message.visibility !== 0) {
+      obj.visibility = statusVisibilityToJSON(message.visibility);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SetStatusRequest>, I>>(base?: I): SetStatusRequest {
+    return SetStatusRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SetStatusRequest>, I>>(object: I): SetStatusRequest {
+    const message = createBaseSetStatusRequest();
+    message.user_id = object.user_id ?? "";
+    message.emoji = object.emoji ?? undefined;
+    message.text = object.text ?? undefined;
+    message.duration = object.duration ?? 0;
+    message.visibility = object.visibility ?? 0;
+    return message;
+  },
+};
+
+function createBaseSetStatusResponse(): SetStatusResponse {
+  return { status: undefined };
+}
+
+export const SetStatusResponse = {
+  encode(message: SetStatusResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.status !== undefined) {
+      ExtendedStatus.encode(message.status, writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): SetStatusResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSetStatusResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.status = ExtendedStatus.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SetStatusResponse {
+    return { status: isSet(object.status) ? ExtendedStatus.fromJSON(object.status) : undefined };
+  },
+
+  toJSON(message: SetStatusResponse): unknown {
+    const obj: any = {};
+    if (message.status !== undefined) {
+      obj.status = ExtendedStatus.toJSON(message.status);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SetStatusResponse>, I>>(base?: I): SetStatusResponse {
+    return SetStatusResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SetStatusResponse>, I>>(object: I): SetStatusResponse {
+    const message = createBaseSetStatusResponse();
+    message.status = (object.status !== undefined && object.status !== null)
+      ? ExtendedStatus.fromPartial(object.status)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseClearStatusRequest(): ClearStatusRequest {
+  return { user_id: "" };
+}
+
+export const ClearStatusRequest = {
+  encode(message: ClearStatusRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.user_id !== "") {
+      writer.uint32(10).string(message.user_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ClearStatusRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseClearStatusRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ClearStatusRequest {
+    return { user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "" };
+  },
+
+  toJSON(message: ClearStatusRequest): unknown {
+    const obj: any = {};
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ClearStatusRequest>, I>>(base?: I): ClearStatusRequest {
+    return ClearStatusRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ClearStatusRequest>, I>>(object: I): ClearStatusRequest {
+    const message = createBaseClearStatusRequest();
+    message.user_id = object.user_id ?? "";
+    return message;
+  },
+};
+
+function createBaseClearStatusResponse(): ClearStatusResponse {
+  return {};
+}
+
+export const ClearStatusResponse = {
+  encode(_: ClearStatusResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): ClearStatusResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseClearStatusResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(_: any): ClearStatusResponse {
+    return {};
+  },
+
+  toJSON(_: ClearStatusResponse): unknown {
+    const obj: any = {};
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ClearStatusResponse>, I>>(base?: I): ClearStatusResponse {
+    return ClearStatusResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ClearStatusResponse>, I>>(_: I): ClearStatusResponse {
+    const message = createBaseClearStatusResponse();
+    return message;
+  },
+};
+
+function createBaseGrantUserFlagRequest(): GrantUserFlagRequest {
+  return { admin_id: "", user_id: "", flag: 0 };
+}
+
+export const GrantUserFlagRequest = {
+  encode(message: GrantUserFlagRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.admin_id !== "") {
+      writer.uint32(10).string(message.admin_id);
+    }
+    if (message.user_id !== "") {
+      writer.uint32(18).string(message.user_id);
+    }
+    if (message.flag !== 0) {
+      writer.uint32(24).int32(message.flag);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): GrantUserFlagRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseGrantUserFlagRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.admin_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.flag = reader.int32() as any;
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): GrantUserFlagRequest {
+    return {
+      admin_id: isSet(object.admin_id) ? globalThis.String(object.admin_id) : "",
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      flag: isSet(object.flag) ? userFlagFromJSON(object.flag) : 0,
+    };
+  },
+
+  toJSON(message: GrantUserFlagRequest): unknown {
+    const obj: any = {};
+    if (message.admin_id !== "") {
+      obj.admin_id = message.admin_id;
+    }
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.flag !== 0) {
+      obj.flag = userFlagToJSON(message.flag);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<GrantUserFlagRequest>, I>>(base?: I): GrantUserFlagRequest {
+    return GrantUserFlagRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<GrantUserFlagRequest>, I>>(object: I): GrantUserFlagRequest {
+    const message = createBaseGrantUserFlagRequest();
+    message.admin_id = object.admin_id ?? "";
+    message.user_id = object.user_id ?? "";
+    message.flag = object.flag ?? 0;
+    return message;
+  },
+};
+
+function createBaseGrantUserFlagResponse(): GrantUserFlagResponse {
+  return { public_flags: 0 };
+}
+
+export const GrantUserFlagResponse = {
+  encode(message: GrantUserFlagResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.public_flags !== 0) {
+      writer.uint32(8).uint32(message.public_flags);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): GrantUserFlagResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseGrantUserFlagResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.public_flags = reader.uint32();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): GrantUserFlagResponse {
+    return { public_flags: isSet(object.public_flags) ? globalThis.Number(object.public_flags) : 0 };
+  },
+
+  toJSON(message: GrantUserFlagResponse): unknown {
+    const obj: any = {};
+    if (message.public_flags !== 0) {
+      obj.public_flags = Math.round(message.public_flags);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<GrantUserFlagResponse>, I>>(base?: I): GrantUserFlagResponse {
+    return GrantUserFlagResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<GrantUserFlagResponse>, I>>(object: I): GrantUserFlagResponse {
+    const message = createBaseGrantUserFlagResponse();
+    message.public_flags = object.public_flags ?? 0;
+    return message;
+  },
+};
+
+function createBaseRevokeUserFlagRequest(): RevokeUserFlagRequest {
+  return { admin_id: "", user_id: "", flag: 0 };
+}
+
+export const RevokeUserFlagRequest = {
+  encode(message: RevokeUserFlagRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.admin_id !== "") {
+      writer.uint32(10).string(message.admin_id);
+    }
+    if (message.user_id !== "") {
+      writer.uint32(18).string(message.user_id);
+    }
+    if (message.flag !== 0) {
+      writer.uint32(24).int32(message.flag);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): RevokeUserFlagRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRevokeUserFlagRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.admin_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.flag = reader.int32() as any;
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): RevokeUserFlagRequest {
+    return {
+      admin_id: isSet(object.admin_id) ? globalThis.String(object.admin_id) : "",
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      flag: isSet(object.flag) ? userFlagFromJSON(object.flag) : 0,
+    };
+  },
+
+  toJSON(message: RevokeUserFlagRequest): unknown {
+    const obj: any = {};
+    if (message.admin_id !== "") {
+      obj.admin_id = message.admin_id;
+    }
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.flag !== 0) {
+      obj.flag = userFlagToJSON(message.flag);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RevokeUserFlagRequest>, I>>(base?: I): RevokeUserFlagRequest {
+    return RevokeUserFlagRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RevokeUserFlagRequest>, I>>(object: I): RevokeUserFlagRequest {
+    const message = createBaseRevokeUserFlagRequest();
+    message.admin_id = object.admin_id ?? "";
+    message.user_id = object.user_id ?? "";
+    message.flag = object.flag ?? 0;
+    return message;
+  },
+};
+
+function createBaseRevokeUserFlagResponse(): RevokeUserFlagResponse {
+  return { public_flags: 0 };
+}
+
+export const RevokeUserFlagResponse = {
+  encode(message: RevokeUserFlagResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.public_flags !== 0) {
+      writer.uint32(8).uint32(message.public_flags);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): RevokeUserFlagResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRevokeUserFlagResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.public_flags = reader.uint32();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): RevokeUserFlagResponse {
+    return { public_flags: isSet(object.public_flags) ? globalThis.Number(object.public_flags) : 0 };
+  },
+
+  toJSON(message: RevokeUserFlagResponse): unknown {
+    const obj: any = {};
+    if (message.public_flags !== 0) {
+      obj.public_flags = Math.round(message.public_flags);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RevokeUserFlagResponse>, I>>(base?: I): RevokeUserFlagResponse {
+    return RevokeUserFlagResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RevokeUserFlagResponse>, I>>(object: I): RevokeUserFlagResponse {
+    const message = createBaseRevokeUserFlagResponse();
+    message.public_flags = object.public_flags ?? 0;
+    return message;
+  },
+};
+
+function createBaseSuspendUserRequest(): SuspendUserRequest {
+  return { admin_id: "", user_id: "", suspension_days: undefined, reason: undefined };
+}
+
+export const SuspendUserRequest = {
+  encode(message: SuspendUserRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.admin_id !== "") {
+      writer.uint32(10).string(message.admin_id);
+    }
+    if (message.user_id !== "") {
+      writer.uint32(18).string(message.user_id);
+    }
+    if (message.suspension_days !== undefined) {
+      writer.uint32(24).uint32(message.suspension_days);
+    }
+    if (message.reason !== undefined) {
+      writer.uint32(34).string(message.reason);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): SuspendUserRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSuspendUserRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.admin_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.suspension_days = reader.uint32();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.reason = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SuspendUserRequest {
+    return {
+      admin_id: isSet(object.admin_id) ? globalThis.String(object.admin_id) : "",
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+      suspension_days: isSet(object.suspension_days) ? globalThis.Number(object.suspension_days) : undefined,
+      reason: isSet(object.reason) ? globalThis.String(object.reason) : undefined,
+    };
+  },
+
+  toJSON(message: SuspendUserRequest): unknown {
+    const obj: any = {};
+    if (message.admin_id !== "") {
+      obj.admin_id = message.admin_id;
+    }
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    if (message.suspension_days !== undefined) {
+      obj.suspension_days = Math.round(message.suspension_days);
+    }
+    if (message.reason !== undefined) {
+      obj.reason = message.reason;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SuspendUserRequest>, I>>(base?: I): SuspendUserRequest {
+    return SuspendUserRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SuspendUserRequest>, I>>(object: I): SuspendUserRequest {
+    const message = createBaseSuspendUserRequest();
+    message.admin_id = object.admin_id ?? "";
+    message.user_id = object.user_id ?? "";
+    message.suspension_days = object.suspension_days ?? undefined;
+    message.reason = object.reason ?? undefined;
+    return message;
+  },
+};
+
+function createBaseSuspendUserResponse(): SuspendUserResponse {
+  return { public_flags: 0, suspended_until: undefined };
+}
+
+export const SuspendUserResponse = {
+  encode(message: SuspendUserResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.public_flags !== 0) {
+      writer.uint32(8).uint32(message.public_flags);
+    }
+    if (message.suspended_until !== undefined) {
+      Timestamp.encode(toTimestamp(message.suspended_until), writer.uint32(18).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): SuspendUserResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSuspendUserResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.public_flags = reader.uint32();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.suspended_until = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SuspendUserResponse {
+    return {
+      public_flags: isSet(object.public_flags) ? globalThis.Number(object.public_flags) : 0,
+      suspended_until: isSet(object.suspended_until) ? fromJsonTimestamp(object.suspended_until) : undefined,
+    };
+  },
+
+  toJSON(message: SuspendUserResponse): unknown {
+    const obj: any = {};
+    if (message.public_flags !== 0) {
+      obj.public_flags = Math.round(message.public_flags);
+    }
+    if (message.suspended_until !== undefined) {
+      obj.suspended_until = message.suspended_until.toISOString();
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SuspendUserResponse>, I>>(base?: I): SuspendUserResponse {
+    return SuspendUserResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SuspendUserResponse>, I>>(object: I): SuspendUserResponse {
+    const message = createBaseSuspendUserResponse();
+    message.public_flags = object.public_flags ?? 0;
+    message.suspended_until = object.suspended_until ?? undefined;
+    return message;
+  },
+};
+
+function createBaseUnsuspendUserRequest(): UnsuspendUserRequest {
+  return { admin_id: "", user_id: "" };
+}
+
+export const UnsuspendUserRequest = {
+  encode(message: UnsuspendUserRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.admin_id !== "") {
+      writer.uint32(10).string(message.admin_id);
+    }
+    if (message.user_id !== "") {
+      writer.uint32(18).string(message.user_id);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): UnsuspendUserRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUnsuspendUserRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.admin_id = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.user_id = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UnsuspendUserRequest {
+    return {
+      admin_id: isSet(object.admin_id) ? globalThis.String(object.admin_id) : "",
+      user_id: isSet(object.user_id) ? globalThis.String(object.user_id) : "",
+    };
+  },
+
+  toJSON(message: UnsuspendUserRequest): unknown {
+    const obj: any = {};
+    if (message.admin_id !== "") {
+      obj.admin_id = message.admin_id;
+    }
+    if (message.user_id !== "") {
+      obj.user_id = message.user_id;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UnsuspendUserRequest>, I>>(base?: I): UnsuspendUserRequest {
+    return UnsuspendUserRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UnsuspendUserRequest>, I>>(object: I): UnsuspendUserRequest {
+    const message = createBaseUnsuspendUserRequest();
+    message.admin_id = object.admin_id ?? "";
+    message.user_id = object.user_id ?? "";
+    return message;
+  },
+};
+
+function createBaseUnsuspendUserResponse(): UnsuspendUserResponse {
+  return { public_flags: 0 };
+}
+
+export const UnsuspendUserResponse = {
+  encode(message: UnsuspendUserResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.public_flags !== 0) {
+      writer.uint32(8).uint32(message.public_flags);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): UnsuspendUserResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUnsuspendUserResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.public_flags = reader.uint32();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UnsuspendUserResponse {
+    return { public_flags: isSet(object.public_flags) ? globalThis.Number(object.public_flags) : 0 };
+  },
+
+  toJSON(message: UnsuspendUserResponse): unknown {
+    const obj: any = {};
+    if (message.public_flags !== 0) {
+      obj.public_flags = Math.round(message.public_flags);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UnsuspendUserResponse>, I>>(base?: I): UnsuspendUserResponse {
+    return UnsuspendUserResponse.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UnsuspendUserResponse>, I>>(object: I): UnsuspendUserResponse {
+    const message = createBaseUnsuspendUserResponse();
+    message.public_flags = object.public_flags ?? 0;
+    return message;
+  },
+};
+
+type Builtin = Date | Function | Uint8Array | string | number | boolean | undefined;
+
+export type DeepPartial<T> = T extends Builtin ? T
+  : T extends globalThis.Array<infer U> ? globalThis.Array<DeepPartial<U>>
+  : T extends ReadonlyArray<infer U> ? ReadonlyArray<DeepPartial<U>>
+  : T extends {} ? { [K in keyof T]?: DeepPartial<T[K]> }
+  : Partial<T>;
+
+type KeysOfUnion<T> = T extends T ? keyof T : never;
+export type Exact<P, I extends P> = P extends Builtin ? P
+  : P & { [K in keyof P]: Exact<P[K], I[K]> } & { [K in Exclude<keyof I, KeysOfUnion<P>>]: never };
+
+function toTimestamp(date: Date): Timestamp {
+  const seconds = Math.trunc(date.getTime() / 1_000);
+  const nanos = (date.getTime() % 1_000) * 1_000_000;
+  return { seconds, nanos };
+}
+
+function fromTimestamp(t: Timestamp): Date {
+  let millis = (t.seconds || 0) * 1_000;
+  millis += (t.nanos || 0) / 1_000_000;
+  return new globalThis.Date(millis);
+}
+
+function fromJsonTimestamp(o: any): Date {
+  if (o instanceof globalThis.Date) {
+    return o;
+  } else if (typeof o === "string") {
+    return new globalThis.Date(o);
+  } else {
+    return fromTimestamp(Timestamp.fromJSON(o));
+  }
+}
 
 function isSet(value: any): boolean {
   return value !== null && value !== undefined;
@@ -0,0 +1 @@
+import "expect-more-jest";
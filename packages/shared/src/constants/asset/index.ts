@@ -2,5 +2,12 @@ export const ASSET_PROPS = {
   alt: {
     max_length: 128,
     min_length: 0
+  },
+  image: {
+    // In bytes
+    max_size: 1024 * 1024 * 10,
+    // In pixels
+    max_dimension: 8192,
+    min_dimension: 16
   }
 } as const;
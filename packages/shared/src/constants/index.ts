@@ -4,6 +4,7 @@ export * from "./blog";
 export * from "./comment";
 export * from "./messages";
 export * from "./reply";
+export * from "./report";
 export * from "./story";
 export * from "./support-articles";
 export * from "./tag";
@@ -0,0 +1,10 @@
+export const REPORT_PROPS = {
+  description: {
+    max_length: 1024,
+    min_length: 0
+  },
+  suspension_days: {
+    max: 365,
+    min: 1
+  }
+} as const;
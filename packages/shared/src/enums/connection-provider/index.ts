@@ -1,3 +1,5 @@
+import { ConnectionProvider as ConnectionProviderProto } from "@storiny/proto/dist/connection_def/v1/def";
+
 export enum ConnectionProvider {
   DISCORD = "discord",
   DRIBBBLE = "dribbble",
@@ -10,3 +12,21 @@ export enum ConnectionProvider {
   TWITCH = "twitch",
   YOUTUBE = "youtube"
 }
+
+/**
+ * Maps the gRPC connection providers to their slugs
+ */
+export const CONNECTION_PROVIDER_SLUG_MAP: Partial<
+  Record<ConnectionProviderProto, ConnectionProvider>
+> = {
+  [ConnectionProviderProto.DISCORD /*     */]: ConnectionProvider.DISCORD,
+  [ConnectionProviderProto.DRIBBBLE /*    */]: ConnectionProvider.DRIBBBLE,
+  [ConnectionProviderProto.FIGMA /*       */]: ConnectionProvider.FIGMA,
+  [ConnectionProviderProto.GITHUB /*      */]: ConnectionProvider.GITHUB,
+  [ConnectionProviderProto.LINKEDIN /*    */]: ConnectionProvider.LINKEDIN,
+  [ConnectionProviderProto.REDDIT /*      */]: ConnectionProvider.REDDIT,
+  [ConnectionProviderProto.SNAPCHAT /*    */]: ConnectionProvider.SNAPCHAT,
+  [ConnectionProviderProto.SPOTIFY /*     */]: ConnectionProvider.SPOTIFY,
+  [ConnectionProviderProto.TWITCH /*      */]: ConnectionProvider.TWITCH,
+  [ConnectionProviderProto.YOUTUBE /*     */]: ConnectionProvider.YOUTUBE
+};
@@ -1,4 +1,9 @@
-export { DeviceType } from "@storiny/proto/dist/login_activity_def/v1/def";
+export { AssetPurpose } from "@storiny/proto/dist/asset_def/v1/def";
+export { LoginProvider } from "@storiny/proto/dist/credential_settings_def/v1/def";
+export {
+  DeviceType,
+  LoginAlertReason
+} from "@storiny/proto/dist/login_activity_def/v1/def";
 export {
   IncomingBlogRequest,
   IncomingCollaborationRequest,
@@ -0,0 +1,216 @@
+import {
+  ScheduleAccountDeletionRequest,
+  StoryDisposition
+} from "@storiny/proto/dist/account_def/v1/def";
+
+import {
+  AccountDeletionStore,
+  delete_account,
+  get_account_deletion_date,
+  get_account_deletion_retry_date,
+  sweep_account_deletions,
+  validate_schedule_account_deletion_request
+} from "./account-deletion";
+
+/**
+ * Returns a store that records the order of the steps
+ * @param steps Recorded steps
+ */
+const get_store = (steps: string[]): AccountDeletionStore => {
+  const record =
+    (step: string) =>
+    async (...args: string[]): Promise<void> => {
+      steps.push([step, ...args].join(":"));
+    };
+
+  return {
+    anonymize_stories: record("anonymize_stories"),
+    delete_blog_memberships: record("delete_blog_memberships"),
+    delete_comments: record("delete_comments"),
+    delete_newsletter_subscriptions: record("delete_newsletter_subscriptions"),
+    delete_relations: record("delete_relations"),
+    delete_stories: record("delete_stories"),
+    delete_user: record("delete_user"),
+    get_co_owned_blog_ids: async () => ["blog"],
+    revoke_logins: record("revoke_logins"),
+    transfer_stories: record("transfer_stories")
+  };
+};
+
+describe("validate_schedule_account_deletion_request", () => {
+  const request = ScheduleAccountDeletionRequest.fromPartial({
+    user_id: "user",
+    story_disposition: StoryDisposition.DELETE
+  });
+
+  it("accepts deleting and anonymizing the stories", () => {
+    expect(validate_schedule_account_deletion_request(request, [])).toBeNull();
+    expect(
+      validate_schedule_account_deletion_request(
+        { ...request, story_disposition: StoryDisposition.ANONYMIZE },
+        []
+      )
+    ).toBeNull();
+  });
+
+  it("rejects an unspecified disposition", () => {
+    expect(
+      validate_schedule_account_deletion_request(
+        { ...request, story_disposition: StoryDisposition.UNSPECIFIED },
+        []
+      )
+    ).toEqual("invalid_disposition");
+  });
+
+  it("only transfers the stories to a co-owned blog", () => {
+    const transfer = {
+      ...request,
+      story_disposition: StoryDisposition.TRANSFER_TO_BLOG,
+      blog_id: "blog"
+    };
+
+    expect(
+      validate_schedule_account_deletion_request(transfer, ["blog"])
+    ).toBeNull();
+    expect(
+      validate_schedule_account_deletion_request(transfer, ["other"])
+    ).toEqual("invalid_blog");
+    expect(
+      validate_schedule_account_deletion_request(
+        { ...transfer, blog_id: undefined },
+        ["blog"]
+      )
+    ).toEqual("invalid_blog");
+  });
+});
+
+describe("get_account_deletion_date", () => {
+  it("returns the end of the grace period", () => {
+    expect(
+      get_account_deletion_date(new Date("2024-01-01T00:00:00.000Z"))
+    ).toEqual(new Date("2024-01-31T00:00:00.000Z"));
+  });
+});
+
+describe("get_account_deletion_retry_date", () => {
+  const now = new Date("2024-01-31T00:00:00.000Z");
+
+  it("doubles the delay after each attempt", () => {
+    expect(get_account_deletion_retry_date(now, 1)).toEqual(
+      new Date("2024-01-31T01:00:00.000Z")
+    );
+    expect(get_account_deletion_retry_date(now, 3)).toEqual(
+      new Date("2024-01-31T04:00:00.000Z")
+    );
+  });
+
+  it("caps the delay", () => {
+    expect(get_account_deletion_retry_date(now, 20)).toEqual(
+      new Date("2024-02-01T00:00:00.000Z")
+    );
+  });
+});
+
+describe("delete_account", () => {
+  it("transfers the stories before cleaning up the account", async () => {
+    const steps: string[] = [];
+
+    await delete_account(
+      {
+        user_id: "user",
+        story_disposition: StoryDisposition.TRANSFER_TO_BLOG,
+        blog_id: "blog",
+        scheduled_for: new Date("2024-01-31T00:00:00.000Z")
+      },
+      get_store(steps)
+    );
+
+    expect(steps).toEqual([
+      "transfer_stories:user:blog",
+      "delete_comments:user",
+      "delete_relations:user",
+      "delete_blog_memberships:user",
+      "delete_newsletter_subscriptions:user",
+      "revoke_logins:user",
+      "delete_user:user"
+    ]);
+  });
+
+  it("anonymizes the stories if the blog is no longer co-owned", async () => {
+    const steps: string[] = [];
+
+    await delete_account(
+      {
+        user_id: "user",
+        story_disposition: StoryDisposition.TRANSFER_TO_BLOG,
+        blog_id: "other",
+        scheduled_for: new Date("2024-01-31T00:00:00.000Z")
+      },
+      get_store(steps)
+    );
+
+    expect(steps[0]).toEqual("anonymize_stories:user");
+    expect(steps).not.toContain("transfer_stories:user:other");
+  });
+
+  it("anonymizes the stories", async () => {
+    const steps: string[] = [];
+
+    await delete_account(
+      {
+        user_id: "user",
+        story_disposition: StoryDisposition.ANONYMIZE,
+        scheduled_for: new Date("2024-01-31T00:00:00.000Z")
+      },
+      get_store(steps)
+    );
+
+    expect(steps[0]).toEqual("anonymize_stories:user");
+    expect(steps).not.toContain("delete_stories:user");
+  });
+});
+
+describe("sweep_account_deletions", () => {
+  it("postpones the accounts that could not be deleted", async () => {
+    const steps: string[] = [];
+    const store = get_store(steps);
+    const record_failure = jest.fn(async () => undefined);
+    const failed = await sweep_account_deletions({
+      clock: () => new Date("2024-01-31T00:00:00.000Z"),
+      get_due: async () => [
+        {
+          user_id: "first",
+          story_disposition: StoryDisposition.DELETE,
+          scheduled_for: new Date("2024-01-30T00:00:00.000Z"),
+          attempts: 1
+        },
+        {
+          user_id: "second",
+          story_disposition: StoryDisposition.DELETE,
+          scheduled_for: new Date("2024-01-30T00:00:00.000Z")
+        }
+      ],
+      record_failure,
+      store: {
+        ...store,
+        delete_comments: async (user_id) => {
+          if (user_id === "first") {
+            throw new Error("Unavailable");
+          }
+
+          await store.delete_comments(user_id);
+        }
+      }
+    });
+
+    expect(failed).toEqual(["first"]);
+    expect(steps).toContain("delete_user:second");
+    expect(steps).not.toContain("delete_user:first");
+    expect(record_failure).toHaveBeenCalledTimes(1);
+    expect(record_failure).toHaveBeenCalledWith(
+      expect.objectContaining({ user_id: "first", attempts: 2 }),
+      new Error("Unavailable"),
+      new Date("2024-01-31T02:00:00.000Z")
+    );
+  });
+});
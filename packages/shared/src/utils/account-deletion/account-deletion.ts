@@ -0,0 +1,215 @@
+import {
+  ScheduleAccountDeletionRequest,
+  StoryDisposition
+} from "@storiny/proto/dist/account_def/v1/def";
+
+const DAY = 24 * 60 * 60 * 1000;
+const MINUTE = 60 * 1000;
+
+export const ACCOUNT_DELETION_PROPS = {
+  // In days
+  grace_period: 30,
+  // Delay before retrying a failed deletion, doubled after each attempt (in
+  // minutes)
+  retry_delay: 60,
+  // In minutes
+  max_retry_delay: 24 * 60
+} as const;
+
+export type AccountDeletionError = "invalid_blog" | "invalid_disposition";
+
+/**
+ * Storage used by the deletion job. Each step is expected to be idempotent
+ * so that an interrupted deletion can be retried.
+ */
+export interface AccountDeletionStore {
+  /**
+   * Replaces the writer of the stories with the placeholder user
+   */
+  anonymize_stories: (user_id: string) => Promise<void>;
+  delete_blog_memberships: (user_id: string) => Promise<void>;
+  delete_comments: (user_id: string) => Promise<void>;
+  delete_newsletter_subscriptions: (user_id: string) => Promise<void>;
+  /**
+   * Deletes the follows, friendships, blocks, and mutes in both directions
+   */
+  delete_relations: (user_id: string) => Promise<void>;
+  delete_stories: (user_id: string) => Promise<void>;
+  delete_user: (user_id: string) => Promise<void>;
+  /**
+   * Returns the IDs of the blogs currently co-owned by the user
+   */
+  get_co_owned_blog_ids: (user_id: string) => Promise<string[]>;
+  /**
+   * Deactivates every `Login` of the user
+   */
+  revoke_logins: (user_id: string) => Promise<void>;
+  transfer_stories: (user_id: string, blog_id: string) => Promise<void>;
+}
+
+export interface ScheduledAccountDeletion {
+  /**
+   * Number of failed attempts to delete the account
+   */
+  attempts?: number;
+  blog_id?: string;
+  scheduled_for: Date;
+  story_disposition: StoryDisposition;
+  user_id: string;
+}
+
+/**
+ * Validates a `ScheduleAccountDeletion` request, returning the error if the
+ * request is invalid, or `null` otherwise
+ * @param request Request
+ * @param co_owned_blog_ids IDs of the blogs co-owned by the user, the stories
+ * can only be transferred to one of these
+ */
+export const validate_schedule_account_deletion_request = (
+  request: ScheduleAccountDeletionRequest,
+  co_owned_blog_ids: string[]
+): AccountDeletionError | null => {
+  switch (request.story_disposition) {
+    case StoryDisposition.DELETE:
+    case StoryDisposition.ANONYMIZE:
+      return request.blog_id === undefined ? null : "invalid_blog";
+    case StoryDisposition.TRANSFER_TO_BLOG:
+      return request.blog_id !== undefined &&
+        co_owned_blog_ids.includes(request.blog_id)
+        ? null
+        : "invalid_blog";
+    default:
+      return "invalid_disposition";
+  }
+};
+
+/**
+ * Returns the date at which the account is deleted, at the end of the grace
+ * period
+ * @param now Current date
+ */
+export const get_account_deletion_date = (now: Date): Date =>
+  new Date(now.getTime() + ACCOUNT_DELETION_PROPS.grace_period * DAY);
+
+/**
+ * Returns the date at which a failed deletion is retried
+ * @param now Current date
+ * @param attempts Number of failed attempts, including the current one
+ */
+export const get_account_deletion_retry_date = (
+  now: Date,
+  attempts: number
+): Date =>
+  new Date(
+    now.getTime() +
+      Math.min(
+        ACCOUNT_DELETION_PROPS.retry_delay * 2 ** Math.max(attempts - 1, 0),
+        ACCOUNT_DELETION_PROPS.max_retry_delay
+      ) *
+        MINUTE
+  );
+
+/**
+ * Deletes an account whose grace period has ended. The stories are handled
+ * first so that they are never left without a writer. The user may have left
+ * the blog chosen for the transfer during the grace period, in which case the
+ * stories are anonymized instead.
+ * @param deletion Scheduled deletion
+ * @param store Storage
+ */
+export const delete_account = async (
+  deletion: ScheduledAccountDeletion,
+  store: AccountDeletionStore
+): Promise<void> => {
+  const { user_id } = deletion;
+
+  switch (deletion.story_disposition) {
+    case StoryDisposition.ANONYMIZE:
+      await store.anonymize_stories(user_id);
+      break;
+    case StoryDisposition.TRANSFER_TO_BLOG: {
+      const co_owned_blog_ids = await store.get_co_owned_blog_ids(user_id);
+
+      if (deletion.blog_id && co_owned_blog_ids.includes(deletion.blog_id)) {
+        await store.transfer_stories(user_id, deletion.blog_id);
+      } else {
+        await store.anonymize_stories(user_id);
+      }
+
+      break;
+    }
+    default:
+      await store.delete_stories(user_id);
+  }
+
+  await store.delete_comments(user_id);
+  await store.delete_relations(user_id);
+  await store.delete_blog_memberships(user_id);
+  await store.delete_newsletter_subscriptions(user_id);
+  await store.revoke_logins(user_id);
+  await store.delete_user(user_id);
+};
+
+export interface AccountDeletionSweeperOptions {
+  /**
+   * Maximum number of accounts deleted in a single batch
+   */
+  batch_size?: number;
+  /**
+   * Returns the current date
+   */
+  clock: () => Date;
+  /**
+   * Returns the deletions scheduled at or before the date
+   */
+  get_due: (
+    before: Date,
+    limit: number
+  ) => Promise<ScheduledAccountDeletion[]>;
+  /**
+   * Records a failed deletion and postpones it until `retry_at`, so that the
+   * deletions that keep failing do not hold back the others
+   */
+  record_failure: (
+    deletion: ScheduledAccountDeletion,
+    error: unknown,
+    retry_at: Date
+  ) => Promise<void>;
+  store: AccountDeletionStore;
+}
+
+/**
+ * Deletes every account whose grace period has ended, returning the IDs of
+ * the accounts that could not be deleted. Failed deletions are retried with
+ * an exponential backoff. Meant to be run periodically in the background.
+ * @param options Sweeper options
+ */
+export const sweep_account_deletions = async (
+  options: AccountDeletionSweeperOptions
+): Promise<string[]> => {
+  const batch_size = options.batch_size ?? 100;
+  const deletions = await options.get_due(options.clock(), batch_size);
+  const failed: string[] = [];
+
+  for (const deletion of deletions) {
+    try {
+      await delete_account(deletion, options.store);
+    } catch (error) {
+      const attempts = (deletion.attempts ?? 0) + 1;
+
+      failed.push(deletion.user_id);
+
+      try {
+        await options.record_failure(
+          { ...deletion, attempts },
+          error,
+          get_account_deletion_retry_date(options.clock(), attempts)
+        );
+      } catch {
+        // Retried as is by the next run
+      }
+    }
+  }
+
+  return failed;
+};
@@ -0,0 +1,104 @@
+import { AssetRating } from "@storiny/proto/dist/asset_def/v1/def";
+import {
+  GetStoryResponse,
+  StoryAgeRestriction
+} from "@storiny/proto/dist/story_def/v1/def";
+
+import {
+  apply_story_media_policy,
+  get_story_media_policy,
+  is_sensitive_rating
+} from "./apply-story-media-policy";
+
+const STORY = GetStoryResponse.fromPartial({
+  id: "1",
+  user_id: "writer",
+  doc_key: "doc",
+  splash_id: "splash",
+  splash_rating: AssetRating.SENSITIVE,
+  sensitive_asset_ids: ["splash", "inline"],
+  age_restriction: StoryAgeRestriction.RATED,
+  contributors: [{ id: "contributor" }],
+  reading_session_token: "token"
+});
+
+const NOT_RATED_STORY: GetStoryResponse = {
+  ...STORY,
+  age_restriction: StoryAgeRestriction.NOT_RATED
+};
+
+describe("is_sensitive_rating", () => {
+  it("treats every rating except `NOT_RATED` as sensitive", () => {
+    expect(is_sensitive_rating(AssetRating.NOT_RATED)).toBeFalse();
+    expect(is_sensitive_rating(AssetRating.UNSPECIFIED)).toBeFalse();
+    expect(is_sensitive_rating(AssetRating.VIOLENCE)).toBeTrue();
+    expect(is_sensitive_rating(AssetRating.SUGGESTIVE_NUDITY)).toBeTrue();
+    expect(is_sensitive_rating(AssetRating.SENSITIVE)).toBeTrue();
+  });
+});
+
+describe("get_story_media_policy", () => {
+  it("gates rated stories for anonymous viewers", () => {
+    expect(get_story_media_policy(STORY, null)).toEqual({
+      is_gated: true,
+      blur_sensitive_media: true
+    });
+  });
+
+  it("gates rated stories for viewers that have not opted in", () => {
+    expect(
+      get_story_media_policy(STORY, {
+        user_id: "viewer",
+        allow_sensitive_media: false
+      })
+    ).toEqual({ is_gated: true, blur_sensitive_media: true });
+  });
+
+  it("shows rated stories to viewers that have opted in", () => {
+    expect(
+      get_story_media_policy(STORY, {
+        user_id: "viewer",
+        allow_sensitive_media: true
+      })
+    ).toEqual({ is_gated: false, blur_sensitive_media: false });
+  });
+
+  it("blurs the sensitive media in stories that are not rated", () => {
+    expect(get_story_media_policy(NOT_RATED_STORY, null)).toEqual({
+      is_gated: false,
+      blur_sensitive_media: true
+    });
+  });
+
+  it("never gates the story for the writer and the contributors", () => {
+    ["writer", "contributor"].forEach((user_id) => {
+      expect(
+        get_story_media_policy(STORY, {
+          user_id,
+          allow_sensitive_media: false
+        })
+      ).toEqual({ is_gated: false, blur_sensitive_media: false });
+    });
+  });
+});
+
+describe("apply_story_media_policy", () => {
+  it("omits the content of gated stories", () => {
+    const story = apply_story_media_policy(STORY, null);
+
+    expect(story.is_gated).toBeTrue();
+    expect(story.doc_key).toEqual("");
+    expect(story.splash_id).toBeUndefined();
+    expect(story.sensitive_asset_ids).toEqual([]);
+    expect(story.reading_session_token).toEqual("");
+  });
+
+  it("keeps the content of stories that are not gated", () => {
+    const story = apply_story_media_policy(NOT_RATED_STORY, null);
+
+    expect(story.is_gated).toBeFalse();
+    expect(story.blur_sensitive_media).toBeTrue();
+    expect(story.doc_key).toEqual("doc");
+    expect(story.sensitive_asset_ids).toEqual(["splash", "inline"]);
+  });
+});
@@ -0,0 +1,94 @@
+import { AssetRating } from "@storiny/proto/dist/asset_def/v1/def";
+import {
+  GetStoryResponse,
+  StoryAgeRestriction
+} from "@storiny/proto/dist/story_def/v1/def";
+
+/**
+ * Viewer of a story, or `null` for anonymous viewers
+ */
+export type StoryViewer = {
+  allow_sensitive_media: boolean;
+  user_id: string;
+} | null;
+
+export interface StoryMediaPolicy {
+  /**
+   * Whether the sensitive images should be blurred
+   */
+  blur_sensitive_media: boolean;
+  /**
+   * Whether the story should be hidden from the viewer
+   */
+  is_gated: boolean;
+}
+
+/**
+ * Predicate function for determining whether an image with the rating is
+ * sensitive
+ * @param rating Rating of the image
+ */
+export const is_sensitive_rating = (rating: AssetRating): boolean =>
+  rating === AssetRating.SUGGESTIVE_NUDITY ||
+  rating === AssetRating.VIOLENCE ||
+  rating === AssetRating.SENSITIVE;
+
+/**
+ * Returns the media policy for a viewer. Rated stories are gated for
+ * anonymous viewers and the viewers that have not allowed sensitive media,
+ * while the writer and the contributors can always view the story.
+ * @param story Story
+ * @param viewer Viewer
+ */
+export const get_story_media_policy = (
+  story: Pick<GetStoryResponse, "age_restriction" | "contributors" | "user_id">,
+  viewer: StoryViewer
+): StoryMediaPolicy => {
+  if (
+    viewer &&
+    (viewer.user_id === story.user_id ||
+      story.contributors.some((user) => user.id === viewer.user_id))
+  ) {
+    return { is_gated: false, blur_sensitive_media: false };
+  }
+
+  const allow_sensitive_media = Boolean(viewer?.allow_sensitive_media);
+
+  return {
+    is_gated:
+      story.age_restriction === StoryAgeRestriction.RATED &&
+      !allow_sensitive_media,
+    blur_sensitive_media: !allow_sensitive_media
+  };
+};
+
+/**
+ * Applies the media policy to a `GetStory` response. The content fields of
+ * gated stories are omitted so that they never reach the viewer.
+ * @param story Story response
+ * @param viewer Viewer
+ */
+export const apply_story_media_policy = (
+  story: GetStoryResponse,
+  viewer: StoryViewer
+): GetStoryResponse => {
+  const policy = get_story_media_policy(story, viewer);
+
+  if (!policy.is_gated) {
+    return { ...story, ...policy };
+  }
+
+  return {
+    ...story,
+    ...policy,
+    description: undefined,
+    splash_id: undefined,
+    splash_hex: undefined,
+    splash_rating: AssetRating.UNSPECIFIED,
+    preview_image: undefined,
+    seo_description: undefined,
+    doc_key: "",
+    reading_session_token: "",
+    sensitive_asset_ids: []
+  };
+};
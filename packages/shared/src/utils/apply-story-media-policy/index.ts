@@ -0,0 +1 @@
+export * from "./apply-story-media-policy";
@@ -0,0 +1,288 @@
+import {
+  AccountExport,
+  AccountExportStatus
+} from "@storiny/proto/dist/account_def/v1/def";
+import { GetCommentResponse } from "@storiny/proto/dist/comment_def/v1/def";
+import { ConnectionSetting } from "@storiny/proto/dist/connection_def/v1/def";
+import {
+  GetCredentialSettingsResponse,
+  LinkedLoginProvider,
+  Passkey
+} from "@storiny/proto/dist/credential_settings_def/v1/def";
+import {
+  GetLoginActivityResponse,
+  Login,
+  LoginAlert
+} from "@storiny/proto/dist/login_activity_def/v1/def";
+import { GetNotificationSettingsResponse } from "@storiny/proto/dist/notification_settings_def/v1/def";
+import { GetPrivacySettingsResponse } from "@storiny/proto/dist/privacy_settings_def/v1/def";
+import { GetProfileResponse } from "@storiny/proto/dist/profile_def/v1/def";
+import { Draft, GetStoryResponse } from "@storiny/proto/dist/story_def/v1/def";
+import { BareUser } from "@storiny/proto/dist/user_def/v1/def";
+
+import {
+  AccountExportSource,
+  build_account_export,
+  can_request_account_export,
+  COMMENT_EXPORT_FIELDS,
+  CONNECTION_EXPORT_FIELDS,
+  CREDENTIAL_SETTINGS_EXPORT_FIELDS,
+  DRAFT_EXPORT_FIELDS,
+  get_account_export_status,
+  LOGIN_ACTIVITY_EXPORT_FIELDS,
+  LOGIN_ALERT_EXPORT_FIELDS,
+  LOGIN_EXPORT_FIELDS,
+  LOGIN_PROVIDER_EXPORT_FIELDS,
+  NOTIFICATION_SETTINGS_EXPORT_FIELDS,
+  PASSKEY_EXPORT_FIELDS,
+  PRIVACY_SETTINGS_EXPORT_FIELDS,
+  PROFILE_EXPORT_FIELDS,
+  RELATION_EXPORT_FIELDS,
+  run_account_export,
+  STORY_EXPORT_FIELDS
+} from "./build-account-export";
+
+const NOW = new Date("2024-01-01T00:00:00.000Z");
+
+const SOURCE: AccountExportSource = {
+  get_profile: async () =>
+    GetProfileResponse.fromPartial({
+      id: "user",
+      username: "writer",
+      is_self: true
+    }),
+  get_stories: async () => [
+    GetStoryResponse.fromPartial({
+      id: "story",
+      title: "Story",
+      doc_key: "doc",
+      reading_session_token: "token",
+      tags: [{ id: "tag", name: "travel" }],
+      first_published_at_ts: new Date("2023-06-01T00:00:00.000Z")
+    })
+  ],
+  get_drafts: async () => [Draft.fromPartial({ id: "draft", title: "Draft" })],
+  get_comments: async () => [
+    GetCommentResponse.fromPartial({ id: "comment", content: "Nice" })
+  ],
+  get_connections: async () => [],
+  get_credential_settings: async () =>
+    GetCredentialSettingsResponse.fromPartial({
+      passkeys: [{ id: "passkey", name: "Laptop" }]
+    }),
+  get_notification_settings: async () =>
+    GetNotificationSettingsResponse.fromPartial({ mail_digest: true }),
+  get_privacy_settings: async () =>
+    GetPrivacySettingsResponse.fromPartial({ is_private_account: true }),
+  get_login_activity: async () =>
+    GetLoginActivityResponse.fromPartial({ logins: [{ id: "login" }] }),
+  get_relations: async () => ({
+    followers: [BareUser.fromPartial({ id: "follower" })],
+    following: [],
+    friends: [],
+    blocked: [],
+    muted: []
+  }),
+  get_markdown: async (id) => `Content of ${id}`
+};
+
+describe("export manifests", () => {
+  [
+    ["GetProfileResponse", GetProfileResponse, PROFILE_EXPORT_FIELDS],
+    ["GetStoryResponse", GetStoryResponse, STORY_EXPORT_FIELDS],
+    ["Draft", Draft, DRAFT_EXPORT_FIELDS],
+    ["GetCommentResponse", GetCommentResponse, COMMENT_EXPORT_FIELDS],
+    ["ConnectionSetting", ConnectionSetting, CONNECTION_EXPORT_FIELDS],
+    [
+      "GetCredentialSettingsResponse",
+      GetCredentialSettingsResponse,
+      CREDENTIAL_SETTINGS_EXPORT_FIELDS
+    ],
+    ["Passkey", Passkey, PASSKEY_EXPORT_FIELDS],
+    ["LinkedLoginProvider", LinkedLoginProvider, LOGIN_PROVIDER_EXPORT_FIELDS],
+    [
+      "GetNotificationSettingsResponse",
+      GetNotificationSettingsResponse,
+      NOTIFICATION_SETTINGS_EXPORT_FIELDS
+    ],
+    [
+      "GetPrivacySettingsResponse",
+      GetPrivacySettingsResponse,
+      PRIVACY_SETTINGS_EXPORT_FIELDS
+    ],
+    [
+      "GetLoginActivityResponse",
+      GetLoginActivityResponse,
+      LOGIN_ACTIVITY_EXPORT_FIELDS
+    ],
+    ["Login", Login, LOGIN_EXPORT_FIELDS],
+    ["LoginAlert", LoginAlert, LOGIN_ALERT_EXPORT_FIELDS],
+    ["BareUser", BareUser, RELATION_EXPORT_FIELDS]
+  ].forEach(([name, message, manifest]) => {
+    it(`accounts for every field of \`${name}\``, () => {
+      const fields = Object.keys(
+        (message as { fromPartial: (object: object) => object }).fromPartial(
+          {}
+        )
+      );
+
+      expect(Object.keys(manifest).sort()).toEqual(fields.sort());
+    });
+  });
+});
+
+describe("build_account_export", () => {
+  it("writes every section of the archive", async () => {
+    const files = await build_account_export("user", SOURCE);
+
+    expect(Object.keys(files).sort()).toEqual([
+      "comments.json",
+      "connections.json",
+      "drafts/draft.json",
+      "drafts/draft.md",
+      "login_activity.json",
+      "profile.json",
+      "relations.json",
+      "settings/credentials.json",
+      "settings/notifications.json",
+      "settings/privacy.json",
+      "stories/story.json",
+      "stories/story.md"
+    ]);
+  });
+
+  it("omits the fields specific to the viewer", async () => {
+    const files = await build_account_export("user", SOURCE);
+    const profile = JSON.parse(files["profile.json"]);
+    const story = JSON.parse(files["stories/story.json"]);
+
+    expect(profile.username).toEqual("writer");
+    expect(profile).not.toHaveProperty("is_self");
+    expect(story).not.toHaveProperty("doc_key");
+    expect(story).not.toHaveProperty("reading_session_token");
+    expect(JSON.parse(files["settings/credentials.json"]).passkeys).toEqual([
+      { id: "passkey", name: "Laptop" }
+    ]);
+  });
+
+  it("renders the stories as Markdown with front matter", async () => {
+    const files = await build_account_export("user", SOURCE);
+
+    expect(files["stories/story.md"]).toEqual(
+      [
+        "---",
+        'title: "Story"',
+        'tags: ["travel"]',
+        'published_at: "2023-06-01T00:00:00.000Z"',
+        "---",
+        "",
+        "Content of story",
+        ""
+      ].join("\n")
+    );
+  });
+});
+
+describe("can_request_account_export", () => {
+  const EXPORT = AccountExport.fromPartial({
+    id: "export",
+    status: AccountExportStatus.READY,
+    created_at: new Date("2023-12-31T12:00:00.000Z")
+  });
+
+  it("allows the first export", () => {
+    expect(can_request_account_export(undefined, NOW)).toBeTrue();
+  });
+
+  it("rejects exports while one is in progress", () => {
+    expect(
+      can_request_account_export(
+        { ...EXPORT, status: AccountExportStatus.PROCESSING },
+        new Date("2024-02-01")
+      )
+    ).toBeFalse();
+  });
+
+  it("enforces the cooldown", () => {
+    expect(can_request_account_export(EXPORT, NOW)).toBeFalse();
+    expect(
+      can_request_account_export(EXPORT, new Date("2024-01-01T12:00:00.000Z"))
+    ).toBeTrue();
+  });
+});
+
+describe("get_account_export_status", () => {
+  it("expires the download token", () => {
+    const account_export = AccountExport.fromPartial({
+      id: "export",
+      status: AccountExportStatus.READY,
+      download_token: "token",
+      expires_at: NOW
+    });
+
+    expect(get_account_export_status(account_export, NOW)).toEqual({
+      ...account_export,
+      status: AccountExportStatus.EXPIRED,
+      download_token: undefined
+    });
+    expect(
+      get_account_export_status(account_export, new Date("2023-12-31"))
+    ).toEqual(account_export);
+  });
+});
+
+describe("run_account_export", () => {
+  const EXPORT = AccountExport.fromPartial({
+    id: "export",
+    status: AccountExportStatus.QUEUED,
+    created_at: NOW
+  });
+
+  it("stores the archive and issues a download token", async () => {
+    const update = jest.fn(async () => undefined);
+    const write_archive = jest.fn(async () => 2048);
+    const result = await run_account_export({
+      account_export: EXPORT,
+      user_id: "user",
+      source: SOURCE,
+      clock: () => NOW,
+      generate_token: () => "token",
+      update,
+      write_archive
+    });
+
+    expect(write_archive).toHaveBeenCalledWith(
+      "export",
+      expect.objectContaining({ "profile.json": expect.any(String) })
+    );
+    expect(update).toHaveBeenCalledTimes(2);
+    expect(result).toEqual({
+      ...EXPORT,
+      status: AccountExportStatus.READY,
+      completed_at: NOW,
+      expires_at: new Date("2024-01-08T00:00:00.000Z"),
+      download_token: "token",
+      size: 2048
+    });
+  });
+
+  it("marks the export as failed", async () => {
+    const result = await run_account_export({
+      account_export: EXPORT,
+      user_id: "user",
+      source: {
+        ...SOURCE,
+        get_comments: async () => {
+          throw new Error("Unavailable");
+        }
+      },
+      clock: () => NOW,
+      generate_token: () => "token",
+      update: async () => undefined,
+      write_archive: async () => 0
+    });
+
+    expect(result.status).toEqual(AccountExportStatus.FAILED);
+    expect(result.download_token).toBeUndefined();
+  });
+});
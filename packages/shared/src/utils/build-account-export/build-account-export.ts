@@ -0,0 +1,577 @@
+import {
+  AccountExport,
+  AccountExportStatus
+} from "@storiny/proto/dist/account_def/v1/def";
+import { GetCommentResponse } from "@storiny/proto/dist/comment_def/v1/def";
+import { ConnectionSetting } from "@storiny/proto/dist/connection_def/v1/def";
+import {
+  GetCredentialSettingsResponse,
+  LinkedLoginProvider,
+  Passkey
+} from "@storiny/proto/dist/credential_settings_def/v1/def";
+import {
+  GetLoginActivityResponse,
+  Login,
+  LoginAlert
+} from "@storiny/proto/dist/login_activity_def/v1/def";
+import { GetNotificationSettingsResponse } from "@storiny/proto/dist/notification_settings_def/v1/def";
+import { GetPrivacySettingsResponse } from "@storiny/proto/dist/privacy_settings_def/v1/def";
+import { GetProfileResponse } from "@storiny/proto/dist/profile_def/v1/def";
+import { Draft, GetStoryResponse } from "@storiny/proto/dist/story_def/v1/def";
+import { BareUser } from "@storiny/proto/dist/user_def/v1/def";
+
+import { get_timestamp } from "../timestamp-fields";
+
+const DAY = 24 * 60 * 60 * 1000;
+
+export const ACCOUNT_EXPORT_PROPS = {
+  // In days
+  request_cooldown: 1,
+  token_lifetime: 7
+} as const;
+
+/**
+ * Whether each field of a message is written to the export. Omitted fields
+ * are specific to the viewer or internal to the service. Keyed by every field
+ * so that new fields on the generated messages have to be accounted for.
+ */
+export type ExportFieldManifest<T> = Record<keyof T, boolean>;
+
+export const PROFILE_EXPORT_FIELDS: ExportFieldManifest<GetProfileResponse> =
+  {
+    id: true,
+    name: true,
+    username: true,
+    status: true,
+    bio: true,
+    rendered_bio: true,
+    avatar_id: true,
+    avatar_hex: true,
+    banner_id: true,
+    banner_hex: true,
+    location: true,
+    created_at: true,
+    public_flags: true,
+    story_count: true,
+    follower_count: true,
+    following_count: true,
+    friend_count: true,
+    is_private: true,
+    connections: true,
+    is_plus_member: true,
+    // Relations with the viewer
+    is_following: false,
+    is_follower: false,
+    is_friend: false,
+    is_subscribed: false,
+    is_friend_request_sent: false,
+    is_blocked_by_user: false,
+    is_blocked: false,
+    is_muted: false,
+    is_self: false,
+    // Duplicates of the deprecated string timestamps
+    created_at_ts: false
+  };
+
+export const STORY_EXPORT_FIELDS: ExportFieldManifest<GetStoryResponse> = {
+  id: true,
+  title: true,
+  slug: true,
+  description: true,
+  splash_id: true,
+  splash_hex: true,
+  category: true,
+  user_id: true,
+  like_count: true,
+  read_count: true,
+  word_count: true,
+  comment_count: true,
+  age_restriction: true,
+  license: true,
+  visibility: true,
+  disable_comments: true,
+  disable_public_revision_history: true,
+  disable_toc: true,
+  canonical_url: true,
+  seo_description: true,
+  seo_title: true,
+  preview_image: true,
+  created_at: true,
+  edited_at: true,
+  published_at: true,
+  first_published_at: true,
+  deleted_at: true,
+  contributors: true,
+  tags: true,
+  blog: true,
+  is_bookmarked: true,
+  is_liked: true,
+  splash_rating: true,
+  sensitive_asset_ids: true,
+  // The content is exported as Markdown instead
+  doc_key: false,
+  // Exported in the profile
+  user: false,
+  // Specific to the reading session of the viewer
+  reading_session_token: false,
+  blur_sensitive_media: false,
+  is_gated: false,
+  // Duplicates of the deprecated string timestamps
+  created_at_ts: false,
+  edited_at_ts: false,
+  published_at_ts: false,
+  first_published_at_ts: false,
+  deleted_at_ts: false
+};
+
+export const DRAFT_EXPORT_FIELDS: ExportFieldManifest<Draft> = {
+  id: true,
+  title: true,
+  splash_id: true,
+  splash_hex: true,
+  word_count: true,
+  created_at: true,
+  edited_at: true,
+  // Duplicates of the deprecated string timestamps
+  created_at_ts: false,
+  edited_at_ts: false
+};
+
+export const COMMENT_EXPORT_FIELDS: ExportFieldManifest<GetCommentResponse> = {
+  id: true,
+  content: true,
+  rendered_content: true,
+  user_id: true,
+  story_id: true,
+  story_slug: true,
+  story_writer_username: true,
+  hidden: true,
+  edited_at: true,
+  created_at: true,
+  like_count: true,
+  reply_count: true,
+  is_liked: true,
+  deleted_at: true,
+  // Exported in the profile
+  user: false,
+  // Duplicates of the deprecated string timestamps
+  edited_at_ts: false,
+  created_at_ts: false,
+  deleted_at_ts: false
+};
+
+export const CONNECTION_EXPORT_FIELDS: ExportFieldManifest<ConnectionSetting> =
+  {
+    id: true,
+    provider: true,
+    hidden: true,
+    display_name: true,
+    url: true,
+    created_at: true,
+    // Duplicate of the deprecated provider slug
+    provider_type: false,
+    // Duplicates of the deprecated string timestamps
+    created_at_ts: false
+  };
+
+export const CREDENTIAL_SETTINGS_EXPORT_FIELDS: ExportFieldManifest<GetCredentialSettingsResponse> =
+  {
+    has_password: true,
+    mfa_enabled: true,
+    login_apple_id: true,
+    login_google_id: true,
+    passkeys: true,
+    login_providers: true
+  };
+
+export const PASSKEY_EXPORT_FIELDS: ExportFieldManifest<Passkey> = {
+  id: true,
+  name: true,
+  last_used_at: true,
+  created_at: true
+};
+
+export const LOGIN_PROVIDER_EXPORT_FIELDS: ExportFieldManifest<LinkedLoginProvider> =
+  {
+    provider: true,
+    provider_user_id: true,
+    email: true,
+    created_at: true
+  };
+
+export const NOTIFICATION_SETTINGS_EXPORT_FIELDS: ExportFieldManifest<GetNotificationSettingsResponse> =
+  {
+    features_and_updates: true,
+    stories: true,
+    story_likes: true,
+    tags: true,
+    comments: true,
+    replies: true,
+    new_followers: true,
+    friend_requests: true,
+    collaboration_requests: true,
+    blog_requests: true,
+    mentions: true,
+    mail_login_activity: true,
+    mail_features_and_updates: true,
+    mail_newsletters: true,
+    mail_digest: true
+  };
+
+export const PRIVACY_SETTINGS_EXPORT_FIELDS: ExportFieldManifest<GetPrivacySettingsResponse> =
+  {
+    is_private_account: true,
+    record_read_history: true,
+    allow_sensitive_media: true,
+    incoming_friend_requests: true,
+    incoming_collaboration_requests: true,
+    incoming_blog_requests: true,
+    following_list_visibility: true,
+    friend_list_visibility: true
+  };
+
+export const LOGIN_ACTIVITY_EXPORT_FIELDS: ExportFieldManifest<GetLoginActivityResponse> =
+  {
+    logins: true,
+    alerts: true,
+    // Duplicate of the latest entry in `logins`
+    recent: false
+  };
+
+export const LOGIN_EXPORT_FIELDS: ExportFieldManifest<Login> = {
+  id: true,
+  device: true,
+  location: true,
+  domain: true,
+  is_active: true,
+  created_at: true,
+  // Duplicates of the deprecated string timestamps
+  created_at_ts: false
+};
+
+export const LOGIN_ALERT_EXPORT_FIELDS: ExportFieldManifest<LoginAlert> = {
+  id: true,
+  login_id: true,
+  reasons: true,
+  mail_sent: true,
+  created_at: true
+};
+
+export const RELATION_EXPORT_FIELDS: ExportFieldManifest<BareUser> = {
+  id: true,
+  name: true,
+  username: true,
+  avatar_id: true,
+  avatar_hex: true,
+  public_flags: true
+};
+
+export interface AccountRelations {
+  blocked: BareUser[];
+  followers: BareUser[];
+  following: BareUser[];
+  friends: BareUser[];
+  muted: BareUser[];
+}
+
+/**
+ * Data source for the export, backed by the same queries as the RPCs
+ */
+export interface AccountExportSource {
+  get_comments: (user_id: string) => Promise<GetCommentResponse[]>;
+  get_connections: (user_id: string) => Promise<ConnectionSetting[]>;
+  get_credential_settings: (
+    user_id: string
+  ) => Promise<GetCredentialSettingsResponse>;
+  get_drafts: (user_id: string) => Promise<Draft[]>;
+  get_login_activity: (user_id: string) => Promise<GetLoginActivityResponse>;
+  /**
+   * Returns the content of a story or a draft as Markdown
+   */
+  get_markdown: (story_id: string) => Promise<string>;
+  get_notification_settings: (
+    user_id: string
+  ) => Promise<GetNotificationSettingsResponse>;
+  get_privacy_settings: (
+    user_id: string
+  ) => Promise<GetPrivacySettingsResponse>;
+  get_profile: (user_id: string) => Promise<GetProfileResponse>;
+  get_relations: (user_id: string) => Promise<AccountRelations>;
+  get_stories: (user_id: string) => Promise<GetStoryResponse[]>;
+}
+
+/**
+ * Picks the fields of a message that are written to the export
+ * @param message Message
+ * @param manifest Export manifest of the message
+ */
+export const pick_export_fields = <T extends object>(
+  message: T,
+  manifest: ExportFieldManifest<T>
+): Partial<T> =>
+  Object.fromEntries(
+    Object.entries(message).filter(
+      ([key]) => manifest[key as keyof T] === true
+    )
+  ) as Partial<T>;
+
+/**
+ * Serializes a value to JSON for the archive
+ * @param value Value
+ */
+const to_json = (value: unknown): string => JSON.stringify(value, null, 2);
+
+/**
+ * Renders the Markdown file for a story or a draft, with the metadata in the
+ * front matter
+ * @param front_matter Metadata
+ * @param content Markdown content
+ */
+export const render_markdown_file = (
+  front_matter: Record<string, string | string[] | undefined>,
+  content: string
+): string => {
+  const lines = Object.entries(front_matter)
+    .filter(([, value]) => value !== undefined)
+    .map(([key, value]) => `${key}: ${JSON.stringify(value)}`);
+
+  return `---\n${lines.join("\n")}\n---\n\n${content.trim()}\n`;
+};
+
+/**
+ * Builds the files of an account export, keyed by their path inside the
+ * archive
+ * @param user_id ID of the user
+ * @param source Data source
+ */
+export const build_account_export = async (
+  user_id: string,
+  source: AccountExportSource
+): Promise<Record<string, string>> => {
+  const [
+    profile,
+    stories,
+    drafts,
+    comments,
+    connections,
+    credential_settings,
+    notification_settings,
+    privacy_settings,
+    login_activity,
+    relations
+  ] = await Promise.all([
+    source.get_profile(user_id),
+    source.get_stories(user_id),
+    source.get_drafts(user_id),
+    source.get_comments(user_id),
+    source.get_connections(user_id),
+    source.get_credential_settings(user_id),
+    source.get_notification_settings(user_id),
+    source.get_privacy_settings(user_id),
+    source.get_login_activity(user_id),
+    source.get_relations(user_id)
+  ]);
+
+  const files: Record<string, string> = {
+    "profile.json": to_json(pick_export_fields(profile, PROFILE_EXPORT_FIELDS)),
+    "comments.json": to_json(
+      comments.map((comment) =>
+        pick_export_fields(comment, COMMENT_EXPORT_FIELDS)
+      )
+    ),
+    "connections.json": to_json(
+      connections.map((connection) =>
+        pick_export_fields(connection, CONNECTION_EXPORT_FIELDS)
+      )
+    ),
+    "settings/credentials.json": to_json({
+      ...pick_export_fields(
+        credential_settings,
+        CREDENTIAL_SETTINGS_EXPORT_FIELDS
+      ),
+      passkeys: credential_settings.passkeys.map((passkey) =>
+        pick_export_fields(passkey, PASSKEY_EXPORT_FIELDS)
+      ),
+      login_providers: credential_settings.login_providers.map((provider) =>
+        pick_export_fields(provider, LOGIN_PROVIDER_EXPORT_FIELDS)
+      )
+    }),
+    "settings/notifications.json": to_json(
+      pick_export_fields(
+        notification_settings,
+        NOTIFICATION_SETTINGS_EXPORT_FIELDS
+      )
+    ),
+    "settings/privacy.json": to_json(
+      pick_export_fields(privacy_settings, PRIVACY_SETTINGS_EXPORT_FIELDS)
+    ),
+    "login_activity.json": to_json({
+      ...pick_export_fields(login_activity, LOGIN_ACTIVITY_EXPORT_FIELDS),
+      logins: login_activity.logins.map((login) =>
+        pick_export_fields(login, LOGIN_EXPORT_FIELDS)
+      ),
+      alerts: login_activity.alerts.map((alert) =>
+        pick_export_fields(alert, LOGIN_ALERT_EXPORT_FIELDS)
+      )
+    }),
+    "relations.json": to_json(
+      Object.fromEntries(
+        Object.entries(relations).map(([key, users]) => [
+          key,
+          (users as BareUser[]).map((user) =>
+            pick_export_fields(user, RELATION_EXPORT_FIELDS)
+          )
+        ])
+      )
+    )
+  };
+
+  for (const story of stories) {
+    files[`stories/${story.id}.json`] = to_json(
+      pick_export_fields(story, STORY_EXPORT_FIELDS)
+    );
+    files[`stories/${story.id}.md`] = render_markdown_file(
+      {
+        title: story.title,
+        description: story.description,
+        tags: story.tags.map((tag) => tag.name),
+        canonical_url: story.canonical_url,
+        published_at: get_timestamp(
+          story,
+          "first_published_at"
+        )?.toISOString()
+      },
+      await source.get_markdown(story.id)
+    );
+  }
+
+  for (const draft of drafts) {
+    files[`drafts/${draft.id}.json`] = to_json(
+      pick_export_fields(draft, DRAFT_EXPORT_FIELDS)
+    );
+    files[`drafts/${draft.id}.md`] = render_markdown_file(
+      { title: draft.title },
+      await source.get_markdown(draft.id)
+    );
+  }
+
+  return files;
+};
+
+/**
+ * Predicate function for determining whether the user can request a new
+ * export. Only one export can be in progress, and the exports are limited by
+ * `ACCOUNT_EXPORT_PROPS.request_cooldown`.
+ * @param latest Latest export of the user
+ * @param now Current date
+ */
+export const can_request_account_export = (
+  latest: AccountExport | undefined,
+  now: Date
+): boolean => {
+  if (!latest) {
+    return true;
+  }
+
+  if (
+    latest.status === AccountExportStatus.QUEUED ||
+    latest.status === AccountExportStatus.PROCESSING
+  ) {
+    return false;
+  }
+
+  return (
+    now.getTime() - (latest.created_at?.getTime() ?? 0) >=
+    ACCOUNT_EXPORT_PROPS.request_cooldown * DAY
+  );
+};
+
+/**
+ * Returns the export as seen by `GetAccountExportStatus`, marking it as
+ * expired (and dropping its download token) once `expires_at` has passed
+ * @param account_export Export
+ * @param now Current date
+ */
+export const get_account_export_status = (
+  account_export: AccountExport,
+  now: Date
+): AccountExport =>
+  account_export.status === AccountExportStatus.READY &&
+  account_export.expires_at !== undefined &&
+  account_export.expires_at.getTime() <= now.getTime()
+    ? {
+        ...account_export,
+        status: AccountExportStatus.EXPIRED,
+        download_token: undefined
+      }
+    : account_export;
+
+export interface AccountExportJobOptions {
+  account_export: AccountExport;
+  /**
+   * Returns the current date
+   */
+  clock: () => Date;
+  /**
+   * Generates the download token for the archive
+   */
+  generate_token: () => string;
+  source: AccountExportSource;
+  /**
+   * Persists the state of the export
+   */
+  update: (account_export: AccountExport) => Promise<void>;
+  user_id: string;
+  /**
+   * Writes the files into an archive, returning its size (in bytes)
+   */
+  write_archive: (
+    export_id: string,
+    files: Record<string, string>
+  ) => Promise<number>;
+}
+
+/**
+ * Builds and stores the archive of a queued export. Meant to be run in the
+ * background after `RequestAccountExport`.
+ * @param options Job options
+ */
+export const run_account_export = async (
+  options: AccountExportJobOptions
+): Promise<AccountExport> => {
+  const processing: AccountExport = {
+    ...options.account_export,
+    status: AccountExportStatus.PROCESSING
+  };
+
+  await options.update(processing);
+
+  let result: AccountExport;
+
+  try {
+    const files = await build_account_export(options.user_id, options.source);
+    const size = await options.write_archive(processing.id, files);
+    const now = options.clock();
+
+    result = {
+      ...processing,
+      status: AccountExportStatus.READY,
+      completed_at: now,
+      expires_at: new Date(
+        now.getTime() + ACCOUNT_EXPORT_PROPS.token_lifetime * DAY
+      ),
+      download_token: options.generate_token(),
+      size
+    };
+  } catch {
+    result = {
+      ...processing,
+      status: AccountExportStatus.FAILED,
+      completed_at: options.clock()
+    };
+  }
+
+  await options.update(result);
+
+  return result;
+};
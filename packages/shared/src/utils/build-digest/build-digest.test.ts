@@ -0,0 +1,338 @@
+import { GetNotificationSettingsResponse } from "@storiny/proto/dist/notification_settings_def/v1/def";
+
+import {
+  build_digest,
+  DIGEST_PROPS,
+  DigestActivity,
+  DigestRecipient,
+  DigestRecord,
+  get_digest_window,
+  run_digest_job
+} from "./build-digest";
+
+const NOW = new Date("2026-01-15T00:00:00.000Z");
+const DAY = 24 * 60 * 60 * 1000;
+
+/**
+ * Returns an ISO date string `days` days before `NOW`
+ * @param days Number of days
+ */
+const days_ago = (days: number): string =>
+  new Date(NOW.getTime() - days * DAY).toISOString();
+
+const get_story = (
+  id: string,
+  created_at: string,
+  like_count = 0
+): DigestActivity["stories"][number] => ({
+  id,
+  created_at,
+  like_count,
+  read_count: 0,
+  read_time: 4,
+  title: `Story ${id}`,
+  url: `https://storiny.com/story/${id}`,
+  user_name: "Someone"
+});
+
+const ACTIVITY: DigestActivity = {
+  stories: [
+    get_story("old", days_ago(10), 100),
+    get_story("a", days_ago(2), 5),
+    get_story("b", days_ago(3), 50),
+    // Same story from a followed tag
+    get_story("b", days_ago(3), 50)
+  ],
+  followers: Array.from({ length: DIGEST_PROPS.max_followers + 2 }, (_, i) => ({
+    created_at: days_ago(1),
+    name: `Follower ${i}`,
+    url: `https://storiny.com/follower_${i}`,
+    username: `follower_${i}`
+  })),
+  comments: [
+    {
+      id: "1",
+      created_at: days_ago(1),
+      story_title: "Story",
+      url: "https://storiny.com/comment/1",
+      user_name: "Commenter"
+    }
+  ]
+};
+
+const SETTINGS = {
+  mail_digest: true
+} as unknown as GetNotificationSettingsResponse;
+
+describe("get_digest_window", () => {
+  it("covers the configured number of days", () => {
+    expect(get_digest_window(NOW, 7).start.toISOString()).toEqual(days_ago(7));
+  });
+
+  it("starts where the previous digest ended", () => {
+    const record: DigestRecord = {
+      story_ids: [],
+      sent_at: days_ago(3),
+      window_start: days_ago(10),
+      window_end: days_ago(3)
+    };
+
+    expect(get_digest_window(NOW, 7, record).start.toISOString()).toEqual(
+      days_ago(3)
+    );
+  });
+});
+
+describe("build_digest", () => {
+  it("ranks and deduplicates the stories within the window", () => {
+    const digest = build_digest(ACTIVITY, get_digest_window(NOW, 7));
+
+    expect(digest?.record.story_ids).toEqual(["b", "a"]);
+    expect(digest?.template_data.stories).toHaveLength(2);
+  });
+
+  it("truncates the followers", () => {
+    const digest = build_digest(ACTIVITY, get_digest_window(NOW, 7));
+
+    expect(digest?.template_data.followers).toHaveLength(
+      DIGEST_PROPS.max_followers
+    );
+    expect(digest?.template_data.follower_overflow).toEqual(2);
+    expect(digest?.template_data.comment_overflow).toEqual(0);
+  });
+
+  it("skips the stories that have already been sent", () => {
+    const digest = build_digest(ACTIVITY, get_digest_window(NOW, 7), {
+      story_ids: ["b"],
+      sent_at: days_ago(7),
+      window_start: days_ago(14),
+      window_end: days_ago(7)
+    });
+
+    expect(digest?.record.story_ids).toEqual(["a"]);
+  });
+
+  it("returns `null` when there is no activity", () => {
+    expect(
+      build_digest(
+        { stories: [], followers: [], comments: [] },
+        get_digest_window(NOW, 7)
+      )
+    ).toBeNull();
+  });
+});
+
+describe("run_digest_job", () => {
+  const RECIPIENTS: DigestRecipient[] = [
+    { user_id: "1", email: "one@storiny.com", settings: SETTINGS },
+    {
+      user_id: "2",
+      email: "two@storiny.com",
+      settings: { ...SETTINGS, mail_digest: false }
+    }
+  ];
+
+  it("sends each digest only once", async () => {
+    const records = new Map<string, DigestRecord>();
+    const send = jest.fn(async () => undefined);
+    const options = {
+      clock: (): Date => NOW,
+      get_activity: async (): Promise<DigestActivity> => ACTIVITY,
+      get_last_record: async (user_id: string): Promise<DigestRecord | null> =>
+        records.get(user_id) || null,
+      on_error: jest.fn(),
+      put_record: async (
+        user_id: string,
+        record: DigestRecord
+      ): Promise<void> => {
+        records.set(user_id, record);
+      },
+      recipients: RECIPIENTS,
+      send
+    };
+
+    expect(await run_digest_job(options)).toEqual({
+      sent: 1,
+      sent_unrecorded: 0,
+      skipped: 1,
+      failed: 0
+    });
+    expect(send).toHaveBeenCalledTimes(1);
+    expect(records.get("1")?.window_end).toEqual(NOW.toISOString());
+    expect(records.get("1")?.status).toEqual("sent");
+
+    // Re-running the job within the same window
+    expect(await run_digest_job(options)).toEqual({
+      sent: 0,
+      sent_unrecorded: 0,
+      skipped: 2,
+      failed: 0
+    });
+    expect(send).toHaveBeenCalledTimes(1);
+
+    // Next window
+    const next = new Date(NOW.getTime() + 7 * DAY);
+    const story = get_story("c", new Date(next.getTime() - DAY).toISOString());
+
+    expect(
+      await run_digest_job({
+        ...options,
+        clock: (): Date => next,
+        get_activity: async (): Promise<DigestActivity> => ({
+          ...ACTIVITY,
+          stories: [story]
+        })
+      })
+    ).toEqual({
+      sent: 1,
+      sent_unrecorded: 0,
+      skipped: 1,
+      failed: 0
+    });
+  });
+
+  it("does not resend digests left pending by a failed run", async () => {
+    const records = new Map<string, DigestRecord>();
+    const send = jest.fn(async (): Promise<void> => {
+      throw new Error("Failed to send");
+    });
+    const options = {
+      clock: (): Date => NOW,
+      get_activity: async (): Promise<DigestActivity> => ACTIVITY,
+      get_last_record: async (user_id: string): Promise<DigestRecord | null> =>
+        records.get(user_id) || null,
+      on_error: jest.fn(),
+      put_record: async (
+        user_id: string,
+        record: DigestRecord
+      ): Promise<void> => {
+        records.set(user_id, record);
+      },
+      recipients: RECIPIENTS,
+      send
+    };
+
+    expect(await run_digest_job(options)).toEqual({
+      sent: 0,
+      sent_unrecorded: 0,
+      skipped: 1,
+      failed: 1
+    });
+    expect(records.get("1")?.status).toEqual("pending");
+
+    // The mail may have been delivered before the failure
+    expect(await run_digest_job(options)).toEqual({
+      sent: 0,
+      sent_unrecorded: 0,
+      skipped: 2,
+      failed: 0
+    });
+    expect(send).toHaveBeenCalledTimes(1);
+  });
+
+  it("does not send digests without a pending record", async () => {
+    const send = jest.fn(async () => undefined);
+
+    expect(
+      await run_digest_job({
+        clock: (): Date => NOW,
+        get_activity: async (): Promise<DigestActivity> => ACTIVITY,
+        get_last_record: async (): Promise<null> => null,
+        on_error: jest.fn(),
+        put_record: async (): Promise<void> => {
+          throw new Error("Failed to write");
+        },
+        recipients: RECIPIENTS,
+        send
+      })
+    ).toEqual({
+      sent: 0,
+      sent_unrecorded: 0,
+      skipped: 1,
+      failed: 1
+    });
+    expect(send).not.toHaveBeenCalled();
+  });
+
+  it("counts store errors as the recipient's failure", async () => {
+    const send = jest.fn(async () => undefined);
+    const on_error = jest.fn();
+
+    expect(
+      await run_digest_job({
+        clock: (): Date => NOW,
+        get_activity: async (): Promise<DigestActivity> => ACTIVITY,
+        get_last_record: async (user_id: string): Promise<null> => {
+          if (user_id === "1") {
+            throw new Error("Failed to read");
+          }
+
+          return null;
+        },
+        on_error,
+        put_record: async (): Promise<void> => undefined,
+        recipients: [
+          ...RECIPIENTS,
+          { user_id: "3", email: "three@storiny.com", settings: SETTINGS }
+        ],
+        send
+      })
+    ).toEqual({
+      sent: 1,
+      sent_unrecorded: 0,
+      skipped: 1,
+      failed: 1
+    });
+    expect(send).toHaveBeenCalledTimes(1);
+    expect(on_error).toHaveBeenCalledTimes(1);
+    expect(on_error).toHaveBeenCalledWith(
+      RECIPIENTS[0],
+      new Error("Failed to read")
+    );
+  });
+
+  it("counts digests that could not be marked as sent separately", async () => {
+    const records = new Map<string, DigestRecord>();
+    const send = jest.fn(async () => undefined);
+    const on_error = jest.fn();
+    const options = {
+      clock: (): Date => NOW,
+      get_activity: async (): Promise<DigestActivity> => ACTIVITY,
+      get_last_record: async (user_id: string): Promise<DigestRecord | null> =>
+        records.get(user_id) || null,
+      on_error,
+      put_record: async (
+        user_id: string,
+        record: DigestRecord
+      ): Promise<void> => {
+        if (record.status === "sent") {
+          throw new Error("Failed to write");
+        }
+
+        records.set(user_id, record);
+      },
+      recipients: RECIPIENTS,
+      send
+    };
+
+    expect(await run_digest_job(options)).toEqual({
+      sent: 0,
+      sent_unrecorded: 1,
+      skipped: 1,
+      failed: 0
+    });
+    expect(on_error).toHaveBeenCalledWith(
+      RECIPIENTS[0],
+      new Error("Failed to write")
+    );
+
+    // The pending record prevents the digest from being sent again
+    expect(await run_digest_job(options)).toEqual({
+      sent: 0,
+      sent_unrecorded: 0,
+      skipped: 2,
+      failed: 0
+    });
+    expect(send).toHaveBeenCalledTimes(1);
+  });
+});
@@ -0,0 +1,350 @@
+import { GetNotificationSettingsResponse } from "@storiny/proto/dist/notification_settings_def/v1/def";
+
+import { should_send_mail } from "../route-notification";
+
+const DAY = 24 * 60 * 60 * 1000;
+
+export const DIGEST_PROPS = {
+  // Default window covered by a digest (in days)
+  default_window: 7,
+  max_comments: 5,
+  max_followers: 5,
+  max_stories: 5,
+  // Name of the SES template
+  template_name: "Digest"
+} as const;
+
+export interface DigestStory {
+  created_at: string;
+  id: string;
+  like_count: number;
+  read_count: number;
+  read_time: number;
+  title: string;
+  url: string;
+  user_name: string;
+}
+
+export interface DigestFollower {
+  created_at: string;
+  name: string;
+  url: string;
+  username: string;
+}
+
+export interface DigestComment {
+  created_at: string;
+  id: string;
+  story_title: string;
+  url: string;
+  user_name: string;
+}
+
+export interface DigestActivity {
+  /**
+   * Comments on the recipient's stories
+   */
+  comments: DigestComment[];
+  /**
+   * New followers of the recipient
+   */
+  followers: DigestFollower[];
+  /**
+   * Stories from the followed users and tags. Stories present in both sets
+   * are deduplicated.
+   */
+  stories: DigestStory[];
+}
+
+export interface DigestRecord {
+  /**
+   * `pending` while the digest is being sent. A pending record left behind by
+   * a failed or interrupted run is treated as sent, as the mail may already
+   * have been delivered. Records without a status are sent.
+   */
+  status?: "pending" | "sent";
+  /**
+   * IDs of the stories included in the digest
+   */
+  story_ids: string[];
+  sent_at: string;
+  window_end: string;
+  window_start: string;
+}
+
+export interface DigestRecipient {
+  email: string;
+  settings: GetNotificationSettingsResponse;
+  user_id: string;
+}
+
+/**
+ * Template data for the `Digest` mail template
+ */
+export interface DigestTemplateData {
+  comment_overflow: number;
+  comments: Pick<DigestComment, "story_title" | "url" | "user_name">[];
+  end_date: string;
+  follower_overflow: number;
+  followers: Pick<DigestFollower, "name" | "url" | "username">[];
+  start_date: string;
+  stories: Pick<DigestStory, "read_time" | "title" | "url" | "user_name">[];
+}
+
+export interface Digest {
+  record: DigestRecord;
+  template_data: DigestTemplateData;
+}
+
+export interface DigestWindow {
+  end: Date;
+  start: Date;
+}
+
+/**
+ * Returns the window covered by the next digest. The window never overlaps
+ * with the previously sent digest.
+ * @param now Current date
+ * @param window Length of the window (in days)
+ * @param last_record Record of the previously sent digest
+ */
+export const get_digest_window = (
+  now: Date,
+  window: number = DIGEST_PROPS.default_window,
+  last_record?: DigestRecord | null
+): DigestWindow => {
+  const start = now.getTime() - window * DAY;
+  const last_end = last_record ? Date.parse(last_record.window_end) : NaN;
+
+  return {
+    start: new Date(Number.isNaN(last_end) ? start : Math.max(start, last_end)),
+    end: now
+  };
+};
+
+/**
+ * Predicate function for determining whether a date lies within a window
+ * @param value ISO date string
+ * @param window Window
+ */
+const is_within_window = (value: string, window: DigestWindow): boolean => {
+  const time = Date.parse(value);
+  return time >= window.start.getTime() && time < window.end.getTime();
+};
+
+/**
+ * Formats a date for the digest mail
+ * @param date Date
+ */
+const format_date = (date: Date): string =>
+  date.toLocaleDateString("en-US", {
+    day: "numeric",
+    month: "short",
+    timeZone: "UTC",
+    year: "numeric"
+  });
+
+/**
+ * Builds a digest from the activity within a window. Returns `null` when
+ * there is nothing to send.
+ * @param activity Activity of the recipient
+ * @param window Window covered by the digest
+ * @param last_record Record of the previously sent digest, used to skip
+ * stories that have already been sent
+ */
+export const build_digest = (
+  activity: DigestActivity,
+  window: DigestWindow,
+  last_record?: DigestRecord | null
+): Digest | null => {
+  const sent = new Set(last_record?.story_ids || []);
+  const seen = new Set<string>();
+  const stories = activity.stories
+    .filter((story) => {
+      if (
+        seen.has(story.id) ||
+        sent.has(story.id) ||
+        !is_within_window(story.created_at, window)
+      ) {
+        return false;
+      }
+
+      seen.add(story.id);
+      return true;
+    })
+    .sort(
+      (a, b) =>
+        b.like_count + b.read_count - (a.like_count + a.read_count) ||
+        Date.parse(b.created_at) - Date.parse(a.created_at)
+    )
+    .slice(0, DIGEST_PROPS.max_stories);
+  const followers = activity.followers.filter((follower) =>
+    is_within_window(follower.created_at, window)
+  );
+  const comments = activity.comments.filter((comment) =>
+    is_within_window(comment.created_at, window)
+  );
+
+  if (!stories.length && !followers.length && !comments.length) {
+    return null;
+  }
+
+  return {
+    record: {
+      story_ids: stories.map((story) => story.id),
+      sent_at: window.end.toISOString(),
+      window_start: window.start.toISOString(),
+      window_end: window.end.toISOString()
+    },
+    template_data: {
+      start_date: format_date(window.start),
+      end_date: format_date(window.end),
+      stories: stories.map(({ read_time, title, url, user_name }) => ({
+        read_time,
+        title,
+        url,
+        user_name
+      })),
+      followers: followers
+        .slice(0, DIGEST_PROPS.max_followers)
+        .map(({ name, url, username }) => ({ name, url, username })),
+      follower_overflow: Math.max(
+        followers.length - DIGEST_PROPS.max_followers,
+        0
+      ),
+      comments: comments
+        .slice(0, DIGEST_PROPS.max_comments)
+        .map(({ story_title, url, user_name }) => ({
+          story_title,
+          url,
+          user_name
+        })),
+      comment_overflow: Math.max(
+        comments.length - DIGEST_PROPS.max_comments,
+        0
+      )
+    }
+  };
+};
+
+export interface DigestJobOptions {
+  /**
+   * Returns the current date
+   */
+  clock: () => Date;
+  /**
+   * Returns the activity of a recipient within a window
+   */
+  get_activity: (
+    recipient: DigestRecipient,
+    window: DigestWindow
+  ) => Promise<DigestActivity>;
+  /**
+   * Returns the record of the last digest sent to a user
+   */
+  get_last_record: (user_id: string) => Promise<DigestRecord | null>;
+  /**
+   * Reports the error that caused a recipient to be counted as `failed` or
+   * `sent_unrecorded`
+   */
+  on_error: (recipient: DigestRecipient, error: unknown) => void;
+  /**
+   * Persists the record of a digest, replacing the previous record
+   */
+  put_record: (user_id: string, record: DigestRecord) => Promise<void>;
+  recipients: AsyncIterable<DigestRecipient> | Iterable<DigestRecipient>;
+  /**
+   * Sends the digest mail
+   */
+  send: (recipient: DigestRecipient, digest: Digest) => Promise<void>;
+  /**
+   * Length of the window (in days)
+   */
+  window?: number;
+}
+
+export interface DigestJobResult {
+  failed: number;
+  sent: number;
+  /**
+   * Digests that were sent but could not be marked as sent. Their records are
+   * left pending, so they are not sent again.
+   */
+  sent_unrecorded: number;
+  skipped: number;
+}
+
+/**
+ * Sends the digest to every recipient that has `mail_digest` enabled. A
+ * recipient is skipped until a full window has passed since their last
+ * digest, so the job can be re-run safely. A pending record is written before
+ * sending and marked sent afterwards, so a digest is never sent twice, even
+ * if the run is interrupted or the record cannot be updated. Errors are
+ * reported per recipient using `on_error`.
+ * @param options Job options
+ */
+export const run_digest_job = async (
+  options: DigestJobOptions
+): Promise<DigestJobResult> => {
+  const window_length = options.window ?? DIGEST_PROPS.default_window;
+  const result: DigestJobResult = {
+    sent: 0,
+    sent_unrecorded: 0,
+    skipped: 0,
+    failed: 0
+  };
+
+  for await (const recipient of options.recipients) {
+    if (!should_send_mail("digest", recipient.settings)) {
+      result.skipped++;
+      continue;
+    }
+
+    try {
+      const now = options.clock();
+      const last_record = await options.get_last_record(recipient.user_id);
+
+      if (
+        last_record &&
+        now.getTime() - Date.parse(last_record.sent_at) < window_length * DAY
+      ) {
+        result.skipped++;
+        continue;
+      }
+
+      const window = get_digest_window(now, window_length, last_record);
+      const digest = build_digest(
+        await options.get_activity(recipient, window),
+        window,
+        last_record
+      );
+
+      if (!digest) {
+        result.skipped++;
+        continue;
+      }
+
+      await options.put_record(recipient.user_id, {
+        ...digest.record,
+        status: "pending"
+      });
+      await options.send(recipient, digest);
+
+      try {
+        await options.put_record(recipient.user_id, {
+          ...digest.record,
+          status: "sent"
+        });
+        result.sent++;
+      } catch (error) {
+        result.sent_unrecorded++;
+        options.on_error(recipient, error);
+      }
+    } catch (error) {
+      result.failed++;
+      options.on_error(recipient, error);
+    }
+  }
+
+  return result;
+};
@@ -0,0 +1 @@
+export * from "./build-digest";
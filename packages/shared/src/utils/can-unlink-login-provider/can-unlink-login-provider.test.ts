@@ -0,0 +1,59 @@
+import { LoginProvider } from "@storiny/proto/dist/credential_settings_def/v1/def";
+
+import { can_unlink_login_provider } from "./can-unlink-login-provider";
+
+const GOOGLE = {
+  provider: LoginProvider.GOOGLE,
+  provider_user_id: "google-user",
+  created_at: new Date("2023-01-01T00:00:00Z")
+};
+
+const APPLE = {
+  provider: LoginProvider.APPLE,
+  provider_user_id: "apple-user",
+  created_at: new Date("2023-01-01T00:00:00Z")
+};
+
+const PASSKEY = {
+  id: "passkey",
+  name: "Laptop",
+  created_at: new Date("2023-01-01T00:00:00Z")
+};
+
+describe("can_unlink_login_provider", () => {
+  it("allows unlinking when a password is set", () => {
+    expect(
+      can_unlink_login_provider(
+        { has_password: true, login_providers: [GOOGLE], passkeys: [] },
+        LoginProvider.GOOGLE
+      )
+    ).toBeTrue();
+  });
+
+  it("allows unlinking when another provider is linked", () => {
+    expect(
+      can_unlink_login_provider(
+        { has_password: false, login_providers: [GOOGLE, APPLE], passkeys: [] },
+        LoginProvider.GOOGLE
+      )
+    ).toBeTrue();
+  });
+
+  it("allows unlinking when a passkey is registered", () => {
+    expect(
+      can_unlink_login_provider(
+        { has_password: false, login_providers: [GOOGLE], passkeys: [PASSKEY] },
+        LoginProvider.GOOGLE
+      )
+    ).toBeTrue();
+  });
+
+  it("refuses to unlink the last login method", () => {
+    expect(
+      can_unlink_login_provider(
+        { has_password: false, login_providers: [GOOGLE], passkeys: [] },
+        LoginProvider.GOOGLE
+      )
+    ).toBeFalse();
+  });
+});
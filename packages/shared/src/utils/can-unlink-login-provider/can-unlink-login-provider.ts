@@ -0,0 +1,21 @@
+import {
+  GetCredentialSettingsResponse,
+  LoginProvider
+} from "@storiny/proto/dist/credential_settings_def/v1/def";
+
+/**
+ * Predicate function for determining whether a login provider can be
+ * unlinked without leaving the account with no login method
+ * @param settings Credential settings of the user
+ * @param provider Login provider to unlink
+ */
+export const can_unlink_login_provider = (
+  settings: Pick<
+    GetCredentialSettingsResponse,
+    "has_password" | "login_providers" | "passkeys"
+  >,
+  provider: LoginProvider
+): boolean =>
+  settings.has_password ||
+  settings.passkeys.length > 0 ||
+  settings.login_providers.some((item) => item.provider !== provider);
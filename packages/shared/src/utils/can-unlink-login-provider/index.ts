@@ -0,0 +1 @@
+export * from "./can-unlink-login-provider";
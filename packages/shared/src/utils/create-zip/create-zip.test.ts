@@ -0,0 +1,71 @@
+import { crc32, create_zip, get_dos_date_time } from "./create-zip";
+
+describe("crc32", () => {
+  it("computes the checksum", () => {
+    expect(crc32(new TextEncoder().encode("123456789"))).toEqual(0xcbf43926);
+    expect(crc32(new Uint8Array())).toEqual(0);
+  });
+});
+
+describe("create_zip", () => {
+  it("writes the entries in order without compression", () => {
+    const archive = create_zip([
+      { path: "mimetype", data: "application/epub+zip" },
+      { path: "data.bin", data: Uint8Array.from([1, 2, 3]) }
+    ]);
+    const view = new DataView(archive.buffer);
+    const decoder = new TextDecoder();
+
+    // First local header
+    expect(view.getUint32(0, true)).toEqual(0x04034b50);
+    expect(view.getUint16(8, true)).toEqual(0); // Stored
+    expect(decoder.decode(archive.slice(30, 38))).toEqual("mimetype");
+    expect(decoder.decode(archive.slice(38, 58))).toEqual(
+      "application/epub+zip"
+    );
+
+    // End of the central directory
+    const end = archive.length - 22;
+    expect(view.getUint32(end, true)).toEqual(0x06054b50);
+    expect(view.getUint16(end + 10, true)).toEqual(2);
+    expect(view.getUint32(end + 16, true)).toEqual(58 + 30 + 8 + 3);
+  });
+
+  it("writes the modification date of the entries", () => {
+    const modified = new Date("2024-03-01T12:34:56.000Z");
+    const archive = create_zip([{ path: "a.txt", data: "a" }], modified);
+    const view = new DataView(archive.buffer);
+    const { date, time } = get_dos_date_time(modified);
+
+    // Local header
+    expect(view.getUint16(10, true)).toEqual(time);
+    expect(view.getUint16(12, true)).toEqual(date);
+    // Central directory header
+    expect(view.getUint16(36 + 12, true)).toEqual(time);
+    expect(view.getUint16(36 + 14, true)).toEqual(date);
+  });
+
+  it("defaults to the earliest representable date", () => {
+    const archive = create_zip([{ path: "a.txt", data: "a" }]);
+    const view = new DataView(archive.buffer);
+
+    expect(view.getUint16(10, true)).toEqual(0);
+    expect(view.getUint16(12, true)).toEqual((1 << 5) | 1); // 1980-01-01
+  });
+});
+
+describe("get_dos_date_time", () => {
+  it("encodes the date with a 2-second resolution", () => {
+    expect(get_dos_date_time(new Date("2024-03-01T12:34:57.000Z"))).toEqual({
+      time: (12 << 11) | (34 << 5) | 28,
+      date: (44 << 9) | (3 << 5) | 1
+    });
+  });
+
+  it("clamps the dates before 1980", () => {
+    expect(get_dos_date_time(new Date(0))).toEqual({
+      time: 0,
+      date: (1 << 5) | 1
+    });
+  });
+});
@@ -0,0 +1,139 @@
+export interface ZipEntry {
+  data: Uint8Array | string;
+  path: string;
+}
+
+const CRC_TABLE = new Uint32Array(256).map((_, index) => {
+  let value = index;
+
+  for (let bit = 0; bit < 8; bit++) {
+    value = value & 1 ? 0xedb88320 ^ (value >>> 1) : value >>> 1;
+  }
+
+  return value >>> 0;
+});
+
+/**
+ * Computes the CRC-32 checksum of the data
+ * @param data Data
+ */
+export const crc32 = (data: Uint8Array): number => {
+  let crc = 0xffffffff;
+
+  for (const byte of data) {
+    crc = CRC_TABLE[(crc ^ byte) & 0xff] ^ (crc >>> 8);
+  }
+
+  return (crc ^ 0xffffffff) >>> 0;
+};
+
+// Earliest date that can be represented in an archive
+const DOS_EPOCH = new Date(Date.UTC(1980, 0, 1));
+
+/**
+ * Encodes a date into the MS-DOS time and date fields of the headers (in
+ * UTC, with a 2-second resolution). Dates outside the range supported by the
+ * format are clamped.
+ * @param date Date
+ */
+export const get_dos_date_time = (
+  date: Date
+): { date: number; time: number } => {
+  const clamped = new Date(
+    Math.min(
+      Math.max(date.getTime(), DOS_EPOCH.getTime()),
+      Date.UTC(2107, 11, 31, 23, 59, 58)
+    )
+  );
+
+  return {
+    time:
+      (clamped.getUTCHours() << 11) |
+      (clamped.getUTCMinutes() << 5) |
+      (clamped.getUTCSeconds() >> 1),
+    date:
+      ((clamped.getUTCFullYear() - 1980) << 9) |
+      ((clamped.getUTCMonth() + 1) << 5) |
+      clamped.getUTCDate()
+  };
+};
+
+/**
+ * Creates an uncompressed (stored) zip archive. The entries are written in
+ * order, which is required for formats such as EPUB where the `mimetype`
+ * entry must come first.
+ * @param entries Entries
+ * @param modified Modification date of the entries, defaults to 1980-01-01
+ */
+export const create_zip = (
+  entries: ZipEntry[],
+  modified: Date = DOS_EPOCH
+): Uint8Array => {
+  const encoder = new TextEncoder();
+  const { date, time } = get_dos_date_time(modified);
+  const local_headers: Uint8Array[] = [];
+  const central_headers: Uint8Array[] = [];
+  let offset = 0;
+
+  for (const entry of entries) {
+    const name = encoder.encode(entry.path);
+    const data =
+      typeof entry.data === "string" ? encoder.encode(entry.data) : entry.data;
+    const checksum = crc32(data);
+
+    const local = new Uint8Array(30 + name.length + data.length);
+    const local_view = new DataView(local.buffer);
+    local_view.setUint32(0, 0x04034b50, true);
+    local_view.setUint16(4, 10, true); // Version needed to extract
+    local_view.setUint16(6, 0x0800, true); // UTF-8 file names
+    local_view.setUint16(10, time, true);
+    local_view.setUint16(12, date, true);
+    local_view.setUint32(14, checksum, true);
+    local_view.setUint32(18, data.length, true);
+    local_view.setUint32(22, data.length, true);
+    local_view.setUint16(26, name.length, true);
+    local.set(name, 30);
+    local.set(data, 30 + name.length);
+
+    const central = new Uint8Array(46 + name.length);
+    const central_view = new DataView(central.buffer);
+    central_view.setUint32(0, 0x02014b50, true);
+    central_view.setUint16(4, 20, true); // Version made by
+    central_view.setUint16(6, 10, true);
+    central_view.setUint16(8, 0x0800, true);
+    central_view.setUint16(12, time, true);
+    central_view.setUint16(14, date, true);
+    central_view.setUint32(16, checksum, true);
+    central_view.setUint32(20, data.length, true);
+    central_view.setUint32(24, data.length, true);
+    central_view.setUint16(28, name.length, true);
+    central_view.setUint32(42, offset, true);
+    central.set(name, 46);
+
+    local_headers.push(local);
+    central_headers.push(central);
+    offset += local.length;
+  }
+
+  const central_size = central_headers.reduce(
+    (size, header) => size + header.length,
+    0
+  );
+  const end = new Uint8Array(22);
+  const end_view = new DataView(end.buffer);
+  end_view.setUint32(0, 0x06054b50, true);
+  end_view.setUint16(8, entries.length, true);
+  end_view.setUint16(10, entries.length, true);
+  end_view.setUint32(12, central_size, true);
+  end_view.setUint32(16, offset, true);
+
+  const archive = new Uint8Array(offset + central_size + end.length);
+  let position = 0;
+
+  for (const chunk of [...local_headers, ...central_headers, end]) {
+    archive.set(chunk, position);
+    position += chunk.length;
+  }
+
+  return archive;
+};
@@ -0,0 +1,57 @@
+import { encode_blurhash } from "./encode-blurhash";
+
+const BASE83_CHARACTERS =
+  "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+/**
+ * Decodes a base 83 string
+ * @param value Encoded string
+ */
+const decode_base83 = (value: string): number =>
+  Array.from(value).reduce(
+    (result, char) => result * 83 + BASE83_CHARACTERS.indexOf(char),
+    0
+  );
+
+/**
+ * Returns the pixel data for a solid image
+ * @param width Width of the image
+ * @param height Height of the image
+ * @param color RGB color
+ */
+const get_solid_pixels = (
+  width: number,
+  height: number,
+  color: [number, number, number]
+): Uint8ClampedArray =>
+  Uint8ClampedArray.from(
+    Array.from({ length: width * height }, () => [...color, 255]).flat()
+  );
+
+describe("encode_blurhash", () => {
+  it("encodes the number of components", () => {
+    const pixels = get_solid_pixels(8, 8, [255, 0, 0]);
+
+    expect(encode_blurhash(pixels, 8, 8, 4, 3)).toHaveLength(4 + 2 * 11);
+    expect(encode_blurhash(pixels, 8, 8, 4, 3)[0]).toEqual("L");
+    expect(encode_blurhash(pixels, 8, 8, 1, 1)).toHaveLength(6);
+  });
+
+  it("encodes the average color", () => {
+    const hash = encode_blurhash(get_solid_pixels(8, 8, [169, 122, 88]), 8, 8);
+    expect(decode_base83(hash.slice(2, 6))).toEqual(0xa97a58);
+  });
+
+  it("encodes a flat image without detail", () => {
+    expect(
+      encode_blurhash(get_solid_pixels(4, 4, [255, 255, 255]), 4, 4, 1, 1)
+    ).toEqual("00TSUA");
+  });
+
+  it("rejects invalid arguments", () => {
+    const pixels = get_solid_pixels(2, 2, [0, 0, 0]);
+
+    expect(() => encode_blurhash(pixels, 2, 2, 0, 3)).toThrow();
+    expect(() => encode_blurhash(pixels, 4, 4)).toThrow();
+  });
+});
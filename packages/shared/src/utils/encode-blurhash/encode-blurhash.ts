@@ -0,0 +1,145 @@
+const BASE83_CHARACTERS =
+  "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+export const BLURHASH_PROPS = {
+  components_x: 4,
+  components_y: 3
+} as const;
+
+/**
+ * Encodes an integer to base 83
+ * @param value Integer
+ * @param length Number of digits
+ */
+const encode_base83 = (value: number, length: number): string => {
+  let result = "";
+
+  for (let i = 1; i <= length; i++) {
+    const digit = Math.floor(value / Math.pow(83, length - i)) % 83;
+    result += BASE83_CHARACTERS[digit];
+  }
+
+  return result;
+};
+
+/**
+ * Converts an sRGB channel value (0-255) to linear space (0-1)
+ * @param value Channel value
+ */
+const srgb_to_linear = (value: number): number => {
+  const v = value / 255;
+  return v <= 0.04045 ? v / 12.92 : Math.pow((v + 0.055) / 1.055, 2.4);
+};
+
+/**
+ * Converts a linear channel value (0-1) to sRGB space (0-255)
+ * @param value Channel value
+ */
+const linear_to_srgb = (value: number): number => {
+  const v = Math.max(0, Math.min(1, value));
+  return v <= 0.0031308
+    ? Math.trunc(v * 12.92 * 255 + 0.5)
+    : Math.trunc((1.055 * Math.pow(v, 1 / 2.4) - 0.055) * 255 + 0.5);
+};
+
+/**
+ * Raises the absolute value to a power, preserving the sign
+ * @param value Value
+ * @param exp Exponent
+ */
+const sign_pow = (value: number, exp: number): number =>
+  Math.sign(value) * Math.pow(Math.abs(value), exp);
+
+/**
+ * Encodes an image to a BlurHash string
+ * @param pixels Pixel data in RGBA order
+ * @param width Width of the image
+ * @param height Height of the image
+ * @param components_x Number of horizontal components (1-9)
+ * @param components_y Number of vertical components (1-9)
+ */
+export const encode_blurhash = (
+  pixels: Uint8Array | Uint8ClampedArray,
+  width: number,
+  height: number,
+  components_x: number = BLURHASH_PROPS.components_x,
+  components_y: number = BLURHASH_PROPS.components_y
+): string => {
+  if (
+    components_x < 1 ||
+    components_x > 9 ||
+    components_y < 1 ||
+    components_y > 9
+  ) {
+    throw new Error("BlurHash must have between 1 and 9 components");
+  }
+
+  if (pixels.length !== width * height * 4) {
+    throw new Error("Pixel data does not match the dimensions");
+  }
+
+  const factors: [number, number, number][] = [];
+
+  for (let y = 0; y < components_y; y++) {
+    for (let x = 0; x < components_x; x++) {
+      const normalisation = x === 0 && y === 0 ? 1 : 2;
+      let [r, g, b] = [0, 0, 0];
+
+      for (let j = 0; j < height; j++) {
+        for (let i = 0; i < width; i++) {
+          const basis =
+            normalisation *
+            Math.cos((Math.PI * x * i) / width) *
+            Math.cos((Math.PI * y * j) / height);
+          const offset = 4 * (j * width + i);
+
+          r += basis * srgb_to_linear(pixels[offset]);
+          g += basis * srgb_to_linear(pixels[offset + 1]);
+          b += basis * srgb_to_linear(pixels[offset + 2]);
+        }
+      }
+
+      const scale = 1 / (width * height);
+      factors.push([r * scale, g * scale, b * scale]);
+    }
+  }
+
+  const [dc, ...ac] = factors;
+  let hash = encode_base83(components_x - 1 + (components_y - 1) * 9, 1);
+  let maximum_value = 1;
+
+  if (ac.length) {
+    const actual_maximum = Math.max(...ac.flat().map(Math.abs));
+    const quantised_maximum = Math.max(
+      0,
+      Math.min(82, Math.floor(actual_maximum * 166 - 0.5))
+    );
+
+    maximum_value = (quantised_maximum + 1) / 166;
+    hash += encode_base83(quantised_maximum, 1);
+  } else {
+    hash += encode_base83(0, 1);
+  }
+
+  hash += encode_base83(
+    (linear_to_srgb(dc[0]) << 16) +
+      (linear_to_srgb(dc[1]) << 8) +
+      linear_to_srgb(dc[2]),
+    4
+  );
+
+  for (const [r, g, b] of ac) {
+    const quantise = (value: number): number =>
+      Math.max(
+        0,
+        Math.min(18, Math.floor(sign_pow(value / maximum_value, 0.5) * 9 + 9.5))
+      );
+
+    hash += encode_base83(
+      quantise(r) * 19 * 19 + quantise(g) * 19 + quantise(b),
+      2
+    );
+  }
+
+  return hash;
+};
@@ -0,0 +1,157 @@
+import {
+  StoryExportFormat,
+  StoryLicense
+} from "@storiny/proto/dist/story_def/v1/def";
+
+import {
+  ExportableStory,
+  export_blog_anthology,
+  export_story,
+  get_license_notice
+} from "./export-story";
+
+const decoder = new TextDecoder();
+
+const story = {
+  id: "1",
+  slug: "my-story",
+  title: "My story",
+  description: "A story",
+  splash_id: "splash",
+  license: StoryLicense.CC_BY,
+  created_at: "2024-01-01T00:00:00.000Z",
+  created_at_ts: new Date("2024-01-01T00:00:00.000Z"),
+  first_published_at_ts: new Date("2024-02-01T00:00:00.000Z"),
+  published_at_ts: new Date("2024-02-01T00:00:00.000Z"),
+  user: { name: "Jane" },
+  contributors: [{ name: "John" }],
+  tags: [{ name: "rust" }],
+  markdown: "Hello *world*"
+} as unknown as ExportableStory;
+
+const options = {
+  clock: (): Date => new Date("2024-03-01T00:00:00.000Z"),
+  get_image: jest.fn(async () => ({
+    content_type: "image/png",
+    data: new Uint8Array([1, 2, 3])
+  })),
+  get_image_url: (id: string): string => `https://cdn.storiny.com/${id}`,
+  render_pdf: jest.fn(async () => new Uint8Array([37, 80, 68, 70]))
+};
+
+describe("get_license_notice", () => {
+  it("returns the notice for reserved and creative commons licenses", () => {
+    expect(
+      get_license_notice({ ...story, license: StoryLicense.RESERVED })
+    ).toEqual("© 2024 Jane. All rights reserved.");
+    expect(get_license_notice(story)).toEqual(
+      "© 2024 Jane. Licensed under CC BY 4.0 (https://creativecommons.org/licenses/by/4.0/)."
+    );
+  });
+});
+
+describe("export_story", () => {
+  it("exports as markdown with front matter and credits", async () => {
+    const file = await export_story(
+      StoryExportFormat.MARKDOWN,
+      story,
+      options
+    );
+
+    expect(file.filename).toEqual("my-story.md");
+    expect(decoder.decode(file.content)).toEqual(
+      [
+        "---",
+        'title: "My story"',
+        'description: "A story"',
+        'author: "Jane"',
+        'contributors: ["John"]',
+        'tags: ["rust"]',
+        'published_at: "2024-02-01T00:00:00.000Z"',
+        'license: "CC BY 4.0"',
+        "---",
+        "",
+        "# My story",
+        "",
+        "![My story](https://cdn.storiny.com/splash)",
+        "",
+        "Hello *world*",
+        "",
+        "---",
+        "",
+        "With contributions from John.",
+        "",
+        "© 2024 Jane. Licensed under CC BY 4.0 (https://creativecommons.org/licenses/by/4.0/).",
+        ""
+      ].join("\n")
+    );
+  });
+
+  it("exports as a standalone html document", async () => {
+    const file = await export_story(StoryExportFormat.HTML, story, options);
+    const html = decoder.decode(file.content);
+
+    expect(file.content_type).toEqual("text/html; charset=utf-8");
+    expect(html.startsWith("<!DOCTYPE html>")).toBeTrue();
+    expect(html.includes("<style>")).toBeTrue();
+    expect(html.includes("<p>Hello <em>world</em></p>")).toBeTrue();
+  });
+
+  it("exports as epub with the embedded splash image", async () => {
+    const file = await export_story(StoryExportFormat.EPUB, story, options);
+    const archive = decoder.decode(file.content);
+
+    expect(file.filename).toEqual("my-story.epub");
+    // The `mimetype` entry must be the first entry
+    expect(archive.slice(30, 38)).toEqual("mimetype");
+    expect(archive.includes("OEBPS/images/splash.png")).toBeTrue();
+    expect(options.get_image).toHaveBeenCalledWith("splash");
+  });
+
+  it("renders the html document into a pdf document", async () => {
+    const file = await export_story(StoryExportFormat.PDF, story, options);
+
+    expect(file.content_type).toEqual("application/pdf");
+    expect(options.render_pdf).toHaveBeenCalledWith(
+      expect.stringContaining("<h1>My story</h1>")
+    );
+  });
+});
+
+describe("export_blog_anthology", () => {
+  it("includes the published stories ordered by `published_at`", async () => {
+    const file = await export_blog_anthology(
+      { id: "2", slug: "blog", name: "Blog" } as never,
+      [
+        {
+          ...story,
+          id: "3",
+          title: "Later",
+          published_at_ts: new Date("2024-05-01")
+        },
+        { ...story, id: "4", title: "Draft", published_at_ts: undefined },
+        {
+          ...story,
+          id: "5",
+          title: "Earlier",
+          // Deprecated string timestamp
+          published_at: "2024-04-01T00:00:00.000Z",
+          published_at_ts: undefined
+        },
+        {
+          ...story,
+          id: "6",
+          title: "Deleted",
+          deleted_at_ts: new Date("2024-05-02")
+        }
+      ],
+      options
+    );
+    const archive = decoder.decode(file.content);
+
+    expect(file.filename).toEqual("blog.epub");
+    expect(archive.includes("Draft")).toBeFalse();
+    expect(archive.includes("Deleted")).toBeFalse();
+    expect(archive.indexOf("Earlier")).toBeLessThan(archive.indexOf("Later"));
+  });
+});
@@ -0,0 +1,488 @@
+import { BareBlog } from "@storiny/proto/dist/blog_def/v1/def";
+import {
+  GetStoryResponse,
+  StoryExportFormat,
+  StoryLicense
+} from "@storiny/proto/dist/story_def/v1/def";
+
+import { create_zip } from "../create-zip";
+import { escape_html, markdown_to_html } from "../markdown-to-html";
+import { get_timestamp } from "../timestamp-fields";
+
+export type ExportableStory = Pick<
+  GetStoryResponse,
+  | "contributors"
+  | "created_at"
+  | "created_at_ts"
+  | "deleted_at"
+  | "deleted_at_ts"
+  | "description"
+  | "first_published_at"
+  | "first_published_at_ts"
+  | "id"
+  | "license"
+  | "published_at"
+  | "published_at_ts"
+  | "slug"
+  | "splash_id"
+  | "tags"
+  | "title"
+  | "user"
+> & {
+  /**
+   * Content of the document behind `doc_key`, as Markdown
+   */
+  markdown: string;
+};
+
+export interface ExportImage {
+  content_type: string;
+  data: Uint8Array;
+}
+
+export interface ExportedFile {
+  content: Uint8Array;
+  content_type: string;
+  filename: string;
+}
+
+export interface ExportStoryOptions {
+  /**
+   * Returns the current date, used for the EPUB metadata
+   */
+  clock: () => Date;
+  /**
+   * Returns the binary data of an image, embedded into the EPUB files
+   */
+  get_image: (id: string) => Promise<ExportImage | null>;
+  /**
+   * Returns the public URL of an image
+   */
+  get_image_url: (id: string) => string;
+  /**
+   * Renders a standalone HTML document into a PDF document
+   */
+  render_pdf: (html: string) => Promise<Uint8Array>;
+}
+
+/**
+ * Name and deed of each license, used for the license notice
+ */
+export const STORY_LICENSE_NOTICE: Partial<
+  Record<StoryLicense, { name: string; url?: string }>
+> = {
+  [StoryLicense.RESERVED /*    */]: { name: "All rights reserved" },
+  [StoryLicense.CC_ZERO /*     */]: {
+    name: "CC0 1.0 Universal",
+    url: "https://creativecommons.org/publicdomain/zero/1.0/"
+  },
+  [StoryLicense.CC_BY /*       */]: {
+    name: "CC BY 4.0",
+    url: "https://creativecommons.org/licenses/by/4.0/"
+  },
+  [StoryLicense.CC_BY_SA /*    */]: {
+    name: "CC BY-SA 4.0",
+    url: "https://creativecommons.org/licenses/by-sa/4.0/"
+  },
+  [StoryLicense.CC_BY_ND /*    */]: {
+    name: "CC BY-ND 4.0",
+    url: "https://creativecommons.org/licenses/by-nd/4.0/"
+  },
+  [StoryLicense.CC_BY_NC /*    */]: {
+    name: "CC BY-NC 4.0",
+    url: "https://creativecommons.org/licenses/by-nc/4.0/"
+  },
+  [StoryLicense.CC_BY_NC_SA /* */]: {
+    name: "CC BY-NC-SA 4.0",
+    url: "https://creativecommons.org/licenses/by-nc-sa/4.0/"
+  },
+  [StoryLicense.CC_BY_NC_ND /* */]: {
+    name: "CC BY-NC-ND 4.0",
+    url: "https://creativecommons.org/licenses/by-nc-nd/4.0/"
+  }
+};
+
+const IMAGE_EXTENSION: Record<string, string> = {
+  "image/gif": "gif",
+  "image/jpeg": "jpg",
+  "image/png": "png",
+  "image/webp": "webp"
+};
+
+const HTML_STYLES = [
+  "body{margin:0 auto;max-width:680px;padding:32px 16px;",
+  "font:18px/1.6 Georgia,serif;color:#1a1a1a}",
+  "img{max-width:100%;height:auto}",
+  "pre{overflow:auto;padding:12px;background:#f4f4f4}",
+  "blockquote{margin:0;padding-left:16px;border-left:3px solid #ccc}",
+  "footer{margin-top:48px;font-size:14px;color:#666}"
+].join("");
+
+/**
+ * Returns the license notice of a story
+ * @param story Story
+ */
+export const get_license_notice = (story: ExportableStory): string => {
+  const author = story.user?.name || "the writer";
+  const year = (
+    get_timestamp(story, "first_published_at") ||
+    get_timestamp(story, "created_at") ||
+    new Date()
+  ).getUTCFullYear();
+  const license = STORY_LICENSE_NOTICE[story.license];
+
+  if (!license || story.license === StoryLicense.RESERVED) {
+    return `© ${year} ${author}. All rights reserved.`;
+  }
+
+  return `© ${year} ${author}. Licensed under ${license.name}${
+    license.url ? ` (${license.url})` : ""
+  }.`;
+};
+
+/**
+ * Returns the credits line naming the contributors of a story
+ * @param story Story
+ */
+export const get_contributors_line = (
+  story: ExportableStory
+): string | null =>
+  story.contributors.length
+    ? `With contributions from ${story.contributors
+        .map((user) => user.name)
+        .join(", ")}.`
+    : null;
+
+/**
+ * Renders a story as a Markdown document with front matter
+ * @param story Story
+ * @param options Export options
+ */
+export const render_story_markdown = (
+  story: ExportableStory,
+  options: Pick<ExportStoryOptions, "get_image_url">
+): string => {
+  const front_matter = Object.entries({
+    title: story.title,
+    description: story.description,
+    author: story.user?.name,
+    contributors: story.contributors.map((user) => user.name),
+    tags: story.tags.map((tag) => tag.name),
+    published_at: get_timestamp(story, "first_published_at")?.toISOString(),
+    license: STORY_LICENSE_NOTICE[story.license]?.name
+  })
+    .filter(([, value]) => value !== undefined)
+    .map(([key, value]) => `${key}: ${JSON.stringify(value)}`);
+
+  return [
+    `---\n${front_matter.join("\n")}\n---`,
+    `# ${story.title}`,
+    story.splash_id
+      ? `![${story.title}](${options.get_image_url(story.splash_id)})`
+      : null,
+    story.markdown.trim(),
+    "---",
+    get_contributors_line(story),
+    get_license_notice(story)
+  ]
+    .filter((block): block is string => Boolean(block))
+    .join("\n\n")
+    .concat("\n");
+};
+
+/**
+ * Renders the body of a story (without the document wrapper)
+ * @param story Story
+ * @param splash_src Source of the splash image
+ */
+const render_story_body = (
+  story: ExportableStory,
+  splash_src: string | null
+): string => {
+  const contributors = get_contributors_line(story);
+
+  return [
+    `<h1>${escape_html(story.title)}</h1>`,
+    story.user ? `<p>By ${escape_html(story.user.name)}</p>` : null,
+    splash_src
+      ? `<figure><img src="${escape_html(splash_src)}" alt="${escape_html(
+          story.title
+        )}" /></figure>`
+      : null,
+    markdown_to_html(story.markdown),
+    "<footer>",
+    contributors ? `<p>${escape_html(contributors)}</p>` : null,
+    `<p>${escape_html(get_license_notice(story))}</p>`,
+    "</footer>"
+  ]
+    .filter(Boolean)
+    .join("\n");
+};
+
+/**
+ * Renders a story as a standalone HTML document
+ * @param story Story
+ * @param options Export options
+ */
+export const render_story_html = (
+  story: ExportableStory,
+  options: Pick<ExportStoryOptions, "get_image_url">
+): string =>
+  [
+    "<!DOCTYPE html>",
+    '<html lang="en">',
+    "<head>",
+    '<meta charset="utf-8" />',
+    '<meta name="viewport" content="width=device-width, initial-scale=1" />',
+    `<title>${escape_html(story.title)}</title>`,
+    `<style>${HTML_STYLES}</style>`,
+    "</head>",
+    "<body>",
+    render_story_body(
+      story,
+      story.splash_id ? options.get_image_url(story.splash_id) : null
+    ),
+    "</body>",
+    "</html>",
+    ""
+  ].join("\n");
+
+type EpubImage = ExportImage & { path: string };
+
+interface EpubChapter {
+  body: string;
+  image?: EpubImage;
+  title: string;
+}
+
+/**
+ * Wraps a chapter into an XHTML document
+ * @param title Title of the chapter
+ * @param body Body of the chapter
+ */
+const render_xhtml = (title: string, body: string): string =>
+  [
+    '<?xml version="1.0" encoding="utf-8"?>',
+    "<!DOCTYPE html>",
+    '<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops" lang="en" xml:lang="en">',
+    `<head><title>${escape_html(title)}</title></head>`,
+    `<body>\n${body}\n</body>`,
+    "</html>",
+    ""
+  ].join("\n");
+
+/**
+ * Creates an EPUB 3 document from the chapters
+ * @param book Book metadata and chapters
+ */
+export const create_epub = (book: {
+  author: string;
+  chapters: EpubChapter[];
+  id: string;
+  modified: Date;
+  title: string;
+}): Uint8Array => {
+  const images = new Map<string, EpubImage>();
+
+  // Stories can share the same splash image
+  for (const { image } of book.chapters) {
+    if (image) {
+      images.set(image.path, image);
+    }
+  }
+
+  const manifest = [
+    '<item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>',
+    ...book.chapters.map(
+      (_, index) =>
+        `<item id="chapter-${index + 1}" href="chapter-${
+          index + 1
+        }.xhtml" media-type="application/xhtml+xml"/>`
+    ),
+    ...Array.from(images.values()).map(
+      (image, index) =>
+        `<item id="image-${index + 1}" href="${image.path}" media-type="${
+          image.content_type
+        }"/>`
+    )
+  ];
+  const spine = book.chapters.map(
+    (_, index) => `<itemref idref="chapter-${index + 1}"/>`
+  );
+  const nav = book.chapters.map(
+    (chapter, index) =>
+      `<li><a href="chapter-${index + 1}.xhtml">${escape_html(
+        chapter.title
+      )}</a></li>`
+  );
+
+  const entries = [
+    // Must be the first entry
+    { path: "mimetype", data: "application/epub+zip" },
+    {
+      path: "META-INF/container.xml",
+      data: [
+        '<?xml version="1.0" encoding="utf-8"?>',
+        '<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">',
+        '<rootfiles><rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/></rootfiles>',
+        "</container>"
+      ].join("\n")
+    },
+    {
+      path: "OEBPS/content.opf",
+      data: [
+        '<?xml version="1.0" encoding="utf-8"?>',
+        '<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="id">',
+        '<metadata xmlns:dc="http://purl.org/dc/elements/1.1/">',
+        `<dc:identifier id="id">urn:storiny:${escape_html(book.id)}</dc:identifier>`,
+        `<dc:title>${escape_html(book.title)}</dc:title>`,
+        `<dc:creator>${escape_html(book.author)}</dc:creator>`,
+        "<dc:language>en</dc:language>",
+        `<meta property="dcterms:modified">${book.modified
+          .toISOString()
+          .replace(/\.\d{3}Z$/, "Z")}</meta>`,
+        "</metadata>",
+        `<manifest>\n${manifest.join("\n")}\n</manifest>`,
+        `<spine>\n${spine.join("\n")}\n</spine>`,
+        "</package>"
+      ].join("\n")
+    },
+    {
+      path: "OEBPS/nav.xhtml",
+      data: render_xhtml(
+        book.title,
+        `<nav epub:type="toc"><h1>Contents</h1><ol>${nav.join("")}</ol></nav>`
+      )
+    },
+    ...book.chapters.map((chapter, index) => ({
+      path: `OEBPS/chapter-${index + 1}.xhtml`,
+      data: render_xhtml(chapter.title, chapter.body)
+    })),
+    ...Array.from(images.values()).map((image) => ({
+      path: `OEBPS/${image.path}`,
+      data: image.data
+    }))
+  ];
+
+  return create_zip(entries, book.modified);
+};
+
+/**
+ * Creates the EPUB chapter for a story, embedding its splash image
+ * @param story Story
+ * @param options Export options
+ */
+const get_epub_chapter = async (
+  story: ExportableStory,
+  options: Pick<ExportStoryOptions, "get_image">
+): Promise<EpubChapter> => {
+  const splash = story.splash_id
+    ? await options.get_image(story.splash_id)
+    : null;
+  const extension = splash ? IMAGE_EXTENSION[splash.content_type] : undefined;
+  const image =
+    splash && extension
+      ? { ...splash, path: `images/${story.splash_id}.${extension}` }
+      : undefined;
+
+  return {
+    title: story.title,
+    body: render_story_body(story, image?.path || null),
+    image
+  };
+};
+
+/**
+ * Exports a story
+ * @param format Export format
+ * @param story Story
+ * @param options Export options
+ */
+export const export_story = async (
+  format: StoryExportFormat,
+  story: ExportableStory,
+  options: ExportStoryOptions
+): Promise<ExportedFile> => {
+  const name = story.slug || story.id;
+  const encoder = new TextEncoder();
+
+  switch (format) {
+    case StoryExportFormat.MARKDOWN:
+      return {
+        filename: `${name}.md`,
+        content_type: "text/markdown; charset=utf-8",
+        content: encoder.encode(render_story_markdown(story, options))
+      };
+    case StoryExportFormat.HTML:
+      return {
+        filename: `${name}.html`,
+        content_type: "text/html; charset=utf-8",
+        content: encoder.encode(render_story_html(story, options))
+      };
+    case StoryExportFormat.EPUB:
+      return {
+        filename: `${name}.epub`,
+        content_type: "application/epub+zip",
+        content: create_epub({
+          id: story.id,
+          title: story.title,
+          author: story.user?.name || "",
+          modified: options.clock(),
+          chapters: [await get_epub_chapter(story, options)]
+        })
+      };
+    case StoryExportFormat.PDF:
+      return {
+        filename: `${name}.pdf`,
+        content_type: "application/pdf",
+        content: await options.render_pdf(render_story_html(story, options))
+      };
+    default:
+      throw new Error(`Unsupported export format: ${format}`);
+  }
+};
+
+/**
+ * Exports the published stories of a blog as an EPUB anthology, ordered by
+ * `published_at`. Deleted stories are left out.
+ * @param blog Blog
+ * @param stories Stories of the blog
+ * @param options Export options
+ */
+export const export_blog_anthology = async (
+  blog: BareBlog,
+  stories: ExportableStory[],
+  options: Pick<ExportStoryOptions, "clock" | "get_image">
+): Promise<ExportedFile> => {
+  const published = stories
+    .filter(
+      (story) =>
+        get_timestamp(story, "published_at") !== undefined &&
+        get_timestamp(story, "deleted_at") === undefined
+    )
+    .sort(
+      (a, b) =>
+        (get_timestamp(a, "published_at") as Date).getTime() -
+          (get_timestamp(b, "published_at") as Date).getTime() ||
+        a.id.localeCompare(b.id)
+    );
+  const chapters: EpubChapter[] = [];
+
+  for (const story of published) {
+    chapters.push(await get_epub_chapter(story, options));
+  }
+
+  return {
+    filename: `${blog.slug}.epub`,
+    content_type: "application/epub+zip",
+    content: create_epub({
+      id: blog.id,
+      title: blog.name,
+      author: Array.from(
+        new Set(published.map((story) => story.user?.name).filter(Boolean))
+      ).join(", "),
+      modified: options.clock(),
+      chapters
+    })
+  };
+};
@@ -0,0 +1,33 @@
+import { ConnectionProvider as ConnectionProviderProto } from "@storiny/proto/dist/connection_def/v1/def";
+
+import { ConnectionProvider } from "../../enums/connection-provider";
+import { get_connection_provider } from "./get-connection-provider";
+
+describe("get_connection_provider", () => {
+  it("prefers the `provider_type` field", () => {
+    expect(
+      get_connection_provider({
+        provider: "github",
+        provider_type: ConnectionProviderProto.TWITCH
+      })
+    ).toEqual(ConnectionProvider.TWITCH);
+  });
+
+  it("accepts the deprecated slug", () => {
+    expect(
+      get_connection_provider({
+        provider: "github",
+        provider_type: ConnectionProviderProto.UNSPECIFIED
+      })
+    ).toEqual(ConnectionProvider.GITHUB);
+  });
+
+  it("ignores unknown providers", () => {
+    expect(
+      get_connection_provider({
+        provider: "myspace",
+        provider_type: ConnectionProviderProto.UNSPECIFIED
+      })
+    ).toBeUndefined();
+  });
+});
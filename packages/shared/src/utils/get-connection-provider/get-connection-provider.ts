@@ -0,0 +1,22 @@
+import { Connection } from "@storiny/proto/dist/connection_def/v1/def";
+
+import {
+  CONNECTION_PROVIDER_SLUG_MAP,
+  ConnectionProvider
+} from "../../enums/connection-provider";
+
+const SLUGS = new Set<string>(Object.values(ConnectionProvider));
+
+/**
+ * Returns the provider of a connection sent in either of its fields,
+ * preferring the `provider_type` field over the deprecated slug. Unknown
+ * providers are treated as omitted.
+ * @param connection Connection
+ */
+export const get_connection_provider = (
+  connection: Pick<Connection, "provider" | "provider_type">
+): ConnectionProvider | undefined =>
+  CONNECTION_PROVIDER_SLUG_MAP[connection.provider_type] ||
+  (SLUGS.has(connection.provider)
+    ? (connection.provider as ConnectionProvider)
+    : undefined);
@@ -0,0 +1 @@
+export * from "./get-connection-provider";
@@ -0,0 +1,56 @@
+import { get_dominant_color } from "./get-dominant-color";
+
+/**
+ * Returns RGBA pixel data for a list of colors
+ * @param colors Tuples of `[r, g, b, a, count]`
+ */
+const get_pixels = (
+  colors: [number, number, number, number, number][]
+): Uint8ClampedArray =>
+  Uint8ClampedArray.from(
+    colors.flatMap(([r, g, b, a, count]) =>
+      Array.from({ length: count }, () => [r, g, b, a]).flat()
+    )
+  );
+
+describe("get_dominant_color", () => {
+  it("returns the color of a solid image", () => {
+    expect(get_dominant_color(get_pixels([[169, 122, 88, 255, 4]]))).toEqual(
+      "a97a58"
+    );
+  });
+
+  it("returns the most common color", () => {
+    expect(
+      get_dominant_color(
+        get_pixels([
+          [255, 255, 255, 255, 2],
+          [0, 0, 0, 255, 3]
+        ])
+      )
+    ).toEqual("000000");
+  });
+
+  it("averages similar colors", () => {
+    expect(
+      get_dominant_color(
+        get_pixels([
+          [16, 32, 48, 255, 1],
+          [18, 34, 50, 255, 1]
+        ])
+      )
+    ).toEqual("112131");
+  });
+
+  it("ignores transparent pixels", () => {
+    expect(
+      get_dominant_color(
+        get_pixels([
+          [255, 0, 0, 0, 10],
+          [0, 0, 255, 255, 1]
+        ])
+      )
+    ).toEqual("0000ff");
+    expect(get_dominant_color(get_pixels([[0, 0, 0, 0, 1]]))).toBeNull();
+  });
+});
@@ -0,0 +1,52 @@
+/**
+ * Pixels with a lower alpha value are ignored
+ */
+const MIN_ALPHA = 128;
+
+/**
+ * Returns the dominant color of an image as a hex string (without the
+ * leading `#`), or `null` when the image has no opaque pixels. Colors are
+ * bucketed to 4 bits per channel, and the average color of the most
+ * populated bucket is returned.
+ * @param pixels Pixel data in RGBA order
+ */
+export const get_dominant_color = (
+  pixels: Uint8Array | Uint8ClampedArray
+): string | null => {
+  const buckets = new Map<number, [number, number, number, number]>();
+  let dominant: [number, number, number, number] | null = null;
+
+  for (let i = 0; i + 3 < pixels.length; i += 4) {
+    if (pixels[i + 3] < MIN_ALPHA) {
+      continue;
+    }
+
+    const [r, g, b] = [pixels[i], pixels[i + 1], pixels[i + 2]];
+    const key = ((r >> 4) << 8) | ((g >> 4) << 4) | (b >> 4);
+    const bucket = buckets.get(key) || [0, 0, 0, 0];
+
+    bucket[0] += r;
+    bucket[1] += g;
+    bucket[2] += b;
+    bucket[3]++;
+    buckets.set(key, bucket);
+
+    if (!dominant || bucket[3] > dominant[3]) {
+      dominant = bucket;
+    }
+  }
+
+  if (!dominant) {
+    return null;
+  }
+
+  const [r, g, b, count] = dominant;
+
+  return [r, g, b]
+    .map((value) =>
+      Math.round(value / count)
+        .toString(16)
+        .padStart(2, "0")
+    )
+    .join("");
+};
@@ -0,0 +1 @@
+export * from "./get-dominant-color";
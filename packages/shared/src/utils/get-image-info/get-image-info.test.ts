@@ -0,0 +1,81 @@
+import { get_image_info, PNG_SIGNATURE } from "./get-image-info";
+
+/**
+ * Returns the bytes for a list of byte values and ASCII strings
+ * @param parts Parts
+ */
+const bytes = (...parts: (number[] | string)[]): Uint8Array =>
+  Uint8Array.from(
+    parts.flatMap((part) =>
+      typeof part === "string"
+        ? Array.from(part, (char) => char.charCodeAt(0))
+        : part
+    )
+  );
+
+const u16_be = (value: number): number[] => [value >> 8, value & 0xff];
+const u16_le = (value: number): number[] => [value & 0xff, value >> 8];
+const u32_be = (value: number): number[] => [
+  ...u16_be(value >>> 16),
+  ...u16_be(value & 0xffff)
+];
+
+describe("get_image_info", () => {
+  it("reads PNG dimensions", () => {
+    expect(
+      get_image_info(
+        bytes(PNG_SIGNATURE, u32_be(13), "IHDR", u32_be(640), u32_be(480))
+      )
+    ).toEqual({ format: "png", width: 640, height: 480 });
+  });
+
+  it("reads JPEG dimensions from the SOF segment", () => {
+    expect(
+      get_image_info(
+        bytes(
+          [0xff, 0xd8],
+          // APP0 segment
+          [0xff, 0xe0],
+          u16_be(4),
+          [0, 0],
+          // SOF0 segment
+          [0xff, 0xc0],
+          u16_be(11),
+          [8],
+          u16_be(300),
+          u16_be(200),
+          [3, 0, 0, 0]
+        )
+      )
+    ).toEqual({ format: "jpeg", width: 200, height: 300 });
+  });
+
+  it("reads GIF dimensions", () => {
+    expect(get_image_info(bytes("GIF89a", u16_le(32), u16_le(16)))).toEqual({
+      format: "gif",
+      width: 32,
+      height: 16
+    });
+  });
+
+  it("reads WebP dimensions", () => {
+    expect(
+      get_image_info(
+        bytes(
+          "RIFF",
+          [0, 0, 0, 0],
+          "WEBPVP8X",
+          [10, 0, 0, 0],
+          [0, 0, 0, 0],
+          // 1024x768, stored minus one
+          [0xff, 0x03, 0x00, 0xff, 0x02, 0x00]
+        )
+      )
+    ).toEqual({ format: "webp", width: 1024, height: 768 });
+  });
+
+  it("returns `null` for unsupported or truncated images", () => {
+    expect(get_image_info(bytes("%PDF-1.7"))).toBeNull();
+    expect(get_image_info(bytes(PNG_SIGNATURE))).toBeNull();
+  });
+});
@@ -0,0 +1,177 @@
+export type ImageFormat = "gif" | "jpeg" | "png" | "webp";
+
+export interface ImageInfo {
+  format: ImageFormat;
+  height: number;
+  width: number;
+}
+
+export const PNG_SIGNATURE = [0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a];
+
+export const IMAGE_CONTENT_TYPE: Record<ImageFormat, string> = {
+  gif: "image/gif",
+  jpeg: "image/jpeg",
+  png: "image/png",
+  webp: "image/webp"
+};
+
+/**
+ * Predicate function for determining whether the bytes at an offset match a
+ * signature
+ * @param bytes Bytes
+ * @param offset Offset
+ * @param signature Expected bytes or ASCII string
+ */
+export const has_signature = (
+  bytes: Uint8Array,
+  offset: number,
+  signature: number[] | string
+): boolean => {
+  const expected =
+    typeof signature === "string"
+      ? Array.from(signature, (char) => char.charCodeAt(0))
+      : signature;
+
+  return (
+    bytes.length >= offset + expected.length &&
+    expected.every((byte, index) => bytes[offset + index] === byte)
+  );
+};
+
+/**
+ * Returns the image format from its magic bytes
+ * @param bytes Image data
+ */
+export const get_image_format = (bytes: Uint8Array): ImageFormat | null => {
+  if (has_signature(bytes, 0, [0xff, 0xd8, 0xff])) {
+    return "jpeg";
+  }
+
+  if (has_signature(bytes, 0, PNG_SIGNATURE)) {
+    return "png";
+  }
+
+  if (has_signature(bytes, 0, "GIF87a") || has_signature(bytes, 0, "GIF89a")) {
+    return "gif";
+  }
+
+  if (has_signature(bytes, 0, "RIFF") && has_signature(bytes, 8, "WEBP")) {
+    return "webp";
+  }
+
+  return null;
+};
+
+/**
+ * Returns the dimensions of a JPEG image from its first SOF segment
+ * @param bytes Image data
+ * @param view Data view over the image data
+ */
+const get_jpeg_dimensions = (
+  bytes: Uint8Array,
+  view: DataView
+): [number, number] | null => {
+  let offset = 2;
+
+  while (offset + 9 < bytes.length) {
+    if (bytes[offset] !== 0xff) {
+      return null;
+    }
+
+    const marker = bytes[offset + 1];
+
+    // Fill bytes and standalone markers
+    if (
+      marker === 0xff ||
+      marker === 0x01 ||
+      (marker >= 0xd0 && marker <= 0xd7)
+    ) {
+      offset += marker === 0xff ? 1 : 2;
+      continue;
+    }
+
+    // SOF0-SOF15, except DHT (C4), JPG (C8), and DAC (CC)
+    if (
+      marker >= 0xc0 &&
+      marker <= 0xcf &&
+      marker !== 0xc4 &&
+      marker !== 0xc8 &&
+      marker !== 0xcc
+    ) {
+      return [view.getUint16(offset + 7), view.getUint16(offset + 5)];
+    }
+
+    offset += 2 + view.getUint16(offset + 2);
+  }
+
+  return null;
+};
+
+/**
+ * Returns the dimensions of a WebP image
+ * @param bytes Image data
+ * @param view Data view over the image data
+ */
+const get_webp_dimensions = (
+  bytes: Uint8Array,
+  view: DataView
+): [number, number] | null => {
+  if (has_signature(bytes, 12, "VP8 ") && bytes.length >= 30) {
+    return [
+      view.getUint16(26, true) & 0x3fff,
+      view.getUint16(28, true) & 0x3fff
+    ];
+  }
+
+  if (has_signature(bytes, 12, "VP8L") && bytes.length >= 25) {
+    const bits = view.getUint32(21, true);
+    return [(bits & 0x3fff) + 1, ((bits >> 14) & 0x3fff) + 1];
+  }
+
+  if (has_signature(bytes, 12, "VP8X") && bytes.length >= 30) {
+    return [
+      (bytes[24] | (bytes[25] << 8) | (bytes[26] << 16)) + 1,
+      (bytes[27] | (bytes[28] << 8) | (bytes[29] << 16)) + 1
+    ];
+  }
+
+  return null;
+};
+
+/**
+ * Returns the format and the dimensions of an image by reading its header,
+ * or `null` if the image is not supported or malformed
+ * @param bytes Image data
+ */
+export const get_image_info = (bytes: Uint8Array): ImageInfo | null => {
+  const format = get_image_format(bytes);
+  const view = new DataView(bytes.buffer, bytes.byteOffset, bytes.byteLength);
+  let dimensions: [number, number] | null = null;
+
+  switch (format) {
+    case "jpeg":
+      dimensions = get_jpeg_dimensions(bytes, view);
+      break;
+    case "png":
+      dimensions =
+        bytes.length >= 24 && has_signature(bytes, 12, "IHDR")
+          ? [view.getUint32(16), view.getUint32(20)]
+          : null;
+      break;
+    case "gif":
+      dimensions =
+        bytes.length >= 10
+          ? [view.getUint16(6, true), view.getUint16(8, true)]
+          : null;
+      break;
+    case "webp":
+      dimensions = get_webp_dimensions(bytes, view);
+      break;
+  }
+
+  if (!format || !dimensions || !dimensions[0] || !dimensions[1]) {
+    return null;
+  }
+
+  return { format, width: dimensions[0], height: dimensions[1] };
+};
@@ -0,0 +1 @@
+export * from "./get-image-info";
@@ -0,0 +1,176 @@
+import {
+  DeviceType,
+  Login,
+  LoginAlertReason
+} from "@storiny/proto/dist/login_activity_def/v1/def";
+
+import {
+  get_distance,
+  get_login_alert_reasons,
+  should_mail_login_alert
+} from "./get-login-alert-reasons";
+
+const LONDON = { lat: 51.5072, lng: -0.1276 };
+const NEW_YORK = { lat: 40.7128, lng: -74.006 };
+const OXFORD = { lat: 51.752, lng: -1.2577 };
+
+let id = 0;
+
+/**
+ * Creates a synthetic login
+ * @param created_at Login date
+ * @param type Device type
+ * @param location Login location
+ */
+const make_login = (
+  created_at: string,
+  type: DeviceType = DeviceType.COMPUTER,
+  location?: { lat: number; lng: number }
+): Login => ({
+  id: String(++id),
+  device: { display_name: "Device", type },
+  location: { display_name: "Location", ...location },
+  is_active: false,
+  created_at,
+  created_at_ts: new Date(created_at)
+});
+
+describe("get_distance", () => {
+  it("returns the distance between two points", () => {
+    expect(
+      get_distance(LONDON.lat, LONDON.lng, NEW_YORK.lat, NEW_YORK.lng)
+    ).toBeCloseTo(5570, -1);
+  });
+
+  it("returns `0` for the same point", () => {
+    expect(get_distance(LONDON.lat, LONDON.lng, LONDON.lat, LONDON.lng)).toBe(
+      0
+    );
+  });
+});
+
+describe("get_login_alert_reasons", () => {
+  it("returns no reasons for the first login", () => {
+    expect(
+      get_login_alert_reasons(
+        make_login("2023-01-01T12:00:00Z", DeviceType.MOBILE, LONDON),
+        []
+      )
+    ).toEqual([]);
+  });
+
+  it("detects a new device type", () => {
+    const history = [make_login("2023-01-01T12:00:00Z", DeviceType.COMPUTER)];
+
+    expect(
+      get_login_alert_reasons(
+        make_login("2023-01-02T12:00:00Z", DeviceType.TABLET),
+        history
+      )
+    ).toEqual([LoginAlertReason.NEW_DEVICE_TYPE]);
+
+    expect(
+      get_login_alert_reasons(
+        make_login("2023-01-02T12:00:00Z", DeviceType.COMPUTER),
+        history
+      )
+    ).toEqual([]);
+  });
+
+  it("ignores unspecified device types", () => {
+    expect(
+      get_login_alert_reasons(
+        make_login("2023-01-02T12:00:00Z", DeviceType.UNSPECIFIED),
+        [make_login("2023-01-01T12:00:00Z")]
+      )
+    ).toEqual([]);
+  });
+
+  it("detects impossible travel", () => {
+    expect(
+      get_login_alert_reasons(
+        make_login("2023-01-01T13:00:00Z", DeviceType.COMPUTER, NEW_YORK),
+        [make_login("2023-01-01T12:00:00Z", DeviceType.COMPUTER, LONDON)]
+      )
+    ).toEqual([LoginAlertReason.IMPOSSIBLE_TRAVEL]);
+  });
+
+  it("allows travel that is possible in the elapsed time", () => {
+    expect(
+      get_login_alert_reasons(
+        make_login("2023-01-01T22:00:00Z", DeviceType.COMPUTER, NEW_YORK),
+        [make_login("2023-01-01T12:00:00Z", DeviceType.COMPUTER, LONDON)]
+      )
+    ).toEqual([]);
+  });
+
+  it("ignores short distances", () => {
+    expect(
+      get_login_alert_reasons(
+        make_login("2023-01-01T12:01:00Z", DeviceType.COMPUTER, OXFORD),
+        [make_login("2023-01-01T12:00:00Z", DeviceType.COMPUTER, LONDON)]
+      )
+    ).toEqual([]);
+  });
+
+  it("compares against the most recent located login", () => {
+    expect(
+      get_login_alert_reasons(
+        make_login("2023-01-02T12:00:00Z", DeviceType.COMPUTER, NEW_YORK),
+        [
+          make_login("2023-01-01T12:00:00Z", DeviceType.COMPUTER, LONDON),
+          make_login("2023-01-02T06:00:00Z", DeviceType.COMPUTER, NEW_YORK),
+          make_login("2023-01-02T11:00:00Z", DeviceType.COMPUTER)
+        ]
+      )
+    ).toEqual([]);
+  });
+
+  it("detects an unusual login time", () => {
+    const history = Array.from({ length: 10 }, (_, index) =>
+      make_login(`2023-01-${String(index + 1).padStart(2, "0")}T12:00:00Z`)
+    );
+
+    expect(
+      get_login_alert_reasons(make_login("2023-01-20T02:00:00Z"), history)
+    ).toEqual([LoginAlertReason.UNUSUAL_TIME]);
+
+    expect(
+      get_login_alert_reasons(make_login("2023-01-20T14:00:00Z"), history)
+    ).toEqual([]);
+  });
+
+  it("requires enough history for the login time check", () => {
+    expect(
+      get_login_alert_reasons(make_login("2023-01-20T02:00:00Z"), [
+        make_login("2023-01-01T12:00:00Z")
+      ])
+    ).toEqual([]);
+  });
+
+  it("ignores the login itself in the history", () => {
+    const login = make_login("2023-01-01T12:00:00Z", DeviceType.MOBILE);
+
+    expect(
+      get_login_alert_reasons(login, [
+        login,
+        make_login("2022-12-31T12:00:00Z", DeviceType.MOBILE)
+      ])
+    ).toEqual([]);
+  });
+});
+
+describe("should_mail_login_alert", () => {
+  it("returns `true` only when `mail_login_activity` is enabled", () => {
+    expect(
+      should_mail_login_alert([LoginAlertReason.NEW_DEVICE_TYPE], true)
+    ).toBeTrue();
+    expect(
+      should_mail_login_alert([LoginAlertReason.NEW_DEVICE_TYPE], false)
+    ).toBeFalse();
+  });
+
+  it("returns `false` for regular logins", () => {
+    expect(should_mail_login_alert([], true)).toBeFalse();
+  });
+});
@@ -0,0 +1,188 @@
+import {
+  DeviceType,
+  Login,
+  LoginAlertReason
+} from "@storiny/proto/dist/login_activity_def/v1/def";
+
+import { get_timestamp } from "../timestamp-fields";
+
+/**
+ * Mean radius of the Earth (in km)
+ */
+const EARTH_RADIUS = 6371;
+
+export const LOGIN_ALERT_PROPS = {
+  impossible_travel: {
+    // Roughly the cruising speed of a commercial airliner (in km/h)
+    max_speed: 900,
+    // IP-based locations are imprecise, so short hops are never flagged (in km)
+    min_distance: 500
+  },
+  unusual_time: {
+    // Number of past logins required before the login hours are trusted
+    min_history: 10,
+    // Hours on either side of a past login hour that are considered usual
+    window: 2
+  }
+} as const;
+
+/**
+ * Returns the creation date of a login
+ * @param login Login
+ */
+const get_login_date = (login: Login): Date =>
+  get_timestamp(login, "created_at") ?? new Date(0);
+
+/**
+ * Returns the great-circle distance (in km) between two points
+ * @param lat_a Latitude of the first point
+ * @param lng_a Longitude of the first point
+ * @param lat_b Latitude of the second point
+ * @param lng_b Longitude of the second point
+ */
+export const get_distance = (
+  lat_a: number,
+  lng_a: number,
+  lat_b: number,
+  lng_b: number
+): number => {
+  const to_rad = (deg: number): number => (deg * Math.PI) / 180;
+  const d_lat = to_rad(lat_b - lat_a);
+  const d_lng = to_rad(lng_b - lng_a);
+  const a =
+    Math.sin(d_lat / 2) ** 2 +
+    Math.cos(to_rad(lat_a)) *
+      Math.cos(to_rad(lat_b)) *
+      Math.sin(d_lng / 2) ** 2;
+
+  return 2 * EARTH_RADIUS * Math.asin(Math.min(1, Math.sqrt(a)));
+};
+
+/**
+ * Predicate function for determining whether the login was made from a
+ * device type that has never been used by the user before
+ * @param login Login to check
+ * @param history Previous logins of the user
+ */
+const is_new_device_type = (login: Login, history: Login[]): boolean => {
+  const type = login.device?.type;
+
+  if (
+    !history.length ||
+    type === undefined ||
+    type === DeviceType.UNSPECIFIED ||
+    type === DeviceType.UNRECOGNIZED
+  ) {
+    return false;
+  }
+
+  return !history.some((item) => item.device?.type === type);
+};
+
+/**
+ * Predicate function for determining whether the distance between the login
+ * and the most recent located login could not have been covered in the
+ * elapsed time
+ * @param login Login to check
+ * @param history Previous logins of the user
+ */
+const is_impossible_travel = (login: Login, history: Login[]): boolean => {
+  const { lat, lng } = login.location || {};
+
+  if (typeof lat !== "number" || typeof lng !== "number") {
+    return false;
+  }
+
+  const created_at = get_login_date(login).getTime();
+  const previous = history
+    .filter(
+      (item) =>
+        typeof item.location?.lat === "number" &&
+        typeof item.location?.lng === "number" &&
+        get_login_date(item).getTime() <= created_at
+    )
+    .sort(
+      (a, b) =>
+        get_login_date(b).getTime() - get_login_date(a).getTime()
+    )[0];
+
+  if (!previous) {
+    return false;
+  }
+
+  const distance = get_distance(
+    previous.location!.lat!,
+    previous.location!.lng!,
+    lat,
+    lng
+  );
+
+  if (distance < LOGIN_ALERT_PROPS.impossible_travel.min_distance) {
+    return false;
+  }
+
+  const hours = (created_at - get_login_date(previous).getTime()) / 36e5;
+
+  return (
+    hours <= 0 ||
+    distance / hours > LOGIN_ALERT_PROPS.impossible_travel.max_speed
+  );
+};
+
+/**
+ * Predicate function for determining whether the login was made at an hour
+ * (UTC) that is far from every hour the user has logged in before
+ * @param login Login to check
+ * @param history Previous logins of the user
+ */
+const is_unusual_time = (login: Login, history: Login[]): boolean => {
+  if (history.length < LOGIN_ALERT_PROPS.unusual_time.min_history) {
+    return false;
+  }
+
+  const hour = get_login_date(login).getUTCHours();
+
+  return !history.some((item) => {
+    const diff = Math.abs(get_login_date(item).getUTCHours() - hour);
+    return Math.min(diff, 24 - diff) <= LOGIN_ALERT_PROPS.unusual_time.window;
+  });
+};
+
+/**
+ * Returns the reasons for which a login should be considered suspicious. An
+ * empty array is returned for regular logins.
+ * @param login The new login
+ * @param history Previous logins of the user
+ */
+export const get_login_alert_reasons = (
+  login: Login,
+  history: Login[]
+): LoginAlertReason[] => {
+  const past = history.filter((item) => item.id !== login.id);
+  const reasons: LoginAlertReason[] = [];
+
+  if (is_new_device_type(login, past)) {
+    reasons.push(LoginAlertReason.NEW_DEVICE_TYPE);
+  }
+
+  if (is_impossible_travel(login, past)) {
+    reasons.push(LoginAlertReason.IMPOSSIBLE_TRAVEL);
+  }
+
+  if (is_unusual_time(login, past)) {
+    reasons.push(LoginAlertReason.UNUSUAL_TIME);
+  }
+
+  return reasons;
+};
+
+/**
+ * Predicate function for determining whether an alert mail should be sent
+ * for the login
+ * @param reasons Reasons returned by `get_login_alert_reasons`
+ * @param mail_login_activity The `mail_login_activity` notification setting of the user
+ */
+export const should_mail_login_alert = (
+  reasons: LoginAlertReason[],
+  mail_login_activity: boolean
+): boolean => mail_login_activity && reasons.length > 0;
@@ -0,0 +1 @@
+export * from "./get-login-alert-reasons";
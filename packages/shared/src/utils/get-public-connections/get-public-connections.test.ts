@@ -0,0 +1,39 @@
+import { ConnectionProvider } from "@storiny/proto/dist/connection_def/v1/def";
+
+import { get_public_connections } from "./get-public-connections";
+
+describe("get_public_connections", () => {
+  it("omits hidden connections and private fields", () => {
+    expect(
+      get_public_connections([
+        {
+          id: "0",
+          provider: "github",
+          provider_type: ConnectionProvider.GITHUB,
+          hidden: false,
+          display_name: "storiny",
+          url: "https://github.com/storiny",
+          created_at: "2023-01-01T00:00:00Z",
+          created_at_ts: new Date("2023-01-01T00:00:00Z")
+        },
+        {
+          id: "1",
+          provider: "spotify",
+          provider_type: ConnectionProvider.SPOTIFY,
+          hidden: true,
+          display_name: "storiny",
+          url: "https://open.spotify.com/user/storiny",
+          created_at: "2023-01-01T00:00:00Z",
+          created_at_ts: new Date("2023-01-01T00:00:00Z")
+        }
+      ])
+    ).toEqual([
+      {
+        provider: "github",
+        provider_type: ConnectionProvider.GITHUB,
+        display_name: "storiny",
+        url: "https://github.com/storiny"
+      }
+    ]);
+  });
+});
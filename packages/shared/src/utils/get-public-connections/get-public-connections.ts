@@ -0,0 +1,21 @@
+import {
+  Connection,
+  ConnectionSetting
+} from "@storiny/proto/dist/connection_def/v1/def";
+
+/**
+ * Returns the connections that can be displayed on the public profile of a
+ * user. Hidden connections are never included.
+ * @param connections Connections of the user
+ */
+export const get_public_connections = (
+  connections: ConnectionSetting[]
+): Connection[] =>
+  connections
+    .filter((connection) => !connection.hidden)
+    .map(({ provider, provider_type, url, display_name }) => ({
+      provider,
+      provider_type,
+      url,
+      display_name
+    }));
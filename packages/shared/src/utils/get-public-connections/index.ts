@@ -0,0 +1 @@
+export * from "./get-public-connections";
@@ -0,0 +1,87 @@
+import {
+  decode_html_entities,
+  html_to_markdown,
+  sanitize_html
+} from "./html-to-markdown";
+
+describe("decode_html_entities", () => {
+  it("decodes named and numeric entities", () => {
+    expect(decode_html_entities("&lt;a&gt; &amp; &#39;b&#x27; &copy;")).toEqual(
+      "<a> & 'b' &copy;"
+    );
+  });
+});
+
+describe("sanitize_html", () => {
+  it("removes scripts, embeds, comments, and event handlers", () => {
+    expect(
+      sanitize_html(
+        '<p onclick="steal()">Text<script>alert(1)</script></p>' +
+          '<iframe src="https://example.com"></iframe><!-- note -->'
+      )
+    ).toEqual("<p>Text</p>");
+  });
+
+  it("removes unsafe URLs", () => {
+    expect(
+      sanitize_html(
+        '<a href="javascript:alert(1)">a</a><a href="https://example.com">b</a>'
+      )
+    ).toEqual('<a>a</a><a href="https://example.com">b</a>');
+  });
+});
+
+describe("html_to_markdown", () => {
+  it("converts the inline formatting", () => {
+    expect(
+      html_to_markdown(
+        '<p>A <strong>bold</strong>, <em>italic</em>, <del>old</del>, <code>x</code>, and <a href="https://example.com">link</a></p>'
+      )
+    ).toEqual(
+      "A **bold**, *italic*, ~~old~~, `x`, and [link](https://example.com)"
+    );
+  });
+
+  it("maps the headings onto the supported levels", () => {
+    expect(html_to_markdown("<h1>Title</h1><h4>Section</h4>")).toEqual(
+      "## Title\n\n### Section"
+    );
+  });
+
+  it("converts lists, quotes, rules, and images", () => {
+    expect(
+      html_to_markdown(
+        "<ul><li>One</li><li>Two</li></ul><ol><li>First</li></ol>" +
+          "<blockquote><p>Quote</p></blockquote><hr>" +
+          '<img src="https://example.com/image.png" alt="Image">'
+      )
+    ).toEqual(
+      [
+        "- One",
+        "- Two",
+        "",
+        "1. First",
+        "",
+        "> Quote",
+        "",
+        "---",
+        "",
+        "![Image](https://example.com/image.png)"
+      ].join("\n")
+    );
+  });
+
+  it("keeps code blocks verbatim", () => {
+    expect(
+      html_to_markdown("<pre><code>if (a &lt; b) {\n  run();\n}</code></pre>")
+    ).toEqual("```\nif (a < b) {\n  run();\n}\n```");
+  });
+
+  it("drops unsafe content", () => {
+    expect(
+      html_to_markdown(
+        '<p><a href="javascript:alert(1)">Click</a><script>alert(1)</script></p>'
+      )
+    ).toEqual("Click");
+  });
+});
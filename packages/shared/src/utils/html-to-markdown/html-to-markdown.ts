@@ -0,0 +1,181 @@
+const BLOCK_ELEMENTS = [
+  "article",
+  "div",
+  "figcaption",
+  "figure",
+  "header",
+  "p",
+  "section"
+];
+
+const REMOVED_ELEMENTS = [
+  "button",
+  "embed",
+  "form",
+  "iframe",
+  "noscript",
+  "object",
+  "script",
+  "style",
+  "svg",
+  "template"
+];
+
+const ENTITIES: Record<string, string> = {
+  amp: "&",
+  apos: "'",
+  gt: ">",
+  lt: "<",
+  nbsp: " ",
+  quot: '"'
+};
+
+/**
+ * Decodes the named and numeric HTML entities
+ * @param value Value
+ */
+export const decode_html_entities = (value: string): string =>
+  value.replace(/&(#x[\da-f]+|#\d+|[a-z]+);/gi, (match, entity: string) => {
+    if (entity[0] === "#") {
+      const code =
+        entity[1].toLowerCase() === "x"
+          ? parseInt(entity.slice(2), 16)
+          : parseInt(entity.slice(1), 10);
+
+      return Number.isNaN(code) ? match : String.fromCodePoint(code);
+    }
+
+    return ENTITIES[entity.toLowerCase()] ?? match;
+  });
+
+/**
+ * Returns the value of an attribute from the opening tag of an element
+ * @param tag Opening tag
+ * @param name Name of the attribute
+ */
+const get_attribute = (tag: string, name: string): string | undefined => {
+  const match = new RegExp(
+    `\\s${name}\\s*=\\s*(?:"([^"]*)"|'([^']*)'|([^\\s>]+))`,
+    "i"
+  ).exec(tag);
+
+  return match
+    ? decode_html_entities(match[1] ?? match[2] ?? match[3])
+    : undefined;
+};
+
+/**
+ * Predicate function for determining whether a URL is safe to keep
+ * @param url URL
+ */
+const is_safe_url = (url: string): boolean =>
+  /^(https?:|mailto:|\/|#)/i.test(url.trim());
+
+/**
+ * Sanitizes untrusted HTML by removing the executable and embedded elements,
+ * comments, event handlers, and unsafe URLs
+ * @param html HTML
+ */
+export const sanitize_html = (html: string): string =>
+  html
+    .replace(/<!--[\s\S]*?-->/g, "")
+    .replace(
+      new RegExp(
+        `<(${REMOVED_ELEMENTS.join("|")})\\b[\\s\\S]*?<\\/\\1\\s*>`,
+        "gi"
+      ),
+      ""
+    )
+    .replace(
+      new RegExp(`<\\/?(${REMOVED_ELEMENTS.join("|")})\\b[^>]*>`, "gi"),
+      ""
+    )
+    .replace(/\s+on[a-z]+\s*=\s*("[^"]*"|'[^']*'|[^\s>]+)/gi, "")
+    .replace(
+      /\s(href|src)\s*=\s*("[^"]*"|'[^']*'|[^\s>]+)/gi,
+      (match, _name: string, value: string) =>
+        is_safe_url(decode_html_entities(value.replace(/^["']|["']$/g, "")))
+          ? match
+          : ""
+    );
+
+/**
+ * Strips every tag, keeping the text content
+ * @param html HTML
+ */
+const strip_tags = (html: string): string => html.replace(/<[^>]*>/g, "");
+
+/**
+ * Converts sanitized HTML into the Markdown subset supported by the editor
+ * @param html HTML
+ */
+export const html_to_markdown = (html: string): string => {
+  const code_blocks: string[] = [];
+  let markdown = sanitize_html(html)
+    // Code blocks are kept verbatim, so they are swapped with placeholders
+    .replace(/<pre\b[^>]*>([\s\S]*?)<\/pre>/gi, (_, content: string) => {
+      code_blocks.push(decode_html_entities(strip_tags(content)).trim());
+      return `\n\n\u0000${code_blocks.length - 1}\u0000\n\n`;
+    })
+    .replace(/\s*\n\s*/g, " ")
+    .replace(/<h([1-6])\b[^>]*>([\s\S]*?)<\/h\1>/gi, (_, level, content) => {
+      // The editor only supports two heading levels
+      const depth = Number(level) <= 2 ? 2 : 3;
+      return `\n\n${"#".repeat(depth)} ${strip_tags(content).trim()}\n\n`;
+    })
+    .replace(/<(strong|b)\b[^>]*>([\s\S]*?)<\/\1>/gi, "**$2**")
+    .replace(/<(em|i)\b[^>]*>([\s\S]*?)<\/\1>/gi, "*$2*")
+    .replace(/<(s|del|strike)\b[^>]*>([\s\S]*?)<\/\1>/gi, "~~$2~~")
+    .replace(/<code\b[^>]*>([\s\S]*?)<\/code>/gi, "`$1`")
+    .replace(/<a\b([^>]*)>([\s\S]*?)<\/a>/gi, (match, attributes, content) => {
+      const href = get_attribute(attributes, "href");
+      const text = strip_tags(content).trim();
+      return href ? `[${text}](${href})` : text;
+    })
+    .replace(/<img\b[^>]*>/gi, (tag) => {
+      const src = get_attribute(tag, "src");
+      return src
+        ? `\n\n![${get_attribute(tag, "alt") || ""}](${src})\n\n`
+        : "";
+    })
+    .replace(/<br\s*\/?>/gi, "\n")
+    .replace(/<hr\b[^>]*>/gi, "\n\n---\n\n")
+    .replace(/<(ul|ol)\b[^>]*>([\s\S]*?)<\/\1>/gi, (_, type, content) => {
+      let index = 0;
+      const items = content
+        .split(/<li\b[^>]*>/i)
+        .slice(1)
+        .map((item: string) => strip_tags(item).trim())
+        .filter(Boolean)
+        .map((item: string) =>
+          type.toLowerCase() === "ol" ? `${++index}. ${item}` : `- ${item}`
+        );
+
+      return `\n\n${items.join("\n")}\n\n`;
+    })
+    .replace(
+      /<blockquote\b[^>]*>([\s\S]*?)<\/blockquote>/gi,
+      (_, content: string) =>
+        `\n\n${strip_tags(content)
+          .trim()
+          .split("\n")
+          .map((line) => `> ${line.trim()}`)
+          .join("\n")}\n\n`
+    )
+    .replace(
+      new RegExp(`<\\/?(${BLOCK_ELEMENTS.join("|")})\\b[^>]*>`, "gi"),
+      "\n\n"
+    );
+
+  markdown = decode_html_entities(strip_tags(markdown))
+    .split("\n")
+    .map((line) => line.replace(/[ \t]+/g, " ").trim())
+    .join("\n")
+    .replace(/\n{3,}/g, "\n\n")
+    .trim();
+
+  return markdown.replace(
+    /\u0000(\d+)\u0000/g,
+    (_, index) => `\`\`\`\n${code_blocks[Number(index)]}\n\`\`\``
+  );
+};
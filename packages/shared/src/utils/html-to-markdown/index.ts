@@ -0,0 +1 @@
+export * from "./html-to-markdown";
@@ -0,0 +1,233 @@
+import { StoryImportFormat } from "@storiny/proto/dist/story_def/v1/def";
+
+import {
+  import_html,
+  import_markdown,
+  import_medium_archive,
+  import_stories,
+  import_substack_archive,
+  parse_csv,
+  parse_front_matter
+} from "./import-story";
+
+describe("parse_front_matter", () => {
+  it("parses strings and lists", () => {
+    expect(
+      parse_front_matter(
+        [
+          "---",
+          'title: "A \\"quoted\\" title"',
+          "tags: [travel, 'food']",
+          "keywords:",
+          "  - one",
+          "  - two",
+          "---",
+          "Body"
+        ].join("\n")
+      )
+    ).toEqual({
+      data: {
+        title: 'A "quoted" title',
+        tags: ["travel", "food"],
+        keywords: ["one", "two"]
+      },
+      body: "Body"
+    });
+  });
+
+  it("returns the source without front matter", () => {
+    expect(parse_front_matter("# Title")).toEqual({
+      data: {},
+      body: "# Title"
+    });
+  });
+});
+
+describe("import_markdown", () => {
+  it("maps the front matter onto the draft", () => {
+    expect(
+      import_markdown(
+        [
+          "---",
+          "title: Trip",
+          "description: Notes from the trip",
+          "tags: [Travel, Road Trip, travel, '#1']",
+          "canonical_url: https://example.com/trip",
+          "date: 2020-05-01",
+          "---",
+          "",
+          "Content"
+        ].join("\n")
+      )
+    ).toEqual({
+      title: "Trip",
+      story: {
+        title: "Trip",
+        description: "Notes from the trip",
+        tags: ["travel", "road-trip", "1"],
+        canonical_url: "https://example.com/trip",
+        published_at: "2020-05-01T00:00:00.000Z",
+        markdown: "Content"
+      }
+    });
+  });
+
+  it("reads the title from the first heading", () => {
+    expect(import_markdown("# Title\n\nContent").story).toEqual(
+      expect.objectContaining({ title: "Title", markdown: "Content" })
+    );
+  });
+
+  it("rejects documents without a title or content", () => {
+    expect(import_markdown("Content").error).toEqual("missing_title");
+    expect(import_markdown("---\ntitle: Title\n---\n").error).toEqual(
+      "empty_content"
+    );
+  });
+
+  it("drops invalid canonical URLs", () => {
+    expect(
+      import_markdown("---\ntitle: A\ncanonical_url: javascript:x\n---\nB")
+        .story?.canonical_url
+    ).toBeUndefined();
+  });
+});
+
+describe("import_html", () => {
+  it("reads the metadata and sanitizes the body", () => {
+    const result = import_html(
+      [
+        "<html><head><title>Title</title>",
+        '<meta name="description" content="Summary">',
+        '<meta name="keywords" content="one, two">',
+        '<link rel="canonical" href="https://example.com/post">',
+        '<meta property="article:published_time" content="2021-01-01T10:00:00Z">',
+        "</head><body><p>Hello</p><script>alert(1)</script></body></html>"
+      ].join("")
+    );
+
+    expect(result.story).toEqual({
+      title: "Title",
+      description: "Summary",
+      tags: ["one", "two"],
+      canonical_url: "https://example.com/post",
+      published_at: "2021-01-01T10:00:00.000Z",
+      markdown: "Hello"
+    });
+  });
+});
+
+describe("import_medium_archive", () => {
+  it("imports the posts and drafts", () => {
+    const post = (title: string): string =>
+      [
+        `<html><head><title>${title}</title></head><body><article>`,
+        `<header><h1 class="p-name">${title}</h1></header>`,
+        '<section data-field="subtitle" class="p-summary">Subtitle</section>',
+        '<section data-field="body" class="e-content"><section class="section">',
+        `<h3 class="graf graf--h3 graf--title">${title}</h3><p>Body</p>`,
+        "</section></section><footer>",
+        '<time class="dt-published" datetime="2019-02-03T04:05:06.000Z">Date</time>',
+        '<a href="https://medium.com/@writer/post" class="p-canonical">Link</a>',
+        "</footer></article></body></html>"
+      ].join("");
+
+    const results = import_medium_archive({
+      "posts/2019-02-03_Post-1.html": post("Post"),
+      "posts/draft_Draft-2.html": post("Draft"),
+      "profile/profile.html": "<html></html>"
+    });
+
+    expect(results).toEqual([
+      {
+        title: "Post",
+        story: {
+          title: "Post",
+          description: "Subtitle",
+          tags: [],
+          canonical_url: "https://medium.com/@writer/post",
+          published_at: "2019-02-03T04:05:06.000Z",
+          markdown: "Body"
+        }
+      },
+      expect.objectContaining({
+        title: "Draft",
+        story: expect.objectContaining({ published_at: undefined })
+      })
+    ]);
+  });
+});
+
+describe("parse_csv", () => {
+  it("parses quoted fields", () => {
+    expect(parse_csv('a,b\n"x, ""y""",z\r\n')).toEqual([
+      { a: 'x, "y"', b: "z" }
+    ]);
+  });
+});
+
+describe("import_substack_archive", () => {
+  const FILES = {
+    "posts.csv": [
+      "post_id,post_date,is_published,type,title,subtitle",
+      "1.first-post,2022-03-04T05:06:07.000Z,true,newsletter,First,Sub",
+      "2.draft,,false,newsletter,Draft,",
+      "3.episode,2022-03-05T00:00:00.000Z,true,podcast,Episode,"
+    ].join("\n"),
+    "posts/1.first-post.html": "<p>First body</p>",
+    "posts/2.draft.html": "<p>Draft body</p>"
+  };
+
+  it("imports the posts with canonical URLs", () => {
+    expect(
+      import_substack_archive(FILES, "https://writer.substack.com/")
+    ).toEqual([
+      {
+        title: "First",
+        story: {
+          title: "First",
+          description: "Sub",
+          tags: [],
+          canonical_url: "https://writer.substack.com/p/first-post",
+          published_at: "2022-03-04T05:06:07.000Z",
+          markdown: "First body"
+        }
+      },
+      {
+        title: "Draft",
+        story: {
+          title: "Draft",
+          description: undefined,
+          tags: [],
+          canonical_url: undefined,
+          published_at: undefined,
+          markdown: "Draft body"
+        }
+      }
+    ]);
+  });
+
+  it("rejects archives without `posts.csv`", () => {
+    expect(import_substack_archive({})).toBeNull();
+  });
+});
+
+describe("import_stories", () => {
+  it("rejects mismatched sources", () => {
+    expect(import_stories(StoryImportFormat.MARKDOWN, {})).toEqual(
+      "unsupported_format"
+    );
+    expect(import_stories(StoryImportFormat.MEDIUM_ARCHIVE, "")).toEqual(
+      "invalid_archive"
+    );
+    expect(import_stories(StoryImportFormat.UNSPECIFIED, "")).toEqual(
+      "unsupported_format"
+    );
+  });
+
+  it("rejects empty archives", () => {
+    expect(import_stories(StoryImportFormat.MEDIUM_ARCHIVE, {})).toEqual(
+      "invalid_archive"
+    );
+  });
+});
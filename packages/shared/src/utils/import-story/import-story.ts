@@ -0,0 +1,450 @@
+import { StoryImportFormat } from "@storiny/proto/dist/story_def/v1/def";
+
+import { MAX_STORY_TAGS, STORY_PROPS } from "../../constants/story";
+import { TAG_NAME_REGEX } from "../../constants/tag";
+import { decode_html_entities, html_to_markdown } from "../html-to-markdown";
+
+export type FrontMatter = Record<string, string | string[]>;
+
+/**
+ * Story parsed from an import source, mapped onto the new draft
+ */
+export interface ImportedStoryContent {
+  canonical_url?: string;
+  description?: string;
+  /**
+   * Content of the story, converted into the document behind `doc_key`
+   */
+  markdown: string;
+  /**
+   * Original publish date
+   */
+  published_at?: string;
+  tags: string[];
+  title: string;
+}
+
+export type ImportStoryError =
+  | "empty_content"
+  | "invalid_archive"
+  | "missing_title"
+  | "unsupported_format";
+
+export type ImportStoryResult =
+  | { error: ImportStoryError; story?: undefined; title: string }
+  | { error?: undefined; story: ImportedStoryContent; title: string };
+
+/**
+ * Parses a front matter value, supporting quoted strings and inline lists
+ * @param value Raw value
+ */
+const parse_front_matter_value = (value: string): string | string[] => {
+  const trimmed = value.trim();
+
+  if (trimmed.startsWith("[") && trimmed.endsWith("]")) {
+    return trimmed
+      .slice(1, -1)
+      .split(",")
+      .map((item) => parse_front_matter_value(item) as string)
+      .filter(Boolean);
+  }
+
+  if (/^".*"$/.test(trimmed)) {
+    try {
+      return JSON.parse(trimmed);
+    } catch {
+      return trimmed.slice(1, -1);
+    }
+  }
+
+  return /^'.*'$/.test(trimmed) ? trimmed.slice(1, -1) : trimmed;
+};
+
+/**
+ * Splits the front matter from a Markdown document
+ * @param source Markdown document
+ */
+export const parse_front_matter = (
+  source: string
+): { body: string; data: FrontMatter } => {
+  const match = /^\uFEFF?---\r?\n([\s\S]*?)\r?\n---[ \t]*(?:\r?\n|$)/.exec(
+    source
+  );
+
+  if (!match) {
+    return { data: {}, body: source };
+  }
+
+  const data: FrontMatter = {};
+  let list_key: string | null = null;
+
+  for (const line of match[1].split(/\r?\n/)) {
+    const item = /^\s*-\s+(.*)$/.exec(line);
+
+    if (item && list_key) {
+      (data[list_key] as string[]).push(
+        parse_front_matter_value(item[1]) as string
+      );
+      continue;
+    }
+
+    const entry = /^([\w-]+)\s*:\s*(.*)$/.exec(line);
+
+    if (!entry) {
+      continue;
+    }
+
+    const [, key, value] = entry;
+
+    if (value.trim()) {
+      data[key] = parse_front_matter_value(value);
+      list_key = null;
+    } else {
+      // Block list on the following lines
+      data[key] = [];
+      list_key = key;
+    }
+  }
+
+  return { data, body: source.slice(match[0].length) };
+};
+
+/**
+ * Normalizes a tag from another platform into a valid tag name
+ * @param tag Tag
+ */
+const normalize_tag = (tag: string): string =>
+  tag
+    .trim()
+    .toLowerCase()
+    .replace(/[\s_]+/g, "-")
+    .replace(/[^a-z0-9-]/g, "")
+    .replace(/-{2,}/g, "-")
+    .replace(/^-|-$/g, "");
+
+/**
+ * Returns the valid and unique tags, up to `MAX_STORY_TAGS`
+ * @param tags Tags
+ */
+const normalize_tags = (tags: string | string[] | undefined): string[] =>
+  Array.from(
+    new Set(
+      (typeof tags === "string" ? tags.split(",") : tags || [])
+        .map(normalize_tag)
+        .filter((tag) => TAG_NAME_REGEX.test(tag))
+    )
+  ).slice(0, MAX_STORY_TAGS);
+
+/**
+ * Returns the date as an ISO string, or `undefined` if it is invalid
+ * @param value Date
+ */
+const normalize_date = (
+  value: string | string[] | undefined
+): string | undefined => {
+  if (typeof value !== "string" || !value.trim()) {
+    return undefined;
+  }
+
+  const date = new Date(value.trim());
+  return Number.isNaN(date.getTime()) ? undefined : date.toISOString();
+};
+
+/**
+ * Returns the value if it is a non-empty string
+ * @param value Value
+ */
+const as_string = (value: string | string[] | undefined): string | undefined =>
+  typeof value === "string" && value.trim() ? value.trim() : undefined;
+
+/**
+ * Predicate function for determining whether a canonical URL can be stored
+ * @param url URL
+ */
+const is_valid_canonical_url = (url: string): boolean =>
+  /^https?:\/\//i.test(url) &&
+  url.length <= STORY_PROPS.canonical_url.max_length;
+
+/**
+ * Builds the result for a parsed story, validating the title and content
+ * @param story Parsed story
+ */
+const to_result = (story: ImportedStoryContent): ImportStoryResult => {
+  const title = story.title
+    .trim()
+    .slice(0, STORY_PROPS.title.max_length)
+    .trim();
+
+  if (!title) {
+    return { title, error: "missing_title" };
+  }
+
+  if (!story.markdown.trim()) {
+    return { title, error: "empty_content" };
+  }
+
+  return {
+    title,
+    story: {
+      ...story,
+      title,
+      markdown: story.markdown.trim(),
+      description: story.description?.slice(
+        0,
+        STORY_PROPS.description.max_length
+      ),
+      canonical_url:
+        story.canonical_url && is_valid_canonical_url(story.canonical_url)
+          ? story.canonical_url
+          : undefined
+    }
+  };
+};
+
+/**
+ * Imports a Markdown document. The metadata is read from the front matter,
+ * falling back to the first top-level heading for the title.
+ * @param source Markdown document
+ */
+export const import_markdown = (source: string): ImportStoryResult => {
+  const { data, body } = parse_front_matter(source);
+  let markdown = body.trim();
+  let title = as_string(data.title);
+
+  if (!title) {
+    const heading = /^#\s+(.+)$/m.exec(markdown);
+
+    if (heading && markdown.startsWith(heading[0])) {
+      title = heading[1].trim();
+      markdown = markdown.slice(heading[0].length);
+    }
+  }
+
+  return to_result({
+    title: title || "",
+    description: as_string(data.description) || as_string(data.subtitle),
+    tags: normalize_tags(data.tags || data.keywords),
+    canonical_url: as_string(data.canonical_url) || as_string(data.canonical),
+    published_at: normalize_date(
+      data.published_at || data.date || data.published
+    ),
+    markdown
+  });
+};
+
+/**
+ * Returns the text content of the first element matching the pattern
+ * @param html HTML
+ * @param pattern Pattern with the content in the first group
+ */
+const match_text = (html: string, pattern: RegExp): string | undefined => {
+  const match = pattern.exec(html);
+  return match
+    ? decode_html_entities(match[1].replace(/<[^>]*>/g, "")).trim() ||
+        undefined
+    : undefined;
+};
+
+/**
+ * Imports a sanitized HTML document, reading the metadata from the `<title>`
+ * and `<meta>` elements
+ * @param source HTML document
+ */
+export const import_html = (source: string): ImportStoryResult => {
+  const meta = (name: string): string | undefined =>
+    match_text(
+      source,
+      new RegExp(
+        `<meta[^>]+(?:name|property)=["']${name}["']` +
+          `[^>]+content=["']([^"']*)["']`,
+        "i"
+      )
+    );
+  const body = /<body\b[^>]*>([\s\S]*)<\/body>/i.exec(source)?.[1] ?? source;
+
+  return to_result({
+    title:
+      match_text(source, /<title\b[^>]*>([\s\S]*?)<\/title>/i) ||
+      match_text(body, /<h1\b[^>]*>([\s\S]*?)<\/h1>/i) ||
+      "",
+    description: meta("description"),
+    tags: normalize_tags(meta("keywords")),
+    canonical_url: match_text(
+      source,
+      /<link[^>]+rel=["']canonical["'][^>]+href=["']([^"']*)["']/i
+    ),
+    published_at: normalize_date(meta("article:published_time")),
+    markdown: html_to_markdown(body)
+  });
+};
+
+/**
+ * Imports the posts from a Medium export archive. Each post is stored as an
+ * HTML file under `posts/`, with the drafts prefixed by `draft_`.
+ * @param files Files of the unzipped archive, keyed by their path
+ */
+export const import_medium_archive = (
+  files: Record<string, string>
+): ImportStoryResult[] =>
+  Object.keys(files)
+    .filter((path) => /^posts\/[^/]+\.html$/.test(path))
+    .sort()
+    .map((path) => {
+      const html = files[path];
+      const body = (
+        /<section data-field="body"[^>]*>([\s\S]*?)<\/section>\s*<footer/i.exec(
+          html
+        )?.[1] ?? ""
+      )
+        // The title is repeated as the first heading of the body
+        .replace(/<h3[^>]*graf--title[^>]*>[\s\S]*?<\/h3>/i, "");
+
+      return to_result({
+        title:
+          match_text(html, /<h1 class="p-name">([\s\S]*?)<\/h1>/i) ||
+          match_text(html, /<title>([\s\S]*?)<\/title>/i) ||
+          "",
+        description: match_text(
+          html,
+          /<section data-field="subtitle"[^>]*>([\s\S]*?)<\/section>/i
+        ),
+        tags: [],
+        canonical_url: match_text(
+          html,
+          /<a href="([^"]*)" class="p-canonical"/i
+        ),
+        published_at: path.startsWith("posts/draft_")
+          ? undefined
+          : normalize_date(
+              match_text(html, /<time class="dt-published" datetime="([^"]*)"/i)
+            ),
+        markdown: html_to_markdown(body)
+      });
+    });
+
+/**
+ * Parses a CSV document into rows keyed by the header
+ * @param csv CSV document
+ */
+export const parse_csv = (csv: string): Record<string, string>[] => {
+  const rows: string[][] = [[]];
+  let field = "";
+  let quoted = false;
+
+  for (let i = 0; i < csv.length; i++) {
+    const char = csv[i];
+
+    if (quoted) {
+      if (char === '"' && csv[i + 1] === '"') {
+        field += '"';
+        i++;
+      } else if (char === '"') {
+        quoted = false;
+      } else {
+        field += char;
+      }
+    } else if (char === '"') {
+      quoted = true;
+    } else if (char === ",") {
+      rows[rows.length - 1].push(field);
+      field = "";
+    } else if (char === "\n") {
+      rows[rows.length - 1].push(field.replace(/\r$/, ""));
+      rows.push([]);
+      field = "";
+    } else {
+      field += char;
+    }
+  }
+
+  rows[rows.length - 1].push(field);
+
+  const [header, ...records] = rows.filter(
+    (row) => row.length > 1 || row[0] !== ""
+  );
+
+  return (records || []).map((record) =>
+    Object.fromEntries(
+      (header || []).map((key, index) => [key, record[index] ?? ""])
+    )
+  );
+};
+
+/**
+ * Imports the posts from a Substack export archive. The metadata is stored in
+ * `posts.csv`, and the content of each post in `posts/<post_id>.html`.
+ * @param files Files of the unzipped archive, keyed by their path
+ * @param publication_url URL of the publication, used to derive the canonical
+ * URLs of the posts
+ */
+export const import_substack_archive = (
+  files: Record<string, string>,
+  publication_url?: string
+): ImportStoryResult[] | null => {
+  if (typeof files["posts.csv"] !== "string") {
+    return null;
+  }
+
+  const base_url = publication_url?.replace(/\/+$/, "");
+
+  return parse_csv(files["posts.csv"])
+    .filter((post) => post.post_id && post.type !== "podcast")
+    .map((post) => {
+      const slug = post.post_id.split(".").slice(1).join(".");
+      const is_published = post.is_published === "true";
+
+      return to_result({
+        title: post.title || "",
+        description: post.subtitle || undefined,
+        tags: [],
+        canonical_url:
+          base_url && slug && is_published
+            ? `${base_url}/p/${slug}`
+            : undefined,
+        published_at: is_published ? normalize_date(post.post_date) : undefined,
+        markdown: html_to_markdown(files[`posts/${post.post_id}.html`] || "")
+      });
+    });
+};
+
+/**
+ * Imports the stories from a source, returning a result for each story
+ * @param format Format of the source
+ * @param source Document, or the files of the unzipped archive keyed by their
+ * path
+ * @param publication_url URL of the Substack publication
+ */
+export const import_stories = (
+  format: StoryImportFormat,
+  source: string | Record<string, string>,
+  publication_url?: string
+): ImportStoryResult[] | ImportStoryError => {
+  switch (format) {
+    case StoryImportFormat.MARKDOWN:
+    case StoryImportFormat.HTML:
+      if (typeof source !== "string") {
+        return "unsupported_format";
+      }
+
+      return [
+        format === StoryImportFormat.MARKDOWN
+          ? import_markdown(source)
+          : import_html(source)
+      ];
+    case StoryImportFormat.MEDIUM_ARCHIVE:
+    case StoryImportFormat.SUBSTACK_ARCHIVE: {
+      if (typeof source === "string") {
+        return "invalid_archive";
+      }
+
+      const results =
+        format === StoryImportFormat.MEDIUM_ARCHIVE
+          ? import_medium_archive(source)
+          : import_substack_archive(source, publication_url);
+
+      return results && results.length ? results : "invalid_archive";
+    }
+    default:
+      return "unsupported_format";
+  }
+};
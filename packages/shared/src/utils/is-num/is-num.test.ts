@@ -9,7 +9,7 @@ describe("is_num", () => {
 
   [0, 10, 25, "", "x"].map(String).forEach((test_case) => {
     it(`returns \`false\` for \`${test_case}\` (string)`, () => {
-      expect(is_num(test_case)).toBeTrue();
+      expect(is_num(test_case)).toBeFalse();
     });
   });
 });
@@ -0,0 +1 @@
+export * from "./markdown-to-html";
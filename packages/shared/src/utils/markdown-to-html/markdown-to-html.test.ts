@@ -0,0 +1,63 @@
+import { escape_html, markdown_to_html } from "./markdown-to-html";
+
+describe("escape_html", () => {
+  it("escapes the special characters", () => {
+    expect(escape_html(`<a href="x">'&'</a>`)).toEqual(
+      "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+    );
+  });
+});
+
+describe("markdown_to_html", () => {
+  it("renders paragraphs with inline formatting", () => {
+    expect(
+      markdown_to_html(
+        "A **bold**, *italic*, ~~old~~, `x < y`, and [link](https://example.com)\nNext line\n\nSecond"
+      )
+    ).toEqual(
+      '<p>A <strong>bold</strong>, <em>italic</em>, <s>old</s>, <code>x &lt; y</code>, and <a href="https://example.com">link</a><br />Next line</p>\n<p>Second</p>'
+    );
+  });
+
+  it("renders headings, lists, quotes, rules, and code blocks", () => {
+    expect(
+      markdown_to_html(
+        [
+          "# Heading",
+          "### Subheading",
+          "- One",
+          "- Two",
+          "1. First",
+          "2. Second",
+          "> Quote",
+          "---",
+          "```",
+          "<b>code</b>",
+          "```"
+        ].join("\n")
+      )
+    ).toEqual(
+      [
+        "<h2>Heading</h2>",
+        "<h3>Subheading</h3>",
+        "<ul><li>One</li><li>Two</li></ul>",
+        "<ol><li>First</li><li>Second</li></ol>",
+        "<blockquote><p>Quote</p></blockquote>",
+        "<hr />",
+        "<pre><code>&lt;b&gt;code&lt;/b&gt;</code></pre>"
+      ].join("\n")
+    );
+  });
+
+  it("renders images", () => {
+    expect(markdown_to_html("![Alt](https://example.com/image.png)")).toEqual(
+      '<p><img src="https://example.com/image.png" alt="Alt" /></p>'
+    );
+  });
+
+  it("escapes raw HTML and drops unsafe URLs", () => {
+    expect(
+      markdown_to_html("<script>x</script> [a](javascript:void)")
+    ).toEqual("<p>&lt;script&gt;x&lt;/script&gt; a</p>");
+  });
+});
@@ -0,0 +1,161 @@
+const HTML_ENTITIES: Record<string, string> = {
+  "&": "&amp;",
+  "<": "&lt;",
+  ">": "&gt;",
+  '"': "&quot;",
+  "'": "&#39;"
+};
+
+/**
+ * Escapes the HTML special characters in a string
+ * @param value String
+ */
+export const escape_html = (value: string): string =>
+  value.replace(/[&<>"']/g, (char) => HTML_ENTITIES[char]);
+
+/**
+ * Matches inline code, images, and links (in that order of precedence).
+ * Applied to the escaped source.
+ */
+const TOKEN_REGEX =
+  /`([^`\n]+)`|!\[([^\]\n]*)\]\(([^\s)]+)\)|\[([^\]\n]+)\]\(([^\s)]+)\)/g;
+
+/**
+ * Predicate function for determining whether a URL is safe to render
+ * @param url Escaped URL
+ */
+const is_safe_url = (url: string): boolean =>
+  /^(https?:\/\/|mailto:)/i.test(url);
+
+/**
+ * Renders the emphasis in a plain (escaped) text segment
+ * @param value Escaped text
+ */
+const render_emphasis = (value: string): string =>
+  value
+    .replace(/\*\*([^*\n]+)\*\*/g, "<strong>$1</strong>")
+    .replace(/~~([^~\n]+)~~/g, "<s>$1</s>")
+    .replace(/\*([^*\n]+)\*/g, "<em>$1</em>");
+
+/**
+ * Renders the inline formatting of a block
+ * @param value Raw text
+ */
+const render_inline = (value: string): string => {
+  const escaped = escape_html(value);
+  let result = "";
+  let cursor = 0;
+
+  for (const match of escaped.matchAll(TOKEN_REGEX)) {
+    const [token, code, alt, src, text, href] = match;
+    result += render_emphasis(escaped.slice(cursor, match.index));
+    cursor = (match.index || 0) + token.length;
+
+    if (code !== undefined) {
+      result += `<code>${code}</code>`;
+    } else if (src !== undefined) {
+      result += is_safe_url(src)
+        ? `<img src="${src}" alt="${alt}" />`
+        : alt;
+    } else {
+      result += is_safe_url(href)
+        ? `<a href="${href}">${render_emphasis(text)}</a>`
+        : render_emphasis(text);
+    }
+  }
+
+  return result + render_emphasis(escaped.slice(cursor));
+};
+
+/**
+ * Converts the Markdown subset supported by the editor into XHTML compatible
+ * markup, escaping any raw HTML in the source
+ * @param markdown Markdown
+ */
+export const markdown_to_html = (markdown: string): string => {
+  const lines = markdown.replace(/\r\n?/g, "\n").split("\n");
+  const blocks: string[] = [];
+  let paragraph: string[] = [];
+
+  const flush_paragraph = (): void => {
+    if (paragraph.length) {
+      blocks.push(`<p>${paragraph.map(render_inline).join("<br />")}</p>`);
+      paragraph = [];
+    }
+  };
+
+  for (let i = 0; i < lines.length; i++) {
+    const line = lines[i];
+    const trimmed = line.trim();
+
+    if (!trimmed) {
+      flush_paragraph();
+      continue;
+    }
+
+    if (trimmed.startsWith("```")) {
+      flush_paragraph();
+      const code: string[] = [];
+
+      while (++i < lines.length && !lines[i].trim().startsWith("```")) {
+        code.push(lines[i]);
+      }
+
+      blocks.push(`<pre><code>${escape_html(code.join("\n"))}</code></pre>`);
+      continue;
+    }
+
+    const heading = /^(#{1,6})\s+(.*)$/.exec(trimmed);
+
+    if (heading) {
+      flush_paragraph();
+      // The editor only supports two heading levels
+      const tag = heading[1].length <= 2 ? "h2" : "h3";
+      blocks.push(`<${tag}>${render_inline(heading[2])}</${tag}>`);
+      continue;
+    }
+
+    if (/^(-{3,}|\*{3,})$/.test(trimmed)) {
+      flush_paragraph();
+      blocks.push("<hr />");
+      continue;
+    }
+
+    const list = /^([-*]|\d+\.)\s+/.exec(trimmed);
+
+    if (list || trimmed.startsWith(">")) {
+      flush_paragraph();
+      const is_quote = !list;
+      const is_ordered = Boolean(list && list[1].endsWith("."));
+      const pattern = is_quote
+        ? /^>\s?/
+        : is_ordered
+          ? /^\d+\.\s+/
+          : /^[-*]\s+/;
+      const items: string[] = [];
+
+      for (; i < lines.length && pattern.test(lines[i].trim()); i++) {
+        items.push(render_inline(lines[i].trim().replace(pattern, "")));
+      }
+
+      i--;
+
+      if (is_quote) {
+        blocks.push(`<blockquote><p>${items.join("<br />")}</p></blockquote>`);
+      } else {
+        const tag = is_ordered ? "ol" : "ul";
+        blocks.push(
+          `<${tag}>${items.map((item) => `<li>${item}</li>`).join("")}</${tag}>`
+        );
+      }
+
+      continue;
+    }
+
+    paragraph.push(trimmed);
+  }
+
+  flush_paragraph();
+
+  return blocks.join("\n");
+};
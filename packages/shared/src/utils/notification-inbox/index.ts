@@ -0,0 +1 @@
+export * from "./notification-inbox";
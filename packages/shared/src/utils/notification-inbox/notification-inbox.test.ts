@@ -0,0 +1,162 @@
+import { Notification } from "@storiny/proto/dist/notification_def/v1/def";
+
+import { NotificationType } from "../../enums/notification-type";
+import {
+  decode_notification_cursor,
+  encode_notification_cursor,
+  get_notification_limit,
+  has_valid_notification_payload,
+  InvalidNotificationCursorError,
+  NOTIFICATION_INBOX_PROPS,
+  paginate_notifications
+} from "./notification-inbox";
+
+const get_notification = (
+  id: string,
+  created_at: string,
+  read = false
+): Notification =>
+  ({
+    id,
+    created_at: new Date(created_at),
+    type: NotificationType.FOLLOWER_ADD,
+    read_at: read ? new Date(created_at) : undefined,
+    new_follower: {}
+  }) as unknown as Notification;
+
+const get_ids = (notifications: Notification[]): string[] =>
+  notifications.map((notification) => notification.id);
+
+const NOTIFICATIONS = [
+  get_notification("5", "2026-01-05T00:00:00.000Z"),
+  get_notification("4", "2026-01-04T00:00:00.000Z", true),
+  get_notification("3b", "2026-01-03T00:00:00.000Z"),
+  get_notification("3a", "2026-01-03T00:00:00.000Z", true),
+  get_notification("2", "2026-01-02T00:00:00.000Z"),
+  get_notification("1", "2026-01-01T00:00:00.000Z", true)
+];
+
+describe("has_valid_notification_payload", () => {
+  it("accepts the payload matching the type", () => {
+    expect(has_valid_notification_payload(NOTIFICATIONS[0])).toBeTrue();
+  });
+
+  it("rejects a missing payload", () => {
+    expect(
+      has_valid_notification_payload({
+        ...NOTIFICATIONS[0],
+        new_follower: undefined
+      })
+    ).toBeFalse();
+  });
+
+  it("rejects a payload for a different category", () => {
+    expect(
+      has_valid_notification_payload({
+        ...NOTIFICATIONS[0],
+        new_follower: undefined,
+        story_like: { story_id: "1", story_slug: "a", story_title: "A" }
+      })
+    ).toBeFalse();
+  });
+
+  it("rejects multiple payloads", () => {
+    expect(
+      has_valid_notification_payload({
+        ...NOTIFICATIONS[0],
+        login_activity: { login_id: "1" }
+      })
+    ).toBeFalse();
+  });
+});
+
+describe("get_notification_limit", () => {
+  it("uses the default limit when omitted", () => {
+    expect(get_notification_limit(0)).toEqual(
+      NOTIFICATION_INBOX_PROPS.default_limit
+    );
+  });
+
+  it("clamps the limit", () => {
+    expect(get_notification_limit(1000)).toEqual(
+      NOTIFICATION_INBOX_PROPS.max_limit
+    );
+  });
+});
+
+describe("notification_cursor", () => {
+  it("round-trips a cursor", () => {
+    const cursor = {
+      created_at: new Date("2026-01-01T00:00:00.000Z"),
+      id: "1"
+    };
+    expect(
+      decode_notification_cursor(encode_notification_cursor(cursor))
+    ).toEqual(cursor);
+  });
+
+  it("returns `null` for malformed cursors", () => {
+    expect(decode_notification_cursor("invalid")).toBeNull();
+    expect(
+      decode_notification_cursor(
+        Buffer.from(JSON.stringify(["invalid", "1"])).toString("base64url")
+      )
+    ).toBeNull();
+  });
+});
+
+describe("paginate_notifications", () => {
+  it("walks through every notification exactly once", () => {
+    const seen: string[] = [];
+    let cursor: string | undefined;
+
+    do {
+      const page = paginate_notifications(NOTIFICATIONS, {
+        cursor,
+        limit: 2,
+        unread_only: false
+      });
+
+      seen.push(...get_ids(page.notifications));
+      cursor = page.next_cursor;
+    } while (cursor);
+
+    expect(seen).toEqual(["5", "4", "3b", "3a", "2", "1"]);
+  });
+
+  it("filters read notifications", () => {
+    const page = paginate_notifications(NOTIFICATIONS, {
+      limit: 2,
+      unread_only: true
+    });
+
+    expect(get_ids(page.notifications)).toEqual(["5", "3b"]);
+    expect(page.next_cursor).toBeDefined();
+
+    const next = paginate_notifications(NOTIFICATIONS, {
+      cursor: page.next_cursor,
+      limit: 2,
+      unread_only: true
+    });
+
+    expect(get_ids(next.notifications)).toEqual(["2"]);
+    expect(next.next_cursor).toBeUndefined();
+  });
+
+  it("rejects malformed cursors", () => {
+    const truncated = encode_notification_cursor({
+      created_at: new Date("2026-01-04T00:00:00.000Z"),
+      id: "4"
+    }).slice(0, -4);
+
+    for (const cursor of ["invalid", truncated]) {
+      expect(() =>
+        paginate_notifications(NOTIFICATIONS, {
+          cursor,
+          limit: 2,
+          unread_only: false
+        })
+      ).toThrow(InvalidNotificationCursorError);
+    }
+  });
+});
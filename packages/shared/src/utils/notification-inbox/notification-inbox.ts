@@ -0,0 +1,180 @@
+import { Notification } from "@storiny/proto/dist/notification_def/v1/def";
+
+import { NotificationType } from "../../enums/notification-type";
+
+export type NotificationPayloadKey = Exclude<
+  keyof Notification,
+  "id" | "type" | "actor" | "read_at" | "created_at"
+>;
+
+export interface NotificationCursor {
+  /**
+   * Creation date of the last notification on the previous page
+   */
+  created_at: Date;
+  /**
+   * ID of the last notification on the previous page, used to break ties
+   */
+  id: string;
+}
+
+/**
+ * The pagination cursor is malformed or has been tampered with. Handlers
+ * respond with `INVALID_ARGUMENT`.
+ */
+export class InvalidNotificationCursorError extends Error {
+  constructor() {
+    super("Invalid notification cursor");
+    this.name = "InvalidNotificationCursorError";
+  }
+}
+
+export const NOTIFICATION_INBOX_PROPS = {
+  default_limit: 20,
+  max_limit: 50
+} as const;
+
+/**
+ * Payload carried by the notifications of each type
+ */
+export const NOTIFICATION_PAYLOAD_KEY: Record<
+  NotificationType,
+  NotificationPayloadKey
+> = {
+  [NotificationType.SYSTEM /*             */]: "features_and_updates",
+  [NotificationType.LOGIN_ATTEMPT /*      */]: "login_activity",
+  [NotificationType.FRIEND_REQ_ACCEPT /*  */]: "friend_request",
+  [NotificationType.FRIEND_REQ_RECEIVED /**/]: "friend_request",
+  [NotificationType.FOLLOWER_ADD /*       */]: "new_follower",
+  [NotificationType.COMMENT_ADD /*        */]: "comment",
+  [NotificationType.REPLY_ADD /*          */]: "reply",
+  [NotificationType.STORY_MENTION /*      */]: "story",
+  [NotificationType.STORY_LIKE /*         */]: "story_like",
+  [NotificationType.STORY_ADD_BY_USER /*  */]: "story",
+  [NotificationType.STORY_ADD_BY_TAG /*   */]: "tag",
+  [NotificationType.COLLAB_REQ_ACCEPT /*  */]: "collaboration_request",
+  [NotificationType.COLLAB_REQ_RECEIVED /**/]: "collaboration_request",
+  [NotificationType.BLOG_EDITOR_INVITE /* */]: "blog_request",
+  [NotificationType.BLOG_WRITER_INVITE /* */]: "blog_request"
+};
+
+/**
+ * Predicate function for determining whether a notification carries the
+ * payload expected for its type (and no other payload)
+ * @param notification Notification
+ */
+export const has_valid_notification_payload = (
+  notification: Notification
+): boolean => {
+  const expected = NOTIFICATION_PAYLOAD_KEY[
+    notification.type as unknown as NotificationType
+  ] as NotificationPayloadKey | undefined;
+
+  if (!expected || notification[expected] === undefined) {
+    return false;
+  }
+
+  return (
+    Object.values(NOTIFICATION_PAYLOAD_KEY).filter(
+      (key) => notification[key] !== undefined
+    ).length === 1
+  );
+};
+
+/**
+ * Clamps the page size requested by the client
+ * @param limit Requested limit (`0` when omitted)
+ */
+export const get_notification_limit = (limit: number): number =>
+  limit <= 0
+    ? NOTIFICATION_INBOX_PROPS.default_limit
+    : Math.min(Math.floor(limit), NOTIFICATION_INBOX_PROPS.max_limit);
+
+/**
+ * Encodes an opaque pagination cursor
+ * @param cursor Cursor
+ */
+export const encode_notification_cursor = (
+  cursor: NotificationCursor
+): string =>
+  Buffer.from(
+    JSON.stringify([cursor.created_at.toISOString(), cursor.id])
+  ).toString("base64url");
+
+/**
+ * Decodes a pagination cursor, returning `null` for malformed values
+ * @param value Encoded cursor
+ */
+export const decode_notification_cursor = (
+  value: string
+): NotificationCursor | null => {
+  try {
+    const decoded: unknown = JSON.parse(
+      Buffer.from(value, "base64url").toString("utf8")
+    );
+
+    if (
+      !Array.isArray(decoded) ||
+      decoded.length !== 2 ||
+      typeof decoded[0] !== "string" ||
+      typeof decoded[1] !== "string" ||
+      Number.isNaN(Date.parse(decoded[0]))
+    ) {
+      return null;
+    }
+
+    return { created_at: new Date(decoded[0]), id: decoded[1] };
+  } catch {
+    return null;
+  }
+};
+
+/**
+ * Returns the next page of notifications from a list sorted newest first,
+ * along with the cursor for the page after it
+ * @param notifications Notifications sorted by `created_at` (newest first),
+ * with ties broken by `id` (descending)
+ * @param options Page options. Throws `InvalidNotificationCursorError` for
+ * malformed cursors.
+ */
+export const paginate_notifications = (
+  notifications: Notification[],
+  options: { cursor?: string; limit: number; unread_only: boolean }
+): { next_cursor?: string; notifications: Notification[] } => {
+  const limit = get_notification_limit(options.limit);
+  const cursor =
+    options.cursor === undefined
+      ? null
+      : decode_notification_cursor(options.cursor);
+
+  if (options.cursor !== undefined && !cursor) {
+    throw new InvalidNotificationCursorError();
+  }
+
+  const after = cursor
+    ? notifications.filter((notification) => {
+        const created_at = notification.created_at?.getTime() ?? 0;
+        return (
+          created_at < cursor.created_at.getTime() ||
+          (created_at === cursor.created_at.getTime() &&
+            notification.id < cursor.id)
+        );
+      })
+    : notifications;
+  const filtered = options.unread_only
+    ? after.filter((notification) => notification.read_at === undefined)
+    : after;
+  const page = filtered.slice(0, limit);
+  const last = page[page.length - 1];
+
+  return {
+    notifications: page,
+    next_cursor:
+      filtered.length > limit && last
+        ? encode_notification_cursor({
+            created_at: last.created_at ?? new Date(0),
+            id: last.id
+          })
+        : undefined
+  };
+};
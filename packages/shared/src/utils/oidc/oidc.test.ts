@@ -0,0 +1,207 @@
+import { generateKeyPairSync, KeyObject, sign } from "crypto";
+import { createServer, Server } from "http";
+import { AddressInfo } from "net";
+
+import {
+  discover_oidc_configuration,
+  exchange_authorization_code,
+  OidcConfiguration,
+  verify_id_token
+} from "./oidc";
+
+const CLIENT = { client_id: "storiny", client_secret: "secret" };
+const REDIRECT_URI = "https://storiny.com/auth/callback";
+const CODE = "authorization-code";
+
+/**
+ * Mock OpenID Connect issuer serving the discovery document, the JWKS, and
+ * the token endpoint
+ */
+class MockIssuer {
+  readonly private_key: KeyObject;
+  readonly server: Server;
+  claims: Record<string, unknown> = {};
+  issuer = "";
+
+  constructor() {
+    const { privateKey, publicKey } = generateKeyPairSync("rsa", {
+      modulusLength: 2048
+    });
+    this.private_key = privateKey;
+
+    this.server = createServer((req, res) => {
+      const send = (status: number, body: unknown): void => {
+        res.writeHead(status, { "Content-Type": "application/json" });
+        res.end(JSON.stringify(body));
+      };
+
+      if (req.url === "/.well-known/openid-configuration") {
+        return send(200, {
+          issuer: this.issuer,
+          authorization_endpoint: `${this.issuer}/authorize`,
+          token_endpoint: `${this.issuer}/token`,
+          jwks_uri: `${this.issuer}/jwks`
+        });
+      }
+
+      if (req.url === "/jwks") {
+        return send(200, {
+          keys: [{ ...publicKey.export({ format: "jwk" }), kid: "key" }]
+        });
+      }
+
+      if (req.url === "/token" && req.method === "POST") {
+        let body = "";
+        req.on("data", (chunk) => (body += chunk));
+        req.on("end", () => {
+          const params = new URLSearchParams(body);
+
+          if (
+            params.get("code") !== CODE ||
+            params.get("client_id") !== CLIENT.client_id ||
+            params.get("client_secret") !== CLIENT.client_secret ||
+            params.get("redirect_uri") !== REDIRECT_URI
+          ) {
+            return send(400, { error: "invalid_grant" });
+          }
+
+          send(200, { id_token: this.sign_token(this.claims) });
+        });
+
+        return;
+      }
+
+      send(404, {});
+    });
+  }
+
+  /**
+   * Starts the issuer on a random port
+   */
+  async start(): Promise<void> {
+    await new Promise<void>((resolve) => this.server.listen(0, resolve));
+    const { port } = this.server.address() as AddressInfo;
+    this.issuer = `http://127.0.0.1:${port}`;
+  }
+
+  /**
+   * Signs an ID token
+   * @param claims Token claims
+   * @param private_key Signing key
+   */
+  sign_token(
+    claims: Record<string, unknown>,
+    private_key: KeyObject = this.private_key
+  ): string {
+    const encode = (value: unknown): string =>
+      Buffer.from(JSON.stringify(value)).toString("base64url");
+    const now = Math.floor(Date.now() / 1000);
+    const input = [
+      encode({ alg: "RS256", kid: "key" }),
+      encode({
+        iss: this.issuer,
+        aud: CLIENT.client_id,
+        sub: "provider-user",
+        email: "someone@storiny.com",
+        iat: now,
+        exp: now + 600,
+        ...claims
+      })
+    ].join(".");
+    const signature = sign("sha256", Buffer.from(input), private_key);
+
+    return `${input}.${signature.toString("base64url")}`;
+  }
+}
+
+describe("oidc", () => {
+  const issuer = new MockIssuer();
+  let configuration: OidcConfiguration;
+
+  beforeAll(async () => {
+    await issuer.start();
+    configuration = await discover_oidc_configuration(issuer.issuer);
+  });
+
+  afterAll(() => {
+    issuer.server.close();
+  });
+
+  beforeEach(() => {
+    issuer.claims = {};
+  });
+
+  it("discovers the provider configuration", () => {
+    expect(configuration.token_endpoint).toEqual(`${issuer.issuer}/token`);
+  });
+
+  it("rejects a configuration for another issuer", async () => {
+    await expect(
+      discover_oidc_configuration(`${issuer.issuer}/other`)
+    ).rejects.toThrow();
+  });
+
+  it("exchanges an authorization code and verifies the ID token", async () => {
+    issuer.claims = { nonce: "nonce" };
+
+    const id_token = await exchange_authorization_code(configuration, CLIENT, {
+      code: CODE,
+      redirect_uri: REDIRECT_URI
+    });
+    const claims = await verify_id_token(id_token, configuration, {
+      client_id: CLIENT.client_id,
+      nonce: "nonce"
+    });
+
+    expect(claims.sub).toEqual("provider-user");
+    expect(claims.email).toEqual("someone@storiny.com");
+  });
+
+  it("rejects an invalid authorization code", async () => {
+    await expect(
+      exchange_authorization_code(configuration, CLIENT, {
+        code: "invalid",
+        redirect_uri: REDIRECT_URI
+      })
+    ).rejects.toThrow();
+  });
+
+  it("rejects a token signed using another key", async () => {
+    const { privateKey } = generateKeyPairSync("rsa", { modulusLength: 2048 });
+
+    await expect(
+      verify_id_token(
+        issuer.sign_token({ nonce: "nonce" }, privateKey),
+        configuration,
+        { client_id: CLIENT.client_id, nonce: "nonce" }
+      )
+    ).rejects.toThrow("Invalid signature");
+  });
+
+  it("requires a nonce", async () => {
+    await expect(
+      verify_id_token(issuer.sign_token({}), configuration, {
+        client_id: CLIENT.client_id,
+        nonce: ""
+      })
+    ).rejects.toThrow("Missing nonce");
+  });
+
+  [
+    [{ aud: "other" }, "Audience mismatch"],
+    [{ iss: "https://example.com" }, "Issuer mismatch"],
+    [{ exp: 0 }, "ID token has expired"],
+    [{ nonce: "other" }, "Nonce mismatch"],
+    [{}, "Nonce mismatch"]
+  ].forEach(([claims, message]) => {
+    it(`rejects a token with \`${JSON.stringify(claims)}\``, async () => {
+      await expect(
+        verify_id_token(
+          issuer.sign_token(claims as Record<string, unknown>),
+          configuration,
+          { client_id: CLIENT.client_id, nonce: "nonce" }
+        )
+      ).rejects.toThrow(message as string);
+    });
+  });
+});
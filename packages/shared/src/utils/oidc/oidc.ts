@@ -0,0 +1,194 @@
+import { LoginProvider } from "@storiny/proto/dist/credential_settings_def/v1/def";
+import { createPublicKey, JsonWebKey, verify } from "crypto";
+
+type Jwk = JsonWebKey & { kid?: string };
+
+export interface OidcConfiguration {
+  authorization_endpoint: string;
+  issuer: string;
+  jwks_uri: string;
+  token_endpoint: string;
+}
+
+export interface OidcClient {
+  client_id: string;
+  client_secret: string;
+}
+
+export interface IdTokenClaims {
+  aud: string | string[];
+  email?: string;
+  email_verified?: boolean;
+  exp: number;
+  iat: number;
+  iss: string;
+  nonce?: string;
+  sub: string;
+}
+
+/**
+ * Issuers of the OpenID Connect login providers
+ */
+export const LOGIN_PROVIDER_ISSUER: Partial<Record<LoginProvider, string>> = {
+  [LoginProvider.APPLE]: "https://appleid.apple.com",
+  [LoginProvider.GOOGLE]: "https://accounts.google.com"
+};
+
+/**
+ * Allowed clock skew (in seconds) while validating the token timestamps
+ */
+const CLOCK_SKEW = 60;
+
+/**
+ * Fetches a JSON document, throwing for non-2xx responses
+ * @param input Request URL
+ * @param init Request options
+ */
+const fetch_json = async <T>(input: string, init?: RequestInit): Promise<T> => {
+  const res = await fetch(input, init);
+
+  if (!res.ok) {
+    throw new Error(`Request to ${input} failed with status ${res.status}`);
+  }
+
+  return res.json() as Promise<T>;
+};
+
+/**
+ * Fetches the OpenID provider configuration of an issuer
+ * @param issuer Issuer URL
+ */
+export const discover_oidc_configuration = async (
+  issuer: string
+): Promise<OidcConfiguration> => {
+  const configuration = await fetch_json<OidcConfiguration>(
+    `${issuer.replace(/\/$/, "")}/.well-known/openid-configuration`
+  );
+
+  if (configuration.issuer !== issuer) {
+    throw new Error("Issuer mismatch");
+  }
+
+  return configuration;
+};
+
+/**
+ * Exchanges an authorization code for an ID token
+ * @param configuration Provider configuration
+ * @param client Client credentials
+ * @param params Authorization code and the redirect URI used to obtain it
+ */
+export const exchange_authorization_code = async (
+  configuration: OidcConfiguration,
+  client: OidcClient,
+  { code, redirect_uri }: { code: string; redirect_uri: string }
+): Promise<string> => {
+  const { id_token } = await fetch_json<{ id_token?: string }>(
+    configuration.token_endpoint,
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/x-www-form-urlencoded" },
+      body: new URLSearchParams({
+        grant_type: "authorization_code",
+        code,
+        redirect_uri,
+        client_id: client.client_id,
+        client_secret: client.client_secret
+      }).toString()
+    }
+  );
+
+  if (!id_token) {
+    throw new Error("Token response does not contain an ID token");
+  }
+
+  return id_token;
+};
+
+/**
+ * Verifies the signature and claims of an ID token and returns the claims.
+ * The token must carry the nonce sent with the authorization request, so that
+ * a token issued for another request cannot be replayed.
+ * @param id_token ID token
+ * @param configuration Provider configuration
+ * @param options Verification options
+ */
+export const verify_id_token = async (
+  id_token: string,
+  configuration: OidcConfiguration,
+  {
+    client_id,
+    nonce,
+    now = Math.floor(Date.now() / 1000)
+  }: { client_id: string; nonce: string; now?: number }
+): Promise<IdTokenClaims> => {
+  if (!nonce) {
+    throw new Error("Missing nonce");
+  }
+
+  const [encoded_header, encoded_payload, signature] = id_token.split(".");
+
+  if (!encoded_header || !encoded_payload || !signature) {
+    throw new Error("Malformed ID token");
+  }
+
+  const header: { alg: string; kid?: string } = JSON.parse(
+    Buffer.from(encoded_header, "base64url").toString("utf8")
+  );
+
+  if (header.alg !== "RS256" && header.alg !== "ES256") {
+    throw new Error(`Unsupported algorithm: ${header.alg}`);
+  }
+
+  const { keys } = await fetch_json<{ keys: Jwk[] }>(configuration.jwks_uri);
+  const jwk = keys.find((key) => key.kid === header.kid);
+
+  if (!jwk) {
+    throw new Error("Signing key not found");
+  }
+
+  const is_valid = verify(
+    "sha256",
+    Buffer.from(`${encoded_header}.${encoded_payload}`),
+    {
+      key: createPublicKey({ key: jwk, format: "jwk" }),
+      dsaEncoding: "ieee-p1363"
+    },
+    Buffer.from(signature, "base64url")
+  );
+
+  if (!is_valid) {
+    throw new Error("Invalid signature");
+  }
+
+  const claims: IdTokenClaims = JSON.parse(
+    Buffer.from(encoded_payload, "base64url").toString("utf8")
+  );
+  const audiences = Array.isArray(claims.aud) ? claims.aud : [claims.aud];
+
+  if (claims.iss !== configuration.issuer) {
+    throw new Error("Issuer mismatch");
+  }
+
+  if (!audiences.includes(client_id)) {
+    throw new Error("Audience mismatch");
+  }
+
+  if (claims.exp + CLOCK_SKEW < now) {
+    throw new Error("ID token has expired");
+  }
+
+  if (claims.iat - CLOCK_SKEW > now) {
+    throw new Error("ID token was issued in the future");
+  }
+
+  if (claims.nonce !== nonce) {
+    throw new Error("Nonce mismatch");
+  }
+
+  if (!claims.sub) {
+    throw new Error("ID token does not contain a subject");
+  }
+
+  return claims;
+};
@@ -0,0 +1,176 @@
+import {
+  IncomingBlogRequest,
+  IncomingCollaborationRequest,
+  IncomingFriendRequest,
+  RelationVisibility
+} from "@storiny/proto/dist/privacy_settings_def/v1/def";
+
+import {
+  apply_privacy_settings_mask,
+  can_send_blog_request,
+  can_send_collaboration_request,
+  can_send_friend_request,
+  get_privacy_settings_cascades,
+  PrivacySettings,
+  RequestSenderRelation
+} from "./privacy-settings";
+
+const SETTINGS: PrivacySettings = {
+  is_private_account: false,
+  record_read_history: true,
+  allow_sensitive_media: false,
+  incoming_friend_requests: IncomingFriendRequest.EVERYONE,
+  incoming_collaboration_requests: IncomingCollaborationRequest.EVERYONE,
+  incoming_blog_requests: IncomingBlogRequest.EVERYONE,
+  following_list_visibility: RelationVisibility.EVERYONE,
+  friend_list_visibility: RelationVisibility.EVERYONE
+};
+
+const STRANGER: RequestSenderRelation = {
+  is_followed: false,
+  is_friend: false,
+  is_friend_of_friend: false
+};
+
+describe("apply_privacy_settings_mask", () => {
+  it("only applies the masked fields", () => {
+    expect(
+      apply_privacy_settings_mask(
+        SETTINGS,
+        {
+          ...SETTINGS,
+          is_private_account: true,
+          allow_sensitive_media: true
+        },
+        ["is_private_account"]
+      )
+    ).toEqual({ ...SETTINGS, is_private_account: true });
+  });
+
+  it("throws for unknown paths", () => {
+    expect(() =>
+      apply_privacy_settings_mask(SETTINGS, SETTINGS, ["user_id"])
+    ).toThrow();
+  });
+});
+
+describe("get_privacy_settings_cascades", () => {
+  it("returns no cascades for unchanged settings", () => {
+    expect(
+      Object.values(get_privacy_settings_cascades(SETTINGS, SETTINGS))
+    ).not.toContain(true);
+  });
+
+  it("hides stories and converts follows for private accounts", () => {
+    expect(
+      get_privacy_settings_cascades(SETTINGS, {
+        ...SETTINGS,
+        is_private_account: true
+      })
+    ).toMatchObject({
+      hide_stories_from_non_followers: true,
+      convert_pending_follows: true
+    });
+  });
+
+  it("offers to purge the read history", () => {
+    expect(
+      get_privacy_settings_cascades(SETTINGS, {
+        ...SETTINGS,
+        record_read_history: false
+      }).offer_read_history_purge
+    ).toBeTrue();
+  });
+
+  it("re-checks pending requests when the settings are narrowed", () => {
+    expect(
+      get_privacy_settings_cascades(SETTINGS, {
+        ...SETTINGS,
+        incoming_friend_requests: IncomingFriendRequest.FOLLOWING,
+        incoming_collaboration_requests: IncomingCollaborationRequest.NONE,
+        incoming_blog_requests: IncomingBlogRequest.FRIENDS
+      })
+    ).toMatchObject({
+      recheck_friend_requests: true,
+      recheck_collaboration_requests: true,
+      recheck_blog_requests: true
+    });
+  });
+
+  it("does not re-check pending requests when the settings are widened", () => {
+    expect(
+      get_privacy_settings_cascades(
+        {
+          ...SETTINGS,
+          incoming_friend_requests: IncomingFriendRequest.NONE
+        },
+        SETTINGS
+      ).recheck_friend_requests
+    ).toBeFalse();
+  });
+});
+
+describe("can_send_friend_request", () => {
+  it("checks the relation of the sender", () => {
+    expect(
+      can_send_friend_request(IncomingFriendRequest.EVERYONE, STRANGER)
+    ).toBeTrue();
+    expect(
+      can_send_friend_request(IncomingFriendRequest.FOLLOWING, STRANGER)
+    ).toBeFalse();
+    expect(
+      can_send_friend_request(IncomingFriendRequest.FOLLOWING, {
+        ...STRANGER,
+        is_followed: true
+      })
+    ).toBeTrue();
+    expect(
+      can_send_friend_request(IncomingFriendRequest.FOF, {
+        ...STRANGER,
+        is_friend_of_friend: true
+      })
+    ).toBeTrue();
+    expect(
+      can_send_friend_request(IncomingFriendRequest.NONE, {
+        is_followed: true,
+        is_friend: false,
+        is_friend_of_friend: true
+      })
+    ).toBeFalse();
+  });
+});
+
+describe("can_send_collaboration_request", () => {
+  it("checks the relation of the sender", () => {
+    expect(
+      can_send_collaboration_request(
+        IncomingCollaborationRequest.FRIENDS,
+        STRANGER
+      )
+    ).toBeFalse();
+    expect(
+      can_send_collaboration_request(IncomingCollaborationRequest.FRIENDS, {
+        ...STRANGER,
+        is_friend: true
+      })
+    ).toBeTrue();
+  });
+});
+
+describe("can_send_blog_request", () => {
+  it("checks the relation of the sender", () => {
+    expect(
+      can_send_blog_request(IncomingBlogRequest.NONE, {
+        is_followed: true,
+        is_friend: true,
+        is_friend_of_friend: true
+      })
+    ).toBeFalse();
+    expect(
+      can_send_blog_request(IncomingBlogRequest.FOLLOWING, {
+        ...STRANGER,
+        is_followed: true
+      })
+    ).toBeTrue();
+  });
+});
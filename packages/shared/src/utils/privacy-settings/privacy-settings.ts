@@ -0,0 +1,191 @@
+import {
+  GetPrivacySettingsResponse,
+  IncomingBlogRequest,
+  IncomingCollaborationRequest,
+  IncomingFriendRequest
+} from "@storiny/proto/dist/privacy_settings_def/v1/def";
+
+export type PrivacySettings = GetPrivacySettingsResponse;
+
+/**
+ * Relation of the sender of an incoming request with the receiver
+ */
+export interface RequestSenderRelation {
+  /**
+   * Whether the receiver follows the sender
+   */
+  is_followed: boolean;
+  /**
+   * Whether the sender is a friend of the receiver
+   */
+  is_friend: boolean;
+  /**
+   * Whether the sender is a friend of one of the friends of the receiver
+   */
+  is_friend_of_friend: boolean;
+}
+
+export interface PrivacySettingsCascades {
+  /**
+   * Pending follows should be converted into follow requests
+   */
+  convert_pending_follows: boolean;
+  /**
+   * Stories should be hidden from the users who are not following
+   */
+  hide_stories_from_non_followers: boolean;
+  /**
+   * The user should be offered to purge the existing read history
+   */
+  offer_read_history_purge: boolean;
+  /**
+   * Pending blog requests should be re-checked using `can_send_blog_request`
+   */
+  recheck_blog_requests: boolean;
+  /**
+   * Pending collaboration requests should be re-checked using
+   * `can_send_collaboration_request`
+   */
+  recheck_collaboration_requests: boolean;
+  /**
+   * Pending friend requests should be re-checked using
+   * `can_send_friend_request`
+   */
+  recheck_friend_requests: boolean;
+}
+
+/**
+ * Fields that can be updated using the field mask
+ */
+export const PRIVACY_SETTINGS_PATHS: readonly (keyof PrivacySettings)[] = [
+  "is_private_account",
+  "record_read_history",
+  "allow_sensitive_media",
+  "incoming_friend_requests",
+  "incoming_collaboration_requests",
+  "incoming_blog_requests",
+  "following_list_visibility",
+  "friend_list_visibility"
+];
+
+/**
+ * Applies the fields from `patch` listed in the field mask to the current
+ * settings. Throws for unknown paths.
+ * @param current Current settings
+ * @param patch Next values
+ * @param paths Field mask paths
+ */
+export const apply_privacy_settings_mask = (
+  current: PrivacySettings,
+  patch: PrivacySettings,
+  paths: string[]
+): PrivacySettings => {
+  const next = { ...current };
+
+  for (const path of paths) {
+    if (!PRIVACY_SETTINGS_PATHS.includes(path as keyof PrivacySettings)) {
+      throw new Error(`Unknown field mask path: ${path}`);
+    }
+
+    Object.assign(next, { [path]: patch[path as keyof PrivacySettings] });
+  }
+
+  return next;
+};
+
+/**
+ * Predicate function for determining whether a friend request from the
+ * sender is allowed by the setting of the receiver
+ * @param setting Incoming friend request setting of the receiver
+ * @param relation Relation of the sender with the receiver
+ */
+export const can_send_friend_request = (
+  setting: IncomingFriendRequest,
+  relation: RequestSenderRelation
+): boolean => {
+  switch (setting) {
+    case IncomingFriendRequest.EVERYONE:
+      return true;
+    case IncomingFriendRequest.FOLLOWING:
+      return relation.is_followed;
+    case IncomingFriendRequest.FOF:
+      return relation.is_friend_of_friend;
+    default:
+      return false;
+  }
+};
+
+/**
+ * Predicate function for determining whether a collaboration request from
+ * the sender is allowed by the setting of the receiver
+ * @param setting Incoming collaboration request setting of the receiver
+ * @param relation Relation of the sender with the receiver
+ */
+export const can_send_collaboration_request = (
+  setting: IncomingCollaborationRequest,
+  relation: RequestSenderRelation
+): boolean => {
+  switch (setting) {
+    case IncomingCollaborationRequest.EVERYONE:
+      return true;
+    case IncomingCollaborationRequest.FOLLOWING:
+      return relation.is_followed;
+    case IncomingCollaborationRequest.FRIENDS:
+      return relation.is_friend;
+    default:
+      return false;
+  }
+};
+
+/**
+ * Predicate function for determining whether a blog request from the sender
+ * is allowed by the setting of the receiver
+ * @param setting Incoming blog request setting of the receiver
+ * @param relation Relation of the sender with the receiver
+ */
+export const can_send_blog_request = (
+  setting: IncomingBlogRequest,
+  relation: RequestSenderRelation
+): boolean => {
+  switch (setting) {
+    case IncomingBlogRequest.EVERYONE:
+      return true;
+    case IncomingBlogRequest.FOLLOWING:
+      return relation.is_followed;
+    case IncomingBlogRequest.FRIENDS:
+      return relation.is_friend;
+    default:
+      return false;
+  }
+};
+
+/**
+ * Returns the cascading effects of a privacy settings update
+ * @param previous Settings before the update
+ * @param next Settings after the update
+ */
+export const get_privacy_settings_cascades = (
+  previous: PrivacySettings,
+  next: PrivacySettings
+): PrivacySettingsCascades => {
+  const became_private =
+    !previous.is_private_account && next.is_private_account;
+
+  return {
+    hide_stories_from_non_followers: became_private,
+    convert_pending_follows: became_private,
+    offer_read_history_purge:
+      previous.record_read_history && !next.record_read_history,
+    recheck_friend_requests:
+      previous.incoming_friend_requests !== next.incoming_friend_requests &&
+      next.incoming_friend_requests !== IncomingFriendRequest.EVERYONE,
+    recheck_collaboration_requests:
+      previous.incoming_collaboration_requests !==
+        next.incoming_collaboration_requests &&
+      next.incoming_collaboration_requests !==
+        IncomingCollaborationRequest.EVERYONE,
+    recheck_blog_requests:
+      previous.incoming_blog_requests !== next.incoming_blog_requests &&
+      next.incoming_blog_requests !== IncomingBlogRequest.EVERYONE
+  };
+};
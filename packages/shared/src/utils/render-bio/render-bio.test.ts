@@ -0,0 +1,50 @@
+import { render_bio } from "./render-bio";
+
+describe("render_bio", () => {
+  it("renders paragraphs and line breaks", () => {
+    expect(render_bio("First line\nSecond line\n\nNext paragraph")).toEqual(
+      "<p>First line<br />Second line</p><p>Next paragraph</p>"
+    );
+  });
+
+  it("renders emphasis and inline code", () => {
+    expect(render_bio("**Bold**, *italic* and `*code*`")).toEqual(
+      "<p><strong>Bold</strong>, <em>italic</em> and <code>*code*</code></p>"
+    );
+  });
+
+  it("escapes HTML", () => {
+    expect(render_bio('<img src="x" onerror="alert(1)">')).toEqual(
+      "<p>&lt;img src=&quot;x&quot; onerror=&quot;alert(1)&quot;&gt;</p>"
+    );
+  });
+
+  it("only renders HTTP(S) links", () => {
+    expect(render_bio("[Site](https://storiny.com?a=1&b=2)")).toEqual(
+      '<p><a href="https://storiny.com?a=1&amp;b=2" target="_blank" rel="noopener noreferrer nofollow ugc">Site</a></p>'
+    );
+    expect(render_bio("[Site](javascript:alert(1))")).toEqual(
+      "<p>[Site](javascript:alert(1))</p>"
+    );
+  });
+
+  it("links mentions", () => {
+    expect(render_bio("Writing with @Alice.")).toEqual(
+      '<p>Writing with <a href="/alice">@Alice</a>.</p>'
+    );
+  });
+
+  it("does not link e-mail addresses or short handles", () => {
+    expect(render_bio("Mail me at someone@storiny.com, @ab")).toEqual(
+      "<p>Mail me at someone@storiny.com, @ab</p>"
+    );
+  });
+
+  it("only links mentions of existing users", () => {
+    expect(
+      render_bio("@alice and @bob", {
+        is_username: (username) => username === "alice"
+      })
+    ).toEqual('<p><a href="/alice">@alice</a> and @bob</p>');
+  });
+});
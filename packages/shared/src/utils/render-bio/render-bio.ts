@@ -0,0 +1,104 @@
+import { USER_PROPS } from "../../constants/user";
+
+const HTML_ENTITIES: Record<string, string> = {
+  "&": "&amp;",
+  "<": "&lt;",
+  ">": "&gt;",
+  '"': "&quot;",
+  "'": "&#39;"
+};
+
+const USERNAME_LENGTH =
+  `${USER_PROPS.username.min_length},${USER_PROPS.username.max_length}`;
+
+/**
+ * Matches inline code, links with an HTTP(S) URL, and mentions (in that
+ * order of precedence). Applied to the escaped source.
+ */
+const TOKEN_REGEX = new RegExp(
+  [
+    "`([^`\\n]+)`",
+    "\\[([^\\]\\n]+)\\]\\((https?:\\/\\/[^\\s)]+)\\)",
+    `(^|[^\\w/])@(\\w{${USERNAME_LENGTH}})(?!\\w)`
+  ].join("|"),
+  "g"
+);
+
+const LINK_ATTRIBUTES =
+  'target="_blank" rel="noopener noreferrer nofollow ugc"';
+
+export interface RenderBioOptions {
+  /**
+   * Predicate function for determining whether a mention points to an
+   * existing user. Every well-formed mention is linked when omitted.
+   */
+  is_username?: (username: string) => boolean;
+}
+
+/**
+ * Escapes the HTML special characters in a string
+ * @param value String
+ */
+const escape_html = (value: string): string =>
+  value.replace(/[&<>"']/g, (char) => HTML_ENTITIES[char]);
+
+/**
+ * Renders the emphasis in a plain (escaped) text segment
+ * @param value Escaped text
+ */
+const render_emphasis = (value: string): string =>
+  value
+    .replace(/\*\*([^*\n]+)\*\*/g, "<strong>$1</strong>")
+    .replace(/\*([^*\n]+)\*/g, "<em>$1</em>");
+
+/**
+ * Renders a single paragraph of the bio
+ * @param value Escaped paragraph
+ * @param options Render options
+ */
+const render_paragraph = (value: string, options: RenderBioOptions): string => {
+  let result = "";
+  let last_index = 0;
+
+  for (const match of value.matchAll(TOKEN_REGEX)) {
+    const [token, code, text, url, prefix, username] = match;
+    const index = match.index || 0;
+    result += render_emphasis(value.slice(last_index, index));
+    last_index = index + token.length;
+
+    if (code !== undefined) {
+      result += `<code>${code}</code>`;
+    } else if (url !== undefined) {
+      result += `<a href="${url}" ${LINK_ATTRIBUTES}>${render_emphasis(text)}</a>`;
+    } else if (
+      !options.is_username ||
+      options.is_username(username.toLowerCase())
+    ) {
+      result += `${prefix}<a href="/${username.toLowerCase()}">@${username}</a>`;
+    } else {
+      result += render_emphasis(token);
+    }
+  }
+
+  return (result + render_emphasis(value.slice(last_index))).replace(
+    /\n/g,
+    "<br />"
+  );
+};
+
+/**
+ * Renders the markdown source of a bio to HTML. The source is escaped
+ * before rendering, so only the supported subset (emphasis, inline code,
+ * HTTP(S) links, and mentions) produces markup.
+ * @param source Markdown source of the bio
+ * @param options Render options
+ */
+export const render_bio = (
+  source: string,
+  options: RenderBioOptions = {}
+): string =>
+  escape_html(source.replace(/\r\n?/g, "\n").trim())
+    .split(/\n{2,}/)
+    .filter(Boolean)
+    .map((paragraph) => `<p>${render_paragraph(paragraph, options)}</p>`)
+    .join("");
@@ -0,0 +1,222 @@
+import {
+  Report,
+  ReportContentRequest,
+  ReportEntityType,
+  ReportReason,
+  ReportResolution,
+  ResolveReportRequest
+} from "@storiny/proto/dist/report_def/v1/def";
+
+import { UserFlag } from "../../enums/user-flag";
+import {
+  resolve_report,
+  ResolveReportContext,
+  validate_report_content_request,
+  validate_resolve_report_request
+} from "./resolve-report";
+
+const NOW = new Date("2024-01-01T00:00:00.000Z");
+
+const REPORT = Report.fromPartial({
+  id: "report",
+  entity_type: ReportEntityType.STORY,
+  entity_id: "story",
+  reason: ReportReason.SPAM,
+  created_at: new Date("2023-12-31T00:00:00.000Z")
+});
+
+const CONTEXT: ResolveReportContext = {
+  clock: () => NOW,
+  generate_id: () => "entry",
+  owner_id: "writer"
+};
+
+const get_request = (
+  resolution: ReportResolution,
+  props: Partial<ResolveReportRequest> = {}
+): ResolveReportRequest =>
+  ResolveReportRequest.fromPartial({
+    moderator_id: "moderator",
+    report_id: REPORT.id,
+    resolution,
+    ...props
+  });
+
+describe("validate_report_content_request", () => {
+  const request = ReportContentRequest.fromPartial({
+    user_id: "reporter",
+    entity_type: ReportEntityType.COMMENT,
+    entity_id: "comment",
+    reason: ReportReason.HARASSMENT
+  });
+
+  it("accepts a valid request", () => {
+    expect(validate_report_content_request(request)).toBeNull();
+  });
+
+  it("rejects unspecified entity types and reasons", () => {
+    expect(
+      validate_report_content_request({
+        ...request,
+        entity_type: ReportEntityType.UNSPECIFIED
+      })
+    ).toEqual("invalid_entity");
+    expect(
+      validate_report_content_request({
+        ...request,
+        reason: ReportReason.UNSPECIFIED
+      })
+    ).toEqual("invalid_reason");
+  });
+
+  it("requires a description for the `OTHER` reason", () => {
+    expect(
+      validate_report_content_request({
+        ...request,
+        reason: ReportReason.OTHER,
+        description: "  "
+      })
+    ).toEqual("missing_description");
+  });
+
+  it("rejects long descriptions", () => {
+    expect(
+      validate_report_content_request({
+        ...request,
+        description: "a".repeat(1025)
+      })
+    ).toEqual("description_too_long");
+  });
+});
+
+describe("validate_resolve_report_request", () => {
+  it("rejects resolved reports", () => {
+    expect(
+      validate_resolve_report_request(
+        { ...REPORT, resolution: ReportResolution.DISMISS },
+        get_request(ReportResolution.DISMISS),
+        "writer"
+      )
+    ).toEqual("already_resolved");
+  });
+
+  it("rejects taking down users", () => {
+    expect(
+      validate_resolve_report_request(
+        { ...REPORT, entity_type: ReportEntityType.USER },
+        get_request(ReportResolution.TAKE_DOWN),
+        "writer"
+      )
+    ).toEqual("invalid_target");
+  });
+
+  it("rejects warning the owner of content without an owner", () => {
+    expect(
+      validate_resolve_report_request(
+        { ...REPORT, entity_type: ReportEntityType.TAG },
+        get_request(ReportResolution.WARN_USER),
+        null
+      )
+    ).toEqual("invalid_target");
+  });
+
+  it("rejects suspension periods for other resolutions", () => {
+    expect(
+      validate_resolve_report_request(
+        REPORT,
+        get_request(ReportResolution.WARN_USER, { suspension_days: 7 }),
+        "writer"
+      )
+    ).toEqual("invalid_suspension");
+  });
+});
+
+describe("resolve_report", () => {
+  it("takes down the content through `deleted_at`", () => {
+    const result = resolve_report(
+      REPORT,
+      get_request(ReportResolution.TAKE_DOWN, { note: " Spam " }),
+      CONTEXT
+    );
+
+    expect(result.action).toEqual({
+      take_down: {
+        entity_type: ReportEntityType.STORY,
+        entity_id: "story",
+        deleted_at: NOW
+      }
+    });
+    expect(result.report).toEqual({
+      ...REPORT,
+      resolution: ReportResolution.TAKE_DOWN,
+      resolved_at: NOW,
+      moderator_id: "moderator"
+    });
+    expect(result.audit_log_entry).toEqual({
+      id: "entry",
+      moderator_id: "moderator",
+      action: ReportResolution.TAKE_DOWN,
+      entity_type: ReportEntityType.STORY,
+      entity_id: "story",
+      report_id: "report",
+      note: "Spam",
+      created_at: NOW
+    });
+  });
+
+  it("warns the owner of the content", () => {
+    expect(
+      resolve_report(REPORT, get_request(ReportResolution.WARN_USER), CONTEXT)
+        .action
+    ).toEqual({ warn_user_id: "writer" });
+  });
+
+  it("suspends the owner temporarily", () => {
+    expect(
+      resolve_report(
+        REPORT,
+        get_request(ReportResolution.SUSPEND_USER, { suspension_days: 7 }),
+        CONTEXT
+      ).action
+    ).toEqual({
+      suspend: {
+        user_id: "writer",
+        flag: UserFlag.TEMPORARILY_SUSPENDED,
+        until: new Date("2024-01-08T00:00:00.000Z")
+      }
+    });
+  });
+
+  it("suspends the owner permanently", () => {
+    expect(
+      resolve_report(
+        REPORT,
+        get_request(ReportResolution.SUSPEND_USER),
+        CONTEXT
+      ).action
+    ).toEqual({
+      suspend: { user_id: "writer", flag: UserFlag.PERMANENTLY_SUSPENDED }
+    });
+  });
+
+  it("records dismissals without an action", () => {
+    const result = resolve_report(
+      REPORT,
+      get_request(ReportResolution.DISMISS),
+      CONTEXT
+    );
+
+    expect(result.action).toEqual({});
+    expect(result.audit_log_entry.action).toEqual(ReportResolution.DISMISS);
+  });
+
+  it("throws for invalid requests", () => {
+    expect(() =>
+      resolve_report(
+        { ...REPORT, entity_type: ReportEntityType.USER },
+        get_request(ReportResolution.TAKE_DOWN),
+        CONTEXT
+      )
+    ).toThrow("invalid_target");
+  });
+});
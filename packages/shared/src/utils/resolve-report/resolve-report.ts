@@ -0,0 +1,236 @@
+import {
+  ModerationAuditLogEntry,
+  Report,
+  ReportContentRequest,
+  ReportEntityType,
+  ReportReason,
+  ReportResolution,
+  ResolveReportRequest
+} from "@storiny/proto/dist/report_def/v1/def";
+
+import { REPORT_PROPS } from "../../constants/report";
+import { UserFlag } from "../../enums/user-flag";
+
+const DAY = 24 * 60 * 60 * 1000;
+
+const REPORT_ENTITY_TYPES: ReportEntityType[] = [
+  ReportEntityType.STORY,
+  ReportEntityType.COMMENT,
+  ReportEntityType.USER,
+  ReportEntityType.BLOG,
+  ReportEntityType.TAG
+];
+
+const REPORT_RESOLUTIONS: ReportResolution[] = [
+  ReportResolution.TAKE_DOWN,
+  ReportResolution.WARN_USER,
+  ReportResolution.SUSPEND_USER,
+  ReportResolution.DISMISS
+];
+
+export type ReportContentError =
+  | "description_too_long"
+  | "invalid_entity"
+  | "invalid_reason"
+  | "missing_description";
+
+export type ResolveReportError =
+  | "already_resolved"
+  | "invalid_resolution"
+  | "invalid_suspension"
+  | "invalid_target";
+
+export interface ResolveReportContext {
+  /**
+   * Returns the current date
+   */
+  clock: () => Date;
+  /**
+   * Generates the ID of the audit log entry
+   */
+  generate_id: () => string;
+  /**
+   * ID of the user that owns the reported content, or `null` for content
+   * without an owner (tags)
+   */
+  owner_id: string | null;
+}
+
+export interface ModerationAction {
+  /**
+   * Content to take down by setting its `deleted_at` column
+   */
+  take_down?: {
+    deleted_at: Date;
+    entity_id: string;
+    entity_type: ReportEntityType;
+  };
+  /**
+   * User to suspend. `until` is omitted for permanent suspensions.
+   */
+  suspend?: {
+    flag: UserFlag;
+    until?: Date;
+    user_id: string;
+  };
+  /**
+   * User to warn
+   */
+  warn_user_id?: string;
+}
+
+export interface ResolveReportResult {
+  action: ModerationAction;
+  audit_log_entry: ModerationAuditLogEntry;
+  report: Report;
+}
+
+/**
+ * Validates a `ReportContent` request, returning the error if the request is
+ * invalid, or `null` otherwise
+ * @param request Request
+ */
+export const validate_report_content_request = (
+  request: ReportContentRequest
+): ReportContentError | null => {
+  if (
+    !REPORT_ENTITY_TYPES.includes(request.entity_type) ||
+    !request.entity_id.trim()
+  ) {
+    return "invalid_entity";
+  }
+
+  if (
+    request.reason < ReportReason.SPAM ||
+    request.reason > ReportReason.OTHER
+  ) {
+    return "invalid_reason";
+  }
+
+  const description = request.description?.trim() || "";
+
+  if (request.reason === ReportReason.OTHER && !description) {
+    return "missing_description";
+  }
+
+  if (description.length > REPORT_PROPS.description.max_length) {
+    return "description_too_long";
+  }
+
+  return null;
+};
+
+/**
+ * Validates a `ResolveReport` request against the report, returning the
+ * error if the request is invalid, or `null` otherwise
+ * @param report Report being resolved
+ * @param request Request
+ * @param owner_id ID of the user that owns the reported content
+ */
+export const validate_resolve_report_request = (
+  report: Report,
+  request: ResolveReportRequest,
+  owner_id: string | null
+): ResolveReportError | null => {
+  if (report.resolution !== undefined) {
+    return "already_resolved";
+  }
+
+  if (!REPORT_RESOLUTIONS.includes(request.resolution)) {
+    return "invalid_resolution";
+  }
+
+  if (request.suspension_days !== undefined) {
+    if (
+      request.resolution !== ReportResolution.SUSPEND_USER ||
+      !Number.isInteger(request.suspension_days) ||
+      request.suspension_days < REPORT_PROPS.suspension_days.min ||
+      request.suspension_days > REPORT_PROPS.suspension_days.max
+    ) {
+      return "invalid_suspension";
+    }
+  }
+
+  switch (request.resolution) {
+    // Users are suspended rather than taken down
+    case ReportResolution.TAKE_DOWN:
+      return report.entity_type === ReportEntityType.USER
+        ? "invalid_target"
+        : null;
+    case ReportResolution.WARN_USER:
+    case ReportResolution.SUSPEND_USER:
+      return owner_id === null ? "invalid_target" : null;
+    default:
+      return null;
+  }
+};
+
+/**
+ * Resolves a report, returning the resolved report, the action to apply, and
+ * the audit log entry that records it. Throws if the request is invalid.
+ * @param report Report being resolved
+ * @param request Request
+ * @param context Resolution context
+ */
+export const resolve_report = (
+  report: Report,
+  request: ResolveReportRequest,
+  context: ResolveReportContext
+): ResolveReportResult => {
+  const error = validate_resolve_report_request(
+    report,
+    request,
+    context.owner_id
+  );
+
+  if (error) {
+    throw new Error(`Unable to resolve the report: ${error}`);
+  }
+
+  const now = context.clock();
+  const action: ModerationAction = {};
+
+  switch (request.resolution) {
+    case ReportResolution.TAKE_DOWN:
+      action.take_down = {
+        entity_type: report.entity_type,
+        entity_id: report.entity_id,
+        deleted_at: now
+      };
+      break;
+    case ReportResolution.WARN_USER:
+      action.warn_user_id = context.owner_id as string;
+      break;
+    case ReportResolution.SUSPEND_USER:
+      action.suspend = {
+        user_id: context.owner_id as string,
+        ...(request.suspension_days === undefined
+          ? { flag: UserFlag.PERMANENTLY_SUSPENDED }
+          : {
+              flag: UserFlag.TEMPORARILY_SUSPENDED,
+              until: new Date(now.getTime() + request.suspension_days * DAY)
+            })
+      };
+      break;
+  }
+
+  return {
+    action,
+    report: {
+      ...report,
+      resolution: request.resolution,
+      resolved_at: now,
+      moderator_id: request.moderator_id
+    },
+    audit_log_entry: {
+      id: context.generate_id(),
+      moderator_id: request.moderator_id,
+      action: request.resolution,
+      entity_type: report.entity_type,
+      entity_id: report.entity_id,
+      report_id: report.id,
+      note: request.note?.trim() || undefined,
+      created_at: now
+    }
+  };
+};
@@ -0,0 +1 @@
+export * from "./route-notification";
@@ -0,0 +1,134 @@
+import { NotificationType } from "../../enums/notification-type";
+import {
+  apply_notification_settings_mask,
+  IN_APP_NOTIFICATION_SETTING,
+  NotificationSettings,
+  route_notification,
+  should_send_mail
+} from "./route-notification";
+
+const ENABLED: NotificationSettings = {
+  features_and_updates: true,
+  stories: true,
+  story_likes: true,
+  tags: true,
+  comments: true,
+  replies: true,
+  new_followers: true,
+  friend_requests: true,
+  collaboration_requests: true,
+  blog_requests: true,
+  mentions: true,
+  mail_login_activity: true,
+  mail_features_and_updates: true,
+  mail_newsletters: true,
+  mail_digest: true
+};
+
+const DISABLED = Object.fromEntries(
+  Object.keys(ENABLED).map((key) => [key, false])
+) as unknown as NotificationSettings;
+
+const TYPES = Object.values(NotificationType).filter(
+  (value): value is NotificationType => typeof value === "number"
+);
+
+describe("route_notification", () => {
+  TYPES.forEach((type) => {
+    const setting = IN_APP_NOTIFICATION_SETTING[type];
+
+    it(`routes \`${NotificationType[type]}\` in-app when enabled`, () => {
+      expect(route_notification(type, ENABLED).in_app).toBeTrue();
+    });
+
+    if (setting) {
+      it(`respects \`${setting}\` for \`${NotificationType[type]}\``, () => {
+        expect(
+          route_notification(type, { ...ENABLED, [setting]: false }).in_app
+        ).toBeFalse();
+      });
+    }
+  });
+
+  it("always routes login attempts in-app", () => {
+    expect(
+      route_notification(NotificationType.LOGIN_ATTEMPT, DISABLED).in_app
+    ).toBeTrue();
+  });
+
+  it("mails login attempts only when `mail_login_activity` is enabled", () => {
+    expect(
+      route_notification(NotificationType.LOGIN_ATTEMPT, ENABLED).mail
+    ).toBeTrue();
+    expect(
+      route_notification(NotificationType.LOGIN_ATTEMPT, {
+        ...ENABLED,
+        mail_login_activity: false
+      }).mail
+    ).toBeFalse();
+  });
+
+  it("mails system notifications using `mail_features_and_updates`", () => {
+    expect(
+      route_notification(NotificationType.SYSTEM, {
+        ...DISABLED,
+        mail_features_and_updates: true
+      })
+    ).toEqual({ in_app: false, mail: true });
+  });
+
+  it("routes story mentions using `mentions`", () => {
+    expect(
+      route_notification(NotificationType.STORY_MENTION, {
+        ...ENABLED,
+        stories: false
+      }).in_app
+    ).toBeTrue();
+    expect(
+      route_notification(NotificationType.STORY_MENTION, {
+        ...ENABLED,
+        mentions: false
+      }).in_app
+    ).toBeFalse();
+  });
+
+  it("does not mail regular notifications", () => {
+    expect(route_notification(NotificationType.STORY_LIKE, ENABLED)).toEqual({
+      in_app: true,
+      mail: false
+    });
+  });
+});
+
+describe("apply_notification_settings_mask", () => {
+  it("only applies the masked fields", () => {
+    expect(
+      apply_notification_settings_mask(ENABLED, DISABLED, [
+        "mentions",
+        "mail_digest"
+      ])
+    ).toEqual({ ...ENABLED, mentions: false, mail_digest: false });
+  });
+
+  it("throws for unknown paths", () => {
+    expect(() =>
+      apply_notification_settings_mask(ENABLED, DISABLED, ["user_id"])
+    ).toThrow();
+  });
+});
+
+describe("should_send_mail", () => {
+  it("respects `mail_digest`", () => {
+    expect(should_send_mail("digest", ENABLED)).toBeTrue();
+    expect(should_send_mail("digest", DISABLED)).toBeFalse();
+  });
+
+  it("respects `mail_newsletters`", () => {
+    expect(
+      should_send_mail("newsletter", { ...DISABLED, mail_newsletters: true })
+    ).toBeTrue();
+    expect(
+      should_send_mail("newsletter", { ...ENABLED, mail_newsletters: false })
+    ).toBeFalse();
+  });
+});
@@ -0,0 +1,141 @@
+import { GetNotificationSettingsResponse } from "@storiny/proto/dist/notification_settings_def/v1/def";
+
+import { NotificationType } from "../../enums/notification-type";
+
+export type NotificationSettings = GetNotificationSettingsResponse;
+export type NotificationSetting = keyof NotificationSettings;
+
+/**
+ * Mail events that do not produce an in-app notification
+ */
+export type MailEvent = "digest" | "newsletter";
+
+export interface NotificationRoute {
+  /**
+   * Whether an in-app notification should be produced
+   */
+  in_app: boolean;
+  /**
+   * Whether an e-mail should be sent
+   */
+  mail: boolean;
+}
+
+/**
+ * Settings that control the in-app notifications for each notification type.
+ * `null` is used for the notifications that cannot be turned off.
+ */
+export const IN_APP_NOTIFICATION_SETTING: Record<
+  NotificationType,
+  NotificationSetting | null
+> = {
+  [NotificationType.SYSTEM /*             */]: "features_and_updates",
+  [NotificationType.LOGIN_ATTEMPT /*      */]: null,
+  [NotificationType.FRIEND_REQ_ACCEPT /*  */]: "friend_requests",
+  [NotificationType.FRIEND_REQ_RECEIVED /**/]: "friend_requests",
+  [NotificationType.FOLLOWER_ADD /*       */]: "new_followers",
+  [NotificationType.COMMENT_ADD /*        */]: "comments",
+  [NotificationType.REPLY_ADD /*          */]: "replies",
+  [NotificationType.STORY_MENTION /*      */]: "mentions",
+  [NotificationType.STORY_LIKE /*         */]: "story_likes",
+  [NotificationType.STORY_ADD_BY_USER /*  */]: "stories",
+  [NotificationType.STORY_ADD_BY_TAG /*   */]: "tags",
+  [NotificationType.COLLAB_REQ_ACCEPT /*  */]: "collaboration_requests",
+  [NotificationType.COLLAB_REQ_RECEIVED /**/]: "collaboration_requests",
+  [NotificationType.BLOG_EDITOR_INVITE /* */]: "blog_requests",
+  [NotificationType.BLOG_WRITER_INVITE /* */]: "blog_requests"
+};
+
+/**
+ * Settings that control the e-mails for each notification type. Types
+ * without an entry are only delivered in-app (and through the digest).
+ */
+export const MAIL_NOTIFICATION_SETTING: Partial<
+  Record<NotificationType, NotificationSetting>
+> = {
+  [NotificationType.SYSTEM /*       */]: "mail_features_and_updates",
+  [NotificationType.LOGIN_ATTEMPT /**/]: "mail_login_activity"
+};
+
+/**
+ * Settings that control the mail-only events
+ */
+export const MAIL_EVENT_SETTING: Record<MailEvent, NotificationSetting> = {
+  digest: "mail_digest",
+  newsletter: "mail_newsletters"
+};
+
+/**
+ * Fields that can be updated using the field mask
+ */
+export const NOTIFICATION_SETTINGS_PATHS: readonly NotificationSetting[] = [
+  "features_and_updates",
+  "stories",
+  "story_likes",
+  "tags",
+  "comments",
+  "replies",
+  "new_followers",
+  "friend_requests",
+  "collaboration_requests",
+  "blog_requests",
+  "mentions",
+  "mail_login_activity",
+  "mail_features_and_updates",
+  "mail_newsletters",
+  "mail_digest"
+];
+
+/**
+ * Applies the fields from `patch` listed in the field mask to the current
+ * settings. Throws for unknown paths.
+ * @param current Current settings
+ * @param patch Next values
+ * @param paths Field mask paths
+ */
+export const apply_notification_settings_mask = (
+  current: NotificationSettings,
+  patch: NotificationSettings,
+  paths: string[]
+): NotificationSettings => {
+  const next = { ...current };
+
+  for (const path of paths) {
+    if (!NOTIFICATION_SETTINGS_PATHS.includes(path as NotificationSetting)) {
+      throw new Error(`Unknown field mask path: ${path}`);
+    }
+
+    Object.assign(next, { [path]: patch[path as NotificationSetting] });
+  }
+
+  return next;
+};
+
+/**
+ * Decides how a notification should be delivered to the user
+ * @param type Notification type
+ * @param settings Notification settings of the receiver
+ */
+export const route_notification = (
+  type: NotificationType,
+  settings: NotificationSettings
+): NotificationRoute => {
+  const in_app_setting = IN_APP_NOTIFICATION_SETTING[type];
+  const mail_setting = MAIL_NOTIFICATION_SETTING[type];
+
+  return {
+    in_app: in_app_setting === null || settings[in_app_setting],
+    mail: mail_setting !== undefined && settings[mail_setting]
+  };
+};
+
+/**
+ * Predicate function for determining whether a mail-only event should be
+ * sent to the user
+ * @param event Mail event
+ * @param settings Notification settings of the receiver
+ */
+export const should_send_mail = (
+  event: MailEvent,
+  settings: NotificationSettings
+): boolean => settings[MAIL_EVENT_SETTING[event]];
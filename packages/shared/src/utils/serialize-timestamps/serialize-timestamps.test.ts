@@ -0,0 +1,48 @@
+import { serialize_timestamps } from "./serialize-timestamps";
+
+describe("serialize_timestamps", () => {
+  it("serializes nested timestamps", () => {
+    expect(
+      serialize_timestamps({
+        id: "0",
+        created_at: new Date("2024-01-01T00:00:00.000Z"),
+        edited_at: undefined,
+        user: {
+          created_at: new Date("2023-01-01T00:00:00.000Z"),
+          status: null
+        },
+        tags: [{ created_at: new Date("2022-01-01T00:00:00.000Z") }]
+      })
+    ).toEqual({
+      id: "0",
+      created_at: "2024-01-01T00:00:00.000Z",
+      edited_at: undefined,
+      user: {
+        created_at: "2023-01-01T00:00:00.000Z",
+        status: null
+      },
+      tags: [{ created_at: "2022-01-01T00:00:00.000Z" }]
+    });
+  });
+
+  it("folds the `Timestamp` fields into the deprecated string fields", () => {
+    expect(
+      serialize_timestamps({
+        created_at: "2023-01-01T00:00:00.000Z",
+        created_at_ts: new Date("2024-01-01T00:00:00.000Z"),
+        edited_at: "2024-02-01T00:00:00.000Z",
+        edited_at_ts: undefined,
+        deleted_at_ts: new Date("2024-03-01T00:00:00.000Z")
+      })
+    ).toEqual({
+      created_at: "2024-01-01T00:00:00.000Z",
+      edited_at: "2024-02-01T00:00:00.000Z",
+      deleted_at: "2024-03-01T00:00:00.000Z"
+    });
+  });
+
+  it("keeps the binary values", () => {
+    const content = new Uint8Array([1, 2, 3]);
+    expect(serialize_timestamps({ content }).content).toBe(content);
+  });
+});
@@ -0,0 +1,64 @@
+/**
+ * Suffix of the `google.protobuf.Timestamp` fields that replace the
+ * deprecated string timestamps
+ */
+const TIMESTAMP_SUFFIX = "_ts";
+
+/**
+ * Replaces the `Date` values in `T` with ISO 8601 strings, folding the
+ * `Timestamp` fields into their deprecated string fields
+ */
+export type SerializedTimestamps<T> = T extends Date
+  ? string
+  : T extends Uint8Array
+    ? T
+    : T extends Array<infer U>
+      ? Array<SerializedTimestamps<U>>
+      : T extends object
+        ? {
+            [K in keyof T as K extends `${string}${typeof TIMESTAMP_SUFFIX}`
+              ? never
+              : K]: SerializedTimestamps<T[K]>;
+          }
+        : T;
+
+/**
+ * Converts the timestamps of a gRPC response (decoded as `Date` objects) into
+ * ISO 8601 strings, as used by the REST types. The `Timestamp` fields are
+ * written to their deprecated string fields (e.g. `created_at_ts` to
+ * `created_at`), taking precedence over the string sent by the server.
+ * @param value Response
+ */
+export const serialize_timestamps = <T>(value: T): SerializedTimestamps<T> => {
+  if (value instanceof Date) {
+    return value.toISOString() as SerializedTimestamps<T>;
+  }
+
+  if (Array.isArray(value)) {
+    return value.map(serialize_timestamps) as SerializedTimestamps<T>;
+  }
+
+  if (
+    value === null ||
+    typeof value !== "object" ||
+    value instanceof Uint8Array
+  ) {
+    return value as SerializedTimestamps<T>;
+  }
+
+  const entries = Object.entries(value);
+
+  return Object.fromEntries([
+    ...entries
+      .filter(([key]) => !key.endsWith(TIMESTAMP_SUFFIX))
+      .map(([key, item]) => [key, serialize_timestamps(item)]),
+    ...entries
+      .filter(
+        ([key, item]) => key.endsWith(TIMESTAMP_SUFFIX) && item instanceof Date
+      )
+      .map(([key, item]) => [
+        key.slice(0, -TIMESTAMP_SUFFIX.length),
+        (item as Date).toISOString()
+      ])
+  ]) as SerializedTimestamps<T>;
+};
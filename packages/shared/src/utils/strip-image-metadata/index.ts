@@ -0,0 +1 @@
+export * from "./strip-image-metadata";
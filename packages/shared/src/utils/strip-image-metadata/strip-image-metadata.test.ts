@@ -0,0 +1,102 @@
+import { get_image_info, PNG_SIGNATURE } from "../get-image-info";
+import { strip_image_metadata } from "./strip-image-metadata";
+
+/**
+ * Returns the bytes for a list of byte values and ASCII strings
+ * @param parts Parts
+ */
+const bytes = (...parts: (number[] | string)[]): Uint8Array =>
+  Uint8Array.from(
+    parts.flatMap((part) =>
+      typeof part === "string"
+        ? Array.from(part, (char) => char.charCodeAt(0))
+        : part
+    )
+  );
+
+const u32_be = (value: number): number[] => [
+  (value >>> 24) & 0xff,
+  (value >>> 16) & 0xff,
+  (value >>> 8) & 0xff,
+  value & 0xff
+];
+const u32_le = (value: number): number[] => u32_be(value).reverse();
+
+/**
+ * Predicate function for determining whether the bytes contain an ASCII
+ * string
+ * @param data Bytes
+ * @param value String
+ */
+const contains = (data: Uint8Array, value: string): boolean =>
+  Buffer.from(data).includes(value);
+
+describe("strip_image_metadata", () => {
+  it("removes the EXIF segment from JPEG images", () => {
+    const jpeg = bytes(
+      [0xff, 0xd8],
+      // APP1 (EXIF) segment
+      [0xff, 0xe1, 0x00, 0x0a],
+      "Exif",
+      [0, 0, 1, 2],
+      // SOF0 segment
+      [0xff, 0xc0, 0x00, 0x0b, 8, 0x00, 0x20, 0x00, 0x20, 3, 0, 0, 0],
+      // Start of scan followed by the image data
+      [0xff, 0xda, 0x00, 0x02, 1, 2, 3, 0xff, 0xd9]
+    );
+    const stripped = strip_image_metadata(jpeg, "jpeg");
+
+    expect(contains(stripped, "Exif")).toBeFalse();
+    expect(stripped).toHaveLength(jpeg.length - 12);
+    expect(get_image_info(stripped)).toEqual(get_image_info(jpeg));
+  });
+
+  it("removes the metadata chunks from PNG images", () => {
+    const png = bytes(
+      PNG_SIGNATURE,
+      u32_be(13),
+      "IHDR",
+      u32_be(32),
+      u32_be(32),
+      [8, 6, 0, 0, 0],
+      [0, 0, 0, 0],
+      u32_be(4),
+      "eXIf",
+      [1, 2, 3, 4],
+      [0, 0, 0, 0],
+      u32_be(0),
+      "IEND",
+      [0, 0, 0, 0]
+    );
+    const stripped = strip_image_metadata(png, "png");
+
+    expect(contains(stripped, "eXIf")).toBeFalse();
+    expect(contains(stripped, "IEND")).toBeTrue();
+    expect(get_image_info(stripped)).toEqual(get_image_info(png));
+  });
+
+  it("removes the metadata chunks from WebP images", () => {
+    const webp = bytes(
+      "RIFF",
+      u32_le(4 + 18 + 13 + 1),
+      "WEBP",
+      "VP8X",
+      u32_le(10),
+      // EXIF flag
+      [0x08, 0, 0, 0],
+      [0x1f, 0, 0, 0x1f, 0, 0],
+      "EXIF",
+      // Odd sized chunk followed by padding
+      u32_le(5),
+      [1, 2, 3, 4, 5, 0]
+    );
+    const stripped = strip_image_metadata(webp, "webp");
+    const view = new DataView(stripped.buffer);
+
+    expect(contains(stripped, "EXIF")).toBeFalse();
+    expect(stripped).toHaveLength(12 + 18);
+    expect(view.getUint32(4, true)).toEqual(stripped.length - 8);
+    expect(stripped[20] & 0x08).toEqual(0);
+    expect(get_image_info(stripped)).toEqual(get_image_info(webp));
+  });
+});
@@ -0,0 +1,162 @@
+import { has_signature, ImageFormat } from "../get-image-info";
+
+/**
+ * JPEG segments removed from the image (APP1 holds EXIF and XMP, APP13
+ * holds IPTC, and COM holds comments)
+ */
+const JPEG_METADATA_MARKERS = new Set([0xe1, 0xed, 0xfe]);
+
+/**
+ * PNG chunks removed from the image
+ */
+const PNG_METADATA_CHUNKS = new Set(["eXIf", "iTXt", "tEXt", "tIME", "zTXt"]);
+
+/**
+ * WebP chunks removed from the image, along with their `VP8X` flags
+ */
+const WEBP_METADATA_CHUNKS: Record<string, number> = {
+  EXIF: 0x08,
+  "XMP ": 0x04
+};
+
+/**
+ * Concatenates byte arrays
+ * @param parts Byte arrays
+ */
+const concat = (parts: Uint8Array[]): Uint8Array => {
+  const result = new Uint8Array(
+    parts.reduce((length, part) => length + part.length, 0)
+  );
+  let offset = 0;
+
+  for (const part of parts) {
+    result.set(part, offset);
+    offset += part.length;
+  }
+
+  return result;
+};
+
+/**
+ * Reads a 4-byte ASCII chunk type
+ * @param bytes Bytes
+ * @param offset Offset
+ */
+const read_type = (bytes: Uint8Array, offset: number): string =>
+  String.fromCharCode(...bytes.subarray(offset, offset + 4));
+
+/**
+ * Removes the metadata segments from a JPEG image
+ * @param bytes Image data
+ * @param view Data view over the image data
+ */
+const strip_jpeg = (bytes: Uint8Array, view: DataView): Uint8Array => {
+  const parts = [bytes.subarray(0, 2)];
+  let offset = 2;
+
+  while (offset + 4 <= bytes.length && bytes[offset] === 0xff) {
+    const marker = bytes[offset + 1];
+
+    // Entropy-coded data follows the start of scan
+    if (marker === 0xda) {
+      break;
+    }
+
+    const end = offset + 2 + view.getUint16(offset + 2);
+
+    if (!JPEG_METADATA_MARKERS.has(marker)) {
+      parts.push(bytes.subarray(offset, end));
+    }
+
+    offset = end;
+  }
+
+  parts.push(bytes.subarray(offset));
+  return concat(parts);
+};
+
+/**
+ * Removes the metadata segments from a PNG image
+ * @param bytes Image data
+ * @param view Data view over the image data
+ */
+const strip_png = (bytes: Uint8Array, view: DataView): Uint8Array => {
+  const parts = [bytes.subarray(0, 8)];
+  let offset = 8;
+
+  while (offset + 12 <= bytes.length) {
+    // Length, type, data, and CRC
+    const end = offset + 12 + view.getUint32(offset);
+
+    if (!PNG_METADATA_CHUNKS.has(read_type(bytes, offset + 4))) {
+      parts.push(bytes.subarray(offset, end));
+    }
+
+    offset = end;
+  }
+
+  return concat(parts);
+};
+
+/**
+ * Removes the metadata segments from a WebP image
+ * @param bytes Image data
+ * @param view Data view over the image data
+ */
+const strip_webp = (bytes: Uint8Array, view: DataView): Uint8Array => {
+  const parts: Uint8Array[] = [];
+  let offset = 12;
+
+  while (offset + 8 <= bytes.length) {
+    const type = read_type(bytes, offset);
+    const size = view.getUint32(offset + 4, true);
+    // Chunks are padded to an even size
+    const end = offset + 8 + size + (size & 1);
+
+    if (!(type in WEBP_METADATA_CHUNKS)) {
+      const chunk = bytes.slice(offset, end);
+
+      if (type === "VP8X") {
+        chunk[8] &= ~Object.values(WEBP_METADATA_CHUNKS).reduce(
+          (flags, flag) => flags | flag,
+          0
+        );
+      }
+
+      parts.push(chunk);
+    }
+
+    offset = end;
+  }
+
+  const body = concat(parts);
+  const header = bytes.slice(0, 12);
+  new DataView(header.buffer).setUint32(4, body.length + 4, true);
+
+  return concat([header, body]);
+};
+
+/**
+ * Removes the EXIF and other textual metadata (location, camera details,
+ * comments) from an image, leaving the image data untouched
+ * @param bytes Image data
+ * @param format Image format
+ */
+export const strip_image_metadata = (
+  bytes: Uint8Array,
+  format: ImageFormat
+): Uint8Array => {
+  const view = new DataView(bytes.buffer, bytes.byteOffset, bytes.byteLength);
+
+  switch (format) {
+    case "jpeg":
+      return strip_jpeg(bytes, view);
+    case "png":
+      return has_signature(bytes, 12, "IHDR") ? strip_png(bytes, view) : bytes;
+    case "webp":
+      return strip_webp(bytes, view);
+    default:
+      // GIF images do not carry EXIF data
+      return bytes;
+  }
+};
@@ -0,0 +1,39 @@
+import { get_timestamp, to_timestamp_fields } from "./timestamp-fields";
+
+const DATE = new Date("2024-01-01T00:00:00.000Z");
+
+describe("get_timestamp", () => {
+  it("prefers the `Timestamp` field", () => {
+    expect(
+      get_timestamp(
+        { created_at: "2023-01-01T00:00:00.000Z", created_at_ts: DATE },
+        "created_at"
+      )
+    ).toEqual(DATE);
+  });
+
+  it("falls back to the deprecated string field", () => {
+    expect(
+      get_timestamp(
+        { created_at: "2024-01-01T00:00:00.000Z", created_at_ts: undefined },
+        "created_at"
+      )
+    ).toEqual(DATE);
+  });
+
+  it("treats empty and malformed strings as omitted", () => {
+    expect(get_timestamp({ created_at: "" }, "created_at")).toBeUndefined();
+    expect(
+      get_timestamp({ created_at: "invalid" }, "created_at")
+    ).toBeUndefined();
+  });
+});
+
+describe("to_timestamp_fields", () => {
+  it("returns both fields", () => {
+    expect(to_timestamp_fields("created_at", DATE)).toEqual({
+      created_at: "2024-01-01T00:00:00.000Z",
+      created_at_ts: DATE
+    });
+  });
+});
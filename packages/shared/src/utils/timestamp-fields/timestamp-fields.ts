@@ -0,0 +1,44 @@
+/**
+ * Message with a timestamp in both its deprecated string field and the
+ * `google.protobuf.Timestamp` field suffixed with `_ts`
+ */
+export type TimestampFields<K extends string> = {
+  [P in K]?: string | undefined;
+} & {
+  [P in `${K}_ts`]?: Date | undefined;
+};
+
+/**
+ * Returns a timestamp sent in either of its fields, preferring the
+ * `Timestamp` field. Empty and malformed strings are treated as omitted.
+ * @param message Message
+ * @param key Name of the deprecated string field
+ */
+export const get_timestamp = <K extends string>(
+  message: TimestampFields<K>,
+  key: K
+): Date | undefined => {
+  const timestamp = message[`${key}_ts` as `${K}_ts`] as Date | undefined;
+
+  if (timestamp) {
+    return timestamp;
+  }
+
+  const value = message[key] as string | undefined;
+  const date = value ? new Date(value) : undefined;
+
+  return date && !Number.isNaN(date.getTime()) ? date : undefined;
+};
+
+/**
+ * Returns both fields of a timestamp, to be spread into a message during the
+ * transition to `google.protobuf.Timestamp`
+ * @param key Name of the deprecated string field
+ * @param date Timestamp
+ */
+export const to_timestamp_fields = <K extends string>(
+  key: K,
+  date: Date
+): Record<K, string> & Record<`${K}_ts`, Date> =>
+  ({ [key]: date.toISOString(), [`${key}_ts`]: date }) as Record<K, string> &
+    Record<`${K}_ts`, Date>;
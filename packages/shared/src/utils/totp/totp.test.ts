@@ -0,0 +1,178 @@
+import {
+  base32_decode,
+  base32_encode,
+  generate_recovery_codes,
+  generate_totp_secret,
+  get_hotp,
+  get_otpauth_uri,
+  get_totp,
+  TotpAlgorithm,
+  verify_totp
+} from "./totp";
+
+// Seeds from RFC 6238, Appendix B
+const SECRETS: Record<TotpAlgorithm, Buffer> = {
+  sha1: Buffer.from("12345678901234567890"),
+  sha256: Buffer.from("12345678901234567890123456789012"),
+  sha512: Buffer.from(
+    "1234567890123456789012345678901234567890123456789012345678901234"
+  )
+};
+
+// Test vectors from RFC 6238, Appendix B
+const TOTP_VECTORS: [number, TotpAlgorithm, string][] = [
+  [59, "sha1", "94287082"],
+  [59, "sha256", "46119246"],
+  [59, "sha512", "90693936"],
+  [1111111109, "sha1", "07081804"],
+  [1111111109, "sha256", "68084774"],
+  [1111111109, "sha512", "25091201"],
+  [1111111111, "sha1", "14050471"],
+  [1111111111, "sha256", "67062674"],
+  [1111111111, "sha512", "99943326"],
+  [1234567890, "sha1", "89005924"],
+  [1234567890, "sha256", "91819424"],
+  [1234567890, "sha512", "93441116"],
+  [2000000000, "sha1", "69279037"],
+  [2000000000, "sha256", "90698825"],
+  [2000000000, "sha512", "38618901"],
+  [20000000000, "sha1", "65353130"],
+  [20000000000, "sha256", "77737706"],
+  [20000000000, "sha512", "47863826"]
+];
+
+// Test vectors from RFC 4226, Appendix D
+const HOTP_VECTORS = [
+  "755224",
+  "287082",
+  "359152",
+  "969429",
+  "338314",
+  "254676",
+  "287922",
+  "162583",
+  "399871",
+  "520489"
+];
+
+describe("base32", () => {
+  it("encodes and decodes the RFC 4648 test vectors", () => {
+    [
+      ["f", "MY"],
+      ["fo", "MZXQ"],
+      ["foo", "MZXW6"],
+      ["foob", "MZXW6YQ"],
+      ["fooba", "MZXW6YTB"],
+      ["foobar", "MZXW6YTBOI"]
+    ].forEach(([input, output]) => {
+      expect(base32_encode(Buffer.from(input))).toEqual(output);
+      expect(base32_decode(output).toString()).toEqual(input);
+    });
+  });
+
+  it("ignores case, whitespace, and padding while decoding", () => {
+    expect(base32_decode("mzxw 6ytb oi======").toString()).toEqual("foobar");
+  });
+
+  it("throws for invalid characters", () => {
+    expect(() => base32_decode("MZXW1")).toThrow();
+  });
+});
+
+describe("get_hotp", () => {
+  HOTP_VECTORS.forEach((code, counter) => {
+    it(`returns \`${code}\` for counter \`${counter}\``, () => {
+      expect(get_hotp(SECRETS.sha1, counter)).toEqual(code);
+    });
+  });
+});
+
+describe("get_totp", () => {
+  TOTP_VECTORS.forEach(([time, algorithm, code]) => {
+    it(`returns \`${code}\` for \`${time}\` (${algorithm})`, () => {
+      expect(
+        get_totp(SECRETS[algorithm], { time, algorithm, digits: 8 })
+      ).toEqual(code);
+    });
+  });
+});
+
+describe("verify_totp", () => {
+  it("accepts codes from the adjacent time steps", () => {
+    expect(
+      verify_totp(SECRETS.sha1, "94287082", { time: 59, digits: 8 })
+    ).toBe(1);
+    expect(
+      verify_totp(SECRETS.sha1, "94287082", { time: 89, digits: 8 })
+    ).toBe(1);
+  });
+
+  it("rejects codes outside the window", () => {
+    expect(
+      verify_totp(SECRETS.sha1, "94287082", { time: 150, digits: 8 })
+    ).toBeNull();
+    expect(
+      verify_totp(SECRETS.sha1, "94287082", {
+        time: 89,
+        digits: 8,
+        window: 0
+      })
+    ).toBeNull();
+  });
+
+  it("rejects replayed codes", () => {
+    expect(
+      verify_totp(SECRETS.sha1, "94287082", {
+        time: 59,
+        digits: 8,
+        last_used_step: 1
+      })
+    ).toBeNull();
+    expect(
+      verify_totp(SECRETS.sha1, "94287082", {
+        time: 59,
+        digits: 8,
+        last_used_step: 0
+      })
+    ).toBe(1);
+  });
+
+  it("rejects malformed codes", () => {
+    expect(verify_totp(SECRETS.sha1, "", { time: 59 })).toBeNull();
+    expect(verify_totp(SECRETS.sha1, "9428708", { time: 59 })).toBeNull();
+  });
+});
+
+describe("generate_totp_secret", () => {
+  it("generates a 160-bit base32 secret", () => {
+    const secret = generate_totp_secret();
+
+    expect(secret).toMatch(/^[A-Z2-7]{32}$/);
+    expect(base32_decode(secret)).toHaveLength(20);
+  });
+});
+
+describe("get_otpauth_uri", () => {
+  it("returns a valid `otpauth` URI", () => {
+    const uri = new URL(get_otpauth_uri("MZXW6YTBOI", "someone@storiny.com"));
+
+    expect(uri.protocol).toEqual("otpauth:");
+    expect(uri.host).toEqual("totp");
+    expect(decodeURIComponent(uri.pathname)).toEqual(
+      "/Storiny:someone@storiny.com"
+    );
+    expect(uri.searchParams.get("secret")).toEqual("MZXW6YTBOI");
+    expect(uri.searchParams.get("issuer")).toEqual("Storiny");
+    expect(uri.searchParams.get("digits")).toEqual("6");
+  });
+});
+
+describe("generate_recovery_codes", () => {
+  it("generates unique recovery codes", () => {
+    const codes = generate_recovery_codes();
+
+    expect(codes).toHaveLength(10);
+    expect(new Set(codes).size).toEqual(codes.length);
+    codes.forEach((code) => expect(code).toMatch(/^[a-z0-9]{12}$/));
+  });
+});
@@ -0,0 +1,217 @@
+import { createHmac, randomBytes, randomInt, timingSafeEqual } from "crypto";
+
+const BASE32_ALPHABET = "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const RECOVERY_CODE_ALPHABET = "abcdefghijklmnopqrstuvwxyz0123456789";
+
+export type TotpAlgorithm = "sha1" | "sha256" | "sha512";
+
+export interface TotpOptions {
+  /**
+   * HMAC algorithm
+   * @default "sha1"
+   */
+  algorithm?: TotpAlgorithm;
+  /**
+   * Number of digits in the generated code
+   * @default 6
+   */
+  digits?: number;
+  /**
+   * Time step (in seconds)
+   * @default 30
+   */
+  period?: number;
+  /**
+   * Unix time (in seconds) to generate the code for
+   * @default Date.now() / 1000
+   */
+  time?: number;
+}
+
+export const TOTP_PROPS = {
+  algorithm: "sha1",
+  digits: 6,
+  period: 30,
+  issuer: "Storiny",
+  secret_length: 20,
+  recovery_code: { count: 10, length: 12 }
+} as const;
+
+/**
+ * Encodes a buffer using the RFC 4648 base32 alphabet (without padding)
+ * @param buffer Buffer to encode
+ */
+export const base32_encode = (buffer: Buffer): string => {
+  let bits = 0;
+  let value = 0;
+  let output = "";
+
+  for (const byte of buffer) {
+    value = (value << 8) | byte;
+    bits += 8;
+
+    while (bits >= 5) {
+      output += BASE32_ALPHABET[(value >>> (bits - 5)) & 31];
+      bits -= 5;
+    }
+  }
+
+  if (bits > 0) {
+    output += BASE32_ALPHABET[(value << (5 - bits)) & 31];
+  }
+
+  return output;
+};
+
+/**
+ * Decodes a base32 string. Whitespace, padding, and case are ignored.
+ * @param input String to decode
+ */
+export const base32_decode = (input: string): Buffer => {
+  const chars = input.replace(/[\s=]+/g, "").toUpperCase();
+  const bytes: number[] = [];
+  let bits = 0;
+  let value = 0;
+
+  for (const char of chars) {
+    const index = BASE32_ALPHABET.indexOf(char);
+
+    if (index === -1) {
+      throw new Error(`Invalid base32 character: ${char}`);
+    }
+
+    value = (value << 5) | index;
+    bits += 5;
+
+    if (bits >= 8) {
+      bytes.push((value >>> (bits - 8)) & 255);
+      bits -= 8;
+    }
+  }
+
+  return Buffer.from(bytes);
+};
+
+/**
+ * Generates a HOTP code (RFC 4226)
+ * @param secret Shared secret
+ * @param counter Moving factor
+ * @param options HOTP options
+ */
+export const get_hotp = (
+  secret: Buffer,
+  counter: number,
+  {
+    algorithm = TOTP_PROPS.algorithm,
+    digits = TOTP_PROPS.digits
+  }: Pick<TotpOptions, "algorithm" | "digits"> = {}
+): string => {
+  const message = Buffer.alloc(8);
+  message.writeUInt32BE(Math.floor(counter / 2 ** 32), 0);
+  message.writeUInt32BE(counter % 2 ** 32, 4);
+
+  const digest = createHmac(algorithm, secret).update(message).digest();
+  const offset = digest[digest.length - 1] & 15;
+  const binary = digest.readUInt32BE(offset) & 0x7fffffff;
+
+  return String(binary % 10 ** digits).padStart(digits, "0");
+};
+
+/**
+ * Generates a TOTP code (RFC 6238)
+ * @param secret Shared secret
+ * @param options TOTP options
+ */
+export const get_totp = (
+  secret: Buffer,
+  {
+    period = TOTP_PROPS.period,
+    time = Date.now() / 1000,
+    ...rest
+  }: TotpOptions = {}
+): string => get_hotp(secret, Math.floor(time / period), rest);
+
+/**
+ * Verifies a TOTP code, returning the time step it was generated for, or
+ * `null` if the code is invalid. Codes from the adjacent time steps are
+ * accepted to allow for clock drift. The returned step must be stored and
+ * passed as `last_used_step` on the next verification, so that a code cannot
+ * be replayed within the window (RFC 6238, Section 5.2).
+ * @param secret Shared secret
+ * @param code Code to verify
+ * @param options TOTP options
+ */
+export const verify_totp = (
+  secret: Buffer,
+  code: string,
+  {
+    window = 1,
+    period = TOTP_PROPS.period,
+    time = Date.now() / 1000,
+    last_used_step = null,
+    ...rest
+  }: TotpOptions & { last_used_step?: number | null; window?: number } = {}
+): number | null => {
+  const input = Buffer.from(code);
+  const current_step = Math.floor(time / period);
+  let matched_step: number | null = null;
+
+  for (let offset = -window; offset <= window; offset++) {
+    const step = current_step + offset;
+    const expected = Buffer.from(get_hotp(secret, step, rest));
+
+    // Every step is checked to keep the comparison time constant
+    if (
+      expected.length === input.length &&
+      timingSafeEqual(expected, input) &&
+      matched_step === null
+    ) {
+      matched_step = step;
+    }
+  }
+
+  if (
+    matched_step === null ||
+    (last_used_step !== null && matched_step <= last_used_step)
+  ) {
+    return null;
+  }
+
+  return matched_step;
+};
+
+/**
+ * Generates a new base32 encoded TOTP secret
+ */
+export const generate_totp_secret = (): string =>
+  base32_encode(randomBytes(TOTP_PROPS.secret_length));
+
+/**
+ * Returns the `otpauth://` URI for the authenticator apps
+ * @param secret Base32 encoded secret
+ * @param account Account name (username or e-mail)
+ */
+export const get_otpauth_uri = (secret: string, account: string): string => {
+  const params = new URLSearchParams({
+    secret,
+    issuer: TOTP_PROPS.issuer,
+    algorithm: TOTP_PROPS.algorithm.toUpperCase(),
+    digits: String(TOTP_PROPS.digits),
+    period: String(TOTP_PROPS.period)
+  });
+
+  const label = encodeURIComponent(`${TOTP_PROPS.issuer}:${account}`);
+
+  return `otpauth://totp/${label}?${params.toString()}`;
+};
+
+/**
+ * Generates a new set of one-time recovery codes
+ */
+export const generate_recovery_codes = (): string[] =>
+  Array.from({ length: TOTP_PROPS.recovery_code.count }, () =>
+    Array.from(
+      { length: TOTP_PROPS.recovery_code.length },
+      () => RECOVERY_CODE_ALPHABET[randomInt(RECOVERY_CODE_ALPHABET.length)]
+    ).join("")
+  );
@@ -0,0 +1,201 @@
+import {
+  AssetPurpose,
+  AssetRating,
+  UploadImageRequest
+} from "@storiny/proto/dist/asset_def/v1/def";
+import fs from "fs";
+import os from "os";
+import path from "path";
+
+import { PNG_SIGNATURE } from "../get-image-info";
+import {
+  ImageCodec,
+  ObjectStore,
+  RawImage,
+  resize_image,
+  upload_image,
+  UploadImageError
+} from "./upload-image";
+
+/**
+ * Object store backed by the local filesystem
+ */
+class LocalObjectStore implements ObjectStore {
+  constructor(private readonly root: string) {}
+
+  async put(key: string, data: Uint8Array): Promise<void> {
+    const file = path.join(this.root, key);
+    await fs.promises.mkdir(path.dirname(file), { recursive: true });
+    await fs.promises.writeFile(file, data);
+  }
+}
+
+/**
+ * Codec that decodes every image to a solid color and encodes the images
+ * to their dimensions
+ */
+const CODEC: ImageCodec = {
+  decode: async (bytes) => {
+    const view = new DataView(bytes.buffer, bytes.byteOffset);
+    const [width, height] = [view.getUint32(16), view.getUint32(20)];
+
+    return {
+      width,
+      height,
+      pixels: Uint8ClampedArray.from(
+        Array.from({ length: width * height }, () => [169, 122, 88, 255]).flat()
+      )
+    };
+  },
+  encode: async (image: RawImage) =>
+    Buffer.from(`${image.width}x${image.height}`)
+};
+
+/**
+ * Returns a PNG header with an `eXIf` chunk
+ * @param width Width of the image
+ * @param height Height of the image
+ */
+const get_png = (width: number, height: number): Uint8Array => {
+  const png = Buffer.alloc(8 + 25 + 16 + 12);
+  png.set(PNG_SIGNATURE, 0);
+  png.writeUInt32BE(13, 8);
+  png.write("IHDR", 12);
+  png.writeUInt32BE(width, 16);
+  png.writeUInt32BE(height, 20);
+  png.writeUInt32BE(4, 33);
+  png.write("eXIf", 37);
+  png.write("GPS!", 41);
+  png.write("IEND", 53);
+  return new Uint8Array(png);
+};
+
+/**
+ * Returns the request stream for an image
+ * @param data Image data
+ * @param purpose Purpose of the image
+ * @param chunk_size Size of each chunk
+ */
+async function* get_stream(
+  data: Uint8Array,
+  purpose: AssetPurpose = AssetPurpose.AVATAR,
+  chunk_size = 16
+): AsyncGenerator<UploadImageRequest> {
+  yield {
+    metadata: {
+      user_id: "1",
+      purpose,
+      content_type: "image/png",
+      size: data.length,
+      rating: AssetRating.UNSPECIFIED
+    }
+  };
+
+  for (let i = 0; i < data.length; i += chunk_size) {
+    yield { chunk: data.subarray(i, i + chunk_size) };
+  }
+}
+
+describe("resize_image", () => {
+  it("downscales while preserving the aspect ratio", () => {
+    const image = resize_image(
+      {
+        width: 4,
+        height: 2,
+        pixels: Uint8ClampedArray.from(
+          [0, 255, 0, 255, 0, 255, 0, 255].flatMap((value) => [
+            value,
+            value,
+            value,
+            255
+          ])
+        )
+      },
+      2
+    );
+
+    expect([image.width, image.height]).toEqual([2, 1]);
+    expect(Array.from(image.pixels)).toEqual([
+      128, 128, 128, 255, 128, 128, 128, 255
+    ]);
+  });
+});
+
+describe("upload_image", () => {
+  let root: string;
+
+  beforeEach(async () => {
+    root = await fs.promises.mkdtemp(path.join(os.tmpdir(), "upload-image-"));
+  });
+
+  afterEach(async () => {
+    await fs.promises.rm(root, { recursive: true, force: true });
+  });
+
+  it("stores the stripped original and the variants", async () => {
+    const response = await upload_image(get_stream(get_png(200, 100)), {
+      codec: CODEC,
+      store: new LocalObjectStore(root),
+      generate_id: () => "image"
+    });
+
+    expect(response).toEqual({
+      id: "image",
+      hex: "a97a58",
+      blurhash: expect.any(String),
+      width: 200,
+      height: 100,
+      rating: AssetRating.NOT_RATED
+    });
+    // Variants larger than the original are skipped
+    const files = await fs.promises.readdir(path.join(root, "image"));
+    expect(files.sort()).toEqual(["128", "64", "original"]);
+    expect(
+      fs.readFileSync(path.join(root, "image", "128")).toString()
+    ).toEqual("128x64");
+
+    const original = fs.readFileSync(path.join(root, "image", "original"));
+    expect(original.includes("eXIf")).toBeFalse();
+    expect(original.includes("IHDR")).toBeTrue();
+  });
+
+  it("rejects streams without metadata", async () => {
+    async function* stream(): AsyncGenerator<UploadImageRequest> {
+      yield { chunk: get_png(32, 32) };
+    }
+
+    await expect(
+      upload_image(stream(), {
+        codec: CODEC,
+        store: new LocalObjectStore(root)
+      })
+    ).rejects.toEqual(new UploadImageError("missing_metadata"));
+  });
+
+  it("rejects unsupported formats", async () => {
+    await expect(
+      upload_image(get_stream(Buffer.from("%PDF-1.7 not an image")), {
+        codec: CODEC,
+        store: new LocalObjectStore(root)
+      })
+    ).rejects.toHaveProperty("reason", "unsupported_format");
+  });
+
+  it("rejects images with invalid dimensions", async () => {
+    await expect(
+      upload_image(get_stream(get_png(8, 8)), {
+        codec: CODEC,
+        store: new LocalObjectStore(root)
+      })
+    ).rejects.toHaveProperty("reason", "invalid_dimensions");
+  });
+
+  it("rejects invalid purposes", async () => {
+    await expect(
+      upload_image(get_stream(get_png(32, 32), AssetPurpose.UNSPECIFIED), {
+        codec: CODEC,
+        store: new LocalObjectStore(root)
+      })
+    ).rejects.toHaveProperty("reason", "invalid_purpose");
+  });
+});
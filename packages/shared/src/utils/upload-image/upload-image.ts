@@ -0,0 +1,259 @@
+import {
+  AssetPurpose,
+  AssetRating,
+  UploadImageMetadata,
+  UploadImageRequest,
+  UploadImageResponse
+} from "@storiny/proto/dist/asset_def/v1/def";
+import { randomUUID as random_uuid } from "crypto";
+
+import { ASSET_PROPS } from "../../constants/asset";
+import { encode_blurhash } from "../encode-blurhash";
+import { get_dominant_color } from "../get-dominant-color";
+import {
+  get_image_info,
+  IMAGE_CONTENT_TYPE,
+  ImageFormat
+} from "../get-image-info";
+import { strip_image_metadata } from "../strip-image-metadata";
+
+/**
+ * Decoded image with the pixel data in RGBA order
+ */
+export interface RawImage {
+  height: number;
+  pixels: Uint8ClampedArray;
+  width: number;
+}
+
+/**
+ * Decodes and encodes the images. Implemented using a native image library
+ * on the server.
+ */
+export interface ImageCodec {
+  decode: (bytes: Uint8Array, format: ImageFormat) => Promise<RawImage>;
+  encode: (image: RawImage, format: ImageFormat) => Promise<Uint8Array>;
+}
+
+/**
+ * Storage for the uploaded images
+ */
+export interface ObjectStore {
+  put: (key: string, data: Uint8Array, content_type: string) => Promise<void>;
+}
+
+export interface UploadImageOptions {
+  codec: ImageCodec;
+  /**
+   * Generates the ID of the image
+   */
+  generate_id?: () => string;
+  store: ObjectStore;
+}
+
+export type UploadImageErrorReason =
+  | "content_type_mismatch"
+  | "invalid_dimensions"
+  | "invalid_purpose"
+  | "missing_metadata"
+  | "size_mismatch"
+  | "too_large"
+  | "unsupported_format";
+
+export class UploadImageError extends Error {
+  constructor(public readonly reason: UploadImageErrorReason) {
+    super(`Unable to upload the image: ${reason}`);
+    this.name = "UploadImageError";
+  }
+}
+
+/**
+ * Widths (in pixels) of the resized variants generated for each purpose.
+ * The original image is always stored as well.
+ */
+export const IMAGE_VARIANT_WIDTHS: Partial<Record<AssetPurpose, number[]>> = {
+  [AssetPurpose.AVATAR /*           */]: [64, 128, 256],
+  [AssetPurpose.BANNER /*           */]: [640, 1280, 1920],
+  [AssetPurpose.SPLASH /*           */]: [320, 640, 1280],
+  [AssetPurpose.LOGO /*             */]: [64, 128, 256],
+  [AssetPurpose.NEWSLETTER_SPLASH /**/]: [320, 640, 1280],
+  [AssetPurpose.STORY /*            */]: [640, 1280]
+};
+
+/**
+ * Width of the thumbnail used to compute the colors
+ */
+const THUMBNAIL_WIDTH = 32;
+
+/**
+ * Downscales an image to a width using a box filter, preserving the aspect
+ * ratio. Images are never upscaled.
+ * @param image Image
+ * @param width Target width
+ */
+export const resize_image = (image: RawImage, width: number): RawImage => {
+  if (width >= image.width) {
+    return image;
+  }
+
+  const height = Math.max(1, Math.round((image.height * width) / image.width));
+  const pixels = new Uint8ClampedArray(width * height * 4);
+  const scale_x = image.width / width;
+  const scale_y = image.height / height;
+
+  for (let y = 0; y < height; y++) {
+    const y_start = Math.floor(y * scale_y);
+    const y_end = Math.max(y_start + 1, Math.floor((y + 1) * scale_y));
+
+    for (let x = 0; x < width; x++) {
+      const x_start = Math.floor(x * scale_x);
+      const x_end = Math.max(x_start + 1, Math.floor((x + 1) * scale_x));
+      const sum = [0, 0, 0, 0];
+
+      for (let j = y_start; j < y_end; j++) {
+        for (let i = x_start; i < x_end; i++) {
+          const offset = 4 * (j * image.width + i);
+
+          for (let c = 0; c < 4; c++) {
+            sum[c] += image.pixels[offset + c];
+          }
+        }
+      }
+
+      const count = (y_end - y_start) * (x_end - x_start);
+      pixels.set(sum.map((value) => value / count), 4 * (y * width + x));
+    }
+  }
+
+  return { width, height, pixels };
+};
+
+/**
+ * Reads the client stream of an `UploadImage` call, returning the metadata
+ * and the concatenated chunks
+ * @param stream Request stream
+ */
+export const read_upload_stream = async (
+  stream: AsyncIterable<UploadImageRequest>
+): Promise<{ bytes: Uint8Array; metadata: UploadImageMetadata }> => {
+  let metadata: UploadImageMetadata | undefined;
+  const chunks: Uint8Array[] = [];
+  let size = 0;
+
+  for await (const message of stream) {
+    if (!metadata) {
+      if (!message.metadata) {
+        throw new UploadImageError("missing_metadata");
+      }
+
+      metadata = message.metadata;
+
+      if (metadata.size > ASSET_PROPS.image.max_size) {
+        throw new UploadImageError("too_large");
+      }
+
+      continue;
+    }
+
+    if (message.chunk) {
+      size += message.chunk.length;
+
+      // Checked eagerly so that oversized uploads are not buffered
+      if (size > metadata.size) {
+        throw new UploadImageError("size_mismatch");
+      }
+
+      chunks.push(message.chunk);
+    }
+  }
+
+  if (!metadata) {
+    throw new UploadImageError("missing_metadata");
+  }
+
+  if (size !== metadata.size) {
+    throw new UploadImageError("size_mismatch");
+  }
+
+  const bytes = new Uint8Array(size);
+  let offset = 0;
+
+  for (const chunk of chunks) {
+    bytes.set(chunk, offset);
+    offset += chunk.length;
+  }
+
+  return { metadata, bytes };
+};
+
+/**
+ * Handles an `UploadImage` call. The image is validated, stripped of its
+ * metadata, and stored along with its resized variants under
+ * `<id>/original` and `<id>/<width>`.
+ * @param stream Request stream
+ * @param options Upload options
+ */
+export const upload_image = async (
+  stream: AsyncIterable<UploadImageRequest>,
+  options: UploadImageOptions
+): Promise<UploadImageResponse> => {
+  const { metadata, bytes } = await read_upload_stream(stream);
+  const widths = IMAGE_VARIANT_WIDTHS[metadata.purpose];
+
+  if (!widths) {
+    throw new UploadImageError("invalid_purpose");
+  }
+
+  const info = get_image_info(bytes);
+
+  if (!info) {
+    throw new UploadImageError("unsupported_format");
+  }
+
+  const content_type = IMAGE_CONTENT_TYPE[info.format];
+
+  if (metadata.content_type !== content_type) {
+    throw new UploadImageError("content_type_mismatch");
+  }
+
+  if (
+    [info.width, info.height].some(
+      (dimension) =>
+        dimension < ASSET_PROPS.image.min_dimension ||
+        dimension > ASSET_PROPS.image.max_dimension
+    )
+  ) {
+    throw new UploadImageError("invalid_dimensions");
+  }
+
+  const original = strip_image_metadata(bytes, info.format);
+  const image = await options.codec.decode(original, info.format);
+  const thumbnail = resize_image(image, THUMBNAIL_WIDTH);
+  const id = (options.generate_id || random_uuid)();
+
+  await options.store.put(`${id}/original`, original, content_type);
+
+  for (const width of widths.filter((width) => width < image.width)) {
+    await options.store.put(
+      `${id}/${width}`,
+      await options.codec.encode(resize_image(image, width), info.format),
+      content_type
+    );
+  }
+
+  return {
+    id,
+    hex: get_dominant_color(thumbnail.pixels) || "000000",
+    blurhash: encode_blurhash(
+      thumbnail.pixels,
+      thumbnail.width,
+      thumbnail.height
+    ),
+    width: image.width,
+    height: image.height,
+    rating:
+      metadata.rating === AssetRating.UNSPECIFIED
+        ? AssetRating.NOT_RATED
+        : metadata.rating
+  };
+};
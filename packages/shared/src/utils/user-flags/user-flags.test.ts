@@ -0,0 +1,72 @@
+import { BareUser } from "@storiny/proto/dist/user_def/v1/def";
+
+import { UserFlag } from "../../enums/user-flag";
+import {
+  get_suspension_flags,
+  has_user_flag,
+  is_grantable_user_flag,
+  is_suspended_user,
+  set_user_flag
+} from "./user-flags";
+
+const USER = BareUser.fromPartial({
+  id: "1",
+  public_flags: UserFlag.STAFF | UserFlag.EARLY_USER
+});
+
+describe("has_user_flag", () => {
+  it("tests the flags on a generated type", () => {
+    expect(has_user_flag(USER, UserFlag.STAFF)).toBeTrue();
+    expect(has_user_flag(USER, UserFlag.EARLY_USER)).toBeTrue();
+    expect(has_user_flag(USER, UserFlag.VERIFIED)).toBeFalse();
+  });
+});
+
+describe("set_user_flag", () => {
+  it("sets a flag without mutating the value", () => {
+    const user = set_user_flag(USER, UserFlag.PLUS_MEMBER);
+
+    expect(user.public_flags).toEqual(
+      UserFlag.STAFF | UserFlag.EARLY_USER | UserFlag.PLUS_MEMBER
+    );
+    expect(USER.public_flags).toEqual(UserFlag.STAFF | UserFlag.EARLY_USER);
+  });
+
+  it("clears a flag", () => {
+    expect(set_user_flag(USER, UserFlag.STAFF, false).public_flags).toEqual(
+      UserFlag.EARLY_USER
+    );
+  });
+
+  it("is idempotent", () => {
+    expect(set_user_flag(USER, UserFlag.STAFF).public_flags).toEqual(
+      USER.public_flags
+    );
+    expect(
+      set_user_flag(USER, UserFlag.VERIFIED, false).public_flags
+    ).toEqual(USER.public_flags);
+  });
+});
+
+describe("is_grantable_user_flag", () => {
+  it("excludes the suspension flags", () => {
+    expect(is_grantable_user_flag(UserFlag.VERIFIED)).toBeTrue();
+    expect(is_grantable_user_flag(UserFlag.TEMPORARILY_SUSPENDED)).toBeFalse();
+    expect(is_grantable_user_flag(UserFlag.PERMANENTLY_SUSPENDED)).toBeFalse();
+    expect(is_grantable_user_flag(3)).toBeFalse();
+  });
+});
+
+describe("suspension", () => {
+  it("suspends and unsuspends a user", () => {
+    const temporary = get_suspension_flags(USER.public_flags, false);
+    const permanent = get_suspension_flags(temporary, true);
+
+    expect(is_suspended_user({ public_flags: temporary })).toBeTrue();
+    expect(permanent).toEqual(
+      UserFlag.STAFF | UserFlag.EARLY_USER | UserFlag.PERMANENTLY_SUSPENDED
+    );
+    expect(get_suspension_flags(permanent, null)).toEqual(USER.public_flags);
+    expect(is_suspended_user(USER)).toBeFalse();
+  });
+});
@@ -0,0 +1,85 @@
+import { UserFlag } from "../../enums/user-flag";
+
+/**
+ * Any generated type carrying the `public_flags` bitfield (`BareUser`,
+ * `ExtendedUser`, `GetProfileResponse`, ...)
+ */
+export interface Flagged {
+  public_flags: number;
+}
+
+/**
+ * Flags that can be granted and revoked by the administrators. The suspension
+ * flags are managed through `SuspendUser` and `UnsuspendUser` instead.
+ */
+export const GRANTABLE_USER_FLAGS: UserFlag[] = [
+  UserFlag.STAFF,
+  UserFlag.VERIFIED,
+  UserFlag.EARLY_USER,
+  UserFlag.PLUS_MEMBER
+];
+
+const SUSPENSION_MASK =
+  UserFlag.TEMPORARILY_SUSPENDED | UserFlag.PERMANENTLY_SUSPENDED;
+
+/**
+ * Predicate function for determining whether the flag is set
+ * @param target Flagged value
+ * @param flag Flag to test
+ */
+export const has_user_flag = (target: Flagged, flag: UserFlag): boolean =>
+  (target.public_flags & flag) === flag;
+
+/**
+ * Sets or clears a flag, returning a copy of the flagged value
+ * @param target Flagged value
+ * @param flag Flag to set
+ * @param enabled Whether to set or clear the flag
+ */
+export const set_user_flag = <T extends Flagged>(
+  target: T,
+  flag: UserFlag,
+  enabled = true
+): T => ({
+  ...target,
+  public_flags: enabled
+    ? target.public_flags | flag
+    : target.public_flags & ~flag
+});
+
+/**
+ * Predicate function for determining whether the flag can be granted or
+ * revoked through `GrantUserFlag` and `RevokeUserFlag`
+ * @param flag Flag
+ */
+export const is_grantable_user_flag = (flag: number): flag is UserFlag =>
+  (GRANTABLE_USER_FLAGS as number[]).includes(flag);
+
+/**
+ * Predicate function for determining whether the user has been suspended,
+ * either temporarily or permanently
+ * @param target Flagged value
+ */
+export const is_suspended_user = (target: Flagged): boolean =>
+  (target.public_flags & SUSPENSION_MASK) !== 0;
+
+/**
+ * Replaces the suspension flag of a user, returning the new bitfield
+ * @param public_flags Current bitfield
+ * @param permanent Whether the suspension is permanent, `null` to lift the
+ * suspension
+ */
+export const get_suspension_flags = (
+  public_flags: number,
+  permanent: boolean | null
+): number => {
+  const flags = public_flags & ~SUSPENSION_MASK;
+
+  if (permanent === null) {
+    return flags;
+  }
+
+  return permanent
+    ? flags | UserFlag.PERMANENTLY_SUSPENDED
+    : flags | UserFlag.TEMPORARILY_SUSPENDED;
+};
@@ -0,0 +1,181 @@
+import {
+  ExtendedStatus,
+  StatusDuration,
+  StatusVisibility
+} from "@storiny/proto/dist/user_def/v1/def";
+
+import {
+  get_status_expires_at,
+  get_visible_status,
+  is_status_expired,
+  StatusViewerRelation,
+  sweep_expired_statuses,
+  validate_set_status_request
+} from "./user-status";
+
+const NOW = new Date("2026-01-01T00:00:00.000Z");
+
+const STRANGER: StatusViewerRelation = {
+  is_self: false,
+  is_following: false,
+  is_friend: false
+};
+
+const get_status = (visibility: StatusVisibility): ExtendedStatus => ({
+  emoji: "🌱",
+  text: "Gardening",
+  expires_at_ts: get_status_expires_at(StatusDuration.HR_4, NOW),
+  duration: StatusDuration.HR_4,
+  visibility
+});
+
+describe("validate_set_status_request", () => {
+  const REQUEST = {
+    user_id: "1",
+    text: "Gardening",
+    duration: StatusDuration.DAY_1,
+    visibility: StatusVisibility.GLOBAL
+  };
+
+  it("accepts a valid request", () => {
+    expect(validate_set_status_request(REQUEST)).toBeNull();
+  });
+
+  it("rejects an empty status", () => {
+    expect(validate_set_status_request({ ...REQUEST, text: " " })).toEqual(
+      "empty"
+    );
+  });
+
+  it("rejects unspecified values", () => {
+    expect(
+      validate_set_status_request({
+        ...REQUEST,
+        duration: StatusDuration.UNSPECIFIED
+      })
+    ).toEqual("invalid_duration");
+    expect(
+      validate_set_status_request({
+        ...REQUEST,
+        visibility: StatusVisibility.UNSPECIFIED
+      })
+    ).toEqual("invalid_visibility");
+  });
+});
+
+describe("get_status_expires_at", () => {
+  it("computes the expiry date from the duration", () => {
+    expect(get_status_expires_at(StatusDuration.MIN_30, NOW)).toEqual(
+      new Date("2026-01-01T00:30:00.000Z")
+    );
+    expect(get_status_expires_at(StatusDuration.DAY_1, NOW)).toEqual(
+      new Date("2026-01-02T00:00:00.000Z")
+    );
+  });
+
+  it("does not expire statuses with `NEVER` duration", () => {
+    expect(get_status_expires_at(StatusDuration.NEVER, NOW)).toBeUndefined();
+  });
+
+  it("throws for unspecified durations", () => {
+    expect(() =>
+      get_status_expires_at(StatusDuration.UNSPECIFIED, NOW)
+    ).toThrow();
+  });
+});
+
+describe("is_status_expired", () => {
+  it("expires statuses once `expires_at` has passed", () => {
+    const status = get_status(StatusVisibility.GLOBAL);
+
+    expect(is_status_expired(status, NOW)).toBeFalse();
+    expect(
+      is_status_expired(status, new Date("2026-01-01T04:00:00.000Z"))
+    ).toBeTrue();
+    expect(is_status_expired({}, NOW)).toBeFalse();
+  });
+
+  it("accepts the deprecated string timestamp", () => {
+    expect(
+      is_status_expired({ expires_at: "2026-01-01T04:00:00.000Z" }, NOW)
+    ).toBeFalse();
+    expect(
+      is_status_expired({ expires_at: "2025-12-31T00:00:00.000Z" }, NOW)
+    ).toBeTrue();
+  });
+});
+
+describe("get_visible_status", () => {
+  it("shows global statuses to everyone", () => {
+    expect(
+      get_visible_status(get_status(StatusVisibility.GLOBAL), STRANGER, NOW)
+    ).toBeDefined();
+  });
+
+  it("shows follower statuses to followers and friends", () => {
+    const status = get_status(StatusVisibility.FOLLOWERS);
+
+    expect(get_visible_status(status, STRANGER, NOW)).toBeUndefined();
+    expect(
+      get_visible_status(status, { ...STRANGER, is_following: true }, NOW)
+    ).toBeDefined();
+    expect(
+      get_visible_status(status, { ...STRANGER, is_friend: true }, NOW)
+    ).toBeDefined();
+  });
+
+  it("shows friend statuses to friends only", () => {
+    const status = get_status(StatusVisibility.FRIENDS);
+
+    expect(
+      get_visible_status(status, { ...STRANGER, is_following: true }, NOW)
+    ).toBeUndefined();
+    expect(
+      get_visible_status(status, { ...STRANGER, is_friend: true }, NOW)
+    ).toBeDefined();
+  });
+
+  it("always shows the status to its owner", () => {
+    expect(
+      get_visible_status(
+        get_status(StatusVisibility.FRIENDS),
+        { ...STRANGER, is_self: true },
+        NOW
+      )
+    ).toEqual({
+      emoji: "🌱",
+      text: "Gardening",
+      expires_at_ts: new Date("2026-01-01T04:00:00.000Z")
+    });
+  });
+
+  it("hides expired statuses", () => {
+    expect(
+      get_visible_status(
+        get_status(StatusVisibility.GLOBAL),
+        STRANGER,
+        new Date("2026-01-02T00:00:00.000Z")
+      )
+    ).toBeUndefined();
+  });
+});
+
+describe("sweep_expired_statuses", () => {
+  it("clears the expired statuses in batches", async () => {
+    const expiring = ["1", "2", "3", "4", "5"];
+    const clear = jest.fn(async (user_ids: string[]) => {
+      user_ids.forEach((id) => expiring.splice(expiring.indexOf(id), 1));
+    });
+
+    expect(
+      await sweep_expired_statuses({
+        batch_size: 2,
+        clock: () => NOW,
+        clear,
+        get_expired: async (_, limit) => expiring.slice(0, limit)
+      })
+    ).toEqual(5);
+    expect(clear).toHaveBeenCalledTimes(3);
+    expect(expiring).toHaveLength(0);
+  });
+});
@@ -0,0 +1,192 @@
+import {
+  BareStatus,
+  ExtendedStatus,
+  SetStatusRequest,
+  StatusDuration,
+  StatusVisibility
+} from "@storiny/proto/dist/user_def/v1/def";
+
+import { USER_PROPS } from "../../constants/user";
+import { get_timestamp } from "../timestamp-fields";
+
+const MINUTE = 60 * 1000;
+
+/**
+ * Lifetime of the status (in ms) for each duration. `null` is used for the
+ * statuses that never expire.
+ */
+export const STATUS_DURATION_MS: Partial<
+  Record<StatusDuration, number | null>
+> = {
+  [StatusDuration.NEVER /* */]: null,
+  [StatusDuration.MIN_30 /**/]: 30 * MINUTE,
+  [StatusDuration.MIN_60 /**/]: 60 * MINUTE,
+  [StatusDuration.HR_4 /*  */]: 4 * 60 * MINUTE,
+  [StatusDuration.DAY_1 /* */]: 24 * 60 * MINUTE
+};
+
+const STATUS_VISIBILITIES: StatusVisibility[] = [
+  StatusVisibility.GLOBAL,
+  StatusVisibility.FOLLOWERS,
+  StatusVisibility.FRIENDS
+];
+
+/**
+ * Relation of the viewer with the owner of the status
+ */
+export interface StatusViewerRelation {
+  /**
+   * Whether the viewer follows the owner of the status
+   */
+  is_following: boolean;
+  is_friend: boolean;
+  is_self: boolean;
+}
+
+export type StatusError =
+  | "empty"
+  | "invalid_duration"
+  | "invalid_visibility"
+  | "text_too_long";
+
+/**
+ * Validates a `SetStatus` request, returning the error if the request is
+ * invalid, or `null` otherwise
+ * @param request Request
+ */
+export const validate_set_status_request = (
+  request: SetStatusRequest
+): StatusError | null => {
+  if (!request.emoji?.trim() && !request.text?.trim()) {
+    return "empty";
+  }
+
+  if ((request.text?.length || 0) > USER_PROPS.status.text.max_length) {
+    return "text_too_long";
+  }
+
+  if (STATUS_DURATION_MS[request.duration] === undefined) {
+    return "invalid_duration";
+  }
+
+  if (!STATUS_VISIBILITIES.includes(request.visibility)) {
+    return "invalid_visibility";
+  }
+
+  return null;
+};
+
+/**
+ * Returns the expiry date for a status, or `undefined` for the statuses that
+ * never expire
+ * @param duration Duration of the status
+ * @param now Date at which the status is set
+ */
+export const get_status_expires_at = (
+  duration: StatusDuration,
+  now: Date
+): Date | undefined => {
+  const lifetime = STATUS_DURATION_MS[duration];
+
+  if (lifetime === undefined) {
+    throw new Error(`Invalid status duration: ${duration}`);
+  }
+
+  return lifetime === null ? undefined : new Date(now.getTime() + lifetime);
+};
+
+/**
+ * Predicate function for determining whether a status has expired
+ * @param status Status
+ * @param now Current date
+ */
+export const is_status_expired = (
+  status: Pick<BareStatus, "expires_at" | "expires_at_ts">,
+  now: Date
+): boolean => {
+  const expires_at = get_timestamp(status, "expires_at");
+
+  return expires_at !== undefined && expires_at.getTime() <= now.getTime();
+};
+
+/**
+ * Returns the status that can be shown to a viewer, or `undefined` when the
+ * status is hidden from the viewer or has expired
+ * @param status Status
+ * @param relation Relation of the viewer with the owner of the status
+ * @param now Current date
+ */
+export const get_visible_status = (
+  status: ExtendedStatus | undefined,
+  relation: StatusViewerRelation,
+  now: Date
+): BareStatus | undefined => {
+  if (!status || is_status_expired(status, now)) {
+    return undefined;
+  }
+
+  const is_visible =
+    relation.is_self ||
+    status.visibility === StatusVisibility.GLOBAL ||
+    (status.visibility === StatusVisibility.FOLLOWERS &&
+      (relation.is_following || relation.is_friend)) ||
+    (status.visibility === StatusVisibility.FRIENDS && relation.is_friend);
+
+  return is_visible
+    ? {
+        emoji: status.emoji,
+        text: status.text,
+        expires_at: status.expires_at,
+        expires_at_ts: status.expires_at_ts
+      }
+    : undefined;
+};
+
+export interface StatusSweeperOptions {
+  /**
+   * Maximum number of statuses cleared in a single batch
+   */
+  batch_size?: number;
+  /**
+   * Clears the statuses of the users
+   */
+  clear: (user_ids: string[]) => Promise<void>;
+  /**
+   * Returns the current date
+   */
+  clock: () => Date;
+  /**
+   * Returns the IDs of the users whose statuses expire at or before the date
+   */
+  get_expired: (before: Date, limit: number) => Promise<string[]>;
+}
+
+/**
+ * Clears every status that has expired, returning the number of statuses
+ * cleared. Meant to be run periodically in the background.
+ * @param options Sweeper options
+ */
+export const sweep_expired_statuses = async (
+  options: StatusSweeperOptions
+): Promise<number> => {
+  const batch_size = options.batch_size ?? 500;
+  const now = options.clock();
+  let cleared = 0;
+
+  for (;;) {
+    const user_ids = await options.get_expired(now, batch_size);
+
+    if (!user_ids.length) {
+      break;
+    }
+
+    await options.clear(user_ids);
+    cleared += user_ids.length;
+
+    if (user_ids.length < batch_size) {
+      break;
+    }
+  }
+
+  return cleared;
+};
@@ -0,0 +1 @@
+export * from "./validate-profile-update";
@@ -0,0 +1,110 @@
+import { UpdateProfileRequest } from "@storiny/proto/dist/profile_def/v1/def";
+
+import {
+  get_next_username_change_at,
+  is_reserved_username,
+  validate_profile_update
+} from "./validate-profile-update";
+
+const NOW = new Date("2026-02-01T00:00:00.000Z");
+
+const REQUEST: UpdateProfileRequest = {
+  user_id: "1",
+  update_mask: ["name", "username", "bio", "location"],
+  name: "Someone",
+  username: "someone_else",
+  bio: "Hello",
+  location: "Earth"
+};
+
+const CONTEXT = { username: "someone" };
+
+describe("is_reserved_username", () => {
+  it("matches reserved usernames regardless of case", () => {
+    expect(is_reserved_username("Explore")).toBeTrue();
+    expect(is_reserved_username("someone")).toBeFalse();
+  });
+});
+
+describe("get_next_username_change_at", () => {
+  it("returns the end of the cooldown", () => {
+    expect(
+      get_next_username_change_at(new Date("2026-01-15T00:00:00.000Z"), NOW)
+    ).toEqual(new Date("2026-02-14T00:00:00.000Z"));
+  });
+
+  it("returns `undefined` once the cooldown is over", () => {
+    expect(
+      get_next_username_change_at(new Date("2025-12-01T00:00:00.000Z"), NOW)
+    ).toBeUndefined();
+    expect(get_next_username_change_at(null, NOW)).toBeUndefined();
+  });
+});
+
+describe("validate_profile_update", () => {
+  it("accepts a valid request", () => {
+    expect(validate_profile_update(REQUEST, CONTEXT, NOW)).toBeNull();
+  });
+
+  it("rejects unknown paths", () => {
+    expect(
+      validate_profile_update(
+        { ...REQUEST, update_mask: ["public_flags"] },
+        CONTEXT,
+        NOW
+      )
+    ).toEqual("invalid_path");
+  });
+
+  it("ignores the fields outside the mask", () => {
+    expect(
+      validate_profile_update(
+        { ...REQUEST, update_mask: ["bio"], name: "" },
+        CONTEXT,
+        NOW
+      )
+    ).toBeNull();
+  });
+
+  it("rejects invalid and reserved usernames", () => {
+    expect(
+      validate_profile_update(
+        { ...REQUEST, username: "no spaces" },
+        CONTEXT,
+        NOW
+      )
+    ).toEqual("invalid_username");
+    expect(
+      validate_profile_update(
+        { ...REQUEST, username: "settings" },
+        CONTEXT,
+        NOW
+      )
+    ).toEqual("reserved_username");
+  });
+
+  it("enforces the username cooldown", () => {
+    const context = {
+      ...CONTEXT,
+      username_changed_at: new Date("2026-01-20T00:00:00.000Z")
+    };
+
+    expect(validate_profile_update(REQUEST, context, NOW)).toEqual(
+      "username_cooldown"
+    );
+    // Case changes are not considered username changes
+    expect(
+      validate_profile_update({ ...REQUEST, username: "SomeOne" }, context, NOW)
+    ).toBeNull();
+  });
+
+  it("rejects a bio that is too long", () => {
+    expect(
+      validate_profile_update(
+        { ...REQUEST, bio: "a".repeat(1024) },
+        CONTEXT,
+        NOW
+      )
+    ).toEqual("invalid_bio");
+  });
+});
@@ -0,0 +1,166 @@
+import { UpdateProfileRequest } from "@storiny/proto/dist/profile_def/v1/def";
+
+import { USER_SCHEMA } from "../../constants/user";
+
+const DAY = 24 * 60 * 60 * 1000;
+
+export const PROFILE_UPDATE_PROPS = {
+  // Minimum time between two username changes (in days)
+  username_cooldown: 30
+} as const;
+
+/**
+ * Paths that can be present in the update mask
+ */
+export const PROFILE_UPDATE_PATHS = [
+  "name",
+  "username",
+  "bio",
+  "location",
+  "avatar_id",
+  "banner_id"
+] as const;
+
+/**
+ * Usernames that collide with the routes or could be used to impersonate
+ * the platform
+ */
+export const RESERVED_USERNAMES: ReadonlySet<string> = new Set([
+  "about",
+  "admin",
+  "api",
+  "auth",
+  "blog",
+  "blogs",
+  "branding",
+  "dashboard",
+  "explore",
+  "help",
+  "legal",
+  "login",
+  "logout",
+  "me",
+  "membership",
+  "moderator",
+  "new",
+  "notifications",
+  "null",
+  "onboarding",
+  "plus",
+  "profile",
+  "root",
+  "settings",
+  "signup",
+  "staff",
+  "storiny",
+  "support",
+  "tag",
+  "tags",
+  "undefined"
+]);
+
+export type ProfileUpdateError =
+  | "invalid_bio"
+  | "invalid_location"
+  | "invalid_name"
+  | "invalid_path"
+  | "invalid_username"
+  | "reserved_username"
+  | "username_cooldown";
+
+export interface ProfileUpdateContext {
+  /**
+   * Current username of the user
+   */
+  username: string;
+  /**
+   * Date of the last username change
+   */
+  username_changed_at?: Date | null;
+}
+
+/**
+ * Predicate function for determining whether a username is reserved
+ * @param username Username
+ */
+export const is_reserved_username = (username: string): boolean =>
+  RESERVED_USERNAMES.has(username.toLowerCase());
+
+/**
+ * Returns the date after which the username can be changed again, or
+ * `undefined` when it can be changed right away
+ * @param username_changed_at Date of the last username change
+ * @param now Current date
+ */
+export const get_next_username_change_at = (
+  username_changed_at: Date | null | undefined,
+  now: Date
+): Date | undefined => {
+  if (!username_changed_at) {
+    return undefined;
+  }
+
+  const next =
+    username_changed_at.getTime() +
+    PROFILE_UPDATE_PROPS.username_cooldown * DAY;
+
+  return next > now.getTime() ? new Date(next) : undefined;
+};
+
+/**
+ * Validates an `UpdateProfile` request, returning the first error, or `null`
+ * when the request is valid
+ * @param request Request
+ * @param context Current state of the profile
+ * @param now Current date
+ */
+export const validate_profile_update = (
+  request: UpdateProfileRequest,
+  context: ProfileUpdateContext,
+  now: Date
+): ProfileUpdateError | null => {
+  const paths = new Set(request.update_mask || []);
+
+  for (const path of paths) {
+    if (!(PROFILE_UPDATE_PATHS as readonly string[]).includes(path)) {
+      return "invalid_path";
+    }
+  }
+
+  if (paths.has("name") && !USER_SCHEMA.name.safeParse(request.name).success) {
+    return "invalid_name";
+  }
+
+  if (
+    paths.has("username") &&
+    request.username.toLowerCase() !== context.username.toLowerCase()
+  ) {
+    if (!USER_SCHEMA.username.safeParse(request.username).success) {
+      return "invalid_username";
+    }
+
+    if (is_reserved_username(request.username)) {
+      return "reserved_username";
+    }
+
+    if (get_next_username_change_at(context.username_changed_at, now)) {
+      return "username_cooldown";
+    }
+  }
+
+  if (
+    paths.has("bio") &&
+    !USER_SCHEMA.bio.safeParse(request.bio || "").success
+  ) {
+    return "invalid_bio";
+  }
+
+  if (
+    paths.has("location") &&
+    !USER_SCHEMA.location.safeParse(request.location).success
+  ) {
+    return "invalid_location";
+  }
+
+  return null;
+};
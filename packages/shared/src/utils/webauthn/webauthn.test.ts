@@ -0,0 +1,377 @@
+import { createHash, createSign, generateKeyPairSync, KeyObject } from "crypto";
+
+import {
+  AuthenticationResponseJSON,
+  cbor_decode,
+  generate_webauthn_challenge,
+  get_authentication_options,
+  get_registration_options,
+  RegistrationResponseJSON,
+  verify_authentication,
+  verify_registration,
+  WebAuthnExpectation
+} from "./webauthn";
+
+const RP_ID = "storiny.com";
+const ORIGIN = "https://storiny.com";
+
+/**
+ * Minimal CBOR encoder for the software authenticator
+ * @param value Value to encode
+ */
+const cbor_encode = (value: unknown): Buffer => {
+  const head = (major: number, length: number): Buffer =>
+    length < 24
+      ? Buffer.from([(major << 5) | length])
+      : length < 256
+      ? Buffer.from([(major << 5) | 24, length])
+      : Buffer.from([(major << 5) | 25, length >> 8, length & 255]);
+
+  if (typeof value === "number") {
+    return value < 0 ? head(1, -1 - value) : head(0, value);
+  }
+
+  if (typeof value === "string") {
+    const bytes = Buffer.from(value);
+    return Buffer.concat([head(3, bytes.length), bytes]);
+  }
+
+  if (Buffer.isBuffer(value)) {
+    return Buffer.concat([head(2, value.length), value]);
+  }
+
+  if (value instanceof Map) {
+    return Buffer.concat([
+      head(5, value.size),
+      ...[...value].flatMap(([key, item]) => [
+        cbor_encode(key),
+        cbor_encode(item)
+      ])
+    ]);
+  }
+
+  throw new Error("Unsupported value");
+};
+
+/**
+ * Software authenticator holding a single ES256 credential
+ */
+class SoftwareAuthenticator {
+  readonly credential_id = Buffer.from("software-credential-id");
+  private readonly private_key: KeyObject;
+  private readonly public_key: KeyObject;
+  sign_count = 0;
+
+  constructor() {
+    const { privateKey, publicKey } = generateKeyPairSync("ec", {
+      namedCurve: "P-256"
+    });
+    this.private_key = privateKey;
+    this.public_key = publicKey;
+  }
+
+  /**
+   * Returns the authenticator data
+   * @param rp_id Relying party ID
+   * @param attested Whether to include the attested credential data
+   */
+  private get_auth_data(rp_id: string, attested: boolean): Buffer {
+    const flags = 0x01 | 0x04 | (attested ? 0x40 : 0);
+    const counter = Buffer.alloc(4);
+    counter.writeUInt32BE(this.sign_count);
+
+    const parts = [
+      createHash("sha256").update(rp_id).digest(),
+      Buffer.from([flags]),
+      counter
+    ];
+
+    if (attested) {
+      const { x, y } = this.public_key.export({ format: "jwk" });
+      const id_length = Buffer.alloc(2);
+      id_length.writeUInt16BE(this.credential_id.length);
+
+      parts.push(
+        Buffer.alloc(16),
+        id_length,
+        this.credential_id,
+        cbor_encode(
+          new Map<number, unknown>([
+            [1, 2],
+            [3, -7],
+            [-1, 1],
+            [-2, Buffer.from(x!, "base64url")],
+            [-3, Buffer.from(y!, "base64url")]
+          ])
+        )
+      );
+    }
+
+    return Buffer.concat(parts);
+  }
+
+  /**
+   * Performs the registration ceremony
+   * @param challenge Challenge from the relying party
+   * @param origin Origin of the client
+   * @param rp_id Relying party ID
+   */
+  create(
+    challenge: string,
+    origin = ORIGIN,
+    rp_id = RP_ID
+  ): RegistrationResponseJSON {
+    const client_data = Buffer.from(
+      JSON.stringify({ type: "webauthn.create", challenge, origin })
+    );
+    const id = this.credential_id.toString("base64url");
+
+    return {
+      id,
+      rawId: id,
+      type: "public-key",
+      response: {
+        clientDataJSON: client_data.toString("base64url"),
+        attestationObject: cbor_encode(
+          new Map<string, unknown>([
+            ["fmt", "none"],
+            ["attStmt", new Map()],
+            ["authData", this.get_auth_data(rp_id, true)]
+          ])
+        ).toString("base64url")
+      }
+    };
+  }
+
+  /**
+   * Performs the authentication ceremony
+   * @param challenge Challenge from the relying party
+   * @param origin Origin of the client
+   * @param rp_id Relying party ID
+   */
+  get(
+    challenge: string,
+    origin = ORIGIN,
+    rp_id = RP_ID
+  ): AuthenticationResponseJSON {
+    this.sign_count += 1;
+
+    const client_data = Buffer.from(
+      JSON.stringify({ type: "webauthn.get", challenge, origin })
+    );
+    const auth_data = this.get_auth_data(rp_id, false);
+    const signature = createSign("sha256")
+      .update(
+        Buffer.concat([
+          auth_data,
+          createHash("sha256").update(client_data).digest()
+        ])
+      )
+      .sign(this.private_key);
+    const id = this.credential_id.toString("base64url");
+
+    return {
+      id,
+      rawId: id,
+      type: "public-key",
+      response: {
+        clientDataJSON: client_data.toString("base64url"),
+        authenticatorData: auth_data.toString("base64url"),
+        signature: signature.toString("base64url"),
+        userHandle: null
+      }
+    };
+  }
+}
+
+/**
+ * Returns the expected ceremony values
+ * @param challenge Challenge issued for the ceremony
+ */
+const get_expectation = (challenge: string): WebAuthnExpectation => ({
+  challenge,
+  origin: ORIGIN,
+  rp_id: RP_ID
+});
+
+describe("cbor_decode", () => {
+  it("decodes nested items", () => {
+    const [value] = cbor_decode(
+      cbor_encode(
+        new Map<unknown, unknown>([
+          ["a", 1],
+          [-2, Buffer.from([1, 2])],
+          [3, "text"]
+        ])
+      )
+    );
+
+    expect(value).toEqual(
+      new Map<unknown, unknown>([
+        ["a", 1],
+        [-2, Buffer.from([1, 2])],
+        [3, "text"]
+      ])
+    );
+  });
+});
+
+describe("get_registration_options", () => {
+  it("returns the creation options", () => {
+    const options = get_registration_options({
+      challenge: "challenge",
+      rp_id: RP_ID,
+      user_id: "1",
+      user_name: "someone",
+      exclude_credential_ids: ["existing"]
+    });
+
+    expect(options).toMatchObject({
+      challenge: "challenge",
+      rp: { id: RP_ID },
+      user: { name: "someone" },
+      excludeCredentials: [{ type: "public-key", id: "existing" }]
+    });
+  });
+});
+
+describe("get_authentication_options", () => {
+  it("returns the request options", () => {
+    expect(
+      get_authentication_options({ challenge: "challenge", rp_id: RP_ID })
+    ).toMatchObject({ challenge: "challenge", rpId: RP_ID });
+  });
+});
+
+describe("verify_registration", () => {
+  it("verifies a registration response", () => {
+    const authenticator = new SoftwareAuthenticator();
+    const challenge = generate_webauthn_challenge();
+    const passkey = verify_registration(
+      authenticator.create(challenge),
+      get_expectation(challenge)
+    );
+
+    expect(passkey.credential_id).toEqual(
+      authenticator.credential_id.toString("base64url")
+    );
+    expect(passkey.sign_count).toEqual(0);
+  });
+
+  it("rejects a mismatching challenge", () => {
+    const authenticator = new SoftwareAuthenticator();
+
+    expect(() =>
+      verify_registration(
+        authenticator.create(generate_webauthn_challenge()),
+        get_expectation(generate_webauthn_challenge())
+      )
+    ).toThrow("Challenge mismatch");
+  });
+
+  it("rejects a mismatching origin", () => {
+    const authenticator = new SoftwareAuthenticator();
+    const challenge = generate_webauthn_challenge();
+
+    expect(() =>
+      verify_registration(
+        authenticator.create(challenge, "https://example.com"),
+        get_expectation(challenge)
+      )
+    ).toThrow("Origin mismatch");
+  });
+
+  it("rejects a mismatching relying party ID", () => {
+    const authenticator = new SoftwareAuthenticator();
+    const challenge = generate_webauthn_challenge();
+
+    expect(() =>
+      verify_registration(
+        authenticator.create(challenge, ORIGIN, "example.com"),
+        get_expectation(challenge)
+      )
+    ).toThrow("Relying party ID mismatch");
+  });
+});
+
+describe("verify_authentication", () => {
+  /**
+   * Registers a new software authenticator
+   */
+  const register = (): {
+    authenticator: SoftwareAuthenticator;
+    passkey: ReturnType<typeof verify_registration>;
+  } => {
+    const authenticator = new SoftwareAuthenticator();
+    const challenge = generate_webauthn_challenge();
+    const passkey = verify_registration(
+      authenticator.create(challenge),
+      get_expectation(challenge)
+    );
+
+    return { authenticator, passkey };
+  };
+
+  it("verifies an authentication response", () => {
+    const { authenticator, passkey } = register();
+    const challenge = generate_webauthn_challenge();
+
+    expect(
+      verify_authentication(
+        authenticator.get(challenge),
+        passkey,
+        get_expectation(challenge)
+      )
+    ).toEqual({ sign_count: 1 });
+  });
+
+  it("rejects a signature from another authenticator", () => {
+    const { passkey } = register();
+    const challenge = generate_webauthn_challenge();
+    const response = new SoftwareAuthenticator().get(challenge);
+
+    expect(() =>
+      verify_authentication(response, passkey, get_expectation(challenge))
+    ).toThrow("Invalid signature");
+  });
+
+  it("rejects a replayed response", () => {
+    const { authenticator, passkey } = register();
+    const challenge = generate_webauthn_challenge();
+    const response = authenticator.get(challenge);
+    const { sign_count } = verify_authentication(
+      response,
+      passkey,
+      get_expectation(challenge)
+    );
+
+    expect(() =>
+      verify_authentication(
+        response,
+        { ...passkey, sign_count },
+        get_expectation(challenge)
+      )
+    ).toThrow("Signature counter did not increase");
+  });
+
+  it("rejects a registration response", () => {
+    const { authenticator, passkey } = register();
+    const challenge = generate_webauthn_challenge();
+    const { response, ...rest } = authenticator.create(challenge);
+
+    expect(() =>
+      verify_authentication(
+        {
+          ...rest,
+          response: {
+            clientDataJSON: response.clientDataJSON,
+            authenticatorData: "",
+            signature: ""
+          }
+        },
+        passkey,
+        get_expectation(challenge)
+      )
+    ).toThrow();
+  });
+});
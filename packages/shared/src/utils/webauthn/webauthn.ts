@@ -0,0 +1,451 @@
+import {
+  createHash,
+  createPublicKey,
+  JsonWebKey,
+  randomBytes,
+  timingSafeEqual,
+  verify
+} from "crypto";
+
+type CborValue =
+  | number
+  | string
+  | boolean
+  | null
+  | undefined
+  | Buffer
+  | CborValue[]
+  | Map<CborValue, CborValue>;
+
+export interface RegistrationResponseJSON {
+  id: string;
+  rawId: string;
+  response: {
+    attestationObject: string;
+    clientDataJSON: string;
+  };
+  type: "public-key";
+}
+
+export interface AuthenticationResponseJSON {
+  id: string;
+  rawId: string;
+  response: {
+    authenticatorData: string;
+    clientDataJSON: string;
+    signature: string;
+    userHandle?: string | null;
+  };
+  type: "public-key";
+}
+
+export interface WebAuthnExpectation {
+  /**
+   * Base64url encoded challenge issued for the ceremony
+   */
+  challenge: string;
+  /**
+   * Origin of the client (such as `https://storiny.com`)
+   */
+  origin: string;
+  /**
+   * Relying party ID (such as `storiny.com`)
+   */
+  rp_id: string;
+}
+
+export interface VerifiedPasskey {
+  /**
+   * Base64url encoded credential ID
+   */
+  credential_id: string;
+  /**
+   * DER encoded SPKI public key
+   */
+  public_key: Buffer;
+  sign_count: number;
+}
+
+export const WEBAUTHN_PROPS = {
+  rp_name: "Storiny",
+  challenge_length: 32,
+  timeout: 300_000,
+  // ES256 and RS256 (COSE algorithm identifiers)
+  algorithms: [-7, -257]
+} as const;
+
+/**
+ * Authenticator data flags
+ */
+const FLAG = {
+  USER_PRESENT: 0x01,
+  ATTESTED_CREDENTIAL_DATA: 0x40
+} as const;
+
+/**
+ * Decodes a single CBOR item (RFC 8949). Only the definite-length items used
+ * by WebAuthn are supported.
+ * @param buffer Buffer to decode
+ * @param offset Offset to start decoding at
+ */
+export const cbor_decode = (
+  buffer: Buffer,
+  offset = 0
+): [value: CborValue, next_offset: number] => {
+  const initial = buffer[offset];
+  const major = initial >> 5;
+  const info = initial & 31;
+  let cursor = offset + 1;
+  let argument: number;
+
+  if (info < 24) {
+    argument = info;
+  } else if (info === 24) {
+    argument = buffer.readUInt8(cursor);
+    cursor += 1;
+  } else if (info === 25) {
+    argument = buffer.readUInt16BE(cursor);
+    cursor += 2;
+  } else if (info === 26) {
+    argument = buffer.readUInt32BE(cursor);
+    cursor += 4;
+  } else if (info === 27) {
+    argument = Number(buffer.readBigUInt64BE(cursor));
+    cursor += 8;
+  } else {
+    throw new Error("Unsupported CBOR item");
+  }
+
+  switch (major) {
+    case 0:
+      return [argument, cursor];
+    case 1:
+      return [-1 - argument, cursor];
+    case 2:
+      return [buffer.subarray(cursor, cursor + argument), cursor + argument];
+    case 3:
+      return [
+        buffer.toString("utf8", cursor, cursor + argument),
+        cursor + argument
+      ];
+    case 4: {
+      const items: CborValue[] = [];
+
+      for (let index = 0; index < argument; index++) {
+        const [item, next] = cbor_decode(buffer, cursor);
+        items.push(item);
+        cursor = next;
+      }
+
+      return [items, cursor];
+    }
+    case 5: {
+      const map = new Map<CborValue, CborValue>();
+
+      for (let index = 0; index < argument; index++) {
+        const [key, after_key] = cbor_decode(buffer, cursor);
+        const [value, after_value] = cbor_decode(buffer, after_key);
+        map.set(key, value);
+        cursor = after_value;
+      }
+
+      return [map, cursor];
+    }
+    case 7:
+      if (info === 20 || info === 21) {
+        return [info === 21, cursor];
+      }
+
+      if (info === 22 || info === 23) {
+        return [info === 22 ? null : undefined, cursor];
+      }
+  }
+
+  throw new Error("Unsupported CBOR item");
+};
+
+/**
+ * Converts a COSE public key to a DER encoded SPKI public key
+ * @param cose COSE key map
+ */
+const cose_to_spki = (cose: Map<CborValue, CborValue>): Buffer => {
+  const kty = cose.get(1);
+  const alg = cose.get(3);
+  let jwk: JsonWebKey;
+
+  if (kty === 2 && alg === -7 && cose.get(-1) === 1) {
+    jwk = {
+      kty: "EC",
+      crv: "P-256",
+      x: (cose.get(-2) as Buffer).toString("base64url"),
+      y: (cose.get(-3) as Buffer).toString("base64url")
+    };
+  } else if (kty === 3 && alg === -257) {
+    jwk = {
+      kty: "RSA",
+      n: (cose.get(-1) as Buffer).toString("base64url"),
+      e: (cose.get(-2) as Buffer).toString("base64url")
+    };
+  } else {
+    throw new Error("Unsupported public key algorithm");
+  }
+
+  return createPublicKey({ key: jwk, format: "jwk" }).export({
+    type: "spki",
+    format: "der"
+  });
+};
+
+/**
+ * Parses the authenticator data
+ * @param data Authenticator data
+ */
+const parse_authenticator_data = (
+  data: Buffer
+): {
+  credential?: { id: Buffer; public_key: Buffer };
+  flags: number;
+  rp_id_hash: Buffer;
+  sign_count: number;
+} => {
+  if (data.length < 37) {
+    throw new Error("Authenticator data is too short");
+  }
+
+  const flags = data[32];
+  const result = {
+    rp_id_hash: data.subarray(0, 32),
+    flags,
+    sign_count: data.readUInt32BE(33)
+  };
+
+  if (!(flags & FLAG.ATTESTED_CREDENTIAL_DATA)) {
+    return result;
+  }
+
+  // Skip the 16 byte AAGUID
+  const id_length = data.readUInt16BE(53);
+  const id = data.subarray(55, 55 + id_length);
+  const [cose] = cbor_decode(data, 55 + id_length);
+
+  if (!(cose instanceof Map)) {
+    throw new Error("Invalid credential public key");
+  }
+
+  return { ...result, credential: { id, public_key: cose_to_spki(cose) } };
+};
+
+/**
+ * Verifies the client data and authenticator data shared by both ceremonies
+ * @param client_data_json Base64url encoded client data
+ * @param auth_data Authenticator data
+ * @param type Expected ceremony type
+ * @param expected Expected ceremony values
+ */
+const verify_common = (
+  client_data_json: string,
+  auth_data: ReturnType<typeof parse_authenticator_data>,
+  type: "webauthn.create" | "webauthn.get",
+  expected: WebAuthnExpectation
+): void => {
+  const client_data = JSON.parse(
+    Buffer.from(client_data_json, "base64url").toString("utf8")
+  );
+
+  if (client_data.type !== type) {
+    throw new Error("Invalid client data type");
+  }
+
+  if (client_data.challenge !== expected.challenge) {
+    throw new Error("Challenge mismatch");
+  }
+
+  if (client_data.origin !== expected.origin) {
+    throw new Error("Origin mismatch");
+  }
+
+  const rp_id_hash = createHash("sha256").update(expected.rp_id).digest();
+
+  if (!timingSafeEqual(auth_data.rp_id_hash, rp_id_hash)) {
+    throw new Error("Relying party ID mismatch");
+  }
+
+  if (!(auth_data.flags & FLAG.USER_PRESENT)) {
+    throw new Error("User was not present");
+  }
+};
+
+/**
+ * Generates a new base64url encoded challenge
+ */
+export const generate_webauthn_challenge = (): string =>
+  randomBytes(WEBAUTHN_PROPS.challenge_length).toString("base64url");
+
+/**
+ * Returns the options for the registration ceremony
+ * @param options Registration options
+ */
+export const get_registration_options = ({
+  challenge,
+  rp_id,
+  user_id,
+  user_name,
+  exclude_credential_ids = []
+}: {
+  challenge: string;
+  exclude_credential_ids?: string[];
+  rp_id: string;
+  user_id: string;
+  user_name: string;
+}): Record<string, unknown> => ({
+  challenge,
+  rp: { id: rp_id, name: WEBAUTHN_PROPS.rp_name },
+  user: {
+    id: Buffer.from(user_id).toString("base64url"),
+    name: user_name,
+    displayName: user_name
+  },
+  pubKeyCredParams: WEBAUTHN_PROPS.algorithms.map((alg) => ({
+    type: "public-key",
+    alg
+  })),
+  timeout: WEBAUTHN_PROPS.timeout,
+  attestation: "none",
+  excludeCredentials: exclude_credential_ids.map((id) => ({
+    type: "public-key",
+    id
+  })),
+  authenticatorSelection: {
+    residentKey: "preferred",
+    userVerification: "preferred"
+  }
+});
+
+/**
+ * Returns the options for the authentication ceremony
+ * @param options Authentication options
+ */
+export const get_authentication_options = ({
+  challenge,
+  rp_id,
+  allow_credential_ids = []
+}: {
+  allow_credential_ids?: string[];
+  challenge: string;
+  rp_id: string;
+}): Record<string, unknown> => ({
+  challenge,
+  rpId: rp_id,
+  timeout: WEBAUTHN_PROPS.timeout,
+  allowCredentials: allow_credential_ids.map((id) => ({
+    type: "public-key",
+    id
+  })),
+  userVerification: "preferred"
+});
+
+/**
+ * Verifies the response of the registration ceremony and returns the
+ * credential to store. Throws if the response is invalid.
+ * @param response Response from the client
+ * @param expected Expected ceremony values
+ */
+export const verify_registration = (
+  response: RegistrationResponseJSON,
+  expected: WebAuthnExpectation
+): VerifiedPasskey => {
+  const [attestation] = cbor_decode(
+    Buffer.from(response.response.attestationObject, "base64url")
+  );
+
+  if (!(attestation instanceof Map)) {
+    throw new Error("Invalid attestation object");
+  }
+
+  const auth_data = parse_authenticator_data(
+    attestation.get("authData") as Buffer
+  );
+
+  verify_common(
+    response.response.clientDataJSON,
+    auth_data,
+    "webauthn.create",
+    expected
+  );
+
+  if (!auth_data.credential) {
+    throw new Error("Missing attested credential data");
+  }
+
+  const credential_id = auth_data.credential.id.toString("base64url");
+
+  if (credential_id !== response.id) {
+    throw new Error("Credential ID mismatch");
+  }
+
+  return {
+    credential_id,
+    public_key: auth_data.credential.public_key,
+    sign_count: auth_data.sign_count
+  };
+};
+
+/**
+ * Verifies the response of the authentication ceremony against a stored
+ * credential and returns the next signature counter. Throws if the response
+ * is invalid.
+ * @param response Response from the client
+ * @param credential Stored credential
+ * @param expected Expected ceremony values
+ */
+export const verify_authentication = (
+  response: AuthenticationResponseJSON,
+  credential: VerifiedPasskey,
+  expected: WebAuthnExpectation
+): { sign_count: number } => {
+  if (response.id !== credential.credential_id) {
+    throw new Error("Credential ID mismatch");
+  }
+
+  const raw_auth_data = Buffer.from(
+    response.response.authenticatorData,
+    "base64url"
+  );
+  const auth_data = parse_authenticator_data(raw_auth_data);
+
+  verify_common(
+    response.response.clientDataJSON,
+    auth_data,
+    "webauthn.get",
+    expected
+  );
+
+  const client_data_hash = createHash("sha256")
+    .update(Buffer.from(response.response.clientDataJSON, "base64url"))
+    .digest();
+  const public_key = createPublicKey({
+    key: credential.public_key,
+    format: "der",
+    type: "spki"
+  });
+  const is_valid = verify(
+    "sha256",
+    Buffer.concat([raw_auth_data, client_data_hash]),
+    public_key,
+    Buffer.from(response.response.signature, "base64url")
+  );
+
+  if (!is_valid) {
+    throw new Error("Invalid signature");
+  }
+
+  // A counter that does not increase indicates a cloned authenticator
+  if (
+    (auth_data.sign_count !== 0 || credential.sign_count !== 0) &&
+    auth_data.sign_count <= credential.sign_count
+  ) {
+    throw new Error("Signature counter did not increase");
+  }
+
+  return { sign_count: auth_data.sign_count };
+};
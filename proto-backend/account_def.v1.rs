@@ -0,0 +1,157 @@
+// @generated
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccountExport {
+    #[prost(string, tag="1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(enumeration="AccountExportStatus", tag="2")]
+    pub status: i32,
+    #[prost(message, optional, tag="3")]
+    pub created_at: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(message, optional, tag="4")]
+    pub completed_at: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// Expiry of the archive and its download token
+    #[prost(message, optional, tag="5")]
+    pub expires_at: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// Only present when the archive is ready
+    #[prost(string, optional, tag="6")]
+    pub download_token: ::core::option::Option<::prost::alloc::string::String>,
+    /// Size of the archive (in bytes)
+    #[prost(uint64, optional, tag="7")]
+    pub size: ::core::option::Option<u64>,
+}
+// Request account export
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestAccountExportRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestAccountExportResponse {
+    #[prost(message, optional, tag="1")]
+    pub account_export: ::core::option::Option<AccountExport>,
+}
+// Get account export status
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAccountExportStatusRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub export_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAccountExportStatusResponse {
+    #[prost(message, optional, tag="1")]
+    pub account_export: ::core::option::Option<AccountExport>,
+}
+// Schedule account deletion
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScheduleAccountDeletionRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(enumeration="StoryDisposition", tag="2")]
+    pub story_disposition: i32,
+    /// Required for `STORY_DISPOSITION_TRANSFER_TO_BLOG`
+    #[prost(string, optional, tag="3")]
+    pub blog_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScheduleAccountDeletionResponse {
+    /// End of the grace period
+    #[prost(message, optional, tag="1")]
+    pub scheduled_for: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+// Cancel account deletion
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelAccountDeletionRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelAccountDeletionResponse {
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AccountExportStatus {
+    Unspecified = 0,
+    Queued = 1,
+    Processing = 2,
+    Ready = 3,
+    Failed = 4,
+    Expired = 5,
+}
+impl AccountExportStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AccountExportStatus::Unspecified => "ACCOUNT_EXPORT_STATUS_UNSPECIFIED",
+            AccountExportStatus::Queued => "ACCOUNT_EXPORT_STATUS_QUEUED",
+            AccountExportStatus::Processing => "ACCOUNT_EXPORT_STATUS_PROCESSING",
+            AccountExportStatus::Ready => "ACCOUNT_EXPORT_STATUS_READY",
+            AccountExportStatus::Failed => "ACCOUNT_EXPORT_STATUS_FAILED",
+            AccountExportStatus::Expired => "ACCOUNT_EXPORT_STATUS_EXPIRED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ACCOUNT_EXPORT_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "ACCOUNT_EXPORT_STATUS_QUEUED" => Some(Self::Queued),
+            "ACCOUNT_EXPORT_STATUS_PROCESSING" => Some(Self::Processing),
+            "ACCOUNT_EXPORT_STATUS_READY" => Some(Self::Ready),
+            "ACCOUNT_EXPORT_STATUS_FAILED" => Some(Self::Failed),
+            "ACCOUNT_EXPORT_STATUS_EXPIRED" => Some(Self::Expired),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum StoryDisposition {
+    Unspecified = 0,
+    Delete = 1,
+    /// Keeps the stories under a placeholder user
+    Anonymize = 2,
+    /// Moves the stories to a co-owned blog
+    TransferToBlog = 3,
+}
+impl StoryDisposition {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            StoryDisposition::Unspecified => "STORY_DISPOSITION_UNSPECIFIED",
+            StoryDisposition::Delete => "STORY_DISPOSITION_DELETE",
+            StoryDisposition::Anonymize => "STORY_DISPOSITION_ANONYMIZE",
+            StoryDisposition::TransferToBlog => "STORY_DISPOSITION_TRANSFER_TO_BLOG",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "STORY_DISPOSITION_UNSPECIFIED" => Some(Self::Unspecified),
+            "STORY_DISPOSITION_DELETE" => Some(Self::Delete),
+            "STORY_DISPOSITION_ANONYMIZE" => Some(Self::Anonymize),
+            "STORY_DISPOSITION_TRANSFER_TO_BLOG" => Some(Self::TransferToBlog),
+            _ => None,
+        }
+    }
+}
+// @@protoc_insertion_point(module)
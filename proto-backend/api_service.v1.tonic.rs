@@ -5,6 +5,22 @@ pub mod api_service_client {
     use tonic::codegen::*;
     use tonic::codegen::http::Uri;
     /** Service definition
+
+ Every call must carry a signed service credential in the `authorization`
+ metadata, and the session token of the user (if any) in the
+ `x-session-token` metadata. The caller identity is derived from these, and the
+ `user_id`, `current_user_id`, `moderator_id`, and `admin_id` fields of the
+ requests can be omitted. When set, they must match the authenticated user.
+
+ The connections use mutual TLS, and the server only allows each method to be
+ invoked by the client services listed in its policy.
+
+ Some methods are rate limited per IP address (from the `x-client-ip`
+ metadata), user, or token. The rejected calls fail with `RESOURCE_EXHAUSTED`
+ and carry a `google.rpc.RetryInfo` in the details.
+
+ Failed RPCs carry an `error_def.v1.ErrorDetail` (and a `google.rpc.BadRequest`
+ for invalid requests) in the `google.rpc.Status` details
 */
     #[derive(Debug, Clone)]
     pub struct ApiServiceClient<T> {
@@ -1356,606 +1372,4511 @@ pub mod api_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-pub mod api_service_server {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with ApiServiceServer.
-    #[async_trait]
-    pub trait ApiService: Send + Sync + 'static {
-        /** *
- Checks whether the user is authenticated using the token from the session cookie
-*/
-        async fn get_user_id(
-            &self,
-            request: tonic::Request<super::super::super::user_def::v1::GetUserIdRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::super::super::user_def::v1::GetUserIdResponse>,
-            tonic::Status,
-        >;
-        /** *
- Returns the username for a user by its ID
-*/
-        async fn get_username(
-            &self,
-            request: tonic::Request<
-                super::super::super::user_def::v1::GetUsernameRequest,
-            >,
-        ) -> std::result::Result<
-            tonic::Response<super::super::super::user_def::v1::GetUsernameResponse>,
-            tonic::Status,
-        >;
         /** *
- Returns the profile page data for a user
+ Generates a pending TOTP secret for the user
 */
-        async fn get_profile(
-            &self,
-            request: tonic::Request<
-                super::super::super::profile_def::v1::GetProfileRequest,
+        pub async fn begin_mfa_enrollment(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::credential_settings_def::v1::BeginMfaEnrollmentRequest,
             >,
         ) -> std::result::Result<
-            tonic::Response<super::super::super::profile_def::v1::GetProfileResponse>,
-            tonic::Status,
-        >;
-        /** *
- Returns the tag page data for a tag
-*/
-        async fn get_tag(
-            &self,
-            request: tonic::Request<super::super::super::tag_def::v1::GetTagRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::super::super::tag_def::v1::GetTagResponse>,
-            tonic::Status,
-        >;
-        /** *
- Returns the token using its identifier
-*/
-        async fn get_token(
-            &self,
-            request: tonic::Request<super::super::super::token_def::v1::GetTokenRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::super::super::token_def::v1::GetTokenResponse>,
-            tonic::Status,
-        >;
-        /** *
- Verifies a user's email using the provided token identifier
-*/
-        async fn verify_email(
-            &self,
-            request: tonic::Request<
-                super::super::super::token_def::v1::VerifyEmailRequest,
+            tonic::Response<
+                super::super::super::credential_settings_def::v1::BeginMfaEnrollmentResponse,
             >,
-        ) -> std::result::Result<
-            tonic::Response<super::super::super::token_def::v1::VerifyEmailResponse>,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/BeginMfaEnrollment",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("api_service.v1.ApiService", "BeginMfaEnrollment"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Verifies a visitor's newsletter subscription request using the provided token identifier
+ Enables MFA for the user after verifying a code generated using the pending secret
 */
-        async fn verify_newsletter_subscription(
-            &self,
-            request: tonic::Request<
-                super::super::super::token_def::v1::VerifyNewsletterSubscriptionRequest,
+        pub async fn confirm_mfa_enrollment(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::credential_settings_def::v1::ConfirmMfaEnrollmentRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::token_def::v1::VerifyNewsletterSubscriptionResponse,
-            >,
-            tonic::Status,
-        >;
-        /** *
- Verifies a user's blog login request using the provided token identifier
-*/
-        async fn verify_blog_login(
-            &self,
-            request: tonic::Request<
-                super::super::super::blog_def::v1::VerifyBlogLoginRequest,
+                super::super::super::credential_settings_def::v1::ConfirmMfaEnrollmentResponse,
             >,
-        ) -> std::result::Result<
-            tonic::Response<super::super::super::blog_def::v1::VerifyBlogLoginResponse>,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/ConfirmMfaEnrollment",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("api_service.v1.ApiService", "ConfirmMfaEnrollment"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the user's credentials settings
+ Issues an MFA challenge for a login whose first factor (password or OAuth) has been verified
 */
-        async fn get_credential_settings(
-            &self,
-            request: tonic::Request<
-                super::super::super::credential_settings_def::v1::GetCredentialSettingsRequest,
+        pub async fn begin_mfa_challenge(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::credential_settings_def::v1::BeginMfaChallengeRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::credential_settings_def::v1::GetCredentialSettingsResponse,
+                super::super::super::credential_settings_def::v1::BeginMfaChallengeResponse,
             >,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/BeginMfaChallenge",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("api_service.v1.ApiService", "BeginMfaChallenge"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the user's privacy settings
+ Verifies a TOTP or recovery code against an MFA challenge during login
 */
-        async fn get_privacy_settings(
-            &self,
-            request: tonic::Request<
-                super::super::super::privacy_settings_def::v1::GetPrivacySettingsRequest,
+        pub async fn verify_mfa_code(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::credential_settings_def::v1::VerifyMfaCodeRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::privacy_settings_def::v1::GetPrivacySettingsResponse,
+                super::super::super::credential_settings_def::v1::VerifyMfaCodeResponse,
             >,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/VerifyMfaCode",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "VerifyMfaCode"));
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the user's notification settings
+ Disables MFA for the user
 */
-        async fn get_notification_settings(
-            &self,
-            request: tonic::Request<
-                super::super::super::notification_settings_def::v1::GetNotificationSettingsRequest,
+        pub async fn disable_mfa(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::credential_settings_def::v1::DisableMfaRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::notification_settings_def::v1::GetNotificationSettingsResponse,
+                super::super::super::credential_settings_def::v1::DisableMfaResponse,
             >,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/DisableMfa",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "DisableMfa"));
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the user's connection settings
+ Replaces the user's recovery codes
 */
-        async fn get_connection_settings(
-            &self,
-            request: tonic::Request<
-                super::super::super::connection_settings_def::v1::GetConnectionSettingsRequest,
+        pub async fn regenerate_mfa_recovery_codes(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::credential_settings_def::v1::RegenerateMfaRecoveryCodesRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::connection_settings_def::v1::GetConnectionSettingsResponse,
+                super::super::super::credential_settings_def::v1::RegenerateMfaRecoveryCodesResponse,
             >,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/RegenerateMfaRecoveryCodes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "api_service.v1.ApiService",
+                        "RegenerateMfaRecoveryCodes",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the user's login activity
+ Returns the options for registering a new passkey
 */
-        async fn get_login_activity(
-            &self,
-            request: tonic::Request<
-                super::super::super::login_activity_def::v1::GetLoginActivityRequest,
+        pub async fn begin_passkey_registration(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::credential_settings_def::v1::BeginPasskeyRegistrationRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::login_activity_def::v1::GetLoginActivityResponse,
+                super::super::super::credential_settings_def::v1::BeginPasskeyRegistrationResponse,
             >,
             tonic::Status,
-        >;
-        /** *
- Validates a story
-*/
-        async fn validate_story(
-            &self,
-            request: tonic::Request<
-                super::super::super::story_def::v1::ValidateStoryRequest,
-            >,
-        ) -> std::result::Result<
-            tonic::Response<super::super::super::story_def::v1::ValidateStoryResponse>,
-            tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/BeginPasskeyRegistration",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "api_service.v1.ApiService",
+                        "BeginPasskeyRegistration",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the user's drafts details
+ Verifies the attestation from the authenticator and registers the passkey
 */
-        async fn get_drafts_info(
-            &self,
-            request: tonic::Request<
-                super::super::super::story_def::v1::GetDraftsInfoRequest,
+        pub async fn finish_passkey_registration(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::credential_settings_def::v1::FinishPasskeyRegistrationRequest,
             >,
         ) -> std::result::Result<
-            tonic::Response<super::super::super::story_def::v1::GetDraftsInfoResponse>,
-            tonic::Status,
-        >;
-        /** *
- Returns the user's stories details
-*/
-        async fn get_stories_info(
-            &self,
-            request: tonic::Request<
-                super::super::super::story_def::v1::GetStoriesInfoRequest,
+            tonic::Response<
+                super::super::super::credential_settings_def::v1::FinishPasskeyRegistrationResponse,
             >,
-        ) -> std::result::Result<
-            tonic::Response<super::super::super::story_def::v1::GetStoriesInfoResponse>,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/FinishPasskeyRegistration",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "api_service.v1.ApiService",
+                        "FinishPasskeyRegistration",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the user's contributions details
+ Returns the options for authenticating using a passkey
 */
-        async fn get_contributions_info(
-            &self,
-            request: tonic::Request<
-                super::super::super::story_def::v1::GetContributionsInfoRequest,
+        pub async fn begin_passkey_authentication(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::credential_settings_def::v1::BeginPasskeyAuthenticationRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::story_def::v1::GetContributionsInfoResponse,
+                super::super::super::credential_settings_def::v1::BeginPasskeyAuthenticationResponse,
             >,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/BeginPasskeyAuthentication",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "api_service.v1.ApiService",
+                        "BeginPasskeyAuthentication",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the user's responses details
+ Verifies the assertion from the authenticator and returns the user ID
 */
-        async fn get_responses_info(
-            &self,
-            request: tonic::Request<
-                super::super::super::response_def::v1::GetResponsesInfoRequest,
+        pub async fn finish_passkey_authentication(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::credential_settings_def::v1::FinishPasskeyAuthenticationRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::response_def::v1::GetResponsesInfoResponse,
+                super::super::super::credential_settings_def::v1::FinishPasskeyAuthenticationResponse,
             >,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/FinishPasskeyAuthentication",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "api_service.v1.ApiService",
+                        "FinishPasskeyAuthentication",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the story's responses details
+ Removes a passkey registered by the user
 */
-        async fn get_story_responses_info(
-            &self,
-            request: tonic::Request<
-                super::super::super::response_def::v1::GetStoryResponsesInfoRequest,
+        pub async fn remove_passkey(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::credential_settings_def::v1::RemovePasskeyRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::response_def::v1::GetStoryResponsesInfoResponse,
+                super::super::super::credential_settings_def::v1::RemovePasskeyResponse,
             >,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/RemovePasskey",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "RemovePasskey"));
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the user's followed tag count
+ Links an external login provider to the user's account
 */
-        async fn get_followed_tag_count(
-            &self,
-            request: tonic::Request<
-                super::super::super::tag_def::v1::GetFollowedTagCountRequest,
+        pub async fn link_login_provider(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::credential_settings_def::v1::LinkLoginProviderRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::tag_def::v1::GetFollowedTagCountResponse,
+                super::super::super::credential_settings_def::v1::LinkLoginProviderResponse,
             >,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/LinkLoginProvider",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("api_service.v1.ApiService", "LinkLoginProvider"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the user's relations details
+ Unlinks an external login provider, unless it is the last remaining login method
 */
-        async fn get_user_relations_info(
-            &self,
-            request: tonic::Request<
-                super::super::super::user_def::v1::GetUserRelationsInfoRequest,
+        pub async fn unlink_login_provider(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::credential_settings_def::v1::UnlinkLoginProviderRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::user_def::v1::GetUserRelationsInfoResponse,
+                super::super::super::credential_settings_def::v1::UnlinkLoginProviderResponse,
             >,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/UnlinkLoginProvider",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("api_service.v1.ApiService", "UnlinkLoginProvider"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the user's blogs details
+ Adds a connection using the authorization code from the provider's OAuth callback
 */
-        async fn get_user_blogs_info(
-            &self,
-            request: tonic::Request<
-                super::super::super::blog_def::v1::GetUserBlogsInfoRequest,
+        pub async fn add_connection(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::connection_settings_def::v1::AddConnectionRequest,
             >,
         ) -> std::result::Result<
-            tonic::Response<super::super::super::blog_def::v1::GetUserBlogsInfoResponse>,
+            tonic::Response<
+                super::super::super::connection_settings_def::v1::AddConnectionResponse,
+            >,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/AddConnection",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "AddConnection"));
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the user's block count
+ Hides or shows a connection on the user's public profile
 */
-        async fn get_user_block_count(
-            &self,
-            request: tonic::Request<
-                super::super::super::user_def::v1::GetUserBlockCountRequest,
+        pub async fn set_connection_hidden(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::connection_settings_def::v1::SetConnectionHiddenRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::user_def::v1::GetUserBlockCountResponse,
+                super::super::super::connection_settings_def::v1::SetConnectionHiddenResponse,
             >,
             tonic::Status,
-        >;
-        /** *
- Returns the user's mute count
-*/
-        async fn get_user_mute_count(
-            &self,
-            request: tonic::Request<
-                super::super::super::user_def::v1::GetUserMuteCountRequest,
-            >,
-        ) -> std::result::Result<
-            tonic::Response<super::super::super::user_def::v1::GetUserMuteCountResponse>,
-            tonic::Status,
-        >;
-        /** *
- Returns the story's data
-*/
-        async fn get_story(
-            &self,
-            request: tonic::Request<super::super::super::story_def::v1::GetStoryRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::super::super::story_def::v1::GetStoryResponse>,
-            tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/SetConnectionHidden",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("api_service.v1.ApiService", "SetConnectionHidden"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the story's metadata
+ Removes a connection along with the data received from the provider
 */
-        async fn get_story_metadata(
-            &self,
-            request: tonic::Request<
-                super::super::super::story_def::v1::GetStoryMetadataRequest,
+        pub async fn remove_connection(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::connection_settings_def::v1::RemoveConnectionRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::story_def::v1::GetStoryMetadataResponse,
-            >,
-            tonic::Status,
-        >;
-        /** *
- Returns the comment's data
-*/
-        async fn get_comment(
-            &self,
-            request: tonic::Request<
-                super::super::super::comment_def::v1::GetCommentRequest,
+                super::super::super::connection_settings_def::v1::RemoveConnectionResponse,
             >,
-        ) -> std::result::Result<
-            tonic::Response<super::super::super::comment_def::v1::GetCommentResponse>,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/RemoveConnection",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("api_service.v1.ApiService", "RemoveConnection"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Creates a new draft
+ Refreshes the display name and URL of a connection from the provider
 */
-        async fn create_draft(
-            &self,
-            request: tonic::Request<
-                super::super::super::story_def::v1::CreateDraftRequest,
+        pub async fn refresh_connection(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::connection_settings_def::v1::RefreshConnectionRequest,
             >,
         ) -> std::result::Result<
-            tonic::Response<super::super::super::story_def::v1::CreateDraftResponse>,
-            tonic::Status,
-        >;
-        /** *
- Returns the blog's data
-*/
-        async fn get_blog(
-            &self,
-            request: tonic::Request<super::super::super::blog_def::v1::GetBlogRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::super::super::blog_def::v1::GetBlogResponse>,
-            tonic::Status,
-        >;
-        /** *
- Returns the blog's archive data
-*/
-        async fn get_blog_archive(
-            &self,
-            request: tonic::Request<
-                super::super::super::blog_def::v1::GetBlogArchiveRequest,
+            tonic::Response<
+                super::super::super::connection_settings_def::v1::RefreshConnectionResponse,
             >,
-        ) -> std::result::Result<
-            tonic::Response<super::super::super::blog_def::v1::GetBlogArchiveResponse>,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/RefreshConnection",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("api_service.v1.ApiService", "RefreshConnection"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the blog's pending story count
+ Updates the user's privacy settings and applies the resulting cascades
 */
-        async fn get_blog_pending_story_count(
-            &self,
-            request: tonic::Request<
-                super::super::super::blog_def::v1::GetBlogPendingStoryCountRequest,
+        pub async fn update_privacy_settings(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::privacy_settings_def::v1::UpdatePrivacySettingsRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::blog_def::v1::GetBlogPendingStoryCountResponse,
+                super::super::super::privacy_settings_def::v1::UpdatePrivacySettingsResponse,
             >,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/UpdatePrivacySettings",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("api_service.v1.ApiService", "UpdatePrivacySettings"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the blog's published story count
+ Updates the user's notification settings
 */
-        async fn get_blog_published_story_count(
-            &self,
-            request: tonic::Request<
-                super::super::super::blog_def::v1::GetBlogPublishedStoryCountRequest,
+        pub async fn update_notification_settings(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::notification_settings_def::v1::UpdateNotificationSettingsRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::blog_def::v1::GetBlogPublishedStoryCountResponse,
+                super::super::super::notification_settings_def::v1::UpdateNotificationSettingsResponse,
             >,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/UpdateNotificationSettings",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "api_service.v1.ApiService",
+                        "UpdateNotificationSettings",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the blog's editors details
+ Returns the notifications for a user, newest first
 */
-        async fn get_blog_editors_info(
-            &self,
-            request: tonic::Request<
-                super::super::super::blog_def::v1::GetBlogEditorsInfoRequest,
+        pub async fn list_notifications(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::notification_def::v1::ListNotificationsRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::blog_def::v1::GetBlogEditorsInfoResponse,
+                super::super::super::notification_def::v1::ListNotificationsResponse,
             >,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/ListNotifications",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("api_service.v1.ApiService", "ListNotifications"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the blog's writers details
+ Marks notifications as read for a user
 */
-        async fn get_blog_writers_info(
-            &self,
-            request: tonic::Request<
-                super::super::super::blog_def::v1::GetBlogWritersInfoRequest,
+        pub async fn mark_notifications_read(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::notification_def::v1::MarkNotificationsReadRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::blog_def::v1::GetBlogWritersInfoResponse,
+                super::super::super::notification_def::v1::MarkNotificationsReadResponse,
             >,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/MarkNotificationsRead",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("api_service.v1.ApiService", "MarkNotificationsRead"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the blog's sitemap
+ Returns the number of unread notifications for a user
 */
-        async fn get_blog_sitemap(
-            &self,
-            request: tonic::Request<
-                super::super::super::blog_def::v1::GetBlogSitemapRequest,
+        pub async fn get_unread_notification_count(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::notification_def::v1::GetUnreadNotificationCountRequest,
             >,
         ) -> std::result::Result<
-            tonic::Response<super::super::super::blog_def::v1::GetBlogSitemapResponse>,
+            tonic::Response<
+                super::super::super::notification_def::v1::GetUnreadNotificationCountResponse,
+            >,
             tonic::Status,
-        >;
-        /** *
- Returns the blog's newsletter
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/GetUnreadNotificationCount",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "api_service.v1.ApiService",
+                        "GetUnreadNotificationCount",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /** *
+ Streams new notifications for a user as they are created
 */
-        async fn get_blog_newsletter(
-            &self,
-            request: tonic::Request<
-                super::super::super::blog_def::v1::GetBlogNewsletterRequest,
+        pub async fn watch_notifications(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::notification_def::v1::WatchNotificationsRequest,
             >,
         ) -> std::result::Result<
             tonic::Response<
-                super::super::super::blog_def::v1::GetBlogNewsletterResponse,
+                tonic::codec::Streaming<
+                    super::super::super::notification_def::v1::WatchNotificationsResponse,
+                >,
             >,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/WatchNotifications",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("api_service.v1.ApiService", "WatchNotifications"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
         /** *
- Returns the blog's newsletter details
+ Sets the status of a user, replacing the previous status
 */
-        async fn get_blog_newsletter_info(
-            &self,
-            request: tonic::Request<
-                super::super::super::blog_def::v1::GetBlogNewsletterInfoRequest,
+        pub async fn set_status(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::user_def::v1::SetStatusRequest,
             >,
         ) -> std::result::Result<
-            tonic::Response<
-                super::super::super::blog_def::v1::GetBlogNewsletterInfoResponse,
-            >,
+            tonic::Response<super::super::super::user_def::v1::SetStatusResponse>,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/SetStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "SetStatus"));
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the story's open graph data
+ Clears the status of a user
 */
-        async fn get_story_open_graph_data(
-            &self,
-            request: tonic::Request<
-                super::super::super::open_graph_def::v1::GetStoryOpenGraphDataRequest,
+        pub async fn clear_status(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::user_def::v1::ClearStatusRequest,
             >,
         ) -> std::result::Result<
-            tonic::Response<
-                super::super::super::open_graph_def::v1::GetStoryOpenGraphDataResponse,
-            >,
+            tonic::Response<super::super::super::user_def::v1::ClearStatusResponse>,
             tonic::Status,
-        >;
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/ClearStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "ClearStatus"));
+            self.inner.unary(req, path, codec).await
+        }
         /** *
- Returns the tag's open graph data
+ Updates the profile of a user
 */
-        async fn get_tag_open_graph_data(
-            &self,
-            request: tonic::Request<
-                super::super::super::open_graph_def::v1::GetTagOpenGraphDataRequest,
+        pub async fn update_profile(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::profile_def::v1::UpdateProfileRequest,
             >,
         ) -> std::result::Result<
-            tonic::Response<
-                super::super::super::open_graph_def::v1::GetTagOpenGraphDataResponse,
+            tonic::Response<super::super::super::profile_def::v1::UpdateProfileResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/UpdateProfile",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "UpdateProfile"));
+            self.inner.unary(req, path, codec).await
+        }
+        /** *
+ Uploads an image in chunks and returns its ID along with the derived colors
+*/
+        pub async fn upload_image(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::super::super::asset_def::v1::UploadImageRequest,
             >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::asset_def::v1::UploadImageResponse>,
             tonic::Status,
-        >;
-    }
-    /** Service definition
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/UploadImage",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "UploadImage"));
+            self.inner.client_streaming(req, path, codec).await
+        }
+        /** *
+ Sets the rating of an image, used by the moderators to flag sensitive media
 */
-    #[derive(Debug)]
-    pub struct ApiServiceServer<T: ApiService> {
-        inner: _Inner<T>,
-        accept_compression_encodings: EnabledCompressionEncodings,
-        send_compression_encodings: EnabledCompressionEncodings,
-        max_decoding_message_size: Option<usize>,
-        max_encoding_message_size: Option<usize>,
-    }
-    struct _Inner<T>(Arc<T>);
-    impl<T: ApiService> ApiServiceServer<T> {
-        pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
+        pub async fn set_asset_rating(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::asset_def::v1::SetAssetRatingRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::asset_def::v1::SetAssetRatingResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/SetAssetRating",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "SetAssetRating"));
+            self.inner.unary(req, path, codec).await
         }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            let inner = _Inner(inner);
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-                max_decoding_message_size: None,
-                max_encoding_message_size: None,
-            }
+        /** *
+ Reports a story, comment, user, blog, or tag
+*/
+        pub async fn report_content(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::report_def::v1::ReportContentRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::report_def::v1::ReportContentResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/ReportContent",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "ReportContent"));
+            self.inner.unary(req, path, codec).await
         }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
-        where
-            F: tonic::service::Interceptor,
-        {
-            InterceptedService::new(Self::new(inner), interceptor)
+        /** *
+ Returns the moderation queue, used by the moderators
+*/
+        pub async fn list_reports(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::report_def::v1::ListReportsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::report_def::v1::ListReportsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/ListReports",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "ListReports"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Enable decompressing requests with the given encoding.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.accept_compression_encodings.enable(encoding);
-            self
+        /** *
+ Resolves a report and records the action in the moderation audit log
+*/
+        pub async fn resolve_report(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::report_def::v1::ResolveReportRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::report_def::v1::ResolveReportResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/ResolveReport",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "ResolveReport"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Compress responses with the given encoding, if the client supports it.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.send_compression_encodings.enable(encoding);
-            self
+        /** *
+ Grants a flag to a user, used by the administrators
+*/
+        pub async fn grant_user_flag(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::user_def::v1::GrantUserFlagRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::user_def::v1::GrantUserFlagResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/GrantUserFlag",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "GrantUserFlag"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.max_decoding_message_size = Some(limit);
-            self
+        /** *
+ Revokes a flag from a user, used by the administrators
+*/
+        pub async fn revoke_user_flag(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::user_def::v1::RevokeUserFlagRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::user_def::v1::RevokeUserFlagResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/RevokeUserFlag",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "RevokeUserFlag"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.max_encoding_message_size = Some(limit);
-            self
+        /** *
+ Suspends a user, hiding their stories, comments, and profile
+*/
+        pub async fn suspend_user(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::user_def::v1::SuspendUserRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::user_def::v1::SuspendUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/SuspendUser",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "SuspendUser"));
+            self.inner.unary(req, path, codec).await
         }
-    }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for ApiServiceServer<T>
-    where
-        T: ApiService,
-        B: Body + Send + 'static,
-        B::Error: Into<StdError> + Send + 'static,
-    {
-        type Response = http::Response<tonic::body::BoxBody>;
-        type Error = std::convert::Infallible;
-        type Future = BoxFuture<Self::Response, Self::Error>;
-        fn poll_ready(
+        /** *
+ Lifts the suspension of a user
+*/
+        pub async fn unsuspend_user(
             &mut self,
-            _cx: &mut Context<'_>,
-        ) -> Poll<std::result::Result<(), Self::Error>> {
-            Poll::Ready(Ok(()))
+            request: impl tonic::IntoRequest<
+                super::super::super::user_def::v1::UnsuspendUserRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::user_def::v1::UnsuspendUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/UnsuspendUser",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "UnsuspendUser"));
+            self.inner.unary(req, path, codec).await
         }
-        fn call(&mut self, req: http::Request<B>) -> Self::Future {
-            let inner = self.inner.clone();
-            match req.uri().path() {
-                "/api_service.v1.ApiService/GetUserId" => {
+        /** *
+ Queues an archive of the user's data for export
+*/
+        pub async fn request_account_export(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::account_def::v1::RequestAccountExportRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::account_def::v1::RequestAccountExportResponse,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/RequestAccountExport",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("api_service.v1.ApiService", "RequestAccountExport"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /** *
+ Returns the status of an account export along with its download token
+*/
+        pub async fn get_account_export_status(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::account_def::v1::GetAccountExportStatusRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::account_def::v1::GetAccountExportStatusResponse,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/GetAccountExportStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "api_service.v1.ApiService",
+                        "GetAccountExportStatus",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /** *
+ Schedules the deletion of an account at the end of the grace period
+*/
+        pub async fn schedule_account_deletion(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::account_def::v1::ScheduleAccountDeletionRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::account_def::v1::ScheduleAccountDeletionResponse,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/ScheduleAccountDeletion",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "api_service.v1.ApiService",
+                        "ScheduleAccountDeletion",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /** *
+ Cancels a scheduled account deletion during the grace period
+*/
+        pub async fn cancel_account_deletion(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::account_def::v1::CancelAccountDeletionRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::account_def::v1::CancelAccountDeletionResponse,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/CancelAccountDeletion",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("api_service.v1.ApiService", "CancelAccountDeletion"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /** *
+ Imports a Markdown or HTML document as a new draft
+*/
+        pub async fn import_story(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::story_def::v1::ImportStoryRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::story_def::v1::ImportStoryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/ImportStory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "ImportStory"));
+            self.inner.unary(req, path, codec).await
+        }
+        /** *
+ Imports every story from an export archive as drafts
+*/
+        pub async fn import_stories(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::story_def::v1::ImportStoriesRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::story_def::v1::ImportStoriesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/ImportStories",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "ImportStories"));
+            self.inner.unary(req, path, codec).await
+        }
+        /** *
+ Exports a story as Markdown, HTML, EPUB, or PDF
+*/
+        pub async fn export_story(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::story_def::v1::ExportStoryRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::story_def::v1::ExportStoryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/ExportStory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "ExportStory"));
+            self.inner.unary(req, path, codec).await
+        }
+        /** *
+ Exports the published stories of a blog as an EPUB anthology
+*/
+        pub async fn export_blog(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::blog_def::v1::ExportBlogRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::blog_def::v1::ExportBlogResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/api_service.v1.ApiService/ExportBlog",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("api_service.v1.ApiService", "ExportBlog"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod api_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ApiServiceServer.
+    #[async_trait]
+    pub trait ApiService: Send + Sync + 'static {
+        /** *
+ Checks whether the user is authenticated using the token from the session cookie
+*/
+        async fn get_user_id(
+            &self,
+            request: tonic::Request<super::super::super::user_def::v1::GetUserIdRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::user_def::v1::GetUserIdResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Returns the username for a user by its ID
+*/
+        async fn get_username(
+            &self,
+            request: tonic::Request<
+                super::super::super::user_def::v1::GetUsernameRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::user_def::v1::GetUsernameResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Returns the profile page data for a user
+*/
+        async fn get_profile(
+            &self,
+            request: tonic::Request<
+                super::super::super::profile_def::v1::GetProfileRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::profile_def::v1::GetProfileResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Returns the tag page data for a tag
+*/
+        async fn get_tag(
+            &self,
+            request: tonic::Request<super::super::super::tag_def::v1::GetTagRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::tag_def::v1::GetTagResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Returns the token using its identifier
+*/
+        async fn get_token(
+            &self,
+            request: tonic::Request<super::super::super::token_def::v1::GetTokenRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::token_def::v1::GetTokenResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Verifies a user's email using the provided token identifier
+*/
+        async fn verify_email(
+            &self,
+            request: tonic::Request<
+                super::super::super::token_def::v1::VerifyEmailRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::token_def::v1::VerifyEmailResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Verifies a visitor's newsletter subscription request using the provided token identifier
+*/
+        async fn verify_newsletter_subscription(
+            &self,
+            request: tonic::Request<
+                super::super::super::token_def::v1::VerifyNewsletterSubscriptionRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::token_def::v1::VerifyNewsletterSubscriptionResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Verifies a user's blog login request using the provided token identifier
+*/
+        async fn verify_blog_login(
+            &self,
+            request: tonic::Request<
+                super::super::super::blog_def::v1::VerifyBlogLoginRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::blog_def::v1::VerifyBlogLoginResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Returns the user's credentials settings
+*/
+        async fn get_credential_settings(
+            &self,
+            request: tonic::Request<
+                super::super::super::credential_settings_def::v1::GetCredentialSettingsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::credential_settings_def::v1::GetCredentialSettingsResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the user's privacy settings
+*/
+        async fn get_privacy_settings(
+            &self,
+            request: tonic::Request<
+                super::super::super::privacy_settings_def::v1::GetPrivacySettingsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::privacy_settings_def::v1::GetPrivacySettingsResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the user's notification settings
+*/
+        async fn get_notification_settings(
+            &self,
+            request: tonic::Request<
+                super::super::super::notification_settings_def::v1::GetNotificationSettingsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::notification_settings_def::v1::GetNotificationSettingsResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the user's connection settings
+*/
+        async fn get_connection_settings(
+            &self,
+            request: tonic::Request<
+                super::super::super::connection_settings_def::v1::GetConnectionSettingsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::connection_settings_def::v1::GetConnectionSettingsResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the user's login activity
+*/
+        async fn get_login_activity(
+            &self,
+            request: tonic::Request<
+                super::super::super::login_activity_def::v1::GetLoginActivityRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::login_activity_def::v1::GetLoginActivityResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Validates a story
+*/
+        async fn validate_story(
+            &self,
+            request: tonic::Request<
+                super::super::super::story_def::v1::ValidateStoryRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::story_def::v1::ValidateStoryResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Returns the user's drafts details
+*/
+        async fn get_drafts_info(
+            &self,
+            request: tonic::Request<
+                super::super::super::story_def::v1::GetDraftsInfoRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::story_def::v1::GetDraftsInfoResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Returns the user's stories details
+*/
+        async fn get_stories_info(
+            &self,
+            request: tonic::Request<
+                super::super::super::story_def::v1::GetStoriesInfoRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::story_def::v1::GetStoriesInfoResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Returns the user's contributions details
+*/
+        async fn get_contributions_info(
+            &self,
+            request: tonic::Request<
+                super::super::super::story_def::v1::GetContributionsInfoRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::story_def::v1::GetContributionsInfoResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the user's responses details
+*/
+        async fn get_responses_info(
+            &self,
+            request: tonic::Request<
+                super::super::super::response_def::v1::GetResponsesInfoRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::response_def::v1::GetResponsesInfoResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the story's responses details
+*/
+        async fn get_story_responses_info(
+            &self,
+            request: tonic::Request<
+                super::super::super::response_def::v1::GetStoryResponsesInfoRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::response_def::v1::GetStoryResponsesInfoResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the user's followed tag count
+*/
+        async fn get_followed_tag_count(
+            &self,
+            request: tonic::Request<
+                super::super::super::tag_def::v1::GetFollowedTagCountRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::tag_def::v1::GetFollowedTagCountResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the user's relations details
+*/
+        async fn get_user_relations_info(
+            &self,
+            request: tonic::Request<
+                super::super::super::user_def::v1::GetUserRelationsInfoRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::user_def::v1::GetUserRelationsInfoResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the user's blogs details
+*/
+        async fn get_user_blogs_info(
+            &self,
+            request: tonic::Request<
+                super::super::super::blog_def::v1::GetUserBlogsInfoRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::blog_def::v1::GetUserBlogsInfoResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Returns the user's block count
+*/
+        async fn get_user_block_count(
+            &self,
+            request: tonic::Request<
+                super::super::super::user_def::v1::GetUserBlockCountRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::user_def::v1::GetUserBlockCountResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the user's mute count
+*/
+        async fn get_user_mute_count(
+            &self,
+            request: tonic::Request<
+                super::super::super::user_def::v1::GetUserMuteCountRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::user_def::v1::GetUserMuteCountResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Returns the story's data
+*/
+        async fn get_story(
+            &self,
+            request: tonic::Request<super::super::super::story_def::v1::GetStoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::story_def::v1::GetStoryResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Returns the story's metadata
+*/
+        async fn get_story_metadata(
+            &self,
+            request: tonic::Request<
+                super::super::super::story_def::v1::GetStoryMetadataRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::story_def::v1::GetStoryMetadataResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the comment's data
+*/
+        async fn get_comment(
+            &self,
+            request: tonic::Request<
+                super::super::super::comment_def::v1::GetCommentRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::comment_def::v1::GetCommentResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Creates a new draft
+*/
+        async fn create_draft(
+            &self,
+            request: tonic::Request<
+                super::super::super::story_def::v1::CreateDraftRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::story_def::v1::CreateDraftResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Returns the blog's data
+*/
+        async fn get_blog(
+            &self,
+            request: tonic::Request<super::super::super::blog_def::v1::GetBlogRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::blog_def::v1::GetBlogResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Returns the blog's archive data
+*/
+        async fn get_blog_archive(
+            &self,
+            request: tonic::Request<
+                super::super::super::blog_def::v1::GetBlogArchiveRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::blog_def::v1::GetBlogArchiveResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Returns the blog's pending story count
+*/
+        async fn get_blog_pending_story_count(
+            &self,
+            request: tonic::Request<
+                super::super::super::blog_def::v1::GetBlogPendingStoryCountRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::blog_def::v1::GetBlogPendingStoryCountResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the blog's published story count
+*/
+        async fn get_blog_published_story_count(
+            &self,
+            request: tonic::Request<
+                super::super::super::blog_def::v1::GetBlogPublishedStoryCountRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::blog_def::v1::GetBlogPublishedStoryCountResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the blog's editors details
+*/
+        async fn get_blog_editors_info(
+            &self,
+            request: tonic::Request<
+                super::super::super::blog_def::v1::GetBlogEditorsInfoRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::blog_def::v1::GetBlogEditorsInfoResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the blog's writers details
+*/
+        async fn get_blog_writers_info(
+            &self,
+            request: tonic::Request<
+                super::super::super::blog_def::v1::GetBlogWritersInfoRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::blog_def::v1::GetBlogWritersInfoResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the blog's sitemap
+*/
+        async fn get_blog_sitemap(
+            &self,
+            request: tonic::Request<
+                super::super::super::blog_def::v1::GetBlogSitemapRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::blog_def::v1::GetBlogSitemapResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Returns the blog's newsletter
+*/
+        async fn get_blog_newsletter(
+            &self,
+            request: tonic::Request<
+                super::super::super::blog_def::v1::GetBlogNewsletterRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::blog_def::v1::GetBlogNewsletterResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the blog's newsletter details
+*/
+        async fn get_blog_newsletter_info(
+            &self,
+            request: tonic::Request<
+                super::super::super::blog_def::v1::GetBlogNewsletterInfoRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::blog_def::v1::GetBlogNewsletterInfoResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the story's open graph data
+*/
+        async fn get_story_open_graph_data(
+            &self,
+            request: tonic::Request<
+                super::super::super::open_graph_def::v1::GetStoryOpenGraphDataRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::open_graph_def::v1::GetStoryOpenGraphDataResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the tag's open graph data
+*/
+        async fn get_tag_open_graph_data(
+            &self,
+            request: tonic::Request<
+                super::super::super::open_graph_def::v1::GetTagOpenGraphDataRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::open_graph_def::v1::GetTagOpenGraphDataResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Generates a pending TOTP secret for the user
+*/
+        async fn begin_mfa_enrollment(
+            &self,
+            request: tonic::Request<
+                super::super::super::credential_settings_def::v1::BeginMfaEnrollmentRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::credential_settings_def::v1::BeginMfaEnrollmentResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Enables MFA for the user after verifying a code generated using the pending secret
+*/
+        async fn confirm_mfa_enrollment(
+            &self,
+            request: tonic::Request<
+                super::super::super::credential_settings_def::v1::ConfirmMfaEnrollmentRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::credential_settings_def::v1::ConfirmMfaEnrollmentResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Issues an MFA challenge for a login whose first factor (password or OAuth) has been verified
+*/
+        async fn begin_mfa_challenge(
+            &self,
+            request: tonic::Request<
+                super::super::super::credential_settings_def::v1::BeginMfaChallengeRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::credential_settings_def::v1::BeginMfaChallengeResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Verifies a TOTP or recovery code against an MFA challenge during login
+*/
+        async fn verify_mfa_code(
+            &self,
+            request: tonic::Request<
+                super::super::super::credential_settings_def::v1::VerifyMfaCodeRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::credential_settings_def::v1::VerifyMfaCodeResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Disables MFA for the user
+*/
+        async fn disable_mfa(
+            &self,
+            request: tonic::Request<
+                super::super::super::credential_settings_def::v1::DisableMfaRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::credential_settings_def::v1::DisableMfaResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Replaces the user's recovery codes
+*/
+        async fn regenerate_mfa_recovery_codes(
+            &self,
+            request: tonic::Request<
+                super::super::super::credential_settings_def::v1::RegenerateMfaRecoveryCodesRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::credential_settings_def::v1::RegenerateMfaRecoveryCodesResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the options for registering a new passkey
+*/
+        async fn begin_passkey_registration(
+            &self,
+            request: tonic::Request<
+                super::super::super::credential_settings_def::v1::BeginPasskeyRegistrationRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::credential_settings_def::v1::BeginPasskeyRegistrationResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Verifies the attestation from the authenticator and registers the passkey
+*/
+        async fn finish_passkey_registration(
+            &self,
+            request: tonic::Request<
+                super::super::super::credential_settings_def::v1::FinishPasskeyRegistrationRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::credential_settings_def::v1::FinishPasskeyRegistrationResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the options for authenticating using a passkey
+*/
+        async fn begin_passkey_authentication(
+            &self,
+            request: tonic::Request<
+                super::super::super::credential_settings_def::v1::BeginPasskeyAuthenticationRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::credential_settings_def::v1::BeginPasskeyAuthenticationResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Verifies the assertion from the authenticator and returns the user ID
+*/
+        async fn finish_passkey_authentication(
+            &self,
+            request: tonic::Request<
+                super::super::super::credential_settings_def::v1::FinishPasskeyAuthenticationRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::credential_settings_def::v1::FinishPasskeyAuthenticationResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Removes a passkey registered by the user
+*/
+        async fn remove_passkey(
+            &self,
+            request: tonic::Request<
+                super::super::super::credential_settings_def::v1::RemovePasskeyRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::credential_settings_def::v1::RemovePasskeyResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Links an external login provider to the user's account
+*/
+        async fn link_login_provider(
+            &self,
+            request: tonic::Request<
+                super::super::super::credential_settings_def::v1::LinkLoginProviderRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::credential_settings_def::v1::LinkLoginProviderResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Unlinks an external login provider, unless it is the last remaining login method
+*/
+        async fn unlink_login_provider(
+            &self,
+            request: tonic::Request<
+                super::super::super::credential_settings_def::v1::UnlinkLoginProviderRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::credential_settings_def::v1::UnlinkLoginProviderResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Adds a connection using the authorization code from the provider's OAuth callback
+*/
+        async fn add_connection(
+            &self,
+            request: tonic::Request<
+                super::super::super::connection_settings_def::v1::AddConnectionRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::connection_settings_def::v1::AddConnectionResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Hides or shows a connection on the user's public profile
+*/
+        async fn set_connection_hidden(
+            &self,
+            request: tonic::Request<
+                super::super::super::connection_settings_def::v1::SetConnectionHiddenRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::connection_settings_def::v1::SetConnectionHiddenResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Removes a connection along with the data received from the provider
+*/
+        async fn remove_connection(
+            &self,
+            request: tonic::Request<
+                super::super::super::connection_settings_def::v1::RemoveConnectionRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::connection_settings_def::v1::RemoveConnectionResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Refreshes the display name and URL of a connection from the provider
+*/
+        async fn refresh_connection(
+            &self,
+            request: tonic::Request<
+                super::super::super::connection_settings_def::v1::RefreshConnectionRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::connection_settings_def::v1::RefreshConnectionResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Updates the user's privacy settings and applies the resulting cascades
+*/
+        async fn update_privacy_settings(
+            &self,
+            request: tonic::Request<
+                super::super::super::privacy_settings_def::v1::UpdatePrivacySettingsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::privacy_settings_def::v1::UpdatePrivacySettingsResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Updates the user's notification settings
+*/
+        async fn update_notification_settings(
+            &self,
+            request: tonic::Request<
+                super::super::super::notification_settings_def::v1::UpdateNotificationSettingsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::notification_settings_def::v1::UpdateNotificationSettingsResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the notifications for a user, newest first
+*/
+        async fn list_notifications(
+            &self,
+            request: tonic::Request<
+                super::super::super::notification_def::v1::ListNotificationsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::notification_def::v1::ListNotificationsResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Marks notifications as read for a user
+*/
+        async fn mark_notifications_read(
+            &self,
+            request: tonic::Request<
+                super::super::super::notification_def::v1::MarkNotificationsReadRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::notification_def::v1::MarkNotificationsReadResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the number of unread notifications for a user
+*/
+        async fn get_unread_notification_count(
+            &self,
+            request: tonic::Request<
+                super::super::super::notification_def::v1::GetUnreadNotificationCountRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::notification_def::v1::GetUnreadNotificationCountResponse,
+            >,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the WatchNotifications method.
+        type WatchNotificationsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::super::super::notification_def::v1::WatchNotificationsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /** *
+ Streams new notifications for a user as they are created
+*/
+        async fn watch_notifications(
+            &self,
+            request: tonic::Request<
+                super::super::super::notification_def::v1::WatchNotificationsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<Self::WatchNotificationsStream>,
+            tonic::Status,
+        >;
+        /** *
+ Sets the status of a user, replacing the previous status
+*/
+        async fn set_status(
+            &self,
+            request: tonic::Request<super::super::super::user_def::v1::SetStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::user_def::v1::SetStatusResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Clears the status of a user
+*/
+        async fn clear_status(
+            &self,
+            request: tonic::Request<
+                super::super::super::user_def::v1::ClearStatusRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::user_def::v1::ClearStatusResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Updates the profile of a user
+*/
+        async fn update_profile(
+            &self,
+            request: tonic::Request<
+                super::super::super::profile_def::v1::UpdateProfileRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::profile_def::v1::UpdateProfileResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Uploads an image in chunks and returns its ID along with the derived colors
+*/
+        async fn upload_image(
+            &self,
+            request: tonic::Request<
+                tonic::Streaming<super::super::super::asset_def::v1::UploadImageRequest>,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::asset_def::v1::UploadImageResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Sets the rating of an image, used by the moderators to flag sensitive media
+*/
+        async fn set_asset_rating(
+            &self,
+            request: tonic::Request<
+                super::super::super::asset_def::v1::SetAssetRatingRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::asset_def::v1::SetAssetRatingResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Reports a story, comment, user, blog, or tag
+*/
+        async fn report_content(
+            &self,
+            request: tonic::Request<
+                super::super::super::report_def::v1::ReportContentRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::report_def::v1::ReportContentResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Returns the moderation queue, used by the moderators
+*/
+        async fn list_reports(
+            &self,
+            request: tonic::Request<
+                super::super::super::report_def::v1::ListReportsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::report_def::v1::ListReportsResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Resolves a report and records the action in the moderation audit log
+*/
+        async fn resolve_report(
+            &self,
+            request: tonic::Request<
+                super::super::super::report_def::v1::ResolveReportRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::report_def::v1::ResolveReportResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Grants a flag to a user, used by the administrators
+*/
+        async fn grant_user_flag(
+            &self,
+            request: tonic::Request<
+                super::super::super::user_def::v1::GrantUserFlagRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::user_def::v1::GrantUserFlagResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Revokes a flag from a user, used by the administrators
+*/
+        async fn revoke_user_flag(
+            &self,
+            request: tonic::Request<
+                super::super::super::user_def::v1::RevokeUserFlagRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::user_def::v1::RevokeUserFlagResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Suspends a user, hiding their stories, comments, and profile
+*/
+        async fn suspend_user(
+            &self,
+            request: tonic::Request<
+                super::super::super::user_def::v1::SuspendUserRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::user_def::v1::SuspendUserResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Lifts the suspension of a user
+*/
+        async fn unsuspend_user(
+            &self,
+            request: tonic::Request<
+                super::super::super::user_def::v1::UnsuspendUserRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::user_def::v1::UnsuspendUserResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Queues an archive of the user's data for export
+*/
+        async fn request_account_export(
+            &self,
+            request: tonic::Request<
+                super::super::super::account_def::v1::RequestAccountExportRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::account_def::v1::RequestAccountExportResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Returns the status of an account export along with its download token
+*/
+        async fn get_account_export_status(
+            &self,
+            request: tonic::Request<
+                super::super::super::account_def::v1::GetAccountExportStatusRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::account_def::v1::GetAccountExportStatusResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Schedules the deletion of an account at the end of the grace period
+*/
+        async fn schedule_account_deletion(
+            &self,
+            request: tonic::Request<
+                super::super::super::account_def::v1::ScheduleAccountDeletionRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::account_def::v1::ScheduleAccountDeletionResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Cancels a scheduled account deletion during the grace period
+*/
+        async fn cancel_account_deletion(
+            &self,
+            request: tonic::Request<
+                super::super::super::account_def::v1::CancelAccountDeletionRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<
+                super::super::super::account_def::v1::CancelAccountDeletionResponse,
+            >,
+            tonic::Status,
+        >;
+        /** *
+ Imports a Markdown or HTML document as a new draft
+*/
+        async fn import_story(
+            &self,
+            request: tonic::Request<
+                super::super::super::story_def::v1::ImportStoryRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::story_def::v1::ImportStoryResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Imports every story from an export archive as drafts
+*/
+        async fn import_stories(
+            &self,
+            request: tonic::Request<
+                super::super::super::story_def::v1::ImportStoriesRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::story_def::v1::ImportStoriesResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Exports a story as Markdown, HTML, EPUB, or PDF
+*/
+        async fn export_story(
+            &self,
+            request: tonic::Request<
+                super::super::super::story_def::v1::ExportStoryRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::story_def::v1::ExportStoryResponse>,
+            tonic::Status,
+        >;
+        /** *
+ Exports the published stories of a blog as an EPUB anthology
+*/
+        async fn export_blog(
+            &self,
+            request: tonic::Request<super::super::super::blog_def::v1::ExportBlogRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::super::blog_def::v1::ExportBlogResponse>,
+            tonic::Status,
+        >;
+    }
+    /** Service definition
+
+ Every call must carry a signed service credential in the `authorization`
+ metadata, and the session token of the user (if any) in the
+ `x-session-token` metadata. The caller identity is derived from these, and the
+ `user_id`, `current_user_id`, `moderator_id`, and `admin_id` fields of the
+ requests can be omitted. When set, they must match the authenticated user.
+
+ The connections use mutual TLS, and the server only allows each method to be
+ invoked by the client services listed in its policy.
+
+ Some methods are rate limited per IP address (from the `x-client-ip`
+ metadata), user, or token. The rejected calls fail with `RESOURCE_EXHAUSTED`
+ and carry a `google.rpc.RetryInfo` in the details.
+
+ Failed RPCs carry an `error_def.v1.ErrorDetail` (and a `google.rpc.BadRequest`
+ for invalid requests) in the `google.rpc.Status` details
+*/
+    #[derive(Debug)]
+    pub struct ApiServiceServer<T: ApiService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: ApiService> ApiServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for ApiServiceServer<T>
+    where
+        T: ApiService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/api_service.v1.ApiService/GetUserId" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetUserIdSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::user_def::v1::GetUserIdRequest,
+                    > for GetUserIdSvc<T> {
+                        type Response = super::super::super::user_def::v1::GetUserIdResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::user_def::v1::GetUserIdRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_user_id(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetUserIdSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetUsername" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetUsernameSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::user_def::v1::GetUsernameRequest,
+                    > for GetUsernameSvc<T> {
+                        type Response = super::super::super::user_def::v1::GetUsernameResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::user_def::v1::GetUsernameRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_username(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetUsernameSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetProfile" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetProfileSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::profile_def::v1::GetProfileRequest,
+                    > for GetProfileSvc<T> {
+                        type Response = super::super::super::profile_def::v1::GetProfileResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::profile_def::v1::GetProfileRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_profile(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetProfileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetTag" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTagSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::tag_def::v1::GetTagRequest,
+                    > for GetTagSvc<T> {
+                        type Response = super::super::super::tag_def::v1::GetTagResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::tag_def::v1::GetTagRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_tag(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTagSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetToken" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTokenSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::token_def::v1::GetTokenRequest,
+                    > for GetTokenSvc<T> {
+                        type Response = super::super::super::token_def::v1::GetTokenResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::token_def::v1::GetTokenRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_token(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTokenSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/VerifyEmail" => {
+                    #[allow(non_camel_case_types)]
+                    struct VerifyEmailSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::token_def::v1::VerifyEmailRequest,
+                    > for VerifyEmailSvc<T> {
+                        type Response = super::super::super::token_def::v1::VerifyEmailResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::token_def::v1::VerifyEmailRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).verify_email(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = VerifyEmailSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/VerifyNewsletterSubscription" => {
+                    #[allow(non_camel_case_types)]
+                    struct VerifyNewsletterSubscriptionSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::token_def::v1::VerifyNewsletterSubscriptionRequest,
+                    > for VerifyNewsletterSubscriptionSvc<T> {
+                        type Response = super::super::super::token_def::v1::VerifyNewsletterSubscriptionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::token_def::v1::VerifyNewsletterSubscriptionRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).verify_newsletter_subscription(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = VerifyNewsletterSubscriptionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/VerifyBlogLogin" => {
+                    #[allow(non_camel_case_types)]
+                    struct VerifyBlogLoginSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::blog_def::v1::VerifyBlogLoginRequest,
+                    > for VerifyBlogLoginSvc<T> {
+                        type Response = super::super::super::blog_def::v1::VerifyBlogLoginResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::blog_def::v1::VerifyBlogLoginRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).verify_blog_login(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = VerifyBlogLoginSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetCredentialSettings" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCredentialSettingsSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::credential_settings_def::v1::GetCredentialSettingsRequest,
+                    > for GetCredentialSettingsSvc<T> {
+                        type Response = super::super::super::credential_settings_def::v1::GetCredentialSettingsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::credential_settings_def::v1::GetCredentialSettingsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_credential_settings(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetCredentialSettingsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetPrivacySettings" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetPrivacySettingsSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::privacy_settings_def::v1::GetPrivacySettingsRequest,
+                    > for GetPrivacySettingsSvc<T> {
+                        type Response = super::super::super::privacy_settings_def::v1::GetPrivacySettingsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::privacy_settings_def::v1::GetPrivacySettingsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_privacy_settings(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetPrivacySettingsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetNotificationSettings" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNotificationSettingsSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::notification_settings_def::v1::GetNotificationSettingsRequest,
+                    > for GetNotificationSettingsSvc<T> {
+                        type Response = super::super::super::notification_settings_def::v1::GetNotificationSettingsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::notification_settings_def::v1::GetNotificationSettingsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_notification_settings(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetNotificationSettingsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetConnectionSettings" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetConnectionSettingsSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::connection_settings_def::v1::GetConnectionSettingsRequest,
+                    > for GetConnectionSettingsSvc<T> {
+                        type Response = super::super::super::connection_settings_def::v1::GetConnectionSettingsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::connection_settings_def::v1::GetConnectionSettingsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_connection_settings(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetConnectionSettingsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetLoginActivity" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetLoginActivitySvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::login_activity_def::v1::GetLoginActivityRequest,
+                    > for GetLoginActivitySvc<T> {
+                        type Response = super::super::super::login_activity_def::v1::GetLoginActivityResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::login_activity_def::v1::GetLoginActivityRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_login_activity(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetLoginActivitySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/ValidateStory" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidateStorySvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::story_def::v1::ValidateStoryRequest,
+                    > for ValidateStorySvc<T> {
+                        type Response = super::super::super::story_def::v1::ValidateStoryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::story_def::v1::ValidateStoryRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).validate_story(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValidateStorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetDraftsInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetDraftsInfoSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::story_def::v1::GetDraftsInfoRequest,
+                    > for GetDraftsInfoSvc<T> {
+                        type Response = super::super::super::story_def::v1::GetDraftsInfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::story_def::v1::GetDraftsInfoRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_drafts_info(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetDraftsInfoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetStoriesInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetStoriesInfoSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::story_def::v1::GetStoriesInfoRequest,
+                    > for GetStoriesInfoSvc<T> {
+                        type Response = super::super::super::story_def::v1::GetStoriesInfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::story_def::v1::GetStoriesInfoRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_stories_info(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetStoriesInfoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetContributionsInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetContributionsInfoSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::story_def::v1::GetContributionsInfoRequest,
+                    > for GetContributionsInfoSvc<T> {
+                        type Response = super::super::super::story_def::v1::GetContributionsInfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::story_def::v1::GetContributionsInfoRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_contributions_info(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetContributionsInfoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetResponsesInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetResponsesInfoSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::response_def::v1::GetResponsesInfoRequest,
+                    > for GetResponsesInfoSvc<T> {
+                        type Response = super::super::super::response_def::v1::GetResponsesInfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::response_def::v1::GetResponsesInfoRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_responses_info(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetResponsesInfoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetStoryResponsesInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetStoryResponsesInfoSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::response_def::v1::GetStoryResponsesInfoRequest,
+                    > for GetStoryResponsesInfoSvc<T> {
+                        type Response = super::super::super::response_def::v1::GetStoryResponsesInfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::response_def::v1::GetStoryResponsesInfoRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_story_responses_info(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetStoryResponsesInfoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetFollowedTagCount" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetFollowedTagCountSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::tag_def::v1::GetFollowedTagCountRequest,
+                    > for GetFollowedTagCountSvc<T> {
+                        type Response = super::super::super::tag_def::v1::GetFollowedTagCountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::tag_def::v1::GetFollowedTagCountRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_followed_tag_count(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetFollowedTagCountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetUserRelationsInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetUserRelationsInfoSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::user_def::v1::GetUserRelationsInfoRequest,
+                    > for GetUserRelationsInfoSvc<T> {
+                        type Response = super::super::super::user_def::v1::GetUserRelationsInfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::user_def::v1::GetUserRelationsInfoRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_user_relations_info(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetUserRelationsInfoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetUserBlogsInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetUserBlogsInfoSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::blog_def::v1::GetUserBlogsInfoRequest,
+                    > for GetUserBlogsInfoSvc<T> {
+                        type Response = super::super::super::blog_def::v1::GetUserBlogsInfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::blog_def::v1::GetUserBlogsInfoRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_user_blogs_info(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetUserBlogsInfoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetUserBlockCount" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetUserBlockCountSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::user_def::v1::GetUserBlockCountRequest,
+                    > for GetUserBlockCountSvc<T> {
+                        type Response = super::super::super::user_def::v1::GetUserBlockCountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::user_def::v1::GetUserBlockCountRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_user_block_count(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetUserBlockCountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetUserMuteCount" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetUserMuteCountSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::user_def::v1::GetUserMuteCountRequest,
+                    > for GetUserMuteCountSvc<T> {
+                        type Response = super::super::super::user_def::v1::GetUserMuteCountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::user_def::v1::GetUserMuteCountRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_user_mute_count(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetUserMuteCountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetStory" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetStorySvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::story_def::v1::GetStoryRequest,
+                    > for GetStorySvc<T> {
+                        type Response = super::super::super::story_def::v1::GetStoryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::story_def::v1::GetStoryRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_story(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetStorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetStoryMetadata" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetStoryMetadataSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::story_def::v1::GetStoryMetadataRequest,
+                    > for GetStoryMetadataSvc<T> {
+                        type Response = super::super::super::story_def::v1::GetStoryMetadataResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::story_def::v1::GetStoryMetadataRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_story_metadata(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetStoryMetadataSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetComment" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCommentSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::comment_def::v1::GetCommentRequest,
+                    > for GetCommentSvc<T> {
+                        type Response = super::super::super::comment_def::v1::GetCommentResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::comment_def::v1::GetCommentRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_comment(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetCommentSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/CreateDraft" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateDraftSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::story_def::v1::CreateDraftRequest,
+                    > for CreateDraftSvc<T> {
+                        type Response = super::super::super::story_def::v1::CreateDraftResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::story_def::v1::CreateDraftRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).create_draft(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CreateDraftSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetBlog" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlogSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::blog_def::v1::GetBlogRequest,
+                    > for GetBlogSvc<T> {
+                        type Response = super::super::super::blog_def::v1::GetBlogResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::blog_def::v1::GetBlogRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move { (*inner).get_blog(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlogSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetBlogArchive" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlogArchiveSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::blog_def::v1::GetBlogArchiveRequest,
+                    > for GetBlogArchiveSvc<T> {
+                        type Response = super::super::super::blog_def::v1::GetBlogArchiveResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::blog_def::v1::GetBlogArchiveRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_blog_archive(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlogArchiveSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetBlogPendingStoryCount" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlogPendingStoryCountSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::blog_def::v1::GetBlogPendingStoryCountRequest,
+                    > for GetBlogPendingStoryCountSvc<T> {
+                        type Response = super::super::super::blog_def::v1::GetBlogPendingStoryCountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::blog_def::v1::GetBlogPendingStoryCountRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_blog_pending_story_count(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlogPendingStoryCountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetBlogPublishedStoryCount" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlogPublishedStoryCountSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::blog_def::v1::GetBlogPublishedStoryCountRequest,
+                    > for GetBlogPublishedStoryCountSvc<T> {
+                        type Response = super::super::super::blog_def::v1::GetBlogPublishedStoryCountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::blog_def::v1::GetBlogPublishedStoryCountRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_blog_published_story_count(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlogPublishedStoryCountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetBlogEditorsInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlogEditorsInfoSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::blog_def::v1::GetBlogEditorsInfoRequest,
+                    > for GetBlogEditorsInfoSvc<T> {
+                        type Response = super::super::super::blog_def::v1::GetBlogEditorsInfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::blog_def::v1::GetBlogEditorsInfoRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_blog_editors_info(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlogEditorsInfoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetBlogWritersInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlogWritersInfoSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::blog_def::v1::GetBlogWritersInfoRequest,
+                    > for GetBlogWritersInfoSvc<T> {
+                        type Response = super::super::super::blog_def::v1::GetBlogWritersInfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::blog_def::v1::GetBlogWritersInfoRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_blog_writers_info(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlogWritersInfoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetBlogSitemap" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlogSitemapSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::blog_def::v1::GetBlogSitemapRequest,
+                    > for GetBlogSitemapSvc<T> {
+                        type Response = super::super::super::blog_def::v1::GetBlogSitemapResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::blog_def::v1::GetBlogSitemapRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_blog_sitemap(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlogSitemapSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetBlogNewsletter" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlogNewsletterSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::blog_def::v1::GetBlogNewsletterRequest,
+                    > for GetBlogNewsletterSvc<T> {
+                        type Response = super::super::super::blog_def::v1::GetBlogNewsletterResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::blog_def::v1::GetBlogNewsletterRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_blog_newsletter(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlogNewsletterSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetBlogNewsletterInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlogNewsletterInfoSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::blog_def::v1::GetBlogNewsletterInfoRequest,
+                    > for GetBlogNewsletterInfoSvc<T> {
+                        type Response = super::super::super::blog_def::v1::GetBlogNewsletterInfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::blog_def::v1::GetBlogNewsletterInfoRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_blog_newsletter_info(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetBlogNewsletterInfoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetStoryOpenGraphData" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetStoryOpenGraphDataSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::open_graph_def::v1::GetStoryOpenGraphDataRequest,
+                    > for GetStoryOpenGraphDataSvc<T> {
+                        type Response = super::super::super::open_graph_def::v1::GetStoryOpenGraphDataResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::open_graph_def::v1::GetStoryOpenGraphDataRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_story_open_graph_data(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetStoryOpenGraphDataSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/GetTagOpenGraphData" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTagOpenGraphDataSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::open_graph_def::v1::GetTagOpenGraphDataRequest,
+                    > for GetTagOpenGraphDataSvc<T> {
+                        type Response = super::super::super::open_graph_def::v1::GetTagOpenGraphDataResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::open_graph_def::v1::GetTagOpenGraphDataRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).get_tag_open_graph_data(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTagOpenGraphDataSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/BeginMfaEnrollment" => {
                     #[allow(non_camel_case_types)]
-                    struct GetUserIdSvc<T: ApiService>(pub Arc<T>);
+                    struct BeginMfaEnrollmentSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::user_def::v1::GetUserIdRequest,
-                    > for GetUserIdSvc<T> {
-                        type Response = super::super::super::user_def::v1::GetUserIdResponse;
+                        super::super::super::credential_settings_def::v1::BeginMfaEnrollmentRequest,
+                    > for BeginMfaEnrollmentSvc<T> {
+                        type Response = super::super::super::credential_settings_def::v1::BeginMfaEnrollmentResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -1963,11 +5884,13 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::user_def::v1::GetUserIdRequest,
+                                super::super::super::credential_settings_def::v1::BeginMfaEnrollmentRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move { (*inner).get_user_id(request).await };
+                            let fut = async move {
+                                (*inner).begin_mfa_enrollment(request).await
+                            };
                             Box::pin(fut)
                         }
                     }
@@ -1978,7 +5901,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetUserIdSvc(inner);
+                        let method = BeginMfaEnrollmentSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1994,15 +5917,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetUsername" => {
+                "/api_service.v1.ApiService/ConfirmMfaEnrollment" => {
                     #[allow(non_camel_case_types)]
-                    struct GetUsernameSvc<T: ApiService>(pub Arc<T>);
+                    struct ConfirmMfaEnrollmentSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::user_def::v1::GetUsernameRequest,
-                    > for GetUsernameSvc<T> {
-                        type Response = super::super::super::user_def::v1::GetUsernameResponse;
+                        super::super::super::credential_settings_def::v1::ConfirmMfaEnrollmentRequest,
+                    > for ConfirmMfaEnrollmentSvc<T> {
+                        type Response = super::super::super::credential_settings_def::v1::ConfirmMfaEnrollmentResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2010,12 +5933,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::user_def::v1::GetUsernameRequest,
+                                super::super::super::credential_settings_def::v1::ConfirmMfaEnrollmentRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_username(request).await
+                                (*inner).confirm_mfa_enrollment(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2027,7 +5950,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetUsernameSvc(inner);
+                        let method = ConfirmMfaEnrollmentSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2043,15 +5966,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetProfile" => {
+                "/api_service.v1.ApiService/BeginMfaChallenge" => {
                     #[allow(non_camel_case_types)]
-                    struct GetProfileSvc<T: ApiService>(pub Arc<T>);
+                    struct BeginMfaChallengeSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::profile_def::v1::GetProfileRequest,
-                    > for GetProfileSvc<T> {
-                        type Response = super::super::super::profile_def::v1::GetProfileResponse;
+                        super::super::super::credential_settings_def::v1::BeginMfaChallengeRequest,
+                    > for BeginMfaChallengeSvc<T> {
+                        type Response = super::super::super::credential_settings_def::v1::BeginMfaChallengeResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2059,11 +5982,13 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::profile_def::v1::GetProfileRequest,
+                                super::super::super::credential_settings_def::v1::BeginMfaChallengeRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move { (*inner).get_profile(request).await };
+                            let fut = async move {
+                                (*inner).begin_mfa_challenge(request).await
+                            };
                             Box::pin(fut)
                         }
                     }
@@ -2074,7 +5999,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetProfileSvc(inner);
+                        let method = BeginMfaChallengeSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2090,15 +6015,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetTag" => {
+                "/api_service.v1.ApiService/VerifyMfaCode" => {
                     #[allow(non_camel_case_types)]
-                    struct GetTagSvc<T: ApiService>(pub Arc<T>);
+                    struct VerifyMfaCodeSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::tag_def::v1::GetTagRequest,
-                    > for GetTagSvc<T> {
-                        type Response = super::super::super::tag_def::v1::GetTagResponse;
+                        super::super::super::credential_settings_def::v1::VerifyMfaCodeRequest,
+                    > for VerifyMfaCodeSvc<T> {
+                        type Response = super::super::super::credential_settings_def::v1::VerifyMfaCodeResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2106,11 +6031,13 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::tag_def::v1::GetTagRequest,
+                                super::super::super::credential_settings_def::v1::VerifyMfaCodeRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move { (*inner).get_tag(request).await };
+                            let fut = async move {
+                                (*inner).verify_mfa_code(request).await
+                            };
                             Box::pin(fut)
                         }
                     }
@@ -2121,7 +6048,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetTagSvc(inner);
+                        let method = VerifyMfaCodeSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2137,15 +6064,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetToken" => {
+                "/api_service.v1.ApiService/DisableMfa" => {
                     #[allow(non_camel_case_types)]
-                    struct GetTokenSvc<T: ApiService>(pub Arc<T>);
+                    struct DisableMfaSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::token_def::v1::GetTokenRequest,
-                    > for GetTokenSvc<T> {
-                        type Response = super::super::super::token_def::v1::GetTokenResponse;
+                        super::super::super::credential_settings_def::v1::DisableMfaRequest,
+                    > for DisableMfaSvc<T> {
+                        type Response = super::super::super::credential_settings_def::v1::DisableMfaResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2153,11 +6080,11 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::token_def::v1::GetTokenRequest,
+                                super::super::super::credential_settings_def::v1::DisableMfaRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move { (*inner).get_token(request).await };
+                            let fut = async move { (*inner).disable_mfa(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2168,7 +6095,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetTokenSvc(inner);
+                        let method = DisableMfaSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2184,15 +6111,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/VerifyEmail" => {
+                "/api_service.v1.ApiService/RegenerateMfaRecoveryCodes" => {
                     #[allow(non_camel_case_types)]
-                    struct VerifyEmailSvc<T: ApiService>(pub Arc<T>);
+                    struct RegenerateMfaRecoveryCodesSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::token_def::v1::VerifyEmailRequest,
-                    > for VerifyEmailSvc<T> {
-                        type Response = super::super::super::token_def::v1::VerifyEmailResponse;
+                        super::super::super::credential_settings_def::v1::RegenerateMfaRecoveryCodesRequest,
+                    > for RegenerateMfaRecoveryCodesSvc<T> {
+                        type Response = super::super::super::credential_settings_def::v1::RegenerateMfaRecoveryCodesResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2200,12 +6127,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::token_def::v1::VerifyEmailRequest,
+                                super::super::super::credential_settings_def::v1::RegenerateMfaRecoveryCodesRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).verify_email(request).await
+                                (*inner).regenerate_mfa_recovery_codes(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2217,7 +6144,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = VerifyEmailSvc(inner);
+                        let method = RegenerateMfaRecoveryCodesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2233,15 +6160,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/VerifyNewsletterSubscription" => {
+                "/api_service.v1.ApiService/BeginPasskeyRegistration" => {
                     #[allow(non_camel_case_types)]
-                    struct VerifyNewsletterSubscriptionSvc<T: ApiService>(pub Arc<T>);
+                    struct BeginPasskeyRegistrationSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::token_def::v1::VerifyNewsletterSubscriptionRequest,
-                    > for VerifyNewsletterSubscriptionSvc<T> {
-                        type Response = super::super::super::token_def::v1::VerifyNewsletterSubscriptionResponse;
+                        super::super::super::credential_settings_def::v1::BeginPasskeyRegistrationRequest,
+                    > for BeginPasskeyRegistrationSvc<T> {
+                        type Response = super::super::super::credential_settings_def::v1::BeginPasskeyRegistrationResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2249,12 +6176,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::token_def::v1::VerifyNewsletterSubscriptionRequest,
+                                super::super::super::credential_settings_def::v1::BeginPasskeyRegistrationRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).verify_newsletter_subscription(request).await
+                                (*inner).begin_passkey_registration(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2266,7 +6193,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = VerifyNewsletterSubscriptionSvc(inner);
+                        let method = BeginPasskeyRegistrationSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2282,15 +6209,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/VerifyBlogLogin" => {
+                "/api_service.v1.ApiService/FinishPasskeyRegistration" => {
                     #[allow(non_camel_case_types)]
-                    struct VerifyBlogLoginSvc<T: ApiService>(pub Arc<T>);
+                    struct FinishPasskeyRegistrationSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::blog_def::v1::VerifyBlogLoginRequest,
-                    > for VerifyBlogLoginSvc<T> {
-                        type Response = super::super::super::blog_def::v1::VerifyBlogLoginResponse;
+                        super::super::super::credential_settings_def::v1::FinishPasskeyRegistrationRequest,
+                    > for FinishPasskeyRegistrationSvc<T> {
+                        type Response = super::super::super::credential_settings_def::v1::FinishPasskeyRegistrationResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2298,12 +6225,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::blog_def::v1::VerifyBlogLoginRequest,
+                                super::super::super::credential_settings_def::v1::FinishPasskeyRegistrationRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).verify_blog_login(request).await
+                                (*inner).finish_passkey_registration(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2315,7 +6242,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = VerifyBlogLoginSvc(inner);
+                        let method = FinishPasskeyRegistrationSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2331,15 +6258,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetCredentialSettings" => {
+                "/api_service.v1.ApiService/BeginPasskeyAuthentication" => {
                     #[allow(non_camel_case_types)]
-                    struct GetCredentialSettingsSvc<T: ApiService>(pub Arc<T>);
+                    struct BeginPasskeyAuthenticationSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::credential_settings_def::v1::GetCredentialSettingsRequest,
-                    > for GetCredentialSettingsSvc<T> {
-                        type Response = super::super::super::credential_settings_def::v1::GetCredentialSettingsResponse;
+                        super::super::super::credential_settings_def::v1::BeginPasskeyAuthenticationRequest,
+                    > for BeginPasskeyAuthenticationSvc<T> {
+                        type Response = super::super::super::credential_settings_def::v1::BeginPasskeyAuthenticationResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2347,12 +6274,208 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::credential_settings_def::v1::GetCredentialSettingsRequest,
+                                super::super::super::credential_settings_def::v1::BeginPasskeyAuthenticationRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_credential_settings(request).await
+                                (*inner).begin_passkey_authentication(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BeginPasskeyAuthenticationSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/FinishPasskeyAuthentication" => {
+                    #[allow(non_camel_case_types)]
+                    struct FinishPasskeyAuthenticationSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::credential_settings_def::v1::FinishPasskeyAuthenticationRequest,
+                    > for FinishPasskeyAuthenticationSvc<T> {
+                        type Response = super::super::super::credential_settings_def::v1::FinishPasskeyAuthenticationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::credential_settings_def::v1::FinishPasskeyAuthenticationRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).finish_passkey_authentication(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = FinishPasskeyAuthenticationSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/RemovePasskey" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemovePasskeySvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::credential_settings_def::v1::RemovePasskeyRequest,
+                    > for RemovePasskeySvc<T> {
+                        type Response = super::super::super::credential_settings_def::v1::RemovePasskeyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::credential_settings_def::v1::RemovePasskeyRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).remove_passkey(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RemovePasskeySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/LinkLoginProvider" => {
+                    #[allow(non_camel_case_types)]
+                    struct LinkLoginProviderSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::credential_settings_def::v1::LinkLoginProviderRequest,
+                    > for LinkLoginProviderSvc<T> {
+                        type Response = super::super::super::credential_settings_def::v1::LinkLoginProviderResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::credential_settings_def::v1::LinkLoginProviderRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).link_login_provider(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = LinkLoginProviderSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/api_service.v1.ApiService/UnlinkLoginProvider" => {
+                    #[allow(non_camel_case_types)]
+                    struct UnlinkLoginProviderSvc<T: ApiService>(pub Arc<T>);
+                    impl<
+                        T: ApiService,
+                    > tonic::server::UnaryService<
+                        super::super::super::credential_settings_def::v1::UnlinkLoginProviderRequest,
+                    > for UnlinkLoginProviderSvc<T> {
+                        type Response = super::super::super::credential_settings_def::v1::UnlinkLoginProviderResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::super::super::credential_settings_def::v1::UnlinkLoginProviderRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                (*inner).unlink_login_provider(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2364,7 +6487,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetCredentialSettingsSvc(inner);
+                        let method = UnlinkLoginProviderSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2380,15 +6503,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetPrivacySettings" => {
+                "/api_service.v1.ApiService/AddConnection" => {
                     #[allow(non_camel_case_types)]
-                    struct GetPrivacySettingsSvc<T: ApiService>(pub Arc<T>);
+                    struct AddConnectionSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::privacy_settings_def::v1::GetPrivacySettingsRequest,
-                    > for GetPrivacySettingsSvc<T> {
-                        type Response = super::super::super::privacy_settings_def::v1::GetPrivacySettingsResponse;
+                        super::super::super::connection_settings_def::v1::AddConnectionRequest,
+                    > for AddConnectionSvc<T> {
+                        type Response = super::super::super::connection_settings_def::v1::AddConnectionResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2396,12 +6519,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::privacy_settings_def::v1::GetPrivacySettingsRequest,
+                                super::super::super::connection_settings_def::v1::AddConnectionRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_privacy_settings(request).await
+                                (*inner).add_connection(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2413,7 +6536,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetPrivacySettingsSvc(inner);
+                        let method = AddConnectionSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2429,15 +6552,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetNotificationSettings" => {
+                "/api_service.v1.ApiService/SetConnectionHidden" => {
                     #[allow(non_camel_case_types)]
-                    struct GetNotificationSettingsSvc<T: ApiService>(pub Arc<T>);
+                    struct SetConnectionHiddenSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::notification_settings_def::v1::GetNotificationSettingsRequest,
-                    > for GetNotificationSettingsSvc<T> {
-                        type Response = super::super::super::notification_settings_def::v1::GetNotificationSettingsResponse;
+                        super::super::super::connection_settings_def::v1::SetConnectionHiddenRequest,
+                    > for SetConnectionHiddenSvc<T> {
+                        type Response = super::super::super::connection_settings_def::v1::SetConnectionHiddenResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2445,12 +6568,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::notification_settings_def::v1::GetNotificationSettingsRequest,
+                                super::super::super::connection_settings_def::v1::SetConnectionHiddenRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_notification_settings(request).await
+                                (*inner).set_connection_hidden(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2462,7 +6585,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetNotificationSettingsSvc(inner);
+                        let method = SetConnectionHiddenSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2478,15 +6601,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetConnectionSettings" => {
+                "/api_service.v1.ApiService/RemoveConnection" => {
                     #[allow(non_camel_case_types)]
-                    struct GetConnectionSettingsSvc<T: ApiService>(pub Arc<T>);
+                    struct RemoveConnectionSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::connection_settings_def::v1::GetConnectionSettingsRequest,
-                    > for GetConnectionSettingsSvc<T> {
-                        type Response = super::super::super::connection_settings_def::v1::GetConnectionSettingsResponse;
+                        super::super::super::connection_settings_def::v1::RemoveConnectionRequest,
+                    > for RemoveConnectionSvc<T> {
+                        type Response = super::super::super::connection_settings_def::v1::RemoveConnectionResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2494,12 +6617,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::connection_settings_def::v1::GetConnectionSettingsRequest,
+                                super::super::super::connection_settings_def::v1::RemoveConnectionRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_connection_settings(request).await
+                                (*inner).remove_connection(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2511,7 +6634,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetConnectionSettingsSvc(inner);
+                        let method = RemoveConnectionSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2527,15 +6650,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetLoginActivity" => {
+                "/api_service.v1.ApiService/RefreshConnection" => {
                     #[allow(non_camel_case_types)]
-                    struct GetLoginActivitySvc<T: ApiService>(pub Arc<T>);
+                    struct RefreshConnectionSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::login_activity_def::v1::GetLoginActivityRequest,
-                    > for GetLoginActivitySvc<T> {
-                        type Response = super::super::super::login_activity_def::v1::GetLoginActivityResponse;
+                        super::super::super::connection_settings_def::v1::RefreshConnectionRequest,
+                    > for RefreshConnectionSvc<T> {
+                        type Response = super::super::super::connection_settings_def::v1::RefreshConnectionResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2543,12 +6666,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::login_activity_def::v1::GetLoginActivityRequest,
+                                super::super::super::connection_settings_def::v1::RefreshConnectionRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_login_activity(request).await
+                                (*inner).refresh_connection(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2560,7 +6683,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetLoginActivitySvc(inner);
+                        let method = RefreshConnectionSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2576,15 +6699,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/ValidateStory" => {
+                "/api_service.v1.ApiService/UpdatePrivacySettings" => {
                     #[allow(non_camel_case_types)]
-                    struct ValidateStorySvc<T: ApiService>(pub Arc<T>);
+                    struct UpdatePrivacySettingsSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::story_def::v1::ValidateStoryRequest,
-                    > for ValidateStorySvc<T> {
-                        type Response = super::super::super::story_def::v1::ValidateStoryResponse;
+                        super::super::super::privacy_settings_def::v1::UpdatePrivacySettingsRequest,
+                    > for UpdatePrivacySettingsSvc<T> {
+                        type Response = super::super::super::privacy_settings_def::v1::UpdatePrivacySettingsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2592,12 +6715,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::story_def::v1::ValidateStoryRequest,
+                                super::super::super::privacy_settings_def::v1::UpdatePrivacySettingsRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).validate_story(request).await
+                                (*inner).update_privacy_settings(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2609,7 +6732,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ValidateStorySvc(inner);
+                        let method = UpdatePrivacySettingsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2625,15 +6748,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetDraftsInfo" => {
+                "/api_service.v1.ApiService/UpdateNotificationSettings" => {
                     #[allow(non_camel_case_types)]
-                    struct GetDraftsInfoSvc<T: ApiService>(pub Arc<T>);
+                    struct UpdateNotificationSettingsSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::story_def::v1::GetDraftsInfoRequest,
-                    > for GetDraftsInfoSvc<T> {
-                        type Response = super::super::super::story_def::v1::GetDraftsInfoResponse;
+                        super::super::super::notification_settings_def::v1::UpdateNotificationSettingsRequest,
+                    > for UpdateNotificationSettingsSvc<T> {
+                        type Response = super::super::super::notification_settings_def::v1::UpdateNotificationSettingsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2641,12 +6764,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::story_def::v1::GetDraftsInfoRequest,
+                                super::super::super::notification_settings_def::v1::UpdateNotificationSettingsRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_drafts_info(request).await
+                                (*inner).update_notification_settings(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2658,7 +6781,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetDraftsInfoSvc(inner);
+                        let method = UpdateNotificationSettingsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2674,15 +6797,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetStoriesInfo" => {
+                "/api_service.v1.ApiService/ListNotifications" => {
                     #[allow(non_camel_case_types)]
-                    struct GetStoriesInfoSvc<T: ApiService>(pub Arc<T>);
+                    struct ListNotificationsSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::story_def::v1::GetStoriesInfoRequest,
-                    > for GetStoriesInfoSvc<T> {
-                        type Response = super::super::super::story_def::v1::GetStoriesInfoResponse;
+                        super::super::super::notification_def::v1::ListNotificationsRequest,
+                    > for ListNotificationsSvc<T> {
+                        type Response = super::super::super::notification_def::v1::ListNotificationsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2690,12 +6813,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::story_def::v1::GetStoriesInfoRequest,
+                                super::super::super::notification_def::v1::ListNotificationsRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_stories_info(request).await
+                                (*inner).list_notifications(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2707,7 +6830,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetStoriesInfoSvc(inner);
+                        let method = ListNotificationsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2723,15 +6846,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetContributionsInfo" => {
+                "/api_service.v1.ApiService/MarkNotificationsRead" => {
                     #[allow(non_camel_case_types)]
-                    struct GetContributionsInfoSvc<T: ApiService>(pub Arc<T>);
+                    struct MarkNotificationsReadSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::story_def::v1::GetContributionsInfoRequest,
-                    > for GetContributionsInfoSvc<T> {
-                        type Response = super::super::super::story_def::v1::GetContributionsInfoResponse;
+                        super::super::super::notification_def::v1::MarkNotificationsReadRequest,
+                    > for MarkNotificationsReadSvc<T> {
+                        type Response = super::super::super::notification_def::v1::MarkNotificationsReadResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2739,12 +6862,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::story_def::v1::GetContributionsInfoRequest,
+                                super::super::super::notification_def::v1::MarkNotificationsReadRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_contributions_info(request).await
+                                (*inner).mark_notifications_read(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2756,7 +6879,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetContributionsInfoSvc(inner);
+                        let method = MarkNotificationsReadSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2772,15 +6895,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetResponsesInfo" => {
+                "/api_service.v1.ApiService/GetUnreadNotificationCount" => {
                     #[allow(non_camel_case_types)]
-                    struct GetResponsesInfoSvc<T: ApiService>(pub Arc<T>);
+                    struct GetUnreadNotificationCountSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::response_def::v1::GetResponsesInfoRequest,
-                    > for GetResponsesInfoSvc<T> {
-                        type Response = super::super::super::response_def::v1::GetResponsesInfoResponse;
+                        super::super::super::notification_def::v1::GetUnreadNotificationCountRequest,
+                    > for GetUnreadNotificationCountSvc<T> {
+                        type Response = super::super::super::notification_def::v1::GetUnreadNotificationCountResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2788,12 +6911,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::response_def::v1::GetResponsesInfoRequest,
+                                super::super::super::notification_def::v1::GetUnreadNotificationCountRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_responses_info(request).await
+                                (*inner).get_unread_notification_count(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2805,7 +6928,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetResponsesInfoSvc(inner);
+                        let method = GetUnreadNotificationCountSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2821,28 +6944,29 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetStoryResponsesInfo" => {
+                "/api_service.v1.ApiService/WatchNotifications" => {
                     #[allow(non_camel_case_types)]
-                    struct GetStoryResponsesInfoSvc<T: ApiService>(pub Arc<T>);
+                    struct WatchNotificationsSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
-                    > tonic::server::UnaryService<
-                        super::super::super::response_def::v1::GetStoryResponsesInfoRequest,
-                    > for GetStoryResponsesInfoSvc<T> {
-                        type Response = super::super::super::response_def::v1::GetStoryResponsesInfoResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::super::super::notification_def::v1::WatchNotificationsRequest,
+                    > for WatchNotificationsSvc<T> {
+                        type Response = super::super::super::notification_def::v1::WatchNotificationsResponse;
+                        type ResponseStream = T::WatchNotificationsStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::response_def::v1::GetStoryResponsesInfoRequest,
+                                super::super::super::notification_def::v1::WatchNotificationsRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_story_responses_info(request).await
+                                (*inner).watch_notifications(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2854,7 +6978,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetStoryResponsesInfoSvc(inner);
+                        let method = WatchNotificationsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2865,20 +6989,20 @@ pub mod api_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetFollowedTagCount" => {
+                "/api_service.v1.ApiService/SetStatus" => {
                     #[allow(non_camel_case_types)]
-                    struct GetFollowedTagCountSvc<T: ApiService>(pub Arc<T>);
+                    struct SetStatusSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::tag_def::v1::GetFollowedTagCountRequest,
-                    > for GetFollowedTagCountSvc<T> {
-                        type Response = super::super::super::tag_def::v1::GetFollowedTagCountResponse;
+                        super::super::super::user_def::v1::SetStatusRequest,
+                    > for SetStatusSvc<T> {
+                        type Response = super::super::super::user_def::v1::SetStatusResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2886,13 +7010,11 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::tag_def::v1::GetFollowedTagCountRequest,
+                                super::super::super::user_def::v1::SetStatusRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                (*inner).get_followed_tag_count(request).await
-                            };
+                            let fut = async move { (*inner).set_status(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -2903,7 +7025,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetFollowedTagCountSvc(inner);
+                        let method = SetStatusSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2919,15 +7041,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetUserRelationsInfo" => {
+                "/api_service.v1.ApiService/ClearStatus" => {
                     #[allow(non_camel_case_types)]
-                    struct GetUserRelationsInfoSvc<T: ApiService>(pub Arc<T>);
+                    struct ClearStatusSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::user_def::v1::GetUserRelationsInfoRequest,
-                    > for GetUserRelationsInfoSvc<T> {
-                        type Response = super::super::super::user_def::v1::GetUserRelationsInfoResponse;
+                        super::super::super::user_def::v1::ClearStatusRequest,
+                    > for ClearStatusSvc<T> {
+                        type Response = super::super::super::user_def::v1::ClearStatusResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2935,12 +7057,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::user_def::v1::GetUserRelationsInfoRequest,
+                                super::super::super::user_def::v1::ClearStatusRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_user_relations_info(request).await
+                                (*inner).clear_status(request).await
                             };
                             Box::pin(fut)
                         }
@@ -2952,7 +7074,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetUserRelationsInfoSvc(inner);
+                        let method = ClearStatusSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2968,15 +7090,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetUserBlogsInfo" => {
+                "/api_service.v1.ApiService/UpdateProfile" => {
                     #[allow(non_camel_case_types)]
-                    struct GetUserBlogsInfoSvc<T: ApiService>(pub Arc<T>);
+                    struct UpdateProfileSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::blog_def::v1::GetUserBlogsInfoRequest,
-                    > for GetUserBlogsInfoSvc<T> {
-                        type Response = super::super::super::blog_def::v1::GetUserBlogsInfoResponse;
+                        super::super::super::profile_def::v1::UpdateProfileRequest,
+                    > for UpdateProfileSvc<T> {
+                        type Response = super::super::super::profile_def::v1::UpdateProfileResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2984,12 +7106,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::blog_def::v1::GetUserBlogsInfoRequest,
+                                super::super::super::profile_def::v1::UpdateProfileRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_user_blogs_info(request).await
+                                (*inner).update_profile(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3001,7 +7123,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetUserBlogsInfoSvc(inner);
+                        let method = UpdateProfileSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3017,15 +7139,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetUserBlockCount" => {
+                "/api_service.v1.ApiService/UploadImage" => {
                     #[allow(non_camel_case_types)]
-                    struct GetUserBlockCountSvc<T: ApiService>(pub Arc<T>);
+                    struct UploadImageSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
-                    > tonic::server::UnaryService<
-                        super::super::super::user_def::v1::GetUserBlockCountRequest,
-                    > for GetUserBlockCountSvc<T> {
-                        type Response = super::super::super::user_def::v1::GetUserBlockCountResponse;
+                    > tonic::server::ClientStreamingService<
+                        super::super::super::asset_def::v1::UploadImageRequest,
+                    > for UploadImageSvc<T> {
+                        type Response = super::super::super::asset_def::v1::UploadImageResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3033,12 +7155,14 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::user_def::v1::GetUserBlockCountRequest,
+                                tonic::Streaming<
+                                    super::super::super::asset_def::v1::UploadImageRequest,
+                                >,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_user_block_count(request).await
+                                (*inner).upload_image(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3050,7 +7174,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetUserBlockCountSvc(inner);
+                        let method = UploadImageSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3061,20 +7185,20 @@ pub mod api_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.client_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetUserMuteCount" => {
+                "/api_service.v1.ApiService/SetAssetRating" => {
                     #[allow(non_camel_case_types)]
-                    struct GetUserMuteCountSvc<T: ApiService>(pub Arc<T>);
+                    struct SetAssetRatingSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::user_def::v1::GetUserMuteCountRequest,
-                    > for GetUserMuteCountSvc<T> {
-                        type Response = super::super::super::user_def::v1::GetUserMuteCountResponse;
+                        super::super::super::asset_def::v1::SetAssetRatingRequest,
+                    > for SetAssetRatingSvc<T> {
+                        type Response = super::super::super::asset_def::v1::SetAssetRatingResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3082,12 +7206,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::user_def::v1::GetUserMuteCountRequest,
+                                super::super::super::asset_def::v1::SetAssetRatingRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_user_mute_count(request).await
+                                (*inner).set_asset_rating(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3099,7 +7223,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetUserMuteCountSvc(inner);
+                        let method = SetAssetRatingSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3115,15 +7239,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetStory" => {
+                "/api_service.v1.ApiService/ReportContent" => {
                     #[allow(non_camel_case_types)]
-                    struct GetStorySvc<T: ApiService>(pub Arc<T>);
+                    struct ReportContentSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::story_def::v1::GetStoryRequest,
-                    > for GetStorySvc<T> {
-                        type Response = super::super::super::story_def::v1::GetStoryResponse;
+                        super::super::super::report_def::v1::ReportContentRequest,
+                    > for ReportContentSvc<T> {
+                        type Response = super::super::super::report_def::v1::ReportContentResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3131,11 +7255,13 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::story_def::v1::GetStoryRequest,
+                                super::super::super::report_def::v1::ReportContentRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move { (*inner).get_story(request).await };
+                            let fut = async move {
+                                (*inner).report_content(request).await
+                            };
                             Box::pin(fut)
                         }
                     }
@@ -3146,7 +7272,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetStorySvc(inner);
+                        let method = ReportContentSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3162,15 +7288,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetStoryMetadata" => {
+                "/api_service.v1.ApiService/ListReports" => {
                     #[allow(non_camel_case_types)]
-                    struct GetStoryMetadataSvc<T: ApiService>(pub Arc<T>);
+                    struct ListReportsSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::story_def::v1::GetStoryMetadataRequest,
-                    > for GetStoryMetadataSvc<T> {
-                        type Response = super::super::super::story_def::v1::GetStoryMetadataResponse;
+                        super::super::super::report_def::v1::ListReportsRequest,
+                    > for ListReportsSvc<T> {
+                        type Response = super::super::super::report_def::v1::ListReportsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3178,12 +7304,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::story_def::v1::GetStoryMetadataRequest,
+                                super::super::super::report_def::v1::ListReportsRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_story_metadata(request).await
+                                (*inner).list_reports(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3195,7 +7321,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetStoryMetadataSvc(inner);
+                        let method = ListReportsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3211,15 +7337,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetComment" => {
+                "/api_service.v1.ApiService/ResolveReport" => {
                     #[allow(non_camel_case_types)]
-                    struct GetCommentSvc<T: ApiService>(pub Arc<T>);
+                    struct ResolveReportSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::comment_def::v1::GetCommentRequest,
-                    > for GetCommentSvc<T> {
-                        type Response = super::super::super::comment_def::v1::GetCommentResponse;
+                        super::super::super::report_def::v1::ResolveReportRequest,
+                    > for ResolveReportSvc<T> {
+                        type Response = super::super::super::report_def::v1::ResolveReportResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3227,11 +7353,13 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::comment_def::v1::GetCommentRequest,
+                                super::super::super::report_def::v1::ResolveReportRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move { (*inner).get_comment(request).await };
+                            let fut = async move {
+                                (*inner).resolve_report(request).await
+                            };
                             Box::pin(fut)
                         }
                     }
@@ -3242,7 +7370,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetCommentSvc(inner);
+                        let method = ResolveReportSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3258,15 +7386,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/CreateDraft" => {
+                "/api_service.v1.ApiService/GrantUserFlag" => {
                     #[allow(non_camel_case_types)]
-                    struct CreateDraftSvc<T: ApiService>(pub Arc<T>);
+                    struct GrantUserFlagSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::story_def::v1::CreateDraftRequest,
-                    > for CreateDraftSvc<T> {
-                        type Response = super::super::super::story_def::v1::CreateDraftResponse;
+                        super::super::super::user_def::v1::GrantUserFlagRequest,
+                    > for GrantUserFlagSvc<T> {
+                        type Response = super::super::super::user_def::v1::GrantUserFlagResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3274,12 +7402,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::story_def::v1::CreateDraftRequest,
+                                super::super::super::user_def::v1::GrantUserFlagRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).create_draft(request).await
+                                (*inner).grant_user_flag(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3291,7 +7419,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = CreateDraftSvc(inner);
+                        let method = GrantUserFlagSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3307,15 +7435,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetBlog" => {
+                "/api_service.v1.ApiService/RevokeUserFlag" => {
                     #[allow(non_camel_case_types)]
-                    struct GetBlogSvc<T: ApiService>(pub Arc<T>);
+                    struct RevokeUserFlagSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::blog_def::v1::GetBlogRequest,
-                    > for GetBlogSvc<T> {
-                        type Response = super::super::super::blog_def::v1::GetBlogResponse;
+                        super::super::super::user_def::v1::RevokeUserFlagRequest,
+                    > for RevokeUserFlagSvc<T> {
+                        type Response = super::super::super::user_def::v1::RevokeUserFlagResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3323,11 +7451,13 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::blog_def::v1::GetBlogRequest,
+                                super::super::super::user_def::v1::RevokeUserFlagRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move { (*inner).get_blog(request).await };
+                            let fut = async move {
+                                (*inner).revoke_user_flag(request).await
+                            };
                             Box::pin(fut)
                         }
                     }
@@ -3338,7 +7468,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetBlogSvc(inner);
+                        let method = RevokeUserFlagSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3354,15 +7484,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetBlogArchive" => {
+                "/api_service.v1.ApiService/SuspendUser" => {
                     #[allow(non_camel_case_types)]
-                    struct GetBlogArchiveSvc<T: ApiService>(pub Arc<T>);
+                    struct SuspendUserSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::blog_def::v1::GetBlogArchiveRequest,
-                    > for GetBlogArchiveSvc<T> {
-                        type Response = super::super::super::blog_def::v1::GetBlogArchiveResponse;
+                        super::super::super::user_def::v1::SuspendUserRequest,
+                    > for SuspendUserSvc<T> {
+                        type Response = super::super::super::user_def::v1::SuspendUserResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3370,12 +7500,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::blog_def::v1::GetBlogArchiveRequest,
+                                super::super::super::user_def::v1::SuspendUserRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_blog_archive(request).await
+                                (*inner).suspend_user(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3387,7 +7517,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetBlogArchiveSvc(inner);
+                        let method = SuspendUserSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3403,15 +7533,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetBlogPendingStoryCount" => {
+                "/api_service.v1.ApiService/UnsuspendUser" => {
                     #[allow(non_camel_case_types)]
-                    struct GetBlogPendingStoryCountSvc<T: ApiService>(pub Arc<T>);
+                    struct UnsuspendUserSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::blog_def::v1::GetBlogPendingStoryCountRequest,
-                    > for GetBlogPendingStoryCountSvc<T> {
-                        type Response = super::super::super::blog_def::v1::GetBlogPendingStoryCountResponse;
+                        super::super::super::user_def::v1::UnsuspendUserRequest,
+                    > for UnsuspendUserSvc<T> {
+                        type Response = super::super::super::user_def::v1::UnsuspendUserResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3419,12 +7549,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::blog_def::v1::GetBlogPendingStoryCountRequest,
+                                super::super::super::user_def::v1::UnsuspendUserRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_blog_pending_story_count(request).await
+                                (*inner).unsuspend_user(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3436,7 +7566,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetBlogPendingStoryCountSvc(inner);
+                        let method = UnsuspendUserSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3452,15 +7582,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetBlogPublishedStoryCount" => {
+                "/api_service.v1.ApiService/RequestAccountExport" => {
                     #[allow(non_camel_case_types)]
-                    struct GetBlogPublishedStoryCountSvc<T: ApiService>(pub Arc<T>);
+                    struct RequestAccountExportSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::blog_def::v1::GetBlogPublishedStoryCountRequest,
-                    > for GetBlogPublishedStoryCountSvc<T> {
-                        type Response = super::super::super::blog_def::v1::GetBlogPublishedStoryCountResponse;
+                        super::super::super::account_def::v1::RequestAccountExportRequest,
+                    > for RequestAccountExportSvc<T> {
+                        type Response = super::super::super::account_def::v1::RequestAccountExportResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3468,12 +7598,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::blog_def::v1::GetBlogPublishedStoryCountRequest,
+                                super::super::super::account_def::v1::RequestAccountExportRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_blog_published_story_count(request).await
+                                (*inner).request_account_export(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3485,7 +7615,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetBlogPublishedStoryCountSvc(inner);
+                        let method = RequestAccountExportSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3501,15 +7631,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetBlogEditorsInfo" => {
+                "/api_service.v1.ApiService/GetAccountExportStatus" => {
                     #[allow(non_camel_case_types)]
-                    struct GetBlogEditorsInfoSvc<T: ApiService>(pub Arc<T>);
+                    struct GetAccountExportStatusSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::blog_def::v1::GetBlogEditorsInfoRequest,
-                    > for GetBlogEditorsInfoSvc<T> {
-                        type Response = super::super::super::blog_def::v1::GetBlogEditorsInfoResponse;
+                        super::super::super::account_def::v1::GetAccountExportStatusRequest,
+                    > for GetAccountExportStatusSvc<T> {
+                        type Response = super::super::super::account_def::v1::GetAccountExportStatusResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3517,12 +7647,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::blog_def::v1::GetBlogEditorsInfoRequest,
+                                super::super::super::account_def::v1::GetAccountExportStatusRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_blog_editors_info(request).await
+                                (*inner).get_account_export_status(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3534,7 +7664,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetBlogEditorsInfoSvc(inner);
+                        let method = GetAccountExportStatusSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3550,15 +7680,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetBlogWritersInfo" => {
+                "/api_service.v1.ApiService/ScheduleAccountDeletion" => {
                     #[allow(non_camel_case_types)]
-                    struct GetBlogWritersInfoSvc<T: ApiService>(pub Arc<T>);
+                    struct ScheduleAccountDeletionSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::blog_def::v1::GetBlogWritersInfoRequest,
-                    > for GetBlogWritersInfoSvc<T> {
-                        type Response = super::super::super::blog_def::v1::GetBlogWritersInfoResponse;
+                        super::super::super::account_def::v1::ScheduleAccountDeletionRequest,
+                    > for ScheduleAccountDeletionSvc<T> {
+                        type Response = super::super::super::account_def::v1::ScheduleAccountDeletionResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3566,12 +7696,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::blog_def::v1::GetBlogWritersInfoRequest,
+                                super::super::super::account_def::v1::ScheduleAccountDeletionRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_blog_writers_info(request).await
+                                (*inner).schedule_account_deletion(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3583,7 +7713,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetBlogWritersInfoSvc(inner);
+                        let method = ScheduleAccountDeletionSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3599,15 +7729,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetBlogSitemap" => {
+                "/api_service.v1.ApiService/CancelAccountDeletion" => {
                     #[allow(non_camel_case_types)]
-                    struct GetBlogSitemapSvc<T: ApiService>(pub Arc<T>);
+                    struct CancelAccountDeletionSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::blog_def::v1::GetBlogSitemapRequest,
-                    > for GetBlogSitemapSvc<T> {
-                        type Response = super::super::super::blog_def::v1::GetBlogSitemapResponse;
+                        super::super::super::account_def::v1::CancelAccountDeletionRequest,
+                    > for CancelAccountDeletionSvc<T> {
+                        type Response = super::super::super::account_def::v1::CancelAccountDeletionResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3615,12 +7745,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::blog_def::v1::GetBlogSitemapRequest,
+                                super::super::super::account_def::v1::CancelAccountDeletionRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_blog_sitemap(request).await
+                                (*inner).cancel_account_deletion(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3632,7 +7762,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetBlogSitemapSvc(inner);
+                        let method = CancelAccountDeletionSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3648,15 +7778,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetBlogNewsletter" => {
+                "/api_service.v1.ApiService/ImportStory" => {
                     #[allow(non_camel_case_types)]
-                    struct GetBlogNewsletterSvc<T: ApiService>(pub Arc<T>);
+                    struct ImportStorySvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::blog_def::v1::GetBlogNewsletterRequest,
-                    > for GetBlogNewsletterSvc<T> {
-                        type Response = super::super::super::blog_def::v1::GetBlogNewsletterResponse;
+                        super::super::super::story_def::v1::ImportStoryRequest,
+                    > for ImportStorySvc<T> {
+                        type Response = super::super::super::story_def::v1::ImportStoryResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3664,12 +7794,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::blog_def::v1::GetBlogNewsletterRequest,
+                                super::super::super::story_def::v1::ImportStoryRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_blog_newsletter(request).await
+                                (*inner).import_story(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3681,7 +7811,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetBlogNewsletterSvc(inner);
+                        let method = ImportStorySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3697,15 +7827,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetBlogNewsletterInfo" => {
+                "/api_service.v1.ApiService/ImportStories" => {
                     #[allow(non_camel_case_types)]
-                    struct GetBlogNewsletterInfoSvc<T: ApiService>(pub Arc<T>);
+                    struct ImportStoriesSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::blog_def::v1::GetBlogNewsletterInfoRequest,
-                    > for GetBlogNewsletterInfoSvc<T> {
-                        type Response = super::super::super::blog_def::v1::GetBlogNewsletterInfoResponse;
+                        super::super::super::story_def::v1::ImportStoriesRequest,
+                    > for ImportStoriesSvc<T> {
+                        type Response = super::super::super::story_def::v1::ImportStoriesResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3713,12 +7843,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::blog_def::v1::GetBlogNewsletterInfoRequest,
+                                super::super::super::story_def::v1::ImportStoriesRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_blog_newsletter_info(request).await
+                                (*inner).import_stories(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3730,7 +7860,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetBlogNewsletterInfoSvc(inner);
+                        let method = ImportStoriesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3746,15 +7876,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetStoryOpenGraphData" => {
+                "/api_service.v1.ApiService/ExportStory" => {
                     #[allow(non_camel_case_types)]
-                    struct GetStoryOpenGraphDataSvc<T: ApiService>(pub Arc<T>);
+                    struct ExportStorySvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::open_graph_def::v1::GetStoryOpenGraphDataRequest,
-                    > for GetStoryOpenGraphDataSvc<T> {
-                        type Response = super::super::super::open_graph_def::v1::GetStoryOpenGraphDataResponse;
+                        super::super::super::story_def::v1::ExportStoryRequest,
+                    > for ExportStorySvc<T> {
+                        type Response = super::super::super::story_def::v1::ExportStoryResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3762,12 +7892,12 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::open_graph_def::v1::GetStoryOpenGraphDataRequest,
+                                super::super::super::story_def::v1::ExportStoryRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                (*inner).get_story_open_graph_data(request).await
+                                (*inner).export_story(request).await
                             };
                             Box::pin(fut)
                         }
@@ -3779,7 +7909,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetStoryOpenGraphDataSvc(inner);
+                        let method = ExportStorySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3795,15 +7925,15 @@ pub mod api_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/api_service.v1.ApiService/GetTagOpenGraphData" => {
+                "/api_service.v1.ApiService/ExportBlog" => {
                     #[allow(non_camel_case_types)]
-                    struct GetTagOpenGraphDataSvc<T: ApiService>(pub Arc<T>);
+                    struct ExportBlogSvc<T: ApiService>(pub Arc<T>);
                     impl<
                         T: ApiService,
                     > tonic::server::UnaryService<
-                        super::super::super::open_graph_def::v1::GetTagOpenGraphDataRequest,
-                    > for GetTagOpenGraphDataSvc<T> {
-                        type Response = super::super::super::open_graph_def::v1::GetTagOpenGraphDataResponse;
+                        super::super::super::blog_def::v1::ExportBlogRequest,
+                    > for ExportBlogSvc<T> {
+                        type Response = super::super::super::blog_def::v1::ExportBlogResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -3811,13 +7941,11 @@ pub mod api_service_server {
                         fn call(
                             &mut self,
                             request: tonic::Request<
-                                super::super::super::open_graph_def::v1::GetTagOpenGraphDataRequest,
+                                super::super::super::blog_def::v1::ExportBlogRequest,
                             >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                (*inner).get_tag_open_graph_data(request).await
-                            };
+                            let fut = async move { (*inner).export_blog(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -3828,7 +7956,7 @@ pub mod api_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GetTagOpenGraphDataSvc(inner);
+                        let method = ExportBlogSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -0,0 +1,154 @@
+// @generated
+// Upload image
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UploadImageMetadata {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    /// Determines the resized variants
+    #[prost(enumeration="AssetPurpose", tag="2")]
+    pub purpose: i32,
+    #[prost(string, tag="3")]
+    pub content_type: ::prost::alloc::string::String,
+    /// Total size of the chunks (in bytes)
+    #[prost(uint32, tag="4")]
+    pub size: u32,
+    #[prost(string, optional, tag="5")]
+    pub alt: ::core::option::Option<::prost::alloc::string::String>,
+    /// Set by the uploader, can be changed by the moderators
+    #[prost(enumeration="AssetRating", tag="6")]
+    pub rating: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UploadImageRequest {
+    /// The first message must carry the metadata, followed by the chunks
+    #[prost(oneof="upload_image_request::Data", tags="1, 2")]
+    pub data: ::core::option::Option<upload_image_request::Data>,
+}
+/// Nested message and enum types in `UploadImageRequest`.
+pub mod upload_image_request {
+    /// The first message must carry the metadata, followed by the chunks
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Data {
+        #[prost(message, tag="1")]
+        Metadata(super::UploadImageMetadata),
+        #[prost(bytes, tag="2")]
+        Chunk(::prost::alloc::vec::Vec<u8>),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UploadImageResponse {
+    /// Used as `avatar_id`, `splash_id`, etc.
+    #[prost(string, tag="1")]
+    pub id: ::prost::alloc::string::String,
+    /// Dominant color, used as `avatar_hex`, `splash_hex`, etc.
+    #[prost(string, tag="2")]
+    pub hex: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub blurhash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="4")]
+    pub width: u32,
+    #[prost(uint32, tag="5")]
+    pub height: u32,
+    #[prost(enumeration="AssetRating", tag="6")]
+    pub rating: i32,
+}
+// Set asset rating
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetAssetRatingRequest {
+    #[prost(string, tag="1")]
+    pub asset_id: ::prost::alloc::string::String,
+    #[prost(enumeration="AssetRating", tag="2")]
+    pub rating: i32,
+    #[prost(string, tag="3")]
+    pub moderator_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetAssetRatingResponse {
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AssetPurpose {
+    Unspecified = 0,
+    Avatar = 1,
+    Banner = 2,
+    Splash = 3,
+    Logo = 4,
+    NewsletterSplash = 5,
+    /// Images embedded in the story content
+    Story = 6,
+}
+impl AssetPurpose {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AssetPurpose::Unspecified => "ASSET_PURPOSE_UNSPECIFIED",
+            AssetPurpose::Avatar => "ASSET_PURPOSE_AVATAR",
+            AssetPurpose::Banner => "ASSET_PURPOSE_BANNER",
+            AssetPurpose::Splash => "ASSET_PURPOSE_SPLASH",
+            AssetPurpose::Logo => "ASSET_PURPOSE_LOGO",
+            AssetPurpose::NewsletterSplash => "ASSET_PURPOSE_NEWSLETTER_SPLASH",
+            AssetPurpose::Story => "ASSET_PURPOSE_STORY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ASSET_PURPOSE_UNSPECIFIED" => Some(Self::Unspecified),
+            "ASSET_PURPOSE_AVATAR" => Some(Self::Avatar),
+            "ASSET_PURPOSE_BANNER" => Some(Self::Banner),
+            "ASSET_PURPOSE_SPLASH" => Some(Self::Splash),
+            "ASSET_PURPOSE_LOGO" => Some(Self::Logo),
+            "ASSET_PURPOSE_NEWSLETTER_SPLASH" => Some(Self::NewsletterSplash),
+            "ASSET_PURPOSE_STORY" => Some(Self::Story),
+            _ => None,
+        }
+    }
+}
+/// Mirrors the `AssetRating` enum in `@storiny/shared`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AssetRating {
+    Unspecified = 0,
+    NotRated = 1,
+    SuggestiveNudity = 2,
+    Violence = 3,
+    Sensitive = 4,
+}
+impl AssetRating {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AssetRating::Unspecified => "ASSET_RATING_UNSPECIFIED",
+            AssetRating::NotRated => "ASSET_RATING_NOT_RATED",
+            AssetRating::SuggestiveNudity => "ASSET_RATING_SUGGESTIVE_NUDITY",
+            AssetRating::Violence => "ASSET_RATING_VIOLENCE",
+            AssetRating::Sensitive => "ASSET_RATING_SENSITIVE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ASSET_RATING_UNSPECIFIED" => Some(Self::Unspecified),
+            "ASSET_RATING_NOT_RATED" => Some(Self::NotRated),
+            "ASSET_RATING_SUGGESTIVE_NUDITY" => Some(Self::SuggestiveNudity),
+            "ASSET_RATING_VIOLENCE" => Some(Self::Violence),
+            "ASSET_RATING_SENSITIVE" => Some(Self::Sensitive),
+            _ => None,
+        }
+    }
+}
+// @@protoc_insertion_point(module)
@@ -0,0 +1,846 @@
+// @generated
+impl serde::Serialize for AssetPurpose {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => 0,
+            Self::Avatar => 1,
+            Self::Banner => 2,
+            Self::Splash => 3,
+            Self::Logo => 4,
+            Self::NewsletterSplash => 5,
+            Self::Story => 6,
+        };
+        serializer.serialize_i32(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for AssetPurpose {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ASSET_PURPOSE_UNSPECIFIED",
+            "ASSET_PURPOSE_AVATAR",
+            "ASSET_PURPOSE_BANNER",
+            "ASSET_PURPOSE_SPLASH",
+            "ASSET_PURPOSE_LOGO",
+            "ASSET_PURPOSE_NEWSLETTER_SPLASH",
+            "ASSET_PURPOSE_STORY",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AssetPurpose;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(AssetPurpose::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(AssetPurpose::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "ASSET_PURPOSE_UNSPECIFIED" => Ok(AssetPurpose::Unspecified),
+                    "ASSET_PURPOSE_AVATAR" => Ok(AssetPurpose::Avatar),
+                    "ASSET_PURPOSE_BANNER" => Ok(AssetPurpose::Banner),
+                    "ASSET_PURPOSE_SPLASH" => Ok(AssetPurpose::Splash),
+                    "ASSET_PURPOSE_LOGO" => Ok(AssetPurpose::Logo),
+                    "ASSET_PURPOSE_NEWSLETTER_SPLASH" => Ok(AssetPurpose::NewsletterSplash),
+                    "ASSET_PURPOSE_STORY" => Ok(AssetPurpose::Story),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AssetRating {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => 0,
+            Self::NotRated => 1,
+            Self::SuggestiveNudity => 2,
+            Self::Violence => 3,
+            Self::Sensitive => 4,
+        };
+        serializer.serialize_i32(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for AssetRating {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ASSET_RATING_UNSPECIFIED",
+            "ASSET_RATING_NOT_RATED",
+            "ASSET_RATING_SUGGESTIVE_NUDITY",
+            "ASSET_RATING_VIOLENCE",
+            "ASSET_RATING_SENSITIVE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AssetRating;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(AssetRating::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(AssetRating::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "ASSET_RATING_UNSPECIFIED" => Ok(AssetRating::Unspecified),
+                    "ASSET_RATING_NOT_RATED" => Ok(AssetRating::NotRated),
+                    "ASSET_RATING_SUGGESTIVE_NUDITY" => Ok(AssetRating::SuggestiveNudity),
+                    "ASSET_RATING_VIOLENCE" => Ok(AssetRating::Violence),
+                    "ASSET_RATING_SENSITIVE" => Ok(AssetRating::Sensitive),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SetAssetRatingRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.asset_id.is_empty() {
+            len += 1;
+        }
+        if self.rating != 0 {
+            len += 1;
+        }
+        if !self.moderator_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("asset_def.v1.SetAssetRatingRequest", len)?;
+        if !self.asset_id.is_empty() {
+            struct_ser.serialize_field("assetId", &self.asset_id)?;
+        }
+        if self.rating != 0 {
+            let v = AssetRating::from_i32(self.rating)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.rating)))?;
+            struct_ser.serialize_field("rating", &v)?;
+        }
+        if !self.moderator_id.is_empty() {
+            struct_ser.serialize_field("moderatorId", &self.moderator_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SetAssetRatingRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset_id",
+            "assetId",
+            "rating",
+            "moderator_id",
+            "moderatorId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AssetId,
+            Rating,
+            ModeratorId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "rating" => Ok(GeneratedField::Rating),
+                            "moderatorId" | "moderator_id" => Ok(GeneratedField::ModeratorId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SetAssetRatingRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct asset_def.v1.SetAssetRatingRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<SetAssetRatingRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset_id__ = None;
+                let mut rating__ = None;
+                let mut moderator_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Rating => {
+                            if rating__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rating"));
+                            }
+                            rating__ = Some(map.next_value::<AssetRating>()? as i32);
+                        }
+                        GeneratedField::ModeratorId => {
+                            if moderator_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("moderatorId"));
+                            }
+                            moderator_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(SetAssetRatingRequest {
+                    asset_id: asset_id__.unwrap_or_default(),
+                    rating: rating__.unwrap_or_default(),
+                    moderator_id: moderator_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("asset_def.v1.SetAssetRatingRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SetAssetRatingResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("asset_def.v1.SetAssetRatingResponse", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SetAssetRatingResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SetAssetRatingResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct asset_def.v1.SetAssetRatingResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<SetAssetRatingResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(SetAssetRatingResponse {
+                })
+            }
+        }
+        deserializer.deserialize_struct("asset_def.v1.SetAssetRatingResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UploadImageMetadata {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if self.purpose != 0 {
+            len += 1;
+        }
+        if !self.content_type.is_empty() {
+            len += 1;
+        }
+        if self.size != 0 {
+            len += 1;
+        }
+        if self.alt.is_some() {
+            len += 1;
+        }
+        if self.rating != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("asset_def.v1.UploadImageMetadata", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if self.purpose != 0 {
+            let v = AssetPurpose::from_i32(self.purpose)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.purpose)))?;
+            struct_ser.serialize_field("purpose", &v)?;
+        }
+        if !self.content_type.is_empty() {
+            struct_ser.serialize_field("contentType", &self.content_type)?;
+        }
+        if self.size != 0 {
+            struct_ser.serialize_field("size", &self.size)?;
+        }
+        if let Some(v) = self.alt.as_ref() {
+            struct_ser.serialize_field("alt", v)?;
+        }
+        if self.rating != 0 {
+            let v = AssetRating::from_i32(self.rating)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.rating)))?;
+            struct_ser.serialize_field("rating", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UploadImageMetadata {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "purpose",
+            "content_type",
+            "contentType",
+            "size",
+            "alt",
+            "rating",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            Purpose,
+            ContentType,
+            Size,
+            Alt,
+            Rating,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "purpose" => Ok(GeneratedField::Purpose),
+                            "contentType" | "content_type" => Ok(GeneratedField::ContentType),
+                            "size" => Ok(GeneratedField::Size),
+                            "alt" => Ok(GeneratedField::Alt),
+                            "rating" => Ok(GeneratedField::Rating),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UploadImageMetadata;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct asset_def.v1.UploadImageMetadata")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UploadImageMetadata, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut purpose__ = None;
+                let mut content_type__ = None;
+                let mut size__ = None;
+                let mut alt__ = None;
+                let mut rating__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Purpose => {
+                            if purpose__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("purpose"));
+                            }
+                            purpose__ = Some(map.next_value::<AssetPurpose>()? as i32);
+                        }
+                        GeneratedField::ContentType => {
+                            if content_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("contentType"));
+                            }
+                            content_type__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Size => {
+                            if size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("size"));
+                            }
+                            size__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Alt => {
+                            if alt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("alt"));
+                            }
+                            alt__ = map.next_value()?;
+                        }
+                        GeneratedField::Rating => {
+                            if rating__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rating"));
+                            }
+                            rating__ = Some(map.next_value::<AssetRating>()? as i32);
+                        }
+                    }
+                }
+                Ok(UploadImageMetadata {
+                    user_id: user_id__.unwrap_or_default(),
+                    purpose: purpose__.unwrap_or_default(),
+                    content_type: content_type__.unwrap_or_default(),
+                    size: size__.unwrap_or_default(),
+                    alt: alt__,
+                    rating: rating__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("asset_def.v1.UploadImageMetadata", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UploadImageRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("asset_def.v1.UploadImageRequest", len)?;
+        if let Some(v) = self.data.as_ref() {
+            match v {
+                upload_image_request::Data::Metadata(v) => {
+                    struct_ser.serialize_field("metadata", v)?;
+                }
+                upload_image_request::Data::Chunk(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser.serialize_field("chunk", pbjson::private::base64::encode(&v).as_str())?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UploadImageRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "metadata",
+            "chunk",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Metadata,
+            Chunk,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            "chunk" => Ok(GeneratedField::Chunk),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UploadImageRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct asset_def.v1.UploadImageRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UploadImageRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut data__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Metadata => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            data__ = map.next_value::<::std::option::Option<_>>()?.map(upload_image_request::Data::Metadata)
+;
+                        }
+                        GeneratedField::Chunk => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chunk"));
+                            }
+                            data__ = map.next_value::<::std::option::Option<::pbjson::private::BytesDeserialize<_>>>()?.map(|x| upload_image_request::Data::Chunk(x.0));
+                        }
+                    }
+                }
+                Ok(UploadImageRequest {
+                    data: data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("asset_def.v1.UploadImageRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UploadImageResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if !self.hex.is_empty() {
+            len += 1;
+        }
+        if !self.blurhash.is_empty() {
+            len += 1;
+        }
+        if self.width != 0 {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.rating != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("asset_def.v1.UploadImageResponse", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if !self.hex.is_empty() {
+            struct_ser.serialize_field("hex", &self.hex)?;
+        }
+        if !self.blurhash.is_empty() {
+            struct_ser.serialize_field("blurhash", &self.blurhash)?;
+        }
+        if self.width != 0 {
+            struct_ser.serialize_field("width", &self.width)?;
+        }
+        if self.height != 0 {
+            struct_ser.serialize_field("height", &self.height)?;
+        }
+        if self.rating != 0 {
+            let v = AssetRating::from_i32(self.rating)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.rating)))?;
+            struct_ser.serialize_field("rating", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UploadImageResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "hex",
+            "blurhash",
+            "width",
+            "height",
+            "rating",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Hex,
+            Blurhash,
+            Width,
+            Height,
+            Rating,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "hex" => Ok(GeneratedField::Hex),
+                            "blurhash" => Ok(GeneratedField::Blurhash),
+                            "width" => Ok(GeneratedField::Width),
+                            "height" => Ok(GeneratedField::Height),
+                            "rating" => Ok(GeneratedField::Rating),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UploadImageResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct asset_def.v1.UploadImageResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UploadImageResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut hex__ = None;
+                let mut blurhash__ = None;
+                let mut width__ = None;
+                let mut height__ = None;
+                let mut rating__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Hex => {
+                            if hex__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hex"));
+                            }
+                            hex__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Blurhash => {
+                            if blurhash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blurhash"));
+                            }
+                            blurhash__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Width => {
+                            if width__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("width"));
+                            }
+                            width__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Rating => {
+                            if rating__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rating"));
+                            }
+                            rating__ = Some(map.next_value::<AssetRating>()? as i32);
+                        }
+                    }
+                }
+                Ok(UploadImageResponse {
+                    id: id__.unwrap_or_default(),
+                    hex: hex__.unwrap_or_default(),
+                    blurhash: blurhash__.unwrap_or_default(),
+                    width: width__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                    rating: rating__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("asset_def.v1.UploadImageResponse", FIELDS, GeneratedVisitor)
+    }
+}
@@ -144,6 +144,7 @@ pub struct GetBlogResponse {
     /// Other props
     #[prost(string, optional, tag="39")]
     pub domain: ::core::option::Option<::prost::alloc::string::String>,
+    #[deprecated]
     #[prost(string, tag="40")]
     pub created_at: ::prost::alloc::string::String,
     #[prost(string, tag="41")]
@@ -156,6 +157,9 @@ pub struct GetBlogResponse {
     pub lsb_items: ::prost::alloc::vec::Vec<LeftSidebarItem>,
     #[prost(message, repeated, tag="45")]
     pub rsb_items: ::prost::alloc::vec::Vec<RightSidebarItem>,
+    /// Timestamps
+    #[prost(message, optional, tag="46")]
+    pub created_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 // Blog archive request
 
@@ -337,4 +341,25 @@ pub struct VerifyBlogLoginResponse {
     #[prost(bool, optional, tag="3")]
     pub is_persistent_cookie: ::core::option::Option<bool>,
 }
+// Export blog request
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportBlogRequest {
+    #[prost(string, tag="1")]
+    pub identifier: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub current_user_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// EPUB anthology of the published stories, ordered by `published_at`
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportBlogResponse {
+    #[prost(string, tag="1")]
+    pub filename: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub content_type: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="3")]
+    pub content: ::prost::alloc::vec::Vec<u8>,
+}
 // @@protoc_insertion_point(module)
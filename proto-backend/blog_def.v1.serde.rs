@@ -291,6 +291,244 @@ impl<'de> serde::Deserialize<'de> for BareBlog {
         deserializer.deserialize_struct("blog_def.v1.BareBlog", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ExportBlogRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.identifier.is_empty() {
+            len += 1;
+        }
+        if self.current_user_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("blog_def.v1.ExportBlogRequest", len)?;
+        if !self.identifier.is_empty() {
+            struct_ser.serialize_field("identifier", &self.identifier)?;
+        }
+        if let Some(v) = self.current_user_id.as_ref() {
+            struct_ser.serialize_field("currentUserId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExportBlogRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identifier",
+            "current_user_id",
+            "currentUserId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Identifier,
+            CurrentUserId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identifier" => Ok(GeneratedField::Identifier),
+                            "currentUserId" | "current_user_id" => Ok(GeneratedField::CurrentUserId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExportBlogRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct blog_def.v1.ExportBlogRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ExportBlogRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identifier__ = None;
+                let mut current_user_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Identifier => {
+                            if identifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identifier"));
+                            }
+                            identifier__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::CurrentUserId => {
+                            if current_user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentUserId"));
+                            }
+                            current_user_id__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ExportBlogRequest {
+                    identifier: identifier__.unwrap_or_default(),
+                    current_user_id: current_user_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("blog_def.v1.ExportBlogRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ExportBlogResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.filename.is_empty() {
+            len += 1;
+        }
+        if !self.content_type.is_empty() {
+            len += 1;
+        }
+        if !self.content.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("blog_def.v1.ExportBlogResponse", len)?;
+        if !self.filename.is_empty() {
+            struct_ser.serialize_field("filename", &self.filename)?;
+        }
+        if !self.content_type.is_empty() {
+            struct_ser.serialize_field("contentType", &self.content_type)?;
+        }
+        if !self.content.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("content", pbjson::private::base64::encode(&self.content).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExportBlogResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "filename",
+            "content_type",
+            "contentType",
+            "content",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Filename,
+            ContentType,
+            Content,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "filename" => Ok(GeneratedField::Filename),
+                            "contentType" | "content_type" => Ok(GeneratedField::ContentType),
+                            "content" => Ok(GeneratedField::Content),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExportBlogResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct blog_def.v1.ExportBlogResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ExportBlogResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut filename__ = None;
+                let mut content_type__ = None;
+                let mut content__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Filename => {
+                            if filename__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("filename"));
+                            }
+                            filename__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ContentType => {
+                            if content_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("contentType"));
+                            }
+                            content_type__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Content => {
+                            if content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("content"));
+                            }
+                            content__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ExportBlogResponse {
+                    filename: filename__.unwrap_or_default(),
+                    content_type: content_type__.unwrap_or_default(),
+                    content: content__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("blog_def.v1.ExportBlogResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for GetBlogArchiveRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1810,6 +2048,9 @@ impl serde::Serialize for GetBlogResponse {
         if !self.rsb_items.is_empty() {
             len += 1;
         }
+        if self.created_at_ts.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("blog_def.v1.GetBlogResponse", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
@@ -1946,6 +2187,9 @@ impl serde::Serialize for GetBlogResponse {
         if !self.rsb_items.is_empty() {
             struct_ser.serialize_field("rsbItems", &self.rsb_items)?;
         }
+        if let Some(v) = self.created_at_ts.as_ref() {
+            struct_ser.serialize_field("createdAtTs", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -2039,6 +2283,8 @@ impl<'de> serde::Deserialize<'de> for GetBlogResponse {
             "lsbItems",
             "rsb_items",
             "rsbItems",
+            "created_at_ts",
+            "createdAtTs",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -2088,6 +2334,7 @@ impl<'de> serde::Deserialize<'de> for GetBlogResponse {
             RsbItemsLabel,
             LsbItems,
             RsbItems,
+            CreatedAtTs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2154,6 +2401,7 @@ impl<'de> serde::Deserialize<'de> for GetBlogResponse {
                             "rsbItemsLabel" | "rsb_items_label" => Ok(GeneratedField::RsbItemsLabel),
                             "lsbItems" | "lsb_items" => Ok(GeneratedField::LsbItems),
                             "rsbItems" | "rsb_items" => Ok(GeneratedField::RsbItems),
+                            "createdAtTs" | "created_at_ts" => Ok(GeneratedField::CreatedAtTs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2218,6 +2466,7 @@ impl<'de> serde::Deserialize<'de> for GetBlogResponse {
                 let mut rsb_items_label__ = None;
                 let mut lsb_items__ = None;
                 let mut rsb_items__ = None;
+                let mut created_at_ts__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -2490,6 +2739,12 @@ impl<'de> serde::Deserialize<'de> for GetBlogResponse {
                             }
                             rsb_items__ = Some(map.next_value()?);
                         }
+                        GeneratedField::CreatedAtTs => {
+                            if created_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAtTs"));
+                            }
+                            created_at_ts__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(GetBlogResponse {
@@ -2538,6 +2793,7 @@ impl<'de> serde::Deserialize<'de> for GetBlogResponse {
                     rsb_items_label: rsb_items_label__.unwrap_or_default(),
                     lsb_items: lsb_items__.unwrap_or_default(),
                     rsb_items: rsb_items__.unwrap_or_default(),
+                    created_at_ts: created_at_ts__,
                 })
             }
         }
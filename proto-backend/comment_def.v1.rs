@@ -26,8 +26,10 @@ pub struct GetCommentResponse {
     pub story_writer_username: ::prost::alloc::string::String,
     #[prost(bool, tag="8")]
     pub hidden: bool,
+    #[deprecated]
     #[prost(string, optional, tag="9")]
     pub edited_at: ::core::option::Option<::prost::alloc::string::String>,
+    #[deprecated]
     #[prost(string, tag="10")]
     pub created_at: ::prost::alloc::string::String,
     #[prost(uint32, tag="11")]
@@ -39,5 +41,17 @@ pub struct GetCommentResponse {
     /// User specific props
     #[prost(bool, tag="14")]
     pub is_liked: bool,
+    /// Moderation
+    #[deprecated]
+    #[prost(string, optional, tag="15")]
+    pub deleted_at: ::core::option::Option<::prost::alloc::string::String>,
+    /// Timestamps
+    #[prost(message, optional, tag="16")]
+    pub edited_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(message, optional, tag="17")]
+    pub created_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// Set when the comment has been taken down
+    #[prost(message, optional, tag="18")]
+    pub deleted_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 // @@protoc_insertion_point(module)
@@ -158,6 +158,18 @@ impl serde::Serialize for GetCommentResponse {
         if self.is_liked {
             len += 1;
         }
+        if self.deleted_at.is_some() {
+            len += 1;
+        }
+        if self.edited_at_ts.is_some() {
+            len += 1;
+        }
+        if self.created_at_ts.is_some() {
+            len += 1;
+        }
+        if self.deleted_at_ts.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("comment_def.v1.GetCommentResponse", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
@@ -201,6 +213,18 @@ impl serde::Serialize for GetCommentResponse {
         if self.is_liked {
             struct_ser.serialize_field("isLiked", &self.is_liked)?;
         }
+        if let Some(v) = self.deleted_at.as_ref() {
+            struct_ser.serialize_field("deletedAt", v)?;
+        }
+        if let Some(v) = self.edited_at_ts.as_ref() {
+            struct_ser.serialize_field("editedAtTs", v)?;
+        }
+        if let Some(v) = self.created_at_ts.as_ref() {
+            struct_ser.serialize_field("createdAtTs", v)?;
+        }
+        if let Some(v) = self.deleted_at_ts.as_ref() {
+            struct_ser.serialize_field("deletedAtTs", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -235,6 +259,14 @@ impl<'de> serde::Deserialize<'de> for GetCommentResponse {
             "user",
             "is_liked",
             "isLiked",
+            "deleted_at",
+            "deletedAt",
+            "edited_at_ts",
+            "editedAtTs",
+            "created_at_ts",
+            "createdAtTs",
+            "deleted_at_ts",
+            "deletedAtTs",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -253,6 +285,10 @@ impl<'de> serde::Deserialize<'de> for GetCommentResponse {
             ReplyCount,
             User,
             IsLiked,
+            DeletedAt,
+            EditedAtTs,
+            CreatedAtTs,
+            DeletedAtTs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -288,6 +324,10 @@ impl<'de> serde::Deserialize<'de> for GetCommentResponse {
                             "replyCount" | "reply_count" => Ok(GeneratedField::ReplyCount),
                             "user" => Ok(GeneratedField::User),
                             "isLiked" | "is_liked" => Ok(GeneratedField::IsLiked),
+                            "deletedAt" | "deleted_at" => Ok(GeneratedField::DeletedAt),
+                            "editedAtTs" | "edited_at_ts" => Ok(GeneratedField::EditedAtTs),
+                            "createdAtTs" | "created_at_ts" => Ok(GeneratedField::CreatedAtTs),
+                            "deletedAtTs" | "deleted_at_ts" => Ok(GeneratedField::DeletedAtTs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -321,6 +361,10 @@ impl<'de> serde::Deserialize<'de> for GetCommentResponse {
                 let mut reply_count__ = None;
                 let mut user__ = None;
                 let mut is_liked__ = None;
+                let mut deleted_at__ = None;
+                let mut edited_at_ts__ = None;
+                let mut created_at_ts__ = None;
+                let mut deleted_at_ts__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -411,6 +455,30 @@ impl<'de> serde::Deserialize<'de> for GetCommentResponse {
                             }
                             is_liked__ = Some(map.next_value()?);
                         }
+                        GeneratedField::DeletedAt => {
+                            if deleted_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deletedAt"));
+                            }
+                            deleted_at__ = map.next_value()?;
+                        }
+                        GeneratedField::EditedAtTs => {
+                            if edited_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("editedAtTs"));
+                            }
+                            edited_at_ts__ = map.next_value()?;
+                        }
+                        GeneratedField::CreatedAtTs => {
+                            if created_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAtTs"));
+                            }
+                            created_at_ts__ = map.next_value()?;
+                        }
+                        GeneratedField::DeletedAtTs => {
+                            if deleted_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deletedAtTs"));
+                            }
+                            deleted_at_ts__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(GetCommentResponse {
@@ -428,6 +496,10 @@ impl<'de> serde::Deserialize<'de> for GetCommentResponse {
                     reply_count: reply_count__.unwrap_or_default(),
                     user: user__,
                     is_liked: is_liked__.unwrap_or_default(),
+                    deleted_at: deleted_at__,
+                    edited_at_ts: edited_at_ts__,
+                    created_at_ts: created_at_ts__,
+                    deleted_at_ts: deleted_at_ts__,
                 })
             }
         }
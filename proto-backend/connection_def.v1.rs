@@ -1,19 +1,28 @@
 // @generated
+// Public connection (hidden connections are never included)
+
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Connection {
+    /// Slug of the provider, e.g. `github`
+    #[deprecated]
     #[prost(string, tag="1")]
     pub provider: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
     pub url: ::prost::alloc::string::String,
     #[prost(string, tag="3")]
     pub display_name: ::prost::alloc::string::String,
+    /// Replaces `provider`
+    #[prost(enumeration="ConnectionProvider", tag="4")]
+    pub provider_type: i32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConnectionSetting {
     #[prost(string, tag="1")]
     pub id: ::prost::alloc::string::String,
+    /// Slug of the provider, e.g. `github`
+    #[deprecated]
     #[prost(string, tag="2")]
     pub provider: ::prost::alloc::string::String,
     #[prost(bool, tag="3")]
@@ -22,7 +31,67 @@ pub struct ConnectionSetting {
     pub display_name: ::prost::alloc::string::String,
     #[prost(string, tag="5")]
     pub url: ::prost::alloc::string::String,
+    #[deprecated]
     #[prost(string, tag="6")]
     pub created_at: ::prost::alloc::string::String,
+    /// Replaces `provider`
+    #[prost(enumeration="ConnectionProvider", tag="8")]
+    pub provider_type: i32,
+    /// Timestamps
+    #[prost(message, optional, tag="7")]
+    pub created_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ConnectionProvider {
+    Unspecified = 0,
+    Discord = 1,
+    Dribbble = 2,
+    Figma = 3,
+    Github = 4,
+    Linkedin = 5,
+    Reddit = 6,
+    Snapchat = 7,
+    Spotify = 8,
+    Twitch = 9,
+    Youtube = 10,
+}
+impl ConnectionProvider {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ConnectionProvider::Unspecified => "CONNECTION_PROVIDER_UNSPECIFIED",
+            ConnectionProvider::Discord => "CONNECTION_PROVIDER_DISCORD",
+            ConnectionProvider::Dribbble => "CONNECTION_PROVIDER_DRIBBBLE",
+            ConnectionProvider::Figma => "CONNECTION_PROVIDER_FIGMA",
+            ConnectionProvider::Github => "CONNECTION_PROVIDER_GITHUB",
+            ConnectionProvider::Linkedin => "CONNECTION_PROVIDER_LINKEDIN",
+            ConnectionProvider::Reddit => "CONNECTION_PROVIDER_REDDIT",
+            ConnectionProvider::Snapchat => "CONNECTION_PROVIDER_SNAPCHAT",
+            ConnectionProvider::Spotify => "CONNECTION_PROVIDER_SPOTIFY",
+            ConnectionProvider::Twitch => "CONNECTION_PROVIDER_TWITCH",
+            ConnectionProvider::Youtube => "CONNECTION_PROVIDER_YOUTUBE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CONNECTION_PROVIDER_UNSPECIFIED" => Some(Self::Unspecified),
+            "CONNECTION_PROVIDER_DISCORD" => Some(Self::Discord),
+            "CONNECTION_PROVIDER_DRIBBBLE" => Some(Self::Dribbble),
+            "CONNECTION_PROVIDER_FIGMA" => Some(Self::Figma),
+            "CONNECTION_PROVIDER_GITHUB" => Some(Self::Github),
+            "CONNECTION_PROVIDER_LINKEDIN" => Some(Self::Linkedin),
+            "CONNECTION_PROVIDER_REDDIT" => Some(Self::Reddit),
+            "CONNECTION_PROVIDER_SNAPCHAT" => Some(Self::Snapchat),
+            "CONNECTION_PROVIDER_SPOTIFY" => Some(Self::Spotify),
+            "CONNECTION_PROVIDER_TWITCH" => Some(Self::Twitch),
+            "CONNECTION_PROVIDER_YOUTUBE" => Some(Self::Youtube),
+            _ => None,
+        }
+    }
 }
 // @@protoc_insertion_point(module)
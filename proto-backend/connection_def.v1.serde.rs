@@ -16,6 +16,9 @@ impl serde::Serialize for Connection {
         if !self.display_name.is_empty() {
             len += 1;
         }
+        if self.provider_type != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("connection_def.v1.Connection", len)?;
         if !self.provider.is_empty() {
             struct_ser.serialize_field("provider", &self.provider)?;
@@ -26,6 +29,11 @@ impl serde::Serialize for Connection {
         if !self.display_name.is_empty() {
             struct_ser.serialize_field("displayName", &self.display_name)?;
         }
+        if self.provider_type != 0 {
+            let v = ConnectionProvider::from_i32(self.provider_type)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.provider_type)))?;
+            struct_ser.serialize_field("providerType", &v)?;
+        }
         struct_ser.end()
     }
 }
@@ -40,6 +48,8 @@ impl<'de> serde::Deserialize<'de> for Connection {
             "url",
             "display_name",
             "displayName",
+            "provider_type",
+            "providerType",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -47,6 +57,7 @@ impl<'de> serde::Deserialize<'de> for Connection {
             Provider,
             Url,
             DisplayName,
+            ProviderType,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -71,6 +82,7 @@ impl<'de> serde::Deserialize<'de> for Connection {
                             "provider" => Ok(GeneratedField::Provider),
                             "url" => Ok(GeneratedField::Url),
                             "displayName" | "display_name" => Ok(GeneratedField::DisplayName),
+                            "providerType" | "provider_type" => Ok(GeneratedField::ProviderType),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -93,6 +105,7 @@ impl<'de> serde::Deserialize<'de> for Connection {
                 let mut provider__ = None;
                 let mut url__ = None;
                 let mut display_name__ = None;
+                let mut provider_type__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Provider => {
@@ -113,18 +126,125 @@ impl<'de> serde::Deserialize<'de> for Connection {
                             }
                             display_name__ = Some(map.next_value()?);
                         }
+                        GeneratedField::ProviderType => {
+                            if provider_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerType"));
+                            }
+                            provider_type__ = Some(map.next_value::<ConnectionProvider>()? as i32);
+                        }
                     }
                 }
                 Ok(Connection {
                     provider: provider__.unwrap_or_default(),
                     url: url__.unwrap_or_default(),
                     display_name: display_name__.unwrap_or_default(),
+                    provider_type: provider_type__.unwrap_or_default(),
                 })
             }
         }
         deserializer.deserialize_struct("connection_def.v1.Connection", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ConnectionProvider {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => 0,
+            Self::Discord => 1,
+            Self::Dribbble => 2,
+            Self::Figma => 3,
+            Self::Github => 4,
+            Self::Linkedin => 5,
+            Self::Reddit => 6,
+            Self::Snapchat => 7,
+            Self::Spotify => 8,
+            Self::Twitch => 9,
+            Self::Youtube => 10,
+        };
+        serializer.serialize_i32(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConnectionProvider {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "CONNECTION_PROVIDER_UNSPECIFIED",
+            "CONNECTION_PROVIDER_DISCORD",
+            "CONNECTION_PROVIDER_DRIBBBLE",
+            "CONNECTION_PROVIDER_FIGMA",
+            "CONNECTION_PROVIDER_GITHUB",
+            "CONNECTION_PROVIDER_LINKEDIN",
+            "CONNECTION_PROVIDER_REDDIT",
+            "CONNECTION_PROVIDER_SNAPCHAT",
+            "CONNECTION_PROVIDER_SPOTIFY",
+            "CONNECTION_PROVIDER_TWITCH",
+            "CONNECTION_PROVIDER_YOUTUBE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConnectionProvider;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(ConnectionProvider::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(ConnectionProvider::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "CONNECTION_PROVIDER_UNSPECIFIED" => Ok(ConnectionProvider::Unspecified),
+                    "CONNECTION_PROVIDER_DISCORD" => Ok(ConnectionProvider::Discord),
+                    "CONNECTION_PROVIDER_DRIBBBLE" => Ok(ConnectionProvider::Dribbble),
+                    "CONNECTION_PROVIDER_FIGMA" => Ok(ConnectionProvider::Figma),
+                    "CONNECTION_PROVIDER_GITHUB" => Ok(ConnectionProvider::Github),
+                    "CONNECTION_PROVIDER_LINKEDIN" => Ok(ConnectionProvider::Linkedin),
+                    "CONNECTION_PROVIDER_REDDIT" => Ok(ConnectionProvider::Reddit),
+                    "CONNECTION_PROVIDER_SNAPCHAT" => Ok(ConnectionProvider::Snapchat),
+                    "CONNECTION_PROVIDER_SPOTIFY" => Ok(ConnectionProvider::Spotify),
+                    "CONNECTION_PROVIDER_TWITCH" => Ok(ConnectionProvider::Twitch),
+                    "CONNECTION_PROVIDER_YOUTUBE" => Ok(ConnectionProvider::Youtube),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ConnectionSetting {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -151,6 +271,12 @@ impl serde::Serialize for ConnectionSetting {
         if !self.created_at.is_empty() {
             len += 1;
         }
+        if self.provider_type != 0 {
+            len += 1;
+        }
+        if self.created_at_ts.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("connection_def.v1.ConnectionSetting", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
@@ -170,6 +296,14 @@ impl serde::Serialize for ConnectionSetting {
         if !self.created_at.is_empty() {
             struct_ser.serialize_field("createdAt", &self.created_at)?;
         }
+        if self.provider_type != 0 {
+            let v = ConnectionProvider::from_i32(self.provider_type)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.provider_type)))?;
+            struct_ser.serialize_field("providerType", &v)?;
+        }
+        if let Some(v) = self.created_at_ts.as_ref() {
+            struct_ser.serialize_field("createdAtTs", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -188,6 +322,10 @@ impl<'de> serde::Deserialize<'de> for ConnectionSetting {
             "url",
             "created_at",
             "createdAt",
+            "provider_type",
+            "providerType",
+            "created_at_ts",
+            "createdAtTs",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -198,6 +336,8 @@ impl<'de> serde::Deserialize<'de> for ConnectionSetting {
             DisplayName,
             Url,
             CreatedAt,
+            ProviderType,
+            CreatedAtTs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -225,6 +365,8 @@ impl<'de> serde::Deserialize<'de> for ConnectionSetting {
                             "displayName" | "display_name" => Ok(GeneratedField::DisplayName),
                             "url" => Ok(GeneratedField::Url),
                             "createdAt" | "created_at" => Ok(GeneratedField::CreatedAt),
+                            "providerType" | "provider_type" => Ok(GeneratedField::ProviderType),
+                            "createdAtTs" | "created_at_ts" => Ok(GeneratedField::CreatedAtTs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -250,6 +392,8 @@ impl<'de> serde::Deserialize<'de> for ConnectionSetting {
                 let mut display_name__ = None;
                 let mut url__ = None;
                 let mut created_at__ = None;
+                let mut provider_type__ = None;
+                let mut created_at_ts__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -288,6 +432,18 @@ impl<'de> serde::Deserialize<'de> for ConnectionSetting {
                             }
                             created_at__ = Some(map.next_value()?);
                         }
+                        GeneratedField::ProviderType => {
+                            if provider_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerType"));
+                            }
+                            provider_type__ = Some(map.next_value::<ConnectionProvider>()? as i32);
+                        }
+                        GeneratedField::CreatedAtTs => {
+                            if created_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAtTs"));
+                            }
+                            created_at_ts__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(ConnectionSetting {
@@ -297,6 +453,8 @@ impl<'de> serde::Deserialize<'de> for ConnectionSetting {
                     display_name: display_name__.unwrap_or_default(),
                     url: url__.unwrap_or_default(),
                     created_at: created_at__.unwrap_or_default(),
+                    provider_type: provider_type__.unwrap_or_default(),
+                    created_at_ts: created_at_ts__,
                 })
             }
         }
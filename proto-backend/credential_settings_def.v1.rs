@@ -1,6 +1,31 @@
 // @generated
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LinkedLoginProvider {
+    #[prost(enumeration="LoginProvider", tag="1")]
+    pub provider: i32,
+    /// Subject identifier issued by the provider
+    #[prost(string, tag="2")]
+    pub provider_user_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub email: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag="4")]
+    pub created_at: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Passkey {
+    #[prost(string, tag="1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="3")]
+    pub last_used_at: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(message, optional, tag="4")]
+    pub created_at: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetCredentialSettingsRequest {
     #[prost(string, tag="1")]
     pub user_id: ::prost::alloc::string::String,
@@ -12,9 +37,273 @@ pub struct GetCredentialSettingsResponse {
     pub has_password: bool,
     #[prost(bool, tag="2")]
     pub mfa_enabled: bool,
+    /// Use `login_providers`
+    #[deprecated]
     #[prost(string, optional, tag="3")]
     pub login_apple_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Use `login_providers`
+    #[deprecated]
     #[prost(string, optional, tag="4")]
     pub login_google_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag="5")]
+    pub passkeys: ::prost::alloc::vec::Vec<Passkey>,
+    #[prost(message, repeated, tag="6")]
+    pub login_providers: ::prost::alloc::vec::Vec<LinkedLoginProvider>,
+}
+// Multi-factor authentication
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BeginMfaEnrollmentRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BeginMfaEnrollmentResponse {
+    /// Base32 encoded TOTP secret
+    #[prost(string, tag="1")]
+    pub secret: ::prost::alloc::string::String,
+    /// `otpauth://` URI for the QR code
+    #[prost(string, tag="2")]
+    pub otpauth_uri: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConfirmMfaEnrollmentRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    /// Code generated using the secret from `BeginMfaEnrollment`
+    #[prost(string, tag="2")]
+    pub code: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConfirmMfaEnrollmentResponse {
+    #[prost(string, repeated, tag="1")]
+    pub recovery_codes: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// Issued only after the first factor of the login has been verified. The
+/// challenge expires, allows a limited number of attempts, and is consumed by
+/// the first valid code.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BeginMfaChallengeRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BeginMfaChallengeResponse {
+    /// Opaque token binding the second factor to the login
+    #[prost(string, tag="1")]
+    pub challenge_token: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub expires_at: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(uint32, tag="3")]
+    pub max_attempts: u32,
+}
+/// The user is identified by the challenge. A TOTP code is rejected if its time
+/// step is not after the last one used by the user (RFC 6238, Section 5.2).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VerifyMfaCodeRequest {
+    /// TOTP code or an unused recovery code
+    #[prost(string, tag="2")]
+    pub code: ::prost::alloc::string::String,
+    /// From `BeginMfaChallenge`
+    #[prost(string, tag="3")]
+    pub challenge_token: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VerifyMfaCodeResponse {
+    #[prost(bool, tag="1")]
+    pub is_valid: bool,
+    /// Recovery codes can only be used once
+    #[prost(bool, tag="2")]
+    pub used_recovery_code: bool,
+    /// Set when the code is valid
+    #[prost(string, optional, tag="3")]
+    pub user_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// The challenge is invalidated once exhausted
+    #[prost(uint32, tag="4")]
+    pub remaining_attempts: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DisableMfaRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    /// TOTP code or an unused recovery code
+    #[prost(string, tag="2")]
+    pub code: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DisableMfaResponse {
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RegenerateMfaRecoveryCodesRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    /// TOTP code
+    #[prost(string, tag="2")]
+    pub code: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RegenerateMfaRecoveryCodesResponse {
+    /// Replaces all the previous recovery codes
+    #[prost(string, repeated, tag="1")]
+    pub recovery_codes: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+// Passkeys
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BeginPasskeyRegistrationRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BeginPasskeyRegistrationResponse {
+    #[prost(string, tag="1")]
+    pub challenge_id: ::prost::alloc::string::String,
+    /// JSON encoded `PublicKeyCredentialCreationOptions`
+    #[prost(string, tag="2")]
+    pub options: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FinishPasskeyRegistrationRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub challenge_id: ::prost::alloc::string::String,
+    /// Display name of the authenticator
+    #[prost(string, tag="3")]
+    pub name: ::prost::alloc::string::String,
+    /// JSON encoded `RegistrationResponseJSON` from the client
+    #[prost(string, tag="4")]
+    pub credential: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FinishPasskeyRegistrationResponse {
+    #[prost(message, optional, tag="1")]
+    pub passkey: ::core::option::Option<Passkey>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BeginPasskeyAuthenticationRequest {
+    /// Omitted for discoverable credentials
+    #[prost(string, optional, tag="1")]
+    pub user_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BeginPasskeyAuthenticationResponse {
+    #[prost(string, tag="1")]
+    pub challenge_id: ::prost::alloc::string::String,
+    /// JSON encoded `PublicKeyCredentialRequestOptions`
+    #[prost(string, tag="2")]
+    pub options: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FinishPasskeyAuthenticationRequest {
+    #[prost(string, tag="1")]
+    pub challenge_id: ::prost::alloc::string::String,
+    /// JSON encoded `AuthenticationResponseJSON` from the client
+    #[prost(string, tag="2")]
+    pub credential: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FinishPasskeyAuthenticationResponse {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub passkey_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemovePasskeyRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub passkey_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemovePasskeyResponse {
+}
+// Login providers
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LinkLoginProviderRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(enumeration="LoginProvider", tag="2")]
+    pub provider: i32,
+    /// Authorization code from the OAuth callback
+    #[prost(string, tag="3")]
+    pub code: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub redirect_uri: ::prost::alloc::string::String,
+    /// Nonce sent with the authorization request, required
+    #[prost(string, tag="5")]
+    pub nonce: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LinkLoginProviderResponse {
+    #[prost(message, optional, tag="1")]
+    pub login_provider: ::core::option::Option<LinkedLoginProvider>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnlinkLoginProviderRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(enumeration="LoginProvider", tag="2")]
+    pub provider: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnlinkLoginProviderResponse {
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum LoginProvider {
+    Unspecified = 0,
+    Apple = 1,
+    Google = 2,
+}
+impl LoginProvider {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            LoginProvider::Unspecified => "LOGIN_PROVIDER_UNSPECIFIED",
+            LoginProvider::Apple => "LOGIN_PROVIDER_APPLE",
+            LoginProvider::Google => "LOGIN_PROVIDER_GOOGLE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LOGIN_PROVIDER_UNSPECIFIED" => Some(Self::Unspecified),
+            "LOGIN_PROVIDER_APPLE" => Some(Self::Apple),
+            "LOGIN_PROVIDER_GOOGLE" => Some(Self::Google),
+            _ => None,
+        }
+    }
 }
 // @@protoc_insertion_point(module)
@@ -1,4 +1,1664 @@
 // @generated
+impl serde::Serialize for BeginMfaChallengeRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.BeginMfaChallengeRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BeginMfaChallengeRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BeginMfaChallengeRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.BeginMfaChallengeRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<BeginMfaChallengeRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(BeginMfaChallengeRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.BeginMfaChallengeRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BeginMfaChallengeResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.challenge_token.is_empty() {
+            len += 1;
+        }
+        if self.expires_at.is_some() {
+            len += 1;
+        }
+        if self.max_attempts != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.BeginMfaChallengeResponse", len)?;
+        if !self.challenge_token.is_empty() {
+            struct_ser.serialize_field("challengeToken", &self.challenge_token)?;
+        }
+        if let Some(v) = self.expires_at.as_ref() {
+            struct_ser.serialize_field("expiresAt", v)?;
+        }
+        if self.max_attempts != 0 {
+            struct_ser.serialize_field("maxAttempts", &self.max_attempts)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BeginMfaChallengeResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "challenge_token",
+            "challengeToken",
+            "expires_at",
+            "expiresAt",
+            "max_attempts",
+            "maxAttempts",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChallengeToken,
+            ExpiresAt,
+            MaxAttempts,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "challengeToken" | "challenge_token" => Ok(GeneratedField::ChallengeToken),
+                            "expiresAt" | "expires_at" => Ok(GeneratedField::ExpiresAt),
+                            "maxAttempts" | "max_attempts" => Ok(GeneratedField::MaxAttempts),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BeginMfaChallengeResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.BeginMfaChallengeResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<BeginMfaChallengeResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut challenge_token__ = None;
+                let mut expires_at__ = None;
+                let mut max_attempts__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChallengeToken => {
+                            if challenge_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("challengeToken"));
+                            }
+                            challenge_token__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ExpiresAt => {
+                            if expires_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiresAt"));
+                            }
+                            expires_at__ = map.next_value()?;
+                        }
+                        GeneratedField::MaxAttempts => {
+                            if max_attempts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxAttempts"));
+                            }
+                            max_attempts__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(BeginMfaChallengeResponse {
+                    challenge_token: challenge_token__.unwrap_or_default(),
+                    expires_at: expires_at__,
+                    max_attempts: max_attempts__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.BeginMfaChallengeResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BeginMfaEnrollmentRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.BeginMfaEnrollmentRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BeginMfaEnrollmentRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BeginMfaEnrollmentRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.BeginMfaEnrollmentRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<BeginMfaEnrollmentRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(BeginMfaEnrollmentRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.BeginMfaEnrollmentRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BeginMfaEnrollmentResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.secret.is_empty() {
+            len += 1;
+        }
+        if !self.otpauth_uri.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.BeginMfaEnrollmentResponse", len)?;
+        if !self.secret.is_empty() {
+            struct_ser.serialize_field("secret", &self.secret)?;
+        }
+        if !self.otpauth_uri.is_empty() {
+            struct_ser.serialize_field("otpauthUri", &self.otpauth_uri)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BeginMfaEnrollmentResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "secret",
+            "otpauth_uri",
+            "otpauthUri",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Secret,
+            OtpauthUri,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "secret" => Ok(GeneratedField::Secret),
+                            "otpauthUri" | "otpauth_uri" => Ok(GeneratedField::OtpauthUri),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BeginMfaEnrollmentResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.BeginMfaEnrollmentResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<BeginMfaEnrollmentResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut secret__ = None;
+                let mut otpauth_uri__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Secret => {
+                            if secret__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("secret"));
+                            }
+                            secret__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::OtpauthUri => {
+                            if otpauth_uri__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("otpauthUri"));
+                            }
+                            otpauth_uri__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(BeginMfaEnrollmentResponse {
+                    secret: secret__.unwrap_or_default(),
+                    otpauth_uri: otpauth_uri__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.BeginMfaEnrollmentResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BeginPasskeyAuthenticationRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.user_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.BeginPasskeyAuthenticationRequest", len)?;
+        if let Some(v) = self.user_id.as_ref() {
+            struct_ser.serialize_field("userId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BeginPasskeyAuthenticationRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BeginPasskeyAuthenticationRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.BeginPasskeyAuthenticationRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<BeginPasskeyAuthenticationRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(BeginPasskeyAuthenticationRequest {
+                    user_id: user_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.BeginPasskeyAuthenticationRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BeginPasskeyAuthenticationResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.challenge_id.is_empty() {
+            len += 1;
+        }
+        if !self.options.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.BeginPasskeyAuthenticationResponse", len)?;
+        if !self.challenge_id.is_empty() {
+            struct_ser.serialize_field("challengeId", &self.challenge_id)?;
+        }
+        if !self.options.is_empty() {
+            struct_ser.serialize_field("options", &self.options)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BeginPasskeyAuthenticationResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "challenge_id",
+            "challengeId",
+            "options",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChallengeId,
+            Options,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "challengeId" | "challenge_id" => Ok(GeneratedField::ChallengeId),
+                            "options" => Ok(GeneratedField::Options),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BeginPasskeyAuthenticationResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.BeginPasskeyAuthenticationResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<BeginPasskeyAuthenticationResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut challenge_id__ = None;
+                let mut options__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChallengeId => {
+                            if challenge_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("challengeId"));
+                            }
+                            challenge_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Options => {
+                            if options__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("options"));
+                            }
+                            options__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(BeginPasskeyAuthenticationResponse {
+                    challenge_id: challenge_id__.unwrap_or_default(),
+                    options: options__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.BeginPasskeyAuthenticationResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BeginPasskeyRegistrationRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.BeginPasskeyRegistrationRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BeginPasskeyRegistrationRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BeginPasskeyRegistrationRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.BeginPasskeyRegistrationRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<BeginPasskeyRegistrationRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(BeginPasskeyRegistrationRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.BeginPasskeyRegistrationRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BeginPasskeyRegistrationResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.challenge_id.is_empty() {
+            len += 1;
+        }
+        if !self.options.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.BeginPasskeyRegistrationResponse", len)?;
+        if !self.challenge_id.is_empty() {
+            struct_ser.serialize_field("challengeId", &self.challenge_id)?;
+        }
+        if !self.options.is_empty() {
+            struct_ser.serialize_field("options", &self.options)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BeginPasskeyRegistrationResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "challenge_id",
+            "challengeId",
+            "options",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChallengeId,
+            Options,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "challengeId" | "challenge_id" => Ok(GeneratedField::ChallengeId),
+                            "options" => Ok(GeneratedField::Options),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BeginPasskeyRegistrationResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.BeginPasskeyRegistrationResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<BeginPasskeyRegistrationResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut challenge_id__ = None;
+                let mut options__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChallengeId => {
+                            if challenge_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("challengeId"));
+                            }
+                            challenge_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Options => {
+                            if options__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("options"));
+                            }
+                            options__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(BeginPasskeyRegistrationResponse {
+                    challenge_id: challenge_id__.unwrap_or_default(),
+                    options: options__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.BeginPasskeyRegistrationResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ConfirmMfaEnrollmentRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if !self.code.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.ConfirmMfaEnrollmentRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if !self.code.is_empty() {
+            struct_ser.serialize_field("code", &self.code)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConfirmMfaEnrollmentRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "code",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            Code,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "code" => Ok(GeneratedField::Code),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConfirmMfaEnrollmentRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.ConfirmMfaEnrollmentRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ConfirmMfaEnrollmentRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut code__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Code => {
+                            if code__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("code"));
+                            }
+                            code__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(ConfirmMfaEnrollmentRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                    code: code__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.ConfirmMfaEnrollmentRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ConfirmMfaEnrollmentResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.recovery_codes.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.ConfirmMfaEnrollmentResponse", len)?;
+        if !self.recovery_codes.is_empty() {
+            struct_ser.serialize_field("recoveryCodes", &self.recovery_codes)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ConfirmMfaEnrollmentResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "recovery_codes",
+            "recoveryCodes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RecoveryCodes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "recoveryCodes" | "recovery_codes" => Ok(GeneratedField::RecoveryCodes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ConfirmMfaEnrollmentResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.ConfirmMfaEnrollmentResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ConfirmMfaEnrollmentResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut recovery_codes__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::RecoveryCodes => {
+                            if recovery_codes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recoveryCodes"));
+                            }
+                            recovery_codes__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(ConfirmMfaEnrollmentResponse {
+                    recovery_codes: recovery_codes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.ConfirmMfaEnrollmentResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DisableMfaRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if !self.code.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.DisableMfaRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if !self.code.is_empty() {
+            struct_ser.serialize_field("code", &self.code)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DisableMfaRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "code",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            Code,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "code" => Ok(GeneratedField::Code),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DisableMfaRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.DisableMfaRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<DisableMfaRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut code__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Code => {
+                            if code__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("code"));
+                            }
+                            code__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(DisableMfaRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                    code: code__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.DisableMfaRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DisableMfaResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("credential_settings_def.v1.DisableMfaResponse", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DisableMfaResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DisableMfaResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.DisableMfaResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<DisableMfaResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(DisableMfaResponse {
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.DisableMfaResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FinishPasskeyAuthenticationRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.challenge_id.is_empty() {
+            len += 1;
+        }
+        if !self.credential.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.FinishPasskeyAuthenticationRequest", len)?;
+        if !self.challenge_id.is_empty() {
+            struct_ser.serialize_field("challengeId", &self.challenge_id)?;
+        }
+        if !self.credential.is_empty() {
+            struct_ser.serialize_field("credential", &self.credential)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FinishPasskeyAuthenticationRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "challenge_id",
+            "challengeId",
+            "credential",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChallengeId,
+            Credential,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "challengeId" | "challenge_id" => Ok(GeneratedField::ChallengeId),
+                            "credential" => Ok(GeneratedField::Credential),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FinishPasskeyAuthenticationRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.FinishPasskeyAuthenticationRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<FinishPasskeyAuthenticationRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut challenge_id__ = None;
+                let mut credential__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ChallengeId => {
+                            if challenge_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("challengeId"));
+                            }
+                            challenge_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Credential => {
+                            if credential__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("credential"));
+                            }
+                            credential__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(FinishPasskeyAuthenticationRequest {
+                    challenge_id: challenge_id__.unwrap_or_default(),
+                    credential: credential__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.FinishPasskeyAuthenticationRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FinishPasskeyAuthenticationResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if !self.passkey_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.FinishPasskeyAuthenticationResponse", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if !self.passkey_id.is_empty() {
+            struct_ser.serialize_field("passkeyId", &self.passkey_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FinishPasskeyAuthenticationResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "passkey_id",
+            "passkeyId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            PasskeyId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "passkeyId" | "passkey_id" => Ok(GeneratedField::PasskeyId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FinishPasskeyAuthenticationResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.FinishPasskeyAuthenticationResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<FinishPasskeyAuthenticationResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut passkey_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::PasskeyId => {
+                            if passkey_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("passkeyId"));
+                            }
+                            passkey_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(FinishPasskeyAuthenticationResponse {
+                    user_id: user_id__.unwrap_or_default(),
+                    passkey_id: passkey_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.FinishPasskeyAuthenticationResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FinishPasskeyRegistrationRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if !self.challenge_id.is_empty() {
+            len += 1;
+        }
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if !self.credential.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.FinishPasskeyRegistrationRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if !self.challenge_id.is_empty() {
+            struct_ser.serialize_field("challengeId", &self.challenge_id)?;
+        }
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.credential.is_empty() {
+            struct_ser.serialize_field("credential", &self.credential)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FinishPasskeyRegistrationRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "challenge_id",
+            "challengeId",
+            "name",
+            "credential",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            ChallengeId,
+            Name,
+            Credential,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "challengeId" | "challenge_id" => Ok(GeneratedField::ChallengeId),
+                            "name" => Ok(GeneratedField::Name),
+                            "credential" => Ok(GeneratedField::Credential),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FinishPasskeyRegistrationRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.FinishPasskeyRegistrationRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<FinishPasskeyRegistrationRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut challenge_id__ = None;
+                let mut name__ = None;
+                let mut credential__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ChallengeId => {
+                            if challenge_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("challengeId"));
+                            }
+                            challenge_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Credential => {
+                            if credential__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("credential"));
+                            }
+                            credential__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(FinishPasskeyRegistrationRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                    challenge_id: challenge_id__.unwrap_or_default(),
+                    name: name__.unwrap_or_default(),
+                    credential: credential__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.FinishPasskeyRegistrationRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FinishPasskeyRegistrationResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.passkey.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.FinishPasskeyRegistrationResponse", len)?;
+        if let Some(v) = self.passkey.as_ref() {
+            struct_ser.serialize_field("passkey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FinishPasskeyRegistrationResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "passkey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Passkey,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "passkey" => Ok(GeneratedField::Passkey),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FinishPasskeyRegistrationResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.FinishPasskeyRegistrationResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<FinishPasskeyRegistrationResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut passkey__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Passkey => {
+                            if passkey__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("passkey"));
+                            }
+                            passkey__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(FinishPasskeyRegistrationResponse {
+                    passkey: passkey__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.FinishPasskeyRegistrationResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for GetCredentialSettingsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -10,14 +1670,1298 @@ impl serde::Serialize for GetCredentialSettingsRequest {
         if !self.user_id.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.GetCredentialSettingsRequest", len)?;
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.GetCredentialSettingsRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetCredentialSettingsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetCredentialSettingsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.GetCredentialSettingsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<GetCredentialSettingsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(GetCredentialSettingsRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.GetCredentialSettingsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetCredentialSettingsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.has_password {
+            len += 1;
+        }
+        if self.mfa_enabled {
+            len += 1;
+        }
+        if self.login_apple_id.is_some() {
+            len += 1;
+        }
+        if self.login_google_id.is_some() {
+            len += 1;
+        }
+        if !self.passkeys.is_empty() {
+            len += 1;
+        }
+        if !self.login_providers.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.GetCredentialSettingsResponse", len)?;
+        if self.has_password {
+            struct_ser.serialize_field("hasPassword", &self.has_password)?;
+        }
+        if self.mfa_enabled {
+            struct_ser.serialize_field("mfaEnabled", &self.mfa_enabled)?;
+        }
+        if let Some(v) = self.login_apple_id.as_ref() {
+            struct_ser.serialize_field("loginAppleId", v)?;
+        }
+        if let Some(v) = self.login_google_id.as_ref() {
+            struct_ser.serialize_field("loginGoogleId", v)?;
+        }
+        if !self.passkeys.is_empty() {
+            struct_ser.serialize_field("passkeys", &self.passkeys)?;
+        }
+        if !self.login_providers.is_empty() {
+            struct_ser.serialize_field("loginProviders", &self.login_providers)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetCredentialSettingsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "has_password",
+            "hasPassword",
+            "mfa_enabled",
+            "mfaEnabled",
+            "login_apple_id",
+            "loginAppleId",
+            "login_google_id",
+            "loginGoogleId",
+            "passkeys",
+            "login_providers",
+            "loginProviders",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            HasPassword,
+            MfaEnabled,
+            LoginAppleId,
+            LoginGoogleId,
+            Passkeys,
+            LoginProviders,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "hasPassword" | "has_password" => Ok(GeneratedField::HasPassword),
+                            "mfaEnabled" | "mfa_enabled" => Ok(GeneratedField::MfaEnabled),
+                            "loginAppleId" | "login_apple_id" => Ok(GeneratedField::LoginAppleId),
+                            "loginGoogleId" | "login_google_id" => Ok(GeneratedField::LoginGoogleId),
+                            "passkeys" => Ok(GeneratedField::Passkeys),
+                            "loginProviders" | "login_providers" => Ok(GeneratedField::LoginProviders),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetCredentialSettingsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.GetCredentialSettingsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<GetCredentialSettingsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut has_password__ = None;
+                let mut mfa_enabled__ = None;
+                let mut login_apple_id__ = None;
+                let mut login_google_id__ = None;
+                let mut passkeys__ = None;
+                let mut login_providers__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::HasPassword => {
+                            if has_password__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hasPassword"));
+                            }
+                            has_password__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::MfaEnabled => {
+                            if mfa_enabled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("mfaEnabled"));
+                            }
+                            mfa_enabled__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::LoginAppleId => {
+                            if login_apple_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("loginAppleId"));
+                            }
+                            login_apple_id__ = map.next_value()?;
+                        }
+                        GeneratedField::LoginGoogleId => {
+                            if login_google_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("loginGoogleId"));
+                            }
+                            login_google_id__ = map.next_value()?;
+                        }
+                        GeneratedField::Passkeys => {
+                            if passkeys__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("passkeys"));
+                            }
+                            passkeys__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::LoginProviders => {
+                            if login_providers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("loginProviders"));
+                            }
+                            login_providers__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(GetCredentialSettingsResponse {
+                    has_password: has_password__.unwrap_or_default(),
+                    mfa_enabled: mfa_enabled__.unwrap_or_default(),
+                    login_apple_id: login_apple_id__,
+                    login_google_id: login_google_id__,
+                    passkeys: passkeys__.unwrap_or_default(),
+                    login_providers: login_providers__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.GetCredentialSettingsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LinkLoginProviderRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if self.provider != 0 {
+            len += 1;
+        }
+        if !self.code.is_empty() {
+            len += 1;
+        }
+        if !self.redirect_uri.is_empty() {
+            len += 1;
+        }
+        if !self.nonce.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.LinkLoginProviderRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if self.provider != 0 {
+            let v = LoginProvider::from_i32(self.provider)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.provider)))?;
+            struct_ser.serialize_field("provider", &v)?;
+        }
+        if !self.code.is_empty() {
+            struct_ser.serialize_field("code", &self.code)?;
+        }
+        if !self.redirect_uri.is_empty() {
+            struct_ser.serialize_field("redirectUri", &self.redirect_uri)?;
+        }
+        if !self.nonce.is_empty() {
+            struct_ser.serialize_field("nonce", &self.nonce)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LinkLoginProviderRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "provider",
+            "code",
+            "redirect_uri",
+            "redirectUri",
+            "nonce",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            Provider,
+            Code,
+            RedirectUri,
+            Nonce,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "provider" => Ok(GeneratedField::Provider),
+                            "code" => Ok(GeneratedField::Code),
+                            "redirectUri" | "redirect_uri" => Ok(GeneratedField::RedirectUri),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LinkLoginProviderRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.LinkLoginProviderRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<LinkLoginProviderRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut provider__ = None;
+                let mut code__ = None;
+                let mut redirect_uri__ = None;
+                let mut nonce__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Provider => {
+                            if provider__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("provider"));
+                            }
+                            provider__ = Some(map.next_value::<LoginProvider>()? as i32);
+                        }
+                        GeneratedField::Code => {
+                            if code__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("code"));
+                            }
+                            code__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::RedirectUri => {
+                            if redirect_uri__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("redirectUri"));
+                            }
+                            redirect_uri__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(LinkLoginProviderRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                    provider: provider__.unwrap_or_default(),
+                    code: code__.unwrap_or_default(),
+                    redirect_uri: redirect_uri__.unwrap_or_default(),
+                    nonce: nonce__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.LinkLoginProviderRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LinkLoginProviderResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.login_provider.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.LinkLoginProviderResponse", len)?;
+        if let Some(v) = self.login_provider.as_ref() {
+            struct_ser.serialize_field("loginProvider", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LinkLoginProviderResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "login_provider",
+            "loginProvider",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            LoginProvider,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "loginProvider" | "login_provider" => Ok(GeneratedField::LoginProvider),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LinkLoginProviderResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.LinkLoginProviderResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<LinkLoginProviderResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut login_provider__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::LoginProvider => {
+                            if login_provider__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("loginProvider"));
+                            }
+                            login_provider__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(LinkLoginProviderResponse {
+                    login_provider: login_provider__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.LinkLoginProviderResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LinkedLoginProvider {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.provider != 0 {
+            len += 1;
+        }
+        if !self.provider_user_id.is_empty() {
+            len += 1;
+        }
+        if self.email.is_some() {
+            len += 1;
+        }
+        if self.created_at.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.LinkedLoginProvider", len)?;
+        if self.provider != 0 {
+            let v = LoginProvider::from_i32(self.provider)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.provider)))?;
+            struct_ser.serialize_field("provider", &v)?;
+        }
+        if !self.provider_user_id.is_empty() {
+            struct_ser.serialize_field("providerUserId", &self.provider_user_id)?;
+        }
+        if let Some(v) = self.email.as_ref() {
+            struct_ser.serialize_field("email", v)?;
+        }
+        if let Some(v) = self.created_at.as_ref() {
+            struct_ser.serialize_field("createdAt", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LinkedLoginProvider {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "provider",
+            "provider_user_id",
+            "providerUserId",
+            "email",
+            "created_at",
+            "createdAt",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Provider,
+            ProviderUserId,
+            Email,
+            CreatedAt,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "provider" => Ok(GeneratedField::Provider),
+                            "providerUserId" | "provider_user_id" => Ok(GeneratedField::ProviderUserId),
+                            "email" => Ok(GeneratedField::Email),
+                            "createdAt" | "created_at" => Ok(GeneratedField::CreatedAt),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LinkedLoginProvider;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.LinkedLoginProvider")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<LinkedLoginProvider, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut provider__ = None;
+                let mut provider_user_id__ = None;
+                let mut email__ = None;
+                let mut created_at__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Provider => {
+                            if provider__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("provider"));
+                            }
+                            provider__ = Some(map.next_value::<LoginProvider>()? as i32);
+                        }
+                        GeneratedField::ProviderUserId => {
+                            if provider_user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("providerUserId"));
+                            }
+                            provider_user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Email => {
+                            if email__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("email"));
+                            }
+                            email__ = map.next_value()?;
+                        }
+                        GeneratedField::CreatedAt => {
+                            if created_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAt"));
+                            }
+                            created_at__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(LinkedLoginProvider {
+                    provider: provider__.unwrap_or_default(),
+                    provider_user_id: provider_user_id__.unwrap_or_default(),
+                    email: email__,
+                    created_at: created_at__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.LinkedLoginProvider", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LoginProvider {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => 0,
+            Self::Apple => 1,
+            Self::Google => 2,
+        };
+        serializer.serialize_i32(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for LoginProvider {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "LOGIN_PROVIDER_UNSPECIFIED",
+            "LOGIN_PROVIDER_APPLE",
+            "LOGIN_PROVIDER_GOOGLE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LoginProvider;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(LoginProvider::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(LoginProvider::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "LOGIN_PROVIDER_UNSPECIFIED" => Ok(LoginProvider::Unspecified),
+                    "LOGIN_PROVIDER_APPLE" => Ok(LoginProvider::Apple),
+                    "LOGIN_PROVIDER_GOOGLE" => Ok(LoginProvider::Google),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Passkey {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if self.last_used_at.is_some() {
+            len += 1;
+        }
+        if self.created_at.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.Passkey", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if let Some(v) = self.last_used_at.as_ref() {
+            struct_ser.serialize_field("lastUsedAt", v)?;
+        }
+        if let Some(v) = self.created_at.as_ref() {
+            struct_ser.serialize_field("createdAt", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Passkey {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "name",
+            "last_used_at",
+            "lastUsedAt",
+            "created_at",
+            "createdAt",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Name,
+            LastUsedAt,
+            CreatedAt,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "name" => Ok(GeneratedField::Name),
+                            "lastUsedAt" | "last_used_at" => Ok(GeneratedField::LastUsedAt),
+                            "createdAt" | "created_at" => Ok(GeneratedField::CreatedAt),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Passkey;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.Passkey")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Passkey, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut name__ = None;
+                let mut last_used_at__ = None;
+                let mut created_at__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::LastUsedAt => {
+                            if last_used_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastUsedAt"));
+                            }
+                            last_used_at__ = map.next_value()?;
+                        }
+                        GeneratedField::CreatedAt => {
+                            if created_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAt"));
+                            }
+                            created_at__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(Passkey {
+                    id: id__.unwrap_or_default(),
+                    name: name__.unwrap_or_default(),
+                    last_used_at: last_used_at__,
+                    created_at: created_at__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.Passkey", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RegenerateMfaRecoveryCodesRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if !self.code.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.RegenerateMfaRecoveryCodesRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if !self.code.is_empty() {
+            struct_ser.serialize_field("code", &self.code)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RegenerateMfaRecoveryCodesRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "code",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            Code,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "code" => Ok(GeneratedField::Code),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RegenerateMfaRecoveryCodesRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.RegenerateMfaRecoveryCodesRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<RegenerateMfaRecoveryCodesRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut code__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Code => {
+                            if code__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("code"));
+                            }
+                            code__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(RegenerateMfaRecoveryCodesRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                    code: code__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.RegenerateMfaRecoveryCodesRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RegenerateMfaRecoveryCodesResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.recovery_codes.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.RegenerateMfaRecoveryCodesResponse", len)?;
+        if !self.recovery_codes.is_empty() {
+            struct_ser.serialize_field("recoveryCodes", &self.recovery_codes)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RegenerateMfaRecoveryCodesResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "recovery_codes",
+            "recoveryCodes",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RecoveryCodes,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "recoveryCodes" | "recovery_codes" => Ok(GeneratedField::RecoveryCodes),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RegenerateMfaRecoveryCodesResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.RegenerateMfaRecoveryCodesResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<RegenerateMfaRecoveryCodesResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut recovery_codes__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::RecoveryCodes => {
+                            if recovery_codes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recoveryCodes"));
+                            }
+                            recovery_codes__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(RegenerateMfaRecoveryCodesResponse {
+                    recovery_codes: recovery_codes__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.RegenerateMfaRecoveryCodesResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RemovePasskeyRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if !self.passkey_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.RemovePasskeyRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if !self.passkey_id.is_empty() {
+            struct_ser.serialize_field("passkeyId", &self.passkey_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RemovePasskeyRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "passkey_id",
+            "passkeyId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            PasskeyId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "passkeyId" | "passkey_id" => Ok(GeneratedField::PasskeyId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RemovePasskeyRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.RemovePasskeyRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<RemovePasskeyRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut passkey_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::PasskeyId => {
+                            if passkey_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("passkeyId"));
+                            }
+                            passkey_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(RemovePasskeyRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                    passkey_id: passkey_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.RemovePasskeyRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RemovePasskeyResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("credential_settings_def.v1.RemovePasskeyResponse", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RemovePasskeyResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RemovePasskeyResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.RemovePasskeyResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<RemovePasskeyResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(RemovePasskeyResponse {
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.RemovePasskeyResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UnlinkLoginProviderRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if self.provider != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.UnlinkLoginProviderRequest", len)?;
         if !self.user_id.is_empty() {
             struct_ser.serialize_field("userId", &self.user_id)?;
         }
+        if self.provider != 0 {
+            let v = LoginProvider::from_i32(self.provider)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.provider)))?;
+            struct_ser.serialize_field("provider", &v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GetCredentialSettingsRequest {
+impl<'de> serde::Deserialize<'de> for UnlinkLoginProviderRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -26,11 +2970,13 @@ impl<'de> serde::Deserialize<'de> for GetCredentialSettingsRequest {
         const FIELDS: &[&str] = &[
             "user_id",
             "userId",
+            "provider",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             UserId,
+            Provider,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -53,6 +2999,7 @@ impl<'de> serde::Deserialize<'de> for GetCredentialSettingsRequest {
                     {
                         match value {
                             "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "provider" => Ok(GeneratedField::Provider),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -62,17 +3009,18 @@ impl<'de> serde::Deserialize<'de> for GetCredentialSettingsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GetCredentialSettingsRequest;
+            type Value = UnlinkLoginProviderRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct credential_settings_def.v1.GetCredentialSettingsRequest")
+                formatter.write_str("struct credential_settings_def.v1.UnlinkLoginProviderRequest")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<GetCredentialSettingsRequest, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UnlinkLoginProviderRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut user_id__ = None;
+                let mut provider__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::UserId => {
@@ -81,17 +3029,95 @@ impl<'de> serde::Deserialize<'de> for GetCredentialSettingsRequest {
                             }
                             user_id__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Provider => {
+                            if provider__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("provider"));
+                            }
+                            provider__ = Some(map.next_value::<LoginProvider>()? as i32);
+                        }
                     }
                 }
-                Ok(GetCredentialSettingsRequest {
+                Ok(UnlinkLoginProviderRequest {
                     user_id: user_id__.unwrap_or_default(),
+                    provider: provider__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("credential_settings_def.v1.GetCredentialSettingsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("credential_settings_def.v1.UnlinkLoginProviderRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GetCredentialSettingsResponse {
+impl serde::Serialize for UnlinkLoginProviderResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("credential_settings_def.v1.UnlinkLoginProviderResponse", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UnlinkLoginProviderResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UnlinkLoginProviderResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.UnlinkLoginProviderResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UnlinkLoginProviderResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(UnlinkLoginProviderResponse {
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.UnlinkLoginProviderResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VerifyMfaCodeRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -99,57 +3125,166 @@ impl serde::Serialize for GetCredentialSettingsResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.has_password {
+        if !self.code.is_empty() {
             len += 1;
         }
-        if self.mfa_enabled {
+        if !self.challenge_token.is_empty() {
             len += 1;
         }
-        if self.login_apple_id.is_some() {
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.VerifyMfaCodeRequest", len)?;
+        if !self.code.is_empty() {
+            struct_ser.serialize_field("code", &self.code)?;
+        }
+        if !self.challenge_token.is_empty() {
+            struct_ser.serialize_field("challengeToken", &self.challenge_token)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for VerifyMfaCodeRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "code",
+            "challenge_token",
+            "challengeToken",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Code,
+            ChallengeToken,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "code" => Ok(GeneratedField::Code),
+                            "challengeToken" | "challenge_token" => Ok(GeneratedField::ChallengeToken),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = VerifyMfaCodeRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct credential_settings_def.v1.VerifyMfaCodeRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<VerifyMfaCodeRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut code__ = None;
+                let mut challenge_token__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Code => {
+                            if code__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("code"));
+                            }
+                            code__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ChallengeToken => {
+                            if challenge_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("challengeToken"));
+                            }
+                            challenge_token__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(VerifyMfaCodeRequest {
+                    code: code__.unwrap_or_default(),
+                    challenge_token: challenge_token__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("credential_settings_def.v1.VerifyMfaCodeRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for VerifyMfaCodeResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.is_valid {
             len += 1;
         }
-        if self.login_google_id.is_some() {
+        if self.used_recovery_code {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.GetCredentialSettingsResponse", len)?;
-        if self.has_password {
-            struct_ser.serialize_field("hasPassword", &self.has_password)?;
+        if self.user_id.is_some() {
+            len += 1;
         }
-        if self.mfa_enabled {
-            struct_ser.serialize_field("mfaEnabled", &self.mfa_enabled)?;
+        if self.remaining_attempts != 0 {
+            len += 1;
         }
-        if let Some(v) = self.login_apple_id.as_ref() {
-            struct_ser.serialize_field("loginAppleId", v)?;
+        let mut struct_ser = serializer.serialize_struct("credential_settings_def.v1.VerifyMfaCodeResponse", len)?;
+        if self.is_valid {
+            struct_ser.serialize_field("isValid", &self.is_valid)?;
         }
-        if let Some(v) = self.login_google_id.as_ref() {
-            struct_ser.serialize_field("loginGoogleId", v)?;
+        if self.used_recovery_code {
+            struct_ser.serialize_field("usedRecoveryCode", &self.used_recovery_code)?;
+        }
+        if let Some(v) = self.user_id.as_ref() {
+            struct_ser.serialize_field("userId", v)?;
+        }
+        if self.remaining_attempts != 0 {
+            struct_ser.serialize_field("remainingAttempts", &self.remaining_attempts)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GetCredentialSettingsResponse {
+impl<'de> serde::Deserialize<'de> for VerifyMfaCodeResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "has_password",
-            "hasPassword",
-            "mfa_enabled",
-            "mfaEnabled",
-            "login_apple_id",
-            "loginAppleId",
-            "login_google_id",
-            "loginGoogleId",
+            "is_valid",
+            "isValid",
+            "used_recovery_code",
+            "usedRecoveryCode",
+            "user_id",
+            "userId",
+            "remaining_attempts",
+            "remainingAttempts",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            HasPassword,
-            MfaEnabled,
-            LoginAppleId,
-            LoginGoogleId,
+            IsValid,
+            UsedRecoveryCode,
+            UserId,
+            RemainingAttempts,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -171,10 +3306,10 @@ impl<'de> serde::Deserialize<'de> for GetCredentialSettingsResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "hasPassword" | "has_password" => Ok(GeneratedField::HasPassword),
-                            "mfaEnabled" | "mfa_enabled" => Ok(GeneratedField::MfaEnabled),
-                            "loginAppleId" | "login_apple_id" => Ok(GeneratedField::LoginAppleId),
-                            "loginGoogleId" | "login_google_id" => Ok(GeneratedField::LoginGoogleId),
+                            "isValid" | "is_valid" => Ok(GeneratedField::IsValid),
+                            "usedRecoveryCode" | "used_recovery_code" => Ok(GeneratedField::UsedRecoveryCode),
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "remainingAttempts" | "remaining_attempts" => Ok(GeneratedField::RemainingAttempts),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -184,56 +3319,58 @@ impl<'de> serde::Deserialize<'de> for GetCredentialSettingsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GetCredentialSettingsResponse;
+            type Value = VerifyMfaCodeResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct credential_settings_def.v1.GetCredentialSettingsResponse")
+                formatter.write_str("struct credential_settings_def.v1.VerifyMfaCodeResponse")
             }
 
-            fn visit_map<V>(self, mut map: V) -> std::result::Result<GetCredentialSettingsResponse, V::Error>
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<VerifyMfaCodeResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut has_password__ = None;
-                let mut mfa_enabled__ = None;
-                let mut login_apple_id__ = None;
-                let mut login_google_id__ = None;
+                let mut is_valid__ = None;
+                let mut used_recovery_code__ = None;
+                let mut user_id__ = None;
+                let mut remaining_attempts__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
-                        GeneratedField::HasPassword => {
-                            if has_password__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("hasPassword"));
+                        GeneratedField::IsValid => {
+                            if is_valid__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("isValid"));
                             }
-                            has_password__ = Some(map.next_value()?);
+                            is_valid__ = Some(map.next_value()?);
                         }
-                        GeneratedField::MfaEnabled => {
-                            if mfa_enabled__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("mfaEnabled"));
+                        GeneratedField::UsedRecoveryCode => {
+                            if used_recovery_code__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("usedRecoveryCode"));
                             }
-                            mfa_enabled__ = Some(map.next_value()?);
+                            used_recovery_code__ = Some(map.next_value()?);
                         }
-                        GeneratedField::LoginAppleId => {
-                            if login_apple_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("loginAppleId"));
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
                             }
-                            login_apple_id__ = map.next_value()?;
+                            user_id__ = map.next_value()?;
                         }
-                        GeneratedField::LoginGoogleId => {
-                            if login_google_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("loginGoogleId"));
+                        GeneratedField::RemainingAttempts => {
+                            if remaining_attempts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("remainingAttempts"));
                             }
-                            login_google_id__ = map.next_value()?;
+                            remaining_attempts__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                     }
                 }
-                Ok(GetCredentialSettingsResponse {
-                    has_password: has_password__.unwrap_or_default(),
-                    mfa_enabled: mfa_enabled__.unwrap_or_default(),
-                    login_apple_id: login_apple_id__,
-                    login_google_id: login_google_id__,
+                Ok(VerifyMfaCodeResponse {
+                    is_valid: is_valid__.unwrap_or_default(),
+                    used_recovery_code: used_recovery_code__.unwrap_or_default(),
+                    user_id: user_id__,
+                    remaining_attempts: remaining_attempts__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("credential_settings_def.v1.GetCredentialSettingsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("credential_settings_def.v1.VerifyMfaCodeResponse", FIELDS, GeneratedVisitor)
     }
 }
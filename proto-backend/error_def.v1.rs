@@ -0,0 +1,115 @@
+// @generated
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ErrorDetail {
+    #[prost(enumeration="ErrorReason", tag="1")]
+    pub reason: i32,
+    /// Identifiers of the entities involved, e.g. `story_id`
+    #[prost(map="string, string", tag="2")]
+    pub metadata: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+/// Attached to the `google.rpc.Status` details of a failed RPC as an `ErrorDetail`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ErrorReason {
+    Unspecified = 0,
+    UserNotFound = 1,
+    UserSuspended = 2,
+    /// The viewer has been blocked by the user
+    UserBlocked = 3,
+    /// The viewer is not a friend of the private user
+    ProfilePrivate = 4,
+    StoryNotFound = 5,
+    StoryDeleted = 6,
+    StoryUnpublished = 7,
+    DraftNotFound = 8,
+    CommentNotFound = 9,
+    CommentDeleted = 10,
+    BlogNotFound = 11,
+    /// The feature requires the blog to have a Plus subscription
+    BlogPlusRequired = 12,
+    BlogLoginRequired = 13,
+    TagNotFound = 14,
+    TokenNotFound = 15,
+    TokenExpired = 16,
+    AccountExportInProgress = 17,
+    AccountExportCooldown = 18,
+    AccountDeletionScheduled = 19,
+    /// The MFA challenge is unknown, expired, consumed, or out of attempts
+    MfaChallengeInvalid = 20,
+    /// The service credential is missing, expired, or has an invalid signature
+    CredentialInvalid = 21,
+    /// The session token is stale or invalid
+    SessionInvalid = 22,
+    /// A user ID in the request differs from the authenticated user
+    IdentityMismatch = 23,
+    /// The caller has exceeded the rate limit of the method
+    RateLimited = 24,
+}
+impl ErrorReason {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ErrorReason::Unspecified => "ERROR_REASON_UNSPECIFIED",
+            ErrorReason::UserNotFound => "ERROR_REASON_USER_NOT_FOUND",
+            ErrorReason::UserSuspended => "ERROR_REASON_USER_SUSPENDED",
+            ErrorReason::UserBlocked => "ERROR_REASON_USER_BLOCKED",
+            ErrorReason::ProfilePrivate => "ERROR_REASON_PROFILE_PRIVATE",
+            ErrorReason::StoryNotFound => "ERROR_REASON_STORY_NOT_FOUND",
+            ErrorReason::StoryDeleted => "ERROR_REASON_STORY_DELETED",
+            ErrorReason::StoryUnpublished => "ERROR_REASON_STORY_UNPUBLISHED",
+            ErrorReason::DraftNotFound => "ERROR_REASON_DRAFT_NOT_FOUND",
+            ErrorReason::CommentNotFound => "ERROR_REASON_COMMENT_NOT_FOUND",
+            ErrorReason::CommentDeleted => "ERROR_REASON_COMMENT_DELETED",
+            ErrorReason::BlogNotFound => "ERROR_REASON_BLOG_NOT_FOUND",
+            ErrorReason::BlogPlusRequired => "ERROR_REASON_BLOG_PLUS_REQUIRED",
+            ErrorReason::BlogLoginRequired => "ERROR_REASON_BLOG_LOGIN_REQUIRED",
+            ErrorReason::TagNotFound => "ERROR_REASON_TAG_NOT_FOUND",
+            ErrorReason::TokenNotFound => "ERROR_REASON_TOKEN_NOT_FOUND",
+            ErrorReason::TokenExpired => "ERROR_REASON_TOKEN_EXPIRED",
+            ErrorReason::AccountExportInProgress => "ERROR_REASON_ACCOUNT_EXPORT_IN_PROGRESS",
+            ErrorReason::AccountExportCooldown => "ERROR_REASON_ACCOUNT_EXPORT_COOLDOWN",
+            ErrorReason::AccountDeletionScheduled => "ERROR_REASON_ACCOUNT_DELETION_SCHEDULED",
+            ErrorReason::MfaChallengeInvalid => "ERROR_REASON_MFA_CHALLENGE_INVALID",
+            ErrorReason::CredentialInvalid => "ERROR_REASON_CREDENTIAL_INVALID",
+            ErrorReason::SessionInvalid => "ERROR_REASON_SESSION_INVALID",
+            ErrorReason::IdentityMismatch => "ERROR_REASON_IDENTITY_MISMATCH",
+            ErrorReason::RateLimited => "ERROR_REASON_RATE_LIMITED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ERROR_REASON_UNSPECIFIED" => Some(Self::Unspecified),
+            "ERROR_REASON_USER_NOT_FOUND" => Some(Self::UserNotFound),
+            "ERROR_REASON_USER_SUSPENDED" => Some(Self::UserSuspended),
+            "ERROR_REASON_USER_BLOCKED" => Some(Self::UserBlocked),
+            "ERROR_REASON_PROFILE_PRIVATE" => Some(Self::ProfilePrivate),
+            "ERROR_REASON_STORY_NOT_FOUND" => Some(Self::StoryNotFound),
+            "ERROR_REASON_STORY_DELETED" => Some(Self::StoryDeleted),
+            "ERROR_REASON_STORY_UNPUBLISHED" => Some(Self::StoryUnpublished),
+            "ERROR_REASON_DRAFT_NOT_FOUND" => Some(Self::DraftNotFound),
+            "ERROR_REASON_COMMENT_NOT_FOUND" => Some(Self::CommentNotFound),
+            "ERROR_REASON_COMMENT_DELETED" => Some(Self::CommentDeleted),
+            "ERROR_REASON_BLOG_NOT_FOUND" => Some(Self::BlogNotFound),
+            "ERROR_REASON_BLOG_PLUS_REQUIRED" => Some(Self::BlogPlusRequired),
+            "ERROR_REASON_BLOG_LOGIN_REQUIRED" => Some(Self::BlogLoginRequired),
+            "ERROR_REASON_TAG_NOT_FOUND" => Some(Self::TagNotFound),
+            "ERROR_REASON_TOKEN_NOT_FOUND" => Some(Self::TokenNotFound),
+            "ERROR_REASON_TOKEN_EXPIRED" => Some(Self::TokenExpired),
+            "ERROR_REASON_ACCOUNT_EXPORT_IN_PROGRESS" => Some(Self::AccountExportInProgress),
+            "ERROR_REASON_ACCOUNT_EXPORT_COOLDOWN" => Some(Self::AccountExportCooldown),
+            "ERROR_REASON_ACCOUNT_DELETION_SCHEDULED" => Some(Self::AccountDeletionScheduled),
+            "ERROR_REASON_MFA_CHALLENGE_INVALID" => Some(Self::MfaChallengeInvalid),
+            "ERROR_REASON_CREDENTIAL_INVALID" => Some(Self::CredentialInvalid),
+            "ERROR_REASON_SESSION_INVALID" => Some(Self::SessionInvalid),
+            "ERROR_REASON_IDENTITY_MISMATCH" => Some(Self::IdentityMismatch),
+            "ERROR_REASON_RATE_LIMITED" => Some(Self::RateLimited),
+            _ => None,
+        }
+    }
+}
+// @@protoc_insertion_point(module)
@@ -0,0 +1,255 @@
+// @generated
+impl serde::Serialize for ErrorDetail {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.reason != 0 {
+            len += 1;
+        }
+        if !self.metadata.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("error_def.v1.ErrorDetail", len)?;
+        if self.reason != 0 {
+            let v = ErrorReason::from_i32(self.reason)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.reason)))?;
+            struct_ser.serialize_field("reason", &v)?;
+        }
+        if !self.metadata.is_empty() {
+            struct_ser.serialize_field("metadata", &self.metadata)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ErrorDetail {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reason",
+            "metadata",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reason,
+            Metadata,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reason" => Ok(GeneratedField::Reason),
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ErrorDetail;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct error_def.v1.ErrorDetail")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ErrorDetail, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reason__ = None;
+                let mut metadata__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map.next_value::<ErrorReason>()? as i32);
+                        }
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = Some(
+                                map.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                    }
+                }
+                Ok(ErrorDetail {
+                    reason: reason__.unwrap_or_default(),
+                    metadata: metadata__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("error_def.v1.ErrorDetail", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ErrorReason {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => 0,
+            Self::UserNotFound => 1,
+            Self::UserSuspended => 2,
+            Self::UserBlocked => 3,
+            Self::ProfilePrivate => 4,
+            Self::StoryNotFound => 5,
+            Self::StoryDeleted => 6,
+            Self::StoryUnpublished => 7,
+            Self::DraftNotFound => 8,
+            Self::CommentNotFound => 9,
+            Self::CommentDeleted => 10,
+            Self::BlogNotFound => 11,
+            Self::BlogPlusRequired => 12,
+            Self::BlogLoginRequired => 13,
+            Self::TagNotFound => 14,
+            Self::TokenNotFound => 15,
+            Self::TokenExpired => 16,
+            Self::AccountExportInProgress => 17,
+            Self::AccountExportCooldown => 18,
+            Self::AccountDeletionScheduled => 19,
+            Self::MfaChallengeInvalid => 20,
+            Self::CredentialInvalid => 21,
+            Self::SessionInvalid => 22,
+            Self::IdentityMismatch => 23,
+            Self::RateLimited => 24,
+        };
+        serializer.serialize_i32(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ErrorReason {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ERROR_REASON_UNSPECIFIED",
+            "ERROR_REASON_USER_NOT_FOUND",
+            "ERROR_REASON_USER_SUSPENDED",
+            "ERROR_REASON_USER_BLOCKED",
+            "ERROR_REASON_PROFILE_PRIVATE",
+            "ERROR_REASON_STORY_NOT_FOUND",
+            "ERROR_REASON_STORY_DELETED",
+            "ERROR_REASON_STORY_UNPUBLISHED",
+            "ERROR_REASON_DRAFT_NOT_FOUND",
+            "ERROR_REASON_COMMENT_NOT_FOUND",
+            "ERROR_REASON_COMMENT_DELETED",
+            "ERROR_REASON_BLOG_NOT_FOUND",
+            "ERROR_REASON_BLOG_PLUS_REQUIRED",
+            "ERROR_REASON_BLOG_LOGIN_REQUIRED",
+            "ERROR_REASON_TAG_NOT_FOUND",
+            "ERROR_REASON_TOKEN_NOT_FOUND",
+            "ERROR_REASON_TOKEN_EXPIRED",
+            "ERROR_REASON_ACCOUNT_EXPORT_IN_PROGRESS",
+            "ERROR_REASON_ACCOUNT_EXPORT_COOLDOWN",
+            "ERROR_REASON_ACCOUNT_DELETION_SCHEDULED",
+            "ERROR_REASON_MFA_CHALLENGE_INVALID",
+            "ERROR_REASON_CREDENTIAL_INVALID",
+            "ERROR_REASON_SESSION_INVALID",
+            "ERROR_REASON_IDENTITY_MISMATCH",
+            "ERROR_REASON_RATE_LIMITED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ErrorReason;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(ErrorReason::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(ErrorReason::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "ERROR_REASON_UNSPECIFIED" => Ok(ErrorReason::Unspecified),
+                    "ERROR_REASON_USER_NOT_FOUND" => Ok(ErrorReason::UserNotFound),
+                    "ERROR_REASON_USER_SUSPENDED" => Ok(ErrorReason::UserSuspended),
+                    "ERROR_REASON_USER_BLOCKED" => Ok(ErrorReason::UserBlocked),
+                    "ERROR_REASON_PROFILE_PRIVATE" => Ok(ErrorReason::ProfilePrivate),
+                    "ERROR_REASON_STORY_NOT_FOUND" => Ok(ErrorReason::StoryNotFound),
+                    "ERROR_REASON_STORY_DELETED" => Ok(ErrorReason::StoryDeleted),
+                    "ERROR_REASON_STORY_UNPUBLISHED" => Ok(ErrorReason::StoryUnpublished),
+                    "ERROR_REASON_DRAFT_NOT_FOUND" => Ok(ErrorReason::DraftNotFound),
+                    "ERROR_REASON_COMMENT_NOT_FOUND" => Ok(ErrorReason::CommentNotFound),
+                    "ERROR_REASON_COMMENT_DELETED" => Ok(ErrorReason::CommentDeleted),
+                    "ERROR_REASON_BLOG_NOT_FOUND" => Ok(ErrorReason::BlogNotFound),
+                    "ERROR_REASON_BLOG_PLUS_REQUIRED" => Ok(ErrorReason::BlogPlusRequired),
+                    "ERROR_REASON_BLOG_LOGIN_REQUIRED" => Ok(ErrorReason::BlogLoginRequired),
+                    "ERROR_REASON_TAG_NOT_FOUND" => Ok(ErrorReason::TagNotFound),
+                    "ERROR_REASON_TOKEN_NOT_FOUND" => Ok(ErrorReason::TokenNotFound),
+                    "ERROR_REASON_TOKEN_EXPIRED" => Ok(ErrorReason::TokenExpired),
+                    "ERROR_REASON_ACCOUNT_EXPORT_IN_PROGRESS" => Ok(ErrorReason::AccountExportInProgress),
+                    "ERROR_REASON_ACCOUNT_EXPORT_COOLDOWN" => Ok(ErrorReason::AccountExportCooldown),
+                    "ERROR_REASON_ACCOUNT_DELETION_SCHEDULED" => Ok(ErrorReason::AccountDeletionScheduled),
+                    "ERROR_REASON_MFA_CHALLENGE_INVALID" => Ok(ErrorReason::MfaChallengeInvalid),
+                    "ERROR_REASON_CREDENTIAL_INVALID" => Ok(ErrorReason::CredentialInvalid),
+                    "ERROR_REASON_SESSION_INVALID" => Ok(ErrorReason::SessionInvalid),
+                    "ERROR_REASON_IDENTITY_MISMATCH" => Ok(ErrorReason::IdentityMismatch),
+                    "ERROR_REASON_RATE_LIMITED" => Ok(ErrorReason::RateLimited),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
@@ -0,0 +1,576 @@
+//! Authentication of the `ApiService` callers.
+//!
+//! The server no longer trusts the `user_id` (and similar) fields sent by the
+//! clients. Instead, [`AuthInterceptor`] derives the identity of the caller
+//! from the call metadata:
+//!
+//! - `authorization` - A signed [`ServiceCredential`] identifying the client
+//!   service (such as the web server). It is required for every call.
+//! - `x-session-token` - The token from the session cookie of the user on whose
+//!   behalf the call is made. It is omitted for anonymous visitors.
+//!
+//! The resulting [`Caller`] is inserted into the request extensions, from
+//! where the handlers read it using [`Caller::from_request`].
+
+use super::{
+    super::{
+        account_def::v1::{
+            CancelAccountDeletionRequest, GetAccountExportStatusRequest,
+            RequestAccountExportRequest, ScheduleAccountDeletionRequest,
+        },
+        asset_def::v1::{SetAssetRatingRequest, UploadImageMetadata},
+        blog_def::v1::{
+            ExportBlogRequest, GetBlogNewsletterRequest, GetBlogRequest, GetUserBlogsInfoRequest,
+        },
+        comment_def::v1::GetCommentRequest,
+        connection_settings_def::v1::{
+            AddConnectionRequest, GetConnectionSettingsRequest, RefreshConnectionRequest,
+            RemoveConnectionRequest, SetConnectionHiddenRequest,
+        },
+        credential_settings_def::v1::{
+            BeginMfaEnrollmentRequest, BeginPasskeyRegistrationRequest,
+            ConfirmMfaEnrollmentRequest, DisableMfaRequest, FinishPasskeyRegistrationRequest,
+            GetCredentialSettingsRequest, LinkLoginProviderRequest,
+            RegenerateMfaRecoveryCodesRequest, RemovePasskeyRequest, UnlinkLoginProviderRequest,
+        },
+        error_def::v1::ErrorReason,
+        login_activity_def::v1::GetLoginActivityRequest,
+        notification_def::v1::{
+            GetUnreadNotificationCountRequest, ListNotificationsRequest,
+            MarkNotificationsReadRequest, WatchNotificationsRequest,
+        },
+        notification_settings_def::v1::{
+            GetNotificationSettingsRequest, UpdateNotificationSettingsRequest,
+        },
+        privacy_settings_def::v1::{GetPrivacySettingsRequest, UpdatePrivacySettingsRequest},
+        profile_def::v1::{GetProfileRequest, UpdateProfileRequest},
+        report_def::v1::{ListReportsRequest, ReportContentRequest, ResolveReportRequest},
+        response_def::v1::{GetResponsesInfoRequest, GetStoryResponsesInfoRequest},
+        story_def::v1::{
+            CreateDraftRequest, ExportStoryRequest, GetContributionsInfoRequest,
+            GetDraftsInfoRequest, GetStoriesInfoRequest, GetStoryMetadataRequest, GetStoryRequest,
+            ImportStoriesRequest, ImportStoryRequest, ValidateStoryRequest,
+        },
+        tag_def::v1::{GetFollowedTagCountRequest, GetTagRequest},
+        user_def::v1::{
+            ClearStatusRequest, GetUserBlockCountRequest, GetUserMuteCountRequest,
+            GetUserRelationsInfoRequest, GrantUserFlagRequest, RevokeUserFlagRequest,
+            SetStatusRequest, SuspendUserRequest, UnsuspendUserRequest,
+        },
+    },
+    errors::ApiError,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tonic::{service::Interceptor, Request, Status};
+
+/// The metadata key carrying the service credential.
+pub const SERVICE_CREDENTIAL_METADATA_KEY: &str = "authorization";
+/// The metadata key carrying the session token.
+pub const SESSION_TOKEN_METADATA_KEY: &str = "x-session-token";
+
+/// The version prefix of the service credentials.
+const CREDENTIAL_VERSION: &str = "v1";
+/// Tolerated clock skew between the services.
+const CLOCK_SKEW: Duration = Duration::from_secs(30);
+/// Maximum lifetime of a service credential.
+const MAX_CREDENTIAL_LIFETIME: Duration = Duration::from_secs(5 * 60);
+
+/// The identity of the caller of an RPC.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Caller {
+    service: String,
+    user_id: Option<String>,
+}
+
+impl Caller {
+    /// Creates a caller.
+    ///
+    /// * `service` - The name of the client service.
+    /// * `user_id` - The ID of the user on whose behalf the call is made.
+    pub fn new(service: impl Into<String>, user_id: Option<String>) -> Self {
+        Self {
+            service: service.into(),
+            user_id,
+        }
+    }
+
+    /// Returns the caller inserted by the [`AuthInterceptor`].
+    ///
+    /// * `request` - The request.
+    #[allow(clippy::result_large_err)]
+    pub fn from_request<T>(request: &Request<T>) -> Result<&Self, Status> {
+        request
+            .extensions()
+            .get::<Self>()
+            .ok_or_else(|| Status::unauthenticated("Missing caller identity"))
+    }
+
+    /// Returns the name of the client service.
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    /// Returns the ID of the authenticated user, if any.
+    pub fn user_id(&self) -> Option<&str> {
+        self.user_id.as_deref()
+    }
+
+    /// Returns the ID of the authenticated user, or an `UNAUTHENTICATED` status
+    /// for anonymous callers.
+    #[allow(clippy::result_large_err)]
+    pub fn require_user_id(&self) -> Result<&str, Status> {
+        self.user_id().ok_or_else(|| {
+            ApiError::new(ErrorReason::SessionInvalid, "A user session is required").into()
+        })
+    }
+
+    /// Rejects a request whose user ID fields disagree with the authenticated
+    /// user.
+    ///
+    /// * `message` - The request message.
+    #[allow(clippy::result_large_err)]
+    pub fn authorize<M: UserScoped>(&self, message: &M) -> Result<(), Status> {
+        for (field, value) in message.claimed_user_ids() {
+            if value.is_empty() {
+                continue;
+            }
+
+            if self.user_id() != Some(value) {
+                return Err(ApiError::new(
+                    ErrorReason::IdentityMismatch,
+                    format!("`{field}` does not match the authenticated user"),
+                )
+                .with_metadata("field", field)
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A request carrying fields that identify the caller.
+pub trait UserScoped {
+    /// Returns the name and the value of the fields identifying the caller.
+    /// Empty values are treated as omitted.
+    fn claimed_user_ids(&self) -> Vec<(&'static str, &str)>;
+}
+
+/// A user ID field, which is either required or optional.
+trait UserIdField {
+    fn as_user_id(&self) -> &str;
+}
+
+impl UserIdField for String {
+    fn as_user_id(&self) -> &str {
+        self
+    }
+}
+
+impl UserIdField for Option<String> {
+    fn as_user_id(&self) -> &str {
+        self.as_deref().unwrap_or_default()
+    }
+}
+
+/// Implements [`UserScoped`] for the requests. The fields that identify
+/// another user (such as the target of `SuspendUser`) are not listed.
+macro_rules! user_scoped {
+    ($($request:ty { $($field:ident),* $(,)? })*) => {
+        $(impl UserScoped for $request {
+            fn claimed_user_ids(&self) -> Vec<(&'static str, &str)> {
+                vec![$((stringify!($field), self.$field.as_user_id())),*]
+            }
+        })*
+    };
+}
+
+user_scoped! {
+    CancelAccountDeletionRequest { user_id }
+    GetAccountExportStatusRequest { user_id }
+    RequestAccountExportRequest { user_id }
+    ScheduleAccountDeletionRequest { user_id }
+    SetAssetRatingRequest { moderator_id }
+    UploadImageMetadata { user_id }
+    ExportBlogRequest { current_user_id }
+    GetBlogNewsletterRequest { current_user_id }
+    GetBlogRequest { current_user_id }
+    GetUserBlogsInfoRequest { user_id }
+    GetCommentRequest { current_user_id }
+    AddConnectionRequest { user_id }
+    GetConnectionSettingsRequest { user_id }
+    RefreshConnectionRequest { user_id }
+    RemoveConnectionRequest { user_id }
+    SetConnectionHiddenRequest { user_id }
+    BeginMfaEnrollmentRequest { user_id }
+    BeginPasskeyRegistrationRequest { user_id }
+    ConfirmMfaEnrollmentRequest { user_id }
+    DisableMfaRequest { user_id }
+    FinishPasskeyRegistrationRequest { user_id }
+    GetCredentialSettingsRequest { user_id }
+    LinkLoginProviderRequest { user_id }
+    RegenerateMfaRecoveryCodesRequest { user_id }
+    RemovePasskeyRequest { user_id }
+    UnlinkLoginProviderRequest { user_id }
+    GetLoginActivityRequest { user_id }
+    GetUnreadNotificationCountRequest { user_id }
+    ListNotificationsRequest { user_id }
+    MarkNotificationsReadRequest { user_id }
+    WatchNotificationsRequest { user_id }
+    GetNotificationSettingsRequest { user_id }
+    UpdateNotificationSettingsRequest { user_id }
+    GetPrivacySettingsRequest { user_id }
+    UpdatePrivacySettingsRequest { user_id }
+    GetProfileRequest { current_user_id }
+    UpdateProfileRequest { user_id }
+    ListReportsRequest { moderator_id }
+    ReportContentRequest { user_id }
+    ResolveReportRequest { moderator_id }
+    GetResponsesInfoRequest { user_id }
+    GetStoryResponsesInfoRequest { user_id }
+    CreateDraftRequest { user_id }
+    ExportStoryRequest { current_user_id }
+    GetContributionsInfoRequest { user_id }
+    GetDraftsInfoRequest { user_id }
+    GetStoriesInfoRequest { user_id }
+    GetStoryMetadataRequest { user_id }
+    GetStoryRequest { current_user_id }
+    ImportStoriesRequest { user_id }
+    ImportStoryRequest { user_id }
+    ValidateStoryRequest { user_id }
+    GetFollowedTagCountRequest { user_id }
+    GetTagRequest { current_user_id }
+    ClearStatusRequest { user_id }
+    GetUserBlockCountRequest { user_id }
+    GetUserMuteCountRequest { user_id }
+    GetUserRelationsInfoRequest { user_id }
+    SetStatusRequest { user_id }
+    GrantUserFlagRequest { admin_id }
+    RevokeUserFlagRequest { admin_id }
+    SuspendUserRequest { admin_id }
+    UnsuspendUserRequest { admin_id }
+}
+
+/// A signed credential identifying a client service, in the form of
+/// `v1.<service>.<expires_at>.<signature>`, where `expires_at` is a UNIX
+/// timestamp (in seconds) and `signature` is the unpadded base64url encoded
+/// HMAC-SHA256 of the preceding parts, keyed with the secret of the service.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServiceCredential {
+    service: String,
+    expires_at: u64,
+}
+
+impl ServiceCredential {
+    /// Signs a credential for a service.
+    ///
+    /// * `service` - The name of the service.
+    /// * `secret` - The secret of the service.
+    /// * `expires_at` - The expiry of the credential.
+    pub fn sign(service: &str, secret: &[u8], expires_at: SystemTime) -> String {
+        let payload = format!(
+            "{CREDENTIAL_VERSION}.{service}.{}",
+            unix_seconds(expires_at)
+        );
+        let signature = URL_SAFE_NO_PAD.encode(hmac(secret, &payload).finalize().into_bytes());
+
+        format!("{payload}.{signature}")
+    }
+
+    /// Returns the name of the service.
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+}
+
+/// Returns the HMAC of a payload.
+///
+/// * `secret` - The key.
+/// * `payload` - The payload.
+fn hmac(secret: &[u8], payload: &str) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any size");
+    mac.update(payload.as_bytes());
+    mac
+}
+
+/// Returns the seconds elapsed since the UNIX epoch.
+///
+/// * `time` - The time.
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// The secrets of the services allowed to call the `ApiService`.
+#[derive(Clone, Default)]
+pub struct ServiceKeys(HashMap<String, Vec<u8>>);
+
+impl ServiceKeys {
+    /// Registers the secret of a service.
+    ///
+    /// * `service` - The name of the service.
+    /// * `secret` - The secret of the service.
+    pub fn with_service(mut self, service: impl Into<String>, secret: impl Into<Vec<u8>>) -> Self {
+        self.0.insert(service.into(), secret.into());
+        self
+    }
+
+    /// Verifies a service credential, returning `None` if it is malformed,
+    /// issued for an unknown service, expired, or has an invalid signature.
+    ///
+    /// * `credential` - The credential.
+    /// * `now` - The current time.
+    pub fn verify(&self, credential: &str, now: SystemTime) -> Option<ServiceCredential> {
+        let (payload, signature) = credential.rsplit_once('.')?;
+        let mut parts = payload.splitn(3, '.');
+
+        if parts.next()? != CREDENTIAL_VERSION {
+            return None;
+        }
+
+        let service = parts.next()?;
+        let expires_at = parts.next()?.parse::<u64>().ok()?;
+        let secret = self.0.get(service)?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).ok()?;
+
+        // Verified in constant time.
+        hmac(secret, payload).verify_slice(&signature).ok()?;
+
+        let now = unix_seconds(now);
+
+        if expires_at + CLOCK_SKEW.as_secs() < now
+            || expires_at > now + (MAX_CREDENTIAL_LIFETIME + CLOCK_SKEW).as_secs()
+        {
+            return None;
+        }
+
+        Some(ServiceCredential {
+            service: service.to_string(),
+            expires_at,
+        })
+    }
+}
+
+/// Resolves the user ID from a session token.
+///
+/// Interceptors are synchronous, so the implementations are expected to verify
+/// signed tokens or to read from a local cache of the session store.
+pub trait SessionVerifier: Send + Sync + 'static {
+    /// Returns the ID of the user owning the session, or `None` if the token is
+    /// stale or invalid.
+    ///
+    /// * `token` - The token from the session cookie.
+    fn verify(&self, token: &str) -> Option<String>;
+}
+
+/// Derives the [`Caller`] of every RPC from the call metadata.
+///
+/// ```ignore
+/// let interceptor = AuthInterceptor::new(
+///     ServiceKeys::default().with_service("web", web_secret),
+///     sessions,
+/// );
+///
+/// Server::builder()
+///     .add_service(ApiServiceServer::with_interceptor(service, interceptor))
+///     .serve(address)
+///     .await?;
+/// ```
+pub struct AuthInterceptor<V> {
+    keys: Arc<ServiceKeys>,
+    sessions: Arc<V>,
+    clock: fn() -> SystemTime,
+}
+
+impl<V> Clone for AuthInterceptor<V> {
+    fn clone(&self) -> Self {
+        Self {
+            keys: self.keys.clone(),
+            sessions: self.sessions.clone(),
+            clock: self.clock,
+        }
+    }
+}
+
+impl<V: SessionVerifier> AuthInterceptor<V> {
+    /// Creates an interceptor.
+    ///
+    /// * `keys` - The secrets of the services allowed to call the API.
+    /// * `sessions` - The session verifier.
+    pub fn new(keys: ServiceKeys, sessions: V) -> Self {
+        Self {
+            keys: Arc::new(keys),
+            sessions: Arc::new(sessions),
+            clock: SystemTime::now,
+        }
+    }
+
+    /// Overrides the clock used to check the expiry of the credentials.
+    ///
+    /// * `clock` - The clock.
+    pub fn with_clock(mut self, clock: fn() -> SystemTime) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+impl<V: SessionVerifier> Interceptor for AuthInterceptor<V> {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let metadata = request.metadata();
+        let credential = metadata
+            .get(SERVICE_CREDENTIAL_METADATA_KEY)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .and_then(|value| self.keys.verify(value, (self.clock)()))
+            .ok_or_else(|| {
+                Status::from(ApiError::new(
+                    ErrorReason::CredentialInvalid,
+                    "Invalid service credential",
+                ))
+            })?;
+        let user_id = match metadata.get(SESSION_TOKEN_METADATA_KEY) {
+            Some(token) => Some(
+                token
+                    .to_str()
+                    .ok()
+                    .and_then(|token| self.sessions.verify(token))
+                    .ok_or_else(|| {
+                        Status::from(ApiError::new(
+                            ErrorReason::SessionInvalid,
+                            "Invalid session token",
+                        ))
+                    })?,
+            ),
+            None => None,
+        };
+
+        request
+            .extensions_mut()
+            .insert(Caller::new(credential.service, user_id));
+
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::errors::StatusExt, *};
+
+    const SECRET: &[u8] = b"secret";
+
+    struct Sessions;
+
+    impl SessionVerifier for Sessions {
+        fn verify(&self, token: &str) -> Option<String> {
+            (token == "session").then(|| "user".to_string())
+        }
+    }
+
+    fn now() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+    }
+
+    fn get_interceptor() -> AuthInterceptor<Sessions> {
+        AuthInterceptor::new(ServiceKeys::default().with_service("web", SECRET), Sessions)
+            .with_clock(now)
+    }
+
+    fn get_request(credential: &str, session: Option<&str>) -> Request<()> {
+        let mut request = Request::new(());
+        let metadata = request.metadata_mut();
+
+        metadata.insert(
+            SERVICE_CREDENTIAL_METADATA_KEY,
+            format!("Bearer {credential}").parse().unwrap(),
+        );
+
+        if let Some(session) = session {
+            metadata.insert(SESSION_TOKEN_METADATA_KEY, session.parse().unwrap());
+        }
+
+        request
+    }
+
+    #[test]
+    fn can_authenticate_the_caller() {
+        let credential = ServiceCredential::sign("web", SECRET, now() + Duration::from_secs(60));
+        let request = get_interceptor()
+            .call(get_request(&credential, Some("session")))
+            .unwrap();
+
+        assert_eq!(
+            Caller::from_request(&request).unwrap(),
+            &Caller::new("web", Some("user".to_string()))
+        );
+
+        let request = get_interceptor()
+            .call(get_request(&credential, None))
+            .unwrap();
+
+        assert_eq!(Caller::from_request(&request).unwrap().user_id(), None);
+    }
+
+    #[test]
+    fn can_reject_invalid_credentials() {
+        let expires_at = now() + Duration::from_secs(60);
+
+        for credential in [
+            ServiceCredential::sign("web", b"other", expires_at),
+            ServiceCredential::sign("unknown", SECRET, expires_at),
+            ServiceCredential::sign("web", SECRET, now() - Duration::from_secs(60)),
+            ServiceCredential::sign("web", SECRET, now() + Duration::from_secs(3600)),
+            "v1.web".to_string(),
+        ] {
+            let status = get_interceptor()
+                .call(get_request(&credential, None))
+                .unwrap_err();
+
+            assert_eq!(status.error_reason(), ErrorReason::CredentialInvalid);
+        }
+    }
+
+    #[test]
+    fn can_reject_invalid_sessions() {
+        let credential = ServiceCredential::sign("web", SECRET, now() + Duration::from_secs(60));
+        let status = get_interceptor()
+            .call(get_request(&credential, Some("stale")))
+            .unwrap_err();
+
+        assert_eq!(status.error_reason(), ErrorReason::SessionInvalid);
+    }
+
+    #[test]
+    fn can_reject_mismatched_user_ids() {
+        let caller = Caller::new("web", Some("user".to_string()));
+
+        assert!(caller
+            .authorize(&GetDraftsInfoRequest {
+                user_id: "user".to_string(),
+            })
+            .is_ok());
+        // Omitted fields fall back to the authenticated user.
+        assert!(caller.authorize(&GetStoryRequest::default()).is_ok());
+
+        let status = caller
+            .authorize(&GetDraftsInfoRequest {
+                user_id: "other".to_string(),
+            })
+            .unwrap_err();
+
+        assert_eq!(status.error_reason(), ErrorReason::IdentityMismatch);
+        assert!(Caller::new("web", None)
+            .authorize(&GetStoryRequest {
+                current_user_id: Some("user".to_string()),
+                ..Default::default()
+            })
+            .is_err());
+    }
+}
@@ -0,0 +1,224 @@
+//! Transition of the connection providers from slugs to `ConnectionProvider`.
+//!
+//! The `provider` string fields of `Connection` and `ConnectionSetting` are
+//! deprecated in favour of the `provider_type` enum fields under new tags.
+//! Until every client has moved to the new fields, the handlers resolve the
+//! provider using [`resolve_provider`] and call
+//! [`FillProviders::fill_providers`] on the responses so that both are set.
+
+use super::super::{
+    connection_def::v1::{Connection, ConnectionProvider, ConnectionSetting},
+    connection_settings_def::v1::{
+        AddConnectionResponse, GetConnectionSettingsResponse, RefreshConnectionResponse,
+    },
+    profile_def::v1::GetProfileResponse,
+};
+
+impl ConnectionProvider {
+    /// Returns the slug used by the deprecated `provider` fields, e.g.
+    /// `github`, or `None` for [`ConnectionProvider::Unspecified`].
+    pub fn slug(&self) -> Option<&'static str> {
+        Some(match self {
+            ConnectionProvider::Unspecified => return None,
+            ConnectionProvider::Discord => "discord",
+            ConnectionProvider::Dribbble => "dribbble",
+            ConnectionProvider::Figma => "figma",
+            ConnectionProvider::Github => "github",
+            ConnectionProvider::Linkedin => "linkedin",
+            ConnectionProvider::Reddit => "reddit",
+            ConnectionProvider::Snapchat => "snapchat",
+            ConnectionProvider::Spotify => "spotify",
+            ConnectionProvider::Twitch => "twitch",
+            ConnectionProvider::Youtube => "youtube",
+        })
+    }
+
+    /// Parses the slug of a provider, returning `None` if it is unknown.
+    ///
+    /// * `slug` - The slug.
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        Some(match slug {
+            "discord" => ConnectionProvider::Discord,
+            "dribbble" => ConnectionProvider::Dribbble,
+            "figma" => ConnectionProvider::Figma,
+            "github" => ConnectionProvider::Github,
+            "linkedin" => ConnectionProvider::Linkedin,
+            "reddit" => ConnectionProvider::Reddit,
+            "snapchat" => ConnectionProvider::Snapchat,
+            "spotify" => ConnectionProvider::Spotify,
+            "twitch" => ConnectionProvider::Twitch,
+            "youtube" => ConnectionProvider::Youtube,
+            _ => return None,
+        })
+    }
+}
+
+/// Resolves a provider sent in either of its fields, preferring the
+/// `provider_type` field. An unspecified or unknown value is treated as
+/// omitted.
+///
+/// * `provider_type` - The raw value of the `provider_type` field.
+/// * `provider` - The deprecated slug field.
+pub fn resolve_provider(provider_type: i32, provider: &str) -> Option<ConnectionProvider> {
+    ConnectionProvider::try_from(provider_type)
+        .ok()
+        .filter(|provider| *provider != ConnectionProvider::Unspecified)
+        .or_else(|| ConnectionProvider::from_slug(provider))
+}
+
+/// A message carrying the deprecated provider slugs.
+pub trait FillProviders {
+    /// Sets both fields of every provider from whichever of the two is set,
+    /// preferring the `provider_type` field, including in the nested messages.
+    fn fill_providers(&mut self);
+
+    /// Returns the message with both fields of every provider set.
+    fn with_providers(mut self) -> Self
+    where
+        Self: Sized,
+    {
+        self.fill_providers();
+        self
+    }
+}
+
+impl<T: FillProviders> FillProviders for Option<T> {
+    fn fill_providers(&mut self) {
+        if let Some(message) = self {
+            message.fill_providers();
+        }
+    }
+}
+
+impl<T: FillProviders> FillProviders for Vec<T> {
+    fn fill_providers(&mut self) {
+        self.iter_mut().for_each(FillProviders::fill_providers);
+    }
+}
+
+/// Sets both fields of a provider. An unknown slug is left as is when the
+/// `provider_type` field is unspecified.
+///
+/// * `provider` - The deprecated slug field.
+/// * `provider_type` - The raw value of the `provider_type` field.
+fn fill(provider: &mut String, provider_type: &mut i32) {
+    let Some(resolved) = resolve_provider(*provider_type, provider) else {
+        return;
+    };
+
+    if let Some(slug) = resolved.slug() {
+        *provider = slug.to_string();
+    }
+
+    *provider_type = resolved as i32;
+}
+
+/// Implements [`FillProviders`] for the messages with a provider, followed by
+/// the messages with nested providers.
+macro_rules! fill_providers {
+    ($($message:ty),* $(,)?; $($wrapper:ty { $($nested:ident),* $(,)? })*) => {
+        $(impl FillProviders for $message {
+            #[allow(deprecated)]
+            fn fill_providers(&mut self) {
+                fill(&mut self.provider, &mut self.provider_type);
+            }
+        })*
+        $(impl FillProviders for $wrapper {
+            fn fill_providers(&mut self) {
+                $(self.$nested.fill_providers();)*
+            }
+        })*
+    };
+}
+
+fill_providers! {
+    Connection, ConnectionSetting;
+    GetConnectionSettingsResponse { connections }
+    AddConnectionResponse { connection }
+    RefreshConnectionResponse { connection }
+    GetProfileResponse { connections }
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_round_trip_the_slugs() {
+        for value in 1..=10 {
+            let provider = ConnectionProvider::try_from(value).unwrap();
+
+            assert_eq!(
+                ConnectionProvider::from_slug(provider.slug().unwrap()),
+                Some(provider)
+            );
+        }
+
+        assert_eq!(ConnectionProvider::Unspecified.slug(), None);
+        assert_eq!(ConnectionProvider::from_slug("myspace"), None);
+    }
+
+    #[test]
+    fn can_resolve_either_field() {
+        assert_eq!(
+            resolve_provider(0, "github"),
+            Some(ConnectionProvider::Github)
+        );
+        assert_eq!(
+            resolve_provider(ConnectionProvider::Twitch as i32, "github"),
+            Some(ConnectionProvider::Twitch)
+        );
+        assert_eq!(
+            resolve_provider(99, "github"),
+            Some(ConnectionProvider::Github)
+        );
+        assert_eq!(resolve_provider(0, ""), None);
+    }
+
+    #[test]
+    fn can_fill_both_fields() {
+        let response = GetConnectionSettingsResponse {
+            connections: vec![
+                ConnectionSetting {
+                    provider: "spotify".to_string(),
+                    ..Default::default()
+                },
+                ConnectionSetting {
+                    provider_type: ConnectionProvider::Github as i32,
+                    ..Default::default()
+                },
+                ConnectionSetting {
+                    provider: "myspace".to_string(),
+                    ..Default::default()
+                },
+            ],
+        }
+        .with_providers();
+
+        assert_eq!(
+            response.connections[0].provider_type,
+            ConnectionProvider::Spotify as i32
+        );
+        assert_eq!(response.connections[1].provider, "github");
+        assert_eq!(response.connections[2].provider, "myspace");
+        assert_eq!(response.connections[2].provider_type, 0);
+    }
+
+    #[test]
+    fn can_fill_the_profile_connections() {
+        let profile = GetProfileResponse {
+            connections: vec![Connection {
+                provider: "youtube".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+        .with_providers();
+
+        assert_eq!(
+            profile.connections[0].provider_type,
+            ConnectionProvider::Youtube as i32
+        );
+    }
+}
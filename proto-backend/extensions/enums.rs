@@ -0,0 +1,264 @@
+//! Fallible accessors for the enumeration fields.
+//!
+//! `prost` stores enumeration fields as raw `i32` values. The getters it
+//! derives (`GetStoryResponse::visibility`, `GetTokenRequest::r#type`, ...)
+//! silently fall back to the default variant when the value is unknown, which
+//! is `*_UNSPECIFIED` for every enum in this API. The `try_*` getters below
+//! return an [`UnknownEnumValue`] instead, so that a value sent by a newer or
+//! misbehaving client is rejected rather than misinterpreted. The derived
+//! `set_*` setters already go through the enums and are not repeated here.
+
+use super::super::{
+    account_def::v1::{
+        AccountExport, AccountExportStatus, ScheduleAccountDeletionRequest, StoryDisposition,
+    },
+    asset_def::v1::{
+        AssetPurpose, AssetRating, SetAssetRatingRequest, UploadImageMetadata, UploadImageResponse,
+    },
+    connection_def::v1::{Connection, ConnectionProvider, ConnectionSetting},
+    connection_settings_def::v1::AddConnectionRequest,
+    credential_settings_def::v1::{
+        LinkLoginProviderRequest, LinkedLoginProvider, LoginProvider, UnlinkLoginProviderRequest,
+    },
+    login_activity_def::v1::{Device, DeviceType},
+    notification_def::v1::{Notification, NotificationType},
+    privacy_settings_def::v1::{
+        GetPrivacySettingsResponse, IncomingBlogRequest, IncomingCollaborationRequest,
+        IncomingFriendRequest, RelationVisibility,
+    },
+    report_def::v1::{
+        ListReportsRequest, ModerationAuditLogEntry, Report, ReportContentRequest,
+        ReportEntityType, ReportReason, ReportResolution, ResolveReportRequest,
+    },
+    story_def::v1::{
+        ExportStoryRequest, GetStoryMetadataResponse, GetStoryResponse, ImportStoriesRequest,
+        ImportStoryRequest, StoryAgeRestriction, StoryExportFormat, StoryImportFormat,
+        StoryLicense, StoryVisibility,
+    },
+    token_def::v1::{GetTokenRequest, TokenType},
+    user_def::v1::{
+        ExtendedStatus, GrantUserFlagRequest, RevokeUserFlagRequest, SetStatusRequest,
+        StatusDuration, StatusVisibility, UserFlag,
+    },
+};
+use std::fmt;
+
+/// An enumeration field holds a value that is not defined by its enum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownEnumValue {
+    /// The name of the field, without the `r#` prefix.
+    pub field: &'static str,
+    /// The raw value of the field.
+    pub value: i32,
+}
+
+impl fmt::Display for UnknownEnumValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown value {} for `{}`", self.value, self.field)
+    }
+}
+
+impl std::error::Error for UnknownEnumValue {}
+
+/// Converts the raw value of an enumeration field.
+///
+/// * `field` - The name of the field.
+/// * `value` - The raw value of the field.
+pub fn try_enum<E>(field: &'static str, value: i32) -> Result<E, UnknownEnumValue>
+where
+    E: TryFrom<i32>,
+{
+    E::try_from(value).map_err(|_| UnknownEnumValue {
+        field: field.trim_start_matches("r#"),
+        value,
+    })
+}
+
+/// Implements the `try_*` getters for the required enumeration fields.
+macro_rules! enum_accessors {
+    ($($message:ty {
+        $($getter:ident => $field:ident: $enum:ty),* $(,)?
+    })*) => {
+        $(impl $message {
+            $(
+                #[doc = concat!(
+                    "Returns the enum value of `",
+                    stringify!($field),
+                    "`, or an error if it is not a known variant."
+                )]
+                pub fn $getter(&self) -> Result<$enum, UnknownEnumValue> {
+                    try_enum(stringify!($field), self.$field)
+                }
+            )*
+        })*
+    };
+}
+
+enum_accessors! {
+    AccountExport {
+        try_status => status: AccountExportStatus,
+    }
+    ScheduleAccountDeletionRequest {
+        try_story_disposition => story_disposition: StoryDisposition,
+    }
+    UploadImageMetadata {
+        try_purpose => purpose: AssetPurpose,
+        try_rating => rating: AssetRating,
+    }
+    UploadImageResponse {
+        try_rating => rating: AssetRating,
+    }
+    SetAssetRatingRequest {
+        try_rating => rating: AssetRating,
+    }
+    Connection {
+        try_provider_type => provider_type: ConnectionProvider,
+    }
+    ConnectionSetting {
+        try_provider_type => provider_type: ConnectionProvider,
+    }
+    AddConnectionRequest {
+        try_provider => provider: ConnectionProvider,
+    }
+    LinkedLoginProvider {
+        try_provider => provider: LoginProvider,
+    }
+    LinkLoginProviderRequest {
+        try_provider => provider: LoginProvider,
+    }
+    UnlinkLoginProviderRequest {
+        try_provider => provider: LoginProvider,
+    }
+    Device {
+        try_type => r#type: DeviceType,
+    }
+    Notification {
+        try_type => r#type: NotificationType,
+    }
+    GetPrivacySettingsResponse {
+        try_incoming_friend_requests => incoming_friend_requests: IncomingFriendRequest,
+        try_incoming_collaboration_requests =>
+            incoming_collaboration_requests: IncomingCollaborationRequest,
+        try_incoming_blog_requests => incoming_blog_requests: IncomingBlogRequest,
+        try_following_list_visibility => following_list_visibility: RelationVisibility,
+        try_friend_list_visibility => friend_list_visibility: RelationVisibility,
+    }
+    Report {
+        try_entity_type => entity_type: ReportEntityType,
+        try_reason => reason: ReportReason,
+    }
+    ModerationAuditLogEntry {
+        try_action => action: ReportResolution,
+        try_entity_type => entity_type: ReportEntityType,
+    }
+    ReportContentRequest {
+        try_entity_type => entity_type: ReportEntityType,
+        try_reason => reason: ReportReason,
+    }
+    ResolveReportRequest {
+        try_resolution => resolution: ReportResolution,
+    }
+    ImportStoryRequest {
+        try_format => format: StoryImportFormat,
+    }
+    ImportStoriesRequest {
+        try_format => format: StoryImportFormat,
+    }
+    ExportStoryRequest {
+        try_format => format: StoryExportFormat,
+    }
+    GetStoryResponse {
+        try_age_restriction => age_restriction: StoryAgeRestriction,
+        try_license => license: StoryLicense,
+        try_visibility => visibility: StoryVisibility,
+        try_splash_rating => splash_rating: AssetRating,
+    }
+    GetStoryMetadataResponse {
+        try_age_restriction => age_restriction: StoryAgeRestriction,
+        try_license => license: StoryLicense,
+        try_visibility => visibility: StoryVisibility,
+    }
+    GetTokenRequest {
+        try_type => r#type: TokenType,
+    }
+    ExtendedStatus {
+        try_duration => duration: StatusDuration,
+        try_visibility => visibility: StatusVisibility,
+    }
+    SetStatusRequest {
+        try_duration => duration: StatusDuration,
+        try_visibility => visibility: StatusVisibility,
+    }
+    GrantUserFlagRequest {
+        try_flag => flag: UserFlag,
+    }
+    RevokeUserFlagRequest {
+        try_flag => flag: UserFlag,
+    }
+}
+
+impl Report {
+    /// Returns the enum value of `resolution` if the report is resolved, or an
+    /// error if it is not a known variant.
+    pub fn try_resolution(&self) -> Result<Option<ReportResolution>, UnknownEnumValue> {
+        self.resolution
+            .map(|value| try_enum("resolution", value))
+            .transpose()
+    }
+}
+
+impl ListReportsRequest {
+    /// Returns the enum value of `entity_type` if the filter is set, or an
+    /// error if it is not a known variant.
+    pub fn try_entity_type(&self) -> Result<Option<ReportEntityType>, UnknownEnumValue> {
+        self.entity_type
+            .map(|value| try_enum("entity_type", value))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_read_known_values() {
+        let mut story = GetStoryResponse::default();
+        story.set_visibility(StoryVisibility::Public);
+
+        assert_eq!(story.try_visibility(), Ok(StoryVisibility::Public));
+    }
+
+    #[test]
+    fn can_reject_unknown_values() {
+        let request = GetTokenRequest {
+            r#type: 99,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            request.try_type(),
+            Err(UnknownEnumValue {
+                field: "type",
+                value: 99
+            })
+        );
+        // The derived getter falls back to the default variant.
+        assert_eq!(request.r#type(), TokenType::Unspecified);
+    }
+
+    #[test]
+    fn can_read_optional_values() {
+        let mut request = ListReportsRequest::default();
+
+        assert_eq!(request.try_entity_type(), Ok(None));
+
+        request.entity_type = Some(ReportEntityType::Story as i32);
+
+        assert_eq!(request.try_entity_type(), Ok(Some(ReportEntityType::Story)));
+
+        request.entity_type = Some(-1);
+
+        assert!(request.try_entity_type().is_err());
+    }
+}
@@ -0,0 +1,215 @@
+//! Structured errors for the `ApiService` RPCs.
+//!
+//! The handlers return an [`ApiError`], which is converted into a
+//! [`tonic::Status`] carrying an `error_def.v1.ErrorDetail`. This lets the
+//! clients tell apart failures that share a status code, such as a story that
+//! does not exist and a story hidden from a blocked viewer. The clients decode
+//! the details using [`StatusExt`].
+
+use super::{
+    super::{
+        error_def::v1::{ErrorDetail, ErrorReason},
+        google::rpc::{BadRequest, RetryInfo},
+    },
+    status::{get_detail, with_details, Detail},
+};
+use std::{collections::HashMap, fmt};
+use tonic::{Code, Status};
+
+impl Detail for ErrorDetail {
+    const NAME: &'static str = "error_def.v1.ErrorDetail";
+}
+
+impl ErrorReason {
+    /// Returns the status code of the errors with this reason.
+    pub fn code(&self) -> Code {
+        match self {
+            ErrorReason::Unspecified => Code::Unknown,
+            ErrorReason::UserNotFound
+            | ErrorReason::UserSuspended
+            | ErrorReason::StoryNotFound
+            | ErrorReason::StoryDeleted
+            | ErrorReason::StoryUnpublished
+            | ErrorReason::DraftNotFound
+            | ErrorReason::CommentNotFound
+            | ErrorReason::CommentDeleted
+            | ErrorReason::BlogNotFound
+            | ErrorReason::TagNotFound
+            | ErrorReason::TokenNotFound => Code::NotFound,
+            ErrorReason::UserBlocked | ErrorReason::ProfilePrivate => Code::PermissionDenied,
+            ErrorReason::BlogLoginRequired
+            | ErrorReason::MfaChallengeInvalid
+            | ErrorReason::CredentialInvalid
+            | ErrorReason::SessionInvalid => Code::Unauthenticated,
+            ErrorReason::IdentityMismatch => Code::PermissionDenied,
+            ErrorReason::BlogPlusRequired
+            | ErrorReason::TokenExpired
+            | ErrorReason::AccountExportInProgress
+            | ErrorReason::AccountDeletionScheduled => Code::FailedPrecondition,
+            ErrorReason::AccountExportCooldown | ErrorReason::RateLimited => {
+                Code::ResourceExhausted
+            }
+        }
+    }
+}
+
+/// An error returned by an `ApiService` RPC.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApiError {
+    reason: ErrorReason,
+    message: String,
+    metadata: HashMap<String, String>,
+}
+
+impl ApiError {
+    /// Creates an error with the status code of the reason.
+    ///
+    /// * `reason` - The reason of the error.
+    /// * `message` - The developer-facing error message.
+    pub fn new(reason: ErrorReason, message: impl Into<String>) -> Self {
+        Self {
+            reason,
+            message: message.into(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Attaches an entry to the metadata of the error, e.g. the ID of the
+    /// entity involved.
+    ///
+    /// * `key` - The key of the entry.
+    /// * `value` - The value of the entry.
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns the reason of the error.
+    pub fn reason(&self) -> ErrorReason {
+        self.reason
+    }
+
+    /// Returns the developer-facing error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the metadata of the error.
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.reason.as_str_name(), self.message)
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl From<ApiError> for Status {
+    fn from(error: ApiError) -> Self {
+        let detail = ErrorDetail {
+            reason: error.reason as i32,
+            metadata: error.metadata,
+        };
+
+        with_details(error.reason.code(), error.message, vec![detail.pack()])
+    }
+}
+
+/// Decodes the details attached to a [`Status`] returned by the `ApiService`.
+pub trait StatusExt {
+    /// Returns the error detail, if any.
+    fn error_detail(&self) -> Option<ErrorDetail>;
+
+    /// Returns the reason of the error, or [`ErrorReason::Unspecified`] when
+    /// the status carries no (or an unknown) reason.
+    fn error_reason(&self) -> ErrorReason {
+        self.error_detail()
+            .map(|detail| detail.reason())
+            .unwrap_or(ErrorReason::Unspecified)
+    }
+
+    /// Returns the invalid fields of the request, if any.
+    fn bad_request(&self) -> Option<BadRequest>;
+
+    /// Returns the delay after which a rate limited request can be retried, if
+    /// any.
+    fn retry_info(&self) -> Option<RetryInfo>;
+
+    /// Converts the status back into an [`ApiError`], returning `None` when it
+    /// carries no error detail.
+    fn to_api_error(&self) -> Option<ApiError>;
+}
+
+impl StatusExt for Status {
+    fn error_detail(&self) -> Option<ErrorDetail> {
+        get_detail(self)
+    }
+
+    fn bad_request(&self) -> Option<BadRequest> {
+        get_detail(self)
+    }
+
+    fn retry_info(&self) -> Option<RetryInfo> {
+        get_detail(self)
+    }
+
+    fn to_api_error(&self) -> Option<ApiError> {
+        self.error_detail().map(|detail| ApiError {
+            reason: detail.reason(),
+            message: self.message().to_string(),
+            metadata: detail.metadata,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{super::token_def::v1::GetTokenRequest, validation::Validate},
+        *,
+    };
+
+    #[test]
+    fn can_round_trip_an_error() {
+        let error = ApiError::new(ErrorReason::StoryDeleted, "Story has been deleted")
+            .with_metadata("story_id", "1");
+        let status = Status::from(error.clone());
+
+        assert_eq!(status.code(), Code::NotFound);
+        assert_eq!(status.error_reason(), ErrorReason::StoryDeleted);
+        assert_eq!(status.to_api_error(), Some(error));
+    }
+
+    #[test]
+    fn can_tell_apart_errors_with_the_same_code() {
+        let blocked = Status::from(ApiError::new(ErrorReason::UserBlocked, "Blocked"));
+        let private = Status::from(ApiError::new(ErrorReason::ProfilePrivate, "Private"));
+
+        assert_eq!(blocked.code(), private.code());
+        assert_ne!(blocked.error_reason(), private.error_reason());
+    }
+
+    #[test]
+    fn can_decode_statuses_without_details() {
+        let status = Status::not_found("Not found");
+
+        assert_eq!(status.error_detail(), None);
+        assert_eq!(status.error_reason(), ErrorReason::Unspecified);
+        assert_eq!(status.to_api_error(), None);
+    }
+
+    #[test]
+    fn can_decode_bad_requests() {
+        let status = GetTokenRequest::default().validate().unwrap_err();
+
+        assert_eq!(status.error_detail(), None);
+        assert_eq!(
+            status.bad_request().unwrap().field_violations[0].field,
+            "type"
+        );
+    }
+}
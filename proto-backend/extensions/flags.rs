@@ -0,0 +1,90 @@
+//! Accessors for the `public_flags` bitfields.
+//!
+//! `prost` stores the bitfields as raw `u32` values, with each bit defined by
+//! a [`UserFlag`] variant. The accessors below test and update a single flag
+//! without repeating the bit arithmetic at every call site.
+
+use super::super::{
+    profile_def::v1::GetProfileResponse,
+    user_def::v1::{
+        BareUser, ExtendedUser, GrantUserFlagResponse, RevokeUserFlagResponse, SuspendUserResponse,
+        UnsuspendUserResponse, UserFlag,
+    },
+};
+
+/// Implements the flag accessors for the messages with a `public_flags` field.
+macro_rules! flag_accessors {
+    ($($message:ty),* $(,)?) => {
+        $(impl $message {
+            /// Returns whether the flag is set in `public_flags`.
+            /// [`UserFlag::Unspecified`] is never set.
+            ///
+            /// * `flag` - The flag to test.
+            pub fn has_flag(&self, flag: UserFlag) -> bool {
+                self.public_flags & flag as u32 != 0
+            }
+
+            /// Sets or clears the flag in `public_flags`.
+            ///
+            /// * `flag` - The flag to update.
+            /// * `enabled` - Whether to set or clear the flag.
+            pub fn set_flag(&mut self, flag: UserFlag, enabled: bool) {
+                if enabled {
+                    self.public_flags |= flag as u32;
+                } else {
+                    self.public_flags &= !(flag as u32);
+                }
+            }
+        })*
+    };
+}
+
+flag_accessors! {
+    GetProfileResponse,
+    BareUser,
+    ExtendedUser,
+    GrantUserFlagResponse,
+    RevokeUserFlagResponse,
+    SuspendUserResponse,
+    UnsuspendUserResponse,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_test_flags() {
+        let user = BareUser {
+            public_flags: UserFlag::Staff as u32 | UserFlag::Verified as u32,
+            ..Default::default()
+        };
+
+        assert!(user.has_flag(UserFlag::Staff));
+        assert!(user.has_flag(UserFlag::Verified));
+        assert!(!user.has_flag(UserFlag::EarlyUser));
+        assert!(!user.has_flag(UserFlag::Unspecified));
+    }
+
+    #[test]
+    fn can_set_and_clear_flags() {
+        let mut profile = GetProfileResponse {
+            public_flags: UserFlag::Staff as u32,
+            ..Default::default()
+        };
+
+        profile.set_flag(UserFlag::PlusMember, true);
+        profile.set_flag(UserFlag::PlusMember, true);
+
+        assert_eq!(
+            profile.public_flags,
+            UserFlag::Staff as u32 | UserFlag::PlusMember as u32
+        );
+
+        profile.set_flag(UserFlag::Staff, false);
+        profile.set_flag(UserFlag::Verified, false);
+
+        assert_eq!(profile.public_flags, UserFlag::PlusMember as u32);
+        assert!(!profile.has_flag(UserFlag::Staff));
+    }
+}
@@ -0,0 +1,36 @@
+//! Hand-written extensions to the generated code.
+//!
+//! Unlike the rest of `proto-backend`, these modules are not generated by
+//! `buf generate`. They are mirrored to the backend repository together with
+//! the generated code and expect to be mounted next to the generated packages
+//! (including the vendored `google.rpc` package):
+//!
+//! ```ignore
+//! pub mod google {
+//!     pub mod rpc {
+//!         include!("google.rpc.rs");
+//!         include!("google.rpc.serde.rs");
+//!     }
+//! }
+//!
+//! pub mod story_def {
+//!     pub mod v1 {
+//!         include!("story_def.v1.rs");
+//!         include!("story_def.v1.serde.rs");
+//!     }
+//! }
+//!
+//! pub mod extensions;
+//! ```
+
+pub mod auth;
+pub mod connections;
+pub mod enums;
+pub mod errors;
+pub mod flags;
+pub mod policy;
+pub mod ratelimit;
+pub mod status;
+pub mod timestamps;
+pub mod tls;
+pub mod validation;
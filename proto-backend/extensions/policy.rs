@@ -0,0 +1,302 @@
+//! Per-method authorization of the client services.
+//!
+//! The clients authenticate using certificates issued by the internal CA (see
+//! [`super::tls`]). [`PolicyLayer`] derives the [`ClientIdentity`] of the peer
+//! from its certificate and rejects the RPCs that the [`MethodPolicy`] does not
+//! allow the identity to invoke, such as a public facing service calling
+//! `GetLoginActivity`.
+
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tonic::{
+    body::BoxBody,
+    codegen::http,
+    transport::{
+        server::{TcpConnectInfo, TlsConnectInfo},
+        Certificate,
+    },
+    Status,
+};
+use tower::{Layer, Service};
+use x509_parser::{certificate::X509Certificate, extensions::GeneralName, prelude::FromDer};
+
+/// The identity of a client service, which is the first DNS name in the
+/// subject alternative names of its certificate (or the common name of the
+/// subject when absent), e.g. `web.storiny.internal`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ClientIdentity(String);
+
+impl ClientIdentity {
+    /// Derives the identity from a DER encoded certificate, returning `None`
+    /// if the certificate is malformed or does not name the client.
+    ///
+    /// * `certificate` - The certificate of the client.
+    pub fn from_certificate(certificate: &Certificate) -> Option<Self> {
+        let (_, certificate) = X509Certificate::from_der(certificate.get_ref()).ok()?;
+        let dns_name = certificate
+            .subject_alternative_name()
+            .ok()
+            .flatten()
+            .and_then(|extension| {
+                extension
+                    .value
+                    .general_names
+                    .iter()
+                    .find_map(|name| match name {
+                        GeneralName::DNSName(name) => Some(name.to_string()),
+                        _ => None,
+                    })
+            });
+        let name = match dns_name {
+            Some(name) => name,
+            None => certificate
+                .subject()
+                .iter_common_name()
+                .next()?
+                .as_str()
+                .ok()?
+                .to_string(),
+        };
+
+        Some(Self(name))
+    }
+
+    /// Returns the identity of the peer of a request, i.e. the leaf certificate
+    /// presented during the TLS handshake.
+    ///
+    /// * `request` - The request.
+    pub fn from_request<B>(request: &http::Request<B>) -> Option<Self> {
+        let certificates = request
+            .extensions()
+            .get::<TlsConnectInfo<TcpConnectInfo>>()?
+            .peer_certs()?;
+
+        Self::from_certificate(certificates.first()?)
+    }
+
+    /// Returns the identity as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Maps the RPCs to the client identities allowed to invoke them.
+///
+/// The methods without a rule of their own fall back to the default rule. Both
+/// deny every client unless configured.
+///
+/// ```ignore
+/// let policy = MethodPolicy::default()
+///     .allow_by_default(["web.storiny.internal", "worker.storiny.internal"])
+///     .allow("GetLoginActivity", ["web.storiny.internal"])
+///     .allow("GetCredentialSettings", ["web.storiny.internal"]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MethodPolicy {
+    default: HashSet<String>,
+    methods: HashMap<String, HashSet<String>>,
+}
+
+impl MethodPolicy {
+    /// Allows the identities to invoke the methods without a rule of their own.
+    ///
+    /// * `identities` - The client identities.
+    pub fn allow_by_default<I, S>(mut self, identities: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.default.extend(identities.into_iter().map(Into::into));
+        self
+    }
+
+    /// Allows the identities to invoke a method. Only the identities allowed
+    /// here (across calls) can invoke the method, irrespective of the default
+    /// rule.
+    ///
+    /// * `method` - The name of the method, e.g. `GetLoginActivity`.
+    /// * `identities` - The client identities.
+    pub fn allow<I, S>(mut self, method: impl Into<String>, identities: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.methods
+            .entry(method.into())
+            .or_default()
+            .extend(identities.into_iter().map(Into::into));
+        self
+    }
+
+    /// Returns `true` if the identity may invoke the method.
+    ///
+    /// * `identity` - The identity of the client, if any.
+    /// * `method` - The name of the method.
+    pub fn is_allowed(&self, identity: Option<&ClientIdentity>, method: &str) -> bool {
+        let Some(identity) = identity else {
+            return false;
+        };
+
+        self.methods
+            .get(method)
+            .unwrap_or(&self.default)
+            .contains(identity.as_str())
+    }
+}
+
+/// Enforces a [`MethodPolicy`] on a server. The identity of the allowed
+/// clients is inserted into the request extensions.
+///
+/// ```ignore
+/// Server::builder()
+///     .tls_config(settings.server_config())?
+///     .layer(PolicyLayer::new(policy))
+///     .add_service(ApiServiceServer::new(service))
+///     .serve(address)
+///     .await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct PolicyLayer {
+    policy: Arc<MethodPolicy>,
+}
+
+impl PolicyLayer {
+    /// Creates a layer.
+    ///
+    /// * `policy` - The policy to enforce.
+    pub fn new(policy: MethodPolicy) -> Self {
+        Self {
+            policy: Arc::new(policy),
+        }
+    }
+}
+
+impl<S> Layer<S> for PolicyLayer {
+    type Service = PolicyService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        PolicyService {
+            inner,
+            policy: self.policy.clone(),
+        }
+    }
+}
+
+/// The service created by [`PolicyLayer`].
+#[derive(Clone, Debug)]
+pub struct PolicyService<S> {
+    inner: S,
+    policy: Arc<MethodPolicy>,
+}
+
+impl<S, B> Service<http::Request<B>> for PolicyService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<B>) -> Self::Future {
+        let identity = ClientIdentity::from_request(&request);
+        let method = request
+            .uri()
+            .path()
+            .rsplit('/')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+
+        if !self.policy.is_allowed(identity.as_ref(), &method) {
+            tracing::warn!(
+                identity = identity.as_ref().map(ClientIdentity::as_str),
+                method = method.as_str(),
+                "denied an RPC by the method policy"
+            );
+
+            let response = Status::permission_denied(format!(
+                "The client is not allowed to invoke `{method}`"
+            ))
+            .to_http();
+
+            return Box::pin(async move { Ok(response) });
+        }
+
+        if let Some(identity) = identity {
+            request.extensions_mut().insert(identity);
+        }
+
+        Box::pin(self.inner.call(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Peer certificates are DER encoded, despite the constructor name.
+    fn get_certificate(params: rcgen::CertificateParams) -> Certificate {
+        Certificate::from_pem(
+            rcgen::Certificate::from_params(params)
+                .unwrap()
+                .serialize_der()
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn can_derive_the_identity_from_a_certificate() {
+        let certificate = get_certificate(rcgen::CertificateParams::new(vec![
+            "web.storiny.internal".to_string(),
+        ]));
+
+        assert_eq!(
+            ClientIdentity::from_certificate(&certificate)
+                .unwrap()
+                .as_str(),
+            "web.storiny.internal"
+        );
+
+        let mut params = rcgen::CertificateParams::new(vec![]);
+        params
+            .distinguished_name
+            .push(rcgen::DnType::CommonName, "worker.storiny.internal");
+
+        assert_eq!(
+            ClientIdentity::from_certificate(&get_certificate(params))
+                .unwrap()
+                .as_str(),
+            "worker.storiny.internal"
+        );
+        assert_eq!(
+            ClientIdentity::from_certificate(&Certificate::from_pem("invalid")),
+            None
+        );
+    }
+
+    #[test]
+    fn can_apply_the_method_rules() {
+        let web = ClientIdentity("web".to_string());
+        let worker = ClientIdentity("worker".to_string());
+        let policy = MethodPolicy::default()
+            .allow_by_default(["web", "worker"])
+            .allow("GetLoginActivity", ["web"]);
+
+        assert!(policy.is_allowed(Some(&web), "GetStory"));
+        assert!(policy.is_allowed(Some(&worker), "GetStory"));
+        assert!(policy.is_allowed(Some(&web), "GetLoginActivity"));
+        assert!(!policy.is_allowed(Some(&worker), "GetLoginActivity"));
+        assert!(!policy.is_allowed(None, "GetStory"));
+        assert!(!MethodPolicy::default().is_allowed(Some(&web), "GetStory"));
+    }
+}
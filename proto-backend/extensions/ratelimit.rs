@@ -0,0 +1,989 @@
+//! Rate limiting of the `ApiService` RPCs.
+//!
+//! [`RateLimitLayer`] applies token bucket limits per method and per key, such
+//! as the IP address of the visitor or the identifier of the token being
+//! verified. This protects the methods open to brute force attacks, e.g.
+//! guessing tokens using `GetToken`. The rejected calls fail with a
+//! `RESOURCE_EXHAUSTED` status carrying a `google.rpc.RetryInfo`.
+//!
+//! The buckets are kept in memory by default. The servers running behind a
+//! load balancer can share the buckets using a [`RateLimitStore`], in which
+//! case the in-memory buckets are only used while the store is unavailable.
+
+use super::{
+    super::{
+        error_def::v1::{ErrorDetail, ErrorReason},
+        google::rpc::RetryInfo,
+    },
+    auth::SESSION_TOKEN_METADATA_KEY,
+    status::{with_details, Detail},
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use flate2::read::GzDecoder;
+use http_body::{LengthLimitError, Limited};
+use prost::Message;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{HashMap, VecDeque},
+    future::Future,
+    io::Read,
+    net::SocketAddr,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+use tonic::{
+    body::BoxBody,
+    codegen::http,
+    transport::{
+        server::{TcpConnectInfo, TlsConnectInfo},
+        Body,
+    },
+    Status,
+};
+use tower::{Layer, Service};
+
+/// The metadata key carrying the IP address of the visitor, set by the web
+/// server. The address of the peer is used when absent.
+pub const CLIENT_IP_METADATA_KEY: &str = "x-client-ip";
+
+/// The maximum size of a buffered request message, matching the default
+/// maximum decoding message size of tonic.
+const MAX_MESSAGE_SIZE: usize = 4 * 1024 * 1024;
+
+/// The size of the gRPC frame header preceding a message.
+const FRAME_HEADER_SIZE: usize = 5;
+
+/// The default maximum number of buckets kept by the [`MemoryStore`].
+const MAX_BUCKETS: usize = 10_000;
+
+/// The boxed error returned by a [`RateLimitStore`].
+pub type StoreError = Box<dyn std::error::Error + Send + Sync>;
+
+/// A limit of `capacity` calls per `period`. The bucket refills continuously,
+/// so a caller that has exhausted the limit can make another call after
+/// `period / capacity`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RateLimit {
+    capacity: u32,
+    period: Duration,
+}
+
+impl RateLimit {
+    /// Creates a limit.
+    ///
+    /// * `capacity` - The number of calls allowed per period (at least one).
+    /// * `period` - The period.
+    pub fn new(capacity: u32, period: Duration) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            period,
+        }
+    }
+
+    /// Creates a limit of `capacity` calls per second.
+    pub fn per_second(capacity: u32) -> Self {
+        Self::new(capacity, Duration::from_secs(1))
+    }
+
+    /// Creates a limit of `capacity` calls per minute.
+    pub fn per_minute(capacity: u32) -> Self {
+        Self::new(capacity, Duration::from_secs(60))
+    }
+
+    /// Creates a limit of `capacity` calls per hour.
+    pub fn per_hour(capacity: u32) -> Self {
+        Self::new(capacity, Duration::from_secs(60 * 60))
+    }
+
+    /// Returns the number of calls allowed per period.
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// Returns the period.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Returns the time it takes to refill a single token.
+    pub fn refill_interval(&self) -> Duration {
+        self.period / self.capacity
+    }
+}
+
+/// A token bucket of the [`MemoryStore`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    /// Creates a full bucket.
+    ///
+    /// * `limit` - The limit.
+    /// * `now` - The current time.
+    fn new(limit: RateLimit, now: Instant) -> Self {
+        Self {
+            tokens: f64::from(limit.capacity),
+            updated_at: now,
+        }
+    }
+
+    /// Refills the bucket for the time elapsed since the last update.
+    fn refill(&mut self, limit: RateLimit, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at);
+
+        self.tokens = (self.tokens + elapsed.as_secs_f64() / limit.refill_interval().as_secs_f64())
+            .min(f64::from(limit.capacity));
+        self.updated_at = now;
+    }
+
+    /// Takes a token from the bucket, returning the delay until the next token
+    /// when the bucket is empty.
+    ///
+    /// * `limit` - The limit.
+    /// * `now` - The current time.
+    fn acquire(&mut self, limit: RateLimit, now: Instant) -> Result<(), Duration> {
+        self.refill(limit, now);
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return Ok(());
+        }
+
+        Err(limit.refill_interval().mul_f64(1.0 - self.tokens))
+    }
+
+    /// Returns `true` if the bucket has refilled completely, i.e. it can be
+    /// dropped without affecting the limit.
+    ///
+    /// * `limit` - The limit.
+    /// * `now` - The current time.
+    fn is_full(&self, limit: RateLimit, now: Instant) -> bool {
+        let mut bucket = *self;
+        bucket.refill(limit, now);
+        bucket.tokens >= f64::from(limit.capacity)
+    }
+}
+
+/// Stores the buckets of the rate limited keys.
+#[tonic::async_trait]
+pub trait RateLimitStore: Send + Sync + 'static {
+    /// Takes a token from the bucket of a key, returning the delay until the
+    /// next token when the bucket is empty.
+    ///
+    /// * `key` - The key of the bucket.
+    /// * `limit` - The limit of the bucket.
+    async fn acquire(&self, key: &str, limit: RateLimit) -> Result<Option<Duration>, StoreError>;
+}
+
+/// A bucket of the [`MemoryStore`].
+#[derive(Debug)]
+struct Entry {
+    bucket: TokenBucket,
+    limit: RateLimit,
+    /// The latest use of the bucket, matching its position in the recency
+    /// queue.
+    used_at: u64,
+}
+
+/// The buckets of the [`MemoryStore`], evicted in least recently used order.
+#[derive(Debug, Default)]
+struct Buckets {
+    entries: HashMap<String, Entry>,
+    /// The uses of the buckets, oldest first. A bucket used again leaves a
+    /// stale use behind, which is skipped during eviction.
+    recency: VecDeque<(u64, String)>,
+    /// The buckets shared by the new keys while the store is full, per limit.
+    overflow: HashMap<RateLimit, TokenBucket>,
+    uses: u64,
+}
+
+impl Buckets {
+    /// Evicts the least recently used bucket if it has refilled completely,
+    /// i.e. evicting it does not affect its limit. Returns `true` if a bucket
+    /// was evicted.
+    ///
+    /// * `now` - The current time.
+    fn evict(&mut self, now: Instant) -> bool {
+        while let Some((used_at, key)) = self.recency.pop_front() {
+            let Some(entry) = self.entries.get(&key) else {
+                continue;
+            };
+
+            if entry.used_at != used_at {
+                continue;
+            }
+
+            if entry.bucket.is_full(entry.limit, now) {
+                self.entries.remove(&key);
+                return true;
+            }
+
+            self.recency.push_front((used_at, key));
+            break;
+        }
+
+        false
+    }
+}
+
+/// Keeps the buckets in the memory of the server, up to a maximum number of
+/// buckets. Once full, the least recently used bucket is evicted if it has
+/// refilled completely, and the new keys share an overflow bucket otherwise,
+/// so that flooding the store with keys cannot reset the existing limits.
+#[derive(Debug)]
+pub struct MemoryStore {
+    buckets: Mutex<Buckets>,
+    capacity: usize,
+}
+
+impl Default for MemoryStore {
+    fn default() -> Self {
+        Self::new(MAX_BUCKETS)
+    }
+}
+
+impl MemoryStore {
+    /// Creates a store.
+    ///
+    /// * `capacity` - The maximum number of buckets (at least one).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            buckets: Mutex::default(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Takes a token from the bucket of a key at the provided time.
+    ///
+    /// * `key` - The key of the bucket.
+    /// * `limit` - The limit of the bucket.
+    /// * `now` - The current time.
+    pub fn acquire_at(&self, key: &str, limit: RateLimit, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self
+            .buckets
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+
+        if !buckets.entries.contains_key(key)
+            && buckets.entries.len() >= self.capacity
+            && !buckets.evict(now)
+        {
+            return buckets
+                .overflow
+                .entry(limit)
+                .or_insert_with(|| TokenBucket::new(limit, now))
+                .acquire(limit, now);
+        }
+
+        buckets.uses += 1;
+        let used_at = buckets.uses;
+        let entry = buckets
+            .entries
+            .entry(key.to_string())
+            .or_insert_with(|| Entry {
+                bucket: TokenBucket::new(limit, now),
+                limit,
+                used_at,
+            });
+        entry.used_at = used_at;
+        let result = entry.bucket.acquire(limit, now);
+
+        buckets.recency.push_back((used_at, key.to_string()));
+
+        // Drops the stale uses once they outnumber the buckets, which keeps
+        // the queue bounded at a constant amortized cost per use.
+        if buckets.recency.len() > self.capacity * 2 {
+            let Buckets {
+                entries, recency, ..
+            } = &mut *buckets;
+
+            recency.retain(|(used_at, key)| {
+                entries
+                    .get(key)
+                    .is_some_and(|entry| entry.used_at == *used_at)
+            });
+        }
+
+        result
+    }
+}
+
+#[tonic::async_trait]
+impl RateLimitStore for MemoryStore {
+    async fn acquire(&self, key: &str, limit: RateLimit) -> Result<Option<Duration>, StoreError> {
+        Ok(self.acquire_at(key, limit, Instant::now()).err())
+    }
+}
+
+/// Derives the value of a key from a request message.
+type MessageKey = Arc<dyn Fn(&[u8]) -> Option<String> + Send + Sync>;
+
+/// The key of the buckets of a limit. The calls whose key cannot be resolved
+/// (such as anonymous calls for [`RateLimitKey::User`]) are not limited by the
+/// rule, so the sensitive methods should also be limited per IP address.
+#[derive(Clone)]
+pub enum RateLimitKey {
+    /// The IP address of the visitor, from the [`CLIENT_IP_METADATA_KEY`]
+    /// metadata (or the address of the peer when absent).
+    Ip,
+    /// The user, as identified by the session token in the
+    /// [`SESSION_TOKEN_METADATA_KEY`] metadata.
+    User,
+    /// A field of the request message, such as the identifier of a token.
+    /// Created using [`RateLimitKey::message`].
+    Message(MessageKey),
+}
+
+impl RateLimitKey {
+    /// Creates a key from a field of the request message.
+    ///
+    /// ```ignore
+    /// RateLimitKey::message(|request: GetTokenRequest| request.identifier)
+    /// ```
+    ///
+    /// * `field` - Returns the value of the field.
+    pub fn message<M: Message + Default + 'static>(field: fn(M) -> String) -> Self {
+        Self::Message(Arc::new(move |bytes| M::decode(bytes).ok().map(field)))
+    }
+
+    /// Returns `true` if the key is resolved from the request message, which
+    /// requires buffering the request body.
+    fn needs_message(&self) -> bool {
+        matches!(self, Self::Message(_))
+    }
+
+    /// Returns the name of the key, which scopes its buckets.
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Ip => "ip",
+            Self::User => "user",
+            Self::Message(_) => "message",
+        }
+    }
+
+    /// Resolves the value of the key for a request.
+    ///
+    /// * `request` - The request.
+    /// * `message` - The request message, if buffered.
+    fn resolve<B>(&self, request: &http::Request<B>, message: Option<&[u8]>) -> Option<String> {
+        let value = match self {
+            Self::Ip => get_metadata(request, CLIENT_IP_METADATA_KEY)
+                .map(str::to_string)
+                .or_else(|| get_remote_addr(request).map(|addr| addr.ip().to_string())),
+            // Hashed, as the shared store must not hold the credentials.
+            Self::User => get_metadata(request, SESSION_TOKEN_METADATA_KEY).map(hash),
+            Self::Message(field) => field(message?).as_deref().map(hash),
+        }?;
+
+        (!value.is_empty()).then_some(value)
+    }
+}
+
+/// Returns the value of a metadata entry, if present and valid.
+///
+/// * `request` - The request.
+/// * `key` - The metadata key.
+fn get_metadata<'a, B>(request: &'a http::Request<B>, key: &str) -> Option<&'a str> {
+    request
+        .headers()
+        .get(key)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+}
+
+/// Returns the address of the peer of a request.
+///
+/// * `request` - The request.
+fn get_remote_addr<B>(request: &http::Request<B>) -> Option<SocketAddr> {
+    let extensions = request.extensions();
+
+    extensions
+        .get::<TcpConnectInfo>()
+        .and_then(TcpConnectInfo::remote_addr)
+        .or_else(|| {
+            extensions
+                .get::<TlsConnectInfo<TcpConnectInfo>>()
+                .and_then(|info| info.get_ref().remote_addr())
+        })
+}
+
+/// Hashes a secret used as a key.
+///
+/// * `value` - The secret.
+fn hash(value: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(value.as_bytes()))
+}
+
+/// Returns the message of a unary gRPC request body, decompressing it if
+/// required. Returns `None` for malformed bodies and unsupported encodings.
+///
+/// * `body` - The request body.
+/// * `encoding` - The value of the `grpc-encoding` header.
+fn get_message(body: &[u8], encoding: Option<&str>) -> Option<Vec<u8>> {
+    let (&compressed, rest) = body.split_first()?;
+    let length = u32::from_be_bytes(rest.get(..4)?.try_into().ok()?) as usize;
+    let message = rest.get(4..4 + length)?;
+
+    match (compressed, encoding) {
+        (0, _) => Some(message.to_vec()),
+        (1, Some("gzip")) => {
+            let mut decompressed = Vec::new();
+            GzDecoder::new(message)
+                .read_to_end(&mut decompressed)
+                .ok()?;
+            Some(decompressed)
+        }
+        _ => None,
+    }
+}
+
+/// A limit applied to a method.
+#[derive(Clone)]
+struct Rule {
+    key: RateLimitKey,
+    limit: RateLimit,
+}
+
+/// The shared state of the [`RateLimitLayer`].
+#[derive(Default)]
+struct RateLimiter {
+    rules: HashMap<String, Vec<Rule>>,
+    memory: MemoryStore,
+    store: Option<Arc<dyn RateLimitStore>>,
+}
+
+impl RateLimiter {
+    /// Takes a token from the bucket of a key, preferring the shared store.
+    ///
+    /// * `key` - The key of the bucket.
+    /// * `limit` - The limit of the bucket.
+    async fn acquire(&self, key: &str, limit: RateLimit) -> Option<Duration> {
+        if let Some(store) = &self.store {
+            match store.acquire(key, limit).await {
+                Ok(delay) => return delay,
+                Err(error) => {
+                    tracing::warn!(%error, "the rate limit store is unavailable");
+                }
+            }
+        }
+
+        self.memory.acquire_at(key, limit, Instant::now()).err()
+    }
+
+    /// Applies the rules of a method to a request, returning the longest delay
+    /// among the exhausted limits.
+    ///
+    /// * `method` - The name of the method.
+    /// * `rules` - The rules to apply.
+    /// * `request` - The request.
+    /// * `message` - The request message, if buffered.
+    async fn check<B>(
+        &self,
+        method: &str,
+        rules: &[&Rule],
+        request: &http::Request<B>,
+        message: Option<&[u8]>,
+    ) -> Option<Duration> {
+        let mut retry_after = None;
+
+        for rule in rules {
+            let Some(value) = rule.key.resolve(request, message) else {
+                continue;
+            };
+            let key = format!("{method}:{}:{value}", rule.key.name());
+
+            if let Some(delay) = self.acquire(&key, rule.limit).await {
+                retry_after = retry_after.max(Some(delay));
+            }
+        }
+
+        retry_after
+    }
+}
+
+/// Applies rate limits to the `ApiService` methods.
+///
+/// ```ignore
+/// let rate_limit = RateLimitLayer::default()
+///     .limit("GetToken", RateLimitKey::Ip, RateLimit::per_minute(10))
+///     .limit(
+///         "GetToken",
+///         RateLimitKey::message(|request: GetTokenRequest| request.identifier),
+///         RateLimit::per_hour(5),
+///     )
+///     .limit("GetProfile", RateLimitKey::Ip, RateLimit::per_second(10))
+///     .with_store(redis_store);
+///
+/// Server::builder()
+///     .layer(rate_limit)
+///     .add_service(ApiServiceServer::new(service))
+///     .serve(address)
+///     .await?;
+/// ```
+#[derive(Clone, Default)]
+pub struct RateLimitLayer {
+    limiter: Arc<RateLimiter>,
+}
+
+impl RateLimitLayer {
+    /// Limits a method. A method can have several limits, each of which must
+    /// allow the call.
+    ///
+    /// * `method` - The name of the method, e.g. `GetToken`.
+    /// * `key` - The key of the buckets.
+    /// * `limit` - The limit of each bucket.
+    pub fn limit(mut self, method: impl Into<String>, key: RateLimitKey, limit: RateLimit) -> Self {
+        self.limiter_mut()
+            .rules
+            .entry(method.into())
+            .or_default()
+            .push(Rule { key, limit });
+        self
+    }
+
+    /// Shares the buckets using a store. The in-memory buckets are used while
+    /// the store fails.
+    ///
+    /// * `store` - The store.
+    pub fn with_store(mut self, store: impl RateLimitStore) -> Self {
+        self.limiter_mut().store = Some(Arc::new(store));
+        self
+    }
+
+    /// Returns the limiter for configuration.
+    fn limiter_mut(&mut self) -> &mut RateLimiter {
+        Arc::get_mut(&mut self.limiter).expect("the layer is configured before it is cloned")
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitService {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+/// The service created by [`RateLimitLayer`].
+#[derive(Clone)]
+pub struct RateLimitService<S> {
+    inner: S,
+    limiter: Arc<RateLimiter>,
+}
+
+impl<S> Service<http::Request<Body>> for RateLimitService<S>
+where
+    S: Service<http::Request<Body>, Response = http::Response<BoxBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+        // The ready service is used for this call, see `tower::Service`.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let limiter = self.limiter.clone();
+
+        Box::pin(async move {
+            let method = request
+                .uri()
+                .path()
+                .rsplit('/')
+                .next()
+                .unwrap_or_default()
+                .to_string();
+            let Some(rules) = limiter.rules.get(&method) else {
+                return inner.call(request).await;
+            };
+            let (message_rules, rules): (Vec<_>, Vec<_>) =
+                rules.iter().partition(|rule| rule.key.needs_message());
+
+            // The limits resolved from the metadata are applied first, so that
+            // the rejected calls are never buffered.
+            if let Some(delay) = limiter.check(&method, &rules, &request, None).await {
+                tracing::info!(method, ?delay, "rate limited an RPC");
+
+                return Ok(rate_limited(&method, delay).to_http());
+            }
+
+            if message_rules.is_empty() {
+                return inner.call(request).await;
+            }
+
+            // Only the unary methods limited by a message field are buffered,
+            // up to the maximum message size.
+            let (parts, body) = request.into_parts();
+            let body = match hyper::body::to_bytes(Limited::new(
+                body,
+                MAX_MESSAGE_SIZE + FRAME_HEADER_SIZE,
+            ))
+            .await
+            {
+                Ok(body) => body,
+                Err(error) if error.is::<LengthLimitError>() => {
+                    return Ok(Status::resource_exhausted("Request message too large").to_http());
+                }
+                Err(_) => {
+                    return Ok(Status::invalid_argument("Malformed request body").to_http());
+                }
+            };
+            let message = get_message(
+                &body,
+                parts
+                    .headers
+                    .get("grpc-encoding")
+                    .and_then(|value| value.to_str().ok()),
+            );
+            let request = http::Request::from_parts(parts, Body::from(body));
+
+            if let Some(delay) = limiter
+                .check(&method, &message_rules, &request, message.as_deref())
+                .await
+            {
+                tracing::info!(method, ?delay, "rate limited an RPC");
+
+                return Ok(rate_limited(&method, delay).to_http());
+            }
+
+            inner.call(request).await
+        })
+    }
+}
+
+/// Returns the status of a rate limited call.
+///
+/// * `method` - The name of the method.
+/// * `delay` - The delay after which the call can be retried.
+fn rate_limited(method: &str, delay: Duration) -> Status {
+    let reason = ErrorReason::RateLimited;
+    let detail = ErrorDetail {
+        reason: reason as i32,
+        metadata: HashMap::from([("method".to_string(), method.to_string())]),
+    };
+    let retry_info = RetryInfo {
+        retry_delay: Some(pbjson_types::Duration {
+            seconds: delay.as_secs() as i64,
+            nanos: delay.subsec_nanos() as i32,
+        }),
+    };
+
+    with_details(
+        reason.code(),
+        "Too many requests",
+        vec![detail.pack(), retry_info.pack()],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{super::token_def::v1::GetTokenRequest, errors::StatusExt},
+        *,
+    };
+    use std::{
+        convert::Infallible,
+        future::{ready, Ready},
+    };
+    use tonic::Code;
+    use tower::ServiceExt;
+
+    #[derive(Clone)]
+    struct Succeed;
+
+    impl Service<http::Request<Body>> for Succeed {
+        type Response = http::Response<BoxBody>;
+        type Error = Infallible;
+        type Future = Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _: http::Request<Body>) -> Self::Future {
+            ready(Ok(Status::new(Code::Ok, "").to_http()))
+        }
+    }
+
+    fn get_request(
+        method: &str,
+        ip: &str,
+        message: Option<&GetTokenRequest>,
+    ) -> http::Request<Body> {
+        let body = message
+            .map(|message| {
+                let message = message.encode_to_vec();
+                let mut body = vec![0];
+                body.extend((message.len() as u32).to_be_bytes());
+                body.extend(message);
+                body
+            })
+            .unwrap_or_default();
+
+        http::Request::builder()
+            .uri(format!("/api_service.v1.ApiService/{method}"))
+            .header(CLIENT_IP_METADATA_KEY, ip)
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    async fn call(layer: &RateLimitLayer, request: http::Request<Body>) -> Status {
+        let response = layer.layer(Succeed).oneshot(request).await.unwrap();
+
+        Status::from_header_map(response.headers()).unwrap()
+    }
+
+    #[test]
+    fn can_refill_the_bucket() {
+        let limit = RateLimit::per_second(2);
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(limit, now);
+
+        assert!(bucket.acquire(limit, now).is_ok());
+        assert!(bucket.acquire(limit, now).is_ok());
+        assert_eq!(bucket.acquire(limit, now), Err(Duration::from_millis(500)));
+        assert!(bucket
+            .acquire(limit, now + Duration::from_millis(500))
+            .is_ok());
+        assert!(!bucket.is_full(limit, now + Duration::from_millis(500)));
+        assert!(bucket.is_full(limit, now + Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn can_keep_separate_buckets_per_key() {
+        let store = MemoryStore::default();
+        let limit = RateLimit::per_minute(1);
+        let now = Instant::now();
+
+        assert!(store.acquire_at("a", limit, now).is_ok());
+        assert!(store.acquire_at("a", limit, now).is_err());
+        assert!(store.acquire_at("b", limit, now).is_ok());
+    }
+
+    #[test]
+    fn can_evict_the_least_recently_used_full_bucket() {
+        let store = MemoryStore::new(2);
+        let limit = RateLimit::per_minute(1);
+        let now = Instant::now();
+        let later = now + Duration::from_secs(60);
+
+        assert!(store.acquire_at("a", limit, now).is_ok());
+        assert!(store.acquire_at("b", limit, now).is_ok());
+        assert!(store.acquire_at("a", limit, later).is_ok());
+        // `b` is the least recently used full bucket.
+        assert!(store.acquire_at("c", limit, later).is_ok());
+        assert!(store.acquire_at("a", limit, later).is_err());
+        assert!(store.acquire_at("c", limit, later).is_err());
+
+        let buckets = store.buckets.lock().unwrap();
+
+        assert_eq!(buckets.entries.len(), 2);
+        assert!(!buckets.entries.contains_key("b"));
+        assert!(buckets.recency.len() <= 4);
+    }
+
+    #[test]
+    fn can_share_an_overflow_bucket_when_full() {
+        let store = MemoryStore::new(1);
+        let limit = RateLimit::per_minute(1);
+        let now = Instant::now();
+
+        assert!(store.acquire_at("a", limit, now).is_ok());
+        // The bucket of `a` cannot be evicted without resetting its limit.
+        assert!(store.acquire_at("b", limit, now).is_ok());
+        assert!(store.acquire_at("c", limit, now).is_err());
+        assert!(store.acquire_at("a", limit, now).is_err());
+        assert_eq!(store.buckets.lock().unwrap().entries.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn can_limit_per_ip() {
+        let layer = RateLimitLayer::default().limit(
+            "GetProfile",
+            RateLimitKey::Ip,
+            RateLimit::per_minute(1),
+        );
+
+        assert_eq!(
+            call(&layer, get_request("GetProfile", "1.1.1.1", None))
+                .await
+                .code(),
+            Code::Ok
+        );
+
+        let status = call(&layer, get_request("GetProfile", "1.1.1.1", None)).await;
+
+        assert_eq!(status.code(), Code::ResourceExhausted);
+        assert_eq!(status.error_reason(), ErrorReason::RateLimited);
+        assert!(status.retry_info().unwrap().retry_delay.unwrap().seconds > 0);
+
+        // Other visitors and methods are not limited.
+        assert_eq!(
+            call(&layer, get_request("GetProfile", "2.2.2.2", None))
+                .await
+                .code(),
+            Code::Ok
+        );
+        assert_eq!(
+            call(&layer, get_request("GetStory", "1.1.1.1", None))
+                .await
+                .code(),
+            Code::Ok
+        );
+    }
+
+    #[tokio::test]
+    async fn can_limit_per_message_field() {
+        let layer = RateLimitLayer::default().limit(
+            "GetToken",
+            RateLimitKey::message(|request: GetTokenRequest| request.identifier),
+            RateLimit::per_hour(1),
+        );
+        let token = |identifier: &str| GetTokenRequest {
+            identifier: identifier.to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            call(
+                &layer,
+                get_request("GetToken", "1.1.1.1", Some(&token("a")))
+            )
+            .await
+            .code(),
+            Code::Ok
+        );
+        // Guessing the same token from another address.
+        assert_eq!(
+            call(
+                &layer,
+                get_request("GetToken", "2.2.2.2", Some(&token("a")))
+            )
+            .await
+            .code(),
+            Code::ResourceExhausted
+        );
+        assert_eq!(
+            call(
+                &layer,
+                get_request("GetToken", "1.1.1.1", Some(&token("b")))
+            )
+            .await
+            .code(),
+            Code::Ok
+        );
+    }
+
+    #[tokio::test]
+    async fn can_limit_before_buffering_the_body() {
+        let layer = RateLimitLayer::default()
+            .limit("GetToken", RateLimitKey::Ip, RateLimit::per_minute(1))
+            .limit(
+                "GetToken",
+                RateLimitKey::message(|request: GetTokenRequest| request.identifier),
+                RateLimit::per_hour(10),
+            );
+        // A body that never completes.
+        let (_sender, body) = Body::channel();
+        let request = http::Request::builder()
+            .uri("/api_service.v1.ApiService/GetToken")
+            .header(CLIENT_IP_METADATA_KEY, "1.1.1.1")
+            .body(body)
+            .unwrap();
+
+        assert_eq!(
+            call(&layer, get_request("GetToken", "1.1.1.1", None))
+                .await
+                .code(),
+            Code::Ok
+        );
+
+        let status = tokio::time::timeout(Duration::from_secs(1), call(&layer, request))
+            .await
+            .expect("the body is not buffered");
+
+        assert_eq!(status.code(), Code::ResourceExhausted);
+        assert_eq!(status.error_reason(), ErrorReason::RateLimited);
+    }
+
+    #[tokio::test]
+    async fn can_reject_large_messages() {
+        let layer = RateLimitLayer::default().limit(
+            "GetToken",
+            RateLimitKey::message(|request: GetTokenRequest| request.identifier),
+            RateLimit::per_hour(1),
+        );
+        let request = http::Request::builder()
+            .uri("/api_service.v1.ApiService/GetToken")
+            .body(Body::from(vec![
+                0;
+                MAX_MESSAGE_SIZE + FRAME_HEADER_SIZE + 1
+            ]))
+            .unwrap();
+        let status = call(&layer, request).await;
+
+        assert_eq!(status.code(), Code::ResourceExhausted);
+        assert_eq!(status.message(), "Request message too large");
+    }
+
+    #[test]
+    fn can_decode_compressed_messages() {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        let message = b"message";
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(message).unwrap();
+        let compressed = encoder.finish().unwrap();
+        let mut body = vec![1];
+        body.extend((compressed.len() as u32).to_be_bytes());
+        body.extend(compressed);
+
+        assert_eq!(get_message(&body, Some("gzip")).unwrap(), message);
+        assert_eq!(get_message(&body, Some("zstd")), None);
+        assert_eq!(get_message(&body[..3], Some("gzip")), None);
+    }
+
+    struct UnavailableStore;
+
+    #[tonic::async_trait]
+    impl RateLimitStore for UnavailableStore {
+        async fn acquire(&self, _: &str, _: RateLimit) -> Result<Option<Duration>, StoreError> {
+            Err("unavailable".into())
+        }
+    }
+
+    #[tokio::test]
+    async fn can_fall_back_to_the_memory_store() {
+        let layer = RateLimitLayer::default()
+            .limit("GetProfile", RateLimitKey::Ip, RateLimit::per_minute(1))
+            .with_store(UnavailableStore);
+
+        assert_eq!(
+            call(&layer, get_request("GetProfile", "1.1.1.1", None))
+                .await
+                .code(),
+            Code::Ok
+        );
+        assert_eq!(
+            call(&layer, get_request("GetProfile", "1.1.1.1", None))
+                .await
+                .code(),
+            Code::ResourceExhausted
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! Helpers for attaching `google.rpc` error details to a [`tonic::Status`].
+//!
+//! The details are sent as a `google.rpc.Status` in the
+//! `grpc-status-details-bin` trailer, which is how both `tonic` and
+//! `@grpc/grpc-js` expose [`tonic::Status::details`].
+
+use super::super::google::rpc::{self, BadRequest, RetryInfo};
+use pbjson_types::Any;
+use prost::{DecodeError, Message};
+use tonic::{Code, Status};
+
+/// The prefix of the type URL of the packed details.
+pub const TYPE_URL_PREFIX: &str = "type.googleapis.com/";
+
+/// A message that can be packed into the details of a status.
+pub trait Detail: Message + Default {
+    /// The fully qualified name of the message.
+    const NAME: &'static str;
+
+    /// Packs the message into an [`Any`].
+    fn pack(&self) -> Any {
+        Any {
+            type_url: format!("{TYPE_URL_PREFIX}{}", Self::NAME),
+            value: self.encode_to_vec().into(),
+        }
+    }
+
+    /// Unpacks the message from an [`Any`], returning `None` if the type URL
+    /// refers to a different message.
+    ///
+    /// * `any` - The packed message.
+    fn unpack(any: &Any) -> Option<Result<Self, DecodeError>> {
+        (any.type_url.strip_prefix(TYPE_URL_PREFIX) == Some(Self::NAME))
+            .then(|| Self::decode(any.value.clone()))
+    }
+}
+
+impl Detail for BadRequest {
+    const NAME: &'static str = "google.rpc.BadRequest";
+}
+
+impl Detail for RetryInfo {
+    const NAME: &'static str = "google.rpc.RetryInfo";
+}
+
+/// Builds a status carrying the provided details.
+///
+/// * `code` - The status code.
+/// * `message` - The developer-facing error message.
+/// * `details` - The packed details.
+pub fn with_details(code: Code, message: impl Into<String>, details: Vec<Any>) -> Status {
+    let message = message.into();
+    let status = rpc::Status {
+        code: code as i32,
+        message: message.clone(),
+        details,
+    };
+
+    Status::with_details(code, message, status.encode_to_vec().into())
+}
+
+/// Returns the details attached to a status. A status without details (or
+/// with malformed details) yields an empty list.
+///
+/// * `status` - The status.
+pub fn get_details(status: &Status) -> Vec<Any> {
+    if status.details().is_empty() {
+        return Vec::new();
+    }
+
+    rpc::Status::decode(status.details())
+        .map(|status| status.details)
+        .unwrap_or_default()
+}
+
+/// Returns the first detail of the provided type attached to a status.
+///
+/// * `status` - The status.
+pub fn get_detail<D: Detail>(status: &Status) -> Option<D> {
+    get_details(status)
+        .iter()
+        .find_map(|any| D::unpack(any).and_then(Result::ok))
+}
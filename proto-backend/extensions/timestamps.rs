@@ -0,0 +1,281 @@
+//! Transition of the timestamps from RFC 3339 strings to
+//! `google.protobuf.Timestamp`.
+//!
+//! Each string timestamp field is deprecated in favour of a `Timestamp` field
+//! suffixed with `_ts` under a new tag, e.g. `created_at` and `created_at_ts`.
+//! Until every client has moved to the new fields, the handlers accept either
+//! field using [`resolve_timestamp`] and call
+//! [`FillTimestamps::fill_timestamps`] on the responses so that both are set.
+//! Messages that never had a released string field use `Timestamp` fields
+//! directly.
+
+use super::super::{
+    blog_def::v1::GetBlogResponse,
+    comment_def::v1::GetCommentResponse,
+    connection_def::v1::ConnectionSetting,
+    connection_settings_def::v1::GetConnectionSettingsResponse,
+    login_activity_def::v1::{GetLoginActivityResponse, Login},
+    profile_def::v1::GetProfileResponse,
+    story_def::v1::{Draft, GetDraftsInfoResponse, GetStoryMetadataResponse, GetStoryResponse},
+    tag_def::v1::GetTagResponse,
+    user_def::v1::{BareStatus, ExtendedStatus, ExtendedUser, SetStatusResponse},
+};
+use chrono::{DateTime, SecondsFormat, Utc};
+use pbjson_types::Timestamp;
+
+/// Formats a timestamp as the deprecated string fields, e.g.
+/// `2026-01-15T00:00:00.000Z`. Returns `None` if the timestamp is out of range.
+///
+/// * `timestamp` - The timestamp.
+pub fn format_timestamp(timestamp: &Timestamp) -> Option<String> {
+    DateTime::<Utc>::try_from(timestamp.clone())
+        .ok()
+        .map(|value| value.to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+/// Parses an RFC 3339 string, returning `None` if it is malformed.
+///
+/// * `value` - The string.
+pub fn parse_timestamp(value: &str) -> Option<Timestamp> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|value| value.with_timezone(&Utc).into())
+}
+
+/// Resolves a timestamp sent in either of its fields, preferring the
+/// `Timestamp` field. An empty string is treated as omitted.
+///
+/// * `timestamp` - The `Timestamp` field.
+/// * `value` - The deprecated string field.
+pub fn resolve_timestamp(timestamp: Option<&Timestamp>, value: Option<&str>) -> Option<Timestamp> {
+    match timestamp {
+        Some(timestamp) => Some(timestamp.clone()),
+        None => value
+            .filter(|value| !value.is_empty())
+            .and_then(parse_timestamp),
+    }
+}
+
+/// A message carrying the deprecated string timestamps.
+pub trait FillTimestamps {
+    /// Sets both fields of every timestamp from whichever of the two is set,
+    /// preferring the `Timestamp` field, including in the nested messages.
+    fn fill_timestamps(&mut self);
+
+    /// Returns the message with both fields of every timestamp set.
+    fn with_timestamps(mut self) -> Self
+    where
+        Self: Sized,
+    {
+        self.fill_timestamps();
+        self
+    }
+}
+
+impl<T: FillTimestamps> FillTimestamps for Option<T> {
+    fn fill_timestamps(&mut self) {
+        if let Some(message) = self {
+            message.fill_timestamps();
+        }
+    }
+}
+
+impl<T: FillTimestamps> FillTimestamps for Vec<T> {
+    fn fill_timestamps(&mut self) {
+        self.iter_mut().for_each(FillTimestamps::fill_timestamps);
+    }
+}
+
+/// A deprecated string timestamp field, which is either required or optional.
+trait StringField {
+    fn get(&self) -> Option<&str>;
+
+    fn set(&mut self, value: String);
+}
+
+impl StringField for String {
+    fn get(&self) -> Option<&str> {
+        Some(self.as_str())
+    }
+
+    fn set(&mut self, value: String) {
+        *self = value;
+    }
+}
+
+impl StringField for Option<String> {
+    fn get(&self) -> Option<&str> {
+        self.as_deref()
+    }
+
+    fn set(&mut self, value: String) {
+        *self = Some(value);
+    }
+}
+
+/// Sets both fields of a timestamp. A malformed string is left as is when the
+/// `Timestamp` field is omitted.
+///
+/// * `value` - The deprecated string field.
+/// * `timestamp` - The `Timestamp` field.
+fn fill(value: &mut impl StringField, timestamp: &mut Option<Timestamp>) {
+    let Some(resolved) = resolve_timestamp(timestamp.as_ref(), value.get()) else {
+        return;
+    };
+
+    if let Some(formatted) = format_timestamp(&resolved) {
+        value.set(formatted);
+    }
+
+    *timestamp = Some(resolved);
+}
+
+/// Implements [`FillTimestamps`] for the messages with the listed pairs of
+/// string and `Timestamp` fields, followed by their nested messages.
+macro_rules! fill_timestamps {
+    ($($message:ty {
+        $($field:ident => $timestamp:ident),* $(,)?
+        $(; $($nested:ident),* $(,)?)?
+    })*) => {
+        $(impl FillTimestamps for $message {
+            #[allow(deprecated)]
+            fn fill_timestamps(&mut self) {
+                $(fill(&mut self.$field, &mut self.$timestamp);)*
+                $($(self.$nested.fill_timestamps();)*)?
+            }
+        })*
+    };
+}
+
+fill_timestamps! {
+    GetBlogResponse { created_at => created_at_ts }
+    GetCommentResponse {
+        edited_at => edited_at_ts,
+        created_at => created_at_ts,
+        deleted_at => deleted_at_ts,
+    }
+    ConnectionSetting { created_at => created_at_ts }
+    GetConnectionSettingsResponse { ; connections }
+    Login { created_at => created_at_ts }
+    GetLoginActivityResponse { ; recent, logins }
+    GetProfileResponse { created_at => created_at_ts; status }
+    Draft {
+        created_at => created_at_ts,
+        edited_at => edited_at_ts,
+    }
+    GetDraftsInfoResponse { ; latest_draft }
+    GetStoryResponse {
+        created_at => created_at_ts,
+        edited_at => edited_at_ts,
+        published_at => published_at_ts,
+        first_published_at => first_published_at_ts,
+        deleted_at => deleted_at_ts;
+        user
+    }
+    GetStoryMetadataResponse {
+        created_at => created_at_ts,
+        edited_at => edited_at_ts,
+        published_at => published_at_ts,
+        first_published_at => first_published_at_ts,
+        deleted_at => deleted_at_ts,
+    }
+    GetTagResponse { created_at => created_at_ts }
+    BareStatus { expires_at => expires_at_ts }
+    ExtendedStatus { expires_at => expires_at_ts }
+    SetStatusResponse { ; status }
+    ExtendedUser { created_at => created_at_ts; status }
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use super::*;
+
+    const VALUE: &str = "2026-01-15T00:00:00.000Z";
+    const TIMESTAMP: Timestamp = Timestamp {
+        seconds: 1_768_435_200,
+        nanos: 0,
+    };
+
+    #[test]
+    fn can_format_and_parse_timestamps() {
+        assert_eq!(format_timestamp(&TIMESTAMP).as_deref(), Some(VALUE));
+        assert_eq!(parse_timestamp(VALUE), Some(TIMESTAMP));
+        assert_eq!(
+            parse_timestamp("2026-01-15T05:30:00+05:30"),
+            Some(TIMESTAMP)
+        );
+        assert_eq!(parse_timestamp("invalid"), None);
+    }
+
+    #[test]
+    fn can_resolve_either_field() {
+        let other = Timestamp {
+            seconds: 0,
+            nanos: 0,
+        };
+
+        assert_eq!(resolve_timestamp(None, Some(VALUE)), Some(TIMESTAMP));
+        assert_eq!(
+            resolve_timestamp(Some(&other), Some(VALUE)),
+            Some(other.clone())
+        );
+        assert_eq!(resolve_timestamp(None, Some("")), None);
+        assert_eq!(resolve_timestamp(None, None), None);
+    }
+
+    #[test]
+    fn can_fill_both_fields() {
+        let comment = GetCommentResponse {
+            created_at: VALUE.to_string(),
+            edited_at_ts: Some(TIMESTAMP),
+            ..Default::default()
+        }
+        .with_timestamps();
+
+        assert_eq!(comment.created_at_ts, Some(TIMESTAMP));
+        assert_eq!(comment.edited_at.as_deref(), Some(VALUE));
+        assert_eq!(comment.deleted_at, None);
+        assert_eq!(comment.deleted_at_ts, None);
+    }
+
+    #[test]
+    fn can_fill_the_nested_messages() {
+        let mut logins = vec![Login {
+            created_at: VALUE.to_string(),
+            ..Default::default()
+        }];
+        let activity = GetLoginActivityResponse {
+            recent: Some(Login {
+                created_at_ts: Some(TIMESTAMP),
+                ..Default::default()
+            }),
+            logins: logins.clone(),
+            ..Default::default()
+        }
+        .with_timestamps();
+        let story = GetStoryResponse {
+            created_at_ts: Some(TIMESTAMP),
+            user: Some(ExtendedUser {
+                created_at: VALUE.to_string(),
+                status: Some(BareStatus {
+                    expires_at_ts: Some(TIMESTAMP),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+        .with_timestamps();
+        let user = story.user.unwrap();
+
+        logins.fill_timestamps();
+
+        assert_eq!(logins[0].created_at_ts, Some(TIMESTAMP));
+        assert_eq!(activity.recent.unwrap().created_at, VALUE);
+        assert_eq!(activity.logins[0].created_at_ts, Some(TIMESTAMP));
+        assert_eq!(story.created_at, VALUE);
+        assert_eq!(user.created_at_ts, Some(TIMESTAMP));
+        assert_eq!(user.status.unwrap().expires_at.as_deref(), Some(VALUE));
+    }
+}
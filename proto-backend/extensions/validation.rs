@@ -0,0 +1,417 @@
+//! Validation of the requests, mainly of their enumeration fields.
+//!
+//! Every enum in this API reserves `0` for `*_UNSPECIFIED`, which is also what
+//! `prost` decodes an omitted field to. The handlers call
+//! [`Validate::validate`] before touching the request so that an omitted or
+//! unknown value is rejected with a consistent `INVALID_ARGUMENT` status
+//! carrying a `google.rpc.BadRequest` detail, instead of being treated as the
+//! default variant.
+
+use super::{
+    super::{
+        account_def::v1::ScheduleAccountDeletionRequest,
+        asset_def::v1::{SetAssetRatingRequest, UploadImageMetadata},
+        connection_settings_def::v1::AddConnectionRequest,
+        credential_settings_def::v1::{
+            LinkLoginProviderRequest, UnlinkLoginProviderRequest, VerifyMfaCodeRequest,
+        },
+        google::rpc::{bad_request::FieldViolation, BadRequest},
+        notification_def::v1::MarkNotificationsReadRequest,
+        privacy_settings_def::v1::UpdatePrivacySettingsRequest,
+        report_def::v1::{ListReportsRequest, ReportContentRequest, ResolveReportRequest},
+        story_def::v1::{
+            ExportStoryRequest, ImportStoriesRequest, ImportStoryRequest, StoryImportFormat,
+        },
+        token_def::v1::GetTokenRequest,
+        user_def::v1::{GrantUserFlagRequest, RevokeUserFlagRequest, SetStatusRequest},
+    },
+    enums::UnknownEnumValue,
+    status::{with_details, Detail},
+};
+use tonic::{Code, Status};
+
+/// A request that can be validated before it is handled.
+pub trait Validate {
+    /// Returns an `INVALID_ARGUMENT` status describing every invalid field.
+    #[allow(clippy::result_large_err)]
+    fn validate(&self) -> Result<(), Status>;
+}
+
+/// Collects the field violations of a request.
+#[derive(Debug, Default)]
+pub struct Violations(Vec<FieldViolation>);
+
+impl Violations {
+    /// Records a violation.
+    ///
+    /// * `field` - The path of the field, e.g. `settings.incoming_blog_requests`.
+    /// * `description` - Why the field is invalid.
+    pub fn add(&mut self, field: impl Into<String>, description: impl Into<String>) {
+        self.0.push(FieldViolation {
+            field: field.into(),
+            description: description.into(),
+        });
+    }
+
+    /// Records a violation if an enumeration field holds an unknown value or
+    /// `*_UNSPECIFIED`. Returns the value when it is valid.
+    ///
+    /// * `field` - The path of the field.
+    /// * `value` - The result of the `try_*` getter of the field.
+    pub fn specified<E>(&mut self, field: &str, value: Result<E, UnknownEnumValue>) -> Option<E>
+    where
+        E: Copy + Into<i32>,
+    {
+        match value {
+            Ok(value) if value.into() != 0 => Some(value),
+            Ok(_) => {
+                self.add(field, "must be specified");
+                None
+            }
+            Err(error) => {
+                self.add(field, format!("unknown value {}", error.value));
+                None
+            }
+        }
+    }
+
+    /// Like [`Violations::specified`], for optional enumeration fields that are
+    /// valid when unset.
+    ///
+    /// * `field` - The path of the field.
+    /// * `value` - The result of the `try_*` getter of the field.
+    pub fn specified_if_set<E>(
+        &mut self,
+        field: &str,
+        value: Result<Option<E>, UnknownEnumValue>,
+    ) -> Option<E>
+    where
+        E: Copy + Into<i32>,
+    {
+        match value.transpose() {
+            Some(value) => self.specified(field, value),
+            None => None,
+        }
+    }
+
+    /// Returns the `INVALID_ARGUMENT` status for the recorded violations, if
+    /// any.
+    #[allow(clippy::result_large_err)]
+    pub fn into_result(self) -> Result<(), Status> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(invalid_argument(self.0))
+        }
+    }
+}
+
+/// Builds an `INVALID_ARGUMENT` status carrying a `google.rpc.BadRequest`
+/// detail.
+///
+/// * `field_violations` - The invalid fields.
+pub fn invalid_argument(field_violations: Vec<FieldViolation>) -> Status {
+    let message = field_violations
+        .iter()
+        .map(|violation| format!("`{}` {}", violation.field, violation.description))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    with_details(
+        Code::InvalidArgument,
+        format!("Invalid request: {message}"),
+        vec![BadRequest { field_violations }.pack()],
+    )
+}
+
+/// Implements [`Validate`] for the requests whose only constraint is that the
+/// listed enumeration fields are specified.
+macro_rules! validate_specified {
+    ($($request:ty { $($field:literal => $getter:ident),* $(,)? })*) => {
+        $(impl Validate for $request {
+            fn validate(&self) -> Result<(), Status> {
+                let mut violations = Violations::default();
+                $(violations.specified($field, self.$getter());)*
+                violations.into_result()
+            }
+        })*
+    };
+}
+
+validate_specified! {
+    ScheduleAccountDeletionRequest { "story_disposition" => try_story_disposition }
+    UploadImageMetadata {
+        "purpose" => try_purpose,
+        "rating" => try_rating,
+    }
+    SetAssetRatingRequest { "rating" => try_rating }
+    AddConnectionRequest { "provider" => try_provider }
+    UnlinkLoginProviderRequest { "provider" => try_provider }
+    ReportContentRequest {
+        "entity_type" => try_entity_type,
+        "reason" => try_reason,
+    }
+    ResolveReportRequest { "resolution" => try_resolution }
+    ImportStoriesRequest { "format" => try_format }
+    ExportStoryRequest { "format" => try_format }
+    GetTokenRequest { "type" => try_type }
+    SetStatusRequest {
+        "duration" => try_duration,
+        "visibility" => try_visibility,
+    }
+    GrantUserFlagRequest { "flag" => try_flag }
+    RevokeUserFlagRequest { "flag" => try_flag }
+}
+
+impl Validate for ListReportsRequest {
+    fn validate(&self) -> Result<(), Status> {
+        let mut violations = Violations::default();
+        violations.specified_if_set("entity_type", self.try_entity_type());
+        violations.into_result()
+    }
+}
+
+impl Validate for ImportStoryRequest {
+    fn validate(&self) -> Result<(), Status> {
+        let mut violations = Violations::default();
+
+        if let Some(format) = violations.specified("format", self.try_format()) {
+            if !matches!(
+                format,
+                StoryImportFormat::Markdown | StoryImportFormat::Html
+            ) {
+                violations.add("format", "archives must be imported using `ImportStories`");
+            }
+        }
+
+        violations.into_result()
+    }
+}
+
+impl Validate for MarkNotificationsReadRequest {
+    /// Marking every notification as read cannot be undone, so it must be
+    /// requested explicitly rather than implied by an empty selection.
+    fn validate(&self) -> Result<(), Status> {
+        let mut violations = Violations::default();
+
+        match (self.all, self.notification_ids.is_empty()) {
+            (false, true) => {
+                violations.add("notification_ids", "must not be empty unless `all` is set")
+            }
+            (true, false) => violations.add("notification_ids", "must be empty when `all` is set"),
+            _ => {}
+        }
+
+        violations.into_result()
+    }
+}
+
+impl Validate for LinkLoginProviderRequest {
+    /// The ID token is only accepted if it carries the nonce of the
+    /// authorization request, which prevents a token from being replayed.
+    fn validate(&self) -> Result<(), Status> {
+        let mut violations = Violations::default();
+        violations.specified("provider", self.try_provider());
+
+        if self.nonce.is_empty() {
+            violations.add("nonce", "must not be empty");
+        }
+
+        violations.into_result()
+    }
+}
+
+impl Validate for VerifyMfaCodeRequest {
+    /// The code is only checked against a challenge issued for a login whose
+    /// first factor has been verified.
+    fn validate(&self) -> Result<(), Status> {
+        let mut violations = Violations::default();
+
+        if self.challenge_token.is_empty() {
+            violations.add("challenge_token", "must be issued by `BeginMfaChallenge`");
+        }
+
+        if self.code.is_empty() {
+            violations.add("code", "must not be empty");
+        }
+
+        violations.into_result()
+    }
+}
+
+impl Validate for UpdatePrivacySettingsRequest {
+    /// Only the fields listed in `update_mask` are validated, as the others are
+    /// left untouched.
+    fn validate(&self) -> Result<(), Status> {
+        let mut violations = Violations::default();
+        let settings = self.settings.clone().unwrap_or_default();
+        let paths = self
+            .update_mask
+            .as_ref()
+            .map(|mask| mask.paths.as_slice())
+            .unwrap_or_default();
+
+        for path in paths {
+            let field = format!("settings.{path}");
+
+            match path.as_str() {
+                "incoming_friend_requests" => {
+                    violations.specified(&field, settings.try_incoming_friend_requests());
+                }
+                "incoming_collaboration_requests" => {
+                    violations.specified(&field, settings.try_incoming_collaboration_requests());
+                }
+                "incoming_blog_requests" => {
+                    violations.specified(&field, settings.try_incoming_blog_requests());
+                }
+                "following_list_visibility" => {
+                    violations.specified(&field, settings.try_following_list_visibility());
+                }
+                "friend_list_visibility" => {
+                    violations.specified(&field, settings.try_friend_list_visibility());
+                }
+                _ => {}
+            }
+        }
+
+        violations.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{
+            super::{
+                credential_settings_def::v1::LoginProvider,
+                privacy_settings_def::v1::GetPrivacySettingsResponse, token_def::v1::TokenType,
+            },
+            status::get_detail,
+        },
+        *,
+    };
+    use pbjson_types::FieldMask;
+
+    #[test]
+    fn can_reject_an_implicit_mark_all_read() {
+        let request = MarkNotificationsReadRequest::default();
+
+        assert_eq!(
+            request.validate().unwrap_err().code(),
+            Code::InvalidArgument
+        );
+        assert!(MarkNotificationsReadRequest {
+            all: true,
+            ..Default::default()
+        }
+        .validate()
+        .is_ok());
+        assert!(MarkNotificationsReadRequest {
+            notification_ids: vec!["1".to_string()],
+            ..Default::default()
+        }
+        .validate()
+        .is_ok());
+    }
+
+    #[test]
+    fn can_reject_mfa_codes_without_a_challenge() {
+        let request = VerifyMfaCodeRequest {
+            code: "123456".to_string(),
+            ..Default::default()
+        };
+        let status = request.validate().unwrap_err();
+
+        assert_eq!(
+            get_detail::<BadRequest>(&status).unwrap().field_violations[0].field,
+            "challenge_token"
+        );
+    }
+
+    #[test]
+    fn can_reject_login_providers_linked_without_a_nonce() {
+        let mut request = LinkLoginProviderRequest {
+            code: "code".to_string(),
+            ..Default::default()
+        };
+        request.set_provider(LoginProvider::Google);
+        let status = request.validate().unwrap_err();
+
+        assert_eq!(
+            get_detail::<BadRequest>(&status).unwrap().field_violations[0].field,
+            "nonce"
+        );
+
+        request.nonce = "nonce".to_string();
+
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn can_accept_a_specified_value() {
+        let mut request = GetTokenRequest::default();
+        request.set_type(TokenType::PasswordReset);
+
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn can_reject_unspecified_and_unknown_values() {
+        let request = SetStatusRequest {
+            duration: 0,
+            visibility: 42,
+            ..Default::default()
+        };
+        let status = request.validate().unwrap_err();
+        let details = get_detail::<BadRequest>(&status).unwrap();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(
+            details.field_violations,
+            vec![
+                FieldViolation {
+                    field: "duration".to_string(),
+                    description: "must be specified".to_string(),
+                },
+                FieldViolation {
+                    field: "visibility".to_string(),
+                    description: "unknown value 42".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn can_reject_archives_in_import_story() {
+        let mut request = ImportStoryRequest::default();
+        request.set_format(StoryImportFormat::MediumArchive);
+
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn can_validate_only_the_masked_privacy_settings() {
+        let request = UpdatePrivacySettingsRequest {
+            update_mask: Some(FieldMask {
+                paths: vec!["is_private_account".to_string()],
+            }),
+            settings: Some(GetPrivacySettingsResponse::default()),
+            ..Default::default()
+        };
+
+        assert!(request.validate().is_ok());
+
+        let request = UpdatePrivacySettingsRequest {
+            update_mask: Some(FieldMask {
+                paths: vec!["friend_list_visibility".to_string()],
+            }),
+            ..request
+        };
+        let details = get_detail::<BadRequest>(&request.validate().unwrap_err()).unwrap();
+
+        assert_eq!(
+            details.field_violations[0].field,
+            "settings.friend_list_visibility"
+        );
+    }
+}
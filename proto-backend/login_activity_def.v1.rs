@@ -30,8 +30,29 @@ pub struct Login {
     pub domain: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(bool, tag="5")]
     pub is_active: bool,
+    #[deprecated]
     #[prost(string, tag="6")]
     pub created_at: ::prost::alloc::string::String,
+    /// Timestamps
+    #[prost(message, optional, tag="7")]
+    pub created_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+// Suspicious login alerts
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LoginAlert {
+    #[prost(string, tag="1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub login_id: ::prost::alloc::string::String,
+    #[prost(enumeration="LoginAlertReason", repeated, tag="3")]
+    pub reasons: ::prost::alloc::vec::Vec<i32>,
+    /// Only sent when `mail_login_activity` is enabled
+    #[prost(bool, tag="4")]
+    pub mail_sent: bool,
+    #[prost(message, optional, tag="5")]
+    pub created_at: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -50,6 +71,8 @@ pub struct GetLoginActivityResponse {
     pub recent: ::core::option::Option<Login>,
     #[prost(message, repeated, tag="2")]
     pub logins: ::prost::alloc::vec::Vec<Login>,
+    #[prost(message, repeated, tag="3")]
+    pub alerts: ::prost::alloc::vec::Vec<LoginAlert>,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -86,4 +109,36 @@ impl DeviceType {
         }
     }
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum LoginAlertReason {
+    Unspecified = 0,
+    NewDeviceType = 1,
+    ImpossibleTravel = 2,
+    UnusualTime = 3,
+}
+impl LoginAlertReason {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            LoginAlertReason::Unspecified => "LOGIN_ALERT_REASON_UNSPECIFIED",
+            LoginAlertReason::NewDeviceType => "LOGIN_ALERT_REASON_NEW_DEVICE_TYPE",
+            LoginAlertReason::ImpossibleTravel => "LOGIN_ALERT_REASON_IMPOSSIBLE_TRAVEL",
+            LoginAlertReason::UnusualTime => "LOGIN_ALERT_REASON_UNUSUAL_TIME",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LOGIN_ALERT_REASON_UNSPECIFIED" => Some(Self::Unspecified),
+            "LOGIN_ALERT_REASON_NEW_DEVICE_TYPE" => Some(Self::NewDeviceType),
+            "LOGIN_ALERT_REASON_IMPOSSIBLE_TRAVEL" => Some(Self::ImpossibleTravel),
+            "LOGIN_ALERT_REASON_UNUSUAL_TIME" => Some(Self::UnusualTime),
+            _ => None,
+        }
+    }
+}
 // @@protoc_insertion_point(module)
@@ -315,6 +315,9 @@ impl serde::Serialize for GetLoginActivityResponse {
         if !self.logins.is_empty() {
             len += 1;
         }
+        if !self.alerts.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("login_activity_def.v1.GetLoginActivityResponse", len)?;
         if let Some(v) = self.recent.as_ref() {
             struct_ser.serialize_field("recent", v)?;
@@ -322,6 +325,9 @@ impl serde::Serialize for GetLoginActivityResponse {
         if !self.logins.is_empty() {
             struct_ser.serialize_field("logins", &self.logins)?;
         }
+        if !self.alerts.is_empty() {
+            struct_ser.serialize_field("alerts", &self.alerts)?;
+        }
         struct_ser.end()
     }
 }
@@ -334,12 +340,14 @@ impl<'de> serde::Deserialize<'de> for GetLoginActivityResponse {
         const FIELDS: &[&str] = &[
             "recent",
             "logins",
+            "alerts",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Recent,
             Logins,
+            Alerts,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -363,6 +371,7 @@ impl<'de> serde::Deserialize<'de> for GetLoginActivityResponse {
                         match value {
                             "recent" => Ok(GeneratedField::Recent),
                             "logins" => Ok(GeneratedField::Logins),
+                            "alerts" => Ok(GeneratedField::Alerts),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -384,6 +393,7 @@ impl<'de> serde::Deserialize<'de> for GetLoginActivityResponse {
             {
                 let mut recent__ = None;
                 let mut logins__ = None;
+                let mut alerts__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Recent => {
@@ -398,11 +408,18 @@ impl<'de> serde::Deserialize<'de> for GetLoginActivityResponse {
                             }
                             logins__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Alerts => {
+                            if alerts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("alerts"));
+                            }
+                            alerts__ = Some(map.next_value()?);
+                        }
                     }
                 }
                 Ok(GetLoginActivityResponse {
                     recent: recent__,
                     logins: logins__.unwrap_or_default(),
+                    alerts: alerts__.unwrap_or_default(),
                 })
             }
         }
@@ -565,6 +582,9 @@ impl serde::Serialize for Login {
         if !self.created_at.is_empty() {
             len += 1;
         }
+        if self.created_at_ts.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("login_activity_def.v1.Login", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
@@ -584,6 +604,9 @@ impl serde::Serialize for Login {
         if !self.created_at.is_empty() {
             struct_ser.serialize_field("createdAt", &self.created_at)?;
         }
+        if let Some(v) = self.created_at_ts.as_ref() {
+            struct_ser.serialize_field("createdAtTs", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -602,6 +625,8 @@ impl<'de> serde::Deserialize<'de> for Login {
             "isActive",
             "created_at",
             "createdAt",
+            "created_at_ts",
+            "createdAtTs",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -612,6 +637,7 @@ impl<'de> serde::Deserialize<'de> for Login {
             Domain,
             IsActive,
             CreatedAt,
+            CreatedAtTs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -639,6 +665,7 @@ impl<'de> serde::Deserialize<'de> for Login {
                             "domain" => Ok(GeneratedField::Domain),
                             "isActive" | "is_active" => Ok(GeneratedField::IsActive),
                             "createdAt" | "created_at" => Ok(GeneratedField::CreatedAt),
+                            "createdAtTs" | "created_at_ts" => Ok(GeneratedField::CreatedAtTs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -664,6 +691,7 @@ impl<'de> serde::Deserialize<'de> for Login {
                 let mut domain__ = None;
                 let mut is_active__ = None;
                 let mut created_at__ = None;
+                let mut created_at_ts__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -702,6 +730,12 @@ impl<'de> serde::Deserialize<'de> for Login {
                             }
                             created_at__ = Some(map.next_value()?);
                         }
+                        GeneratedField::CreatedAtTs => {
+                            if created_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAtTs"));
+                            }
+                            created_at_ts__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(Login {
@@ -711,9 +745,255 @@ impl<'de> serde::Deserialize<'de> for Login {
                     domain: domain__,
                     is_active: is_active__.unwrap_or_default(),
                     created_at: created_at__.unwrap_or_default(),
+                    created_at_ts: created_at_ts__,
                 })
             }
         }
         deserializer.deserialize_struct("login_activity_def.v1.Login", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for LoginAlert {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if !self.login_id.is_empty() {
+            len += 1;
+        }
+        if !self.reasons.is_empty() {
+            len += 1;
+        }
+        if self.mail_sent {
+            len += 1;
+        }
+        if self.created_at.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("login_activity_def.v1.LoginAlert", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if !self.login_id.is_empty() {
+            struct_ser.serialize_field("loginId", &self.login_id)?;
+        }
+        if !self.reasons.is_empty() {
+            let v = self.reasons.iter().cloned().map(|v| {
+                LoginAlertReason::from_i32(v)
+                    .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", v)))
+                }).collect::<Result<Vec<_>, _>>()?;
+            struct_ser.serialize_field("reasons", &v)?;
+        }
+        if self.mail_sent {
+            struct_ser.serialize_field("mailSent", &self.mail_sent)?;
+        }
+        if let Some(v) = self.created_at.as_ref() {
+            struct_ser.serialize_field("createdAt", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LoginAlert {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "login_id",
+            "loginId",
+            "reasons",
+            "mail_sent",
+            "mailSent",
+            "created_at",
+            "createdAt",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            LoginId,
+            Reasons,
+            MailSent,
+            CreatedAt,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "loginId" | "login_id" => Ok(GeneratedField::LoginId),
+                            "reasons" => Ok(GeneratedField::Reasons),
+                            "mailSent" | "mail_sent" => Ok(GeneratedField::MailSent),
+                            "createdAt" | "created_at" => Ok(GeneratedField::CreatedAt),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LoginAlert;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct login_activity_def.v1.LoginAlert")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<LoginAlert, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut login_id__ = None;
+                let mut reasons__ = None;
+                let mut mail_sent__ = None;
+                let mut created_at__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::LoginId => {
+                            if login_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("loginId"));
+                            }
+                            login_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Reasons => {
+                            if reasons__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reasons"));
+                            }
+                            reasons__ = Some(map.next_value::<Vec<LoginAlertReason>>()?.into_iter().map(|x| x as i32).collect());
+                        }
+                        GeneratedField::MailSent => {
+                            if mail_sent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("mailSent"));
+                            }
+                            mail_sent__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::CreatedAt => {
+                            if created_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAt"));
+                            }
+                            created_at__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(LoginAlert {
+                    id: id__.unwrap_or_default(),
+                    login_id: login_id__.unwrap_or_default(),
+                    reasons: reasons__.unwrap_or_default(),
+                    mail_sent: mail_sent__.unwrap_or_default(),
+                    created_at: created_at__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("login_activity_def.v1.LoginAlert", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LoginAlertReason {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => 0,
+            Self::NewDeviceType => 1,
+            Self::ImpossibleTravel => 2,
+            Self::UnusualTime => 3,
+        };
+        serializer.serialize_i32(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for LoginAlertReason {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "LOGIN_ALERT_REASON_UNSPECIFIED",
+            "LOGIN_ALERT_REASON_NEW_DEVICE_TYPE",
+            "LOGIN_ALERT_REASON_IMPOSSIBLE_TRAVEL",
+            "LOGIN_ALERT_REASON_UNUSUAL_TIME",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LoginAlertReason;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(LoginAlertReason::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(LoginAlertReason::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "LOGIN_ALERT_REASON_UNSPECIFIED" => Ok(LoginAlertReason::Unspecified),
+                    "LOGIN_ALERT_REASON_NEW_DEVICE_TYPE" => Ok(LoginAlertReason::NewDeviceType),
+                    "LOGIN_ALERT_REASON_IMPOSSIBLE_TRAVEL" => Ok(LoginAlertReason::ImpossibleTravel),
+                    "LOGIN_ALERT_REASON_UNUSUAL_TIME" => Ok(LoginAlertReason::UnusualTime),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
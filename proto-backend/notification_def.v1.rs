@@ -0,0 +1,301 @@
+// @generated
+// Payloads, one for each category in `GetNotificationSettingsResponse`
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeaturesAndUpdatesPayload {
+    #[prost(string, tag="1")]
+    pub title: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub description: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub url: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LoginActivityPayload {
+    #[prost(string, tag="1")]
+    pub login_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StoryPayload {
+    #[prost(string, tag="1")]
+    pub story_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub story_slug: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub story_title: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StoryLikePayload {
+    #[prost(string, tag="1")]
+    pub story_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub story_slug: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub story_title: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TagPayload {
+    #[prost(string, tag="1")]
+    pub tag_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub tag_name: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub story_id: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub story_slug: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub story_title: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommentPayload {
+    #[prost(string, tag="1")]
+    pub comment_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub story_id: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub story_slug: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub story_title: ::prost::alloc::string::String,
+    /// Truncated plain text content of the comment
+    #[prost(string, tag="5")]
+    pub excerpt: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReplyPayload {
+    #[prost(string, tag="1")]
+    pub reply_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub comment_id: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub story_id: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub story_slug: ::prost::alloc::string::String,
+    /// Truncated plain text content of the reply
+    #[prost(string, tag="5")]
+    pub excerpt: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NewFollowerPayload {
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FriendRequestPayload {
+    /// Omitted once the request is accepted
+    #[prost(string, optional, tag="1")]
+    pub friend_request_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollaborationRequestPayload {
+    /// Omitted once the request is accepted
+    #[prost(string, optional, tag="1")]
+    pub collaboration_request_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="2")]
+    pub story_id: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub story_title: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlogRequestPayload {
+    #[prost(string, tag="1")]
+    pub blog_request_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub blog_id: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub blog_name: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub blog_slug: ::prost::alloc::string::String,
+}
+// Notification
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Notification {
+    #[prost(string, tag="1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(enumeration="NotificationType", tag="2")]
+    pub r#type: i32,
+    /// Omitted for system notifications
+    #[prost(message, optional, tag="3")]
+    pub actor: ::core::option::Option<super::super::user_def::v1::BareUser>,
+    #[prost(message, optional, tag="4")]
+    pub read_at: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(message, optional, tag="5")]
+    pub created_at: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(oneof="notification::Payload", tags="6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16")]
+    pub payload: ::core::option::Option<notification::Payload>,
+}
+/// Nested message and enum types in `Notification`.
+pub mod notification {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Payload {
+        #[prost(message, tag="6")]
+        FeaturesAndUpdates(super::FeaturesAndUpdatesPayload),
+        #[prost(message, tag="7")]
+        LoginActivity(super::LoginActivityPayload),
+        #[prost(message, tag="8")]
+        Story(super::StoryPayload),
+        #[prost(message, tag="9")]
+        StoryLike(super::StoryLikePayload),
+        #[prost(message, tag="10")]
+        Tag(super::TagPayload),
+        #[prost(message, tag="11")]
+        Comment(super::CommentPayload),
+        #[prost(message, tag="12")]
+        Reply(super::ReplyPayload),
+        #[prost(message, tag="13")]
+        NewFollower(super::NewFollowerPayload),
+        #[prost(message, tag="14")]
+        FriendRequest(super::FriendRequestPayload),
+        #[prost(message, tag="15")]
+        CollaborationRequest(super::CollaborationRequestPayload),
+        #[prost(message, tag="16")]
+        BlogRequest(super::BlogRequestPayload),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNotificationsRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    /// `next_cursor` from the previous page, `INVALID_ARGUMENT` if malformed
+    #[prost(string, optional, tag="2")]
+    pub cursor: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag="3")]
+    pub limit: u32,
+    #[prost(bool, tag="4")]
+    pub unread_only: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNotificationsResponse {
+    #[prost(message, repeated, tag="1")]
+    pub notifications: ::prost::alloc::vec::Vec<Notification>,
+    /// Omitted on the last page
+    #[prost(string, optional, tag="2")]
+    pub next_cursor: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Either `notification_ids` or `all` must be set. An empty list without `all`
+/// is rejected with `INVALID_ARGUMENT`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarkNotificationsReadRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="2")]
+    pub notification_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Marks every notification as read
+    #[prost(bool, tag="3")]
+    pub all: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarkNotificationsReadResponse {
+    #[prost(uint32, tag="1")]
+    pub unread_count: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetUnreadNotificationCountRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetUnreadNotificationCountResponse {
+    #[prost(uint32, tag="1")]
+    pub unread_count: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchNotificationsRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchNotificationsResponse {
+    #[prost(message, optional, tag="1")]
+    pub notification: ::core::option::Option<Notification>,
+    #[prost(uint32, tag="2")]
+    pub unread_count: u32,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NotificationType {
+    Unspecified = 0,
+    System = 1,
+    LoginAttempt = 2,
+    FriendReqAccept = 3,
+    FriendReqReceived = 4,
+    FollowerAdd = 5,
+    CommentAdd = 6,
+    ReplyAdd = 7,
+    StoryMention = 8,
+    StoryLike = 9,
+    StoryAddByUser = 10,
+    StoryAddByTag = 11,
+    CollabReqAccept = 12,
+    CollabReqReceived = 13,
+    BlogEditorInvite = 14,
+    BlogWriterInvite = 15,
+}
+impl NotificationType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            NotificationType::Unspecified => "NOTIFICATION_TYPE_UNSPECIFIED",
+            NotificationType::System => "NOTIFICATION_TYPE_SYSTEM",
+            NotificationType::LoginAttempt => "NOTIFICATION_TYPE_LOGIN_ATTEMPT",
+            NotificationType::FriendReqAccept => "NOTIFICATION_TYPE_FRIEND_REQ_ACCEPT",
+            NotificationType::FriendReqReceived => "NOTIFICATION_TYPE_FRIEND_REQ_RECEIVED",
+            NotificationType::FollowerAdd => "NOTIFICATION_TYPE_FOLLOWER_ADD",
+            NotificationType::CommentAdd => "NOTIFICATION_TYPE_COMMENT_ADD",
+            NotificationType::ReplyAdd => "NOTIFICATION_TYPE_REPLY_ADD",
+            NotificationType::StoryMention => "NOTIFICATION_TYPE_STORY_MENTION",
+            NotificationType::StoryLike => "NOTIFICATION_TYPE_STORY_LIKE",
+            NotificationType::StoryAddByUser => "NOTIFICATION_TYPE_STORY_ADD_BY_USER",
+            NotificationType::StoryAddByTag => "NOTIFICATION_TYPE_STORY_ADD_BY_TAG",
+            NotificationType::CollabReqAccept => "NOTIFICATION_TYPE_COLLAB_REQ_ACCEPT",
+            NotificationType::CollabReqReceived => "NOTIFICATION_TYPE_COLLAB_REQ_RECEIVED",
+            NotificationType::BlogEditorInvite => "NOTIFICATION_TYPE_BLOG_EDITOR_INVITE",
+            NotificationType::BlogWriterInvite => "NOTIFICATION_TYPE_BLOG_WRITER_INVITE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NOTIFICATION_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "NOTIFICATION_TYPE_SYSTEM" => Some(Self::System),
+            "NOTIFICATION_TYPE_LOGIN_ATTEMPT" => Some(Self::LoginAttempt),
+            "NOTIFICATION_TYPE_FRIEND_REQ_ACCEPT" => Some(Self::FriendReqAccept),
+            "NOTIFICATION_TYPE_FRIEND_REQ_RECEIVED" => Some(Self::FriendReqReceived),
+            "NOTIFICATION_TYPE_FOLLOWER_ADD" => Some(Self::FollowerAdd),
+            "NOTIFICATION_TYPE_COMMENT_ADD" => Some(Self::CommentAdd),
+            "NOTIFICATION_TYPE_REPLY_ADD" => Some(Self::ReplyAdd),
+            "NOTIFICATION_TYPE_STORY_MENTION" => Some(Self::StoryMention),
+            "NOTIFICATION_TYPE_STORY_LIKE" => Some(Self::StoryLike),
+            "NOTIFICATION_TYPE_STORY_ADD_BY_USER" => Some(Self::StoryAddByUser),
+            "NOTIFICATION_TYPE_STORY_ADD_BY_TAG" => Some(Self::StoryAddByTag),
+            "NOTIFICATION_TYPE_COLLAB_REQ_ACCEPT" => Some(Self::CollabReqAccept),
+            "NOTIFICATION_TYPE_COLLAB_REQ_RECEIVED" => Some(Self::CollabReqReceived),
+            "NOTIFICATION_TYPE_BLOG_EDITOR_INVITE" => Some(Self::BlogEditorInvite),
+            "NOTIFICATION_TYPE_BLOG_WRITER_INVITE" => Some(Self::BlogWriterInvite),
+            _ => None,
+        }
+    }
+}
+// @@protoc_insertion_point(module)
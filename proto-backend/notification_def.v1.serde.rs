@@ -0,0 +1,2703 @@
+// @generated
+impl serde::Serialize for BlogRequestPayload {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.blog_request_id.is_empty() {
+            len += 1;
+        }
+        if !self.blog_id.is_empty() {
+            len += 1;
+        }
+        if !self.blog_name.is_empty() {
+            len += 1;
+        }
+        if !self.blog_slug.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.BlogRequestPayload", len)?;
+        if !self.blog_request_id.is_empty() {
+            struct_ser.serialize_field("blogRequestId", &self.blog_request_id)?;
+        }
+        if !self.blog_id.is_empty() {
+            struct_ser.serialize_field("blogId", &self.blog_id)?;
+        }
+        if !self.blog_name.is_empty() {
+            struct_ser.serialize_field("blogName", &self.blog_name)?;
+        }
+        if !self.blog_slug.is_empty() {
+            struct_ser.serialize_field("blogSlug", &self.blog_slug)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BlogRequestPayload {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "blog_request_id",
+            "blogRequestId",
+            "blog_id",
+            "blogId",
+            "blog_name",
+            "blogName",
+            "blog_slug",
+            "blogSlug",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BlogRequestId,
+            BlogId,
+            BlogName,
+            BlogSlug,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "blogRequestId" | "blog_request_id" => Ok(GeneratedField::BlogRequestId),
+                            "blogId" | "blog_id" => Ok(GeneratedField::BlogId),
+                            "blogName" | "blog_name" => Ok(GeneratedField::BlogName),
+                            "blogSlug" | "blog_slug" => Ok(GeneratedField::BlogSlug),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BlogRequestPayload;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.BlogRequestPayload")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<BlogRequestPayload, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut blog_request_id__ = None;
+                let mut blog_id__ = None;
+                let mut blog_name__ = None;
+                let mut blog_slug__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::BlogRequestId => {
+                            if blog_request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blogRequestId"));
+                            }
+                            blog_request_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::BlogId => {
+                            if blog_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blogId"));
+                            }
+                            blog_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::BlogName => {
+                            if blog_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blogName"));
+                            }
+                            blog_name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::BlogSlug => {
+                            if blog_slug__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blogSlug"));
+                            }
+                            blog_slug__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(BlogRequestPayload {
+                    blog_request_id: blog_request_id__.unwrap_or_default(),
+                    blog_id: blog_id__.unwrap_or_default(),
+                    blog_name: blog_name__.unwrap_or_default(),
+                    blog_slug: blog_slug__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.BlogRequestPayload", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CollaborationRequestPayload {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.collaboration_request_id.is_some() {
+            len += 1;
+        }
+        if !self.story_id.is_empty() {
+            len += 1;
+        }
+        if !self.story_title.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.CollaborationRequestPayload", len)?;
+        if let Some(v) = self.collaboration_request_id.as_ref() {
+            struct_ser.serialize_field("collaborationRequestId", v)?;
+        }
+        if !self.story_id.is_empty() {
+            struct_ser.serialize_field("storyId", &self.story_id)?;
+        }
+        if !self.story_title.is_empty() {
+            struct_ser.serialize_field("storyTitle", &self.story_title)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CollaborationRequestPayload {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "collaboration_request_id",
+            "collaborationRequestId",
+            "story_id",
+            "storyId",
+            "story_title",
+            "storyTitle",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CollaborationRequestId,
+            StoryId,
+            StoryTitle,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "collaborationRequestId" | "collaboration_request_id" => Ok(GeneratedField::CollaborationRequestId),
+                            "storyId" | "story_id" => Ok(GeneratedField::StoryId),
+                            "storyTitle" | "story_title" => Ok(GeneratedField::StoryTitle),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CollaborationRequestPayload;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.CollaborationRequestPayload")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<CollaborationRequestPayload, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut collaboration_request_id__ = None;
+                let mut story_id__ = None;
+                let mut story_title__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::CollaborationRequestId => {
+                            if collaboration_request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("collaborationRequestId"));
+                            }
+                            collaboration_request_id__ = map.next_value()?;
+                        }
+                        GeneratedField::StoryId => {
+                            if story_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storyId"));
+                            }
+                            story_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StoryTitle => {
+                            if story_title__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storyTitle"));
+                            }
+                            story_title__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(CollaborationRequestPayload {
+                    collaboration_request_id: collaboration_request_id__,
+                    story_id: story_id__.unwrap_or_default(),
+                    story_title: story_title__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.CollaborationRequestPayload", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CommentPayload {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.comment_id.is_empty() {
+            len += 1;
+        }
+        if !self.story_id.is_empty() {
+            len += 1;
+        }
+        if !self.story_slug.is_empty() {
+            len += 1;
+        }
+        if !self.story_title.is_empty() {
+            len += 1;
+        }
+        if !self.excerpt.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.CommentPayload", len)?;
+        if !self.comment_id.is_empty() {
+            struct_ser.serialize_field("commentId", &self.comment_id)?;
+        }
+        if !self.story_id.is_empty() {
+            struct_ser.serialize_field("storyId", &self.story_id)?;
+        }
+        if !self.story_slug.is_empty() {
+            struct_ser.serialize_field("storySlug", &self.story_slug)?;
+        }
+        if !self.story_title.is_empty() {
+            struct_ser.serialize_field("storyTitle", &self.story_title)?;
+        }
+        if !self.excerpt.is_empty() {
+            struct_ser.serialize_field("excerpt", &self.excerpt)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CommentPayload {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "comment_id",
+            "commentId",
+            "story_id",
+            "storyId",
+            "story_slug",
+            "storySlug",
+            "story_title",
+            "storyTitle",
+            "excerpt",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            CommentId,
+            StoryId,
+            StorySlug,
+            StoryTitle,
+            Excerpt,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "commentId" | "comment_id" => Ok(GeneratedField::CommentId),
+                            "storyId" | "story_id" => Ok(GeneratedField::StoryId),
+                            "storySlug" | "story_slug" => Ok(GeneratedField::StorySlug),
+                            "storyTitle" | "story_title" => Ok(GeneratedField::StoryTitle),
+                            "excerpt" => Ok(GeneratedField::Excerpt),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CommentPayload;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.CommentPayload")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<CommentPayload, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut comment_id__ = None;
+                let mut story_id__ = None;
+                let mut story_slug__ = None;
+                let mut story_title__ = None;
+                let mut excerpt__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::CommentId => {
+                            if comment_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commentId"));
+                            }
+                            comment_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StoryId => {
+                            if story_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storyId"));
+                            }
+                            story_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StorySlug => {
+                            if story_slug__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storySlug"));
+                            }
+                            story_slug__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StoryTitle => {
+                            if story_title__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storyTitle"));
+                            }
+                            story_title__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Excerpt => {
+                            if excerpt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("excerpt"));
+                            }
+                            excerpt__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(CommentPayload {
+                    comment_id: comment_id__.unwrap_or_default(),
+                    story_id: story_id__.unwrap_or_default(),
+                    story_slug: story_slug__.unwrap_or_default(),
+                    story_title: story_title__.unwrap_or_default(),
+                    excerpt: excerpt__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.CommentPayload", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeaturesAndUpdatesPayload {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.title.is_empty() {
+            len += 1;
+        }
+        if !self.description.is_empty() {
+            len += 1;
+        }
+        if self.url.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.FeaturesAndUpdatesPayload", len)?;
+        if !self.title.is_empty() {
+            struct_ser.serialize_field("title", &self.title)?;
+        }
+        if !self.description.is_empty() {
+            struct_ser.serialize_field("description", &self.description)?;
+        }
+        if let Some(v) = self.url.as_ref() {
+            struct_ser.serialize_field("url", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeaturesAndUpdatesPayload {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "title",
+            "description",
+            "url",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Title,
+            Description,
+            Url,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "title" => Ok(GeneratedField::Title),
+                            "description" => Ok(GeneratedField::Description),
+                            "url" => Ok(GeneratedField::Url),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeaturesAndUpdatesPayload;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.FeaturesAndUpdatesPayload")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<FeaturesAndUpdatesPayload, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut title__ = None;
+                let mut description__ = None;
+                let mut url__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Title => {
+                            if title__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("title"));
+                            }
+                            title__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Url => {
+                            if url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("url"));
+                            }
+                            url__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(FeaturesAndUpdatesPayload {
+                    title: title__.unwrap_or_default(),
+                    description: description__.unwrap_or_default(),
+                    url: url__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.FeaturesAndUpdatesPayload", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FriendRequestPayload {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.friend_request_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.FriendRequestPayload", len)?;
+        if let Some(v) = self.friend_request_id.as_ref() {
+            struct_ser.serialize_field("friendRequestId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FriendRequestPayload {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "friend_request_id",
+            "friendRequestId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FriendRequestId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "friendRequestId" | "friend_request_id" => Ok(GeneratedField::FriendRequestId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FriendRequestPayload;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.FriendRequestPayload")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<FriendRequestPayload, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut friend_request_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::FriendRequestId => {
+                            if friend_request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("friendRequestId"));
+                            }
+                            friend_request_id__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(FriendRequestPayload {
+                    friend_request_id: friend_request_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.FriendRequestPayload", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetUnreadNotificationCountRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.GetUnreadNotificationCountRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetUnreadNotificationCountRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetUnreadNotificationCountRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.GetUnreadNotificationCountRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<GetUnreadNotificationCountRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(GetUnreadNotificationCountRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.GetUnreadNotificationCountRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetUnreadNotificationCountResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.unread_count != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.GetUnreadNotificationCountResponse", len)?;
+        if self.unread_count != 0 {
+            struct_ser.serialize_field("unreadCount", &self.unread_count)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetUnreadNotificationCountResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "unread_count",
+            "unreadCount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UnreadCount,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "unreadCount" | "unread_count" => Ok(GeneratedField::UnreadCount),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetUnreadNotificationCountResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.GetUnreadNotificationCountResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<GetUnreadNotificationCountResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut unread_count__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UnreadCount => {
+                            if unread_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unreadCount"));
+                            }
+                            unread_count__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(GetUnreadNotificationCountResponse {
+                    unread_count: unread_count__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.GetUnreadNotificationCountResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListNotificationsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if self.cursor.is_some() {
+            len += 1;
+        }
+        if self.limit != 0 {
+            len += 1;
+        }
+        if self.unread_only {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.ListNotificationsRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if let Some(v) = self.cursor.as_ref() {
+            struct_ser.serialize_field("cursor", v)?;
+        }
+        if self.limit != 0 {
+            struct_ser.serialize_field("limit", &self.limit)?;
+        }
+        if self.unread_only {
+            struct_ser.serialize_field("unreadOnly", &self.unread_only)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListNotificationsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "cursor",
+            "limit",
+            "unread_only",
+            "unreadOnly",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            Cursor,
+            Limit,
+            UnreadOnly,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "cursor" => Ok(GeneratedField::Cursor),
+                            "limit" => Ok(GeneratedField::Limit),
+                            "unreadOnly" | "unread_only" => Ok(GeneratedField::UnreadOnly),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListNotificationsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.ListNotificationsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ListNotificationsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut cursor__ = None;
+                let mut limit__ = None;
+                let mut unread_only__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Cursor => {
+                            if cursor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cursor"));
+                            }
+                            cursor__ = map.next_value()?;
+                        }
+                        GeneratedField::Limit => {
+                            if limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limit"));
+                            }
+                            limit__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::UnreadOnly => {
+                            if unread_only__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unreadOnly"));
+                            }
+                            unread_only__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(ListNotificationsRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                    cursor: cursor__,
+                    limit: limit__.unwrap_or_default(),
+                    unread_only: unread_only__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.ListNotificationsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListNotificationsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.notifications.is_empty() {
+            len += 1;
+        }
+        if self.next_cursor.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.ListNotificationsResponse", len)?;
+        if !self.notifications.is_empty() {
+            struct_ser.serialize_field("notifications", &self.notifications)?;
+        }
+        if let Some(v) = self.next_cursor.as_ref() {
+            struct_ser.serialize_field("nextCursor", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListNotificationsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "notifications",
+            "next_cursor",
+            "nextCursor",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Notifications,
+            NextCursor,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "notifications" => Ok(GeneratedField::Notifications),
+                            "nextCursor" | "next_cursor" => Ok(GeneratedField::NextCursor),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListNotificationsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.ListNotificationsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ListNotificationsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut notifications__ = None;
+                let mut next_cursor__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Notifications => {
+                            if notifications__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("notifications"));
+                            }
+                            notifications__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::NextCursor => {
+                            if next_cursor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextCursor"));
+                            }
+                            next_cursor__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ListNotificationsResponse {
+                    notifications: notifications__.unwrap_or_default(),
+                    next_cursor: next_cursor__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.ListNotificationsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LoginActivityPayload {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.login_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.LoginActivityPayload", len)?;
+        if !self.login_id.is_empty() {
+            struct_ser.serialize_field("loginId", &self.login_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LoginActivityPayload {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "login_id",
+            "loginId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            LoginId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "loginId" | "login_id" => Ok(GeneratedField::LoginId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LoginActivityPayload;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.LoginActivityPayload")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<LoginActivityPayload, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut login_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::LoginId => {
+                            if login_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("loginId"));
+                            }
+                            login_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(LoginActivityPayload {
+                    login_id: login_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.LoginActivityPayload", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MarkNotificationsReadRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if !self.notification_ids.is_empty() {
+            len += 1;
+        }
+        if self.all {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.MarkNotificationsReadRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if !self.notification_ids.is_empty() {
+            struct_ser.serialize_field("notificationIds", &self.notification_ids)?;
+        }
+        if self.all {
+            struct_ser.serialize_field("all", &self.all)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MarkNotificationsReadRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "notification_ids",
+            "notificationIds",
+            "all",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            NotificationIds,
+            All,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "notificationIds" | "notification_ids" => Ok(GeneratedField::NotificationIds),
+                            "all" => Ok(GeneratedField::All),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MarkNotificationsReadRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.MarkNotificationsReadRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<MarkNotificationsReadRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut notification_ids__ = None;
+                let mut all__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::NotificationIds => {
+                            if notification_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("notificationIds"));
+                            }
+                            notification_ids__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::All => {
+                            if all__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("all"));
+                            }
+                            all__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(MarkNotificationsReadRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                    notification_ids: notification_ids__.unwrap_or_default(),
+                    all: all__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.MarkNotificationsReadRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for MarkNotificationsReadResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.unread_count != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.MarkNotificationsReadResponse", len)?;
+        if self.unread_count != 0 {
+            struct_ser.serialize_field("unreadCount", &self.unread_count)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for MarkNotificationsReadResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "unread_count",
+            "unreadCount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UnreadCount,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "unreadCount" | "unread_count" => Ok(GeneratedField::UnreadCount),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = MarkNotificationsReadResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.MarkNotificationsReadResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<MarkNotificationsReadResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut unread_count__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UnreadCount => {
+                            if unread_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unreadCount"));
+                            }
+                            unread_count__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(MarkNotificationsReadResponse {
+                    unread_count: unread_count__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.MarkNotificationsReadResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NewFollowerPayload {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("notification_def.v1.NewFollowerPayload", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for NewFollowerPayload {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NewFollowerPayload;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.NewFollowerPayload")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<NewFollowerPayload, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(NewFollowerPayload {
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.NewFollowerPayload", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Notification {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.r#type != 0 {
+            len += 1;
+        }
+        if self.actor.is_some() {
+            len += 1;
+        }
+        if self.read_at.is_some() {
+            len += 1;
+        }
+        if self.created_at.is_some() {
+            len += 1;
+        }
+        if self.payload.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.Notification", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if self.r#type != 0 {
+            let v = NotificationType::from_i32(self.r#type)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.r#type)))?;
+            struct_ser.serialize_field("type", &v)?;
+        }
+        if let Some(v) = self.actor.as_ref() {
+            struct_ser.serialize_field("actor", v)?;
+        }
+        if let Some(v) = self.read_at.as_ref() {
+            struct_ser.serialize_field("readAt", v)?;
+        }
+        if let Some(v) = self.created_at.as_ref() {
+            struct_ser.serialize_field("createdAt", v)?;
+        }
+        if let Some(v) = self.payload.as_ref() {
+            match v {
+                notification::Payload::FeaturesAndUpdates(v) => {
+                    struct_ser.serialize_field("featuresAndUpdates", v)?;
+                }
+                notification::Payload::LoginActivity(v) => {
+                    struct_ser.serialize_field("loginActivity", v)?;
+                }
+                notification::Payload::Story(v) => {
+                    struct_ser.serialize_field("story", v)?;
+                }
+                notification::Payload::StoryLike(v) => {
+                    struct_ser.serialize_field("storyLike", v)?;
+                }
+                notification::Payload::Tag(v) => {
+                    struct_ser.serialize_field("tag", v)?;
+                }
+                notification::Payload::Comment(v) => {
+                    struct_ser.serialize_field("comment", v)?;
+                }
+                notification::Payload::Reply(v) => {
+                    struct_ser.serialize_field("reply", v)?;
+                }
+                notification::Payload::NewFollower(v) => {
+                    struct_ser.serialize_field("newFollower", v)?;
+                }
+                notification::Payload::FriendRequest(v) => {
+                    struct_ser.serialize_field("friendRequest", v)?;
+                }
+                notification::Payload::CollaborationRequest(v) => {
+                    struct_ser.serialize_field("collaborationRequest", v)?;
+                }
+                notification::Payload::BlogRequest(v) => {
+                    struct_ser.serialize_field("blogRequest", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Notification {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "type",
+            "actor",
+            "read_at",
+            "readAt",
+            "created_at",
+            "createdAt",
+            "features_and_updates",
+            "featuresAndUpdates",
+            "login_activity",
+            "loginActivity",
+            "story",
+            "story_like",
+            "storyLike",
+            "tag",
+            "comment",
+            "reply",
+            "new_follower",
+            "newFollower",
+            "friend_request",
+            "friendRequest",
+            "collaboration_request",
+            "collaborationRequest",
+            "blog_request",
+            "blogRequest",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Type,
+            Actor,
+            ReadAt,
+            CreatedAt,
+            FeaturesAndUpdates,
+            LoginActivity,
+            Story,
+            StoryLike,
+            Tag,
+            Comment,
+            Reply,
+            NewFollower,
+            FriendRequest,
+            CollaborationRequest,
+            BlogRequest,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "type" => Ok(GeneratedField::Type),
+                            "actor" => Ok(GeneratedField::Actor),
+                            "readAt" | "read_at" => Ok(GeneratedField::ReadAt),
+                            "createdAt" | "created_at" => Ok(GeneratedField::CreatedAt),
+                            "featuresAndUpdates" | "features_and_updates" => Ok(GeneratedField::FeaturesAndUpdates),
+                            "loginActivity" | "login_activity" => Ok(GeneratedField::LoginActivity),
+                            "story" => Ok(GeneratedField::Story),
+                            "storyLike" | "story_like" => Ok(GeneratedField::StoryLike),
+                            "tag" => Ok(GeneratedField::Tag),
+                            "comment" => Ok(GeneratedField::Comment),
+                            "reply" => Ok(GeneratedField::Reply),
+                            "newFollower" | "new_follower" => Ok(GeneratedField::NewFollower),
+                            "friendRequest" | "friend_request" => Ok(GeneratedField::FriendRequest),
+                            "collaborationRequest" | "collaboration_request" => Ok(GeneratedField::CollaborationRequest),
+                            "blogRequest" | "blog_request" => Ok(GeneratedField::BlogRequest),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Notification;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.Notification")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Notification, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut r#type__ = None;
+                let mut actor__ = None;
+                let mut read_at__ = None;
+                let mut created_at__ = None;
+                let mut payload__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Type => {
+                            if r#type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("type"));
+                            }
+                            r#type__ = Some(map.next_value::<NotificationType>()? as i32);
+                        }
+                        GeneratedField::Actor => {
+                            if actor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actor"));
+                            }
+                            actor__ = map.next_value()?;
+                        }
+                        GeneratedField::ReadAt => {
+                            if read_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("readAt"));
+                            }
+                            read_at__ = map.next_value()?;
+                        }
+                        GeneratedField::CreatedAt => {
+                            if created_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAt"));
+                            }
+                            created_at__ = map.next_value()?;
+                        }
+                        GeneratedField::FeaturesAndUpdates => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("featuresAndUpdates"));
+                            }
+                            payload__ = map.next_value::<::std::option::Option<_>>()?.map(notification::Payload::FeaturesAndUpdates)
+;
+                        }
+                        GeneratedField::LoginActivity => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("loginActivity"));
+                            }
+                            payload__ = map.next_value::<::std::option::Option<_>>()?.map(notification::Payload::LoginActivity)
+;
+                        }
+                        GeneratedField::Story => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("story"));
+                            }
+                            payload__ = map.next_value::<::std::option::Option<_>>()?.map(notification::Payload::Story)
+;
+                        }
+                        GeneratedField::StoryLike => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storyLike"));
+                            }
+                            payload__ = map.next_value::<::std::option::Option<_>>()?.map(notification::Payload::StoryLike)
+;
+                        }
+                        GeneratedField::Tag => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tag"));
+                            }
+                            payload__ = map.next_value::<::std::option::Option<_>>()?.map(notification::Payload::Tag)
+;
+                        }
+                        GeneratedField::Comment => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("comment"));
+                            }
+                            payload__ = map.next_value::<::std::option::Option<_>>()?.map(notification::Payload::Comment)
+;
+                        }
+                        GeneratedField::Reply => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reply"));
+                            }
+                            payload__ = map.next_value::<::std::option::Option<_>>()?.map(notification::Payload::Reply)
+;
+                        }
+                        GeneratedField::NewFollower => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newFollower"));
+                            }
+                            payload__ = map.next_value::<::std::option::Option<_>>()?.map(notification::Payload::NewFollower)
+;
+                        }
+                        GeneratedField::FriendRequest => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("friendRequest"));
+                            }
+                            payload__ = map.next_value::<::std::option::Option<_>>()?.map(notification::Payload::FriendRequest)
+;
+                        }
+                        GeneratedField::CollaborationRequest => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("collaborationRequest"));
+                            }
+                            payload__ = map.next_value::<::std::option::Option<_>>()?.map(notification::Payload::CollaborationRequest)
+;
+                        }
+                        GeneratedField::BlogRequest => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blogRequest"));
+                            }
+                            payload__ = map.next_value::<::std::option::Option<_>>()?.map(notification::Payload::BlogRequest)
+;
+                        }
+                    }
+                }
+                Ok(Notification {
+                    id: id__.unwrap_or_default(),
+                    r#type: r#type__.unwrap_or_default(),
+                    actor: actor__,
+                    read_at: read_at__,
+                    created_at: created_at__,
+                    payload: payload__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.Notification", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for NotificationType {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => 0,
+            Self::System => 1,
+            Self::LoginAttempt => 2,
+            Self::FriendReqAccept => 3,
+            Self::FriendReqReceived => 4,
+            Self::FollowerAdd => 5,
+            Self::CommentAdd => 6,
+            Self::ReplyAdd => 7,
+            Self::StoryMention => 8,
+            Self::StoryLike => 9,
+            Self::StoryAddByUser => 10,
+            Self::StoryAddByTag => 11,
+            Self::CollabReqAccept => 12,
+            Self::CollabReqReceived => 13,
+            Self::BlogEditorInvite => 14,
+            Self::BlogWriterInvite => 15,
+        };
+        serializer.serialize_i32(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for NotificationType {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "NOTIFICATION_TYPE_UNSPECIFIED",
+            "NOTIFICATION_TYPE_SYSTEM",
+            "NOTIFICATION_TYPE_LOGIN_ATTEMPT",
+            "NOTIFICATION_TYPE_FRIEND_REQ_ACCEPT",
+            "NOTIFICATION_TYPE_FRIEND_REQ_RECEIVED",
+            "NOTIFICATION_TYPE_FOLLOWER_ADD",
+            "NOTIFICATION_TYPE_COMMENT_ADD",
+            "NOTIFICATION_TYPE_REPLY_ADD",
+            "NOTIFICATION_TYPE_STORY_MENTION",
+            "NOTIFICATION_TYPE_STORY_LIKE",
+            "NOTIFICATION_TYPE_STORY_ADD_BY_USER",
+            "NOTIFICATION_TYPE_STORY_ADD_BY_TAG",
+            "NOTIFICATION_TYPE_COLLAB_REQ_ACCEPT",
+            "NOTIFICATION_TYPE_COLLAB_REQ_RECEIVED",
+            "NOTIFICATION_TYPE_BLOG_EDITOR_INVITE",
+            "NOTIFICATION_TYPE_BLOG_WRITER_INVITE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = NotificationType;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(NotificationType::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(NotificationType::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "NOTIFICATION_TYPE_UNSPECIFIED" => Ok(NotificationType::Unspecified),
+                    "NOTIFICATION_TYPE_SYSTEM" => Ok(NotificationType::System),
+                    "NOTIFICATION_TYPE_LOGIN_ATTEMPT" => Ok(NotificationType::LoginAttempt),
+                    "NOTIFICATION_TYPE_FRIEND_REQ_ACCEPT" => Ok(NotificationType::FriendReqAccept),
+                    "NOTIFICATION_TYPE_FRIEND_REQ_RECEIVED" => Ok(NotificationType::FriendReqReceived),
+                    "NOTIFICATION_TYPE_FOLLOWER_ADD" => Ok(NotificationType::FollowerAdd),
+                    "NOTIFICATION_TYPE_COMMENT_ADD" => Ok(NotificationType::CommentAdd),
+                    "NOTIFICATION_TYPE_REPLY_ADD" => Ok(NotificationType::ReplyAdd),
+                    "NOTIFICATION_TYPE_STORY_MENTION" => Ok(NotificationType::StoryMention),
+                    "NOTIFICATION_TYPE_STORY_LIKE" => Ok(NotificationType::StoryLike),
+                    "NOTIFICATION_TYPE_STORY_ADD_BY_USER" => Ok(NotificationType::StoryAddByUser),
+                    "NOTIFICATION_TYPE_STORY_ADD_BY_TAG" => Ok(NotificationType::StoryAddByTag),
+                    "NOTIFICATION_TYPE_COLLAB_REQ_ACCEPT" => Ok(NotificationType::CollabReqAccept),
+                    "NOTIFICATION_TYPE_COLLAB_REQ_RECEIVED" => Ok(NotificationType::CollabReqReceived),
+                    "NOTIFICATION_TYPE_BLOG_EDITOR_INVITE" => Ok(NotificationType::BlogEditorInvite),
+                    "NOTIFICATION_TYPE_BLOG_WRITER_INVITE" => Ok(NotificationType::BlogWriterInvite),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReplyPayload {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.reply_id.is_empty() {
+            len += 1;
+        }
+        if !self.comment_id.is_empty() {
+            len += 1;
+        }
+        if !self.story_id.is_empty() {
+            len += 1;
+        }
+        if !self.story_slug.is_empty() {
+            len += 1;
+        }
+        if !self.excerpt.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.ReplyPayload", len)?;
+        if !self.reply_id.is_empty() {
+            struct_ser.serialize_field("replyId", &self.reply_id)?;
+        }
+        if !self.comment_id.is_empty() {
+            struct_ser.serialize_field("commentId", &self.comment_id)?;
+        }
+        if !self.story_id.is_empty() {
+            struct_ser.serialize_field("storyId", &self.story_id)?;
+        }
+        if !self.story_slug.is_empty() {
+            struct_ser.serialize_field("storySlug", &self.story_slug)?;
+        }
+        if !self.excerpt.is_empty() {
+            struct_ser.serialize_field("excerpt", &self.excerpt)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReplyPayload {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reply_id",
+            "replyId",
+            "comment_id",
+            "commentId",
+            "story_id",
+            "storyId",
+            "story_slug",
+            "storySlug",
+            "excerpt",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ReplyId,
+            CommentId,
+            StoryId,
+            StorySlug,
+            Excerpt,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "replyId" | "reply_id" => Ok(GeneratedField::ReplyId),
+                            "commentId" | "comment_id" => Ok(GeneratedField::CommentId),
+                            "storyId" | "story_id" => Ok(GeneratedField::StoryId),
+                            "storySlug" | "story_slug" => Ok(GeneratedField::StorySlug),
+                            "excerpt" => Ok(GeneratedField::Excerpt),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReplyPayload;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.ReplyPayload")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ReplyPayload, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reply_id__ = None;
+                let mut comment_id__ = None;
+                let mut story_id__ = None;
+                let mut story_slug__ = None;
+                let mut excerpt__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ReplyId => {
+                            if reply_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("replyId"));
+                            }
+                            reply_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::CommentId => {
+                            if comment_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commentId"));
+                            }
+                            comment_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StoryId => {
+                            if story_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storyId"));
+                            }
+                            story_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StorySlug => {
+                            if story_slug__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storySlug"));
+                            }
+                            story_slug__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Excerpt => {
+                            if excerpt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("excerpt"));
+                            }
+                            excerpt__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(ReplyPayload {
+                    reply_id: reply_id__.unwrap_or_default(),
+                    comment_id: comment_id__.unwrap_or_default(),
+                    story_id: story_id__.unwrap_or_default(),
+                    story_slug: story_slug__.unwrap_or_default(),
+                    excerpt: excerpt__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.ReplyPayload", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StoryLikePayload {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.story_id.is_empty() {
+            len += 1;
+        }
+        if !self.story_slug.is_empty() {
+            len += 1;
+        }
+        if !self.story_title.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.StoryLikePayload", len)?;
+        if !self.story_id.is_empty() {
+            struct_ser.serialize_field("storyId", &self.story_id)?;
+        }
+        if !self.story_slug.is_empty() {
+            struct_ser.serialize_field("storySlug", &self.story_slug)?;
+        }
+        if !self.story_title.is_empty() {
+            struct_ser.serialize_field("storyTitle", &self.story_title)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StoryLikePayload {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "story_id",
+            "storyId",
+            "story_slug",
+            "storySlug",
+            "story_title",
+            "storyTitle",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StoryId,
+            StorySlug,
+            StoryTitle,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "storyId" | "story_id" => Ok(GeneratedField::StoryId),
+                            "storySlug" | "story_slug" => Ok(GeneratedField::StorySlug),
+                            "storyTitle" | "story_title" => Ok(GeneratedField::StoryTitle),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StoryLikePayload;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.StoryLikePayload")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<StoryLikePayload, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut story_id__ = None;
+                let mut story_slug__ = None;
+                let mut story_title__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::StoryId => {
+                            if story_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storyId"));
+                            }
+                            story_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StorySlug => {
+                            if story_slug__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storySlug"));
+                            }
+                            story_slug__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StoryTitle => {
+                            if story_title__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storyTitle"));
+                            }
+                            story_title__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(StoryLikePayload {
+                    story_id: story_id__.unwrap_or_default(),
+                    story_slug: story_slug__.unwrap_or_default(),
+                    story_title: story_title__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.StoryLikePayload", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StoryPayload {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.story_id.is_empty() {
+            len += 1;
+        }
+        if !self.story_slug.is_empty() {
+            len += 1;
+        }
+        if !self.story_title.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.StoryPayload", len)?;
+        if !self.story_id.is_empty() {
+            struct_ser.serialize_field("storyId", &self.story_id)?;
+        }
+        if !self.story_slug.is_empty() {
+            struct_ser.serialize_field("storySlug", &self.story_slug)?;
+        }
+        if !self.story_title.is_empty() {
+            struct_ser.serialize_field("storyTitle", &self.story_title)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StoryPayload {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "story_id",
+            "storyId",
+            "story_slug",
+            "storySlug",
+            "story_title",
+            "storyTitle",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StoryId,
+            StorySlug,
+            StoryTitle,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "storyId" | "story_id" => Ok(GeneratedField::StoryId),
+                            "storySlug" | "story_slug" => Ok(GeneratedField::StorySlug),
+                            "storyTitle" | "story_title" => Ok(GeneratedField::StoryTitle),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StoryPayload;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.StoryPayload")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<StoryPayload, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut story_id__ = None;
+                let mut story_slug__ = None;
+                let mut story_title__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::StoryId => {
+                            if story_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storyId"));
+                            }
+                            story_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StorySlug => {
+                            if story_slug__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storySlug"));
+                            }
+                            story_slug__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StoryTitle => {
+                            if story_title__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storyTitle"));
+                            }
+                            story_title__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(StoryPayload {
+                    story_id: story_id__.unwrap_or_default(),
+                    story_slug: story_slug__.unwrap_or_default(),
+                    story_title: story_title__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.StoryPayload", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TagPayload {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.tag_id.is_empty() {
+            len += 1;
+        }
+        if !self.tag_name.is_empty() {
+            len += 1;
+        }
+        if !self.story_id.is_empty() {
+            len += 1;
+        }
+        if !self.story_slug.is_empty() {
+            len += 1;
+        }
+        if !self.story_title.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.TagPayload", len)?;
+        if !self.tag_id.is_empty() {
+            struct_ser.serialize_field("tagId", &self.tag_id)?;
+        }
+        if !self.tag_name.is_empty() {
+            struct_ser.serialize_field("tagName", &self.tag_name)?;
+        }
+        if !self.story_id.is_empty() {
+            struct_ser.serialize_field("storyId", &self.story_id)?;
+        }
+        if !self.story_slug.is_empty() {
+            struct_ser.serialize_field("storySlug", &self.story_slug)?;
+        }
+        if !self.story_title.is_empty() {
+            struct_ser.serialize_field("storyTitle", &self.story_title)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TagPayload {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "tag_id",
+            "tagId",
+            "tag_name",
+            "tagName",
+            "story_id",
+            "storyId",
+            "story_slug",
+            "storySlug",
+            "story_title",
+            "storyTitle",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TagId,
+            TagName,
+            StoryId,
+            StorySlug,
+            StoryTitle,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tagId" | "tag_id" => Ok(GeneratedField::TagId),
+                            "tagName" | "tag_name" => Ok(GeneratedField::TagName),
+                            "storyId" | "story_id" => Ok(GeneratedField::StoryId),
+                            "storySlug" | "story_slug" => Ok(GeneratedField::StorySlug),
+                            "storyTitle" | "story_title" => Ok(GeneratedField::StoryTitle),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TagPayload;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.TagPayload")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<TagPayload, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut tag_id__ = None;
+                let mut tag_name__ = None;
+                let mut story_id__ = None;
+                let mut story_slug__ = None;
+                let mut story_title__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::TagId => {
+                            if tag_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tagId"));
+                            }
+                            tag_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::TagName => {
+                            if tag_name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tagName"));
+                            }
+                            tag_name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StoryId => {
+                            if story_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storyId"));
+                            }
+                            story_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StorySlug => {
+                            if story_slug__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storySlug"));
+                            }
+                            story_slug__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::StoryTitle => {
+                            if story_title__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storyTitle"));
+                            }
+                            story_title__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(TagPayload {
+                    tag_id: tag_id__.unwrap_or_default(),
+                    tag_name: tag_name__.unwrap_or_default(),
+                    story_id: story_id__.unwrap_or_default(),
+                    story_slug: story_slug__.unwrap_or_default(),
+                    story_title: story_title__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.TagPayload", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WatchNotificationsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.WatchNotificationsRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WatchNotificationsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WatchNotificationsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.WatchNotificationsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<WatchNotificationsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(WatchNotificationsRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.WatchNotificationsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WatchNotificationsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.notification.is_some() {
+            len += 1;
+        }
+        if self.unread_count != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_def.v1.WatchNotificationsResponse", len)?;
+        if let Some(v) = self.notification.as_ref() {
+            struct_ser.serialize_field("notification", v)?;
+        }
+        if self.unread_count != 0 {
+            struct_ser.serialize_field("unreadCount", &self.unread_count)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WatchNotificationsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "notification",
+            "unread_count",
+            "unreadCount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Notification,
+            UnreadCount,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "notification" => Ok(GeneratedField::Notification),
+                            "unreadCount" | "unread_count" => Ok(GeneratedField::UnreadCount),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WatchNotificationsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_def.v1.WatchNotificationsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<WatchNotificationsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut notification__ = None;
+                let mut unread_count__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Notification => {
+                            if notification__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("notification"));
+                            }
+                            notification__ = map.next_value()?;
+                        }
+                        GeneratedField::UnreadCount => {
+                            if unread_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unreadCount"));
+                            }
+                            unread_count__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(WatchNotificationsResponse {
+                    notification: notification__,
+                    unread_count: unread_count__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_def.v1.WatchNotificationsResponse", FIELDS, GeneratedVisitor)
+    }
+}
@@ -29,6 +29,8 @@ pub struct GetNotificationSettingsResponse {
     pub collaboration_requests: bool,
     #[prost(bool, tag="10")]
     pub blog_requests: bool,
+    #[prost(bool, tag="15")]
+    pub mentions: bool,
     /// Mail notifications
     #[prost(bool, tag="11")]
     pub mail_login_activity: bool,
@@ -39,4 +41,23 @@ pub struct GetNotificationSettingsResponse {
     #[prost(bool, tag="14")]
     pub mail_digest: bool,
 }
+// Update notification settings
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateNotificationSettingsRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    /// Paths of the fields in `settings` to update
+    #[prost(message, optional, tag="2")]
+    pub update_mask: ::core::option::Option<::pbjson_types::FieldMask>,
+    #[prost(message, optional, tag="3")]
+    pub settings: ::core::option::Option<GetNotificationSettingsResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateNotificationSettingsResponse {
+    #[prost(message, optional, tag="1")]
+    pub settings: ::core::option::Option<GetNotificationSettingsResponse>,
+}
 // @@protoc_insertion_point(module)
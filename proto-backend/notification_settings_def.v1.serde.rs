@@ -129,6 +129,9 @@ impl serde::Serialize for GetNotificationSettingsResponse {
         if self.blog_requests {
             len += 1;
         }
+        if self.mentions {
+            len += 1;
+        }
         if self.mail_login_activity {
             len += 1;
         }
@@ -172,6 +175,9 @@ impl serde::Serialize for GetNotificationSettingsResponse {
         if self.blog_requests {
             struct_ser.serialize_field("blogRequests", &self.blog_requests)?;
         }
+        if self.mentions {
+            struct_ser.serialize_field("mentions", &self.mentions)?;
+        }
         if self.mail_login_activity {
             struct_ser.serialize_field("mailLoginActivity", &self.mail_login_activity)?;
         }
@@ -210,6 +216,7 @@ impl<'de> serde::Deserialize<'de> for GetNotificationSettingsResponse {
             "collaborationRequests",
             "blog_requests",
             "blogRequests",
+            "mentions",
             "mail_login_activity",
             "mailLoginActivity",
             "mail_features_and_updates",
@@ -232,6 +239,7 @@ impl<'de> serde::Deserialize<'de> for GetNotificationSettingsResponse {
             FriendRequests,
             CollaborationRequests,
             BlogRequests,
+            Mentions,
             MailLoginActivity,
             MailFeaturesAndUpdates,
             MailNewsletters,
@@ -267,6 +275,7 @@ impl<'de> serde::Deserialize<'de> for GetNotificationSettingsResponse {
                             "friendRequests" | "friend_requests" => Ok(GeneratedField::FriendRequests),
                             "collaborationRequests" | "collaboration_requests" => Ok(GeneratedField::CollaborationRequests),
                             "blogRequests" | "blog_requests" => Ok(GeneratedField::BlogRequests),
+                            "mentions" => Ok(GeneratedField::Mentions),
                             "mailLoginActivity" | "mail_login_activity" => Ok(GeneratedField::MailLoginActivity),
                             "mailFeaturesAndUpdates" | "mail_features_and_updates" => Ok(GeneratedField::MailFeaturesAndUpdates),
                             "mailNewsletters" | "mail_newsletters" => Ok(GeneratedField::MailNewsletters),
@@ -300,6 +309,7 @@ impl<'de> serde::Deserialize<'de> for GetNotificationSettingsResponse {
                 let mut friend_requests__ = None;
                 let mut collaboration_requests__ = None;
                 let mut blog_requests__ = None;
+                let mut mentions__ = None;
                 let mut mail_login_activity__ = None;
                 let mut mail_features_and_updates__ = None;
                 let mut mail_newsletters__ = None;
@@ -366,6 +376,12 @@ impl<'de> serde::Deserialize<'de> for GetNotificationSettingsResponse {
                             }
                             blog_requests__ = Some(map.next_value()?);
                         }
+                        GeneratedField::Mentions => {
+                            if mentions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("mentions"));
+                            }
+                            mentions__ = Some(map.next_value()?);
+                        }
                         GeneratedField::MailLoginActivity => {
                             if mail_login_activity__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("mailLoginActivity"));
@@ -403,6 +419,7 @@ impl<'de> serde::Deserialize<'de> for GetNotificationSettingsResponse {
                     friend_requests: friend_requests__.unwrap_or_default(),
                     collaboration_requests: collaboration_requests__.unwrap_or_default(),
                     blog_requests: blog_requests__.unwrap_or_default(),
+                    mentions: mentions__.unwrap_or_default(),
                     mail_login_activity: mail_login_activity__.unwrap_or_default(),
                     mail_features_and_updates: mail_features_and_updates__.unwrap_or_default(),
                     mail_newsletters: mail_newsletters__.unwrap_or_default(),
@@ -413,3 +430,221 @@ impl<'de> serde::Deserialize<'de> for GetNotificationSettingsResponse {
         deserializer.deserialize_struct("notification_settings_def.v1.GetNotificationSettingsResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for UpdateNotificationSettingsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if self.update_mask.is_some() {
+            len += 1;
+        }
+        if self.settings.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_settings_def.v1.UpdateNotificationSettingsRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if let Some(v) = self.update_mask.as_ref() {
+            struct_ser.serialize_field("updateMask", v)?;
+        }
+        if let Some(v) = self.settings.as_ref() {
+            struct_ser.serialize_field("settings", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdateNotificationSettingsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "update_mask",
+            "updateMask",
+            "settings",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            UpdateMask,
+            Settings,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "updateMask" | "update_mask" => Ok(GeneratedField::UpdateMask),
+                            "settings" => Ok(GeneratedField::Settings),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdateNotificationSettingsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_settings_def.v1.UpdateNotificationSettingsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UpdateNotificationSettingsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut update_mask__ = None;
+                let mut settings__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::UpdateMask => {
+                            if update_mask__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateMask"));
+                            }
+                            update_mask__ = map.next_value()?;
+                        }
+                        GeneratedField::Settings => {
+                            if settings__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("settings"));
+                            }
+                            settings__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(UpdateNotificationSettingsRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                    update_mask: update_mask__,
+                    settings: settings__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_settings_def.v1.UpdateNotificationSettingsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpdateNotificationSettingsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.settings.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("notification_settings_def.v1.UpdateNotificationSettingsResponse", len)?;
+        if let Some(v) = self.settings.as_ref() {
+            struct_ser.serialize_field("settings", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdateNotificationSettingsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "settings",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Settings,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "settings" => Ok(GeneratedField::Settings),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdateNotificationSettingsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct notification_settings_def.v1.UpdateNotificationSettingsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UpdateNotificationSettingsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut settings__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Settings => {
+                            if settings__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("settings"));
+                            }
+                            settings__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(UpdateNotificationSettingsResponse {
+                    settings: settings__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("notification_settings_def.v1.UpdateNotificationSettingsResponse", FIELDS, GeneratedVisitor)
+    }
+}
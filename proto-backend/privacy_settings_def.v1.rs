@@ -25,6 +25,39 @@ pub struct GetPrivacySettingsResponse {
     #[prost(enumeration="RelationVisibility", tag="8")]
     pub friend_list_visibility: i32,
 }
+// Update privacy settings
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdatePrivacySettingsRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    /// Paths of the fields in `settings` to update
+    #[prost(message, optional, tag="2")]
+    pub update_mask: ::core::option::Option<::pbjson_types::FieldMask>,
+    #[prost(message, optional, tag="3")]
+    pub settings: ::core::option::Option<GetPrivacySettingsResponse>,
+    /// Deletes the existing read history when `record_read_history` is turned off
+    #[prost(bool, tag="4")]
+    pub purge_read_history: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdatePrivacySettingsResponse {
+    #[prost(message, optional, tag="1")]
+    pub settings: ::core::option::Option<GetPrivacySettingsResponse>,
+    /// Pending follows converted into follow requests
+    #[prost(uint32, tag="2")]
+    pub converted_follow_count: u32,
+    #[prost(uint32, tag="3")]
+    pub declined_friend_request_count: u32,
+    #[prost(uint32, tag="4")]
+    pub declined_collaboration_request_count: u32,
+    #[prost(uint32, tag="5")]
+    pub declined_blog_request_count: u32,
+    #[prost(bool, tag="6")]
+    pub read_history_purged: bool,
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum IncomingFriendRequest {
@@ -644,3 +644,337 @@ impl<'de> serde::Deserialize<'de> for RelationVisibility {
         deserializer.deserialize_any(GeneratedVisitor)
     }
 }
+impl serde::Serialize for UpdatePrivacySettingsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if self.update_mask.is_some() {
+            len += 1;
+        }
+        if self.settings.is_some() {
+            len += 1;
+        }
+        if self.purge_read_history {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("privacy_settings_def.v1.UpdatePrivacySettingsRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if let Some(v) = self.update_mask.as_ref() {
+            struct_ser.serialize_field("updateMask", v)?;
+        }
+        if let Some(v) = self.settings.as_ref() {
+            struct_ser.serialize_field("settings", v)?;
+        }
+        if self.purge_read_history {
+            struct_ser.serialize_field("purgeReadHistory", &self.purge_read_history)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdatePrivacySettingsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "update_mask",
+            "updateMask",
+            "settings",
+            "purge_read_history",
+            "purgeReadHistory",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            UpdateMask,
+            Settings,
+            PurgeReadHistory,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "updateMask" | "update_mask" => Ok(GeneratedField::UpdateMask),
+                            "settings" => Ok(GeneratedField::Settings),
+                            "purgeReadHistory" | "purge_read_history" => Ok(GeneratedField::PurgeReadHistory),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdatePrivacySettingsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct privacy_settings_def.v1.UpdatePrivacySettingsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UpdatePrivacySettingsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut update_mask__ = None;
+                let mut settings__ = None;
+                let mut purge_read_history__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::UpdateMask => {
+                            if update_mask__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateMask"));
+                            }
+                            update_mask__ = map.next_value()?;
+                        }
+                        GeneratedField::Settings => {
+                            if settings__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("settings"));
+                            }
+                            settings__ = map.next_value()?;
+                        }
+                        GeneratedField::PurgeReadHistory => {
+                            if purge_read_history__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("purgeReadHistory"));
+                            }
+                            purge_read_history__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(UpdatePrivacySettingsRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                    update_mask: update_mask__,
+                    settings: settings__,
+                    purge_read_history: purge_read_history__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("privacy_settings_def.v1.UpdatePrivacySettingsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpdatePrivacySettingsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.settings.is_some() {
+            len += 1;
+        }
+        if self.converted_follow_count != 0 {
+            len += 1;
+        }
+        if self.declined_friend_request_count != 0 {
+            len += 1;
+        }
+        if self.declined_collaboration_request_count != 0 {
+            len += 1;
+        }
+        if self.declined_blog_request_count != 0 {
+            len += 1;
+        }
+        if self.read_history_purged {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("privacy_settings_def.v1.UpdatePrivacySettingsResponse", len)?;
+        if let Some(v) = self.settings.as_ref() {
+            struct_ser.serialize_field("settings", v)?;
+        }
+        if self.converted_follow_count != 0 {
+            struct_ser.serialize_field("convertedFollowCount", &self.converted_follow_count)?;
+        }
+        if self.declined_friend_request_count != 0 {
+            struct_ser.serialize_field("declinedFriendRequestCount", &self.declined_friend_request_count)?;
+        }
+        if self.declined_collaboration_request_count != 0 {
+            struct_ser.serialize_field("declinedCollaborationRequestCount", &self.declined_collaboration_request_count)?;
+        }
+        if self.declined_blog_request_count != 0 {
+            struct_ser.serialize_field("declinedBlogRequestCount", &self.declined_blog_request_count)?;
+        }
+        if self.read_history_purged {
+            struct_ser.serialize_field("readHistoryPurged", &self.read_history_purged)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdatePrivacySettingsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "settings",
+            "converted_follow_count",
+            "convertedFollowCount",
+            "declined_friend_request_count",
+            "declinedFriendRequestCount",
+            "declined_collaboration_request_count",
+            "declinedCollaborationRequestCount",
+            "declined_blog_request_count",
+            "declinedBlogRequestCount",
+            "read_history_purged",
+            "readHistoryPurged",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Settings,
+            ConvertedFollowCount,
+            DeclinedFriendRequestCount,
+            DeclinedCollaborationRequestCount,
+            DeclinedBlogRequestCount,
+            ReadHistoryPurged,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "settings" => Ok(GeneratedField::Settings),
+                            "convertedFollowCount" | "converted_follow_count" => Ok(GeneratedField::ConvertedFollowCount),
+                            "declinedFriendRequestCount" | "declined_friend_request_count" => Ok(GeneratedField::DeclinedFriendRequestCount),
+                            "declinedCollaborationRequestCount" | "declined_collaboration_request_count" => Ok(GeneratedField::DeclinedCollaborationRequestCount),
+                            "declinedBlogRequestCount" | "declined_blog_request_count" => Ok(GeneratedField::DeclinedBlogRequestCount),
+                            "readHistoryPurged" | "read_history_purged" => Ok(GeneratedField::ReadHistoryPurged),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdatePrivacySettingsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct privacy_settings_def.v1.UpdatePrivacySettingsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UpdatePrivacySettingsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut settings__ = None;
+                let mut converted_follow_count__ = None;
+                let mut declined_friend_request_count__ = None;
+                let mut declined_collaboration_request_count__ = None;
+                let mut declined_blog_request_count__ = None;
+                let mut read_history_purged__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Settings => {
+                            if settings__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("settings"));
+                            }
+                            settings__ = map.next_value()?;
+                        }
+                        GeneratedField::ConvertedFollowCount => {
+                            if converted_follow_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("convertedFollowCount"));
+                            }
+                            converted_follow_count__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DeclinedFriendRequestCount => {
+                            if declined_friend_request_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("declinedFriendRequestCount"));
+                            }
+                            declined_friend_request_count__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DeclinedCollaborationRequestCount => {
+                            if declined_collaboration_request_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("declinedCollaborationRequestCount"));
+                            }
+                            declined_collaboration_request_count__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DeclinedBlogRequestCount => {
+                            if declined_blog_request_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("declinedBlogRequestCount"));
+                            }
+                            declined_blog_request_count__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ReadHistoryPurged => {
+                            if read_history_purged__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("readHistoryPurged"));
+                            }
+                            read_history_purged__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(UpdatePrivacySettingsResponse {
+                    settings: settings__,
+                    converted_follow_count: converted_follow_count__.unwrap_or_default(),
+                    declined_friend_request_count: declined_friend_request_count__.unwrap_or_default(),
+                    declined_collaboration_request_count: declined_collaboration_request_count__.unwrap_or_default(),
+                    declined_blog_request_count: declined_blog_request_count__.unwrap_or_default(),
+                    read_history_purged: read_history_purged__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("privacy_settings_def.v1.UpdatePrivacySettingsResponse", FIELDS, GeneratedVisitor)
+    }
+}
@@ -33,8 +33,10 @@ pub struct GetProfileResponse {
     pub banner_hex: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(string, tag="11")]
     pub location: ::prost::alloc::string::String,
+    #[deprecated]
     #[prost(string, tag="12")]
     pub created_at: ::prost::alloc::string::String,
+    /// Bitfield of `user_def.v1.UserFlag`
     #[prost(uint32, tag="13")]
     pub public_flags: u32,
     #[prost(uint32, tag="14")]
@@ -71,5 +73,60 @@ pub struct GetProfileResponse {
     pub is_self: bool,
     #[prost(bool, tag="29")]
     pub is_plus_member: bool,
+    /// Timestamps
+    #[prost(message, optional, tag="30")]
+    pub created_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+// Update profile
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateProfileRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    /// Only the fields in the mask are updated
+    #[prost(message, optional, tag="2")]
+    pub update_mask: ::core::option::Option<::pbjson_types::FieldMask>,
+    #[prost(string, tag="3")]
+    pub name: ::prost::alloc::string::String,
+    /// Subject to a cooldown and the reserved names
+    #[prost(string, tag="4")]
+    pub username: ::prost::alloc::string::String,
+    /// Markdown source for `rendered_bio`
+    #[prost(string, optional, tag="5")]
+    pub bio: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="6")]
+    pub location: ::prost::alloc::string::String,
+    /// ID of an uploaded image, or omitted to remove the avatar
+    #[prost(string, optional, tag="7")]
+    pub avatar_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// ID of an uploaded image, or omitted to remove the banner
+    #[prost(string, optional, tag="8")]
+    pub banner_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateProfileResponse {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub username: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub bio: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="4")]
+    pub rendered_bio: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="5")]
+    pub location: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="6")]
+    pub avatar_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="7")]
+    pub avatar_hex: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="8")]
+    pub banner_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="9")]
+    pub banner_hex: ::core::option::Option<::prost::alloc::string::String>,
+    /// Omitted when the username can be changed right away
+    #[prost(message, optional, tag="10")]
+    pub next_username_change_at: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 // @@protoc_insertion_point(module)
@@ -203,6 +203,9 @@ impl serde::Serialize for GetProfileResponse {
         if self.is_plus_member {
             len += 1;
         }
+        if self.created_at_ts.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("profile_def.v1.GetProfileResponse", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
@@ -291,6 +294,9 @@ impl serde::Serialize for GetProfileResponse {
         if self.is_plus_member {
             struct_ser.serialize_field("isPlusMember", &self.is_plus_member)?;
         }
+        if let Some(v) = self.created_at_ts.as_ref() {
+            struct_ser.serialize_field("createdAtTs", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -352,6 +358,8 @@ impl<'de> serde::Deserialize<'de> for GetProfileResponse {
             "isSelf",
             "is_plus_member",
             "isPlusMember",
+            "created_at_ts",
+            "createdAtTs",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -385,6 +393,7 @@ impl<'de> serde::Deserialize<'de> for GetProfileResponse {
             IsMuted,
             IsSelf,
             IsPlusMember,
+            CreatedAtTs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -435,6 +444,7 @@ impl<'de> serde::Deserialize<'de> for GetProfileResponse {
                             "isMuted" | "is_muted" => Ok(GeneratedField::IsMuted),
                             "isSelf" | "is_self" => Ok(GeneratedField::IsSelf),
                             "isPlusMember" | "is_plus_member" => Ok(GeneratedField::IsPlusMember),
+                            "createdAtTs" | "created_at_ts" => Ok(GeneratedField::CreatedAtTs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -483,6 +493,7 @@ impl<'de> serde::Deserialize<'de> for GetProfileResponse {
                 let mut is_muted__ = None;
                 let mut is_self__ = None;
                 let mut is_plus_member__ = None;
+                let mut created_at_ts__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -669,6 +680,12 @@ impl<'de> serde::Deserialize<'de> for GetProfileResponse {
                             }
                             is_plus_member__ = Some(map.next_value()?);
                         }
+                        GeneratedField::CreatedAtTs => {
+                            if created_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAtTs"));
+                            }
+                            created_at_ts__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(GetProfileResponse {
@@ -701,9 +718,474 @@ impl<'de> serde::Deserialize<'de> for GetProfileResponse {
                     is_muted: is_muted__.unwrap_or_default(),
                     is_self: is_self__.unwrap_or_default(),
                     is_plus_member: is_plus_member__.unwrap_or_default(),
+                    created_at_ts: created_at_ts__,
                 })
             }
         }
         deserializer.deserialize_struct("profile_def.v1.GetProfileResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for UpdateProfileRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if self.update_mask.is_some() {
+            len += 1;
+        }
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if !self.username.is_empty() {
+            len += 1;
+        }
+        if self.bio.is_some() {
+            len += 1;
+        }
+        if !self.location.is_empty() {
+            len += 1;
+        }
+        if self.avatar_id.is_some() {
+            len += 1;
+        }
+        if self.banner_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("profile_def.v1.UpdateProfileRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if let Some(v) = self.update_mask.as_ref() {
+            struct_ser.serialize_field("updateMask", v)?;
+        }
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.username.is_empty() {
+            struct_ser.serialize_field("username", &self.username)?;
+        }
+        if let Some(v) = self.bio.as_ref() {
+            struct_ser.serialize_field("bio", v)?;
+        }
+        if !self.location.is_empty() {
+            struct_ser.serialize_field("location", &self.location)?;
+        }
+        if let Some(v) = self.avatar_id.as_ref() {
+            struct_ser.serialize_field("avatarId", v)?;
+        }
+        if let Some(v) = self.banner_id.as_ref() {
+            struct_ser.serialize_field("bannerId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdateProfileRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "update_mask",
+            "updateMask",
+            "name",
+            "username",
+            "bio",
+            "location",
+            "avatar_id",
+            "avatarId",
+            "banner_id",
+            "bannerId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            UpdateMask,
+            Name,
+            Username,
+            Bio,
+            Location,
+            AvatarId,
+            BannerId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "updateMask" | "update_mask" => Ok(GeneratedField::UpdateMask),
+                            "name" => Ok(GeneratedField::Name),
+                            "username" => Ok(GeneratedField::Username),
+                            "bio" => Ok(GeneratedField::Bio),
+                            "location" => Ok(GeneratedField::Location),
+                            "avatarId" | "avatar_id" => Ok(GeneratedField::AvatarId),
+                            "bannerId" | "banner_id" => Ok(GeneratedField::BannerId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdateProfileRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct profile_def.v1.UpdateProfileRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UpdateProfileRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut update_mask__ = None;
+                let mut name__ = None;
+                let mut username__ = None;
+                let mut bio__ = None;
+                let mut location__ = None;
+                let mut avatar_id__ = None;
+                let mut banner_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::UpdateMask => {
+                            if update_mask__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updateMask"));
+                            }
+                            update_mask__ = map.next_value()?;
+                        }
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Username => {
+                            if username__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("username"));
+                            }
+                            username__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Bio => {
+                            if bio__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bio"));
+                            }
+                            bio__ = map.next_value()?;
+                        }
+                        GeneratedField::Location => {
+                            if location__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("location"));
+                            }
+                            location__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::AvatarId => {
+                            if avatar_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("avatarId"));
+                            }
+                            avatar_id__ = map.next_value()?;
+                        }
+                        GeneratedField::BannerId => {
+                            if banner_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bannerId"));
+                            }
+                            banner_id__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(UpdateProfileRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                    update_mask: update_mask__,
+                    name: name__.unwrap_or_default(),
+                    username: username__.unwrap_or_default(),
+                    bio: bio__,
+                    location: location__.unwrap_or_default(),
+                    avatar_id: avatar_id__,
+                    banner_id: banner_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("profile_def.v1.UpdateProfileRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpdateProfileResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.name.is_empty() {
+            len += 1;
+        }
+        if !self.username.is_empty() {
+            len += 1;
+        }
+        if self.bio.is_some() {
+            len += 1;
+        }
+        if self.rendered_bio.is_some() {
+            len += 1;
+        }
+        if !self.location.is_empty() {
+            len += 1;
+        }
+        if self.avatar_id.is_some() {
+            len += 1;
+        }
+        if self.avatar_hex.is_some() {
+            len += 1;
+        }
+        if self.banner_id.is_some() {
+            len += 1;
+        }
+        if self.banner_hex.is_some() {
+            len += 1;
+        }
+        if self.next_username_change_at.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("profile_def.v1.UpdateProfileResponse", len)?;
+        if !self.name.is_empty() {
+            struct_ser.serialize_field("name", &self.name)?;
+        }
+        if !self.username.is_empty() {
+            struct_ser.serialize_field("username", &self.username)?;
+        }
+        if let Some(v) = self.bio.as_ref() {
+            struct_ser.serialize_field("bio", v)?;
+        }
+        if let Some(v) = self.rendered_bio.as_ref() {
+            struct_ser.serialize_field("renderedBio", v)?;
+        }
+        if !self.location.is_empty() {
+            struct_ser.serialize_field("location", &self.location)?;
+        }
+        if let Some(v) = self.avatar_id.as_ref() {
+            struct_ser.serialize_field("avatarId", v)?;
+        }
+        if let Some(v) = self.avatar_hex.as_ref() {
+            struct_ser.serialize_field("avatarHex", v)?;
+        }
+        if let Some(v) = self.banner_id.as_ref() {
+            struct_ser.serialize_field("bannerId", v)?;
+        }
+        if let Some(v) = self.banner_hex.as_ref() {
+            struct_ser.serialize_field("bannerHex", v)?;
+        }
+        if let Some(v) = self.next_username_change_at.as_ref() {
+            struct_ser.serialize_field("nextUsernameChangeAt", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdateProfileResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "name",
+            "username",
+            "bio",
+            "rendered_bio",
+            "renderedBio",
+            "location",
+            "avatar_id",
+            "avatarId",
+            "avatar_hex",
+            "avatarHex",
+            "banner_id",
+            "bannerId",
+            "banner_hex",
+            "bannerHex",
+            "next_username_change_at",
+            "nextUsernameChangeAt",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Name,
+            Username,
+            Bio,
+            RenderedBio,
+            Location,
+            AvatarId,
+            AvatarHex,
+            BannerId,
+            BannerHex,
+            NextUsernameChangeAt,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "name" => Ok(GeneratedField::Name),
+                            "username" => Ok(GeneratedField::Username),
+                            "bio" => Ok(GeneratedField::Bio),
+                            "renderedBio" | "rendered_bio" => Ok(GeneratedField::RenderedBio),
+                            "location" => Ok(GeneratedField::Location),
+                            "avatarId" | "avatar_id" => Ok(GeneratedField::AvatarId),
+                            "avatarHex" | "avatar_hex" => Ok(GeneratedField::AvatarHex),
+                            "bannerId" | "banner_id" => Ok(GeneratedField::BannerId),
+                            "bannerHex" | "banner_hex" => Ok(GeneratedField::BannerHex),
+                            "nextUsernameChangeAt" | "next_username_change_at" => Ok(GeneratedField::NextUsernameChangeAt),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdateProfileResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct profile_def.v1.UpdateProfileResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UpdateProfileResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut name__ = None;
+                let mut username__ = None;
+                let mut bio__ = None;
+                let mut rendered_bio__ = None;
+                let mut location__ = None;
+                let mut avatar_id__ = None;
+                let mut avatar_hex__ = None;
+                let mut banner_id__ = None;
+                let mut banner_hex__ = None;
+                let mut next_username_change_at__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Name => {
+                            if name__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("name"));
+                            }
+                            name__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Username => {
+                            if username__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("username"));
+                            }
+                            username__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Bio => {
+                            if bio__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bio"));
+                            }
+                            bio__ = map.next_value()?;
+                        }
+                        GeneratedField::RenderedBio => {
+                            if rendered_bio__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("renderedBio"));
+                            }
+                            rendered_bio__ = map.next_value()?;
+                        }
+                        GeneratedField::Location => {
+                            if location__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("location"));
+                            }
+                            location__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::AvatarId => {
+                            if avatar_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("avatarId"));
+                            }
+                            avatar_id__ = map.next_value()?;
+                        }
+                        GeneratedField::AvatarHex => {
+                            if avatar_hex__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("avatarHex"));
+                            }
+                            avatar_hex__ = map.next_value()?;
+                        }
+                        GeneratedField::BannerId => {
+                            if banner_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bannerId"));
+                            }
+                            banner_id__ = map.next_value()?;
+                        }
+                        GeneratedField::BannerHex => {
+                            if banner_hex__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bannerHex"));
+                            }
+                            banner_hex__ = map.next_value()?;
+                        }
+                        GeneratedField::NextUsernameChangeAt => {
+                            if next_username_change_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextUsernameChangeAt"));
+                            }
+                            next_username_change_at__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(UpdateProfileResponse {
+                    name: name__.unwrap_or_default(),
+                    username: username__.unwrap_or_default(),
+                    bio: bio__,
+                    rendered_bio: rendered_bio__,
+                    location: location__.unwrap_or_default(),
+                    avatar_id: avatar_id__,
+                    avatar_hex: avatar_hex__,
+                    banner_id: banner_id__,
+                    banner_hex: banner_hex__,
+                    next_username_change_at: next_username_change_at__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("profile_def.v1.UpdateProfileResponse", FIELDS, GeneratedVisitor)
+    }
+}
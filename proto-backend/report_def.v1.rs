@@ -0,0 +1,253 @@
+// @generated
+// Report
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Report {
+    #[prost(string, tag="1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(enumeration="ReportEntityType", tag="2")]
+    pub entity_type: i32,
+    #[prost(string, tag="3")]
+    pub entity_id: ::prost::alloc::string::String,
+    #[prost(enumeration="ReportReason", tag="4")]
+    pub reason: i32,
+    #[prost(string, optional, tag="5")]
+    pub description: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag="6")]
+    pub reporter: ::core::option::Option<super::super::user_def::v1::BareUser>,
+    #[prost(message, optional, tag="7")]
+    pub created_at: ::core::option::Option<::pbjson_types::Timestamp>,
+    /// Resolution props
+    #[prost(enumeration="ReportResolution", optional, tag="8")]
+    pub resolution: ::core::option::Option<i32>,
+    #[prost(message, optional, tag="9")]
+    pub resolved_at: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(string, optional, tag="10")]
+    pub moderator_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ModerationAuditLogEntry {
+    #[prost(string, tag="1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub moderator_id: ::prost::alloc::string::String,
+    #[prost(enumeration="ReportResolution", tag="3")]
+    pub action: i32,
+    #[prost(enumeration="ReportEntityType", tag="4")]
+    pub entity_type: i32,
+    #[prost(string, tag="5")]
+    pub entity_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="6")]
+    pub report_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="7")]
+    pub note: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag="8")]
+    pub created_at: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+// Report content
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReportContentRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(enumeration="ReportEntityType", tag="2")]
+    pub entity_type: i32,
+    #[prost(string, tag="3")]
+    pub entity_id: ::prost::alloc::string::String,
+    #[prost(enumeration="ReportReason", tag="4")]
+    pub reason: i32,
+    #[prost(string, optional, tag="5")]
+    pub description: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReportContentResponse {
+    #[prost(string, tag="1")]
+    pub report_id: ::prost::alloc::string::String,
+}
+// List reports
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListReportsRequest {
+    #[prost(string, tag="1")]
+    pub moderator_id: ::prost::alloc::string::String,
+    /// `next_cursor` from the previous page
+    #[prost(string, optional, tag="2")]
+    pub cursor: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag="3")]
+    pub limit: u32,
+    #[prost(bool, tag="4")]
+    pub pending_only: bool,
+    #[prost(enumeration="ReportEntityType", optional, tag="5")]
+    pub entity_type: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListReportsResponse {
+    #[prost(message, repeated, tag="1")]
+    pub reports: ::prost::alloc::vec::Vec<Report>,
+    /// Omitted on the last page
+    #[prost(string, optional, tag="2")]
+    pub next_cursor: ::core::option::Option<::prost::alloc::string::String>,
+}
+// Resolve report
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResolveReportRequest {
+    #[prost(string, tag="1")]
+    pub moderator_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub report_id: ::prost::alloc::string::String,
+    #[prost(enumeration="ReportResolution", tag="3")]
+    pub resolution: i32,
+    /// Recorded in the audit log and included in the warning
+    #[prost(string, optional, tag="4")]
+    pub note: ::core::option::Option<::prost::alloc::string::String>,
+    /// Suspension period, omitted for a permanent suspension
+    #[prost(uint32, optional, tag="5")]
+    pub suspension_days: ::core::option::Option<u32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResolveReportResponse {
+    #[prost(message, optional, tag="1")]
+    pub report: ::core::option::Option<Report>,
+    #[prost(message, optional, tag="2")]
+    pub audit_log_entry: ::core::option::Option<ModerationAuditLogEntry>,
+    /// Set when the content has been taken down
+    #[prost(message, optional, tag="3")]
+    pub deleted_at: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ReportEntityType {
+    Unspecified = 0,
+    Story = 1,
+    Comment = 2,
+    User = 3,
+    Blog = 4,
+    Tag = 5,
+}
+impl ReportEntityType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ReportEntityType::Unspecified => "REPORT_ENTITY_TYPE_UNSPECIFIED",
+            ReportEntityType::Story => "REPORT_ENTITY_TYPE_STORY",
+            ReportEntityType::Comment => "REPORT_ENTITY_TYPE_COMMENT",
+            ReportEntityType::User => "REPORT_ENTITY_TYPE_USER",
+            ReportEntityType::Blog => "REPORT_ENTITY_TYPE_BLOG",
+            ReportEntityType::Tag => "REPORT_ENTITY_TYPE_TAG",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "REPORT_ENTITY_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "REPORT_ENTITY_TYPE_STORY" => Some(Self::Story),
+            "REPORT_ENTITY_TYPE_COMMENT" => Some(Self::Comment),
+            "REPORT_ENTITY_TYPE_USER" => Some(Self::User),
+            "REPORT_ENTITY_TYPE_BLOG" => Some(Self::Blog),
+            "REPORT_ENTITY_TYPE_TAG" => Some(Self::Tag),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ReportReason {
+    Unspecified = 0,
+    Spam = 1,
+    Harassment = 2,
+    HateSpeech = 3,
+    Violence = 4,
+    SexualContent = 5,
+    SelfHarm = 6,
+    Misinformation = 7,
+    Copyright = 8,
+    Impersonation = 9,
+    Other = 10,
+}
+impl ReportReason {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ReportReason::Unspecified => "REPORT_REASON_UNSPECIFIED",
+            ReportReason::Spam => "REPORT_REASON_SPAM",
+            ReportReason::Harassment => "REPORT_REASON_HARASSMENT",
+            ReportReason::HateSpeech => "REPORT_REASON_HATE_SPEECH",
+            ReportReason::Violence => "REPORT_REASON_VIOLENCE",
+            ReportReason::SexualContent => "REPORT_REASON_SEXUAL_CONTENT",
+            ReportReason::SelfHarm => "REPORT_REASON_SELF_HARM",
+            ReportReason::Misinformation => "REPORT_REASON_MISINFORMATION",
+            ReportReason::Copyright => "REPORT_REASON_COPYRIGHT",
+            ReportReason::Impersonation => "REPORT_REASON_IMPERSONATION",
+            ReportReason::Other => "REPORT_REASON_OTHER",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "REPORT_REASON_UNSPECIFIED" => Some(Self::Unspecified),
+            "REPORT_REASON_SPAM" => Some(Self::Spam),
+            "REPORT_REASON_HARASSMENT" => Some(Self::Harassment),
+            "REPORT_REASON_HATE_SPEECH" => Some(Self::HateSpeech),
+            "REPORT_REASON_VIOLENCE" => Some(Self::Violence),
+            "REPORT_REASON_SEXUAL_CONTENT" => Some(Self::SexualContent),
+            "REPORT_REASON_SELF_HARM" => Some(Self::SelfHarm),
+            "REPORT_REASON_MISINFORMATION" => Some(Self::Misinformation),
+            "REPORT_REASON_COPYRIGHT" => Some(Self::Copyright),
+            "REPORT_REASON_IMPERSONATION" => Some(Self::Impersonation),
+            "REPORT_REASON_OTHER" => Some(Self::Other),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ReportResolution {
+    Unspecified = 0,
+    /// Sets `deleted_at` on the reported content
+    TakeDown = 1,
+    WarnUser = 2,
+    SuspendUser = 3,
+    Dismiss = 4,
+}
+impl ReportResolution {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ReportResolution::Unspecified => "REPORT_RESOLUTION_UNSPECIFIED",
+            ReportResolution::TakeDown => "REPORT_RESOLUTION_TAKE_DOWN",
+            ReportResolution::WarnUser => "REPORT_RESOLUTION_WARN_USER",
+            ReportResolution::SuspendUser => "REPORT_RESOLUTION_SUSPEND_USER",
+            ReportResolution::Dismiss => "REPORT_RESOLUTION_DISMISS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "REPORT_RESOLUTION_UNSPECIFIED" => Some(Self::Unspecified),
+            "REPORT_RESOLUTION_TAKE_DOWN" => Some(Self::TakeDown),
+            "REPORT_RESOLUTION_WARN_USER" => Some(Self::WarnUser),
+            "REPORT_RESOLUTION_SUSPEND_USER" => Some(Self::SuspendUser),
+            "REPORT_RESOLUTION_DISMISS" => Some(Self::Dismiss),
+            _ => None,
+        }
+    }
+}
+// @@protoc_insertion_point(module)
@@ -0,0 +1,1568 @@
+// @generated
+impl serde::Serialize for ListReportsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.moderator_id.is_empty() {
+            len += 1;
+        }
+        if self.cursor.is_some() {
+            len += 1;
+        }
+        if self.limit != 0 {
+            len += 1;
+        }
+        if self.pending_only {
+            len += 1;
+        }
+        if self.entity_type.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("report_def.v1.ListReportsRequest", len)?;
+        if !self.moderator_id.is_empty() {
+            struct_ser.serialize_field("moderatorId", &self.moderator_id)?;
+        }
+        if let Some(v) = self.cursor.as_ref() {
+            struct_ser.serialize_field("cursor", v)?;
+        }
+        if self.limit != 0 {
+            struct_ser.serialize_field("limit", &self.limit)?;
+        }
+        if self.pending_only {
+            struct_ser.serialize_field("pendingOnly", &self.pending_only)?;
+        }
+        if let Some(v) = self.entity_type.as_ref() {
+            let v = ReportEntityType::from_i32(*v)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", *v)))?;
+            struct_ser.serialize_field("entityType", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListReportsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "moderator_id",
+            "moderatorId",
+            "cursor",
+            "limit",
+            "pending_only",
+            "pendingOnly",
+            "entity_type",
+            "entityType",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ModeratorId,
+            Cursor,
+            Limit,
+            PendingOnly,
+            EntityType,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "moderatorId" | "moderator_id" => Ok(GeneratedField::ModeratorId),
+                            "cursor" => Ok(GeneratedField::Cursor),
+                            "limit" => Ok(GeneratedField::Limit),
+                            "pendingOnly" | "pending_only" => Ok(GeneratedField::PendingOnly),
+                            "entityType" | "entity_type" => Ok(GeneratedField::EntityType),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListReportsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct report_def.v1.ListReportsRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ListReportsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut moderator_id__ = None;
+                let mut cursor__ = None;
+                let mut limit__ = None;
+                let mut pending_only__ = None;
+                let mut entity_type__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ModeratorId => {
+                            if moderator_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("moderatorId"));
+                            }
+                            moderator_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Cursor => {
+                            if cursor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cursor"));
+                            }
+                            cursor__ = map.next_value()?;
+                        }
+                        GeneratedField::Limit => {
+                            if limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limit"));
+                            }
+                            limit__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PendingOnly => {
+                            if pending_only__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pendingOnly"));
+                            }
+                            pending_only__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::EntityType => {
+                            if entity_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entityType"));
+                            }
+                            entity_type__ = map.next_value::<::std::option::Option<ReportEntityType>>()?.map(|x| x as i32);
+                        }
+                    }
+                }
+                Ok(ListReportsRequest {
+                    moderator_id: moderator_id__.unwrap_or_default(),
+                    cursor: cursor__,
+                    limit: limit__.unwrap_or_default(),
+                    pending_only: pending_only__.unwrap_or_default(),
+                    entity_type: entity_type__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("report_def.v1.ListReportsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ListReportsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.reports.is_empty() {
+            len += 1;
+        }
+        if self.next_cursor.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("report_def.v1.ListReportsResponse", len)?;
+        if !self.reports.is_empty() {
+            struct_ser.serialize_field("reports", &self.reports)?;
+        }
+        if let Some(v) = self.next_cursor.as_ref() {
+            struct_ser.serialize_field("nextCursor", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ListReportsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reports",
+            "next_cursor",
+            "nextCursor",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reports,
+            NextCursor,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reports" => Ok(GeneratedField::Reports),
+                            "nextCursor" | "next_cursor" => Ok(GeneratedField::NextCursor),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ListReportsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct report_def.v1.ListReportsResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ListReportsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reports__ = None;
+                let mut next_cursor__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Reports => {
+                            if reports__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reports"));
+                            }
+                            reports__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::NextCursor => {
+                            if next_cursor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextCursor"));
+                            }
+                            next_cursor__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ListReportsResponse {
+                    reports: reports__.unwrap_or_default(),
+                    next_cursor: next_cursor__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("report_def.v1.ListReportsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ModerationAuditLogEntry {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if !self.moderator_id.is_empty() {
+            len += 1;
+        }
+        if self.action != 0 {
+            len += 1;
+        }
+        if self.entity_type != 0 {
+            len += 1;
+        }
+        if !self.entity_id.is_empty() {
+            len += 1;
+        }
+        if self.report_id.is_some() {
+            len += 1;
+        }
+        if self.note.is_some() {
+            len += 1;
+        }
+        if self.created_at.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("report_def.v1.ModerationAuditLogEntry", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if !self.moderator_id.is_empty() {
+            struct_ser.serialize_field("moderatorId", &self.moderator_id)?;
+        }
+        if self.action != 0 {
+            let v = ReportResolution::from_i32(self.action)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.action)))?;
+            struct_ser.serialize_field("action", &v)?;
+        }
+        if self.entity_type != 0 {
+            let v = ReportEntityType::from_i32(self.entity_type)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.entity_type)))?;
+            struct_ser.serialize_field("entityType", &v)?;
+        }
+        if !self.entity_id.is_empty() {
+            struct_ser.serialize_field("entityId", &self.entity_id)?;
+        }
+        if let Some(v) = self.report_id.as_ref() {
+            struct_ser.serialize_field("reportId", v)?;
+        }
+        if let Some(v) = self.note.as_ref() {
+            struct_ser.serialize_field("note", v)?;
+        }
+        if let Some(v) = self.created_at.as_ref() {
+            struct_ser.serialize_field("createdAt", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ModerationAuditLogEntry {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "moderator_id",
+            "moderatorId",
+            "action",
+            "entity_type",
+            "entityType",
+            "entity_id",
+            "entityId",
+            "report_id",
+            "reportId",
+            "note",
+            "created_at",
+            "createdAt",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            ModeratorId,
+            Action,
+            EntityType,
+            EntityId,
+            ReportId,
+            Note,
+            CreatedAt,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "moderatorId" | "moderator_id" => Ok(GeneratedField::ModeratorId),
+                            "action" => Ok(GeneratedField::Action),
+                            "entityType" | "entity_type" => Ok(GeneratedField::EntityType),
+                            "entityId" | "entity_id" => Ok(GeneratedField::EntityId),
+                            "reportId" | "report_id" => Ok(GeneratedField::ReportId),
+                            "note" => Ok(GeneratedField::Note),
+                            "createdAt" | "created_at" => Ok(GeneratedField::CreatedAt),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ModerationAuditLogEntry;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct report_def.v1.ModerationAuditLogEntry")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ModerationAuditLogEntry, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut moderator_id__ = None;
+                let mut action__ = None;
+                let mut entity_type__ = None;
+                let mut entity_id__ = None;
+                let mut report_id__ = None;
+                let mut note__ = None;
+                let mut created_at__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ModeratorId => {
+                            if moderator_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("moderatorId"));
+                            }
+                            moderator_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Action => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("action"));
+                            }
+                            action__ = Some(map.next_value::<ReportResolution>()? as i32);
+                        }
+                        GeneratedField::EntityType => {
+                            if entity_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entityType"));
+                            }
+                            entity_type__ = Some(map.next_value::<ReportEntityType>()? as i32);
+                        }
+                        GeneratedField::EntityId => {
+                            if entity_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entityId"));
+                            }
+                            entity_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ReportId => {
+                            if report_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reportId"));
+                            }
+                            report_id__ = map.next_value()?;
+                        }
+                        GeneratedField::Note => {
+                            if note__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("note"));
+                            }
+                            note__ = map.next_value()?;
+                        }
+                        GeneratedField::CreatedAt => {
+                            if created_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAt"));
+                            }
+                            created_at__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ModerationAuditLogEntry {
+                    id: id__.unwrap_or_default(),
+                    moderator_id: moderator_id__.unwrap_or_default(),
+                    action: action__.unwrap_or_default(),
+                    entity_type: entity_type__.unwrap_or_default(),
+                    entity_id: entity_id__.unwrap_or_default(),
+                    report_id: report_id__,
+                    note: note__,
+                    created_at: created_at__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("report_def.v1.ModerationAuditLogEntry", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Report {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id.is_empty() {
+            len += 1;
+        }
+        if self.entity_type != 0 {
+            len += 1;
+        }
+        if !self.entity_id.is_empty() {
+            len += 1;
+        }
+        if self.reason != 0 {
+            len += 1;
+        }
+        if self.description.is_some() {
+            len += 1;
+        }
+        if self.reporter.is_some() {
+            len += 1;
+        }
+        if self.created_at.is_some() {
+            len += 1;
+        }
+        if self.resolution.is_some() {
+            len += 1;
+        }
+        if self.resolved_at.is_some() {
+            len += 1;
+        }
+        if self.moderator_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("report_def.v1.Report", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
+        }
+        if self.entity_type != 0 {
+            let v = ReportEntityType::from_i32(self.entity_type)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.entity_type)))?;
+            struct_ser.serialize_field("entityType", &v)?;
+        }
+        if !self.entity_id.is_empty() {
+            struct_ser.serialize_field("entityId", &self.entity_id)?;
+        }
+        if self.reason != 0 {
+            let v = ReportReason::from_i32(self.reason)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.reason)))?;
+            struct_ser.serialize_field("reason", &v)?;
+        }
+        if let Some(v) = self.description.as_ref() {
+            struct_ser.serialize_field("description", v)?;
+        }
+        if let Some(v) = self.reporter.as_ref() {
+            struct_ser.serialize_field("reporter", v)?;
+        }
+        if let Some(v) = self.created_at.as_ref() {
+            struct_ser.serialize_field("createdAt", v)?;
+        }
+        if let Some(v) = self.resolution.as_ref() {
+            let v = ReportResolution::from_i32(*v)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", *v)))?;
+            struct_ser.serialize_field("resolution", &v)?;
+        }
+        if let Some(v) = self.resolved_at.as_ref() {
+            struct_ser.serialize_field("resolvedAt", v)?;
+        }
+        if let Some(v) = self.moderator_id.as_ref() {
+            struct_ser.serialize_field("moderatorId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Report {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "entity_type",
+            "entityType",
+            "entity_id",
+            "entityId",
+            "reason",
+            "description",
+            "reporter",
+            "created_at",
+            "createdAt",
+            "resolution",
+            "resolved_at",
+            "resolvedAt",
+            "moderator_id",
+            "moderatorId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            EntityType,
+            EntityId,
+            Reason,
+            Description,
+            Reporter,
+            CreatedAt,
+            Resolution,
+            ResolvedAt,
+            ModeratorId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "entityType" | "entity_type" => Ok(GeneratedField::EntityType),
+                            "entityId" | "entity_id" => Ok(GeneratedField::EntityId),
+                            "reason" => Ok(GeneratedField::Reason),
+                            "description" => Ok(GeneratedField::Description),
+                            "reporter" => Ok(GeneratedField::Reporter),
+                            "createdAt" | "created_at" => Ok(GeneratedField::CreatedAt),
+                            "resolution" => Ok(GeneratedField::Resolution),
+                            "resolvedAt" | "resolved_at" => Ok(GeneratedField::ResolvedAt),
+                            "moderatorId" | "moderator_id" => Ok(GeneratedField::ModeratorId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Report;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct report_def.v1.Report")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Report, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut entity_type__ = None;
+                let mut entity_id__ = None;
+                let mut reason__ = None;
+                let mut description__ = None;
+                let mut reporter__ = None;
+                let mut created_at__ = None;
+                let mut resolution__ = None;
+                let mut resolved_at__ = None;
+                let mut moderator_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::EntityType => {
+                            if entity_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entityType"));
+                            }
+                            entity_type__ = Some(map.next_value::<ReportEntityType>()? as i32);
+                        }
+                        GeneratedField::EntityId => {
+                            if entity_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entityId"));
+                            }
+                            entity_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map.next_value::<ReportReason>()? as i32);
+                        }
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = map.next_value()?;
+                        }
+                        GeneratedField::Reporter => {
+                            if reporter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reporter"));
+                            }
+                            reporter__ = map.next_value()?;
+                        }
+                        GeneratedField::CreatedAt => {
+                            if created_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAt"));
+                            }
+                            created_at__ = map.next_value()?;
+                        }
+                        GeneratedField::Resolution => {
+                            if resolution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("resolution"));
+                            }
+                            resolution__ = map.next_value::<::std::option::Option<ReportResolution>>()?.map(|x| x as i32);
+                        }
+                        GeneratedField::ResolvedAt => {
+                            if resolved_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("resolvedAt"));
+                            }
+                            resolved_at__ = map.next_value()?;
+                        }
+                        GeneratedField::ModeratorId => {
+                            if moderator_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("moderatorId"));
+                            }
+                            moderator_id__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(Report {
+                    id: id__.unwrap_or_default(),
+                    entity_type: entity_type__.unwrap_or_default(),
+                    entity_id: entity_id__.unwrap_or_default(),
+                    reason: reason__.unwrap_or_default(),
+                    description: description__,
+                    reporter: reporter__,
+                    created_at: created_at__,
+                    resolution: resolution__,
+                    resolved_at: resolved_at__,
+                    moderator_id: moderator_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("report_def.v1.Report", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReportContentRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if self.entity_type != 0 {
+            len += 1;
+        }
+        if !self.entity_id.is_empty() {
+            len += 1;
+        }
+        if self.reason != 0 {
+            len += 1;
+        }
+        if self.description.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("report_def.v1.ReportContentRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if self.entity_type != 0 {
+            let v = ReportEntityType::from_i32(self.entity_type)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.entity_type)))?;
+            struct_ser.serialize_field("entityType", &v)?;
+        }
+        if !self.entity_id.is_empty() {
+            struct_ser.serialize_field("entityId", &self.entity_id)?;
+        }
+        if self.reason != 0 {
+            let v = ReportReason::from_i32(self.reason)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.reason)))?;
+            struct_ser.serialize_field("reason", &v)?;
+        }
+        if let Some(v) = self.description.as_ref() {
+            struct_ser.serialize_field("description", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReportContentRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "entity_type",
+            "entityType",
+            "entity_id",
+            "entityId",
+            "reason",
+            "description",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            EntityType,
+            EntityId,
+            Reason,
+            Description,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "entityType" | "entity_type" => Ok(GeneratedField::EntityType),
+                            "entityId" | "entity_id" => Ok(GeneratedField::EntityId),
+                            "reason" => Ok(GeneratedField::Reason),
+                            "description" => Ok(GeneratedField::Description),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReportContentRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct report_def.v1.ReportContentRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ReportContentRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut entity_type__ = None;
+                let mut entity_id__ = None;
+                let mut reason__ = None;
+                let mut description__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::EntityType => {
+                            if entity_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entityType"));
+                            }
+                            entity_type__ = Some(map.next_value::<ReportEntityType>()? as i32);
+                        }
+                        GeneratedField::EntityId => {
+                            if entity_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entityId"));
+                            }
+                            entity_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map.next_value::<ReportReason>()? as i32);
+                        }
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ReportContentRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                    entity_type: entity_type__.unwrap_or_default(),
+                    entity_id: entity_id__.unwrap_or_default(),
+                    reason: reason__.unwrap_or_default(),
+                    description: description__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("report_def.v1.ReportContentRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReportContentResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.report_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("report_def.v1.ReportContentResponse", len)?;
+        if !self.report_id.is_empty() {
+            struct_ser.serialize_field("reportId", &self.report_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReportContentResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "report_id",
+            "reportId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ReportId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reportId" | "report_id" => Ok(GeneratedField::ReportId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReportContentResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct report_def.v1.ReportContentResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ReportContentResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut report_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ReportId => {
+                            if report_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reportId"));
+                            }
+                            report_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(ReportContentResponse {
+                    report_id: report_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("report_def.v1.ReportContentResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReportEntityType {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => 0,
+            Self::Story => 1,
+            Self::Comment => 2,
+            Self::User => 3,
+            Self::Blog => 4,
+            Self::Tag => 5,
+        };
+        serializer.serialize_i32(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReportEntityType {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "REPORT_ENTITY_TYPE_UNSPECIFIED",
+            "REPORT_ENTITY_TYPE_STORY",
+            "REPORT_ENTITY_TYPE_COMMENT",
+            "REPORT_ENTITY_TYPE_USER",
+            "REPORT_ENTITY_TYPE_BLOG",
+            "REPORT_ENTITY_TYPE_TAG",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReportEntityType;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(ReportEntityType::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(ReportEntityType::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "REPORT_ENTITY_TYPE_UNSPECIFIED" => Ok(ReportEntityType::Unspecified),
+                    "REPORT_ENTITY_TYPE_STORY" => Ok(ReportEntityType::Story),
+                    "REPORT_ENTITY_TYPE_COMMENT" => Ok(ReportEntityType::Comment),
+                    "REPORT_ENTITY_TYPE_USER" => Ok(ReportEntityType::User),
+                    "REPORT_ENTITY_TYPE_BLOG" => Ok(ReportEntityType::Blog),
+                    "REPORT_ENTITY_TYPE_TAG" => Ok(ReportEntityType::Tag),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReportReason {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => 0,
+            Self::Spam => 1,
+            Self::Harassment => 2,
+            Self::HateSpeech => 3,
+            Self::Violence => 4,
+            Self::SexualContent => 5,
+            Self::SelfHarm => 6,
+            Self::Misinformation => 7,
+            Self::Copyright => 8,
+            Self::Impersonation => 9,
+            Self::Other => 10,
+        };
+        serializer.serialize_i32(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReportReason {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "REPORT_REASON_UNSPECIFIED",
+            "REPORT_REASON_SPAM",
+            "REPORT_REASON_HARASSMENT",
+            "REPORT_REASON_HATE_SPEECH",
+            "REPORT_REASON_VIOLENCE",
+            "REPORT_REASON_SEXUAL_CONTENT",
+            "REPORT_REASON_SELF_HARM",
+            "REPORT_REASON_MISINFORMATION",
+            "REPORT_REASON_COPYRIGHT",
+            "REPORT_REASON_IMPERSONATION",
+            "REPORT_REASON_OTHER",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReportReason;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(ReportReason::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(ReportReason::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "REPORT_REASON_UNSPECIFIED" => Ok(ReportReason::Unspecified),
+                    "REPORT_REASON_SPAM" => Ok(ReportReason::Spam),
+                    "REPORT_REASON_HARASSMENT" => Ok(ReportReason::Harassment),
+                    "REPORT_REASON_HATE_SPEECH" => Ok(ReportReason::HateSpeech),
+                    "REPORT_REASON_VIOLENCE" => Ok(ReportReason::Violence),
+                    "REPORT_REASON_SEXUAL_CONTENT" => Ok(ReportReason::SexualContent),
+                    "REPORT_REASON_SELF_HARM" => Ok(ReportReason::SelfHarm),
+                    "REPORT_REASON_MISINFORMATION" => Ok(ReportReason::Misinformation),
+                    "REPORT_REASON_COPYRIGHT" => Ok(ReportReason::Copyright),
+                    "REPORT_REASON_IMPERSONATION" => Ok(ReportReason::Impersonation),
+                    "REPORT_REASON_OTHER" => Ok(ReportReason::Other),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ReportResolution {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => 0,
+            Self::TakeDown => 1,
+            Self::WarnUser => 2,
+            Self::SuspendUser => 3,
+            Self::Dismiss => 4,
+        };
+        serializer.serialize_i32(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for ReportResolution {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "REPORT_RESOLUTION_UNSPECIFIED",
+            "REPORT_RESOLUTION_TAKE_DOWN",
+            "REPORT_RESOLUTION_WARN_USER",
+            "REPORT_RESOLUTION_SUSPEND_USER",
+            "REPORT_RESOLUTION_DISMISS",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ReportResolution;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(ReportResolution::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(ReportResolution::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "REPORT_RESOLUTION_UNSPECIFIED" => Ok(ReportResolution::Unspecified),
+                    "REPORT_RESOLUTION_TAKE_DOWN" => Ok(ReportResolution::TakeDown),
+                    "REPORT_RESOLUTION_WARN_USER" => Ok(ReportResolution::WarnUser),
+                    "REPORT_RESOLUTION_SUSPEND_USER" => Ok(ReportResolution::SuspendUser),
+                    "REPORT_RESOLUTION_DISMISS" => Ok(ReportResolution::Dismiss),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ResolveReportRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.moderator_id.is_empty() {
+            len += 1;
+        }
+        if !self.report_id.is_empty() {
+            len += 1;
+        }
+        if self.resolution != 0 {
+            len += 1;
+        }
+        if self.note.is_some() {
+            len += 1;
+        }
+        if self.suspension_days.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("report_def.v1.ResolveReportRequest", len)?;
+        if !self.moderator_id.is_empty() {
+            struct_ser.serialize_field("moderatorId", &self.moderator_id)?;
+        }
+        if !self.report_id.is_empty() {
+            struct_ser.serialize_field("reportId", &self.report_id)?;
+        }
+        if self.resolution != 0 {
+            let v = ReportResolution::from_i32(self.resolution)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.resolution)))?;
+            struct_ser.serialize_field("resolution", &v)?;
+        }
+        if let Some(v) = self.note.as_ref() {
+            struct_ser.serialize_field("note", v)?;
+        }
+        if let Some(v) = self.suspension_days.as_ref() {
+            struct_ser.serialize_field("suspensionDays", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ResolveReportRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "moderator_id",
+            "moderatorId",
+            "report_id",
+            "reportId",
+            "resolution",
+            "note",
+            "suspension_days",
+            "suspensionDays",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ModeratorId,
+            ReportId,
+            Resolution,
+            Note,
+            SuspensionDays,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "moderatorId" | "moderator_id" => Ok(GeneratedField::ModeratorId),
+                            "reportId" | "report_id" => Ok(GeneratedField::ReportId),
+                            "resolution" => Ok(GeneratedField::Resolution),
+                            "note" => Ok(GeneratedField::Note),
+                            "suspensionDays" | "suspension_days" => Ok(GeneratedField::SuspensionDays),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ResolveReportRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct report_def.v1.ResolveReportRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ResolveReportRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut moderator_id__ = None;
+                let mut report_id__ = None;
+                let mut resolution__ = None;
+                let mut note__ = None;
+                let mut suspension_days__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::ModeratorId => {
+                            if moderator_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("moderatorId"));
+                            }
+                            moderator_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ReportId => {
+                            if report_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reportId"));
+                            }
+                            report_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Resolution => {
+                            if resolution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("resolution"));
+                            }
+                            resolution__ = Some(map.next_value::<ReportResolution>()? as i32);
+                        }
+                        GeneratedField::Note => {
+                            if note__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("note"));
+                            }
+                            note__ = map.next_value()?;
+                        }
+                        GeneratedField::SuspensionDays => {
+                            if suspension_days__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("suspensionDays"));
+                            }
+                            suspension_days__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ResolveReportRequest {
+                    moderator_id: moderator_id__.unwrap_or_default(),
+                    report_id: report_id__.unwrap_or_default(),
+                    resolution: resolution__.unwrap_or_default(),
+                    note: note__,
+                    suspension_days: suspension_days__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("report_def.v1.ResolveReportRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ResolveReportResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.report.is_some() {
+            len += 1;
+        }
+        if self.audit_log_entry.is_some() {
+            len += 1;
+        }
+        if self.deleted_at.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("report_def.v1.ResolveReportResponse", len)?;
+        if let Some(v) = self.report.as_ref() {
+            struct_ser.serialize_field("report", v)?;
+        }
+        if let Some(v) = self.audit_log_entry.as_ref() {
+            struct_ser.serialize_field("auditLogEntry", v)?;
+        }
+        if let Some(v) = self.deleted_at.as_ref() {
+            struct_ser.serialize_field("deletedAt", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ResolveReportResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "report",
+            "audit_log_entry",
+            "auditLogEntry",
+            "deleted_at",
+            "deletedAt",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Report,
+            AuditLogEntry,
+            DeletedAt,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "report" => Ok(GeneratedField::Report),
+                            "auditLogEntry" | "audit_log_entry" => Ok(GeneratedField::AuditLogEntry),
+                            "deletedAt" | "deleted_at" => Ok(GeneratedField::DeletedAt),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ResolveReportResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct report_def.v1.ResolveReportResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ResolveReportResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut report__ = None;
+                let mut audit_log_entry__ = None;
+                let mut deleted_at__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Report => {
+                            if report__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("report"));
+                            }
+                            report__ = map.next_value()?;
+                        }
+                        GeneratedField::AuditLogEntry => {
+                            if audit_log_entry__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auditLogEntry"));
+                            }
+                            audit_log_entry__ = map.next_value()?;
+                        }
+                        GeneratedField::DeletedAt => {
+                            if deleted_at__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deletedAt"));
+                            }
+                            deleted_at__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ResolveReportResponse {
+                    report: report__,
+                    audit_log_entry: audit_log_entry__,
+                    deleted_at: deleted_at__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("report_def.v1.ResolveReportResponse", FIELDS, GeneratedVisitor)
+    }
+}
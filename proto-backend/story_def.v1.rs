@@ -12,10 +12,17 @@ pub struct Draft {
     pub splash_hex: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(uint32, tag="5")]
     pub word_count: u32,
+    #[deprecated]
     #[prost(string, tag="6")]
     pub created_at: ::prost::alloc::string::String,
+    #[deprecated]
     #[prost(string, optional, tag="7")]
     pub edited_at: ::core::option::Option<::prost::alloc::string::String>,
+    /// Timestamps
+    #[prost(message, optional, tag="8")]
+    pub created_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(message, optional, tag="9")]
+    pub edited_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 // Story validation request
 
@@ -45,6 +52,55 @@ pub struct CreateDraftResponse {
     #[prost(string, tag="1")]
     pub draft_id: ::prost::alloc::string::String,
 }
+// Import story request
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportStoryRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    /// Only `MARKDOWN` and `HTML`, archives are imported using `ImportStories`
+    #[prost(enumeration="StoryImportFormat", tag="2")]
+    pub format: i32,
+    #[prost(bytes="vec", tag="3")]
+    pub content: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportStoryResponse {
+    #[prost(string, tag="1")]
+    pub draft_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportStoriesRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(enumeration="StoryImportFormat", tag="2")]
+    pub format: i32,
+    #[prost(bytes="vec", tag="3")]
+    pub content: ::prost::alloc::vec::Vec<u8>,
+    /// Used to derive the canonical URLs of Substack posts
+    #[prost(string, optional, tag="4")]
+    pub publication_url: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportedStory {
+    #[prost(string, tag="1")]
+    pub title: ::prost::alloc::string::String,
+    /// Omitted when the story could not be imported
+    #[prost(string, optional, tag="2")]
+    pub draft_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub error: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportStoriesResponse {
+    #[prost(message, repeated, tag="1")]
+    pub stories: ::prost::alloc::vec::Vec<ImportedStory>,
+}
 // Drafts information request
 
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -156,14 +212,19 @@ pub struct GetStoryResponse {
     pub seo_title: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(string, optional, tag="23")]
     pub preview_image: ::core::option::Option<::prost::alloc::string::String>,
+    #[deprecated]
     #[prost(string, tag="24")]
     pub created_at: ::prost::alloc::string::String,
+    #[deprecated]
     #[prost(string, optional, tag="25")]
     pub edited_at: ::core::option::Option<::prost::alloc::string::String>,
+    #[deprecated]
     #[prost(string, optional, tag="26")]
     pub published_at: ::core::option::Option<::prost::alloc::string::String>,
+    #[deprecated]
     #[prost(string, optional, tag="27")]
     pub first_published_at: ::core::option::Option<::prost::alloc::string::String>,
+    #[deprecated]
     #[prost(string, optional, tag="28")]
     pub deleted_at: ::core::option::Option<::prost::alloc::string::String>,
     /// Joins
@@ -183,6 +244,51 @@ pub struct GetStoryResponse {
     /// Reading session token
     #[prost(string, tag="35")]
     pub reading_session_token: ::prost::alloc::string::String,
+    /// Sensitive media
+    #[prost(enumeration="super::super::asset_def::v1::AssetRating", tag="36")]
+    pub splash_rating: i32,
+    /// Rated images embedded in the content
+    #[prost(string, repeated, tag="37")]
+    pub sensitive_asset_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Set when the viewer has not opted into sensitive media
+    #[prost(bool, tag="38")]
+    pub blur_sensitive_media: bool,
+    /// Rated story hidden from the viewer, the content fields are omitted
+    #[prost(bool, tag="39")]
+    pub is_gated: bool,
+    /// Timestamps
+    #[prost(message, optional, tag="40")]
+    pub created_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(message, optional, tag="41")]
+    pub edited_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(message, optional, tag="42")]
+    pub published_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(message, optional, tag="43")]
+    pub first_published_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(message, optional, tag="44")]
+    pub deleted_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+// Export story request
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportStoryRequest {
+    #[prost(string, tag="1")]
+    pub id_or_slug: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub current_user_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(enumeration="StoryExportFormat", tag="3")]
+    pub format: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportStoryResponse {
+    #[prost(string, tag="1")]
+    pub filename: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub content_type: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="3")]
+    pub content: ::prost::alloc::vec::Vec<u8>,
 }
 // Story metadata request
 
@@ -238,14 +344,19 @@ pub struct GetStoryMetadataResponse {
     pub seo_title: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(string, optional, tag="20")]
     pub preview_image: ::core::option::Option<::prost::alloc::string::String>,
+    #[deprecated]
     #[prost(string, tag="21")]
     pub created_at: ::prost::alloc::string::String,
+    #[deprecated]
     #[prost(string, optional, tag="22")]
     pub edited_at: ::core::option::Option<::prost::alloc::string::String>,
+    #[deprecated]
     #[prost(string, optional, tag="23")]
     pub published_at: ::core::option::Option<::prost::alloc::string::String>,
+    #[deprecated]
     #[prost(string, optional, tag="24")]
     pub first_published_at: ::core::option::Option<::prost::alloc::string::String>,
+    #[deprecated]
     #[prost(string, optional, tag="25")]
     pub deleted_at: ::core::option::Option<::prost::alloc::string::String>,
     /// Joins
@@ -255,6 +366,17 @@ pub struct GetStoryMetadataResponse {
     pub blog: ::core::option::Option<super::super::blog_def::v1::BareBlog>,
     #[prost(message, repeated, tag="28")]
     pub tags: ::prost::alloc::vec::Vec<super::super::tag_def::v1::Tag>,
+    /// Timestamps
+    #[prost(message, optional, tag="29")]
+    pub created_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(message, optional, tag="30")]
+    pub edited_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(message, optional, tag="31")]
+    pub published_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(message, optional, tag="32")]
+    pub first_published_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
+    #[prost(message, optional, tag="33")]
+    pub deleted_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -361,4 +483,78 @@ impl StoryLicense {
         }
     }
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum StoryImportFormat {
+    Unspecified = 0,
+    /// Markdown with optional front matter
+    Markdown = 1,
+    Html = 2,
+    /// Zip archive from Medium's "Download your information"
+    MediumArchive = 3,
+    /// Zip archive from Substack's "Export your data"
+    SubstackArchive = 4,
+}
+impl StoryImportFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            StoryImportFormat::Unspecified => "STORY_IMPORT_FORMAT_UNSPECIFIED",
+            StoryImportFormat::Markdown => "STORY_IMPORT_FORMAT_MARKDOWN",
+            StoryImportFormat::Html => "STORY_IMPORT_FORMAT_HTML",
+            StoryImportFormat::MediumArchive => "STORY_IMPORT_FORMAT_MEDIUM_ARCHIVE",
+            StoryImportFormat::SubstackArchive => "STORY_IMPORT_FORMAT_SUBSTACK_ARCHIVE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "STORY_IMPORT_FORMAT_UNSPECIFIED" => Some(Self::Unspecified),
+            "STORY_IMPORT_FORMAT_MARKDOWN" => Some(Self::Markdown),
+            "STORY_IMPORT_FORMAT_HTML" => Some(Self::Html),
+            "STORY_IMPORT_FORMAT_MEDIUM_ARCHIVE" => Some(Self::MediumArchive),
+            "STORY_IMPORT_FORMAT_SUBSTACK_ARCHIVE" => Some(Self::SubstackArchive),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum StoryExportFormat {
+    Unspecified = 0,
+    Markdown = 1,
+    /// Standalone document with inline styles
+    Html = 2,
+    Epub = 3,
+    Pdf = 4,
+}
+impl StoryExportFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            StoryExportFormat::Unspecified => "STORY_EXPORT_FORMAT_UNSPECIFIED",
+            StoryExportFormat::Markdown => "STORY_EXPORT_FORMAT_MARKDOWN",
+            StoryExportFormat::Html => "STORY_EXPORT_FORMAT_HTML",
+            StoryExportFormat::Epub => "STORY_EXPORT_FORMAT_EPUB",
+            StoryExportFormat::Pdf => "STORY_EXPORT_FORMAT_PDF",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "STORY_EXPORT_FORMAT_UNSPECIFIED" => Some(Self::Unspecified),
+            "STORY_EXPORT_FORMAT_MARKDOWN" => Some(Self::Markdown),
+            "STORY_EXPORT_FORMAT_HTML" => Some(Self::Html),
+            "STORY_EXPORT_FORMAT_EPUB" => Some(Self::Epub),
+            "STORY_EXPORT_FORMAT_PDF" => Some(Self::Pdf),
+            _ => None,
+        }
+    }
+}
 // @@protoc_insertion_point(module)
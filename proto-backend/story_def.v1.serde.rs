@@ -212,6 +212,12 @@ impl serde::Serialize for Draft {
         if self.edited_at.is_some() {
             len += 1;
         }
+        if self.created_at_ts.is_some() {
+            len += 1;
+        }
+        if self.edited_at_ts.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("story_def.v1.Draft", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
@@ -234,6 +240,12 @@ impl serde::Serialize for Draft {
         if let Some(v) = self.edited_at.as_ref() {
             struct_ser.serialize_field("editedAt", v)?;
         }
+        if let Some(v) = self.created_at_ts.as_ref() {
+            struct_ser.serialize_field("createdAtTs", v)?;
+        }
+        if let Some(v) = self.edited_at_ts.as_ref() {
+            struct_ser.serialize_field("editedAtTs", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -256,6 +268,10 @@ impl<'de> serde::Deserialize<'de> for Draft {
             "createdAt",
             "edited_at",
             "editedAt",
+            "created_at_ts",
+            "createdAtTs",
+            "edited_at_ts",
+            "editedAtTs",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -267,6 +283,8 @@ impl<'de> serde::Deserialize<'de> for Draft {
             WordCount,
             CreatedAt,
             EditedAt,
+            CreatedAtTs,
+            EditedAtTs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -295,6 +313,8 @@ impl<'de> serde::Deserialize<'de> for Draft {
                             "wordCount" | "word_count" => Ok(GeneratedField::WordCount),
                             "createdAt" | "created_at" => Ok(GeneratedField::CreatedAt),
                             "editedAt" | "edited_at" => Ok(GeneratedField::EditedAt),
+                            "createdAtTs" | "created_at_ts" => Ok(GeneratedField::CreatedAtTs),
+                            "editedAtTs" | "edited_at_ts" => Ok(GeneratedField::EditedAtTs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -321,6 +341,8 @@ impl<'de> serde::Deserialize<'de> for Draft {
                 let mut word_count__ = None;
                 let mut created_at__ = None;
                 let mut edited_at__ = None;
+                let mut created_at_ts__ = None;
+                let mut edited_at_ts__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -367,6 +389,18 @@ impl<'de> serde::Deserialize<'de> for Draft {
                             }
                             edited_at__ = map.next_value()?;
                         }
+                        GeneratedField::CreatedAtTs => {
+                            if created_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAtTs"));
+                            }
+                            created_at_ts__ = map.next_value()?;
+                        }
+                        GeneratedField::EditedAtTs => {
+                            if edited_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("editedAtTs"));
+                            }
+                            edited_at_ts__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(Draft {
@@ -377,12 +411,272 @@ impl<'de> serde::Deserialize<'de> for Draft {
                     word_count: word_count__.unwrap_or_default(),
                     created_at: created_at__.unwrap_or_default(),
                     edited_at: edited_at__,
+                    created_at_ts: created_at_ts__,
+                    edited_at_ts: edited_at_ts__,
                 })
             }
         }
         deserializer.deserialize_struct("story_def.v1.Draft", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ExportStoryRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.id_or_slug.is_empty() {
+            len += 1;
+        }
+        if self.current_user_id.is_some() {
+            len += 1;
+        }
+        if self.format != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("story_def.v1.ExportStoryRequest", len)?;
+        if !self.id_or_slug.is_empty() {
+            struct_ser.serialize_field("idOrSlug", &self.id_or_slug)?;
+        }
+        if let Some(v) = self.current_user_id.as_ref() {
+            struct_ser.serialize_field("currentUserId", v)?;
+        }
+        if self.format != 0 {
+            let v = StoryExportFormat::from_i32(self.format)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.format)))?;
+            struct_ser.serialize_field("format", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExportStoryRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id_or_slug",
+            "idOrSlug",
+            "current_user_id",
+            "currentUserId",
+            "format",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdOrSlug,
+            CurrentUserId,
+            Format,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "idOrSlug" | "id_or_slug" => Ok(GeneratedField::IdOrSlug),
+                            "currentUserId" | "current_user_id" => Ok(GeneratedField::CurrentUserId),
+                            "format" => Ok(GeneratedField::Format),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExportStoryRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct story_def.v1.ExportStoryRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ExportStoryRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id_or_slug__ = None;
+                let mut current_user_id__ = None;
+                let mut format__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::IdOrSlug => {
+                            if id_or_slug__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idOrSlug"));
+                            }
+                            id_or_slug__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::CurrentUserId => {
+                            if current_user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentUserId"));
+                            }
+                            current_user_id__ = map.next_value()?;
+                        }
+                        GeneratedField::Format => {
+                            if format__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("format"));
+                            }
+                            format__ = Some(map.next_value::<StoryExportFormat>()? as i32);
+                        }
+                    }
+                }
+                Ok(ExportStoryRequest {
+                    id_or_slug: id_or_slug__.unwrap_or_default(),
+                    current_user_id: current_user_id__,
+                    format: format__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("story_def.v1.ExportStoryRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ExportStoryResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.filename.is_empty() {
+            len += 1;
+        }
+        if !self.content_type.is_empty() {
+            len += 1;
+        }
+        if !self.content.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("story_def.v1.ExportStoryResponse", len)?;
+        if !self.filename.is_empty() {
+            struct_ser.serialize_field("filename", &self.filename)?;
+        }
+        if !self.content_type.is_empty() {
+            struct_ser.serialize_field("contentType", &self.content_type)?;
+        }
+        if !self.content.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("content", pbjson::private::base64::encode(&self.content).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExportStoryResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "filename",
+            "content_type",
+            "contentType",
+            "content",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Filename,
+            ContentType,
+            Content,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "filename" => Ok(GeneratedField::Filename),
+                            "contentType" | "content_type" => Ok(GeneratedField::ContentType),
+                            "content" => Ok(GeneratedField::Content),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExportStoryResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct story_def.v1.ExportStoryResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ExportStoryResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut filename__ = None;
+                let mut content_type__ = None;
+                let mut content__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Filename => {
+                            if filename__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("filename"));
+                            }
+                            filename__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::ContentType => {
+                            if content_type__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("contentType"));
+                            }
+                            content_type__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Content => {
+                            if content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("content"));
+                            }
+                            content__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ExportStoryResponse {
+                    filename: filename__.unwrap_or_default(),
+                    content_type: content_type__.unwrap_or_default(),
+                    content: content__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("story_def.v1.ExportStoryResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for GetContributionsInfoRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1221,6 +1515,21 @@ impl serde::Serialize for GetStoryMetadataResponse {
         if !self.tags.is_empty() {
             len += 1;
         }
+        if self.created_at_ts.is_some() {
+            len += 1;
+        }
+        if self.edited_at_ts.is_some() {
+            len += 1;
+        }
+        if self.published_at_ts.is_some() {
+            len += 1;
+        }
+        if self.first_published_at_ts.is_some() {
+            len += 1;
+        }
+        if self.deleted_at_ts.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("story_def.v1.GetStoryMetadataResponse", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
@@ -1312,6 +1621,21 @@ impl serde::Serialize for GetStoryMetadataResponse {
         if !self.tags.is_empty() {
             struct_ser.serialize_field("tags", &self.tags)?;
         }
+        if let Some(v) = self.created_at_ts.as_ref() {
+            struct_ser.serialize_field("createdAtTs", v)?;
+        }
+        if let Some(v) = self.edited_at_ts.as_ref() {
+            struct_ser.serialize_field("editedAtTs", v)?;
+        }
+        if let Some(v) = self.published_at_ts.as_ref() {
+            struct_ser.serialize_field("publishedAtTs", v)?;
+        }
+        if let Some(v) = self.first_published_at_ts.as_ref() {
+            struct_ser.serialize_field("firstPublishedAtTs", v)?;
+        }
+        if let Some(v) = self.deleted_at_ts.as_ref() {
+            struct_ser.serialize_field("deletedAtTs", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1367,6 +1691,16 @@ impl<'de> serde::Deserialize<'de> for GetStoryMetadataResponse {
             "user",
             "blog",
             "tags",
+            "created_at_ts",
+            "createdAtTs",
+            "edited_at_ts",
+            "editedAtTs",
+            "published_at_ts",
+            "publishedAtTs",
+            "first_published_at_ts",
+            "firstPublishedAtTs",
+            "deleted_at_ts",
+            "deletedAtTs",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1399,6 +1733,11 @@ impl<'de> serde::Deserialize<'de> for GetStoryMetadataResponse {
             User,
             Blog,
             Tags,
+            CreatedAtTs,
+            EditedAtTs,
+            PublishedAtTs,
+            FirstPublishedAtTs,
+            DeletedAtTs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1448,6 +1787,11 @@ impl<'de> serde::Deserialize<'de> for GetStoryMetadataResponse {
                             "user" => Ok(GeneratedField::User),
                             "blog" => Ok(GeneratedField::Blog),
                             "tags" => Ok(GeneratedField::Tags),
+                            "createdAtTs" | "created_at_ts" => Ok(GeneratedField::CreatedAtTs),
+                            "editedAtTs" | "edited_at_ts" => Ok(GeneratedField::EditedAtTs),
+                            "publishedAtTs" | "published_at_ts" => Ok(GeneratedField::PublishedAtTs),
+                            "firstPublishedAtTs" | "first_published_at_ts" => Ok(GeneratedField::FirstPublishedAtTs),
+                            "deletedAtTs" | "deleted_at_ts" => Ok(GeneratedField::DeletedAtTs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1495,6 +1839,11 @@ impl<'de> serde::Deserialize<'de> for GetStoryMetadataResponse {
                 let mut user__ = None;
                 let mut blog__ = None;
                 let mut tags__ = None;
+                let mut created_at_ts__ = None;
+                let mut edited_at_ts__ = None;
+                let mut published_at_ts__ = None;
+                let mut first_published_at_ts__ = None;
+                let mut deleted_at_ts__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -1665,6 +2014,36 @@ impl<'de> serde::Deserialize<'de> for GetStoryMetadataResponse {
                             }
                             tags__ = Some(map.next_value()?);
                         }
+                        GeneratedField::CreatedAtTs => {
+                            if created_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAtTs"));
+                            }
+                            created_at_ts__ = map.next_value()?;
+                        }
+                        GeneratedField::EditedAtTs => {
+                            if edited_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("editedAtTs"));
+                            }
+                            edited_at_ts__ = map.next_value()?;
+                        }
+                        GeneratedField::PublishedAtTs => {
+                            if published_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publishedAtTs"));
+                            }
+                            published_at_ts__ = map.next_value()?;
+                        }
+                        GeneratedField::FirstPublishedAtTs => {
+                            if first_published_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("firstPublishedAtTs"));
+                            }
+                            first_published_at_ts__ = map.next_value()?;
+                        }
+                        GeneratedField::DeletedAtTs => {
+                            if deleted_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deletedAtTs"));
+                            }
+                            deleted_at_ts__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(GetStoryMetadataResponse {
@@ -1696,6 +2075,11 @@ impl<'de> serde::Deserialize<'de> for GetStoryMetadataResponse {
                     user: user__,
                     blog: blog__,
                     tags: tags__.unwrap_or_default(),
+                    created_at_ts: created_at_ts__,
+                    edited_at_ts: edited_at_ts__,
+                    published_at_ts: published_at_ts__,
+                    first_published_at_ts: first_published_at_ts__,
+                    deleted_at_ts: deleted_at_ts__,
                 })
             }
         }
@@ -1925,6 +2309,33 @@ impl serde::Serialize for GetStoryResponse {
         if !self.reading_session_token.is_empty() {
             len += 1;
         }
+        if self.splash_rating != 0 {
+            len += 1;
+        }
+        if !self.sensitive_asset_ids.is_empty() {
+            len += 1;
+        }
+        if self.blur_sensitive_media {
+            len += 1;
+        }
+        if self.is_gated {
+            len += 1;
+        }
+        if self.created_at_ts.is_some() {
+            len += 1;
+        }
+        if self.edited_at_ts.is_some() {
+            len += 1;
+        }
+        if self.published_at_ts.is_some() {
+            len += 1;
+        }
+        if self.first_published_at_ts.is_some() {
+            len += 1;
+        }
+        if self.deleted_at_ts.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("story_def.v1.GetStoryResponse", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
@@ -2037,6 +2448,35 @@ impl serde::Serialize for GetStoryResponse {
         if !self.reading_session_token.is_empty() {
             struct_ser.serialize_field("readingSessionToken", &self.reading_session_token)?;
         }
+        if self.splash_rating != 0 {
+            let v = super::super::asset_def::v1::AssetRating::from_i32(self.splash_rating)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.splash_rating)))?;
+            struct_ser.serialize_field("splashRating", &v)?;
+        }
+        if !self.sensitive_asset_ids.is_empty() {
+            struct_ser.serialize_field("sensitiveAssetIds", &self.sensitive_asset_ids)?;
+        }
+        if self.blur_sensitive_media {
+            struct_ser.serialize_field("blurSensitiveMedia", &self.blur_sensitive_media)?;
+        }
+        if self.is_gated {
+            struct_ser.serialize_field("isGated", &self.is_gated)?;
+        }
+        if let Some(v) = self.created_at_ts.as_ref() {
+            struct_ser.serialize_field("createdAtTs", v)?;
+        }
+        if let Some(v) = self.edited_at_ts.as_ref() {
+            struct_ser.serialize_field("editedAtTs", v)?;
+        }
+        if let Some(v) = self.published_at_ts.as_ref() {
+            struct_ser.serialize_field("publishedAtTs", v)?;
+        }
+        if let Some(v) = self.first_published_at_ts.as_ref() {
+            struct_ser.serialize_field("firstPublishedAtTs", v)?;
+        }
+        if let Some(v) = self.deleted_at_ts.as_ref() {
+            struct_ser.serialize_field("deletedAtTs", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -2106,6 +2546,24 @@ impl<'de> serde::Deserialize<'de> for GetStoryResponse {
             "isLiked",
             "reading_session_token",
             "readingSessionToken",
+            "splash_rating",
+            "splashRating",
+            "sensitive_asset_ids",
+            "sensitiveAssetIds",
+            "blur_sensitive_media",
+            "blurSensitiveMedia",
+            "is_gated",
+            "isGated",
+            "created_at_ts",
+            "createdAtTs",
+            "edited_at_ts",
+            "editedAtTs",
+            "published_at_ts",
+            "publishedAtTs",
+            "first_published_at_ts",
+            "firstPublishedAtTs",
+            "deleted_at_ts",
+            "deletedAtTs",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -2145,6 +2603,15 @@ impl<'de> serde::Deserialize<'de> for GetStoryResponse {
             IsBookmarked,
             IsLiked,
             ReadingSessionToken,
+            SplashRating,
+            SensitiveAssetIds,
+            BlurSensitiveMedia,
+            IsGated,
+            CreatedAtTs,
+            EditedAtTs,
+            PublishedAtTs,
+            FirstPublishedAtTs,
+            DeletedAtTs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -2201,6 +2668,15 @@ impl<'de> serde::Deserialize<'de> for GetStoryResponse {
                             "isBookmarked" | "is_bookmarked" => Ok(GeneratedField::IsBookmarked),
                             "isLiked" | "is_liked" => Ok(GeneratedField::IsLiked),
                             "readingSessionToken" | "reading_session_token" => Ok(GeneratedField::ReadingSessionToken),
+                            "splashRating" | "splash_rating" => Ok(GeneratedField::SplashRating),
+                            "sensitiveAssetIds" | "sensitive_asset_ids" => Ok(GeneratedField::SensitiveAssetIds),
+                            "blurSensitiveMedia" | "blur_sensitive_media" => Ok(GeneratedField::BlurSensitiveMedia),
+                            "isGated" | "is_gated" => Ok(GeneratedField::IsGated),
+                            "createdAtTs" | "created_at_ts" => Ok(GeneratedField::CreatedAtTs),
+                            "editedAtTs" | "edited_at_ts" => Ok(GeneratedField::EditedAtTs),
+                            "publishedAtTs" | "published_at_ts" => Ok(GeneratedField::PublishedAtTs),
+                            "firstPublishedAtTs" | "first_published_at_ts" => Ok(GeneratedField::FirstPublishedAtTs),
+                            "deletedAtTs" | "deleted_at_ts" => Ok(GeneratedField::DeletedAtTs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -2255,6 +2731,15 @@ impl<'de> serde::Deserialize<'de> for GetStoryResponse {
                 let mut is_bookmarked__ = None;
                 let mut is_liked__ = None;
                 let mut reading_session_token__ = None;
+                let mut splash_rating__ = None;
+                let mut sensitive_asset_ids__ = None;
+                let mut blur_sensitive_media__ = None;
+                let mut is_gated__ = None;
+                let mut created_at_ts__ = None;
+                let mut edited_at_ts__ = None;
+                let mut published_at_ts__ = None;
+                let mut first_published_at_ts__ = None;
+                let mut deleted_at_ts__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -2475,11 +2960,65 @@ impl<'de> serde::Deserialize<'de> for GetStoryResponse {
                             }
                             reading_session_token__ = Some(map.next_value()?);
                         }
-                    }
-                }
-                Ok(GetStoryResponse {
-                    id: id__.unwrap_or_default(),
-                    title: title__.unwrap_or_default(),
+                        GeneratedField::SplashRating => {
+                            if splash_rating__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("splashRating"));
+                            }
+                            splash_rating__ = Some(map.next_value::<super::super::asset_def::v1::AssetRating>()? as i32);
+                        }
+                        GeneratedField::SensitiveAssetIds => {
+                            if sensitive_asset_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sensitiveAssetIds"));
+                            }
+                            sensitive_asset_ids__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::BlurSensitiveMedia => {
+                            if blur_sensitive_media__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blurSensitiveMedia"));
+                            }
+                            blur_sensitive_media__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::IsGated => {
+                            if is_gated__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("isGated"));
+                            }
+                            is_gated__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::CreatedAtTs => {
+                            if created_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAtTs"));
+                            }
+                            created_at_ts__ = map.next_value()?;
+                        }
+                        GeneratedField::EditedAtTs => {
+                            if edited_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("editedAtTs"));
+                            }
+                            edited_at_ts__ = map.next_value()?;
+                        }
+                        GeneratedField::PublishedAtTs => {
+                            if published_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publishedAtTs"));
+                            }
+                            published_at_ts__ = map.next_value()?;
+                        }
+                        GeneratedField::FirstPublishedAtTs => {
+                            if first_published_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("firstPublishedAtTs"));
+                            }
+                            first_published_at_ts__ = map.next_value()?;
+                        }
+                        GeneratedField::DeletedAtTs => {
+                            if deleted_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deletedAtTs"));
+                            }
+                            deleted_at_ts__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(GetStoryResponse {
+                    id: id__.unwrap_or_default(),
+                    title: title__.unwrap_or_default(),
                     slug: slug__,
                     description: description__,
                     splash_id: splash_id__,
@@ -2513,10 +3052,608 @@ impl<'de> serde::Deserialize<'de> for GetStoryResponse {
                     is_bookmarked: is_bookmarked__.unwrap_or_default(),
                     is_liked: is_liked__.unwrap_or_default(),
                     reading_session_token: reading_session_token__.unwrap_or_default(),
+                    splash_rating: splash_rating__.unwrap_or_default(),
+                    sensitive_asset_ids: sensitive_asset_ids__.unwrap_or_default(),
+                    blur_sensitive_media: blur_sensitive_media__.unwrap_or_default(),
+                    is_gated: is_gated__.unwrap_or_default(),
+                    created_at_ts: created_at_ts__,
+                    edited_at_ts: edited_at_ts__,
+                    published_at_ts: published_at_ts__,
+                    first_published_at_ts: first_published_at_ts__,
+                    deleted_at_ts: deleted_at_ts__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("story_def.v1.GetStoryResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ImportStoriesRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if self.format != 0 {
+            len += 1;
+        }
+        if !self.content.is_empty() {
+            len += 1;
+        }
+        if self.publication_url.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("story_def.v1.ImportStoriesRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if self.format != 0 {
+            let v = StoryImportFormat::from_i32(self.format)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.format)))?;
+            struct_ser.serialize_field("format", &v)?;
+        }
+        if !self.content.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("content", pbjson::private::base64::encode(&self.content).as_str())?;
+        }
+        if let Some(v) = self.publication_url.as_ref() {
+            struct_ser.serialize_field("publicationUrl", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ImportStoriesRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "format",
+            "content",
+            "publication_url",
+            "publicationUrl",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            Format,
+            Content,
+            PublicationUrl,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "format" => Ok(GeneratedField::Format),
+                            "content" => Ok(GeneratedField::Content),
+                            "publicationUrl" | "publication_url" => Ok(GeneratedField::PublicationUrl),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ImportStoriesRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct story_def.v1.ImportStoriesRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ImportStoriesRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut format__ = None;
+                let mut content__ = None;
+                let mut publication_url__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Format => {
+                            if format__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("format"));
+                            }
+                            format__ = Some(map.next_value::<StoryImportFormat>()? as i32);
+                        }
+                        GeneratedField::Content => {
+                            if content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("content"));
+                            }
+                            content__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PublicationUrl => {
+                            if publication_url__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicationUrl"));
+                            }
+                            publication_url__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ImportStoriesRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                    format: format__.unwrap_or_default(),
+                    content: content__.unwrap_or_default(),
+                    publication_url: publication_url__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("story_def.v1.ImportStoriesRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ImportStoriesResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.stories.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("story_def.v1.ImportStoriesResponse", len)?;
+        if !self.stories.is_empty() {
+            struct_ser.serialize_field("stories", &self.stories)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ImportStoriesResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "stories",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Stories,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "stories" => Ok(GeneratedField::Stories),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ImportStoriesResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct story_def.v1.ImportStoriesResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ImportStoriesResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut stories__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Stories => {
+                            if stories__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stories"));
+                            }
+                            stories__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(ImportStoriesResponse {
+                    stories: stories__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("story_def.v1.ImportStoriesResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ImportStoryRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if self.format != 0 {
+            len += 1;
+        }
+        if !self.content.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("story_def.v1.ImportStoryRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if self.format != 0 {
+            let v = StoryImportFormat::from_i32(self.format)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.format)))?;
+            struct_ser.serialize_field("format", &v)?;
+        }
+        if !self.content.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("content", pbjson::private::base64::encode(&self.content).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ImportStoryRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "format",
+            "content",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            Format,
+            Content,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "format" => Ok(GeneratedField::Format),
+                            "content" => Ok(GeneratedField::Content),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ImportStoryRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct story_def.v1.ImportStoryRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ImportStoryRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut format__ = None;
+                let mut content__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Format => {
+                            if format__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("format"));
+                            }
+                            format__ = Some(map.next_value::<StoryImportFormat>()? as i32);
+                        }
+                        GeneratedField::Content => {
+                            if content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("content"));
+                            }
+                            content__ = 
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(ImportStoryRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                    format: format__.unwrap_or_default(),
+                    content: content__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("story_def.v1.ImportStoryRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ImportStoryResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.draft_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("story_def.v1.ImportStoryResponse", len)?;
+        if !self.draft_id.is_empty() {
+            struct_ser.serialize_field("draftId", &self.draft_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ImportStoryResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "draft_id",
+            "draftId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DraftId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "draftId" | "draft_id" => Ok(GeneratedField::DraftId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ImportStoryResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct story_def.v1.ImportStoryResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ImportStoryResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut draft_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::DraftId => {
+                            if draft_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("draftId"));
+                            }
+                            draft_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(ImportStoryResponse {
+                    draft_id: draft_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("story_def.v1.GetStoryResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("story_def.v1.ImportStoryResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ImportedStory {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.title.is_empty() {
+            len += 1;
+        }
+        if self.draft_id.is_some() {
+            len += 1;
+        }
+        if self.error.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("story_def.v1.ImportedStory", len)?;
+        if !self.title.is_empty() {
+            struct_ser.serialize_field("title", &self.title)?;
+        }
+        if let Some(v) = self.draft_id.as_ref() {
+            struct_ser.serialize_field("draftId", v)?;
+        }
+        if let Some(v) = self.error.as_ref() {
+            struct_ser.serialize_field("error", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ImportedStory {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "title",
+            "draft_id",
+            "draftId",
+            "error",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Title,
+            DraftId,
+            Error,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "title" => Ok(GeneratedField::Title),
+                            "draftId" | "draft_id" => Ok(GeneratedField::DraftId),
+                            "error" => Ok(GeneratedField::Error),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ImportedStory;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct story_def.v1.ImportedStory")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ImportedStory, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut title__ = None;
+                let mut draft_id__ = None;
+                let mut error__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Title => {
+                            if title__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("title"));
+                            }
+                            title__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::DraftId => {
+                            if draft_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("draftId"));
+                            }
+                            draft_id__ = map.next_value()?;
+                        }
+                        GeneratedField::Error => {
+                            if error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("error"));
+                            }
+                            error__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(ImportedStory {
+                    title: title__.unwrap_or_default(),
+                    draft_id: draft_id__,
+                    error: error__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("story_def.v1.ImportedStory", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for StoryAgeRestriction {
@@ -2595,6 +3732,170 @@ impl<'de> serde::Deserialize<'de> for StoryAgeRestriction {
         deserializer.deserialize_any(GeneratedVisitor)
     }
 }
+impl serde::Serialize for StoryExportFormat {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => 0,
+            Self::Markdown => 1,
+            Self::Html => 2,
+            Self::Epub => 3,
+            Self::Pdf => 4,
+        };
+        serializer.serialize_i32(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for StoryExportFormat {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "STORY_EXPORT_FORMAT_UNSPECIFIED",
+            "STORY_EXPORT_FORMAT_MARKDOWN",
+            "STORY_EXPORT_FORMAT_HTML",
+            "STORY_EXPORT_FORMAT_EPUB",
+            "STORY_EXPORT_FORMAT_PDF",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StoryExportFormat;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(StoryExportFormat::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(StoryExportFormat::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "STORY_EXPORT_FORMAT_UNSPECIFIED" => Ok(StoryExportFormat::Unspecified),
+                    "STORY_EXPORT_FORMAT_MARKDOWN" => Ok(StoryExportFormat::Markdown),
+                    "STORY_EXPORT_FORMAT_HTML" => Ok(StoryExportFormat::Html),
+                    "STORY_EXPORT_FORMAT_EPUB" => Ok(StoryExportFormat::Epub),
+                    "STORY_EXPORT_FORMAT_PDF" => Ok(StoryExportFormat::Pdf),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StoryImportFormat {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => 0,
+            Self::Markdown => 1,
+            Self::Html => 2,
+            Self::MediumArchive => 3,
+            Self::SubstackArchive => 4,
+        };
+        serializer.serialize_i32(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for StoryImportFormat {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "STORY_IMPORT_FORMAT_UNSPECIFIED",
+            "STORY_IMPORT_FORMAT_MARKDOWN",
+            "STORY_IMPORT_FORMAT_HTML",
+            "STORY_IMPORT_FORMAT_MEDIUM_ARCHIVE",
+            "STORY_IMPORT_FORMAT_SUBSTACK_ARCHIVE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StoryImportFormat;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(StoryImportFormat::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(StoryImportFormat::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "STORY_IMPORT_FORMAT_UNSPECIFIED" => Ok(StoryImportFormat::Unspecified),
+                    "STORY_IMPORT_FORMAT_MARKDOWN" => Ok(StoryImportFormat::Markdown),
+                    "STORY_IMPORT_FORMAT_HTML" => Ok(StoryImportFormat::Html),
+                    "STORY_IMPORT_FORMAT_MEDIUM_ARCHIVE" => Ok(StoryImportFormat::MediumArchive),
+                    "STORY_IMPORT_FORMAT_SUBSTACK_ARCHIVE" => Ok(StoryImportFormat::SubstackArchive),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
 impl serde::Serialize for StoryLicense {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
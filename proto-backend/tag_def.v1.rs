@@ -31,11 +31,15 @@ pub struct GetTagResponse {
     pub story_count: u32,
     #[prost(uint32, tag="4")]
     pub follower_count: u32,
+    #[deprecated]
     #[prost(string, tag="5")]
     pub created_at: ::prost::alloc::string::String,
     /// User specific props
     #[prost(bool, tag="6")]
     pub is_following: bool,
+    /// Timestamps
+    #[prost(message, optional, tag="7")]
+    pub created_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 // Followed tag count
 
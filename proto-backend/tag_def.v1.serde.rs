@@ -320,6 +320,9 @@ impl serde::Serialize for GetTagResponse {
         if self.is_following {
             len += 1;
         }
+        if self.created_at_ts.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("tag_def.v1.GetTagResponse", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
@@ -339,6 +342,9 @@ impl serde::Serialize for GetTagResponse {
         if self.is_following {
             struct_ser.serialize_field("isFollowing", &self.is_following)?;
         }
+        if let Some(v) = self.created_at_ts.as_ref() {
+            struct_ser.serialize_field("createdAtTs", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -359,6 +365,8 @@ impl<'de> serde::Deserialize<'de> for GetTagResponse {
             "createdAt",
             "is_following",
             "isFollowing",
+            "created_at_ts",
+            "createdAtTs",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -369,6 +377,7 @@ impl<'de> serde::Deserialize<'de> for GetTagResponse {
             FollowerCount,
             CreatedAt,
             IsFollowing,
+            CreatedAtTs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -396,6 +405,7 @@ impl<'de> serde::Deserialize<'de> for GetTagResponse {
                             "followerCount" | "follower_count" => Ok(GeneratedField::FollowerCount),
                             "createdAt" | "created_at" => Ok(GeneratedField::CreatedAt),
                             "isFollowing" | "is_following" => Ok(GeneratedField::IsFollowing),
+                            "createdAtTs" | "created_at_ts" => Ok(GeneratedField::CreatedAtTs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -421,6 +431,7 @@ impl<'de> serde::Deserialize<'de> for GetTagResponse {
                 let mut follower_count__ = None;
                 let mut created_at__ = None;
                 let mut is_following__ = None;
+                let mut created_at_ts__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -463,6 +474,12 @@ impl<'de> serde::Deserialize<'de> for GetTagResponse {
                             }
                             is_following__ = Some(map.next_value()?);
                         }
+                        GeneratedField::CreatedAtTs => {
+                            if created_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAtTs"));
+                            }
+                            created_at_ts__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(GetTagResponse {
@@ -472,6 +489,7 @@ impl<'de> serde::Deserialize<'de> for GetTagResponse {
                     follower_count: follower_count__.unwrap_or_default(),
                     created_at: created_at__.unwrap_or_default(),
                     is_following: is_following__.unwrap_or_default(),
+                    created_at_ts: created_at_ts__,
                 })
             }
         }
@@ -50,6 +50,7 @@ pub enum TokenType {
     EmailVerification = 1,
     PasswordReset = 2,
     PasswordAdd = 3,
+    AccountExport = 4,
 }
 impl TokenType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -62,6 +63,7 @@ impl TokenType {
             TokenType::EmailVerification => "TOKEN_TYPE_EMAIL_VERIFICATION",
             TokenType::PasswordReset => "TOKEN_TYPE_PASSWORD_RESET",
             TokenType::PasswordAdd => "TOKEN_TYPE_PASSWORD_ADD",
+            TokenType::AccountExport => "TOKEN_TYPE_ACCOUNT_EXPORT",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -71,6 +73,7 @@ impl TokenType {
             "TOKEN_TYPE_EMAIL_VERIFICATION" => Some(Self::EmailVerification),
             "TOKEN_TYPE_PASSWORD_RESET" => Some(Self::PasswordReset),
             "TOKEN_TYPE_PASSWORD_ADD" => Some(Self::PasswordAdd),
+            "TOKEN_TYPE_ACCOUNT_EXPORT" => Some(Self::AccountExport),
             _ => None,
         }
     }
@@ -230,6 +230,7 @@ impl serde::Serialize for TokenType {
             Self::EmailVerification => 1,
             Self::PasswordReset => 2,
             Self::PasswordAdd => 3,
+            Self::AccountExport => 4,
         };
         serializer.serialize_i32(variant)
     }
@@ -245,6 +246,7 @@ impl<'de> serde::Deserialize<'de> for TokenType {
             "TOKEN_TYPE_EMAIL_VERIFICATION",
             "TOKEN_TYPE_PASSWORD_RESET",
             "TOKEN_TYPE_PASSWORD_ADD",
+            "TOKEN_TYPE_ACCOUNT_EXPORT",
         ];
 
         struct GeneratedVisitor;
@@ -291,6 +293,7 @@ impl<'de> serde::Deserialize<'de> for TokenType {
                     "TOKEN_TYPE_EMAIL_VERIFICATION" => Ok(TokenType::EmailVerification),
                     "TOKEN_TYPE_PASSWORD_RESET" => Ok(TokenType::PasswordReset),
                     "TOKEN_TYPE_PASSWORD_ADD" => Ok(TokenType::PasswordAdd),
+                    "TOKEN_TYPE_ACCOUNT_EXPORT" => Ok(TokenType::AccountExport),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
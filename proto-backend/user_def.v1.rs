@@ -6,8 +6,12 @@ pub struct BareStatus {
     pub emoji: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(string, optional, tag="2")]
     pub text: ::core::option::Option<::prost::alloc::string::String>,
+    #[deprecated]
     #[prost(string, optional, tag="3")]
     pub expires_at: ::core::option::Option<::prost::alloc::string::String>,
+    /// Timestamps
+    #[prost(message, optional, tag="4")]
+    pub expires_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -16,12 +20,16 @@ pub struct ExtendedStatus {
     pub emoji: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(string, optional, tag="2")]
     pub text: ::core::option::Option<::prost::alloc::string::String>,
+    #[deprecated]
     #[prost(string, optional, tag="3")]
     pub expires_at: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(enumeration="StatusDuration", tag="4")]
     pub duration: i32,
     #[prost(enumeration="StatusVisibility", tag="5")]
     pub visibility: i32,
+    /// Timestamps
+    #[prost(message, optional, tag="6")]
+    pub expires_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 // User
 
@@ -38,6 +46,7 @@ pub struct BareUser {
     pub avatar_id: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(string, optional, tag="5")]
     pub avatar_hex: ::core::option::Option<::prost::alloc::string::String>,
+    /// Bitfield of `UserFlag`
     #[prost(uint32, tag="6")]
     pub public_flags: u32,
 }
@@ -56,12 +65,14 @@ pub struct ExtendedUser {
     pub avatar_id: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(string, optional, tag="6")]
     pub avatar_hex: ::core::option::Option<::prost::alloc::string::String>,
+    /// Bitfield of `UserFlag`
     #[prost(uint32, tag="7")]
     pub public_flags: u32,
     #[prost(bool, tag="8")]
     pub is_private: bool,
     #[prost(string, tag="9")]
     pub location: ::prost::alloc::string::String,
+    #[deprecated]
     #[prost(string, tag="10")]
     pub created_at: ::prost::alloc::string::String,
     #[prost(uint32, tag="11")]
@@ -79,6 +90,9 @@ pub struct ExtendedUser {
     pub is_friend: bool,
     #[prost(bool, tag="17")]
     pub is_blocked_by_user: bool,
+    /// Timestamps
+    #[prost(message, optional, tag="18")]
+    pub created_at_ts: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 // Get user ID
 
@@ -157,6 +171,119 @@ pub struct GetUserMuteCountResponse {
     #[prost(uint32, tag="1")]
     pub mute_count: u32,
 }
+// Set status
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetStatusRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub emoji: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub text: ::core::option::Option<::prost::alloc::string::String>,
+    /// Used to compute `expires_at`
+    #[prost(enumeration="StatusDuration", tag="4")]
+    pub duration: i32,
+    #[prost(enumeration="StatusVisibility", tag="5")]
+    pub visibility: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetStatusResponse {
+    #[prost(message, optional, tag="1")]
+    pub status: ::core::option::Option<ExtendedStatus>,
+}
+// Clear status
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClearStatusRequest {
+    #[prost(string, tag="1")]
+    pub user_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClearStatusResponse {
+}
+// Grant user flag
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GrantUserFlagRequest {
+    #[prost(string, tag="1")]
+    pub admin_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub user_id: ::prost::alloc::string::String,
+    /// Suspension flags are managed through `SuspendUser`
+    #[prost(enumeration="UserFlag", tag="3")]
+    pub flag: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GrantUserFlagResponse {
+    #[prost(uint32, tag="1")]
+    pub public_flags: u32,
+}
+// Revoke user flag
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevokeUserFlagRequest {
+    #[prost(string, tag="1")]
+    pub admin_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub user_id: ::prost::alloc::string::String,
+    /// Suspension flags are managed through `UnsuspendUser`
+    #[prost(enumeration="UserFlag", tag="3")]
+    pub flag: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevokeUserFlagResponse {
+    #[prost(uint32, tag="1")]
+    pub public_flags: u32,
+}
+// Suspend user
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuspendUserRequest {
+    #[prost(string, tag="1")]
+    pub admin_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub user_id: ::prost::alloc::string::String,
+    /// Omitted for a permanent suspension
+    #[prost(uint32, optional, tag="3")]
+    pub suspension_days: ::core::option::Option<u32>,
+    #[prost(string, optional, tag="4")]
+    pub reason: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuspendUserResponse {
+    #[prost(uint32, tag="1")]
+    pub public_flags: u32,
+    /// Omitted for a permanent suspension
+    #[prost(message, optional, tag="2")]
+    pub suspended_until: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+// Unsuspend user
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnsuspendUserRequest {
+    #[prost(string, tag="1")]
+    pub admin_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub user_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnsuspendUserResponse {
+    #[prost(uint32, tag="1")]
+    pub public_flags: u32,
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum StatusVisibility {
@@ -227,4 +354,46 @@ impl StatusDuration {
         }
     }
 }
+/// Flag bits for `public_flags`, always a power of 2 (2, 4, 8, ...)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum UserFlag {
+    Unspecified = 0,
+    Staff = 1,
+    TemporarilySuspended = 2,
+    PermanentlySuspended = 4,
+    Verified = 8,
+    EarlyUser = 16,
+    PlusMember = 32,
+}
+impl UserFlag {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            UserFlag::Unspecified => "USER_FLAG_UNSPECIFIED",
+            UserFlag::Staff => "USER_FLAG_STAFF",
+            UserFlag::TemporarilySuspended => "USER_FLAG_TEMPORARILY_SUSPENDED",
+            UserFlag::PermanentlySuspended => "USER_FLAG_PERMANENTLY_SUSPENDED",
+            UserFlag::Verified => "USER_FLAG_VERIFIED",
+            UserFlag::EarlyUser => "USER_FLAG_EARLY_USER",
+            UserFlag::PlusMember => "USER_FLAG_PLUS_MEMBER",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "USER_FLAG_UNSPECIFIED" => Some(Self::Unspecified),
+            "USER_FLAG_STAFF" => Some(Self::Staff),
+            "USER_FLAG_TEMPORARILY_SUSPENDED" => Some(Self::TemporarilySuspended),
+            "USER_FLAG_PERMANENTLY_SUSPENDED" => Some(Self::PermanentlySuspended),
+            "USER_FLAG_VERIFIED" => Some(Self::Verified),
+            "USER_FLAG_EARLY_USER" => Some(Self::EarlyUser),
+            "USER_FLAG_PLUS_MEMBER" => Some(Self::PlusMember),
+            _ => None,
+        }
+    }
+}
 // @@protoc_insertion_point(module)
@@ -16,6 +16,9 @@ impl serde::Serialize for BareStatus {
         if self.expires_at.is_some() {
             len += 1;
         }
+        if self.expires_at_ts.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("user_def.v1.BareStatus", len)?;
         if let Some(v) = self.emoji.as_ref() {
             struct_ser.serialize_field("emoji", v)?;
@@ -26,6 +29,9 @@ impl serde::Serialize for BareStatus {
         if let Some(v) = self.expires_at.as_ref() {
             struct_ser.serialize_field("expiresAt", v)?;
         }
+        if let Some(v) = self.expires_at_ts.as_ref() {
+            struct_ser.serialize_field("expiresAtTs", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -40,6 +46,8 @@ impl<'de> serde::Deserialize<'de> for BareStatus {
             "text",
             "expires_at",
             "expiresAt",
+            "expires_at_ts",
+            "expiresAtTs",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -47,6 +55,7 @@ impl<'de> serde::Deserialize<'de> for BareStatus {
             Emoji,
             Text,
             ExpiresAt,
+            ExpiresAtTs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -71,6 +80,7 @@ impl<'de> serde::Deserialize<'de> for BareStatus {
                             "emoji" => Ok(GeneratedField::Emoji),
                             "text" => Ok(GeneratedField::Text),
                             "expiresAt" | "expires_at" => Ok(GeneratedField::ExpiresAt),
+                            "expiresAtTs" | "expires_at_ts" => Ok(GeneratedField::ExpiresAtTs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -93,6 +103,7 @@ impl<'de> serde::Deserialize<'de> for BareStatus {
                 let mut emoji__ = None;
                 let mut text__ = None;
                 let mut expires_at__ = None;
+                let mut expires_at_ts__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Emoji => {
@@ -113,12 +124,19 @@ impl<'de> serde::Deserialize<'de> for BareStatus {
                             }
                             expires_at__ = map.next_value()?;
                         }
+                        GeneratedField::ExpiresAtTs => {
+                            if expires_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiresAtTs"));
+                            }
+                            expires_at_ts__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(BareStatus {
                     emoji: emoji__,
                     text: text__,
                     expires_at: expires_at__,
+                    expires_at_ts: expires_at_ts__,
                 })
             }
         }
@@ -306,6 +324,169 @@ impl<'de> serde::Deserialize<'de> for BareUser {
         deserializer.deserialize_struct("user_def.v1.BareUser", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ClearStatusRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("user_def.v1.ClearStatusRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ClearStatusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ClearStatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct user_def.v1.ClearStatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ClearStatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(ClearStatusRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("user_def.v1.ClearStatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ClearStatusResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("user_def.v1.ClearStatusResponse", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ClearStatusResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ClearStatusResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct user_def.v1.ClearStatusResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<ClearStatusResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(ClearStatusResponse {
+                })
+            }
+        }
+        deserializer.deserialize_struct("user_def.v1.ClearStatusResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ExtendedStatus {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -329,6 +510,9 @@ impl serde::Serialize for ExtendedStatus {
         if self.visibility != 0 {
             len += 1;
         }
+        if self.expires_at_ts.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("user_def.v1.ExtendedStatus", len)?;
         if let Some(v) = self.emoji.as_ref() {
             struct_ser.serialize_field("emoji", v)?;
@@ -349,6 +533,9 @@ impl serde::Serialize for ExtendedStatus {
                 .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.visibility)))?;
             struct_ser.serialize_field("visibility", &v)?;
         }
+        if let Some(v) = self.expires_at_ts.as_ref() {
+            struct_ser.serialize_field("expiresAtTs", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -365,6 +552,8 @@ impl<'de> serde::Deserialize<'de> for ExtendedStatus {
             "expiresAt",
             "duration",
             "visibility",
+            "expires_at_ts",
+            "expiresAtTs",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -374,6 +563,7 @@ impl<'de> serde::Deserialize<'de> for ExtendedStatus {
             ExpiresAt,
             Duration,
             Visibility,
+            ExpiresAtTs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -400,6 +590,7 @@ impl<'de> serde::Deserialize<'de> for ExtendedStatus {
                             "expiresAt" | "expires_at" => Ok(GeneratedField::ExpiresAt),
                             "duration" => Ok(GeneratedField::Duration),
                             "visibility" => Ok(GeneratedField::Visibility),
+                            "expiresAtTs" | "expires_at_ts" => Ok(GeneratedField::ExpiresAtTs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -424,6 +615,7 @@ impl<'de> serde::Deserialize<'de> for ExtendedStatus {
                 let mut expires_at__ = None;
                 let mut duration__ = None;
                 let mut visibility__ = None;
+                let mut expires_at_ts__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Emoji => {
@@ -456,6 +648,12 @@ impl<'de> serde::Deserialize<'de> for ExtendedStatus {
                             }
                             visibility__ = Some(map.next_value::<StatusVisibility>()? as i32);
                         }
+                        GeneratedField::ExpiresAtTs => {
+                            if expires_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiresAtTs"));
+                            }
+                            expires_at_ts__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(ExtendedStatus {
@@ -464,6 +662,7 @@ impl<'de> serde::Deserialize<'de> for ExtendedStatus {
                     expires_at: expires_at__,
                     duration: duration__.unwrap_or_default(),
                     visibility: visibility__.unwrap_or_default(),
+                    expires_at_ts: expires_at_ts__,
                 })
             }
         }
@@ -529,6 +728,9 @@ impl serde::Serialize for ExtendedUser {
         if self.is_blocked_by_user {
             len += 1;
         }
+        if self.created_at_ts.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("user_def.v1.ExtendedUser", len)?;
         if !self.id.is_empty() {
             struct_ser.serialize_field("id", &self.id)?;
@@ -581,6 +783,9 @@ impl serde::Serialize for ExtendedUser {
         if self.is_blocked_by_user {
             struct_ser.serialize_field("isBlockedByUser", &self.is_blocked_by_user)?;
         }
+        if let Some(v) = self.created_at_ts.as_ref() {
+            struct_ser.serialize_field("createdAtTs", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -620,6 +825,8 @@ impl<'de> serde::Deserialize<'de> for ExtendedUser {
             "isFriend",
             "is_blocked_by_user",
             "isBlockedByUser",
+            "created_at_ts",
+            "createdAtTs",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -641,6 +848,7 @@ impl<'de> serde::Deserialize<'de> for ExtendedUser {
             IsFollower,
             IsFriend,
             IsBlockedByUser,
+            CreatedAtTs,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -679,6 +887,7 @@ impl<'de> serde::Deserialize<'de> for ExtendedUser {
                             "isFollower" | "is_follower" => Ok(GeneratedField::IsFollower),
                             "isFriend" | "is_friend" => Ok(GeneratedField::IsFriend),
                             "isBlockedByUser" | "is_blocked_by_user" => Ok(GeneratedField::IsBlockedByUser),
+                            "createdAtTs" | "created_at_ts" => Ok(GeneratedField::CreatedAtTs),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -715,6 +924,7 @@ impl<'de> serde::Deserialize<'de> for ExtendedUser {
                 let mut is_follower__ = None;
                 let mut is_friend__ = None;
                 let mut is_blocked_by_user__ = None;
+                let mut created_at_ts__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -823,6 +1033,12 @@ impl<'de> serde::Deserialize<'de> for ExtendedUser {
                             }
                             is_blocked_by_user__ = Some(map.next_value()?);
                         }
+                        GeneratedField::CreatedAtTs => {
+                            if created_at_ts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("createdAtTs"));
+                            }
+                            created_at_ts__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(ExtendedUser {
@@ -843,6 +1059,7 @@ impl<'de> serde::Deserialize<'de> for ExtendedUser {
                     is_follower: is_follower__.unwrap_or_default(),
                     is_friend: is_friend__.unwrap_or_default(),
                     is_blocked_by_user: is_blocked_by_user__.unwrap_or_default(),
+                    created_at_ts: created_at_ts__,
                 })
             }
         }
@@ -1832,76 +2049,777 @@ impl<'de> serde::Deserialize<'de> for GetUsernameResponse {
         deserializer.deserialize_struct("user_def.v1.GetUsernameResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StatusDuration {
+impl serde::Serialize for GrantUserFlagRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let variant = match self {
-            Self::Unspecified => 0,
-            Self::Never => 1,
-            Self::Min30 => 2,
-            Self::Min60 => 3,
-            Self::Hr4 => 4,
-            Self::Day1 => 5,
-        };
-        serializer.serialize_i32(variant)
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.admin_id.is_empty() {
+            len += 1;
+        }
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if self.flag != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("user_def.v1.GrantUserFlagRequest", len)?;
+        if !self.admin_id.is_empty() {
+            struct_ser.serialize_field("adminId", &self.admin_id)?;
+        }
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if self.flag != 0 {
+            let v = UserFlag::from_i32(self.flag)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.flag)))?;
+            struct_ser.serialize_field("flag", &v)?;
+        }
+        struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for StatusDuration {
+impl<'de> serde::Deserialize<'de> for GrantUserFlagRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "STATUS_DURATION_UNSPECIFIED",
-            "STATUS_DURATION_NEVER",
-            "STATUS_DURATION_MIN_30",
-            "STATUS_DURATION_MIN_60",
-            "STATUS_DURATION_HR_4",
-            "STATUS_DURATION_DAY_1",
+            "admin_id",
+            "adminId",
+            "user_id",
+            "userId",
+            "flag",
         ];
 
-        struct GeneratedVisitor;
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AdminId,
+            UserId,
+            Flag,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
 
-        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StatusDuration;
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(formatter, "expected one of: {:?}", &FIELDS)
-            }
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
 
-            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                use std::convert::TryFrom;
-                i32::try_from(v)
-                    .ok()
-                    .and_then(StatusDuration::from_i32)
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
-                    })
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "adminId" | "admin_id" => Ok(GeneratedField::AdminId),
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "flag" => Ok(GeneratedField::Flag),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
             }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GrantUserFlagRequest;
 
-            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                use std::convert::TryFrom;
-                i32::try_from(v)
-                    .ok()
-                    .and_then(StatusDuration::from_i32)
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
-                    })
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct user_def.v1.GrantUserFlagRequest")
             }
 
-            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<GrantUserFlagRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut admin_id__ = None;
+                let mut user_id__ = None;
+                let mut flag__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::AdminId => {
+                            if admin_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("adminId"));
+                            }
+                            admin_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Flag => {
+                            if flag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("flag"));
+                            }
+                            flag__ = Some(map.next_value::<UserFlag>()? as i32);
+                        }
+                    }
+                }
+                Ok(GrantUserFlagRequest {
+                    admin_id: admin_id__.unwrap_or_default(),
+                    user_id: user_id__.unwrap_or_default(),
+                    flag: flag__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("user_def.v1.GrantUserFlagRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GrantUserFlagResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.public_flags != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("user_def.v1.GrantUserFlagResponse", len)?;
+        if self.public_flags != 0 {
+            struct_ser.serialize_field("publicFlags", &self.public_flags)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GrantUserFlagResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "public_flags",
+            "publicFlags",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PublicFlags,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "publicFlags" | "public_flags" => Ok(GeneratedField::PublicFlags),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GrantUserFlagResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct user_def.v1.GrantUserFlagResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<GrantUserFlagResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut public_flags__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::PublicFlags => {
+                            if public_flags__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicFlags"));
+                            }
+                            public_flags__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(GrantUserFlagResponse {
+                    public_flags: public_flags__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("user_def.v1.GrantUserFlagResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RevokeUserFlagRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.admin_id.is_empty() {
+            len += 1;
+        }
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if self.flag != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("user_def.v1.RevokeUserFlagRequest", len)?;
+        if !self.admin_id.is_empty() {
+            struct_ser.serialize_field("adminId", &self.admin_id)?;
+        }
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if self.flag != 0 {
+            let v = UserFlag::from_i32(self.flag)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.flag)))?;
+            struct_ser.serialize_field("flag", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RevokeUserFlagRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "admin_id",
+            "adminId",
+            "user_id",
+            "userId",
+            "flag",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AdminId,
+            UserId,
+            Flag,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "adminId" | "admin_id" => Ok(GeneratedField::AdminId),
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "flag" => Ok(GeneratedField::Flag),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RevokeUserFlagRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct user_def.v1.RevokeUserFlagRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<RevokeUserFlagRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut admin_id__ = None;
+                let mut user_id__ = None;
+                let mut flag__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::AdminId => {
+                            if admin_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("adminId"));
+                            }
+                            admin_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Flag => {
+                            if flag__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("flag"));
+                            }
+                            flag__ = Some(map.next_value::<UserFlag>()? as i32);
+                        }
+                    }
+                }
+                Ok(RevokeUserFlagRequest {
+                    admin_id: admin_id__.unwrap_or_default(),
+                    user_id: user_id__.unwrap_or_default(),
+                    flag: flag__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("user_def.v1.RevokeUserFlagRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RevokeUserFlagResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.public_flags != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("user_def.v1.RevokeUserFlagResponse", len)?;
+        if self.public_flags != 0 {
+            struct_ser.serialize_field("publicFlags", &self.public_flags)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RevokeUserFlagResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "public_flags",
+            "publicFlags",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PublicFlags,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "publicFlags" | "public_flags" => Ok(GeneratedField::PublicFlags),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RevokeUserFlagResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct user_def.v1.RevokeUserFlagResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<RevokeUserFlagResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut public_flags__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::PublicFlags => {
+                            if public_flags__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicFlags"));
+                            }
+                            public_flags__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(RevokeUserFlagResponse {
+                    public_flags: public_flags__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("user_def.v1.RevokeUserFlagResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SetStatusRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if self.emoji.is_some() {
+            len += 1;
+        }
+        if self.text.is_some() {
+            len += 1;
+        }
+        if self.duration != 0 {
+            len += 1;
+        }
+        if self.visibility != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("user_def.v1.SetStatusRequest", len)?;
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if let Some(v) = self.emoji.as_ref() {
+            struct_ser.serialize_field("emoji", v)?;
+        }
+        if let Some(v) = self.text.as_ref() {
+            struct_ser.serialize_field("text", v)?;
+        }
+        if self.duration != 0 {
+            let v = StatusDuration::from_i32(self.duration)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.duration)))?;
+            struct_ser.serialize_field("duration", &v)?;
+        }
+        if self.visibility != 0 {
+            let v = StatusVisibility::from_i32(self.visibility)
+                .ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.visibility)))?;
+            struct_ser.serialize_field("visibility", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SetStatusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "userId",
+            "emoji",
+            "text",
+            "duration",
+            "visibility",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            UserId,
+            Emoji,
+            Text,
+            Duration,
+            Visibility,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "emoji" => Ok(GeneratedField::Emoji),
+                            "text" => Ok(GeneratedField::Text),
+                            "duration" => Ok(GeneratedField::Duration),
+                            "visibility" => Ok(GeneratedField::Visibility),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SetStatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct user_def.v1.SetStatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<SetStatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user_id__ = None;
+                let mut emoji__ = None;
+                let mut text__ = None;
+                let mut duration__ = None;
+                let mut visibility__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::Emoji => {
+                            if emoji__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("emoji"));
+                            }
+                            emoji__ = map.next_value()?;
+                        }
+                        GeneratedField::Text => {
+                            if text__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("text"));
+                            }
+                            text__ = map.next_value()?;
+                        }
+                        GeneratedField::Duration => {
+                            if duration__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("duration"));
+                            }
+                            duration__ = Some(map.next_value::<StatusDuration>()? as i32);
+                        }
+                        GeneratedField::Visibility => {
+                            if visibility__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("visibility"));
+                            }
+                            visibility__ = Some(map.next_value::<StatusVisibility>()? as i32);
+                        }
+                    }
+                }
+                Ok(SetStatusRequest {
+                    user_id: user_id__.unwrap_or_default(),
+                    emoji: emoji__,
+                    text: text__,
+                    duration: duration__.unwrap_or_default(),
+                    visibility: visibility__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("user_def.v1.SetStatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SetStatusResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.status.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("user_def.v1.SetStatusResponse", len)?;
+        if let Some(v) = self.status.as_ref() {
+            struct_ser.serialize_field("status", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SetStatusResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "status",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Status,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "status" => Ok(GeneratedField::Status),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SetStatusResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct user_def.v1.SetStatusResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<SetStatusResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut status__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(SetStatusResponse {
+                    status: status__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("user_def.v1.SetStatusResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StatusDuration {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => 0,
+            Self::Never => 1,
+            Self::Min30 => 2,
+            Self::Min60 => 3,
+            Self::Hr4 => 4,
+            Self::Day1 => 5,
+        };
+        serializer.serialize_i32(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for StatusDuration {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "STATUS_DURATION_UNSPECIFIED",
+            "STATUS_DURATION_NEVER",
+            "STATUS_DURATION_MIN_30",
+            "STATUS_DURATION_MIN_60",
+            "STATUS_DURATION_HR_4",
+            "STATUS_DURATION_DAY_1",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StatusDuration;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(StatusDuration::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(StatusDuration::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
             {
                 match value {
                     "STATUS_DURATION_UNSPECIFIED" => Ok(StatusDuration::Unspecified),
@@ -1914,10 +2832,552 @@ impl<'de> serde::Deserialize<'de> for StatusDuration {
                 }
             }
         }
-        deserializer.deserialize_any(GeneratedVisitor)
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StatusVisibility {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => 0,
+            Self::Global => 1,
+            Self::Followers => 2,
+            Self::Friends => 3,
+        };
+        serializer.serialize_i32(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for StatusVisibility {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "STATUS_VISIBILITY_UNSPECIFIED",
+            "STATUS_VISIBILITY_GLOBAL",
+            "STATUS_VISIBILITY_FOLLOWERS",
+            "STATUS_VISIBILITY_FRIENDS",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StatusVisibility;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(StatusVisibility::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                use std::convert::TryFrom;
+                i32::try_from(v)
+                    .ok()
+                    .and_then(StatusVisibility::from_i32)
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "STATUS_VISIBILITY_UNSPECIFIED" => Ok(StatusVisibility::Unspecified),
+                    "STATUS_VISIBILITY_GLOBAL" => Ok(StatusVisibility::Global),
+                    "STATUS_VISIBILITY_FOLLOWERS" => Ok(StatusVisibility::Followers),
+                    "STATUS_VISIBILITY_FRIENDS" => Ok(StatusVisibility::Friends),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SuspendUserRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.admin_id.is_empty() {
+            len += 1;
+        }
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        if self.suspension_days.is_some() {
+            len += 1;
+        }
+        if self.reason.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("user_def.v1.SuspendUserRequest", len)?;
+        if !self.admin_id.is_empty() {
+            struct_ser.serialize_field("adminId", &self.admin_id)?;
+        }
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        if let Some(v) = self.suspension_days.as_ref() {
+            struct_ser.serialize_field("suspensionDays", v)?;
+        }
+        if let Some(v) = self.reason.as_ref() {
+            struct_ser.serialize_field("reason", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SuspendUserRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "admin_id",
+            "adminId",
+            "user_id",
+            "userId",
+            "suspension_days",
+            "suspensionDays",
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AdminId,
+            UserId,
+            SuspensionDays,
+            Reason,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "adminId" | "admin_id" => Ok(GeneratedField::AdminId),
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            "suspensionDays" | "suspension_days" => Ok(GeneratedField::SuspensionDays),
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SuspendUserRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct user_def.v1.SuspendUserRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<SuspendUserRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut admin_id__ = None;
+                let mut user_id__ = None;
+                let mut suspension_days__ = None;
+                let mut reason__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::AdminId => {
+                            if admin_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("adminId"));
+                            }
+                            admin_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::SuspensionDays => {
+                            if suspension_days__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("suspensionDays"));
+                            }
+                            suspension_days__ = 
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(SuspendUserRequest {
+                    admin_id: admin_id__.unwrap_or_default(),
+                    user_id: user_id__.unwrap_or_default(),
+                    suspension_days: suspension_days__,
+                    reason: reason__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("user_def.v1.SuspendUserRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SuspendUserResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.public_flags != 0 {
+            len += 1;
+        }
+        if self.suspended_until.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("user_def.v1.SuspendUserResponse", len)?;
+        if self.public_flags != 0 {
+            struct_ser.serialize_field("publicFlags", &self.public_flags)?;
+        }
+        if let Some(v) = self.suspended_until.as_ref() {
+            struct_ser.serialize_field("suspendedUntil", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SuspendUserResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "public_flags",
+            "publicFlags",
+            "suspended_until",
+            "suspendedUntil",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PublicFlags,
+            SuspendedUntil,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "publicFlags" | "public_flags" => Ok(GeneratedField::PublicFlags),
+                            "suspendedUntil" | "suspended_until" => Ok(GeneratedField::SuspendedUntil),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SuspendUserResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct user_def.v1.SuspendUserResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<SuspendUserResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut public_flags__ = None;
+                let mut suspended_until__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::PublicFlags => {
+                            if public_flags__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicFlags"));
+                            }
+                            public_flags__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SuspendedUntil => {
+                            if suspended_until__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("suspendedUntil"));
+                            }
+                            suspended_until__ = map.next_value()?;
+                        }
+                    }
+                }
+                Ok(SuspendUserResponse {
+                    public_flags: public_flags__.unwrap_or_default(),
+                    suspended_until: suspended_until__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("user_def.v1.SuspendUserResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StatusVisibility {
+impl serde::Serialize for UnsuspendUserRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.admin_id.is_empty() {
+            len += 1;
+        }
+        if !self.user_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("user_def.v1.UnsuspendUserRequest", len)?;
+        if !self.admin_id.is_empty() {
+            struct_ser.serialize_field("adminId", &self.admin_id)?;
+        }
+        if !self.user_id.is_empty() {
+            struct_ser.serialize_field("userId", &self.user_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UnsuspendUserRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "admin_id",
+            "adminId",
+            "user_id",
+            "userId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AdminId,
+            UserId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "adminId" | "admin_id" => Ok(GeneratedField::AdminId),
+                            "userId" | "user_id" => Ok(GeneratedField::UserId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UnsuspendUserRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct user_def.v1.UnsuspendUserRequest")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UnsuspendUserRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut admin_id__ = None;
+                let mut user_id__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::AdminId => {
+                            if admin_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("adminId"));
+                            }
+                            admin_id__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::UserId => {
+                            if user_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("userId"));
+                            }
+                            user_id__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(UnsuspendUserRequest {
+                    admin_id: admin_id__.unwrap_or_default(),
+                    user_id: user_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("user_def.v1.UnsuspendUserRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UnsuspendUserResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.public_flags != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("user_def.v1.UnsuspendUserResponse", len)?;
+        if self.public_flags != 0 {
+            struct_ser.serialize_field("publicFlags", &self.public_flags)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UnsuspendUserResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "public_flags",
+            "publicFlags",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PublicFlags,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "publicFlags" | "public_flags" => Ok(GeneratedField::PublicFlags),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UnsuspendUserResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct user_def.v1.UnsuspendUserResponse")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<UnsuspendUserResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut public_flags__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::PublicFlags => {
+                            if public_flags__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicFlags"));
+                            }
+                            public_flags__ = 
+                                Some(map.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(UnsuspendUserResponse {
+                    public_flags: public_flags__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("user_def.v1.UnsuspendUserResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UserFlag {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1925,30 +3385,36 @@ impl serde::Serialize for StatusVisibility {
     {
         let variant = match self {
             Self::Unspecified => 0,
-            Self::Global => 1,
-            Self::Followers => 2,
-            Self::Friends => 3,
+            Self::Staff => 1,
+            Self::TemporarilySuspended => 2,
+            Self::PermanentlySuspended => 4,
+            Self::Verified => 8,
+            Self::EarlyUser => 16,
+            Self::PlusMember => 32,
         };
         serializer.serialize_i32(variant)
     }
 }
-impl<'de> serde::Deserialize<'de> for StatusVisibility {
+impl<'de> serde::Deserialize<'de> for UserFlag {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "STATUS_VISIBILITY_UNSPECIFIED",
-            "STATUS_VISIBILITY_GLOBAL",
-            "STATUS_VISIBILITY_FOLLOWERS",
-            "STATUS_VISIBILITY_FRIENDS",
+            "USER_FLAG_UNSPECIFIED",
+            "USER_FLAG_STAFF",
+            "USER_FLAG_TEMPORARILY_SUSPENDED",
+            "USER_FLAG_PERMANENTLY_SUSPENDED",
+            "USER_FLAG_VERIFIED",
+            "USER_FLAG_EARLY_USER",
+            "USER_FLAG_PLUS_MEMBER",
         ];
 
         struct GeneratedVisitor;
 
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StatusVisibility;
+            type Value = UserFlag;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 write!(formatter, "expected one of: {:?}", &FIELDS)
@@ -1961,7 +3427,7 @@ impl<'de> serde::Deserialize<'de> for StatusVisibility {
                 use std::convert::TryFrom;
                 i32::try_from(v)
                     .ok()
-                    .and_then(StatusVisibility::from_i32)
+                    .and_then(UserFlag::from_i32)
                     .ok_or_else(|| {
                         serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
                     })
@@ -1974,7 +3440,7 @@ impl<'de> serde::Deserialize<'de> for StatusVisibility {
                 use std::convert::TryFrom;
                 i32::try_from(v)
                     .ok()
-                    .and_then(StatusVisibility::from_i32)
+                    .and_then(UserFlag::from_i32)
                     .ok_or_else(|| {
                         serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
                     })
@@ -1985,10 +3451,13 @@ impl<'de> serde::Deserialize<'de> for StatusVisibility {
                 E: serde::de::Error,
             {
                 match value {
-                    "STATUS_VISIBILITY_UNSPECIFIED" => Ok(StatusVisibility::Unspecified),
-                    "STATUS_VISIBILITY_GLOBAL" => Ok(StatusVisibility::Global),
-                    "STATUS_VISIBILITY_FOLLOWERS" => Ok(StatusVisibility::Followers),
-                    "STATUS_VISIBILITY_FRIENDS" => Ok(StatusVisibility::Friends),
+                    "USER_FLAG_UNSPECIFIED" => Ok(UserFlag::Unspecified),
+                    "USER_FLAG_STAFF" => Ok(UserFlag::Staff),
+                    "USER_FLAG_TEMPORARILY_SUSPENDED" => Ok(UserFlag::TemporarilySuspended),
+                    "USER_FLAG_PERMANENTLY_SUSPENDED" => Ok(UserFlag::PermanentlySuspended),
+                    "USER_FLAG_VERIFIED" => Ok(UserFlag::Verified),
+                    "USER_FLAG_EARLY_USER" => Ok(UserFlag::EarlyUser),
+                    "USER_FLAG_PLUS_MEMBER" => Ok(UserFlag::PlusMember),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }